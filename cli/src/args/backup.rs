@@ -0,0 +1,79 @@
+use crate::args::common::ListMode;
+use clap::{ArgGroup, Args, Subcommand};
+use iggy::models::backup::BackupRestoreTarget;
+use iggy::utils::timestamp::IggyTimestamp;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum BackupAction {
+    /// Create backup of the server data
+    ///
+    /// Create a consistent backup of the state, segments and consumer offsets
+    /// while the server is running. Incremental backup stores only the data
+    /// appended since the latest backup (or a full copy if there is none).
+    ///
+    /// Examples
+    ///  iggy backup create
+    ///  iggy backup create --incremental
+    #[clap(verbatim_doc_comment, visible_alias = "c")]
+    Create(BackupCreateArgs),
+    /// List all backups
+    ///
+    /// Examples
+    ///  iggy backup list
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    List(BackupListArgs),
+    /// Restore backup to the chosen point in time
+    ///
+    /// Restore the backed up data (verified by checksums) either to an empty
+    /// directory on the server side or using the configured archiver.
+    /// The data created after the provided point in time is discarded,
+    /// if the point in time is not provided, the latest backup is restored.
+    ///
+    /// Examples
+    ///  iggy backup restore --directory /var/lib/iggy/restored
+    ///  iggy backup restore --directory restored --point-in-time "2024-05-01 12:00:00"
+    ///  iggy backup restore --archiver --point-in-time 2024-05-01T12:00:00Z
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Restore(BackupRestoreArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct BackupCreateArgs {
+    /// Store only the data appended since the latest backup
+    #[clap(short, long, default_value_t = false)]
+    pub(crate) incremental: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct BackupListArgs {
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+#[derive(Debug, Clone, Args)]
+#[command(group(ArgGroup::new("target").required(true).args(["directory", "archiver"])))]
+pub(crate) struct BackupRestoreArgs {
+    /// Point in time to restore the data to
+    ///
+    /// Either microseconds since the Unix epoch, RFC 3339 date time
+    /// or UTC date time in "%Y-%m-%d %H:%M:%S" format
+    #[clap(long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(IggyTimestamp))]
+    pub(crate) point_in_time: Option<IggyTimestamp>,
+    /// Restore the data to the empty directory on the server side
+    #[clap(long)]
+    pub(crate) directory: Option<String>,
+    /// Restore the data using the archiver configured on the server side
+    #[clap(long, default_value_t = false)]
+    pub(crate) archiver: bool,
+}
+
+impl BackupRestoreArgs {
+    pub(crate) fn target(&self) -> BackupRestoreTarget {
+        match &self.directory {
+            Some(directory) => BackupRestoreTarget::Directory(directory.clone()),
+            None => BackupRestoreTarget::Archiver,
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use clap::ValueEnum;
+use iggy::cli::backups::get_backups::GetBackupsOutput;
 use iggy::cli::client::get_clients::GetClientsOutput;
 use iggy::cli::consumer_group::get_consumer_groups::GetConsumerGroupsOutput;
 use iggy::cli::context::get_contexts::GetContextsOutput;
 use iggy::cli::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokensOutput;
 use iggy::cli::streams::get_streams::GetStreamsOutput;
 use iggy::cli::system::stats::GetStatsOutput;
+use iggy::cli::topic_templates::get_topic_templates::GetTopicTemplatesOutput;
 use iggy::cli::topics::get_topics::GetTopicsOutput;
 use iggy::cli::users::get_users::GetUsersOutput;
 
@@ -32,6 +34,15 @@ impl From<ListMode> for GetTopicsOutput {
     }
 }
 
+impl From<ListMode> for GetTopicTemplatesOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetTopicTemplatesOutput::Table,
+            ListMode::List => GetTopicTemplatesOutput::List,
+        }
+    }
+}
+
 impl From<ListMode> for GetPersonalAccessTokensOutput {
     fn from(mode: ListMode) -> Self {
         match mode {
@@ -41,6 +52,15 @@ impl From<ListMode> for GetPersonalAccessTokensOutput {
     }
 }
 
+impl From<ListMode> for GetBackupsOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetBackupsOutput::Table,
+            ListMode::List => GetBackupsOutput::List,
+        }
+    }
+}
+
 impl From<ListMode> for GetUsersOutput {
     fn from(mode: ListMode) -> Self {
         match mode {
@@ -10,6 +10,7 @@ use iggy::cli::context::common::ContextConfig;
 use system::SnapshotArgs;
 
 use crate::args::{
+    backup::BackupAction,
     client::ClientAction,
     consumer_group::ConsumerGroupAction,
     consumer_offset::ConsumerOffsetAction,
@@ -20,6 +21,7 @@ use crate::args::{
     stream::StreamAction,
     system::{PingArgs, StatsArgs},
     topic::TopicAction,
+    topic_template::TopicTemplateAction,
 };
 
 #[cfg(feature = "login-session")]
@@ -27,6 +29,7 @@ use crate::args::system::LoginArgs;
 
 use self::user::UserAction;
 
+pub(crate) mod backup;
 pub(crate) mod client;
 pub(crate) mod common;
 pub(crate) mod consumer_group;
@@ -39,6 +42,7 @@ pub(crate) mod personal_access_token;
 pub(crate) mod stream;
 pub(crate) mod system;
 pub(crate) mod topic;
+pub(crate) mod topic_template;
 pub(crate) mod user;
 
 static CARGO_BIN_NAME: &str = env!("CARGO_BIN_NAME");
@@ -118,6 +122,9 @@ pub(crate) enum Command {
     /// topic operations
     #[command(subcommand, visible_alias = "t")]
     Topic(TopicAction),
+    /// topic template operations
+    #[command(subcommand, visible_alias = "tt")]
+    TopicTemplate(TopicTemplateAction),
     /// partition operations
     #[command(subcommand, visible_alias = "p")]
     Partition(PartitionAction),
@@ -141,6 +148,9 @@ pub(crate) enum Command {
     /// collect iggy server troubleshooting data
     #[clap(verbatim_doc_comment)]
     Snapshot(SnapshotArgs),
+    /// backup operations
+    #[command(subcommand)]
+    Backup(BackupAction),
     /// personal access token operations
     #[command(subcommand)]
     Pat(PersonalAccessTokenAction),
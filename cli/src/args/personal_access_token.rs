@@ -1,4 +1,6 @@
 use crate::args::common::ListMode;
+use crate::args::permissions::global::GlobalPermissionsArg;
+use crate::args::permissions::stream::StreamPermissionsArg;
 use clap::{Args, Subcommand};
 use iggy::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 
@@ -9,11 +11,15 @@ pub(crate) enum PersonalAccessTokenAction {
     /// Create personal access token which allow authenticating the clients using
     /// a token, instead of the regular credentials (username and password)
     /// In quiet mode only the personal access token name is printed
+    /// By default the token has all the permissions of the user, global and stream
+    /// permissions can be provided to restrict the token to a subset of them
     ///
     /// Examples
     ///  iggy pat create name
     ///  iggy pat create client 1day
     ///  iggy pat create sensor 3weeks
+    ///  iggy pat create sender --stream-permissions 1:s_msg
+    ///  iggy pat create reader -g r_str,r_top,p_msg
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(PersonalAccessTokenCreateArgs),
     /// Delete personal access token
@@ -50,6 +56,27 @@ pub(crate) struct PersonalAccessTokenCreateArgs {
     /// This option can only be used for creating tokens which does not have expiry time set.
     #[clap(short, long, default_value_t = false, group = "store")]
     pub(crate) store_token: bool,
+    /// Restrict the token to the given global permissions
+    ///
+    /// Format is the same as for the global permissions of the user (see iggy user create --help).
+    /// The token never exceeds the permissions of the user, even if they are changed later.
+    ///
+    /// Examples:
+    ///  iggy pat create reader --global-permissions r_str,r_top,p_msg
+    #[clap(short, long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(GlobalPermissionsArg))]
+    pub(crate) global_permissions: Option<GlobalPermissionsArg>,
+    /// Restrict the token to the given stream permissions
+    ///
+    /// Format is the same as for the stream permissions of the user (see iggy user create --help).
+    /// The token never exceeds the permissions of the user, even if they are changed later.
+    ///
+    /// Examples:
+    ///  iggy pat create sender --stream-permissions 1:s_msg
+    ///  iggy pat create topic_reader --stream-permissions 2#1:r_top,p_msg
+    #[clap(long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
+    pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -1,6 +1,8 @@
 use crate::args::common::ListMode;
 use clap::{Args, Subcommand};
 use iggy::identifier::Identifier;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum StreamAction {
@@ -58,6 +60,27 @@ pub(crate) enum StreamAction {
     ///  iggy stream purge test
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Purge(StreamPurgeArgs),
+    /// Get default settings of the topics created in given stream ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    ///
+    /// Examples:
+    ///  iggy stream get-defaults 1
+    ///  iggy stream get-defaults test
+    #[clap(verbatim_doc_comment, visible_alias = "gd")]
+    GetDefaults(StreamGetDefaultsArgs),
+    /// Set default settings of the topics created in given stream ID
+    ///
+    /// Defaults are used for the settings left at server default when the topic is created
+    /// Setting all the values to server default clears the stream defaults
+    /// Stream ID can be specified as a stream name or ID
+    ///
+    /// Examples:
+    ///  iggy stream set-defaults 1 7days
+    ///  iggy stream set-defaults test -m 10GB -r 3
+    ///  iggy stream set-defaults test server_default
+    #[clap(verbatim_doc_comment, visible_alias = "sd")]
+    SetDefaults(StreamSetDefaultsArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -109,3 +132,34 @@ pub(crate) struct StreamPurgeArgs {
     /// Stream ID can be specified as a stream name or ID
     pub(crate) stream_id: Identifier,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct StreamGetDefaultsArgs {
+    /// Stream ID to get topic defaults
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    pub(crate) stream_id: Identifier,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct StreamSetDefaultsArgs {
+    /// Stream ID to set topic defaults
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    pub(crate) stream_id: Identifier,
+    /// Default max topic size in human-readable format like "unlimited" or "15GB"
+    ///
+    /// "server_default" or skipping parameter makes the topics use server default max topic size
+    #[arg(short, long, default_value = "server_default", verbatim_doc_comment)]
+    pub(crate) max_topic_size: MaxTopicSize,
+    /// Default replication factor of the topics
+    ///
+    /// Skipping parameter makes the topics use server default replication factor
+    #[arg(short, long, verbatim_doc_comment)]
+    pub(crate) replication_factor: Option<u8>,
+    /// Default message expiry time in human-readable format like "unlimited" or "15days 2min 2s"
+    ///
+    /// "server_default" or skipping parameter makes the topics use server default expiry time
+    #[arg(default_value = "server_default", value_parser = clap::value_parser!(IggyExpiry), verbatim_doc_comment)]
+    pub(crate) message_expiry: Vec<IggyExpiry>,
+}
@@ -19,6 +19,18 @@ pub(crate) enum TopicAction {
     ///  iggy topic create -t 3 1 sensor3 2 none unlimited
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(TopicCreateArgs),
+    /// Create topic with given name from topic template for given stream ID
+    ///
+    /// Partitions count and compression algorithm are taken from the template,
+    /// remaining settings are taken from the template, stream defaults or server config
+    /// Stream ID can be specified as a stream name or ID
+    /// If topic ID is not provided then the server will automatically assign it
+    ///
+    /// Examples
+    ///  iggy topic create-from-template 1 sensor1 sensors
+    ///  iggy topic create-from-template -t 3 prod sensor2 sensors
+    #[clap(verbatim_doc_comment, visible_alias = "ct")]
+    CreateFromTemplate(TopicCreateFromTemplateArgs),
     /// Delete topic with given ID in given stream ID
     ///
     /// Stream ID can be specified as a stream name or ID
@@ -113,6 +125,22 @@ pub(crate) struct TopicCreateArgs {
     pub(crate) message_expiry: Vec<IggyExpiry>,
 }
 
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicCreateFromTemplateArgs {
+    /// Stream ID to create topic
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Name of the topic
+    pub(crate) name: String,
+    /// Name of the topic template
+    pub(crate) template: String,
+    /// Topic ID to create
+    #[clap(short, long)]
+    pub(crate) topic_id: Option<u32>,
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct TopicDeleteArgs {
     /// Stream ID to delete topic
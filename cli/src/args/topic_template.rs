@@ -0,0 +1,85 @@
+use crate::args::common::ListMode;
+use clap::{Args, Subcommand};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum TopicTemplateAction {
+    /// Create topic template with given name, number of partitions, compression algorithm and expiry time
+    ///
+    /// Settings left at server default are taken from the stream defaults or server config
+    /// when the topic is created from the template
+    ///
+    /// Examples
+    ///  iggy topic-template create sensors 2 gzip 15days
+    ///  iggy topic-template create logs 4 none -m 10GB -r 3
+    #[clap(verbatim_doc_comment, visible_alias = "c")]
+    Create(TopicTemplateCreateArgs),
+    /// Delete topic template with given name
+    ///
+    /// Topics created from the template are not affected
+    ///
+    /// Examples
+    ///  iggy topic-template delete sensors
+    #[clap(verbatim_doc_comment, visible_alias = "d")]
+    Delete(TopicTemplateDeleteArgs),
+    /// Get topic template with given name
+    ///
+    /// Examples
+    ///  iggy topic-template get sensors
+    #[clap(verbatim_doc_comment, visible_alias = "g")]
+    Get(TopicTemplateGetArgs),
+    /// List all topic templates
+    ///
+    /// Examples
+    ///  iggy topic-template list
+    ///  iggy topic-template list -l list
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    List(TopicTemplateListArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicTemplateCreateArgs {
+    /// Name of the topic template
+    pub(crate) name: String,
+    /// Number of partitions of the created topics
+    pub(crate) partitions_count: u32,
+    /// Compression algorithm of the created topics, set to "none" for no compression
+    #[arg(value_parser = clap::value_parser!(CompressionAlgorithm), verbatim_doc_comment)]
+    pub(crate) compression_algorithm: CompressionAlgorithm,
+    /// Max topic size in human-readable format like "unlimited" or "15GB"
+    ///
+    /// "server_default" or skipping parameter makes the topics use stream or server default max topic size
+    #[arg(short, long, default_value = "server_default", verbatim_doc_comment)]
+    pub(crate) max_topic_size: MaxTopicSize,
+    /// Replication factor of the created topics
+    ///
+    /// Skipping parameter makes the topics use stream or server default replication factor
+    #[arg(short, long, verbatim_doc_comment)]
+    pub(crate) replication_factor: Option<u8>,
+    /// Message expiry time in human-readable format like "unlimited" or "15days 2min 2s"
+    ///
+    /// "server_default" or skipping parameter makes the topics use stream or server default expiry time
+    #[arg(default_value = "server_default", value_parser = clap::value_parser!(IggyExpiry), verbatim_doc_comment)]
+    pub(crate) message_expiry: Vec<IggyExpiry>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicTemplateDeleteArgs {
+    /// Name of the topic template to delete
+    pub(crate) name: String,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicTemplateGetArgs {
+    /// Name of the topic template to get
+    pub(crate) name: String,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicTemplateListArgs {
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
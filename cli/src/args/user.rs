@@ -82,6 +82,17 @@ pub(crate) enum UserAction {
     ///  iggy user permissions client
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Permissions(UserPermissionsArgs),
+    /// Unlock user with given ID
+    ///
+    /// Clears the temporary lockout applied to the user after
+    /// too many failed login attempts.
+    /// The user ID can be specified as either a username or an ID
+    ///
+    /// Examples:
+    ///  iggy user unlock 2
+    ///  iggy user unlock testuser
+    #[clap(verbatim_doc_comment, visible_alias = "u")]
+    Unlock(UserUnlockArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -163,6 +174,14 @@ pub(crate) struct UserDeleteArgs {
     pub(crate) user_id: Identifier,
 }
 
+#[derive(Debug, Clone, Args)]
+pub(crate) struct UserUnlockArgs {
+    /// User ID to unlock
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct UserGetArgs {
     /// User ID to get
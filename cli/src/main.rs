@@ -4,10 +4,10 @@ mod error;
 mod logging;
 
 use crate::args::{
-    client::ClientAction, consumer_group::ConsumerGroupAction,
+    backup::BackupAction, client::ClientAction, consumer_group::ConsumerGroupAction,
     consumer_offset::ConsumerOffsetAction, permissions::PermissionsArgs,
     personal_access_token::PersonalAccessTokenAction, stream::StreamAction, topic::TopicAction,
-    Command, IggyConsoleArgs,
+    topic_template::TopicTemplateAction, Command, IggyConsoleArgs,
 };
 use crate::credentials::IggyCredentials;
 use crate::error::IggyCmdError;
@@ -23,6 +23,10 @@ use iggy::cli::context::common::ContextManager;
 use iggy::cli::context::use_context::UseContextCmd;
 use iggy::cli::system::snapshot::GetSnapshotCmd;
 use iggy::cli::{
+    backups::{
+        create_backup::CreateBackupCmd, get_backups::GetBackupsCmd,
+        restore_backup::RestoreBackupCmd,
+    },
     client::{get_client::GetClientCmd, get_clients::GetClientsCmd},
     consumer_group::{
         create_consumer_group::CreateConsumerGroupCmd,
@@ -45,12 +49,20 @@ use iggy::cli::{
     },
     streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
-        get_streams::GetStreamsCmd, purge_stream::PurgeStreamCmd, update_stream::UpdateStreamCmd,
+        get_stream_topic_defaults::GetStreamTopicDefaultsCmd, get_streams::GetStreamsCmd,
+        purge_stream::PurgeStreamCmd, update_stream::UpdateStreamCmd,
+        update_stream_topic_defaults::UpdateStreamTopicDefaultsCmd,
     },
     system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
+    topic_templates::{
+        create_topic_template::CreateTopicTemplateCmd,
+        delete_topic_template::DeleteTopicTemplateCmd, get_topic_template::GetTopicTemplateCmd,
+        get_topic_templates::GetTopicTemplatesCmd,
+    },
     topics::{
-        create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd,
-        get_topics::GetTopicsCmd, purge_topic::PurgeTopicCmd, update_topic::UpdateTopicCmd,
+        create_topic::CreateTopicCmd, create_topic_from_template::CreateTopicFromTemplateCmd,
+        delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd, get_topics::GetTopicsCmd,
+        purge_topic::PurgeTopicCmd, update_topic::UpdateTopicCmd,
     },
     users::{
         change_password::ChangePasswordCmd,
@@ -58,6 +70,7 @@ use iggy::cli::{
         delete_user::DeleteUserCmd,
         get_user::GetUserCmd,
         get_users::GetUsersCmd,
+        unlock_user::UnlockUserCmd,
         update_permissions::UpdatePermissionsCmd,
         update_user::{UpdateUserCmd, UpdateUserType},
     },
@@ -98,6 +111,15 @@ fn get_command(
             StreamAction::Get(args) => Box::new(GetStreamCmd::new(args.stream_id.clone())),
             StreamAction::List(args) => Box::new(GetStreamsCmd::new(args.list_mode.into())),
             StreamAction::Purge(args) => Box::new(PurgeStreamCmd::new(args.stream_id.clone())),
+            StreamAction::GetDefaults(args) => {
+                Box::new(GetStreamTopicDefaultsCmd::new(args.stream_id.clone()))
+            }
+            StreamAction::SetDefaults(args) => Box::new(UpdateStreamTopicDefaultsCmd::new(
+                args.stream_id.clone(),
+                args.message_expiry.clone().into(),
+                args.max_topic_size,
+                args.replication_factor,
+            )),
         },
         Command::Topic(command) => match command {
             TopicAction::Create(args) => Box::new(CreateTopicCmd::new(
@@ -110,6 +132,12 @@ fn get_command(
                 args.max_topic_size,
                 args.replication_factor,
             )),
+            TopicAction::CreateFromTemplate(args) => Box::new(CreateTopicFromTemplateCmd::new(
+                args.stream_id.clone(),
+                args.topic_id,
+                args.name.clone(),
+                args.template.clone(),
+            )),
             TopicAction::Delete(args) => Box::new(DeleteTopicCmd::new(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
@@ -136,6 +164,23 @@ fn get_command(
                 args.topic_id.clone(),
             )),
         },
+        Command::TopicTemplate(command) => match command {
+            TopicTemplateAction::Create(args) => Box::new(CreateTopicTemplateCmd::new(
+                args.name.clone(),
+                args.partitions_count,
+                args.compression_algorithm,
+                args.message_expiry.clone().into(),
+                args.max_topic_size,
+                args.replication_factor,
+            )),
+            TopicTemplateAction::Delete(args) => {
+                Box::new(DeleteTopicTemplateCmd::new(args.name.clone()))
+            }
+            TopicTemplateAction::Get(args) => Box::new(GetTopicTemplateCmd::new(args.name.clone())),
+            TopicTemplateAction::List(args) => {
+                Box::new(GetTopicTemplatesCmd::new(args.list_mode.into()))
+            }
+        },
         Command::Partition(command) => match command {
             PartitionAction::Create(args) => Box::new(CreatePartitionsCmd::new(
                 args.stream_id.clone(),
@@ -156,6 +201,18 @@ fn get_command(
             args.snapshot_types,
             args.out_dir,
         )),
+        Command::Backup(command) => match command {
+            BackupAction::Create(create_args) => {
+                Box::new(CreateBackupCmd::new(create_args.incremental))
+            }
+            BackupAction::List(list_args) => {
+                Box::new(GetBackupsCmd::new(list_args.list_mode.into()))
+            }
+            BackupAction::Restore(restore_args) => Box::new(RestoreBackupCmd::new(
+                restore_args.point_in_time,
+                restore_args.target(),
+            )),
+        },
         Command::Pat(command) => match command {
             PersonalAccessTokenAction::Create(pat_create_args) => {
                 Box::new(CreatePersonalAccessTokenCmd::new(
@@ -164,6 +221,11 @@ fn get_command(
                     cli_options.quiet,
                     pat_create_args.store_token,
                     iggy_args.get_server_address().unwrap(),
+                    PermissionsArgs::new(
+                        pat_create_args.global_permissions.clone(),
+                        pat_create_args.stream_permissions.clone(),
+                    )
+                    .into(),
                 ))
             }
             PersonalAccessTokenAction::Delete(pat_delete_args) => {
@@ -213,6 +275,9 @@ fn get_command(
                 )
                 .into(),
             )),
+            UserAction::Unlock(unlock_args) => {
+                Box::new(UnlockUserCmd::new(unlock_args.user_id.clone()))
+            }
         },
         Command::Client(command) => match command {
             ClientAction::Get(get_args) => Box::new(GetClientCmd::new(get_args.client_id)),
@@ -235,6 +235,14 @@ impl TestServer {
         self.cleanup();
     }
 
+    /// Kills the server process immediately, without letting it shut down gracefully.
+    pub fn kill(&mut self) {
+        if let Some(mut child_handle) = self.child_handle.take() {
+            child_handle.kill().unwrap();
+            child_handle.wait().unwrap();
+        }
+    }
+
     pub fn is_started(&self) -> bool {
         self.child_handle.is_some()
     }
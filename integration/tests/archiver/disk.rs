@@ -91,6 +91,39 @@ async fn should_fail_when_file_to_archive_does_not_exist() {
     assert!(matches!(error, ArchiverError::FileToArchiveNotFound { .. }));
 }
 
+#[tokio::test]
+async fn should_fetch_archived_file_from_disk() {
+    let setup = DiskArchiverSetup::init().await;
+    let archiver = setup.archiver();
+    let content = "hello world";
+    let file_to_archive_path = format!("{}/file_to_archive", setup.base_path);
+    create_file(&file_to_archive_path, content).await;
+    let files_to_archive = vec![file_to_archive_path.as_ref()];
+    archiver.archive(&files_to_archive, None).await.unwrap();
+    let fetched_file_path = format!("{}/fetched/file", setup.base_path);
+
+    let result = archiver
+        .fetch(&file_to_archive_path, None, &fetched_file_path)
+        .await;
+    assert!(result.is_ok());
+    assert_archived_file(&file_to_archive_path, &fetched_file_path, content).await;
+}
+
+#[tokio::test]
+async fn should_fail_when_file_to_fetch_is_not_archived() {
+    let setup = DiskArchiverSetup::init().await;
+    let archiver = setup.archiver();
+    let fetched_file_path = format!("{}/fetched/file", setup.base_path);
+    let result = archiver
+        .fetch("invalid_archived_file", None, &fetched_file_path)
+        .await;
+
+    assert!(result.is_err());
+    let error = result.err().unwrap();
+    assert!(matches!(error, ArchiverError::ArchivedFileNotFound { .. }));
+    assert!(!Path::new(&fetched_file_path).exists());
+}
+
 async fn create_file(path: &str, content: &str) {
     let mut file = file::overwrite(path).await.unwrap();
     file.write_all(content.as_bytes()).await.unwrap();
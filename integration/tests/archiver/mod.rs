@@ -4,6 +4,7 @@ use tokio::fs::create_dir;
 use uuid::Uuid;
 
 mod disk;
+mod object_storage;
 mod s3;
 
 pub struct DiskArchiverSetup {
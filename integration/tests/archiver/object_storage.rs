@@ -0,0 +1,119 @@
+use server::archiver::object_storage::ObjectStoreArchiver;
+use server::archiver::Archiver;
+use server::server_error::ArchiverError;
+use tokio::fs::{create_dir, read_to_string, write};
+use uuid::Uuid;
+
+struct ObjectStoreArchiverSetup {
+    base_path: String,
+    archiver: ObjectStoreArchiver,
+}
+
+impl ObjectStoreArchiverSetup {
+    async fn init() -> ObjectStoreArchiverSetup {
+        let base_path = format!("test_local_data_{}", Uuid::now_v7().to_u128_le());
+        create_dir(&base_path).await.unwrap();
+        let archiver = ObjectStoreArchiver::new_in_memory();
+        archiver.init().await.unwrap();
+        Self {
+            base_path,
+            archiver,
+        }
+    }
+}
+
+impl Drop for ObjectStoreArchiverSetup {
+    fn drop(&mut self) {
+        std::fs::remove_dir_all(&self.base_path).unwrap();
+    }
+}
+
+#[tokio::test]
+async fn should_archive_and_fetch_file_from_object_store() {
+    let setup = ObjectStoreArchiverSetup::init().await;
+    let content = "hello world";
+    let file_to_archive_path = format!("{}/file_to_archive", setup.base_path);
+    write(&file_to_archive_path, content).await.unwrap();
+
+    setup
+        .archiver
+        .archive(&[file_to_archive_path.as_ref()], None)
+        .await
+        .unwrap();
+    assert!(setup
+        .archiver
+        .is_archived(&file_to_archive_path, None)
+        .await
+        .unwrap());
+
+    let fetched_file_path = format!("{}/fetched/file", setup.base_path);
+    setup
+        .archiver
+        .fetch(&file_to_archive_path, None, &fetched_file_path)
+        .await
+        .unwrap();
+    assert_eq!(read_to_string(&fetched_file_path).await.unwrap(), content);
+}
+
+#[tokio::test]
+async fn should_archive_file_within_additional_base_directory() {
+    let setup = ObjectStoreArchiverSetup::init().await;
+    let base_directory = Some("base".to_string());
+    let file_to_archive_path = format!("{}/file_to_archive", setup.base_path);
+    write(&file_to_archive_path, "hello world").await.unwrap();
+
+    setup
+        .archiver
+        .archive(&[file_to_archive_path.as_ref()], base_directory.clone())
+        .await
+        .unwrap();
+
+    assert!(setup
+        .archiver
+        .is_archived(&file_to_archive_path, base_directory)
+        .await
+        .unwrap());
+    assert!(!setup
+        .archiver
+        .is_archived(&file_to_archive_path, None)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn should_return_false_when_file_is_not_archived_in_object_store() {
+    let setup = ObjectStoreArchiverSetup::init().await;
+    let file_path = format!("{}/file", setup.base_path);
+
+    let is_archived = setup.archiver.is_archived(&file_path, None).await;
+    assert!(is_archived.is_ok());
+    assert!(!is_archived.unwrap());
+}
+
+#[tokio::test]
+async fn should_fail_when_file_to_archive_in_object_store_does_not_exist() {
+    let setup = ObjectStoreArchiverSetup::init().await;
+    let file_path = format!("{}/file", setup.base_path);
+
+    let result = setup.archiver.archive(&[file_path.as_ref()], None).await;
+    assert!(matches!(
+        result,
+        Err(ArchiverError::FileToArchiveNotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn should_fail_when_file_to_fetch_is_not_archived_in_object_store() {
+    let setup = ObjectStoreArchiverSetup::init().await;
+    let file_path = format!("{}/file", setup.base_path);
+    let fetched_file_path = format!("{}/fetched", setup.base_path);
+
+    let result = setup
+        .archiver
+        .fetch(&file_path, None, &fetched_file_path)
+        .await;
+    assert!(matches!(
+        result,
+        Err(ArchiverError::ArchivedFileNotFound { .. })
+    ));
+}
@@ -17,11 +17,13 @@ pub async fn should_help_match() {
 Commands:
   stream           stream operations [aliases: s]
   topic            topic operations [aliases: t]
+  topic-template   topic template operations [aliases: tt]
   partition        partition operations [aliases: p]
   ping             ping iggy server
   me               get current client info
   stats            get iggy server statistics
   snapshot         collect iggy server troubleshooting data
+  backup           backup operations
   pat              personal access token operations
   user             user operations [aliases: u]
   client           client operations [aliases: c]
@@ -10,4 +10,5 @@ mod personal_access_token;
 mod stream;
 mod system;
 mod topic;
+mod topic_template;
 mod user;
@@ -112,11 +112,15 @@ pub async fn should_help_match() {
 Create personal access token which allow authenticating the clients using
 a token, instead of the regular credentials (username and password)
 In quiet mode only the personal access token name is printed
+By default the token has all the permissions of the user, global and stream
+permissions can be provided to restrict the token to a subset of them
 
 Examples
  iggy pat create name
  iggy pat create client 1day
  iggy pat create sensor 3weeks
+ iggy pat create sender --stream-permissions 1:s_msg
+ iggy pat create reader -g r_str,r_top,p_msg
 
 {USAGE_PREFIX} pat create [OPTIONS] <NAME> [EXPIRY]...
 
@@ -135,6 +139,25 @@ Options:
 {CLAP_INDENT}
           Generated token is stored in a platform-specific secure storage without revealing its content to the user. It can be used to authenticate on iggy server using associated name and -n/--token-name command line option instead of -u/--username and -p/--password or -t/--token. In quiet mode only the token name is printed. This option can only be used for creating tokens which does not have expiry time set.
 
+  -g, --global-permissions <GLOBAL_PERMISSIONS>
+          Restrict the token to the given global permissions
+{CLAP_INDENT}
+          Format is the same as for the global permissions of the user (see iggy user create --help).
+          The token never exceeds the permissions of the user, even if they are changed later.
+{CLAP_INDENT}
+          Examples:
+           iggy pat create reader --global-permissions r_str,r_top,p_msg
+
+      --stream-permissions <STREAM_PERMISSIONS>
+          Restrict the token to the given stream permissions
+{CLAP_INDENT}
+          Format is the same as for the stream permissions of the user (see iggy user create --help).
+          The token never exceeds the permissions of the user, even if they are changed later.
+{CLAP_INDENT}
+          Examples:
+           iggy pat create sender --stream-permissions 1:s_msg
+           iggy pat create topic_reader --stream-permissions 2#1:r_top,p_msg
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -161,8 +184,14 @@ Arguments:
   [EXPIRY]...  Personal access token expiry time in human-readable format
 
 Options:
-  -s, --store-token  Store token in an underlying platform-specific secure store
-  -h, --help         Print help (see more with '--help')
+  -s, --store-token
+          Store token in an underlying platform-specific secure store
+  -g, --global-permissions <GLOBAL_PERMISSIONS>
+          Restrict the token to the given global permissions
+      --stream-permissions <STREAM_PERMISSIONS>
+          Restrict the token to the given stream permissions
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
@@ -24,7 +24,7 @@ impl TestPatDeleteCmd {
 impl IggyCmdTestCase for TestPatDeleteCmd {
     async fn prepare_server_state(&mut self, client: &dyn Client) {
         let pat = client
-            .create_personal_access_token(&self.name, PersonalAccessTokenExpiry::NeverExpire)
+            .create_personal_access_token(&self.name, PersonalAccessTokenExpiry::NeverExpire, None)
             .await;
         assert!(pat.is_ok());
     }
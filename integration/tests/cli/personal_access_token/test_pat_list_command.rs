@@ -5,6 +5,7 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::models::permissions::{Permissions, StreamPermissions};
 use iggy::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -12,11 +13,21 @@ use serial_test::parallel;
 struct TestPatListCmd {
     name: String,
     output: OutputFormat,
+    permissions: Option<Permissions>,
 }
 
 impl TestPatListCmd {
     fn new(name: String, output: OutputFormat) -> Self {
-        Self { name, output }
+        Self {
+            name,
+            output,
+            permissions: None,
+        }
+    }
+
+    fn with_permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = Some(permissions);
+        self
     }
 
     fn to_args(&self) -> Vec<String> {
@@ -32,7 +43,11 @@ impl TestPatListCmd {
 impl IggyCmdTestCase for TestPatListCmd {
     async fn prepare_server_state(&mut self, client: &dyn Client) {
         let pat = client
-            .create_personal_access_token(&self.name, PersonalAccessTokenExpiry::NeverExpire)
+            .create_personal_access_token(
+                &self.name,
+                PersonalAccessTokenExpiry::NeverExpire,
+                self.permissions.clone(),
+            )
             .await;
         assert!(pat.is_ok());
     }
@@ -52,7 +67,11 @@ impl IggyCmdTestCase for TestPatListCmd {
                 "Executing list personal access tokens in {} mode",
                 self.output
             )))
-            .stdout(contains(self.name.clone()));
+            .stdout(contains(self.name.clone()))
+            .stdout(contains(match self.permissions {
+                Some(_) => "global: none; stream 1: send_messages",
+                None => "all user permissions",
+            }));
     }
 
     async fn verify_server_state(&self, client: &dyn Client) {
@@ -85,6 +104,22 @@ pub async fn should_be_successful() {
             OutputFormat::Table,
         ))
         .await;
+    iggy_cmd_test
+        .execute_test(
+            TestPatListCmd::new(String::from("sender"), OutputFormat::List).with_permissions(
+                Permissions {
+                    global: Default::default(),
+                    streams: Some(ahash::AHashMap::from([(
+                        1,
+                        StreamPermissions {
+                            send_messages: true,
+                            ..Default::default()
+                        },
+                    )])),
+                },
+            ),
+        )
+        .await;
 }
 
 #[tokio::test]
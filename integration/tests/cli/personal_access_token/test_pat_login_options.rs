@@ -67,7 +67,11 @@ impl TestLoginOptions {
 impl IggyCmdTestCase for TestLoginOptions {
     async fn prepare_server_state(&mut self, client: &dyn Client) {
         let token = client
-            .create_personal_access_token(&self.token_name, PersonalAccessTokenExpiry::NeverExpire)
+            .create_personal_access_token(
+                &self.token_name,
+                PersonalAccessTokenExpiry::NeverExpire,
+                None,
+            )
             .await;
         assert!(token.is_ok());
         let token = token.unwrap();
@@ -15,13 +15,15 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} stream <COMMAND>
 
 Commands:
-  create  Create stream with given name [aliases: c]
-  delete  Delete stream with given ID [aliases: d]
-  update  Update stream name for given stream ID [aliases: u]
-  get     Get details of a single stream with given ID [aliases: g]
-  list    List all streams [aliases: l]
-  purge   Purge all topics in given stream ID [aliases: p]
-  help    Print this message or the help of the given subcommand(s)
+  create        Create stream with given name [aliases: c]
+  delete        Delete stream with given ID [aliases: d]
+  update        Update stream name for given stream ID [aliases: u]
+  get           Get details of a single stream with given ID [aliases: g]
+  list          List all streams [aliases: l]
+  purge         Purge all topics in given stream ID [aliases: p]
+  get-defaults  Get default settings of the topics created in given stream ID [aliases: gd]
+  set-defaults  Set default settings of the topics created in given stream ID [aliases: sd]
+  help          Print this message or the help of the given subcommand(s)
 
 Options:
   -h, --help  Print help
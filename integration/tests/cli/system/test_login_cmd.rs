@@ -54,6 +54,7 @@ impl IggyCmdTestCase for TestLoginCmd {
                     .create_personal_access_token(
                         &login_session.get_token_name(),
                         PersonalAccessTokenExpiry::NeverExpire,
+                        None,
                     )
                     .await;
                 assert!(pat.is_ok());
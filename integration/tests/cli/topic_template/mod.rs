@@ -0,0 +1,3 @@
+mod test_topic_template_create_command;
+mod test_topic_template_delete_command;
+mod test_topic_template_help_command;
@@ -0,0 +1,154 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use predicates::str::diff;
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestTopicTemplateCreateCmd {
+    name: String,
+    partitions_count: u32,
+    compression_algorithm: CompressionAlgorithm,
+    message_expiry: Option<String>,
+    max_topic_size: Option<String>,
+    replication_factor: Option<u8>,
+}
+
+impl TestTopicTemplateCreateCmd {
+    fn new(
+        name: String,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        message_expiry: Option<String>,
+        max_topic_size: Option<String>,
+        replication_factor: Option<u8>,
+    ) -> Self {
+        Self {
+            name,
+            partitions_count,
+            compression_algorithm,
+            message_expiry,
+            max_topic_size,
+            replication_factor,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(max_topic_size) = &self.max_topic_size {
+            args.push("--max-topic-size".to_string());
+            args.push(max_topic_size.clone());
+        }
+        if let Some(replication_factor) = self.replication_factor {
+            args.push("--replication-factor".to_string());
+            args.push(format!("{replication_factor}"));
+        }
+        args.push(self.name.clone());
+        args.push(format!("{}", self.partitions_count));
+        args.push(format!("{}", self.compression_algorithm));
+        if let Some(message_expiry) = &self.message_expiry {
+            args.push(message_expiry.clone());
+        }
+        args
+    }
+
+    fn message_expiry(&self) -> IggyExpiry {
+        match &self.message_expiry {
+            Some(message_expiry) => IggyExpiry::from_str(message_expiry).unwrap(),
+            None => IggyExpiry::ServerDefault,
+        }
+    }
+
+    fn max_topic_size(&self) -> MaxTopicSize {
+        match &self.max_topic_size {
+            Some(max_topic_size) => MaxTopicSize::from_str(max_topic_size).unwrap(),
+            None => MaxTopicSize::ServerDefault,
+        }
+    }
+
+    fn replication_factor(&self) -> String {
+        match self.replication_factor {
+            Some(replication_factor) => format!("{replication_factor}"),
+            None => String::from("server_default"),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestTopicTemplateCreateCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("topic-template")
+            .arg("create")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let settings = format!(
+            "name: {}, partitions count: {}, compression algorithm: {}, message expiry: {}, max topic size: {}, replication factor: {}",
+            self.name,
+            self.partitions_count,
+            self.compression_algorithm,
+            self.message_expiry(),
+            self.max_topic_size(),
+            self.replication_factor(),
+        );
+        let message = format!(
+            "Executing create topic template with {settings}\nTopic template with {settings} created\n"
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let template = client
+            .get_topic_template(&self.name)
+            .await
+            .unwrap()
+            .expect("Failed to get topic template");
+
+        assert_eq!(template.name, self.name);
+        assert_eq!(template.partitions_count, self.partitions_count);
+        assert_eq!(template.compression_algorithm, self.compression_algorithm);
+        assert_eq!(template.message_expiry, self.message_expiry());
+        assert_eq!(template.max_topic_size, self.max_topic_size());
+        assert_eq!(template.replication_factor, self.replication_factor);
+
+        client.delete_topic_template(&self.name).await.unwrap();
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestTopicTemplateCreateCmd::new(
+            String::from("sensors"),
+            2,
+            CompressionAlgorithm::Gzip,
+            Some(String::from("1day")),
+            None,
+            None,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestTopicTemplateCreateCmd::new(
+            String::from("logs"),
+            4,
+            CompressionAlgorithm::None,
+            None,
+            Some(String::from("10GB")),
+            Some(3),
+        ))
+        .await;
+}
@@ -0,0 +1,71 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::models::topic_template::TopicTemplate;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestTopicTemplateDeleteCmd {
+    name: String,
+}
+
+impl TestTopicTemplateDeleteCmd {
+    fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestTopicTemplateDeleteCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let template = client
+            .create_topic_template(TopicTemplate {
+                name: self.name.clone(),
+                partitions_count: 1,
+                compression_algorithm: CompressionAlgorithm::None,
+                message_expiry: IggyExpiry::ServerDefault,
+                max_topic_size: MaxTopicSize::ServerDefault,
+                replication_factor: None,
+            })
+            .await;
+        assert!(template.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("topic-template")
+            .arg("delete")
+            .arg(self.name.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing delete topic template with name: {}\nTopic template with name: {} deleted\n",
+            self.name, self.name
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let template = client.get_topic_template(&self.name).await;
+        assert!(template.is_ok());
+        assert!(template.unwrap().is_none());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestTopicTemplateDeleteCmd::new(String::from("sensors")))
+        .await;
+}
@@ -0,0 +1,30 @@
+use crate::cli::common::{help::TestHelpCmd, IggyCmdTest, USAGE_PREFIX};
+use serial_test::parallel;
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["topic-template", "help"],
+            format!(
+                r#"topic template operations
+
+{USAGE_PREFIX} topic-template <COMMAND>
+
+Commands:
+  create  Create topic template with given name, number of partitions, compression algorithm and expiry time [aliases: c]
+  delete  Delete topic template with given name [aliases: d]
+  get     Get topic template with given name [aliases: g]
+  list    List all topic templates [aliases: l]
+  help    Print this message or the help of the given subcommand(s)
+
+Options:
+  -h, --help  Print help
+"#,
+            ),
+        ))
+        .await;
+}
@@ -127,7 +127,10 @@ impl IggyCmdTestCase for TestUserGetCmd {
                 user_id
             )))
             .stdout(is_match(format!("| Username[ ]+| {}", self.username)).unwrap())
-            .stdout(is_match(format!("| Status[ ]+| {}", self.status)).unwrap());
+            .stdout(is_match(format!("| Status[ ]+| {}", self.status)).unwrap())
+            .stdout(is_match("| Streams[ ]+| 0 / unlimited").unwrap())
+            .stdout(is_match("| Topics[ ]+| 0 / unlimited").unwrap())
+            .stdout(is_match("| Partitions[ ]+| 0 / unlimited").unwrap());
 
         // Check global permissions
         let assert = if self.check_global_perms {
@@ -0,0 +1,170 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestUserId, CLAP_INDENT,
+    USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::error::IggyError;
+use iggy::models::user_status::UserStatus;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use predicates::str::diff;
+use serial_test::parallel;
+
+const FAILED_LOGIN_ATTEMPTS: usize = 5;
+
+struct TestUserUnlockCmd {
+    username: String,
+    password: String,
+    user_id: u32,
+    using_identifier: TestUserId,
+}
+
+impl TestUserUnlockCmd {
+    fn new(username: String, password: String, user_id: u32, using_identifier: TestUserId) -> Self {
+        Self {
+            username,
+            password,
+            user_id,
+            using_identifier,
+        }
+    }
+
+    fn to_arg(&self) -> String {
+        match self.using_identifier {
+            TestUserId::Named => self.username.clone(),
+            TestUserId::Numeric => format!("{}", self.user_id),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserUnlockCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let user = client
+            .create_user(&self.username, &self.password, UserStatus::Active, None)
+            .await;
+        assert!(user.is_ok());
+
+        for _ in 0..FAILED_LOGIN_ATTEMPTS {
+            let login_user = client.login_user(&self.username, "invalid_password").await;
+            assert!(login_user.is_err());
+        }
+
+        let login_user = client.login_user(&self.username, &self.password).await;
+        assert!(matches!(
+            login_user,
+            Err(IggyError::TooManyFailedLoginAttempts(_))
+        ));
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("unlock")
+            .arg(self.to_arg())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let identifier = self.to_arg();
+        let message = format!(
+            "Executing unlock user with ID: {identifier}\nUser with ID: {identifier} unlocked\n"
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let login_user = client.login_user(&self.username, &self.password).await;
+        assert!(login_user.is_ok());
+        let login_user = client
+            .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+            .await;
+        assert!(login_user.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserUnlockCmd::new(
+            String::from("username"),
+            String::from("password"),
+            2,
+            TestUserId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserUnlockCmd::new(
+            String::from("testuser"),
+            String::from("testpass"),
+            3,
+            TestUserId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "unlock", "--help"],
+            format!(
+                r"Unlock user with given ID
+
+Clears the temporary lockout applied to the user after
+too many failed login attempts.
+The user ID can be specified as either a username or an ID
+
+Examples:
+ iggy user unlock 2
+ iggy user unlock testuser
+
+{USAGE_PREFIX} user unlock <USER_ID>
+
+Arguments:
+  <USER_ID>
+          User ID to unlock
+{CLAP_INDENT}
+          The user ID can be specified as either a username or an ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+",
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "unlock", "-h"],
+            format!(
+                r#"Unlock user with given ID
+
+{USAGE_PREFIX} user unlock <USER_ID>
+
+Arguments:
+  <USER_ID>  User ID to unlock
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
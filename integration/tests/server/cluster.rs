@@ -0,0 +1,234 @@
+use iggy::client::{StreamClient, TopicClient, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::http::client::HttpClient;
+use iggy::http::config::HttpClientConfig;
+use iggy::http::HttpTransport;
+use iggy::identifier::Identifier;
+use iggy::models::stream::StreamDetails;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{create_user, login_root, ClientFactory, IpAddrKind, TestServer};
+use serial_test::parallel;
+use server::cluster::ClusterInfo;
+use std::collections::HashMap;
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+const NODES_COUNT: u32 = 3;
+const STREAMS_COUNT: u32 = 3;
+const PARTITIONS_COUNT: u32 = 2;
+const USERNAME: &str = "cluster-user";
+const LEADER_ELECTION_TIMEOUT: Duration = Duration::from_secs(30);
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+#[tokio::test]
+#[parallel]
+async fn committed_state_should_be_preserved_after_leader_failure() {
+    let mut servers = start_cluster();
+
+    // 1. Wait until the leader is elected and change the state through it
+    let leader_id = wait_for_leader(&servers, None).await;
+    let leader = create_client(&servers[node_index(leader_id)]).await;
+    login_root(&leader).await;
+    for stream_id in 1..=STREAMS_COUNT {
+        leader
+            .create_stream(&stream_name(stream_id), Some(stream_id))
+            .await
+            .unwrap();
+        leader
+            .create_topic(
+                &Identifier::numeric(stream_id).unwrap(),
+                &topic_name(stream_id),
+                PARTITIONS_COUNT,
+                CompressionAlgorithm::None,
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+            .unwrap();
+    }
+    create_user(&leader, USERNAME).await;
+
+    // 2. Ensure that the follower rejects the commands changing the state
+    let follower_id = (1..=NODES_COUNT).find(|id| *id != leader_id).unwrap();
+    let follower = create_client(&servers[node_index(follower_id)]).await;
+    login_root(&follower).await;
+    let result = follower.create_stream("rejected", None).await;
+    assert!(matches!(
+        result,
+        Err(IggyError::NotClusterLeader(id)) if id == leader_id
+    ));
+
+    // 3. Kill the leader and wait until one of the remaining nodes is elected
+    servers[node_index(leader_id)].kill();
+    let new_leader_id = wait_for_leader(&servers, Some(leader_id)).await;
+    assert_ne!(new_leader_id, leader_id);
+
+    // 4. Ensure that none of the committed changes has been lost
+    let new_leader = create_client(&servers[node_index(new_leader_id)]).await;
+    login_root(&new_leader).await;
+    assert_committed_state(&new_leader).await;
+
+    // 5. Ensure that the new leader accepts the commands changing the state and replicates them
+    let stream_id = STREAMS_COUNT + 1;
+    new_leader
+        .create_stream(&stream_name(stream_id), Some(stream_id))
+        .await
+        .unwrap();
+    let follower_id = (1..=NODES_COUNT)
+        .find(|id| *id != leader_id && *id != new_leader_id)
+        .unwrap();
+    let follower = create_client(&servers[node_index(follower_id)]).await;
+    login_root(&follower).await;
+    assert_committed_state(&follower).await;
+    wait_for_stream(&follower, stream_id).await;
+}
+
+fn start_cluster() -> Vec<TestServer> {
+    let addresses = (1..=NODES_COUNT)
+        .map(|_| get_free_address())
+        .collect::<Vec<_>>();
+    let nodes = addresses
+        .iter()
+        .enumerate()
+        .map(|(index, address)| format!("{}={address}", index + 1))
+        .collect::<Vec<_>>()
+        .join(",");
+    addresses
+        .iter()
+        .enumerate()
+        .map(|(index, address)| {
+            let envs = HashMap::from([
+                ("IGGY_CLUSTER_ENABLED".to_string(), "true".to_string()),
+                ("IGGY_CLUSTER_NODE_ID".to_string(), (index + 1).to_string()),
+                ("IGGY_CLUSTER_ADDRESS".to_string(), address.clone()),
+                ("IGGY_CLUSTER_NODES".to_string(), format!("[{nodes}]")),
+                // Hashing the passwords in the debug build could otherwise delay the heartbeats long enough
+                // to trigger the election, when the nodes are running on the same machine.
+                (
+                    "IGGY_CLUSTER_ELECTION_TIMEOUT".to_string(),
+                    "1s".to_string(),
+                ),
+                (
+                    "IGGY_CLUSTER_HEARTBEAT_INTERVAL".to_string(),
+                    "100ms".to_string(),
+                ),
+                (
+                    "IGGY_SYSTEM_PASSWORD_HASHING_BCRYPT_COST".to_string(),
+                    "4".to_string(),
+                ),
+            ]);
+            let mut server = TestServer::new(Some(envs), true, None, IpAddrKind::V4);
+            server.start();
+            server
+        })
+        .collect()
+}
+
+fn get_free_address() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.local_addr().unwrap().to_string()
+}
+
+fn node_index(node_id: u32) -> usize {
+    (node_id - 1) as usize
+}
+
+fn stream_name(stream_id: u32) -> String {
+    format!("stream-{stream_id}")
+}
+
+fn topic_name(stream_id: u32) -> String {
+    format!("topic-{stream_id}")
+}
+
+async fn create_client(server: &TestServer) -> IggyClient {
+    let client_factory = TcpClientFactory {
+        server_addr: server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+    IggyClient::create(client_factory.create_client().await, None, None)
+}
+
+/// Waits until the node other than the excluded one reports itself as the leader.
+async fn wait_for_leader(servers: &[TestServer], excluded_id: Option<u32>) -> u32 {
+    let deadline = Instant::now() + LEADER_ELECTION_TIMEOUT;
+    while Instant::now() < deadline {
+        for server in servers.iter().filter(|server| server.is_started()) {
+            let Some(info) = get_cluster_info(server).await else {
+                continue;
+            };
+            if info.leader_id == Some(info.node_id) && Some(info.node_id) != excluded_id {
+                return info.node_id;
+            }
+        }
+        sleep(POLL_INTERVAL).await;
+    }
+    panic!("The cluster leader has not been elected within {LEADER_ELECTION_TIMEOUT:?}.");
+}
+
+async fn get_cluster_info(server: &TestServer) -> Option<ClusterInfo> {
+    let config = HttpClientConfig {
+        api_url: format!("http://{}", server.get_http_api_addr().unwrap()),
+        ..HttpClientConfig::default()
+    };
+    let client = HttpClient::create(Arc::new(config)).unwrap();
+    client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .ok()?;
+    let response = client.get("/cluster").await.ok()?;
+    response.json().await.ok()
+}
+
+async fn assert_committed_state(client: &IggyClient) {
+    for stream_id in 1..=STREAMS_COUNT {
+        let stream = wait_for_stream(client, stream_id).await;
+        assert_eq!(stream.name, stream_name(stream_id));
+        assert_eq!(stream.topics_count, 1);
+        let topic = client
+            .get_topic(
+                &Identifier::numeric(stream_id).unwrap(),
+                &Identifier::named(&topic_name(stream_id)).unwrap(),
+            )
+            .await
+            .unwrap()
+            .expect("Failed to get the committed topic");
+        assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
+    }
+
+    let user = client
+        .get_user(&Identifier::named(USERNAME).unwrap())
+        .await
+        .unwrap()
+        .expect("Failed to get the committed user");
+    assert_eq!(user.username, USERNAME);
+}
+
+/// Waits until the stream is applied by the node, as the followers apply the committed entries asynchronously.
+async fn wait_for_stream(client: &IggyClient, stream_id: u32) -> StreamDetails {
+    let deadline = Instant::now() + LEADER_ELECTION_TIMEOUT;
+    loop {
+        if let Some(stream) = client
+            .get_stream(&Identifier::numeric(stream_id).unwrap())
+            .await
+            .unwrap()
+        {
+            return stream;
+        }
+
+        assert!(
+            Instant::now() < deadline,
+            "The stream with ID: {stream_id} has not been applied within {LEADER_ELECTION_TIMEOUT:?}."
+        );
+        sleep(POLL_INTERVAL).await;
+    }
+}
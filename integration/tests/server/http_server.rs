@@ -1,8 +1,13 @@
 use crate::server::scenarios::{
-    create_message_payload, stream_size_validation_scenario, system_scenario, user_scenario,
+    auto_create_scenario, create_message_payload, message_key_scenario, schemas_scenario,
+    stream_size_validation_scenario, system_scenario, topic_templates_scenario, user_scenario,
+};
+use integration::{
+    http_client::HttpClientFactory,
+    test_server::{IpAddrKind, TestServer},
 };
-use integration::{http_client::HttpClientFactory, test_server::TestServer};
 use serial_test::parallel;
+use std::collections::HashMap;
 
 #[tokio::test]
 #[parallel]
@@ -14,6 +19,16 @@ async fn create_message_payload_scenario_should_be_valid() {
     create_message_payload::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn message_key_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    message_key_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn message_headers_scenario_should_be_valid() {
@@ -53,3 +68,47 @@ async fn user_scenario_should_be_valid() {
     let client_factory = HttpClientFactory { server_addr };
     user_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn schemas_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    schemas_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn topic_templates_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    topic_templates_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn auto_create_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([
+        (
+            "IGGY_SYSTEM_AUTO_CREATE_ENABLED".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_AUTO_CREATE_STREAMS_PATTERN".to_string(),
+            auto_create_scenario::STREAMS_PATTERN.to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_AUTO_CREATE_PARTITIONS_COUNT".to_string(),
+            auto_create_scenario::PARTITIONS_COUNT.to_string(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    auto_create_scenario::run(&client_factory).await;
+}
@@ -0,0 +1,160 @@
+use crate::server::scenarios::{create_client, USERNAME_1};
+use ahash::AHashMap;
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient, UserClient};
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::permissions::{Permissions, StreamPermissions};
+use iggy::models::user_status::UserStatus;
+use integration::test_server::{assert_clean_system, delete_user, login_root, ClientFactory};
+
+/// The server is expected to be started with the auto create enabled on send, for the streams matching this pattern.
+pub const STREAMS_PATTERN: &str = "auto-*";
+/// The server is expected to create the topics with this number of partitions.
+pub const PARTITIONS_COUNT: u32 = 2;
+
+const STREAM_NAME: &str = "auto-stream";
+const TOPIC_NAME: &str = "auto-topic";
+const OTHER_TOPIC_NAME: &str = "other-topic";
+const USER_PASSWORD: &str = "secret";
+const MESSAGES_COUNT: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+
+    // 1. Ensure that the stream and topic are created when the messages are sent
+    send_messages(&client, &stream_id, &topic_id).await.unwrap();
+    let stream = client
+        .get_stream(&stream_id)
+        .await
+        .unwrap()
+        .expect("Failed to get auto created stream");
+    assert_eq!(stream.topics_count, 1);
+    let topic = client
+        .get_topic(&stream_id, &topic_id)
+        .await
+        .unwrap()
+        .expect("Failed to get auto created topic");
+    assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
+    assert_eq!(topic.messages_count, MESSAGES_COUNT as u64);
+
+    // 2. Ensure that the messages can be polled from the auto created topic
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(1),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+
+    // 3. Ensure that the stream which doesn't match the pattern is not created
+    let other_stream_id = Identifier::named("other-stream").unwrap();
+    assert!(send_messages(&client, &other_stream_id, &topic_id)
+        .await
+        .is_err());
+    assert!(client.get_stream(&other_stream_id).await.unwrap().is_none());
+
+    // 4. Ensure that the resources referenced by the numeric ID are not created
+    let numeric_topic_id = Identifier::numeric(100).unwrap();
+    assert!(send_messages(&client, &stream_id, &numeric_topic_id)
+        .await
+        .is_err());
+
+    // 5. Ensure that the topic is not created when the messages are polled, as it's enabled only on send
+    let other_topic_id = Identifier::named(OTHER_TOPIC_NAME).unwrap();
+    let poll_result = client
+        .poll_messages(
+            &stream_id,
+            &other_topic_id,
+            Some(1),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            1,
+            false,
+        )
+        .await;
+    assert!(poll_result.is_err());
+    assert!(client
+        .get_topic(&stream_id, &other_topic_id)
+        .await
+        .unwrap()
+        .is_none());
+
+    // 6. Create the user allowed to read the auto created stream and send the messages to it only
+    client
+        .create_user(
+            USERNAME_1,
+            USER_PASSWORD,
+            UserStatus::Active,
+            Some(Permissions {
+                global: Default::default(),
+                streams: Some(AHashMap::from([(
+                    stream.id,
+                    StreamPermissions {
+                        read_stream: true,
+                        read_topics: true,
+                        send_messages: true,
+                        ..Default::default()
+                    },
+                )])),
+            }),
+        )
+        .await
+        .unwrap();
+    let user_client = create_client(client_factory).await;
+    user_client
+        .login_user(USERNAME_1, USER_PASSWORD)
+        .await
+        .unwrap();
+
+    // 7. Ensure that the user can create the topic in the stream, but not the new stream
+    send_messages(&user_client, &stream_id, &other_topic_id)
+        .await
+        .unwrap();
+    let topic = client
+        .get_topic(&stream_id, &other_topic_id)
+        .await
+        .unwrap()
+        .expect("Failed to get topic auto created by user");
+    assert_eq!(topic.messages_count, MESSAGES_COUNT as u64);
+
+    let new_stream_id = Identifier::named("auto-new-stream").unwrap();
+    assert!(send_messages(&user_client, &new_stream_id, &topic_id)
+        .await
+        .is_err());
+    assert!(client.get_stream(&new_stream_id).await.unwrap().is_none());
+
+    delete_user(&client, USERNAME_1).await;
+    client.delete_stream(&stream_id).await.unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn send_messages(
+    client: &dyn MessageClient,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+) -> Result<(), IggyError> {
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|id| Message::new(None, Bytes::from(format!("message-{id}")), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            stream_id,
+            topic_id,
+            &Partitioning::partition_id(1),
+            &mut messages,
+        )
+        .await
+}
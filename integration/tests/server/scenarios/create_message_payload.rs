@@ -37,6 +37,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             length: payload.len() as u32,
             payload,
             headers: Some(headers),
+            key: None,
         });
     }
 
@@ -0,0 +1,130 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const MESSAGES_PER_BATCH: u32 = 3;
+const FIRST_KEY: &str = "customer-1";
+const SECOND_KEY: &str = "customer-2";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the messages using the messages key, the partition ID, the messages key again,
+    // and finally the messages having their own keys, as only these keys are stored with the messages
+    send_messages(
+        &client,
+        Partitioning::messages_key_str(FIRST_KEY).unwrap(),
+        false,
+    )
+    .await;
+    send_messages(&client, Partitioning::partition_id(PARTITION_ID), false).await;
+    send_messages(
+        &client,
+        Partitioning::messages_key_str(SECOND_KEY).unwrap(),
+        false,
+    )
+    .await;
+    send_messages(
+        &client,
+        Partitioning::messages_key_str(SECOND_KEY).unwrap(),
+        true,
+    )
+    .await;
+
+    // 2. Poll the messages and ensure that each of them has the expected key assigned
+    let polled_messages = client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            4 * MESSAGES_PER_BATCH,
+            false,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        polled_messages.messages.len() as u32,
+        4 * MESSAGES_PER_BATCH
+    );
+    for (index, message) in polled_messages.messages.iter().enumerate() {
+        let expected_key = match index as u32 / MESSAGES_PER_BATCH {
+            3 => Some(create_message_key(index as u32)),
+            _ => None,
+        };
+        assert_eq!(message.offset, index as u64);
+        assert_eq!(message.key, expected_key);
+        assert_eq!(message.payload, create_message_payload(index as u32));
+    }
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
+
+async fn send_messages(client: &IggyClient, partitioning: Partitioning, with_own_keys: bool) {
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let sent_messages_count = client
+        .get_topic(&stream_id, &topic_id)
+        .await
+        .unwrap()
+        .expect("Failed to get topic")
+        .messages_count as u32;
+    let mut messages = (0..MESSAGES_PER_BATCH)
+        .map(|index| {
+            let index = sent_messages_count + index;
+            let message = Message::new(None, create_message_payload(index), None);
+            match with_own_keys {
+                true => message.with_key(create_message_key(index)),
+                false => message,
+            }
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(&stream_id, &topic_id, &partitioning, &mut messages)
+        .await
+        .unwrap();
+}
+
+fn create_message_payload(index: u32) -> Bytes {
+    Bytes::from(format!("message-{index}"))
+}
+
+fn create_message_key(index: u32) -> Bytes {
+    Bytes::from(format!("key-{index}"))
+}
@@ -212,5 +212,6 @@ fn create_message(header_size: Option<usize>, payload_size: usize) -> Message {
         length: payload.len() as u32,
         payload: Bytes::from(payload),
         headers,
+        key: None,
     }
 }
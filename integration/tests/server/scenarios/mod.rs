@@ -5,14 +5,19 @@ use iggy::identifier::Identifier;
 use iggy::models::consumer_group::ConsumerGroupDetails;
 use integration::test_server::{delete_user, ClientFactory};
 
+pub mod auto_create_scenario;
 pub mod consumer_group_join_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
 pub mod create_message_payload;
 pub mod message_headers_scenario;
+pub mod message_key_scenario;
 pub mod message_size_scenario;
+pub mod partitions_merge_scenario;
+pub mod schemas_scenario;
 pub mod stream_size_validation_scenario;
 pub mod system_scenario;
+pub mod topic_templates_scenario;
 pub mod user_scenario;
 
 const STREAM_ID: u32 = 1;
@@ -0,0 +1,215 @@
+use crate::server::scenarios::{
+    cleanup, create_client, CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME, PARTITIONS_COUNT, STREAM_ID,
+    STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{
+    ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient, StreamClient,
+    TopicClient,
+};
+use iggy::clients::client::IggyClient;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::partitions_merge::{PartitionsMergeInfo, PartitionsMergeStatus};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::time::Duration;
+use tokio::time::sleep;
+
+const MESSAGES_PER_PARTITION: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let consumer = Consumer::group(Identifier::numeric(CONSUMER_GROUP_ID).unwrap());
+
+    // 1. Send messages to the first and the last partition
+    send_messages(&client, 1).await;
+    send_messages(&client, PARTITIONS_COUNT).await;
+
+    // 2. Store the consumer group offsets, the first partition is consumed completely, the last one partially
+    client
+        .store_consumer_offset(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            Some(1),
+            (MESSAGES_PER_PARTITION - 1) as u64,
+        )
+        .await
+        .unwrap();
+    client
+        .store_consumer_offset(&consumer, &stream_id, &topic_id, Some(PARTITIONS_COUNT), 4)
+        .await
+        .unwrap();
+
+    // 3. Ensure that the merge has to leave at least one partition
+    let merge_result = client
+        .merge_partitions(&stream_id, &topic_id, PARTITIONS_COUNT)
+        .await;
+    assert!(merge_result.is_err());
+
+    // 4. Merge the last partition and wait until its messages are moved
+    let partitions_merge = client
+        .merge_partitions(&stream_id, &topic_id, 1)
+        .await
+        .unwrap();
+    assert_eq!(partitions_merge.stream_id, STREAM_ID);
+    assert_eq!(partitions_merge.topic_id, TOPIC_ID);
+    assert_eq!(partitions_merge.partitions_count, 1);
+    assert_eq!(
+        partitions_merge.messages_count,
+        MESSAGES_PER_PARTITION as u64
+    );
+
+    let partitions_merge = wait_for_partitions_merge(&client).await;
+    assert_eq!(partitions_merge.status, PartitionsMergeStatus::Completed);
+    assert_eq!(partitions_merge.merged_partitions, 1);
+    assert_eq!(
+        partitions_merge.merged_messages,
+        MESSAGES_PER_PARTITION as u64
+    );
+    assert!(partitions_merge.finished_at.is_some());
+
+    // 5. Ensure that the messages of the merged partition were appended to the first partition
+    let topic = client
+        .get_topic(&stream_id, &topic_id)
+        .await
+        .unwrap()
+        .expect("Failed to get topic");
+    assert_eq!(topic.partitions_count, PARTITIONS_COUNT - 1);
+    assert_eq!(topic.messages_count, 2 * MESSAGES_PER_PARTITION as u64);
+
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(1),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            2 * MESSAGES_PER_PARTITION,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        polled_messages.messages.len() as u32,
+        2 * MESSAGES_PER_PARTITION
+    );
+    for (offset, message) in polled_messages.messages.iter().enumerate() {
+        let partition_id = if offset < MESSAGES_PER_PARTITION as usize {
+            1
+        } else {
+            PARTITIONS_COUNT
+        };
+        let index = offset as u32 % MESSAGES_PER_PARTITION;
+        assert_eq!(message.offset, offset as u64);
+        assert_eq!(message.id, get_message_id(partition_id, index));
+        assert_eq!(message.payload, create_payload(partition_id, index));
+    }
+
+    // 6. Ensure that the consumer group continues from the first not consumed message of the merged partition
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(1),
+            &consumer,
+            &PollingStrategy::next(),
+            1,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    let message = &polled_messages.messages[0];
+    assert_eq!(message.offset, (MESSAGES_PER_PARTITION + 5) as u64);
+    assert_eq!(message.id, get_message_id(PARTITIONS_COUNT, 5));
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            Default::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+    client
+        .create_consumer_group(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+        )
+        .await
+        .unwrap();
+}
+
+async fn send_messages(client: &IggyClient, partition_id: u32) {
+    let mut messages = (0..MESSAGES_PER_PARTITION)
+        .map(|index| {
+            Message::new(
+                Some(get_message_id(partition_id, index)),
+                create_payload(partition_id, index),
+                None,
+            )
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn wait_for_partitions_merge(client: &IggyClient) -> PartitionsMergeInfo {
+    for _ in 0..100 {
+        let partitions_merge = client
+            .get_partitions_merge(
+                &Identifier::numeric(STREAM_ID).unwrap(),
+                &Identifier::numeric(TOPIC_ID).unwrap(),
+            )
+            .await
+            .unwrap()
+            .expect("Failed to get partitions merge");
+        if partitions_merge.status != PartitionsMergeStatus::InProgress {
+            return partitions_merge;
+        }
+
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    panic!("Partitions merge was not finished in time");
+}
+
+fn get_message_id(partition_id: u32, index: u32) -> u128 {
+    (partition_id * 1000 + index + 1) as u128
+}
+
+fn create_payload(partition_id: u32, index: u32) -> Bytes {
+    Bytes::from(format!("message {index} from partition {partition_id}"))
+}
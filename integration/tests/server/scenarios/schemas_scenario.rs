@@ -0,0 +1,259 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{MessageClient, SchemaClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::schema::{schema_id_header, SchemaCompatibility, SchemaType};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+
+const ORDER_SCHEMA_V1: &str = r#"{
+    "type": "object",
+    "properties": {
+        "id": {"type": "integer"},
+        "status": {"type": "string"}
+    },
+    "required": ["id"]
+}"#;
+const ORDER_SCHEMA_V2: &str = r#"{
+    "type": "object",
+    "properties": {
+        "id": {"type": "integer"},
+        "status": {"type": "string"},
+        "note": {"type": "string"}
+    },
+    "required": ["id"]
+}"#;
+const ORDER_SCHEMA_WITH_REQUIRED_CUSTOMER: &str = r#"{
+    "type": "object",
+    "properties": {
+        "id": {"type": "integer"},
+        "customer": {"type": "string"}
+    },
+    "required": ["id", "customer"]
+}"#;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Ensure that the topic has no schemas and the payloads are not validated by default
+    let topic_schemas = client
+        .get_topic_schemas(&stream_id, &topic_id)
+        .await
+        .unwrap();
+    assert!(topic_schemas.schemas.is_empty());
+    assert_eq!(topic_schemas.compatibility, SchemaCompatibility::Backward);
+    assert!(!topic_schemas.validate_payloads);
+    let schema = client
+        .get_topic_schema(&stream_id, &topic_id, None)
+        .await
+        .unwrap();
+    assert!(schema.is_none());
+
+    // 2. Ensure that the invalid schema cannot be registered
+    let register_result = client
+        .register_topic_schema(&stream_id, &topic_id, SchemaType::JsonSchema, "{")
+        .await;
+    assert!(register_result.is_err());
+
+    // 3. Register the first version and ensure that registering the same definition returns it again
+    let schema_v1 = client
+        .register_topic_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::JsonSchema,
+            ORDER_SCHEMA_V1,
+        )
+        .await
+        .unwrap();
+    assert_eq!(schema_v1.version, 1);
+    assert_eq!(schema_v1.stream_id, STREAM_ID);
+    assert_eq!(schema_v1.topic_id, TOPIC_ID);
+    assert_eq!(schema_v1.schema_type, SchemaType::JsonSchema);
+    let same_schema = client
+        .register_topic_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::JsonSchema,
+            ORDER_SCHEMA_V1,
+        )
+        .await
+        .unwrap();
+    assert_eq!(same_schema, schema_v1);
+
+    // 4. Register the backward compatible version, while the incompatible one is rejected
+    let schema_v2 = client
+        .register_topic_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::JsonSchema,
+            ORDER_SCHEMA_V2,
+        )
+        .await
+        .unwrap();
+    assert_eq!(schema_v2.version, 2);
+    assert_ne!(schema_v2.id, schema_v1.id);
+
+    let register_result = client
+        .register_topic_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::JsonSchema,
+            ORDER_SCHEMA_WITH_REQUIRED_CUSTOMER,
+        )
+        .await;
+    assert!(register_result.is_err());
+
+    // 5. Get the schemas by ID, by version and the latest one
+    let schema = client
+        .get_schema(schema_v1.id)
+        .await
+        .unwrap()
+        .expect("Failed to get schema");
+    assert_eq!(schema, schema_v1);
+    let schema = client
+        .get_topic_schema(&stream_id, &topic_id, Some(1))
+        .await
+        .unwrap()
+        .expect("Failed to get topic schema");
+    assert_eq!(schema, schema_v1);
+    let schema = client
+        .get_topic_schema(&stream_id, &topic_id, None)
+        .await
+        .unwrap()
+        .expect("Failed to get latest topic schema");
+    assert_eq!(schema, schema_v2);
+    let topic_schemas = client
+        .get_topic_schemas(&stream_id, &topic_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        topic_schemas.schemas,
+        vec![schema_v1.clone(), schema_v2.clone()]
+    );
+
+    // 6. Enable the payload validation, the messages must reference the registered schema and be valid
+    client
+        .update_topic_schema_settings(&stream_id, &topic_id, SchemaCompatibility::Full, true)
+        .await
+        .unwrap();
+    let topic_schemas = client
+        .get_topic_schemas(&stream_id, &topic_id)
+        .await
+        .unwrap();
+    assert_eq!(topic_schemas.compatibility, SchemaCompatibility::Full);
+    assert!(topic_schemas.validate_payloads);
+
+    send_message(&client, r#"{"id": 1, "status": "new"}"#, Some(schema_v1.id))
+        .await
+        .unwrap();
+    send_message(&client, r#"{"id": 2, "note": "gift"}"#, Some(schema_v2.id))
+        .await
+        .unwrap();
+    assert!(
+        send_message(&client, r#"{"status": "new"}"#, Some(schema_v1.id))
+            .await
+            .is_err()
+    );
+    assert!(send_message(&client, "not json", Some(schema_v1.id))
+        .await
+        .is_err());
+    assert!(send_message(&client, r#"{"id": 3}"#, None).await.is_err());
+    assert!(
+        send_message(&client, r#"{"id": 3}"#, Some(schema_v2.id + 100))
+            .await
+            .is_err()
+    );
+
+    // 7. Delete the first version, the messages referencing it are rejected and the version is not reused
+    client
+        .delete_topic_schema(&stream_id, &topic_id, 1)
+        .await
+        .unwrap();
+    let delete_result = client.delete_topic_schema(&stream_id, &topic_id, 1).await;
+    assert!(delete_result.is_err());
+    let schema = client
+        .get_topic_schema(&stream_id, &topic_id, Some(1))
+        .await
+        .unwrap();
+    assert!(schema.is_none());
+    let schema = client.get_schema(schema_v1.id).await.unwrap();
+    assert!(schema.is_none());
+    assert!(send_message(&client, r#"{"id": 4}"#, Some(schema_v1.id))
+        .await
+        .is_err());
+
+    // 8. Disable the payload validation, any message can be sent again
+    client
+        .update_topic_schema_settings(&stream_id, &topic_id, SchemaCompatibility::None, false)
+        .await
+        .unwrap();
+    send_message(&client, "not json", None).await.unwrap();
+    let schema_v3 = client
+        .register_topic_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::JsonSchema,
+            ORDER_SCHEMA_WITH_REQUIRED_CUSTOMER,
+        )
+        .await
+        .unwrap();
+    assert_eq!(schema_v3.version, 3);
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
+
+async fn send_message(
+    client: &IggyClient,
+    payload: &str,
+    schema_id: Option<u32>,
+) -> Result<(), iggy::error::IggyError> {
+    let headers = schema_id.map(|schema_id| {
+        let (key, value) = schema_id_header(schema_id).unwrap();
+        HashMap::from([(key, value)])
+    });
+    let mut messages = vec![Message::new(
+        None,
+        Bytes::from(payload.to_string()),
+        headers,
+    )];
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+}
@@ -280,6 +280,7 @@ fn create_messages() -> Vec<Message> {
             length: payload.len() as u32,
             payload,
             headers: None,
+            key: None,
         };
         messages.push(message);
     }
@@ -0,0 +1,123 @@
+use crate::server::scenarios::{cleanup, create_client, STREAM_ID, STREAM_NAME};
+use iggy::client::{StreamClient, TopicClient, TopicTemplateClient};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::models::topic_template::{TopicDefaults, TopicTemplate};
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const TEMPLATE_NAME: &str = "sensors";
+const TEMPLATE_PARTITIONS_COUNT: u32 = 2;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let template_expiry = IggyExpiry::ExpireDuration(IggyDuration::from_str("1day").unwrap());
+    let stream_expiry = IggyExpiry::ExpireDuration(IggyDuration::from_str("2days").unwrap());
+    let stream_max_topic_size = MaxTopicSize::Custom(IggyByteSize::from_str("10GB").unwrap());
+
+    // 1. Create the topic template, which leaves the max topic size at the server default
+    let template = TopicTemplate {
+        name: TEMPLATE_NAME.to_string(),
+        partitions_count: TEMPLATE_PARTITIONS_COUNT,
+        compression_algorithm: CompressionAlgorithm::Gzip,
+        message_expiry: template_expiry,
+        max_topic_size: MaxTopicSize::ServerDefault,
+        replication_factor: None,
+    };
+    client
+        .create_topic_template(template.clone())
+        .await
+        .unwrap();
+
+    // 2. Ensure that the template with the same name cannot be created again
+    let create_result = client.create_topic_template(template.clone()).await;
+    assert!(create_result.is_err());
+
+    // 3. Get the template by name and the list of all the templates
+    let stored_template = client
+        .get_topic_template(TEMPLATE_NAME)
+        .await
+        .unwrap()
+        .expect("Failed to get topic template");
+    assert_eq!(stored_template, template);
+
+    let templates = client.get_topic_templates().await.unwrap();
+    assert_eq!(templates, vec![template]);
+
+    // 4. Set the default topic settings of the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    let topic_defaults = client.get_stream_topic_defaults(&stream_id).await.unwrap();
+    assert_eq!(topic_defaults, TopicDefaults::default());
+
+    let topic_defaults = TopicDefaults {
+        message_expiry: stream_expiry,
+        max_topic_size: stream_max_topic_size,
+        replication_factor: None,
+    };
+    client
+        .update_stream_topic_defaults(&stream_id, topic_defaults)
+        .await
+        .unwrap();
+    let stored_topic_defaults = client.get_stream_topic_defaults(&stream_id).await.unwrap();
+    assert_eq!(stored_topic_defaults, topic_defaults);
+
+    // 5. Create the topic from the template, the settings missing in the template are taken from the stream
+    let topic = client
+        .create_topic_from_template(&stream_id, "from-template", TEMPLATE_NAME, None)
+        .await
+        .unwrap();
+    assert_eq!(topic.partitions_count, TEMPLATE_PARTITIONS_COUNT);
+    assert_eq!(topic.compression_algorithm, CompressionAlgorithm::Gzip);
+    assert_eq!(topic.message_expiry, template_expiry);
+    assert_eq!(topic.max_topic_size, stream_max_topic_size);
+
+    // 6. Create the topic without the template, the server defaults are replaced with the stream defaults
+    let topic = client
+        .create_topic(
+            &stream_id,
+            "from-stream-defaults",
+            1,
+            CompressionAlgorithm::None,
+            None,
+            None,
+            IggyExpiry::ServerDefault,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+    assert_eq!(topic.partitions_count, 1);
+    assert_eq!(topic.message_expiry, stream_expiry);
+    assert_eq!(topic.max_topic_size, stream_max_topic_size);
+
+    // 7. Ensure that the topic cannot be created from the template which does not exist
+    let create_result = client
+        .create_topic_from_template(&stream_id, "unknown-template", "unknown", None)
+        .await;
+    assert!(create_result.is_err());
+
+    // 8. Delete the template, the topics created from it are not affected
+    client.delete_topic_template(TEMPLATE_NAME).await.unwrap();
+    let template = client.get_topic_template(TEMPLATE_NAME).await.unwrap();
+    assert!(template.is_none());
+    let delete_result = client.delete_topic_template(TEMPLATE_NAME).await;
+    assert!(delete_result.is_err());
+
+    let topic = client
+        .get_topic(&stream_id, &Identifier::named("from-template").unwrap())
+        .await
+        .unwrap()
+        .expect("Failed to get topic");
+    assert_eq!(topic.partitions_count, TEMPLATE_PARTITIONS_COUNT);
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
@@ -1,5 +1,5 @@
 use crate::server::scenarios::create_client;
-use iggy::client::{PersonalAccessTokenClient, SystemClient, UserClient};
+use iggy::client::{PersonalAccessTokenClient, StreamClient, SystemClient, UserClient};
 use iggy::identifier::Identifier;
 use iggy::models::permissions::{GlobalPermissions, Permissions};
 use iggy::models::user_status::UserStatus;
@@ -125,6 +125,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .create_personal_access_token(
             pat_name1,
             PersonalAccessTokenExpiry::ExpireDuration((SEC_IN_MICRO * 3600).into()),
+            None,
         )
         .await
         .unwrap();
@@ -132,7 +133,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert!(!raw_pat1.token.is_empty());
 
     let raw_pat2 = client
-        .create_personal_access_token(pat_name2, PersonalAccessTokenExpiry::NeverExpire)
+        .create_personal_access_token(pat_name2, PersonalAccessTokenExpiry::NeverExpire, None)
         .await
         .unwrap();
 
@@ -175,17 +176,60 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let personal_access_tokens = client.get_personal_access_tokens().await.unwrap();
     assert!(personal_access_tokens.is_empty());
 
-    // 19. Login as root user again
+    // 19. Create the scoped personal access token allowed only to read the users
+    let scoped_pat_name = "scoped_token";
+    let raw_scoped_pat = client
+        .create_personal_access_token(
+            scoped_pat_name,
+            PersonalAccessTokenExpiry::NeverExpire,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_users: true,
+                    ..Default::default()
+                },
+                streams: None,
+            }),
+        )
+        .await
+        .unwrap();
+
+    let personal_access_tokens = client.get_personal_access_tokens().await.unwrap();
+    assert_eq!(personal_access_tokens.len(), 1);
+    assert!(personal_access_tokens[0].permissions.is_some());
+
+    // 20. Login with the scoped personal access token, only the permissions of the token should be granted
+    client
+        .login_with_personal_access_token(&raw_scoped_pat.token)
+        .await
+        .unwrap();
+
+    client.get_users().await.unwrap();
+    // The stats are available to every authenticated user, so the missing permission is checked by creating the stream.
+    assert!(client.create_stream("scoped-stream", None).await.is_err());
+    assert!(client
+        .create_personal_access_token(
+            "another_token",
+            PersonalAccessTokenExpiry::NeverExpire,
+            None
+        )
+        .await
+        .is_err());
+    assert!(client
+        .delete_personal_access_token(scoped_pat_name)
+        .await
+        .is_err());
+
+    // 21. Login as root user again
     login_root(&client).await;
 
-    // 20. Trying to create a new user with the same username should fail
+    // 22. Trying to create a new user with the same username should fail
     let create_duplicated_user = client
         .create_user(test_user, test_password, UserStatus::Active, None)
         .await;
 
     assert!(create_duplicated_user.is_err());
 
-    // 21. Update user details
+    // 23. Update user details
     let updated_test_user = "user2";
 
     client
@@ -197,7 +241,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
 
-    // 22. Update user permissions
+    // 24. Update user permissions
     client
         .update_permissions(
             &Identifier::named(updated_test_user).unwrap(),
@@ -220,13 +264,13 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
 
-    // 23. Deleting another user should be allowed
+    // 25. Deleting another user should be allowed
     client
         .delete_user(&Identifier::named(updated_test_user).unwrap())
         .await
         .unwrap();
 
-    // 24. Trying to delete the root user should fail
+    // 26. Trying to delete the root user should fail
     let delete_root_user = client
         .delete_user(&Identifier::named(DEFAULT_ROOT_USERNAME).unwrap())
         .await;
@@ -235,10 +279,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_clean_system(&client).await;
 
-    // 25. Logout
+    // 27. Logout
     client.logout_user().await.unwrap();
 
-    // 26. Trying to perform any secured operation after logout should fail
+    // 28. Trying to perform any secured operation after logout should fail
     let get_users = client.get_users().await;
     assert!(get_users.is_err());
 }
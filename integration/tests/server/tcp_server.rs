@@ -1,11 +1,17 @@
 use crate::server::scenarios::{
-    consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
+    auto_create_scenario, consumer_group_join_scenario,
+    consumer_group_with_multiple_clients_polling_messages_scenario,
     consumer_group_with_single_client_polling_messages_scenario, create_message_payload,
-    message_headers_scenario, message_size_scenario, stream_size_validation_scenario,
-    system_scenario, user_scenario,
+    message_headers_scenario, message_key_scenario, message_size_scenario,
+    partitions_merge_scenario, schemas_scenario, stream_size_validation_scenario, system_scenario,
+    topic_templates_scenario, user_scenario,
+};
+use integration::{
+    tcp_client::TcpClientFactory,
+    test_server::{IpAddrKind, TestServer},
 };
-use integration::{tcp_client::TcpClientFactory, test_server::TestServer};
 use serial_test::parallel;
+use std::collections::HashMap;
 
 #[tokio::test]
 #[parallel]
@@ -46,6 +52,53 @@ async fn message_headers_scenario_should_be_valid() {
     message_headers_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn message_headers_scenario_should_be_valid_with_zero_copy_polling() {
+    // The cache is disabled, so the messages are sent directly from the segment files.
+    let extra_envs =
+        HashMap::from([("IGGY_SYSTEM_CACHE_ENABLED".to_string(), "false".to_string())]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        zero_copy_polling: true,
+        ..Default::default()
+    };
+    message_headers_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_key_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    message_key_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_key_scenario_should_be_valid_with_zero_copy_polling() {
+    // The cache is disabled, so the messages are sent directly from the segment files.
+    let extra_envs =
+        HashMap::from([("IGGY_SYSTEM_CACHE_ENABLED".to_string(), "false".to_string())]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        zero_copy_polling: true,
+        ..Default::default()
+    };
+    message_key_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn create_message_payload_scenario_should_be_valid() {
@@ -123,3 +176,69 @@ async fn message_size_scenario_should_be_valid() {
     };
     message_size_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn partitions_merge_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    partitions_merge_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schemas_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    schemas_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn topic_templates_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    topic_templates_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn auto_create_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([
+        (
+            "IGGY_SYSTEM_AUTO_CREATE_ENABLED".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_AUTO_CREATE_STREAMS_PATTERN".to_string(),
+            auto_create_scenario::STREAMS_PATTERN.to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_AUTO_CREATE_PARTITIONS_COUNT".to_string(),
+            auto_create_scenario::PARTITIONS_COUNT.to_string(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    auto_create_scenario::run(&client_factory).await;
+}
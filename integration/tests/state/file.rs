@@ -121,6 +121,38 @@ async fn should_apply_multiple_entries() {
     );
 }
 
+#[tokio::test]
+async fn should_truncate_entries_starting_from_given_index() {
+    let setup = StateSetup::init().await;
+    let state = setup.state();
+    state.init().await.unwrap();
+
+    let user_id = 1;
+    let create_stream_bytes = create_stream_command(1).to_bytes();
+    for stream_id in 1..=3 {
+        state
+            .apply(user_id, create_stream_command(stream_id))
+            .await
+            .unwrap();
+    }
+
+    state.truncate(1).await.unwrap();
+
+    assert_eq!(state.current_index(), 0);
+    assert_eq!(state.entries_count(), 1);
+    let mut entries = state.load_entries().await.unwrap();
+    assert_eq!(entries.len(), 1);
+    let entry = entries.remove(0);
+    assert_entry(entry, 0, setup.version(), user_id, create_stream_bytes);
+}
+
+fn create_stream_command(stream_id: u32) -> EntryCommand {
+    EntryCommand::CreateStream(CreateStream {
+        stream_id: Some(stream_id),
+        name: format!("test-{stream_id}"),
+    })
+}
+
 fn assert_entry(entry: StateEntry, index: u64, version: u32, user_id: u32, command: Bytes) {
     assert_eq!(entry.index, index);
     assert_eq!(entry.term, 0);
@@ -50,6 +50,7 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic1".to_string(),
         replication_factor: None,
+        template: None,
     };
 
     let create_topic1_clone = CreateTopic {
@@ -61,6 +62,7 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic1".to_string(),
         replication_factor: None,
+        template: None,
     };
 
     let create_stream2 = CreateStream {
@@ -77,6 +79,7 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic2".to_string(),
         replication_factor: None,
+        template: None,
     };
 
     let create_partitions = CreatePartitions {
@@ -93,6 +96,7 @@ async fn should_be_initialized_based_on_state_entries() {
         command: CreatePersonalAccessToken {
             name: "test".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            permissions: None,
         },
         hash: "hash".to_string(),
     };
@@ -101,6 +105,7 @@ async fn should_be_initialized_based_on_state_entries() {
         command: CreatePersonalAccessToken {
             name: "test".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            permissions: None,
         },
         hash: "hash".to_string(),
     };
@@ -141,7 +146,7 @@ async fn should_be_initialized_based_on_state_entries() {
         .unwrap();
 
     let entries = state.load_entries().await.unwrap();
-    let mut system = SystemState::init(entries).await.unwrap();
+    let mut system = SystemState::init(entries, 0).await.unwrap();
 
     assert_eq!(system.users.len(), 1);
     let mut user = system.users.remove(&1).unwrap();
@@ -8,7 +8,9 @@ use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
 use server::configs::resource_quota::MemoryResourceQuota;
-use server::configs::system::{CacheConfig, PartitionConfig, SegmentConfig, SystemConfig};
+use server::configs::system::{
+    CacheConfig, IndexCacheMode, PartitionConfig, SegmentConfig, SystemConfig,
+};
 use server::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use server::streaming::partitions::partition::Partition;
 use std::collections::HashMap;
@@ -40,12 +42,16 @@ fn msg_cache_size(size: u64) -> Option<IggyByteSize> {
     Some(IggyByteSize::from_str(&format!("{}b", size)).unwrap())
 }
 
-fn index_cache_enabled() -> bool {
-    true
+fn index_cache_eager() -> IndexCacheMode {
+    IndexCacheMode::Eager
 }
 
-fn index_cache_disabled() -> bool {
-    false
+fn index_cache_mmap() -> IndexCacheMode {
+    IndexCacheMode::Mmap
+}
+
+fn index_cache_on_demand() -> IndexCacheMode {
+    IndexCacheMode::OnDemand
 }
 
 #[test_matrix(
@@ -53,14 +59,14 @@ fn index_cache_disabled() -> bool {
     [msgs_req_to_save(3), msgs_req_to_save(10),  msgs_req_to_save(1000)],
     [segment_size(500), segment_size(2000), segment_size(100000)],
     [msg_cache_size(0), msg_cache_size(5000), msg_cache_size(50000), msg_cache_size(2000000)],
-    [index_cache_disabled(), index_cache_enabled()])]
+    [index_cache_on_demand(), index_cache_mmap(), index_cache_eager()])]
 #[tokio::test]
 async fn test_get_messages_by_timestamp(
     message_size: IggyByteSize,
     messages_required_to_save: u32,
     segment_size: IggyByteSize,
     msg_cache_size: Option<IggyByteSize>,
-    index_cache_enabled: bool,
+    cache_indexes: IndexCacheMode,
 ) {
     println!(
         "Running test with msg_cache_enabled: {}, messages_required_to_save: {}, segment_size: {}, message_size: {}, cache_indexes: {}",
@@ -68,7 +74,7 @@ async fn test_get_messages_by_timestamp(
         messages_required_to_save,
         segment_size,
         message_size,
-        index_cache_enabled
+        cache_indexes
     );
 
     let setup = TestSetup::init().await;
@@ -95,7 +101,7 @@ async fn test_get_messages_by_timestamp(
             ..Default::default()
         },
         segment: SegmentConfig {
-            cache_indexes: index_cache_enabled,
+            cache_indexes,
             size: segment_size,
             ..Default::default()
         },
@@ -148,6 +154,7 @@ async fn test_get_messages_by_timestamp(
             length: payload.len() as u32,
             payload: payload.clone(),
             headers: Some(headers),
+            key: None,
         };
         all_messages.push(message);
     }
@@ -0,0 +1,172 @@
+use crate::streaming::common::test_setup::TestSetup;
+use bytes::Bytes;
+use iggy::models::messages::MessageState;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::crypto::KeyringEncryptor;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::sizeable::Sizeable;
+use iggy::utils::{checksum, text};
+use server::configs::system::SystemConfig;
+use server::streaming::batching::iterator::IntoMessagesIterator;
+use server::streaming::batching::message_batch::{
+    RetainedBatchHeader, RetainedMessageBatch, RETAINED_BATCH_HEADER_LEN,
+};
+use server::streaming::keyring::Keyring;
+use server::streaming::models::messages::RetainedMessage;
+use server::streaming::segments::Segment;
+use std::path::Path;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn should_create_keyring_file_when_it_does_not_exist() {
+    let setup = TestSetup::init().await;
+    let config = create_config(&setup, "");
+
+    let keyring = Keyring::load(&config).unwrap();
+
+    assert!(Path::new(&config.get_keyring_path()).exists());
+    assert_eq!(keyring.current_key_id(), 1);
+}
+
+#[tokio::test]
+async fn should_decrypt_data_encrypted_with_previous_key_after_rotation_and_reload() {
+    let setup = TestSetup::init().await;
+    let config = create_config(&setup, "");
+    let keyring = Keyring::load(&config).unwrap();
+    let data = b"message encrypted with the first key";
+    let encrypted = keyring.encryptor().encrypt(data).unwrap();
+
+    let key_id = keyring.rotate().unwrap();
+    assert_eq!(key_id, 2);
+    assert_eq!(keyring.current_key_id(), 2);
+
+    let loaded_keyring = Keyring::load(&config).unwrap();
+    assert_eq!(loaded_keyring.current_key_id(), 2);
+    let decrypted = loaded_keyring
+        .keyring_encryptor()
+        .decrypt_with_key(&encrypted, Some(1))
+        .unwrap();
+    assert_eq!(decrypted, data);
+}
+
+#[tokio::test]
+async fn should_store_keys_encrypted_with_key_encryption_key() {
+    let setup = TestSetup::init().await;
+    let key_encryption_key = text::as_base64(&KeyringEncryptor::generate_key());
+    let config = create_config(&setup, &key_encryption_key);
+    let keyring = Keyring::load(&config).unwrap();
+    let encrypted = keyring.encryptor().encrypt(b"message").unwrap();
+
+    let file = std::fs::read_to_string(config.get_keyring_path()).unwrap();
+    assert!(file.contains("\"wrapped\": true"));
+    assert!(!file.contains("\"wrapped\": false"));
+    let loaded_keyring = Keyring::load(&config).unwrap();
+    assert_eq!(
+        loaded_keyring.encryptor().decrypt(&encrypted).unwrap(),
+        b"message"
+    );
+
+    let config = create_config(&setup, "");
+    assert!(Keyring::load(&config).is_err());
+}
+
+#[tokio::test]
+async fn should_reencrypt_batches_of_closed_segment_with_current_key() {
+    let setup = TestSetup::init().await;
+    let config = Arc::new(create_config(&setup, ""));
+    let keyring = Keyring::load(&config).unwrap();
+    let encryptor = keyring.keyring_encryptor();
+    setup.create_partition_directory(1, 1, 1).await;
+    let mut segment = Segment::create(
+        1,
+        1,
+        1,
+        0,
+        config.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    );
+    segment.persist().await.unwrap();
+
+    append_encrypted_messages(&mut segment, encryptor, 0..3, 1).await;
+    let key_id = keyring.rotate().unwrap();
+    append_encrypted_messages(&mut segment, encryptor, 3..5, key_id).await;
+    segment.is_closed = true;
+
+    let reencrypted = segment.reencrypt(encryptor).await.unwrap().unwrap();
+    assert_eq!(reencrypted.reencrypted_messages, 3);
+
+    let log = tokio::fs::read(&reencrypted.log_path).await.unwrap();
+    let header_len = RETAINED_BATCH_HEADER_LEN as usize;
+    let mut position = 0;
+    let mut offset = 0;
+    while position < log.len() {
+        let header = RetainedBatchHeader::from_bytes(&log[position..]).unwrap();
+        assert_eq!(header.encryption_key_id, Some(key_id));
+        let batch_end = position + header_len + header.length as usize;
+        let batch = RetainedMessageBatch::from_header(
+            &header,
+            Bytes::copy_from_slice(&log[position + header_len..batch_end]),
+        );
+        for message in batch.into_messages_iter() {
+            let payload = encryptor
+                .decrypt_with_key(&message.payload, Some(key_id))
+                .unwrap();
+            assert_eq!(payload, format!("message-{offset}").as_bytes());
+            offset += 1;
+        }
+        position = batch_end;
+    }
+    assert_eq!(offset, 5);
+    reencrypted.remove().await;
+}
+
+async fn append_encrypted_messages(
+    segment: &mut Segment,
+    encryptor: &KeyringEncryptor,
+    offsets: std::ops::Range<u64>,
+    key_id: u32,
+) {
+    let mut batch_size = IggyByteSize::default();
+    let mut messages = Vec::new();
+    for offset in offsets {
+        let payload = encryptor
+            .encrypt_with_key(format!("message-{offset}").as_bytes(), key_id)
+            .unwrap();
+        let payload = Bytes::from(payload);
+        let message = Arc::new(RetainedMessage {
+            id: offset as u128,
+            offset,
+            timestamp: 1000 + offset,
+            checksum: checksum::calculate(&payload),
+            message_state: MessageState::Available,
+            headers: None,
+            key: None,
+            payload,
+        });
+        batch_size += message.get_size_bytes();
+        messages.push(message);
+    }
+    segment
+        .append_batch(batch_size, messages.len() as u32, &messages, Some(key_id))
+        .await
+        .unwrap();
+    segment.persist_messages(None).await.unwrap();
+}
+
+fn create_config(setup: &TestSetup, key_encryption_key: &str) -> SystemConfig {
+    let mut config = SystemConfig {
+        path: setup.config.path.to_string(),
+        ..Default::default()
+    };
+    config.encryption.enabled = true;
+    config.encryption.keyring.enabled = true;
+    config.encryption.keyring.key_encryption_key = key_encryption_key.to_owned();
+    config
+}
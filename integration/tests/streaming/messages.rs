@@ -10,6 +10,7 @@ use iggy::utils::timestamp::IggyTimestamp;
 use server::configs::system::{PartitionConfig, SystemConfig};
 use server::state::system::PartitionState;
 use server::streaming::batching::appendable_batch_info::AppendableBatchInfo;
+use server::streaming::batching::message_batch::{RetainedBatchHeader, RETAINED_BATCH_HEADER_LEN};
 use server::streaming::partitions::partition::Partition;
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -73,6 +74,7 @@ async fn should_persist_messages_and_then_load_them_by_timestamp() {
             length: payload.len() as u32,
             payload: payload.clone(),
             headers: Some(headers),
+            key: None,
         };
         messages.push(message);
     }
@@ -98,6 +100,7 @@ async fn should_persist_messages_and_then_load_them_by_timestamp() {
             length: payload.len() as u32,
             payload: payload.clone(),
             headers: Some(headers),
+            key: None,
         };
         appended_messages.push(message.clone());
         messages_two.push(message);
@@ -234,12 +237,14 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
             length: payload.len() as u32,
             payload: payload.clone(),
             headers: Some(headers.clone()),
+            key: None,
         };
         let message = Message {
             id,
             length: payload.len() as u32,
             payload: payload.clone(),
             headers: Some(headers),
+            key: None,
         };
         appended_messages.push(appended_message);
         messages.push(message);
@@ -327,3 +332,70 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         );
     }
 }
+
+#[tokio::test]
+async fn should_persist_unsaved_messages_when_encryption_key_changes() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partition_id = 1;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        partition: PartitionConfig {
+            messages_required_to_save: 100,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config.clone(),
+        setup.storage.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        IggyTimestamp::now(),
+    )
+    .await;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    partition.persist().await.unwrap();
+
+    for (id, encryption_key_id) in [(1, 1), (2, 1), (3, 2)] {
+        let payload = Bytes::from(format!("message {id}"));
+        let message = Message {
+            id,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+            key: None,
+        };
+        let appendable_batch_info =
+            AppendableBatchInfo::new(message.get_size_bytes(), partition.partition_id)
+                .with_encryption_key_id(Some(encryption_key_id));
+        partition
+            .append_messages(appendable_batch_info, vec![message], None)
+            .await
+            .unwrap();
+    }
+
+    // Only the messages encrypted with the previous key have been saved, as a single batch.
+    assert_eq!(partition.unsaved_messages_count, 1);
+    let segment = &partition.get_segments()[0];
+    let log = tokio::fs::read(&segment.log_path).await.unwrap();
+    let header = RetainedBatchHeader::from_bytes(&log).unwrap();
+    assert_eq!(header.encryption_key_id, Some(1));
+    assert_eq!(header.last_offset_delta, 1);
+    assert_eq!(
+        log.len() as u64,
+        RETAINED_BATCH_HEADER_LEN + header.length as u64
+    );
+    let unsaved = segment.unsaved_messages.as_ref().unwrap();
+    assert_eq!(unsaved.encryption_key_id(), Some(2));
+    assert_eq!(unsaved.unsaved_messages_count(), 1);
+}
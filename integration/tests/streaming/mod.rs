@@ -5,12 +5,14 @@ mod common;
 mod consumer_offset;
 mod get_by_offset;
 mod get_by_timestamp;
+mod keyring;
 mod messages;
 mod partition;
 mod segment;
 mod snapshot;
 mod stream;
 mod system;
+mod tiered_storage;
 mod topic;
 mod topic_messages;
 
@@ -32,5 +34,6 @@ fn create_message(id: u128, payload: &str) -> Message {
         length: payload.len() as u32,
         payload,
         headers: None,
+        key: None,
     }
 }
@@ -6,7 +6,9 @@ use iggy::models::messages::{MessageState, PolledMessage};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::{checksum, timestamp::IggyTimestamp};
-use server::configs::system::{SegmentConfig, SystemConfig};
+use server::configs::system::{IndexCacheMode, SegmentConfig, SystemConfig};
+#[cfg(target_os = "linux")]
+use server::configs::system::{IoBackend, IoUringConfig, PartitionConfig};
 use server::streaming::local_sizeable::LocalSizeable;
 use server::streaming::models::messages::RetainedMessage;
 use server::streaming::segments::*;
@@ -162,6 +164,7 @@ async fn should_persist_and_load_segment_with_messages() {
             checksum: message.checksum,
             message_state: message.state,
             headers: message.headers.map(|headers| headers.to_bytes()),
+            key: message.key.clone(),
             payload: message.payload.clone(),
         });
         batch_size += retained_message.get_size_bytes();
@@ -169,7 +172,7 @@ async fn should_persist_and_load_segment_with_messages() {
     }
 
     segment
-        .append_batch(batch_size, messages_count as u32, &messages)
+        .append_batch(batch_size, messages_count as u32, &messages, None)
         .await
         .unwrap();
     segment.persist_messages(None).await.unwrap();
@@ -195,6 +198,86 @@ async fn should_persist_and_load_segment_with_messages() {
     assert_eq!(messages.len(), messages_count as usize);
 }
 
+#[tokio::test]
+async fn should_load_all_batches_of_segment_with_non_zero_start_offset() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 100;
+    let mut segment = Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let batches_count = 5;
+    let messages_per_batch = 2;
+    for batch in 0..batches_count {
+        let mut messages = Vec::new();
+        let mut batch_size = IggyByteSize::default();
+        for i in 0..messages_per_batch {
+            let offset = start_offset + batch * messages_per_batch + i;
+            let message = create_message(offset, "test", IggyTimestamp::now());
+            let retained_message = Arc::new(RetainedMessage {
+                id: message.id,
+                offset: message.offset,
+                timestamp: message.timestamp,
+                checksum: message.checksum,
+                message_state: message.state,
+                headers: message.headers.map(|headers| headers.to_bytes()),
+                key: message.key.clone(),
+                payload: message.payload.clone(),
+            });
+            batch_size += retained_message.get_size_bytes();
+            messages.push(retained_message);
+        }
+        segment
+            .append_batch(batch_size, messages_per_batch as u32, &messages, None)
+            .await
+            .unwrap();
+        segment.persist_messages(None).await.unwrap();
+    }
+
+    let mut loaded_segment = Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    );
+    loaded_segment.load_from_disk().await.unwrap();
+    let messages_count = (batches_count * messages_per_batch) as u32;
+    let messages = loaded_segment
+        .get_messages_by_offset(start_offset, messages_count)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), messages_count as usize);
+    for (i, message) in messages.iter().enumerate() {
+        assert_eq!(message.offset, start_offset + i as u64);
+    }
+}
+
 #[tokio::test]
 async fn should_persist_and_load_segment_with_messages_with_nowait_confirmation() {
     let setup = TestSetup::init_with_config(SystemConfig {
@@ -248,6 +331,7 @@ async fn should_persist_and_load_segment_with_messages_with_nowait_confirmation(
             checksum: message.checksum,
             message_state: message.state,
             headers: message.headers.map(|headers| headers.to_bytes()),
+            key: message.key.clone(),
             payload: message.payload.clone(),
         });
         batch_size += retained_message.get_size_bytes();
@@ -255,7 +339,7 @@ async fn should_persist_and_load_segment_with_messages_with_nowait_confirmation(
     }
 
     segment
-        .append_batch(batch_size, messages_count as u32, &messages)
+        .append_batch(batch_size, messages_count as u32, &messages, None)
         .await
         .unwrap();
     segment
@@ -285,6 +369,107 @@ async fn should_persist_and_load_segment_with_messages_with_nowait_confirmation(
     assert_eq!(messages.len(), messages_count as usize);
 }
 
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn should_persist_and_load_segment_with_messages_with_io_uring_backend() {
+    for direct_io in [false, true] {
+        let setup = TestSetup::init_with_config(SystemConfig {
+            partition: PartitionConfig {
+                io_backend: IoBackend::IoUring,
+                io_uring: IoUringConfig {
+                    direct_io,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+        let stream_id = 1;
+        let topic_id = 2;
+        let partition_id = 3;
+        let start_offset = 0;
+        let mut segment = Segment::create(
+            stream_id,
+            topic_id,
+            partition_id,
+            start_offset,
+            setup.config.clone(),
+            IggyExpiry::NeverExpire,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+        );
+
+        setup
+            .create_partition_directory(stream_id, topic_id, partition_id)
+            .await;
+        segment.persist().await.unwrap();
+        let batches_count = 3;
+        let messages_count = 10;
+        for batch in 0..batches_count {
+            let mut messages = Vec::new();
+            let mut batch_size = IggyByteSize::default();
+            for i in 0..messages_count {
+                let offset = batch * messages_count + i;
+                let message = create_message(offset, "test", IggyTimestamp::now());
+                let retained_message = Arc::new(RetainedMessage {
+                    id: message.id,
+                    offset: message.offset,
+                    timestamp: message.timestamp,
+                    checksum: message.checksum,
+                    message_state: message.state,
+                    headers: message.headers.map(|headers| headers.to_bytes()),
+                    key: message.key.clone(),
+                    payload: message.payload.clone(),
+                });
+                batch_size += retained_message.get_size_bytes();
+                messages.push(retained_message);
+            }
+
+            segment
+                .append_batch(batch_size, messages_count as u32, &messages, None)
+                .await
+                .unwrap();
+            segment
+                .persist_messages(Some(Confirmation::Wait))
+                .await
+                .unwrap();
+        }
+        // Each message is stored on the disk along with its length, which isn't a part of the segment size.
+        let size_bytes = segment.size_bytes.as_bytes_u64() + batches_count * messages_count * 4;
+        segment.shutdown_writing().await;
+        sleep(Duration::from_millis(200)).await;
+
+        // The padding of the direct I/O writes is truncated once the writer is closed.
+        let log_size_bytes = fs::metadata(&segment.log_path).await.unwrap().len();
+        assert_eq!(log_size_bytes, size_bytes);
+        let mut loaded_segment = Segment::create(
+            stream_id,
+            topic_id,
+            partition_id,
+            start_offset,
+            setup.config.clone(),
+            IggyExpiry::NeverExpire,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+        );
+        loaded_segment.load_from_disk().await.unwrap();
+        let messages = loaded_segment
+            .get_messages_by_offset(0, (batches_count * messages_count) as u32)
+            .await
+            .unwrap();
+        assert_eq!(messages.len(), (batches_count * messages_count) as usize);
+    }
+}
+
 #[tokio::test]
 async fn given_all_expired_messages_segment_should_be_expired() {
     let setup = TestSetup::init().await;
@@ -336,13 +521,14 @@ async fn given_all_expired_messages_segment_should_be_expired() {
             checksum: message.checksum,
             message_state: message.state,
             headers: message.headers.map(|headers| headers.to_bytes()),
+            key: message.key.clone(),
             payload: message.payload.clone(),
         });
         batch_size += retained_message.get_size_bytes();
         messages.push(retained_message);
     }
     segment
-        .append_batch(batch_size, messages_count as u32, &messages)
+        .append_batch(batch_size, messages_count as u32, &messages, None)
         .await
         .unwrap();
     segment.persist_messages(None).await.unwrap();
@@ -400,6 +586,7 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
         checksum: expired_message.checksum,
         message_state: expired_message.state,
         headers: expired_message.headers.map(|headers| headers.to_bytes()),
+        key: expired_message.key.clone(),
         payload: expired_message.payload.clone(),
     });
     let mut expired_messages = Vec::new();
@@ -416,17 +603,18 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
         headers: not_expired_message
             .headers
             .map(|headers| headers.to_bytes()),
+        key: not_expired_message.key.clone(),
         payload: not_expired_message.payload.clone(),
     });
     let not_expired_message_size = not_expired_retained_message.get_size_bytes();
     not_expired_messages.push(not_expired_retained_message);
 
     segment
-        .append_batch(expired_message_size, 1, &expired_messages)
+        .append_batch(expired_message_size, 1, &expired_messages, None)
         .await
         .unwrap();
     segment
-        .append_batch(not_expired_message_size, 1, &not_expired_messages)
+        .append_batch(not_expired_message_size, 1, &not_expired_messages, None)
         .await
         .unwrap();
     segment.persist_messages(None).await.unwrap();
@@ -435,12 +623,198 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
     assert!(!is_expired);
 }
 
+#[tokio::test]
+async fn should_return_file_range_only_for_saved_messages() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let batches_count = 3;
+    let messages_per_batch = 5;
+    for batch in 0..batches_count {
+        let mut messages = Vec::new();
+        let mut batch_size = IggyByteSize::default();
+        for i in 0..messages_per_batch {
+            let offset = batch * messages_per_batch + i;
+            let message = create_message(offset, "test", IggyTimestamp::now());
+            let retained_message = Arc::new(RetainedMessage {
+                id: message.id,
+                offset: message.offset,
+                timestamp: message.timestamp,
+                checksum: message.checksum,
+                message_state: message.state,
+                headers: message.headers.map(|headers| headers.to_bytes()),
+                key: message.key.clone(),
+                payload: message.payload.clone(),
+            });
+            batch_size += retained_message.get_size_bytes();
+            messages.push(retained_message);
+        }
+        segment
+            .append_batch(batch_size, messages_per_batch as u32, &messages, None)
+            .await
+            .unwrap();
+        // The last batch remains unsaved.
+        if batch < batches_count - 1 {
+            segment.persist_messages(None).await.unwrap();
+        }
+    }
+
+    let log_size = fs::metadata(&segment.log_path).await.unwrap().len();
+    let file_range = segment
+        .get_file_range_by_offset(3, 12)
+        .await
+        .unwrap()
+        .expect("File range should be available for the saved messages");
+    assert_eq!(file_range.position, 0);
+    assert_eq!(file_range.length, log_size);
+    assert_eq!(file_range.start_offset, 3);
+    assert_eq!(file_range.end_offset, 9);
+    assert_eq!(file_range.messages_count(), 7);
+
+    let file_range = segment
+        .get_file_range_by_offset(7, 8)
+        .await
+        .unwrap()
+        .expect("File range should be available for the saved messages");
+    assert!(file_range.position > 0);
+    assert_eq!(file_range.position + file_range.length, log_size);
+    assert_eq!(file_range.start_offset, 7);
+    assert_eq!(file_range.end_offset, 8);
+
+    let file_range = segment.get_file_range_by_offset(10, 14).await.unwrap();
+    assert!(file_range.is_none());
+}
+
+#[tokio::test]
+async fn should_rebuild_missing_time_index_and_find_messages_by_timestamp() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        segment: SegmentConfig {
+            time_index_interval: IggyByteSize::from(0),
+            cache_indexes: IndexCacheMode::OnDemand,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let batches_count = 3;
+    let messages_per_batch = 5;
+    let base_timestamp = IggyTimestamp::now().as_micros();
+    for batch in 0..batches_count {
+        let mut messages = Vec::new();
+        let mut batch_size = IggyByteSize::default();
+        for i in 0..messages_per_batch {
+            let offset = batch * messages_per_batch + i;
+            let timestamp = IggyTimestamp::from(base_timestamp + offset * 1000);
+            let message = create_message(offset, "test", timestamp);
+            let retained_message = Arc::new(RetainedMessage {
+                id: message.id,
+                offset: message.offset,
+                timestamp: message.timestamp,
+                checksum: message.checksum,
+                message_state: message.state,
+                headers: message.headers.map(|headers| headers.to_bytes()),
+                key: message.key.clone(),
+                payload: message.payload.clone(),
+            });
+            batch_size += retained_message.get_size_bytes();
+            messages.push(retained_message);
+        }
+        segment
+            .append_batch(batch_size, messages_per_batch as u32, &messages, None)
+            .await
+            .unwrap();
+        segment.persist_messages(None).await.unwrap();
+    }
+    segment.shutdown_writing().await;
+    sleep(Duration::from_millis(200)).await;
+
+    // Every batch is indexed with the zero interval.
+    let time_index_size = fs::metadata(&segment.time_index_path).await.unwrap().len();
+    assert_eq!(time_index_size, batches_count * TIME_INDEX_SIZE);
+    fs::remove_file(&segment.time_index_path).await.unwrap();
+
+    let mut loaded_segment = Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    );
+    loaded_segment.load_from_disk().await.unwrap();
+    let time_index_size = fs::metadata(&loaded_segment.time_index_path)
+        .await
+        .unwrap()
+        .len();
+    assert_eq!(time_index_size, batches_count * TIME_INDEX_SIZE);
+
+    let messages = loaded_segment
+        .get_messages_by_timestamp(base_timestamp + 7 * 1000, 5)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), 5);
+    assert_eq!(messages[0].offset, 7);
+    assert_eq!(messages[4].offset, 11);
+}
+
 async fn assert_persisted_segment(partition_path: &str, start_offset: u64) {
     let segment_path = format!("{}/{:0>20}", partition_path, start_offset);
     let log_path = format!("{}.{}", segment_path, LOG_EXTENSION);
     let index_path = format!("{}.{}", segment_path, INDEX_EXTENSION);
+    let time_index_path = format!("{}.{}", segment_path, TIME_INDEX_EXTENSION);
     assert!(fs::metadata(&log_path).await.is_ok());
     assert!(fs::metadata(&index_path).await.is_ok());
+    assert!(fs::metadata(&time_index_path).await.is_ok());
 }
 
 fn create_message(offset: u64, payload: &str, timestamp: IggyTimestamp) -> PolledMessage {
@@ -462,3 +836,86 @@ fn get_start_offsets() -> Vec<u64> {
         0, 1, 2, 9, 10, 99, 100, 110, 200, 1000, 1234, 12345, 100000, 9999999,
     ]
 }
+
+#[tokio::test]
+async fn should_read_messages_appended_after_index_file_was_mapped() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        segment: SegmentConfig {
+            cache_indexes: IndexCacheMode::Mmap,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    assert!(segment.indexes.is_none());
+    let batches_count = 3;
+    let messages_per_batch = 5;
+    for batch in 0..batches_count {
+        let mut messages = Vec::new();
+        let mut batch_size = IggyByteSize::default();
+        for i in 0..messages_per_batch {
+            let offset = batch * messages_per_batch + i;
+            let message = create_message(offset, "test", IggyTimestamp::now());
+            let retained_message = Arc::new(RetainedMessage {
+                id: message.id,
+                offset: message.offset,
+                timestamp: message.timestamp,
+                checksum: message.checksum,
+                message_state: message.state,
+                headers: message.headers.map(|headers| headers.to_bytes()),
+                key: message.key.clone(),
+                payload: message.payload.clone(),
+            });
+            batch_size += retained_message.get_size_bytes();
+            messages.push(retained_message);
+        }
+        segment
+            .append_batch(batch_size, messages_per_batch as u32, &messages, None)
+            .await
+            .unwrap();
+        segment.persist_messages(None).await.unwrap();
+
+        // The index file is mapped again, as it has grown since the previous batch was read.
+        let start_offset = batch * messages_per_batch;
+        let messages = segment
+            .get_messages_by_offset(start_offset, messages_per_batch as u32)
+            .await
+            .unwrap();
+        assert_eq!(messages.len(), messages_per_batch as usize);
+        for (i, message) in messages.iter().enumerate() {
+            assert_eq!(message.offset, start_offset + i as u64);
+        }
+    }
+
+    let messages = segment
+        .get_messages_by_offset(0, (batches_count * messages_per_batch) as u32)
+        .await
+        .unwrap();
+    assert_eq!(
+        messages.len(),
+        (batches_count * messages_per_batch) as usize
+    );
+}
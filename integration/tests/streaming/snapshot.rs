@@ -1,6 +1,6 @@
 use crate::streaming::common::test_setup::TestSetup;
 use iggy::snapshot::{SnapshotCompression, SystemSnapshotType};
-use server::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
+use server::configs::server::ServerConfig;
 use server::streaming::session::Session;
 use server::streaming::systems::system::System;
 use std::io::{Cursor, Read};
@@ -10,11 +10,10 @@ use zip::ZipArchive;
 #[tokio::test]
 async fn should_create_snapshot_file() {
     let setup = TestSetup::init().await;
-    let mut system = System::new(
-        setup.config.clone(),
-        DataMaintenanceConfig::default(),
-        PersonalAccessTokenConfig::default(),
-    );
+    let mut system = System::new(&ServerConfig {
+        system: setup.config.clone(),
+        ..Default::default()
+    });
 
     system.init().await.unwrap();
 
@@ -60,6 +60,7 @@ async fn should_load_existing_stream_from_disk() {
             id: stream_id,
             name: name.clone(),
             created_at: IggyTimestamp::now(),
+            created_by: 0,
             topics: AHashMap::new(),
             current_topic_id: 0,
         };
@@ -134,7 +135,13 @@ async fn should_purge_existing_stream_on_disk() {
             .map(|msg| msg.get_size_bytes())
             .sum::<IggyByteSize>();
         topic
-            .append_messages(batch_size, Partitioning::partition_id(1), messages, None)
+            .append_messages(
+                batch_size,
+                Partitioning::partition_id(1),
+                messages,
+                None,
+                None,
+            )
             .await
             .unwrap();
         let loaded_messages = topic
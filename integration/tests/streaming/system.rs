@@ -1,6 +1,6 @@
 use crate::streaming::common::test_setup::TestSetup;
 use iggy::identifier::Identifier;
-use server::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
+use server::configs::server::ServerConfig;
 use server::streaming::session::Session;
 use server::streaming::systems::system::System;
 use std::net::{Ipv4Addr, SocketAddr};
@@ -9,11 +9,10 @@ use tokio::fs;
 #[tokio::test]
 async fn should_initialize_system_and_base_directories() {
     let setup = TestSetup::init().await;
-    let mut system = System::new(
-        setup.config.clone(),
-        DataMaintenanceConfig::default(),
-        PersonalAccessTokenConfig::default(),
-    );
+    let mut system = System::new(&ServerConfig {
+        system: setup.config.clone(),
+        ..Default::default()
+    });
 
     system.init().await.unwrap();
 
@@ -32,11 +31,10 @@ async fn should_initialize_system_and_base_directories() {
 #[tokio::test]
 async fn should_create_and_persist_stream() {
     let setup = TestSetup::init().await;
-    let mut system = System::new(
-        setup.config.clone(),
-        DataMaintenanceConfig::default(),
-        PersonalAccessTokenConfig::default(),
-    );
+    let mut system = System::new(&ServerConfig {
+        system: setup.config.clone(),
+        ..Default::default()
+    });
     let stream_id = 1;
     let stream_name = "test";
     let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
@@ -53,11 +51,10 @@ async fn should_create_and_persist_stream() {
 #[tokio::test]
 async fn should_create_and_persist_stream_with_automatically_generated_id() {
     let setup = TestSetup::init().await;
-    let mut system = System::new(
-        setup.config.clone(),
-        DataMaintenanceConfig::default(),
-        PersonalAccessTokenConfig::default(),
-    );
+    let mut system = System::new(&ServerConfig {
+        system: setup.config.clone(),
+        ..Default::default()
+    });
     let stream_id = 1;
     let stream_name = "test";
     let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
@@ -74,11 +71,10 @@ async fn should_create_and_persist_stream_with_automatically_generated_id() {
 #[tokio::test]
 async fn should_delete_persisted_stream() {
     let setup = TestSetup::init().await;
-    let mut system = System::new(
-        setup.config.clone(),
-        DataMaintenanceConfig::default(),
-        PersonalAccessTokenConfig::default(),
-    );
+    let mut system = System::new(&ServerConfig {
+        system: setup.config.clone(),
+        ..Default::default()
+    });
     let stream_id = 1;
     let stream_name = "test";
     let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
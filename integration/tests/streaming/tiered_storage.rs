@@ -0,0 +1,250 @@
+use crate::streaming::common::test_setup::TestSetup;
+use bytes::BytesMut;
+use iggy::messages::send_messages::Message;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::sizeable::Sizeable;
+use iggy::utils::timestamp::IggyTimestamp;
+use server::archiver::ArchiverKind;
+use server::configs::server::DiskArchiverConfig;
+use server::configs::system::{
+    CacheConfig, PartitionConfig, SegmentConfig, SystemConfig, TieredStorageConfig,
+};
+use server::state::system::PartitionState;
+use server::streaming::batching::appendable_batch_info::AppendableBatchInfo;
+use server::streaming::partitions::partition::Partition;
+use server::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+use server::streaming::storage::SystemStorage;
+use server::streaming::tiered_storage::TieredStorage;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU32, AtomicU64};
+use std::sync::Arc;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 20;
+
+#[tokio::test]
+async fn should_poll_messages_by_offset_from_offloaded_segments() {
+    let setup = TestSetup::init().await;
+    let (config, storage) = create_tiered_storage(&setup);
+    let mut partition = create_partition(config.clone(), storage.clone(), true).await;
+    setup.create_partitions_directory(STREAM_ID, TOPIC_ID).await;
+    partition.persist().await.unwrap();
+    append_messages(&mut partition).await;
+
+    let offloaded_segments_count = offload_closed_segments(&mut partition, &config).await;
+    assert!(offloaded_segments_count > 0);
+    assert_eq!(partition.get_segments().len(), 1);
+    assert_eq!(
+        partition.get_offloaded_segments().len(),
+        offloaded_segments_count
+    );
+
+    let messages = partition
+        .get_messages_by_offset(0, MESSAGES_COUNT)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), MESSAGES_COUNT as usize);
+    for (offset, message) in messages.iter().enumerate() {
+        assert_eq!(message.offset, offset as u64);
+        assert_eq!(message.id, offset as u128 + 1);
+    }
+
+    let last_offloaded_offset = partition
+        .get_offloaded_segments()
+        .last()
+        .unwrap()
+        .end_offset;
+    let messages = partition
+        .get_messages_by_offset(last_offloaded_offset, 2)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].offset, last_offloaded_offset);
+    assert_eq!(messages[1].offset, last_offloaded_offset + 1);
+}
+
+#[tokio::test]
+async fn should_poll_messages_by_timestamp_from_offloaded_segments() {
+    let setup = TestSetup::init().await;
+    let (config, storage) = create_tiered_storage(&setup);
+    let mut partition = create_partition(config.clone(), storage.clone(), true).await;
+    setup.create_partitions_directory(STREAM_ID, TOPIC_ID).await;
+    partition.persist().await.unwrap();
+    append_messages(&mut partition).await;
+    offload_closed_segments(&mut partition, &config).await;
+
+    let messages = partition
+        .get_messages_by_timestamp(IggyTimestamp::from(0), MESSAGES_COUNT)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), MESSAGES_COUNT as usize);
+    assert_eq!(messages[0].offset, 0);
+    assert_eq!(messages.last().unwrap().offset, MESSAGES_COUNT as u64 - 1);
+}
+
+#[tokio::test]
+async fn should_load_offloaded_segments_from_disk() {
+    let setup = TestSetup::init().await;
+    let (config, storage) = create_tiered_storage(&setup);
+    let mut partition = create_partition(config.clone(), storage.clone(), true).await;
+    setup.create_partitions_directory(STREAM_ID, TOPIC_ID).await;
+    partition.persist().await.unwrap();
+    append_messages(&mut partition).await;
+    offload_closed_segments(&mut partition, &config).await;
+
+    let mut loaded_partition = create_partition(config.clone(), storage.clone(), false).await;
+    loaded_partition
+        .load(PartitionState {
+            id: PARTITION_ID,
+            created_at: IggyTimestamp::now(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        loaded_partition.get_offloaded_segments(),
+        partition.get_offloaded_segments()
+    );
+    assert_eq!(loaded_partition.current_offset, partition.current_offset);
+    let messages = loaded_partition
+        .get_messages_by_offset(0, MESSAGES_COUNT)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), MESSAGES_COUNT as usize);
+}
+
+#[tokio::test]
+async fn should_delete_offloaded_segments_when_partition_is_purged() {
+    let setup = TestSetup::init().await;
+    let (config, storage) = create_tiered_storage(&setup);
+    let mut partition = create_partition(config.clone(), storage.clone(), true).await;
+    setup.create_partitions_directory(STREAM_ID, TOPIC_ID).await;
+    partition.persist().await.unwrap();
+    append_messages(&mut partition).await;
+    offload_closed_segments(&mut partition, &config).await;
+    let offloaded_paths = partition
+        .get_offloaded_segments()
+        .iter()
+        .map(|segment| segment.get_path(&config))
+        .collect::<Vec<_>>();
+
+    partition.purge().await.unwrap();
+
+    assert!(partition.get_offloaded_segments().is_empty());
+    for path in offloaded_paths {
+        assert!(!Path::new(&path).exists());
+    }
+    let messages = partition
+        .get_messages_by_offset(0, MESSAGES_COUNT)
+        .await
+        .unwrap();
+    assert!(messages.is_empty());
+}
+
+fn create_tiered_storage(setup: &TestSetup) -> (Arc<SystemConfig>, Arc<SystemStorage>) {
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        cache: CacheConfig {
+            enabled: false,
+            ..Default::default()
+        },
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            enforce_fsync: true,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            size: IggyByteSize::from_str("2000b").unwrap(),
+            ..Default::default()
+        },
+        tiered_storage: TieredStorageConfig {
+            enabled: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let archiver = Arc::new(ArchiverKind::get_disk_archiver(DiskArchiverConfig {
+        path: format!("{}/archive", config.get_system_path()),
+    }));
+    let mut storage = SystemStorage::new(
+        config.clone(),
+        Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+    );
+    storage.tiered = Some(Arc::new(TieredStorage::new(archiver, config.clone())));
+    (config, Arc::new(storage))
+}
+
+async fn create_partition(
+    config: Arc<SystemConfig>,
+    storage: Arc<SystemStorage>,
+    with_segment: bool,
+) -> Partition {
+    Partition::create(
+        STREAM_ID,
+        TOPIC_ID,
+        PARTITION_ID,
+        with_segment,
+        config,
+        storage,
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        IggyTimestamp::now(),
+    )
+    .await
+}
+
+async fn append_messages(partition: &mut Partition) {
+    for id in 1..=MESSAGES_COUNT {
+        let mut payload = BytesMut::new();
+        payload.extend_from_slice(format!("message {id}").as_bytes());
+        payload.resize(500, 0xD);
+        let payload = payload.freeze();
+        let message = Message {
+            id: id as u128,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+            key: None,
+        };
+        let batch_info = AppendableBatchInfo::new(message.get_size_bytes(), PARTITION_ID);
+        partition
+            .append_messages(batch_info, vec![message], None)
+            .await
+            .unwrap();
+    }
+}
+
+async fn offload_closed_segments(partition: &mut Partition, config: &SystemConfig) -> usize {
+    let archiver = ArchiverKind::get_disk_archiver(DiskArchiverConfig {
+        path: format!("{}/archive", config.get_system_path()),
+    });
+    // The last segment is the active one, even if it's already full, so it's never offloaded.
+    let segments = partition.get_segments();
+    let start_offsets = segments[..segments.len() - 1]
+        .iter()
+        .filter(|segment| segment.is_closed)
+        .map(|segment| {
+            (
+                segment.start_offset,
+                segment.index_path.clone(),
+                segment.log_path.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    for (start_offset, index_path, log_path) in &start_offsets {
+        archiver
+            .archive(&[index_path.as_str(), log_path.as_str()], None)
+            .await
+            .unwrap();
+        partition.offload_segment(*start_offset).await.unwrap();
+    }
+    start_offsets.len()
+}
@@ -117,6 +117,7 @@ async fn should_load_existing_topic_from_disk() {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
             created_at: Default::default(),
+            created_by: 0,
             current_consumer_group_id: 0,
         };
         loaded_topic.load(topic_state).await.unwrap();
@@ -214,7 +215,13 @@ async fn should_purge_existing_topic_on_disk() {
             .map(|msg| msg.get_size_bytes())
             .sum::<IggyByteSize>();
         topic
-            .append_messages(batch_size, Partitioning::partition_id(1), messages, None)
+            .append_messages(
+                batch_size,
+                Partitioning::partition_id(1),
+                messages,
+                None,
+                None,
+            )
             .await
             .unwrap();
         let loaded_messages = topic
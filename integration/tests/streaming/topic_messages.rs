@@ -84,7 +84,7 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
         .map(|m| m.get_size_bytes())
         .sum::<IggyByteSize>();
     topic
-        .append_messages(batch_size, partitioning, messages, None)
+        .append_messages(batch_size, partitioning, messages, None, None)
         .await
         .unwrap();
 
@@ -130,6 +130,7 @@ async fn given_key_none_messages_should_be_appended_to_the_next_partition_using_
                 partitioning.clone(),
                 vec![get_message(i as u128, &payload)],
                 None,
+                None,
             )
             .await
             .unwrap();
@@ -156,6 +157,7 @@ async fn given_key_partition_id_messages_should_be_appended_to_the_chosen_partit
                 partitioning.clone(),
                 vec![get_message(i as u128, &payload)],
                 None,
+                None,
             )
             .await
             .unwrap();
@@ -186,6 +188,7 @@ async fn given_key_messages_key_messages_should_be_appended_to_the_calculated_pa
                 partitioning,
                 vec![get_message(entity_id as u128, &payload)],
                 None,
+                None,
             )
             .await
             .unwrap();
@@ -256,6 +259,7 @@ fn get_message(id: u128, payload: &str) -> Message {
         length: payload.len() as u32,
         payload: Bytes::from(payload.as_bytes().to_vec()),
         headers: None,
+        key: None,
     }
 }
 
@@ -64,6 +64,16 @@ get_env_vars() {
         ;;
     esac
 
+    # The same benchmarks are run with the io_uring backend, to compare it with the default one
+    case "$bench_type" in
+    *"io_uring_direct_io"*)
+        env_vars+=("IGGY_SYSTEM_PARTITION_IO_BACKEND=io_uring IGGY_SYSTEM_PARTITION_IO_URING_DIRECT_IO=true")
+        ;;
+    *"io_uring"*)
+        env_vars+=("IGGY_SYSTEM_PARTITION_IO_BACKEND=io_uring")
+        ;;
+    esac
+
     # Convert array to env var string
     local env_string=""
     for var in "${env_vars[@]}"; do
@@ -111,6 +121,14 @@ NO_CACHE_RL_SINGLE_PINNED_CONSUMER=$(construct_bench_command "$IGGY_BENCH_CMD" "
 NO_CACHE_FSYNC_RL_SINGLE_PINNED_PRODUCER=$(construct_bench_command "$IGGY_BENCH_CMD" "pinned-producer" 1 1 1000 1000 2000 tcp "1_producer_no_cache_fsync_rl_100MB" "$IDENTIFIER" "100MB") # 2GB data, 1KB messages, 100 msgs/batch with forced cache
 NO_CACHE_FSYNC_RL_SINGLE_PINNED_CONSUMER=$(construct_bench_command "$IGGY_BENCH_CMD" "pinned-consumer" 1 1 1000 1000 2000 tcp "1_consumer_no_cache_fsync_rl_100MB" "$IDENTIFIER" "100MB") # 2GB data, 1KB messages, 100 msgs/batch with forced cache
 
+# Large batch and single actor tests with cache disabled, fsync enabled and io_uring backend
+IO_URING_NO_CACHE_FSYNC_PINNED_PRODUCER=$(construct_bench_command "$IGGY_BENCH_CMD" "pinned-producer" 8 8 1000 1000 1000 tcp "send_no_cache_fsync_io_uring" "$IDENTIFIER") # 8GB data, 1KB messages, 1000 msgs/batch with io_uring
+IO_URING_NO_CACHE_FSYNC_PINNED_CONSUMER=$(construct_bench_command "$IGGY_BENCH_CMD" "pinned-consumer" 8 8 1000 1000 1000 tcp "poll_no_cache_fsync_io_uring" "$IDENTIFIER") # 8GB data, 1KB messages, 1000 msgs/batch with io_uring
+DIRECT_IO_NO_CACHE_FSYNC_PINNED_PRODUCER=$(construct_bench_command "$IGGY_BENCH_CMD" "pinned-producer" 8 8 1000 1000 1000 tcp "send_no_cache_fsync_io_uring_direct_io" "$IDENTIFIER") # 8GB data, 1KB messages, 1000 msgs/batch with io_uring and direct I/O
+DIRECT_IO_NO_CACHE_FSYNC_PINNED_CONSUMER=$(construct_bench_command "$IGGY_BENCH_CMD" "pinned-consumer" 8 8 1000 1000 1000 tcp "poll_no_cache_fsync_io_uring_direct_io" "$IDENTIFIER") # 8GB data, 1KB messages, 1000 msgs/batch with io_uring and direct I/O
+IO_URING_NO_CACHE_FSYNC_RL_SINGLE_PINNED_PRODUCER=$(construct_bench_command "$IGGY_BENCH_CMD" "pinned-producer" 1 1 1000 1000 2000 tcp "1_producer_no_cache_fsync_rl_100MB_io_uring" "$IDENTIFIER" "100MB") # 2GB data, 1KB messages, 100 msgs/batch with io_uring
+IO_URING_NO_CACHE_FSYNC_RL_SINGLE_PINNED_CONSUMER=$(construct_bench_command "$IGGY_BENCH_CMD" "pinned-consumer" 1 1 1000 1000 2000 tcp "1_consumer_no_cache_fsync_rl_100MB_io_uring" "$IDENTIFIER" "100MB") # 2GB data, 1KB messages, 100 msgs/batch with io_uring
+
 ###############################
 #      Single benchmarks      #
 ###############################
@@ -143,6 +161,12 @@ DOUBLE_SUITES=(
     "$NO_CACHE_RL_SINGLE_PINNED_CONSUMER"
     "$NO_CACHE_FSYNC_RL_SINGLE_PINNED_PRODUCER"
     "$NO_CACHE_FSYNC_RL_SINGLE_PINNED_CONSUMER"
+    "$IO_URING_NO_CACHE_FSYNC_PINNED_PRODUCER"
+    "$IO_URING_NO_CACHE_FSYNC_PINNED_CONSUMER"
+    "$DIRECT_IO_NO_CACHE_FSYNC_PINNED_PRODUCER"
+    "$DIRECT_IO_NO_CACHE_FSYNC_PINNED_CONSUMER"
+    "$IO_URING_NO_CACHE_FSYNC_RL_SINGLE_PINNED_PRODUCER"
+    "$IO_URING_NO_CACHE_FSYNC_RL_SINGLE_PINNED_CONSUMER"
 )
 
 SINGLE_SUITES=(
@@ -0,0 +1,81 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, CREATE_BACKUP_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// `CreateBackup` command is used to create the backup of the server data while the server is running.
+/// It has additional payload:
+/// - `incremental` - if `true`, only the data appended since the latest backup is stored, otherwise the full backup is created.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct CreateBackup {
+    /// If `true`, only the data appended since the latest backup is stored, otherwise the full backup is created.
+    #[serde(default)]
+    pub incremental: bool,
+}
+
+impl Command for CreateBackup {
+    fn code(&self) -> u32 {
+        CREATE_BACKUP_CODE
+    }
+}
+
+impl Validatable<IggyError> for CreateBackup {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for CreateBackup {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1);
+        bytes.put_u8(u8::from(self.incremental));
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<CreateBackup, IggyError> {
+        if bytes.len() != 1 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let incremental = match bytes[0] {
+            0 => false,
+            1 => true,
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        Ok(CreateBackup { incremental })
+    }
+}
+
+impl Display for CreateBackup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.incremental)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = CreateBackup { incremental: true };
+        let bytes = command.to_bytes();
+        assert_eq!(bytes.len(), 1);
+        assert_eq!(bytes[0], 1);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = CreateBackup::from_bytes(Bytes::from_static(&[0])).unwrap();
+        assert!(!command.incremental);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_bytes() {
+        assert!(CreateBackup::from_bytes(Bytes::from_static(&[2])).is_err());
+        assert!(CreateBackup::from_bytes(Bytes::new()).is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_BACKUPS_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetBackups` command is used to get the info about all the backups stored by the server.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct GetBackups {}
+
+impl Command for GetBackups {
+    fn code(&self) -> u32 {
+        GET_BACKUPS_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetBackups {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetBackups {
+    fn to_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetBackups, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(GetBackups {})
+    }
+}
+
+impl Display for GetBackups {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = GetBackups {};
+        let bytes = command.to_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let command = GetBackups::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_backup;
+pub mod get_backups;
+pub mod restore_backup;
@@ -0,0 +1,182 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, RESTORE_BACKUP_CODE};
+use crate::error::IggyError;
+use crate::models::backup::BackupRestoreTarget;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::from_utf8;
+
+const DIRECTORY_TARGET_CODE: u8 = 1;
+const ARCHIVER_TARGET_CODE: u8 = 2;
+
+/// `RestoreBackup` command is used to restore the server data from the backups to the chosen point in time.
+/// It has additional payload:
+/// - `timestamp` - optional point in time to restore the data to, if not provided, the latest backup is restored.
+///   The data (state entries and message batches) created after the timestamp is discarded.
+/// - `target` - destination of the restored data, either the empty directory on the server side or the configured archiver.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RestoreBackup {
+    /// Optional point in time to restore the data to, if not provided, the latest backup is restored.
+    #[serde(default)]
+    pub timestamp: Option<IggyTimestamp>,
+    /// Destination of the restored data, either the empty directory on the server side or the configured archiver.
+    pub target: BackupRestoreTarget,
+}
+
+impl Command for RestoreBackup {
+    fn code(&self) -> u32 {
+        RESTORE_BACKUP_CODE
+    }
+}
+
+impl Default for RestoreBackup {
+    fn default() -> Self {
+        RestoreBackup {
+            timestamp: None,
+            target: BackupRestoreTarget::Directory("restore".to_string()),
+        }
+    }
+}
+
+impl Validatable<IggyError> for RestoreBackup {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let BackupRestoreTarget::Directory(path) = &self.target {
+            if path.trim().is_empty() {
+                return Err(IggyError::InvalidCommand);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RestoreBackup {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u64_le(
+            self.timestamp
+                .map(|timestamp| timestamp.as_micros())
+                .unwrap_or(0),
+        );
+        match &self.target {
+            BackupRestoreTarget::Directory(path) => {
+                bytes.put_u8(DIRECTORY_TARGET_CODE);
+                #[allow(clippy::cast_possible_truncation)]
+                bytes.put_u32_le(path.len() as u32);
+                bytes.put_slice(path.as_bytes());
+            }
+            BackupRestoreTarget::Archiver => {
+                bytes.put_u8(ARCHIVER_TARGET_CODE);
+            }
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RestoreBackup, IggyError> {
+        if bytes.len() < 9 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let timestamp = u64::from_le_bytes(
+            bytes[..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let timestamp = match timestamp {
+            0 => None,
+            timestamp => Some(IggyTimestamp::from(timestamp)),
+        };
+        let target = match bytes[8] {
+            DIRECTORY_TARGET_CODE => {
+                if bytes.len() < 13 {
+                    return Err(IggyError::InvalidCommand);
+                }
+
+                let path_length = u32::from_le_bytes(
+                    bytes[9..13]
+                        .try_into()
+                        .map_err(|_| IggyError::InvalidNumberEncoding)?,
+                ) as usize;
+                if bytes.len() != 13 + path_length {
+                    return Err(IggyError::InvalidCommand);
+                }
+
+                let path = from_utf8(&bytes[13..13 + path_length])
+                    .map_err(|_| IggyError::InvalidUtf8)?
+                    .to_string();
+                BackupRestoreTarget::Directory(path)
+            }
+            ARCHIVER_TARGET_CODE => {
+                if bytes.len() != 9 {
+                    return Err(IggyError::InvalidCommand);
+                }
+
+                BackupRestoreTarget::Archiver
+            }
+            _ => return Err(IggyError::InvalidCommand),
+        };
+
+        Ok(RestoreBackup { timestamp, target })
+    }
+}
+
+impl Display for RestoreBackup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let timestamp = match self.timestamp {
+            Some(timestamp) => timestamp.as_micros().to_string(),
+            None => "latest".to_string(),
+        };
+        write!(f, "{}|{}", timestamp, self.target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_directory_target() {
+        let command = RestoreBackup {
+            timestamp: Some(IggyTimestamp::from(1000)),
+            target: BackupRestoreTarget::Directory("restored".to_string()),
+        };
+
+        let deserialized = RestoreBackup::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_archiver_target() {
+        let command = RestoreBackup {
+            timestamp: None,
+            target: BackupRestoreTarget::Archiver,
+        };
+
+        let bytes = command.to_bytes();
+        assert_eq!(bytes.len(), 9);
+        let deserialized = RestoreBackup::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_target() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u64_le(0);
+        bytes.put_u8(3);
+        assert!(RestoreBackup::from_bytes(bytes.freeze()).is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_empty_directory() {
+        let command = RestoreBackup {
+            timestamp: None,
+            target: BackupRestoreTarget::Directory(" ".to_string()),
+        };
+        assert!(command.validate().is_err());
+    }
+}
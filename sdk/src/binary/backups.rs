@@ -0,0 +1,38 @@
+use crate::backups::create_backup::CreateBackup;
+use crate::backups::get_backups::GetBackups;
+use crate::backups::restore_backup::RestoreBackup;
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::client::BackupClient;
+use crate::error::IggyError;
+use crate::models::backup::{BackupInfo, BackupRestoreTarget};
+use crate::utils::timestamp::IggyTimestamp;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> BackupClient for B {
+    async fn create_backup(&self, incremental: bool) -> Result<BackupInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&CreateBackup { incremental })
+            .await?;
+        mapper::map_backup(response)
+    }
+
+    async fn get_backups(&self) -> Result<Vec<BackupInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetBackups {}).await?;
+        mapper::map_backups(response)
+    }
+
+    async fn restore_backup(
+        &self,
+        timestamp: Option<IggyTimestamp>,
+        target: BackupRestoreTarget,
+    ) -> Result<BackupInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&RestoreBackup { timestamp, target })
+            .await?;
+        mapper::map_backup(response)
+    }
+}
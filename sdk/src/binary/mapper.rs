@@ -1,18 +1,23 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
+use crate::messages::poll_messages::{PolledMessagesFormat, POLLED_BATCH_FORMAT_VERSION};
+use crate::models::backup::{BackupInfo, BackupKind};
 use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::{MessageState, PolledMessage, PolledMessages};
 use crate::models::partition::Partition;
+use crate::models::partitions_merge::{PartitionsMergeInfo, PartitionsMergeStatus};
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::{Schema, SchemaCompatibility, SchemaType, TopicSchemas};
 use crate::models::stats::{CacheMetrics, CacheMetricsKey, Stats};
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
-use crate::models::user_info::{UserInfo, UserInfoDetails};
+use crate::models::topic_template::{TopicDefaults, TopicTemplate};
+use crate::models::user_info::{UserInfo, UserInfoDetails, UserUsage};
 use crate::models::user_status::UserStatus;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::expiry::IggyExpiry;
@@ -28,6 +33,15 @@ const EMPTY_CLIENTS: Vec<ClientInfo> = vec![];
 const EMPTY_USERS: Vec<UserInfo> = vec![];
 const EMPTY_PERSONAL_ACCESS_TOKENS: Vec<PersonalAccessTokenInfo> = vec![];
 const EMPTY_CONSUMER_GROUPS: Vec<ConsumerGroup> = vec![];
+const EMPTY_BACKUPS: Vec<BackupInfo> = vec![];
+const EMPTY_TOPIC_TEMPLATES: Vec<TopicTemplate> = vec![];
+const BACKUP_INFO_SIZE: usize = 37;
+const PARTITIONS_MERGE_INFO_SIZE: usize = 49;
+const TOPIC_DEFAULTS_SIZE: usize = 17;
+const SCHEMA_HEADER_SIZE: usize = 29;
+const TOPIC_SCHEMAS_HEADER_SIZE: usize = 2;
+const POLLED_BATCHES_HEADER_SIZE: usize = 32;
+const BATCH_HEADER_SIZE: usize = 57;
 
 pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
     let process_id = u32::from_le_bytes(
@@ -334,6 +348,52 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
         }
     }
 
+    // Read login protection counters (if they exist)
+    let mut failed_login_attempts = 0;
+    let mut login_lockouts = 0;
+    if current_position + 16 <= payload.len() {
+        failed_login_attempts = u64::from_le_bytes(
+            payload[current_position..current_position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        current_position += 8;
+        login_lockouts = u64::from_le_bytes(
+            payload[current_position..current_position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        current_position += 8;
+    }
+
+    // Read quota violations counter (if it exists)
+    let mut quota_violations = 0;
+    if current_position + 8 <= payload.len() {
+        quota_violations = u64::from_le_bytes(
+            payload[current_position..current_position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        current_position += 8;
+    }
+
+    // Read segment recovery counters (if they exist)
+    let mut recovered_segments = 0;
+    let mut truncated_segment_bytes = 0;
+    if current_position + 16 <= payload.len() {
+        recovered_segments = u64::from_le_bytes(
+            payload[current_position..current_position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        current_position += 8;
+        truncated_segment_bytes = u64::from_le_bytes(
+            payload[current_position..current_position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+    }
+
     Ok(Stats {
         process_id,
         cpu_usage,
@@ -360,6 +420,11 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
         iggy_server_version,
         iggy_server_semver,
         cache_metrics,
+        failed_login_attempts,
+        login_lockouts,
+        quota_violations,
+        recovered_segments,
+        truncated_segment_bytes,
     })
 }
 
@@ -389,14 +454,43 @@ pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyErr
 pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
     let (user, position) = map_to_user_info(payload.clone(), 0)?;
     let has_permissions = payload[position];
-    let permissions = if has_permissions == 1 {
+    let (permissions, mut position) = if has_permissions == 1 {
         let permissions_length = u32::from_le_bytes(
             payload[position + 1..position + 5]
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         ) as usize;
         let permissions = payload.slice(position + 5..position + 5 + permissions_length);
-        Some(Permissions::from_bytes(permissions)?)
+        (
+            Some(Permissions::from_bytes(permissions)?),
+            position + 5 + permissions_length,
+        )
+    } else {
+        (None, position + 4)
+    };
+
+    // The usage is optional, as it's not returned by the older servers.
+    let usage = if payload.len() > position && payload[position] == 1 {
+        position += 1;
+        let mut values = [0u32; 6];
+        for value in values.iter_mut() {
+            *value = u32::from_le_bytes(
+                payload
+                    .get(position..position + 4)
+                    .ok_or(IggyError::InvalidNumberEncoding)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            position += 4;
+        }
+        Some(UserUsage {
+            streams_count: values[0],
+            topics_count: values[1],
+            partitions_count: values[2],
+            max_streams: values[3],
+            max_topics: values[4],
+            max_partitions: values[5],
+        })
     } else {
         None
     };
@@ -407,6 +501,7 @@ pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
         status: user.status,
         username: user.username,
         permissions,
+        usage,
     };
     Ok(user)
 }
@@ -447,6 +542,168 @@ pub fn map_personal_access_tokens(
     Ok(personal_access_tokens)
 }
 
+pub fn map_backup(payload: Bytes) -> Result<BackupInfo, IggyError> {
+    let (backup, _) = map_to_backup_info(payload, 0)?;
+    Ok(backup)
+}
+
+pub fn map_backups(payload: Bytes) -> Result<Vec<BackupInfo>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_BACKUPS);
+    }
+
+    let mut backups = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (backup, read_bytes) = map_to_backup_info(payload.clone(), position)?;
+        backups.push(backup);
+        position += read_bytes;
+    }
+    backups.sort_by_key(|backup| backup.id);
+    Ok(backups)
+}
+
+pub fn map_partitions_merge(payload: Bytes) -> Result<PartitionsMergeInfo, IggyError> {
+    if payload.len() < PARTITIONS_MERGE_INFO_SIZE {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let stream_id = u32::from_le_bytes(
+        payload[..4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let topic_id = u32::from_le_bytes(
+        payload[4..8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let status = PartitionsMergeStatus::from_code(payload[8])?;
+    let partitions_count = u32::from_le_bytes(
+        payload[9..13]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let merged_partitions = u32::from_le_bytes(
+        payload[13..17]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let messages_count = u64::from_le_bytes(
+        payload[17..25]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let merged_messages = u64::from_le_bytes(
+        payload[25..33]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let started_at = u64::from_le_bytes(
+        payload[33..41]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let finished_at = u64::from_le_bytes(
+        payload[41..49]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let finished_at = match finished_at {
+        0 => None,
+        finished_at => Some(finished_at.into()),
+    };
+    Ok(PartitionsMergeInfo {
+        stream_id,
+        topic_id,
+        status,
+        partitions_count,
+        merged_partitions,
+        messages_count,
+        merged_messages,
+        started_at,
+        finished_at,
+    })
+}
+
+pub fn map_topic_template(payload: Bytes) -> Result<TopicTemplate, IggyError> {
+    let (template, _) = map_to_topic_template(payload, 0)?;
+    Ok(template)
+}
+
+pub fn map_topic_templates(payload: Bytes) -> Result<Vec<TopicTemplate>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_TOPIC_TEMPLATES);
+    }
+
+    let mut templates = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (template, read_bytes) = map_to_topic_template(payload.clone(), position)?;
+        templates.push(template);
+        position += read_bytes;
+    }
+    templates.sort_by(|x, y| x.name.cmp(&y.name));
+    Ok(templates)
+}
+
+pub fn map_schema(payload: Bytes) -> Result<Schema, IggyError> {
+    let (schema, _) = map_to_schema(payload, 0)?;
+    Ok(schema)
+}
+
+pub fn map_topic_schemas(payload: Bytes) -> Result<TopicSchemas, IggyError> {
+    if payload.len() < TOPIC_SCHEMAS_HEADER_SIZE {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let compatibility = SchemaCompatibility::from_code(payload[0])?;
+    let validate_payloads = payload[1] == 1;
+    let mut schemas = Vec::new();
+    let length = payload.len();
+    let mut position = TOPIC_SCHEMAS_HEADER_SIZE;
+    while position < length {
+        let (schema, read_bytes) = map_to_schema(payload.clone(), position)?;
+        schemas.push(schema);
+        position += read_bytes;
+    }
+    schemas.sort_by_key(|schema| schema.version);
+    Ok(TopicSchemas {
+        compatibility,
+        validate_payloads,
+        schemas,
+    })
+}
+
+pub fn map_topic_defaults(payload: Bytes) -> Result<TopicDefaults, IggyError> {
+    if payload.len() < TOPIC_DEFAULTS_SIZE {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let message_expiry = u64::from_le_bytes(
+        payload[..8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let max_topic_size = u64::from_le_bytes(
+        payload[8..16]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let replication_factor = match payload[16] {
+        0 => None,
+        factor => Some(factor),
+    };
+    Ok(TopicDefaults {
+        message_expiry: message_expiry.into(),
+        max_topic_size: max_topic_size.into(),
+        replication_factor,
+    })
+}
+
 pub fn map_identity_info(payload: Bytes) -> Result<IdentityInfo, IggyError> {
     let user_id = u32::from_le_bytes(
         payload[..4]
@@ -561,7 +818,7 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
-        let state = MessageState::from_code(payload[position + 8])?;
+        let (state, has_key) = MessageState::from_code_with_key_flag(payload[position + 8])?;
         let timestamp = u64::from_le_bytes(
             payload[position + 9..position + 17]
                 .try_into()
@@ -590,6 +847,14 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
             None
         };
         position += headers_length as usize;
+        let key = if has_key {
+            let key_length = payload[position + 41] as usize;
+            let key = payload.slice(position + 42..position + 42 + key_length);
+            position += 1 + key_length;
+            Some(key)
+        } else {
+            None
+        };
         let message_length = u32::from_le_bytes(
             payload[position + 41..position + 45]
                 .try_into()
@@ -610,6 +875,7 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
             checksum,
             id,
             headers,
+            key,
             length: IggyByteSize::from(message_length as u64),
             payload: Bytes::from(payload),
         });
@@ -627,6 +893,165 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
     })
 }
 
+pub fn map_polled_messages_with_format(payload: Bytes) -> Result<PolledMessages, IggyError> {
+    if payload.is_empty() {
+        return map_polled_messages(payload);
+    }
+
+    match PolledMessagesFormat::from_code(payload[0])
+        .map_err(|_| IggyError::InvalidBytesResponse)?
+    {
+        PolledMessagesFormat::Messages => map_polled_messages(payload.slice(1..)),
+        PolledMessagesFormat::Batches => map_polled_message_batches(payload.slice(1..)),
+    }
+}
+
+// The batches are sent as stored in the segment file, so the first and the last ones might contain
+// the messages outside the polled offsets range, which are skipped. The header of the batches depends
+// on the batch format version of the server, so the batches in any other version are rejected.
+fn map_polled_message_batches(payload: Bytes) -> Result<PolledMessages, IggyError> {
+    if payload.len() < 1 + POLLED_BATCHES_HEADER_SIZE {
+        return Err(IggyError::InvalidBytesResponse);
+    }
+
+    if payload[0] != POLLED_BATCH_FORMAT_VERSION {
+        return Err(IggyError::InvalidBatchFormatVersion(payload[0]));
+    }
+
+    let payload = payload.slice(1..);
+
+    let length = payload.len();
+    let partition_id = u32::from_le_bytes(
+        payload[..4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let current_offset = u64::from_le_bytes(
+        payload[4..12]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let messages_count = u32::from_le_bytes(
+        payload[12..16]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let start_offset = u64::from_le_bytes(
+        payload[16..24]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let end_offset = u64::from_le_bytes(
+        payload[24..32]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let mut position = POLLED_BATCHES_HEADER_SIZE;
+    let mut messages = Vec::with_capacity(messages_count as usize);
+    while position + BATCH_HEADER_SIZE <= length {
+        // Base offset (8 bytes) is followed by the batch length, the rest of the header isn't needed to read the messages.
+        let batch_length = u32::from_le_bytes(
+            payload[position + 8..position + 12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += BATCH_HEADER_SIZE;
+        let batch_end = position + batch_length as usize;
+        if batch_end > length {
+            return Err(IggyError::InvalidBytesResponse);
+        }
+
+        while position + 4 <= batch_end {
+            let message_length = u32::from_le_bytes(
+                payload[position..position + 4]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            position += 4;
+            if message_length < 41 || position + message_length > batch_end {
+                return Err(IggyError::InvalidBytesResponse);
+            }
+
+            let message = payload.slice(position..position + message_length);
+            position += message_length;
+            let offset = u64::from_le_bytes(
+                message[..8]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            if offset < start_offset || offset > end_offset {
+                continue;
+            }
+
+            let (state, has_key) = MessageState::from_code_with_key_flag(message[8])?;
+            let timestamp = u64::from_le_bytes(
+                message[9..17]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let id = u128::from_le_bytes(
+                message[17..33]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let checksum = u32::from_le_bytes(
+                message[33..37]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let headers_length = u32::from_le_bytes(
+                message[37..41]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            if 41 + headers_length > message_length {
+                return Err(IggyError::InvalidBytesResponse);
+            }
+
+            let headers = if headers_length > 0 {
+                Some(HashMap::from_bytes(message.slice(41..41 + headers_length))?)
+            } else {
+                None
+            };
+            let mut payload_position = 41 + headers_length;
+            let key = if has_key {
+                let key_length = *message
+                    .get(payload_position)
+                    .ok_or(IggyError::InvalidBytesResponse)?
+                    as usize;
+                if payload_position + 1 + key_length > message_length {
+                    return Err(IggyError::InvalidBytesResponse);
+                }
+
+                let key = message.slice(payload_position + 1..payload_position + 1 + key_length);
+                payload_position += 1 + key_length;
+                Some(key)
+            } else {
+                None
+            };
+            let payload = message.slice(payload_position..);
+            messages.push(PolledMessage {
+                offset,
+                timestamp,
+                state,
+                checksum,
+                id,
+                headers,
+                key,
+                length: IggyByteSize::from(payload.len() as u64),
+                payload,
+            });
+        }
+        position = batch_end;
+    }
+
+    Ok(PolledMessages {
+        partition_id,
+        current_offset,
+        messages,
+    })
+}
+
 pub fn map_streams(payload: Bytes) -> Result<Vec<Stream>, IggyError> {
     if payload.is_empty() {
         return Ok(EMPTY_STREAMS);
@@ -1064,6 +1489,149 @@ fn map_to_user_info(payload: Bytes, position: usize) -> Result<(UserInfo, usize)
     ))
 }
 
+fn map_to_backup_info(payload: Bytes, position: usize) -> Result<(BackupInfo, usize), IggyError> {
+    if payload.len() < position + BACKUP_INFO_SIZE {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let id = u64::from_le_bytes(
+        payload[position..position + 8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let kind = BackupKind::from_code(payload[position + 8])?;
+    let parent_id = u64::from_le_bytes(
+        payload[position + 9..position + 17]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let parent_id = match parent_id {
+        0 => None,
+        parent_id => Some(parent_id),
+    };
+    let created_at = u64::from_le_bytes(
+        payload[position + 17..position + 25]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let files_count = u32::from_le_bytes(
+        payload[position + 25..position + 29]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let size = u64::from_le_bytes(
+        payload[position + 29..position + 37]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    Ok((
+        BackupInfo {
+            id,
+            kind,
+            parent_id,
+            created_at,
+            files_count,
+            size,
+        },
+        BACKUP_INFO_SIZE,
+    ))
+}
+
+fn map_to_topic_template(
+    payload: Bytes,
+    position: usize,
+) -> Result<(TopicTemplate, usize), IggyError> {
+    if payload.len() < position + 23 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let partitions_count = u32::from_le_bytes(
+        payload[position..position + 4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let compression_algorithm = CompressionAlgorithm::from_code(payload[position + 4])?;
+    let message_expiry = u64::from_le_bytes(
+        payload[position + 5..position + 13]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let max_topic_size = u64::from_le_bytes(
+        payload[position + 13..position + 21]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let replication_factor = match payload[position + 21] {
+        0 => None,
+        factor => Some(factor),
+    };
+    let name_length = payload[position + 22] as usize;
+    let name = from_utf8(
+        payload
+            .get(position + 23..position + 23 + name_length)
+            .ok_or(IggyError::InvalidCommand)?,
+    )
+    .map_err(|_| IggyError::InvalidUtf8)?
+    .to_string();
+    Ok((
+        TopicTemplate {
+            name,
+            partitions_count,
+            compression_algorithm,
+            message_expiry: message_expiry.into(),
+            max_topic_size: max_topic_size.into(),
+            replication_factor,
+        },
+        23 + name_length,
+    ))
+}
+
+fn map_to_schema(payload: Bytes, position: usize) -> Result<(Schema, usize), IggyError> {
+    if payload.len() < position + SCHEMA_HEADER_SIZE {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let read_u32 = |offset: usize| -> Result<u32, IggyError> {
+        Ok(u32::from_le_bytes(
+            payload[position + offset..position + offset + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ))
+    };
+    let id = read_u32(0)?;
+    let stream_id = read_u32(4)?;
+    let topic_id = read_u32(8)?;
+    let version = read_u32(12)?;
+    let schema_type = SchemaType::from_code(payload[position + 16])?;
+    let created_at = u64::from_le_bytes(
+        payload[position + 17..position + 25]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let definition_length = read_u32(25)? as usize;
+    let definition = from_utf8(
+        payload
+            .get(position + SCHEMA_HEADER_SIZE..position + SCHEMA_HEADER_SIZE + definition_length)
+            .ok_or(IggyError::InvalidCommand)?,
+    )
+    .map_err(|_| IggyError::InvalidUtf8)?
+    .to_string();
+    Ok((
+        Schema {
+            id,
+            stream_id,
+            topic_id,
+            version,
+            schema_type,
+            definition,
+            created_at: created_at.into(),
+        },
+        SCHEMA_HEADER_SIZE + definition_length,
+    ))
+}
+
 fn map_to_pat_info(
     payload: Bytes,
     position: usize,
@@ -1082,6 +1650,29 @@ fn map_to_pat_info(
         0 => None,
         value => Some(value.into()),
     };
-    let read_bytes = 1 + name_length as usize + 8;
-    Ok((PersonalAccessTokenInfo { name, expiry_at }, read_bytes))
+    let position = position + 8;
+    let has_permissions = payload[position];
+    let (permissions, permissions_length) = if has_permissions == 1 {
+        let permissions_length = u32::from_le_bytes(
+            payload[position + 1..position + 5]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        let permissions = payload.slice(position + 5..position + 5 + permissions_length);
+        (
+            Some(Permissions::from_bytes(permissions)?),
+            4 + permissions_length,
+        )
+    } else {
+        (None, 0)
+    };
+    let read_bytes = 1 + name_length as usize + 8 + 1 + permissions_length;
+    Ok((
+        PersonalAccessTokenInfo {
+            name,
+            expiry_at,
+            permissions,
+        },
+        read_bytes,
+    ))
 }
@@ -6,7 +6,7 @@ use crate::consumer::Consumer;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
-use crate::messages::poll_messages::PollingStrategy;
+use crate::messages::poll_messages::{PolledMessagesFormat, PollingStrategy};
 use crate::messages::send_messages::{Message, Partitioning};
 use crate::messages::{poll_messages, send_messages};
 use crate::models::messages::PolledMessages;
@@ -24,6 +24,7 @@ impl<B: BinaryClient> MessageClient for B {
         auto_commit: bool,
     ) -> Result<PolledMessages, IggyError> {
         fail_if_not_authenticated(self).await?;
+        let format = self.get_polled_messages_format();
         let response = self
             .send_raw_with_response(
                 POLL_MESSAGES_CODE,
@@ -35,10 +36,14 @@ impl<B: BinaryClient> MessageClient for B {
                     strategy,
                     count,
                     auto_commit,
+                    format,
                 ),
             )
             .await?;
-        mapper::map_polled_messages(response)
+        match format {
+            PolledMessagesFormat::Messages => mapper::map_polled_messages(response),
+            PolledMessagesFormat::Batches => mapper::map_polled_messages_with_format(response),
+        }
     }
 
     async fn send_messages(
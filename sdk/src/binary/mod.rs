@@ -1,11 +1,14 @@
 use crate::command::Command;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
+use crate::messages::poll_messages::PolledMessagesFormat;
 use crate::utils::duration::IggyDuration;
 use async_trait::async_trait;
 use bytes::Bytes;
 use derive_more::Display;
 
+#[allow(deprecated)]
+pub mod backups;
 #[allow(deprecated)]
 pub mod binary_client;
 #[allow(deprecated)]
@@ -20,10 +23,14 @@ pub mod partitions;
 #[allow(deprecated)]
 pub mod personal_access_tokens;
 #[allow(deprecated)]
+pub mod schemas;
+#[allow(deprecated)]
 pub mod streams;
 #[allow(deprecated)]
 pub mod system;
 #[allow(deprecated)]
+pub mod topic_templates;
+#[allow(deprecated)]
 pub mod topics;
 #[allow(deprecated)]
 pub mod users;
@@ -62,6 +69,10 @@ pub trait BinaryTransport {
     async fn send_with_response<T: Command>(&self, command: &T) -> Result<Bytes, IggyError>;
     async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError>;
     fn get_heartbeat_interval(&self) -> IggyDuration;
+    /// Gets the format of the polled messages accepted by the client.
+    fn get_polled_messages_format(&self) -> PolledMessagesFormat {
+        PolledMessagesFormat::Messages
+    }
 }
 
 async fn fail_if_not_authenticated<T: BinaryTransport>(transport: &T) -> Result<(), IggyError> {
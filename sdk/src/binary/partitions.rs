@@ -1,11 +1,14 @@
 #[allow(deprecated)]
 use crate::binary::binary_client::BinaryClient;
-use crate::binary::fail_if_not_authenticated;
+use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::PartitionClient;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::partitions_merge::PartitionsMergeInfo;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_partitions_merge::GetPartitionsMerge;
+use crate::partitions::merge_partitions::MergePartitions;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> PartitionClient for B {
@@ -40,4 +43,40 @@ impl<B: BinaryClient> PartitionClient for B {
         .await?;
         Ok(())
     }
+
+    async fn merge_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<PartitionsMergeInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&MergePartitions {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partitions_count,
+            })
+            .await?;
+        mapper::map_partitions_merge(response)
+    }
+
+    async fn get_partitions_merge(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Option<PartitionsMergeInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetPartitionsMerge {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            })
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_partitions_merge(response).map(Some)
+    }
 }
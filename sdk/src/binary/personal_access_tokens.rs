@@ -3,6 +3,7 @@ use crate::binary::{fail_if_not_authenticated, mapper, ClientState};
 use crate::client::PersonalAccessTokenClient;
 use crate::error::IggyError;
 use crate::models::identity_info::IdentityInfo;
+use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
@@ -22,12 +23,14 @@ impl<B: BinaryClient> PersonalAccessTokenClient for B {
         &self,
         name: &str,
         expiry: PersonalAccessTokenExpiry,
+        permissions: Option<Permissions>,
     ) -> Result<RawPersonalAccessToken, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
             .send_with_response(&CreatePersonalAccessToken {
                 name: name.to_string(),
                 expiry,
+                permissions,
             })
             .await?;
         mapper::map_raw_pat(response)
@@ -0,0 +1,114 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::client::SchemaClient;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::schema::{Schema, SchemaCompatibility, SchemaType, TopicSchemas};
+use crate::schemas::delete_topic_schema::DeleteTopicSchema;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_topic_schema::GetTopicSchema;
+use crate::schemas::get_topic_schemas::GetTopicSchemas;
+use crate::schemas::register_topic_schema::RegisterTopicSchema;
+use crate::schemas::update_topic_schema_settings::UpdateTopicSchemaSettings;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> SchemaClient for B {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetSchema { schema_id }).await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_schema(response).map(Some)
+    }
+
+    async fn get_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: Option<u32>,
+    ) -> Result<Option<Schema>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetTopicSchema {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                version,
+            })
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_schema(response).map(Some)
+    }
+
+    async fn get_topic_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetTopicSchemas {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            })
+            .await?;
+        mapper::map_topic_schemas(response)
+    }
+
+    async fn register_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        definition: &str,
+    ) -> Result<Schema, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&RegisterTopicSchema {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                schema_type,
+                definition: definition.to_string(),
+            })
+            .await?;
+        mapper::map_schema(response)
+    }
+
+    async fn delete_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: u32,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DeleteTopicSchema {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            version,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_schema_settings(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+        validate_payloads: bool,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateTopicSchemaSettings {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            compatibility,
+            validate_payloads,
+        })
+        .await?;
+        Ok(())
+    }
+}
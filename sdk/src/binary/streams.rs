@@ -4,12 +4,15 @@ use crate::client::StreamClient;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::models::stream::{Stream, StreamDetails};
+use crate::models::topic_template::TopicDefaults;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
+use crate::streams::get_stream_topic_defaults::GetStreamTopicDefaults;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::streams::update_stream_topic_defaults::UpdateStreamTopicDefaults;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> StreamClient for B {
@@ -75,4 +78,33 @@ impl<B: BinaryClient> StreamClient for B {
         .await?;
         Ok(())
     }
+
+    async fn get_stream_topic_defaults(
+        &self,
+        stream_id: &Identifier,
+    ) -> Result<TopicDefaults, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetStreamTopicDefaults {
+                stream_id: stream_id.clone(),
+            })
+            .await?;
+        mapper::map_topic_defaults(response)
+    }
+
+    async fn update_stream_topic_defaults(
+        &self,
+        stream_id: &Identifier,
+        topic_defaults: TopicDefaults,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateStreamTopicDefaults {
+            stream_id: stream_id.clone(),
+            message_expiry: topic_defaults.message_expiry,
+            max_topic_size: topic_defaults.max_topic_size,
+            replication_factor: topic_defaults.replication_factor,
+        })
+        .await?;
+        Ok(())
+    }
 }
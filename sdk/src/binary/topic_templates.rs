@@ -0,0 +1,55 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::client::TopicTemplateClient;
+use crate::error::IggyError;
+use crate::models::topic_template::TopicTemplate;
+use crate::topic_templates::create_topic_template::CreateTopicTemplate;
+use crate::topic_templates::delete_topic_template::DeleteTopicTemplate;
+use crate::topic_templates::get_topic_template::GetTopicTemplate;
+use crate::topic_templates::get_topic_templates::GetTopicTemplates;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> TopicTemplateClient for B {
+    async fn get_topic_template(&self, name: &str) -> Result<Option<TopicTemplate>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetTopicTemplate {
+                name: name.to_string(),
+            })
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_topic_template(response).map(Some)
+    }
+
+    async fn get_topic_templates(&self) -> Result<Vec<TopicTemplate>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetTopicTemplates {}).await?;
+        mapper::map_topic_templates(response)
+    }
+
+    async fn create_topic_template(&self, template: TopicTemplate) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&CreateTopicTemplate {
+            name: template.name,
+            partitions_count: template.partitions_count,
+            compression_algorithm: template.compression_algorithm,
+            message_expiry: template.message_expiry,
+            max_topic_size: template.max_topic_size,
+            replication_factor: template.replication_factor,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_topic_template(&self, name: &str) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DeleteTopicTemplate {
+            name: name.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+}
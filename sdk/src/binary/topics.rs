@@ -67,6 +67,31 @@ impl<B: BinaryClient> TopicClient for B {
                 topic_id,
                 message_expiry,
                 max_topic_size,
+                template: None,
+            })
+            .await?;
+        mapper::map_topic(response)
+    }
+
+    async fn create_topic_from_template(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        template: &str,
+        topic_id: Option<u32>,
+    ) -> Result<TopicDetails, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&CreateTopic {
+                stream_id: stream_id.clone(),
+                name: name.to_string(),
+                partitions_count: 0,
+                compression_algorithm: CompressionAlgorithm::None,
+                replication_factor: None,
+                topic_id,
+                message_expiry: IggyExpiry::ServerDefault,
+                max_topic_size: MaxTopicSize::ServerDefault,
+                template: Some(template.to_string()),
             })
             .await?;
         mapper::map_topic(response)
@@ -15,6 +15,7 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
 
@@ -114,6 +115,15 @@ impl<B: BinaryClient> UserClient for B {
         Ok(())
     }
 
+    async fn unlock_user(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UnlockUser {
+            user_id: user_id.clone(),
+        })
+        .await?;
+        Ok(())
+    }
+
     async fn login_user(&self, username: &str, password: &str) -> Result<IdentityInfo, IggyError> {
         let response = self
             .send_with_response(&LoginUser {
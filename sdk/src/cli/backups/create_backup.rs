@@ -0,0 +1,47 @@
+use crate::backups::create_backup::CreateBackup;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct CreateBackupCmd {
+    create_backup: CreateBackup,
+}
+
+impl CreateBackupCmd {
+    pub fn new(incremental: bool) -> Self {
+        Self {
+            create_backup: CreateBackup { incremental },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CreateBackupCmd {
+    fn explain(&self) -> String {
+        let kind = match self.create_backup.incremental {
+            true => "incremental",
+            false => "full",
+        };
+        format!("create {kind} backup")
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let backup = client
+            .create_backup(self.create_backup.incremental)
+            .await
+            .with_context(|| String::from("Problem creating backup"))?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Backup with ID: {} ({}) created at: {}, files: {}, size: {}",
+            backup.id,
+            backup.kind,
+            backup.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+            backup.files_count,
+            backup.size.as_human_string(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::backups::get_backups::GetBackups;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub enum GetBackupsOutput {
+    Table,
+    List,
+}
+
+pub struct GetBackupsCmd {
+    _get_backups: GetBackups,
+    output: GetBackupsOutput,
+}
+
+impl GetBackupsCmd {
+    pub fn new(output: GetBackupsOutput) -> Self {
+        Self {
+            _get_backups: GetBackups {},
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetBackupsCmd {
+    fn explain(&self) -> String {
+        let mode = match self.output {
+            GetBackupsOutput::Table => "table",
+            GetBackupsOutput::List => "list",
+        };
+        format!("list backups in {mode} mode")
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let backups = client
+            .get_backups()
+            .await
+            .with_context(|| String::from("Problem getting list of backups"))?;
+
+        match self.output {
+            GetBackupsOutput::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec!["ID", "Kind", "Parent ID", "Created", "Files", "Size"]);
+
+                backups.iter().for_each(|backup| {
+                    table.add_row(vec![
+                        format!("{}", backup.id),
+                        format!("{}", backup.kind),
+                        match backup.parent_id {
+                            None => String::from("-"),
+                            Some(parent_id) => format!("{parent_id}"),
+                        },
+                        backup.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+                        format!("{}", backup.files_count),
+                        backup.size.as_human_string(),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetBackupsOutput::List => {
+                backups.iter().for_each(|backup| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}|{}|{}|{}",
+                        backup.id,
+                        backup.kind,
+                        match backup.parent_id {
+                            None => String::from("-"),
+                            Some(parent_id) => format!("{parent_id}"),
+                        },
+                        backup.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+                        backup.files_count,
+                        backup.size.as_human_string(),
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
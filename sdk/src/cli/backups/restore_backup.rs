@@ -0,0 +1,58 @@
+use crate::backups::restore_backup::RestoreBackup;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::models::backup::BackupRestoreTarget;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct RestoreBackupCmd {
+    restore_backup: RestoreBackup,
+}
+
+impl RestoreBackupCmd {
+    pub fn new(timestamp: Option<IggyTimestamp>, target: BackupRestoreTarget) -> Self {
+        Self {
+            restore_backup: RestoreBackup { timestamp, target },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RestoreBackupCmd {
+    fn explain(&self) -> String {
+        let point_in_time = match self.restore_backup.timestamp {
+            Some(timestamp) => timestamp.to_local_string("%Y-%m-%d %H:%M:%S"),
+            None => String::from("latest backup"),
+        };
+        format!(
+            "restore backup to point in time: {point_in_time} using target: {}",
+            self.restore_backup.target
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let backup = client
+            .restore_backup(
+                self.restore_backup.timestamp,
+                self.restore_backup.target.clone(),
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem restoring backup using target: {}",
+                    self.restore_backup.target
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Backup with ID: {} created at: {} restored using target: {}",
+            backup.id,
+            backup.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+            self.restore_backup.target,
+        );
+
+        Ok(())
+    }
+}
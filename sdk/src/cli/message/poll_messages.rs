@@ -3,7 +3,7 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer::Consumer;
 use crate::identifier::Identifier;
-use crate::messages::poll_messages::{PollMessages, PollingStrategy};
+use crate::messages::poll_messages::{PollMessages, PolledMessagesFormat, PollingStrategy};
 use crate::messages::send_messages::Message;
 use crate::models::header::{HeaderKey, HeaderKind};
 use crate::models::messages::PolledMessages;
@@ -56,6 +56,7 @@ impl PollMessagesCmd {
                 strategy,
                 count: message_count,
                 auto_commit,
+                format: PolledMessagesFormat::Messages,
             },
             show_headers,
             output_file,
@@ -87,6 +88,7 @@ impl PollMessagesCmd {
             Cell::new("Offset"),
             Cell::new("Timestamp"),
             Cell::new("ID"),
+            Cell::new("Key"),
             Cell::new("Length"),
             Cell::new("Payload"),
         ];
@@ -113,6 +115,11 @@ impl PollMessagesCmd {
                     format!("{}", message.offset),
                     IggyTimestamp::from(message.timestamp).to_local_string("%Y-%m-%d %H:%M:%S%.6f"),
                     format!("{}", message.id),
+                    message
+                        .key
+                        .as_ref()
+                        .map(|key| String::from_utf8_lossy(key).to_string())
+                        .unwrap_or_default(),
                     format!("{}", message.payload.len()),
                     String::from_utf8_lossy(&message.payload).to_string(),
                 ];
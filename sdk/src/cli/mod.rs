@@ -1,3 +1,4 @@
+pub mod backups;
 pub mod client;
 pub mod consumer_group;
 pub mod consumer_offset;
@@ -7,6 +8,7 @@ pub mod partitions;
 pub mod personal_access_tokens;
 pub mod streams;
 pub mod system;
+pub mod topic_templates;
 pub mod topics;
 pub mod users;
 pub mod utils;
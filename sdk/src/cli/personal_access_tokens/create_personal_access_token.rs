@@ -1,5 +1,6 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::models::permissions::Permissions;
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 use anyhow::Context;
@@ -22,6 +23,7 @@ impl CreatePersonalAccessTokenCmd {
         quiet_mode: bool,
         store_token: bool,
         server_address: String,
+        permissions: Option<Permissions>,
     ) -> Self {
         Self {
             create_token: CreatePersonalAccessToken {
@@ -30,6 +32,7 @@ impl CreatePersonalAccessTokenCmd {
                     None => PersonalAccessTokenExpiry::NeverExpire,
                     Some(value) => *value,
                 },
+                permissions,
             },
             token_expiry: pat_expiry,
             quiet_mode,
@@ -46,15 +49,23 @@ impl CliCommand for CreatePersonalAccessTokenCmd {
             Some(value) => format!("token expire time: {}", value),
             None => String::from("without token expire time"),
         };
+        let permissions_text = match &self.create_token.permissions {
+            Some(_) => " restricted to the provided permissions",
+            None => "",
+        };
         format!(
-            "create personal access token with name: {} and {}",
-            self.create_token.name, expiry_text
+            "create personal access token with name: {} and {}{}",
+            self.create_token.name, expiry_text, permissions_text
         )
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         let token = client
-            .create_personal_access_token(&self.create_token.name, self.create_token.expiry)
+            .create_personal_access_token(
+                &self.create_token.name,
+                self.create_token.expiry,
+                self.create_token.permissions.clone(),
+            )
             .await
             .with_context(|| {
                 format!(
@@ -1,5 +1,6 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::models::permissions::Permissions;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -45,7 +46,7 @@ impl CliCommand for GetPersonalAccessTokensCmd {
             GetPersonalAccessTokensOutput::Table => {
                 let mut table = Table::new();
 
-                table.set_header(vec!["Name", "Token Expiry Time"]);
+                table.set_header(vec!["Name", "Token Expiry Time", "Permissions"]);
 
                 tokens.iter().for_each(|token| {
                     table.add_row(vec![
@@ -54,6 +55,7 @@ impl CliCommand for GetPersonalAccessTokensCmd {
                             None => String::from("unlimited"),
                             Some(value) => value.to_local_string("%Y-%m-%d %H:%M:%S"),
                         },
+                        format_permissions(&token.permissions),
                     ]);
                 });
 
@@ -62,12 +64,13 @@ impl CliCommand for GetPersonalAccessTokensCmd {
             GetPersonalAccessTokensOutput::List => {
                 tokens.iter().for_each(|token| {
                     event!(target: PRINT_TARGET, Level::INFO,
-                        "{}|{}",
+                        "{}|{}|{}",
                         token.name,
                         match token.expiry_at {
                             None => String::from("unlimited"),
                             Some(value) => value.to_local_string("%Y-%m-%d %H:%M:%S"),
                         },
+                        format_permissions(&token.permissions),
                     );
                 });
             }
@@ -76,3 +79,76 @@ impl CliCommand for GetPersonalAccessTokensCmd {
         Ok(())
     }
 }
+
+/// Formats the permissions of the token as a compact, single line list of the granted permissions.
+fn format_permissions(permissions: &Option<Permissions>) -> String {
+    let Some(permissions) = permissions else {
+        return String::from("all user permissions");
+    };
+
+    let global = &permissions.global;
+    let mut scopes = vec![format_granted(
+        "global",
+        &[
+            ("manage_servers", global.manage_servers),
+            ("read_servers", global.read_servers),
+            ("manage_users", global.manage_users),
+            ("read_users", global.read_users),
+            ("manage_streams", global.manage_streams),
+            ("read_streams", global.read_streams),
+            ("manage_topics", global.manage_topics),
+            ("read_topics", global.read_topics),
+            ("poll_messages", global.poll_messages),
+            ("send_messages", global.send_messages),
+        ],
+    )];
+
+    if let Some(streams) = &permissions.streams {
+        let mut streams = streams.iter().collect::<Vec<_>>();
+        streams.sort_by_key(|(stream_id, _)| **stream_id);
+        for (stream_id, stream) in streams {
+            scopes.push(format_granted(
+                &format!("stream {stream_id}"),
+                &[
+                    ("manage_stream", stream.manage_stream),
+                    ("read_stream", stream.read_stream),
+                    ("manage_topics", stream.manage_topics),
+                    ("read_topics", stream.read_topics),
+                    ("poll_messages", stream.poll_messages),
+                    ("send_messages", stream.send_messages),
+                ],
+            ));
+
+            if let Some(topics) = &stream.topics {
+                let mut topics = topics.iter().collect::<Vec<_>>();
+                topics.sort_by_key(|(topic_id, _)| **topic_id);
+                for (topic_id, topic) in topics {
+                    scopes.push(format_granted(
+                        &format!("stream {stream_id} topic {topic_id}"),
+                        &[
+                            ("manage_topic", topic.manage_topic),
+                            ("read_topic", topic.read_topic),
+                            ("poll_messages", topic.poll_messages),
+                            ("send_messages", topic.send_messages),
+                        ],
+                    ));
+                }
+            }
+        }
+    }
+
+    scopes.join("; ")
+}
+
+fn format_granted(scope: &str, permissions: &[(&str, bool)]) -> String {
+    let granted = permissions
+        .iter()
+        .filter(|(_, granted)| *granted)
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+    if granted.is_empty() {
+        return format!("{scope}: none");
+    }
+
+    format!("{scope}: {}", granted.join(", "))
+}
@@ -0,0 +1,63 @@
+use crate::cli::topic_templates::create_topic_template::format_replication_factor;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::streams::get_stream_topic_defaults::GetStreamTopicDefaults;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetStreamTopicDefaultsCmd {
+    get_topic_defaults: GetStreamTopicDefaults,
+}
+
+impl GetStreamTopicDefaultsCmd {
+    pub fn new(stream_id: Identifier) -> Self {
+        Self {
+            get_topic_defaults: GetStreamTopicDefaults { stream_id },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetStreamTopicDefaultsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get topic defaults of stream with ID: {}",
+            self.get_topic_defaults.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let topic_defaults = client
+            .get_stream_topic_defaults(&self.get_topic_defaults.stream_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic defaults of stream with ID: {}",
+                    self.get_topic_defaults.stream_id
+                )
+            })?;
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec![
+            "Message expiry",
+            format!("{}", topic_defaults.message_expiry).as_str(),
+        ]);
+        table.add_row(vec![
+            "Max topic size",
+            format!("{}", topic_defaults.max_topic_size).as_str(),
+        ]);
+        table.add_row(vec![
+            "Replication factor",
+            format_replication_factor(topic_defaults.replication_factor).as_str(),
+        ]);
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
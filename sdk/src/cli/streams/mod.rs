@@ -1,6 +1,8 @@
 pub mod create_stream;
 pub mod delete_stream;
 pub mod get_stream;
+pub mod get_stream_topic_defaults;
 pub mod get_streams;
 pub mod purge_stream;
 pub mod update_stream;
+pub mod update_stream_topic_defaults;
@@ -0,0 +1,68 @@
+use crate::cli::topic_templates::create_topic_template::format_replication_factor;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::models::topic_template::TopicDefaults;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_size::MaxTopicSize;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UpdateStreamTopicDefaultsCmd {
+    stream_id: Identifier,
+    topic_defaults: TopicDefaults,
+}
+
+impl UpdateStreamTopicDefaultsCmd {
+    pub fn new(
+        stream_id: Identifier,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        replication_factor: Option<u8>,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_defaults: TopicDefaults {
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdateStreamTopicDefaultsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update topic defaults of stream with ID: {}, message expiry: {}, max topic size: {}, replication factor: {}",
+            self.stream_id,
+            self.topic_defaults.message_expiry,
+            self.topic_defaults.max_topic_size,
+            format_replication_factor(self.topic_defaults.replication_factor),
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_stream_topic_defaults(&self.stream_id, self.topic_defaults)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating topic defaults of stream with ID: {}",
+                    self.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Topic defaults of stream with ID: {} updated, message expiry: {}, max topic size: {}, replication factor: {}",
+            self.stream_id,
+            self.topic_defaults.message_expiry,
+            self.topic_defaults.max_topic_size,
+            format_replication_factor(self.topic_defaults.replication_factor),
+        );
+
+        Ok(())
+    }
+}
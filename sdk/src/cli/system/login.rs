@@ -67,6 +67,7 @@ impl CliCommand for LoginCmd {
                     None => Some(DEFAULT_LOGIN_SESSION_TIMEOUT).into(),
                     Some(value) => *value,
                 },
+                None,
             )
             .await
             .with_context(|| {
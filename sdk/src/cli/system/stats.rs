@@ -140,6 +140,26 @@ impl CliCommand for GetStatsCmd {
                     "Consumer Groups Count",
                     format!("{}", stats.consumer_groups_count).as_str(),
                 ]);
+                table.add_row(vec![
+                    "Failed Login Attempts",
+                    format!("{}", stats.failed_login_attempts).as_str(),
+                ]);
+                table.add_row(vec![
+                    "Login Lockouts",
+                    format!("{}", stats.login_lockouts).as_str(),
+                ]);
+                table.add_row(vec![
+                    "Quota Violations",
+                    format!("{}", stats.quota_violations).as_str(),
+                ]);
+                table.add_row(vec![
+                    "Recovered Segments",
+                    format!("{}", stats.recovered_segments).as_str(),
+                ]);
+                table.add_row(vec![
+                    "Truncated Segment Bytes",
+                    format!("{}", stats.truncated_segment_bytes).as_str(),
+                ]);
 
                 table.add_row(vec!["OS Name", stats.os_name.as_str()]);
                 table.add_row(vec!["OS Version", stats.os_version.as_str()]);
@@ -189,6 +209,17 @@ impl CliCommand for GetStatsCmd {
                     "Consumer Groups Count|{}",
                     stats.consumer_groups_count
                 ));
+                list.push(format!(
+                    "Failed Login Attempts|{}",
+                    stats.failed_login_attempts
+                ));
+                list.push(format!("Login Lockouts|{}", stats.login_lockouts));
+                list.push(format!("Quota Violations|{}", stats.quota_violations));
+                list.push(format!("Recovered Segments|{}", stats.recovered_segments));
+                list.push(format!(
+                    "Truncated Segment Bytes|{}",
+                    stats.truncated_segment_bytes
+                ));
 
                 list.push(format!("OS Name|{}", stats.os_name));
                 list.push(format!("OS Version|{}", stats.os_version));
@@ -0,0 +1,90 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::models::topic_template::TopicTemplate;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_size::MaxTopicSize;
+use anyhow::Context;
+use async_trait::async_trait;
+use core::fmt;
+use tracing::{event, Level};
+
+pub struct CreateTopicTemplateCmd {
+    template: TopicTemplate,
+}
+
+impl CreateTopicTemplateCmd {
+    pub fn new(
+        name: String,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        replication_factor: Option<u8>,
+    ) -> Self {
+        Self {
+            template: TopicTemplate {
+                name,
+                partitions_count,
+                compression_algorithm,
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CreateTopicTemplateCmd {
+    fn explain(&self) -> String {
+        format!("{}", self)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .create_topic_template(self.template.clone())
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem creating topic template with name: {}",
+                    self.template.name
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Topic template with name: {}, partitions count: {}, compression algorithm: {}, message expiry: {}, max topic size: {}, replication factor: {} created",
+            self.template.name,
+            self.template.partitions_count,
+            self.template.compression_algorithm,
+            self.template.message_expiry,
+            self.template.max_topic_size,
+            format_replication_factor(self.template.replication_factor),
+        );
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for CreateTopicTemplateCmd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "create topic template with name: {}, partitions count: {}, compression algorithm: {}, message expiry: {}, \
+            max topic size: {}, replication factor: {}",
+            self.template.name,
+            self.template.partitions_count,
+            self.template.compression_algorithm,
+            self.template.message_expiry,
+            self.template.max_topic_size,
+            format_replication_factor(self.template.replication_factor),
+        )
+    }
+}
+
+pub(crate) fn format_replication_factor(replication_factor: Option<u8>) -> String {
+    match replication_factor {
+        Some(replication_factor) => format!("{replication_factor}"),
+        None => String::from("server_default"),
+    }
+}
@@ -0,0 +1,46 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::topic_templates::delete_topic_template::DeleteTopicTemplate;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct DeleteTopicTemplateCmd {
+    delete_template: DeleteTopicTemplate,
+}
+
+impl DeleteTopicTemplateCmd {
+    pub fn new(name: String) -> Self {
+        Self {
+            delete_template: DeleteTopicTemplate { name },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for DeleteTopicTemplateCmd {
+    fn explain(&self) -> String {
+        format!(
+            "delete topic template with name: {}",
+            self.delete_template.name
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .delete_topic_template(&self.delete_template.name)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem deleting topic template with name: {}",
+                    self.delete_template.name
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Topic template with name: {} deleted", self.delete_template.name
+        );
+
+        Ok(())
+    }
+}
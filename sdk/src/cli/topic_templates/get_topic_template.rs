@@ -0,0 +1,73 @@
+use crate::cli::topic_templates::create_topic_template::format_replication_factor;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::topic_templates::get_topic_template::GetTopicTemplate;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetTopicTemplateCmd {
+    get_template: GetTopicTemplate,
+}
+
+impl GetTopicTemplateCmd {
+    pub fn new(name: String) -> Self {
+        Self {
+            get_template: GetTopicTemplate { name },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetTopicTemplateCmd {
+    fn explain(&self) -> String {
+        format!("get topic template with name: {}", self.get_template.name)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let template = client
+            .get_topic_template(&self.get_template.name)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic template with name: {}",
+                    self.get_template.name
+                )
+            })?;
+
+        let Some(template) = template else {
+            event!(target: PRINT_TARGET, Level::INFO, "Topic template with name: {} was not found", self.get_template.name);
+            return Ok(());
+        };
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["Template name", template.name.as_str()]);
+        table.add_row(vec![
+            "Partitions count",
+            format!("{}", template.partitions_count).as_str(),
+        ]);
+        table.add_row(vec![
+            "Compression",
+            template.compression_algorithm.to_string().as_str(),
+        ]);
+        table.add_row(vec![
+            "Message expiry",
+            format!("{}", template.message_expiry).as_str(),
+        ]);
+        table.add_row(vec![
+            "Max topic size",
+            format!("{}", template.max_topic_size).as_str(),
+        ]);
+        table.add_row(vec![
+            "Replication factor",
+            format_replication_factor(template.replication_factor).as_str(),
+        ]);
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::cli::topic_templates::create_topic_template::format_replication_factor;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::topic_templates::get_topic_templates::GetTopicTemplates;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub enum GetTopicTemplatesOutput {
+    Table,
+    List,
+}
+
+pub struct GetTopicTemplatesCmd {
+    _get_templates: GetTopicTemplates,
+    output: GetTopicTemplatesOutput,
+}
+
+impl GetTopicTemplatesCmd {
+    pub fn new(output: GetTopicTemplatesOutput) -> Self {
+        Self {
+            _get_templates: GetTopicTemplates {},
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetTopicTemplatesCmd {
+    fn explain(&self) -> String {
+        let mode = match self.output {
+            GetTopicTemplatesOutput::Table => "table",
+            GetTopicTemplatesOutput::List => "list",
+        };
+        format!("list topic templates in {mode} mode")
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let templates = client
+            .get_topic_templates()
+            .await
+            .with_context(|| String::from("Problem getting list of topic templates"))?;
+
+        match self.output {
+            GetTopicTemplatesOutput::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec![
+                    "Name",
+                    "Partitions Count",
+                    "Compression",
+                    "Message Expiry",
+                    "Max Topic Size",
+                    "Replication Factor",
+                ]);
+
+                templates.iter().for_each(|template| {
+                    table.add_row(vec![
+                        template.name.clone(),
+                        format!("{}", template.partitions_count),
+                        template.compression_algorithm.to_string(),
+                        format!("{}", template.message_expiry),
+                        format!("{}", template.max_topic_size),
+                        format_replication_factor(template.replication_factor),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetTopicTemplatesOutput::List => {
+                templates.iter().for_each(|template| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}|{}|{}|{}",
+                        template.name,
+                        template.partitions_count,
+                        template.compression_algorithm,
+                        template.message_expiry,
+                        template.max_topic_size,
+                        format_replication_factor(template.replication_factor),
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod create_topic_template;
+pub mod delete_topic_template;
+pub mod get_topic_template;
+pub mod get_topic_templates;
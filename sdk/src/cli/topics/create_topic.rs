@@ -39,6 +39,7 @@ impl CreateTopicCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: Some(replication_factor),
+                template: None,
             },
             message_expiry,
             max_topic_size,
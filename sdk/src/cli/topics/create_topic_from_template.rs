@@ -0,0 +1,66 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct CreateTopicFromTemplateCmd {
+    stream_id: Identifier,
+    topic_id: Option<u32>,
+    name: String,
+    template: String,
+}
+
+impl CreateTopicFromTemplateCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Option<u32>,
+        name: String,
+        template: String,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            name,
+            template,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CreateTopicFromTemplateCmd {
+    fn explain(&self) -> String {
+        format!(
+            "create topic with name: {} from template: {} in stream with ID: {}",
+            self.name, self.template, self.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let topic = client
+            .create_topic_from_template(&self.stream_id, &self.name, &self.template, self.topic_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem creating topic with name: {} from template: {} in stream with ID: {}",
+                    self.name, self.template, self.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Topic with name: {}, ID: {}, partitions count: {}, compression algorithm: {}, message expiry: {}, max topic size: {}, replication factor: {} created from template: {} in stream with ID: {}",
+            topic.name,
+            topic.id,
+            topic.partitions_count,
+            topic.compression_algorithm,
+            topic.message_expiry,
+            topic.max_topic_size,
+            topic.replication_factor,
+            self.template,
+            self.stream_id,
+        );
+
+        Ok(())
+    }
+}
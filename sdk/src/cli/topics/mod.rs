@@ -1,4 +1,5 @@
 pub mod create_topic;
+pub mod create_topic_from_template;
 pub mod delete_topic;
 pub mod get_topic;
 pub mod get_topics;
@@ -165,6 +165,21 @@ impl CliCommand for GetUserCmd {
         table.add_row(vec!["Status", format!("{}", user.status).as_str()]);
         table.add_row(vec!["Username", user.username.as_str()]);
 
+        if let Some(usage) = user.usage {
+            table.add_row(vec![
+                "Streams",
+                format_usage(usage.streams_count, usage.max_streams).as_str(),
+            ]);
+            table.add_row(vec![
+                "Topics",
+                format_usage(usage.topics_count, usage.max_topics).as_str(),
+            ]);
+            table.add_row(vec![
+                "Partitions",
+                format_usage(usage.partitions_count, usage.max_partitions).as_str(),
+            ]);
+        }
+
         if let Some(permissions) = user.permissions {
             let global_permissions: Table = permissions.global.into();
             table.add_row(vec!["Global", format!("{}", global_permissions).as_str()]);
@@ -185,3 +200,10 @@ impl CliCommand for GetUserCmd {
         Ok(())
     }
 }
+
+fn format_usage(count: u32, max: u32) -> String {
+    match max {
+        0 => format!("{count} / unlimited"),
+        max => format!("{count} / {max}"),
+    }
+}
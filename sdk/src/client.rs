@@ -5,17 +5,21 @@ use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::messages::poll_messages::PollingStrategy;
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::backup::{BackupInfo, BackupRestoreTarget};
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
+use crate::models::partitions_merge::PartitionsMergeInfo;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::{Schema, SchemaCompatibility, SchemaType, TopicSchemas};
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic_template::{TopicDefaults, TopicTemplate};
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
@@ -23,6 +27,7 @@ use crate::tcp::config::{TcpClientConfig, TcpClientReconnectionConfig};
 use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use crate::utils::timestamp::IggyTimestamp;
 use crate::utils::topic_size::MaxTopicSize;
 use async_broadcast::Receiver;
 use async_trait::async_trait;
@@ -60,10 +65,13 @@ pub trait Client:
     + PersonalAccessTokenClient
     + StreamClient
     + TopicClient
+    + TopicTemplateClient
+    + SchemaClient
     + PartitionClient
     + MessageClient
     + ConsumerOffsetClient
     + ConsumerGroupClient
+    + BackupClient
     + Sync
     + Send
     + Debug
@@ -114,6 +122,32 @@ pub trait SystemClient {
     ) -> Result<Snapshot, IggyError>;
 }
 
+/// This trait defines the methods to interact with the backup module.
+#[async_trait]
+pub trait BackupClient {
+    /// Create the backup of the state, segments and consumer offsets while the server is running.
+    ///
+    /// If `incremental` is set, only the data appended since the latest backup is stored.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn create_backup(&self, incremental: bool) -> Result<BackupInfo, IggyError>;
+    /// Get the info about all the backups stored by the server.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_backups(&self) -> Result<Vec<BackupInfo>, IggyError>;
+    /// Restore the backed up data to the chosen point in time (or the latest backup if not provided).
+    ///
+    /// The data is restored either to the empty directory on the server side or using the configured archiver.
+    /// Returns the info about the backup the data was restored from.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn restore_backup(
+        &self,
+        timestamp: Option<IggyTimestamp>,
+        target: BackupRestoreTarget,
+    ) -> Result<BackupInfo, IggyError>;
+}
+
 /// This trait defines the methods to interact with the user module.
 #[async_trait]
 pub trait UserClient {
@@ -165,6 +199,10 @@ pub trait UserClient {
         current_password: &str,
         new_password: &str,
     ) -> Result<(), IggyError>;
+    /// Unlock a user by unique ID or username, clearing the temporary lockout caused by too many failed login attempts.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn unlock_user(&self, user_id: &Identifier) -> Result<(), IggyError>;
     /// Login a user by username and password.
     async fn login_user(&self, username: &str, password: &str) -> Result<IdentityInfo, IggyError>;
     /// Logout the currently authenticated user.
@@ -177,10 +215,14 @@ pub trait PersonalAccessTokenClient {
     /// Get the info about all the personal access tokens of the currently authenticated user.
     async fn get_personal_access_tokens(&self) -> Result<Vec<PersonalAccessTokenInfo>, IggyError>;
     /// Create a new personal access token for the currently authenticated user.
+    ///
+    /// The optional permissions restrict the token to a subset of the user's permissions,
+    /// if not provided, the token has all the permissions of the user.
     async fn create_personal_access_token(
         &self,
         name: &str,
         expiry: PersonalAccessTokenExpiry,
+        permissions: Option<Permissions>,
     ) -> Result<RawPersonalAccessToken, IggyError>;
     /// Delete a personal access token of the currently authenticated user by unique token name.
     async fn delete_personal_access_token(&self, name: &str) -> Result<(), IggyError>;
@@ -222,6 +264,22 @@ pub trait StreamClient {
     ///
     /// Authentication is required, and the permission to manage the streams.
     async fn purge_stream(&self, stream_id: &Identifier) -> Result<(), IggyError>;
+    /// Get the default settings of the topics created in the stream by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to read the streams.
+    async fn get_stream_topic_defaults(
+        &self,
+        stream_id: &Identifier,
+    ) -> Result<TopicDefaults, IggyError>;
+    /// Update the default settings of the topics created in the stream by unique ID or name.
+    /// They're used when the topic is created with the fields left at `ServerDefault`.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    async fn update_stream_topic_defaults(
+        &self,
+        stream_id: &Identifier,
+        topic_defaults: TopicDefaults,
+    ) -> Result<(), IggyError>;
 }
 
 /// This trait defines the methods to interact with the topic module.
@@ -255,6 +313,17 @@ pub trait TopicClient {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
     ) -> Result<TopicDetails, IggyError>;
+    /// Create a new topic using the settings of the topic template,
+    /// the ones left at `ServerDefault` by the template are taken from the stream defaults.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn create_topic_from_template(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        template: &str,
+        topic_id: Option<u32>,
+    ) -> Result<TopicDetails, IggyError>;
     /// Update a topic by unique ID or name.
     ///
     /// Authentication is required, and the permission to manage the topics.
@@ -286,6 +355,84 @@ pub trait TopicClient {
     ) -> Result<(), IggyError>;
 }
 
+/// This trait defines the methods to interact with the topic template module.
+#[async_trait]
+pub trait TopicTemplateClient {
+    /// Get the topic template by its name.
+    ///
+    /// Authentication is required.
+    async fn get_topic_template(&self, name: &str) -> Result<Option<TopicTemplate>, IggyError>;
+    /// Get all the topic templates.
+    ///
+    /// Authentication is required.
+    async fn get_topic_templates(&self) -> Result<Vec<TopicTemplate>, IggyError>;
+    /// Create a new topic template.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn create_topic_template(&self, template: TopicTemplate) -> Result<(), IggyError>;
+    /// Delete the topic template by its name, the topics created from it are not affected.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn delete_topic_template(&self, name: &str) -> Result<(), IggyError>;
+}
+
+/// This trait defines the methods to interact with the schema registry module.
+#[async_trait]
+pub trait SchemaClient {
+    /// Get the schema by its unique ID, e.g. the one referenced in the message header.
+    ///
+    /// Authentication is required.
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError>;
+    /// Get the version of the schema registered for the topic by unique stream and topic IDs or names.
+    /// If the version is `None`, the latest version is returned.
+    ///
+    /// Authentication is required, and the permission to read the topics.
+    async fn get_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: Option<u32>,
+    ) -> Result<Option<Schema>, IggyError>;
+    /// Get the schema registry of the topic, including its settings and all the schema versions.
+    ///
+    /// Authentication is required, and the permission to read the topics.
+    async fn get_topic_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError>;
+    /// Register the new version of the schema for the topic, checked against the compatibility rule of the topic.
+    /// If the same definition is already registered for the topic, the existing schema is returned.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn register_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        definition: &str,
+    ) -> Result<Schema, IggyError>;
+    /// Delete the version of the schema registered for the topic.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn delete_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: u32,
+    ) -> Result<(), IggyError>;
+    /// Update the compatibility rule and the payload validation of the topic schema registry.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn update_topic_schema_settings(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+        validate_payloads: bool,
+    ) -> Result<(), IggyError>;
+}
+
 /// This trait defines the methods to interact with the partition module.
 #[async_trait]
 pub trait PartitionClient {
@@ -311,6 +458,27 @@ pub trait PartitionClient {
         topic_id: &Identifier,
         partitions_count: u32,
     ) -> Result<(), IggyError>;
+    /// Merge last N partitions for a topic by unique ID or name into the remaining ones.
+    ///
+    /// The messages of the merged partitions are appended to the remaining partitions in the background,
+    /// and the consumer group offsets are remapped, so that the already consumed messages are not delivered again.
+    /// For example, given a topic with 5 partitions, if you merge 2 partitions, the topic will have 3 partitions left (from 1 to 3) once the merge is completed.
+    ///
+    /// Authentication is required, and the permission to manage the partitions.
+    async fn merge_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<PartitionsMergeInfo, IggyError>;
+    /// Get the progress of the last partitions merge for a topic by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to read the topics.
+    async fn get_partitions_merge(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Option<PartitionsMergeInfo>, IggyError>;
 }
 
 /// This trait defines the methods to interact with the messaging module.
@@ -627,6 +795,7 @@ impl From<ConnectionString> for TcpClientConfig {
             reconnection: connection_string.options.reconnection,
             heartbeat_interval: connection_string.options.heartbeat_interval,
             nodelay: connection_string.options.nodelay,
+            zero_copy_polling: false,
         }
     }
 }
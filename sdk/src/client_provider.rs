@@ -113,6 +113,7 @@ impl ClientProviderConfig {
                     tls_domain: args.tcp_tls_domain,
                     tls_ca_file: args.tcp_tls_ca_file,
                     nodelay: args.tcp_nodelay,
+                    zero_copy_polling: false,
                     heartbeat_interval: IggyDuration::from_str(&args.tcp_heartbeat_interval)
                         .unwrap(),
                     reconnection: TcpClientReconnectionConfig {
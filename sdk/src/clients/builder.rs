@@ -157,6 +157,12 @@ impl TcpClientBuilder {
         self
     }
 
+    /// Sets the zero-copy polling, so the server can send the polled messages directly from the disk.
+    pub fn with_zero_copy_polling(mut self) -> Self {
+        self.config = self.config.with_zero_copy_polling();
+        self
+    }
+
     /// Builds the parent `IggyClient` with TCP configuration.
     pub fn build(self) -> Result<IggyClient, IggyError> {
         let client = TcpClient::create(Arc::new(self.config.build()))?;
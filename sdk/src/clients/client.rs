@@ -1,6 +1,7 @@
 use crate::client::{
-    Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, StreamClient, SystemClient, TopicClient, UserClient,
+    BackupClient, Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient,
+    PartitionClient, PersonalAccessTokenClient, SchemaClient, StreamClient, SystemClient,
+    TopicClient, TopicTemplateClient, UserClient,
 };
 use crate::clients::builder::IggyClientBuilder;
 use crate::clients::consumer::IggyConsumerBuilder;
@@ -14,17 +15,21 @@ use crate::locking::IggySharedMut;
 use crate::locking::IggySharedMutFn;
 use crate::messages::poll_messages::PollingStrategy;
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::backup::{BackupInfo, BackupRestoreTarget};
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
+use crate::models::partitions_merge::PartitionsMergeInfo;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::{Schema, SchemaCompatibility, SchemaType, TopicSchemas};
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic_template::{TopicDefaults, TopicTemplate};
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
 use crate::partitioner::Partitioner;
@@ -35,6 +40,7 @@ use crate::utils::crypto::EncryptorKind;
 use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use crate::utils::timestamp::IggyTimestamp;
 use crate::utils::topic_size::MaxTopicSize;
 use async_broadcast::Receiver;
 use async_dropper::AsyncDrop;
@@ -279,6 +285,10 @@ impl UserClient for IggyClient {
             .await
     }
 
+    async fn unlock_user(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        self.client.read().await.unlock_user(user_id).await
+    }
+
     async fn login_user(&self, username: &str, password: &str) -> Result<IdentityInfo, IggyError> {
         self.client
             .read()
@@ -302,11 +312,12 @@ impl PersonalAccessTokenClient for IggyClient {
         &self,
         name: &str,
         expiry: PersonalAccessTokenExpiry,
+        permissions: Option<Permissions>,
     ) -> Result<RawPersonalAccessToken, IggyError> {
         self.client
             .read()
             .await
-            .create_personal_access_token(name, expiry)
+            .create_personal_access_token(name, expiry, permissions)
             .await
     }
 
@@ -330,6 +341,29 @@ impl PersonalAccessTokenClient for IggyClient {
     }
 }
 
+#[async_trait]
+impl BackupClient for IggyClient {
+    async fn create_backup(&self, incremental: bool) -> Result<BackupInfo, IggyError> {
+        self.client.read().await.create_backup(incremental).await
+    }
+
+    async fn get_backups(&self) -> Result<Vec<BackupInfo>, IggyError> {
+        self.client.read().await.get_backups().await
+    }
+
+    async fn restore_backup(
+        &self,
+        timestamp: Option<IggyTimestamp>,
+        target: BackupRestoreTarget,
+    ) -> Result<BackupInfo, IggyError> {
+        self.client
+            .read()
+            .await
+            .restore_backup(timestamp, target)
+            .await
+    }
+}
+
 #[async_trait]
 impl SystemClient for IggyClient {
     async fn get_stats(&self) -> Result<Stats, IggyError> {
@@ -406,6 +440,29 @@ impl StreamClient for IggyClient {
     async fn purge_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
         self.client.read().await.purge_stream(stream_id).await
     }
+
+    async fn get_stream_topic_defaults(
+        &self,
+        stream_id: &Identifier,
+    ) -> Result<TopicDefaults, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_stream_topic_defaults(stream_id)
+            .await
+    }
+
+    async fn update_stream_topic_defaults(
+        &self,
+        stream_id: &Identifier,
+        topic_defaults: TopicDefaults,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_stream_topic_defaults(stream_id, topic_defaults)
+            .await
+    }
 }
 
 #[async_trait]
@@ -453,6 +510,20 @@ impl TopicClient for IggyClient {
             .await
     }
 
+    async fn create_topic_from_template(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        template: &str,
+        topic_id: Option<u32>,
+    ) -> Result<TopicDetails, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_topic_from_template(stream_id, name, template, topic_id)
+            .await
+    }
+
     async fn update_topic(
         &self,
         stream_id: &Identifier,
@@ -503,6 +574,102 @@ impl TopicClient for IggyClient {
     }
 }
 
+#[async_trait]
+impl TopicTemplateClient for IggyClient {
+    async fn get_topic_template(&self, name: &str) -> Result<Option<TopicTemplate>, IggyError> {
+        self.client.read().await.get_topic_template(name).await
+    }
+
+    async fn get_topic_templates(&self) -> Result<Vec<TopicTemplate>, IggyError> {
+        self.client.read().await.get_topic_templates().await
+    }
+
+    async fn create_topic_template(&self, template: TopicTemplate) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .create_topic_template(template)
+            .await
+    }
+
+    async fn delete_topic_template(&self, name: &str) -> Result<(), IggyError> {
+        self.client.read().await.delete_topic_template(name).await
+    }
+}
+
+#[async_trait]
+impl SchemaClient for IggyClient {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        self.client.read().await.get_schema(schema_id).await
+    }
+
+    async fn get_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: Option<u32>,
+    ) -> Result<Option<Schema>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_topic_schema(stream_id, topic_id, version)
+            .await
+    }
+
+    async fn get_topic_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_topic_schemas(stream_id, topic_id)
+            .await
+    }
+
+    async fn register_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        definition: &str,
+    ) -> Result<Schema, IggyError> {
+        self.client
+            .read()
+            .await
+            .register_topic_schema(stream_id, topic_id, schema_type, definition)
+            .await
+    }
+
+    async fn delete_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: u32,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .delete_topic_schema(stream_id, topic_id, version)
+            .await
+    }
+
+    async fn update_topic_schema_settings(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+        validate_payloads: bool,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_topic_schema_settings(stream_id, topic_id, compatibility, validate_payloads)
+            .await
+    }
+}
+
 #[async_trait]
 impl PartitionClient for IggyClient {
     async fn create_partitions(
@@ -530,6 +697,31 @@ impl PartitionClient for IggyClient {
             .delete_partitions(stream_id, topic_id, partitions_count)
             .await
     }
+
+    async fn merge_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<PartitionsMergeInfo, IggyError> {
+        self.client
+            .read()
+            .await
+            .merge_partitions(stream_id, topic_id, partitions_count)
+            .await
+    }
+
+    async fn get_partitions_merge(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Option<PartitionsMergeInfo>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_partitions_merge(stream_id, topic_id)
+            .await
+    }
 }
 
 #[async_trait]
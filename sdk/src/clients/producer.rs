@@ -5,6 +5,7 @@ use crate::error::IggyError;
 use crate::identifier::{IdKind, Identifier};
 use crate::locking::{IggySharedMut, IggySharedMutFn};
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::partitioner::partitioner_kind::PartitionerKind;
 use crate::partitioner::Partitioner;
 use crate::utils::crypto::EncryptorKind;
 use crate::utils::duration::IggyDuration;
@@ -37,6 +38,7 @@ pub struct IggyProducer {
     partitioning: Option<Arc<Partitioning>>,
     encryptor: Option<Arc<EncryptorKind>>,
     partitioner: Option<Arc<dyn Partitioner>>,
+    partitioner_kind: Option<PartitionerKind>,
     send_interval_micros: u64,
     create_stream_if_not_exists: bool,
     create_topic_if_not_exists: bool,
@@ -63,6 +65,7 @@ impl IggyProducer {
         partitioning: Option<Partitioning>,
         encryptor: Option<Arc<EncryptorKind>>,
         partitioner: Option<Arc<dyn Partitioner>>,
+        partitioner_kind: Option<PartitionerKind>,
         interval: Option<IggyDuration>,
         create_stream_if_not_exists: bool,
         create_topic_if_not_exists: bool,
@@ -85,6 +88,7 @@ impl IggyProducer {
             partitioning: partitioning.map(Arc::new),
             encryptor,
             partitioner,
+            partitioner_kind,
             send_interval_micros: interval.map_or(0, |i| i.as_micros()),
             create_stream_if_not_exists,
             create_topic_if_not_exists,
@@ -139,7 +143,9 @@ impl IggyProducer {
             client.create_stream(&name, id).await?;
         }
 
-        if client.get_topic(&stream_id, &topic_id).await?.is_none() {
+        let partitions_count = if let Some(topic) = client.get_topic(&stream_id, &topic_id).await? {
+            topic.partitions_count
+        } else {
             if !self.create_topic_if_not_exists {
                 error!("Topic does not exist and auto-creation is disabled.");
                 return Err(IggyError::TopicNameNotFound(
@@ -168,6 +174,12 @@ impl IggyProducer {
                     self.topic_max_size,
                 )
                 .await?;
+            self.topic_partitions_count
+        };
+
+        if let Some(partitioner_kind) = &self.partitioner_kind {
+            trace!("Creating partitioner: {partitioner_kind:?} for {partitions_count} partitions.");
+            self.partitioner = Some(partitioner_kind.create(partitions_count)?);
         }
 
         self.initialized = true;
@@ -482,6 +494,16 @@ impl IggyProducer {
                          {error} Retrying {retries}/{max_retries}..."
                     );
 
+                    // The server hints when the request can be retried, so there's no point in retrying sooner.
+                    if let IggyError::RateLimitExceeded(retry_after) = error {
+                        trace!(
+                            "Waiting {retry_after} ms for the rate limit to send messages to topic: {topic}, \
+                             stream: {stream}..."
+                        );
+                        sleep(Duration::from_millis(retry_after)).await;
+                        continue;
+                    }
+
                     if let Some(t) = timer.as_mut() {
                         trace!(
                             "Waiting for the next retry to send messages to topic: {topic}, \
@@ -527,6 +549,7 @@ pub struct IggyProducerBuilder {
     partitioning: Option<Partitioning>,
     encryptor: Option<Arc<EncryptorKind>>,
     partitioner: Option<Arc<dyn Partitioner>>,
+    partitioner_kind: Option<PartitionerKind>,
     send_interval: Option<IggyDuration>,
     create_stream_if_not_exists: bool,
     create_topic_if_not_exists: bool,
@@ -559,6 +582,7 @@ impl IggyProducerBuilder {
             partitioning: None,
             encryptor,
             partitioner,
+            partitioner_kind: None,
             send_interval: Some(IggyDuration::from(1000)),
             create_stream_if_not_exists: true,
             create_topic_if_not_exists: true,
@@ -653,6 +677,16 @@ impl IggyProducerBuilder {
     pub fn partitioner(self, partitioner: Arc<dyn Partitioner>) -> Self {
         Self {
             partitioner: Some(partitioner),
+            partitioner_kind: None,
+            ..self
+        }
+    }
+
+    /// Sets one of the built-in partitioners for messages, created for the partitions count of the topic during `init()`.
+    pub fn partitioner_kind(self, partitioner_kind: PartitionerKind) -> Self {
+        Self {
+            partitioner: None,
+            partitioner_kind: Some(partitioner_kind),
             ..self
         }
     }
@@ -661,6 +695,7 @@ impl IggyProducerBuilder {
     pub fn without_partitioner(self) -> Self {
         Self {
             partitioner: None,
+            partitioner_kind: None,
             ..self
         }
     }
@@ -732,6 +767,7 @@ impl IggyProducerBuilder {
             self.partitioning,
             self.encryptor,
             self.partitioner,
+            self.partitioner_kind,
             self.send_interval,
             self.create_stream_if_not_exists,
             self.create_topic_if_not_exists,
@@ -13,6 +13,12 @@ pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
 pub const GET_SNAPSHOT_FILE: &str = "snapshot";
 pub const GET_SNAPSHOT_FILE_CODE: u32 = 11;
+pub const CREATE_BACKUP: &str = "backup.create";
+pub const CREATE_BACKUP_CODE: u32 = 12;
+pub const GET_BACKUPS: &str = "backup.list";
+pub const GET_BACKUPS_CODE: u32 = 13;
+pub const RESTORE_BACKUP: &str = "backup.restore";
+pub const RESTORE_BACKUP_CODE: u32 = 14;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
@@ -37,6 +43,8 @@ pub const LOGIN_USER: &str = "user.login";
 pub const LOGIN_USER_CODE: u32 = 38;
 pub const LOGOUT_USER: &str = "user.logout";
 pub const LOGOUT_USER_CODE: u32 = 39;
+pub const UNLOCK_USER: &str = "user.unlock";
+pub const UNLOCK_USER_CODE: u32 = 40;
 pub const GET_PERSONAL_ACCESS_TOKENS: &str = "personal_access_token.list";
 pub const GET_PERSONAL_ACCESS_TOKENS_CODE: u32 = 41;
 pub const CREATE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.create";
@@ -69,6 +77,10 @@ pub const UPDATE_STREAM: &str = "stream.update";
 pub const UPDATE_STREAM_CODE: u32 = 204;
 pub const PURGE_STREAM: &str = "stream.purge";
 pub const PURGE_STREAM_CODE: u32 = 205;
+pub const GET_STREAM_TOPIC_DEFAULTS: &str = "stream.topic_defaults.get";
+pub const GET_STREAM_TOPIC_DEFAULTS_CODE: u32 = 206;
+pub const UPDATE_STREAM_TOPIC_DEFAULTS: &str = "stream.topic_defaults.update";
+pub const UPDATE_STREAM_TOPIC_DEFAULTS_CODE: u32 = 207;
 pub const GET_TOPIC: &str = "topic.get";
 pub const GET_TOPIC_CODE: u32 = 300;
 pub const GET_TOPICS: &str = "topic.list";
@@ -81,10 +93,34 @@ pub const UPDATE_TOPIC: &str = "topic.update";
 pub const UPDATE_TOPIC_CODE: u32 = 304;
 pub const PURGE_TOPIC: &str = "topic.purge";
 pub const PURGE_TOPIC_CODE: u32 = 305;
+pub const GET_TOPIC_TEMPLATE: &str = "topic_template.get";
+pub const GET_TOPIC_TEMPLATE_CODE: u32 = 310;
+pub const GET_TOPIC_TEMPLATES: &str = "topic_template.list";
+pub const GET_TOPIC_TEMPLATES_CODE: u32 = 311;
+pub const CREATE_TOPIC_TEMPLATE: &str = "topic_template.create";
+pub const CREATE_TOPIC_TEMPLATE_CODE: u32 = 312;
+pub const DELETE_TOPIC_TEMPLATE: &str = "topic_template.delete";
+pub const DELETE_TOPIC_TEMPLATE_CODE: u32 = 313;
+pub const GET_SCHEMA: &str = "schema.get";
+pub const GET_SCHEMA_CODE: u32 = 320;
+pub const GET_TOPIC_SCHEMA: &str = "topic_schema.get";
+pub const GET_TOPIC_SCHEMA_CODE: u32 = 321;
+pub const GET_TOPIC_SCHEMAS: &str = "topic_schema.list";
+pub const GET_TOPIC_SCHEMAS_CODE: u32 = 322;
+pub const REGISTER_TOPIC_SCHEMA: &str = "topic_schema.register";
+pub const REGISTER_TOPIC_SCHEMA_CODE: u32 = 323;
+pub const DELETE_TOPIC_SCHEMA: &str = "topic_schema.delete";
+pub const DELETE_TOPIC_SCHEMA_CODE: u32 = 324;
+pub const UPDATE_TOPIC_SCHEMA_SETTINGS: &str = "topic_schema.settings.update";
+pub const UPDATE_TOPIC_SCHEMA_SETTINGS_CODE: u32 = 325;
 pub const CREATE_PARTITIONS: &str = "partition.create";
 pub const CREATE_PARTITIONS_CODE: u32 = 402;
 pub const DELETE_PARTITIONS: &str = "partition.delete";
 pub const DELETE_PARTITIONS_CODE: u32 = 403;
+pub const MERGE_PARTITIONS: &str = "partition.merge";
+pub const MERGE_PARTITIONS_CODE: u32 = 404;
+pub const GET_PARTITIONS_MERGE: &str = "partition.merge.get";
+pub const GET_PARTITIONS_MERGE_CODE: u32 = 405;
 pub const GET_CONSUMER_GROUP: &str = "consumer_group.get";
 pub const GET_CONSUMER_GROUP_CODE: u32 = 600;
 pub const GET_CONSUMER_GROUPS: &str = "consumer_group.list";
@@ -102,6 +138,9 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
     match code {
         PING_CODE => Ok(PING),
         GET_STATS_CODE => Ok(GET_STATS),
+        CREATE_BACKUP_CODE => Ok(CREATE_BACKUP),
+        GET_BACKUPS_CODE => Ok(GET_BACKUPS),
+        RESTORE_BACKUP_CODE => Ok(RESTORE_BACKUP),
         GET_ME_CODE => Ok(GET_ME),
         GET_CLIENT_CODE => Ok(GET_CLIENT),
         GET_CLIENTS_CODE => Ok(GET_CLIENTS),
@@ -114,6 +153,7 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         CHANGE_PASSWORD_CODE => Ok(CHANGE_PASSWORD),
         LOGIN_USER_CODE => Ok(LOGIN_USER),
         LOGOUT_USER_CODE => Ok(LOGOUT_USER),
+        UNLOCK_USER_CODE => Ok(UNLOCK_USER),
         GET_PERSONAL_ACCESS_TOKENS_CODE => Ok(GET_PERSONAL_ACCESS_TOKENS),
         CREATE_PERSONAL_ACCESS_TOKEN_CODE => Ok(CREATE_PERSONAL_ACCESS_TOKEN),
         DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(DELETE_PERSONAL_ACCESS_TOKEN),
@@ -129,14 +169,28 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         DELETE_STREAM_CODE => Ok(DELETE_STREAM),
         UPDATE_STREAM_CODE => Ok(UPDATE_STREAM),
         PURGE_STREAM_CODE => Ok(PURGE_STREAM),
+        GET_STREAM_TOPIC_DEFAULTS_CODE => Ok(GET_STREAM_TOPIC_DEFAULTS),
+        UPDATE_STREAM_TOPIC_DEFAULTS_CODE => Ok(UPDATE_STREAM_TOPIC_DEFAULTS),
         GET_TOPIC_CODE => Ok(GET_TOPIC),
         GET_TOPICS_CODE => Ok(GET_TOPICS),
         CREATE_TOPIC_CODE => Ok(CREATE_TOPIC),
         DELETE_TOPIC_CODE => Ok(DELETE_TOPIC),
         UPDATE_TOPIC_CODE => Ok(UPDATE_TOPIC),
         PURGE_TOPIC_CODE => Ok(PURGE_TOPIC),
+        GET_TOPIC_TEMPLATE_CODE => Ok(GET_TOPIC_TEMPLATE),
+        GET_TOPIC_TEMPLATES_CODE => Ok(GET_TOPIC_TEMPLATES),
+        CREATE_TOPIC_TEMPLATE_CODE => Ok(CREATE_TOPIC_TEMPLATE),
+        DELETE_TOPIC_TEMPLATE_CODE => Ok(DELETE_TOPIC_TEMPLATE),
+        GET_SCHEMA_CODE => Ok(GET_SCHEMA),
+        GET_TOPIC_SCHEMA_CODE => Ok(GET_TOPIC_SCHEMA),
+        GET_TOPIC_SCHEMAS_CODE => Ok(GET_TOPIC_SCHEMAS),
+        REGISTER_TOPIC_SCHEMA_CODE => Ok(REGISTER_TOPIC_SCHEMA),
+        DELETE_TOPIC_SCHEMA_CODE => Ok(DELETE_TOPIC_SCHEMA),
+        UPDATE_TOPIC_SCHEMA_SETTINGS_CODE => Ok(UPDATE_TOPIC_SCHEMA_SETTINGS),
         CREATE_PARTITIONS_CODE => Ok(CREATE_PARTITIONS),
         DELETE_PARTITIONS_CODE => Ok(DELETE_PARTITIONS),
+        MERGE_PARTITIONS_CODE => Ok(MERGE_PARTITIONS),
+        GET_PARTITIONS_MERGE_CODE => Ok(GET_PARTITIONS_MERGE),
         GET_CONSUMER_GROUP_CODE => Ok(GET_CONSUMER_GROUP),
         GET_CONSUMER_GROUPS_CODE => Ok(GET_CONSUMER_GROUPS),
         CREATE_CONSUMER_GROUP_CODE => Ok(CREATE_CONSUMER_GROUP),
@@ -44,6 +44,8 @@ pub enum IggyError {
     StateFileCorrupted = 15,
     #[error("Invalid state entry checksum: {0}, expected: {1}, for index: {2}")]
     InvalidStateEntryChecksum(u32, u32, u64) = 16,
+    #[error("Invalid state snapshot checksum: {0}, expected: {1}, for index: {2}")]
+    InvalidStateSnapshotChecksum(u32, u32, u64) = 17,
     #[error("Cannot open database, Path: {0}")]
     CannotOpenDatabase(String) = 19,
     #[error("Resource with key: {0} was not found.")]
@@ -90,6 +92,14 @@ pub enum IggyError {
     PersonalAccessTokenExpired(String, u32) = 54,
     #[error("Users limit reached.")]
     UsersLimitReached = 55,
+    #[error("Too many failed login attempts, retry after {0} seconds.")]
+    TooManyFailedLoginAttempts(u64) = 56,
+    #[error("Password does not satisfy the password policy: {0}.")]
+    PasswordPolicyViolation(String) = 57,
+    #[error("Password was used recently and cannot be reused.")]
+    PasswordReused = 58,
+    #[error("Password for user with ID: {0} has expired and must be changed.")]
+    PasswordExpired(u32) = 59,
     #[error("Not connected")]
     NotConnected = 61,
     #[error("Client shutdown")]
@@ -120,16 +130,42 @@ pub enum IggyError {
     AccessTokenMissing = 77,
     #[error("Invalid access token")]
     InvalidAccessToken = 78,
+    #[error("Encryption key with ID: {0} was not found")]
+    EncryptionKeyNotFound(u32) = 79,
     #[error("Invalid size bytes")]
     InvalidSizeBytes = 80,
     #[error("Invalid UTF-8")]
     InvalidUtf8 = 81,
     #[error("Invalid number encoding")]
     InvalidNumberEncoding = 82,
+    #[error("Streams quota exceeded for user with ID: {0}, max streams: {1}.")]
+    StreamsQuotaExceeded(u32, u32) = 83,
+    #[error("Topics quota exceeded for user with ID: {0}, max topics: {1}.")]
+    TopicsQuotaExceeded(u32, u32) = 84,
+    #[error("Partitions quota exceeded for user with ID: {0}, max partitions: {1}.")]
+    PartitionsQuotaExceeded(u32, u32) = 85,
+    #[error("Size quota exceeded for stream with ID: {0}, max size: {1} bytes.")]
+    StreamSizeQuotaExceeded(u32, u64) = 86,
+    #[error("Send throughput quota exceeded.")]
+    SendThroughputQuotaExceeded = 87,
+    #[error("Poll throughput quota exceeded.")]
+    PollThroughputQuotaExceeded = 88,
+    #[error("Rate limit exceeded, retry after {0} ms.")]
+    RateLimitExceeded(u64) = 89,
     #[error("Invalid boolean value")]
     InvalidBooleanValue,
     #[error("Invalid number value")]
     InvalidNumberValue,
+    #[error("Node is not the cluster leader, current leader ID: {0}.")]
+    NotClusterLeader(u32) = 92,
+    #[error("Cluster has not committed the state entry in time.")]
+    ClusterCommitTimeout = 93,
+    #[error("Cluster membership change is already in progress.")]
+    ClusterMembershipChangeInProgress = 94,
+    #[error("Cluster node with ID: {0} already exists.")]
+    ClusterNodeAlreadyExists(u32) = 95,
+    #[error("Cluster node with ID: {0} was not found.")]
+    ClusterNodeNotFound(u32) = 96,
     #[error("Client with ID: {0} was not found.")]
     ClientNotFound(u32) = 100,
     #[error("Invalid client ID")]
@@ -232,6 +268,32 @@ pub enum IggyError {
     CannotReadTopics(u32) = 2017,
     #[error("Invalid replication factor")]
     InvalidReplicationFactor = 2018,
+    #[error("Topic template with name: {0} was not found.")]
+    TopicTemplateNotFound(String) = 2019,
+    #[error("Topic template with name: {0} already exists.")]
+    TopicTemplateAlreadyExists(String) = 2020,
+    #[error("Invalid topic template name")]
+    InvalidTopicTemplateName = 2021,
+    #[error("Schema with ID: {0} was not found.")]
+    SchemaNotFound(u32) = 2030,
+    #[error("Schema version: {0} for topic with ID: {1} and stream with ID: {2} was not found.")]
+    TopicSchemaVersionNotFound(u32, u32, u32) = 2031,
+    #[error("Invalid schema: {0}")]
+    InvalidSchema(String) = 2032,
+    #[error("Schema is incompatible with version: {0}: {1}")]
+    IncompatibleSchema(u32, String) = 2033,
+    #[error("Invalid schema type")]
+    InvalidSchemaType = 2034,
+    #[error("Invalid schema compatibility")]
+    InvalidSchemaCompatibility = 2035,
+    #[error("Message with ID: {0} has no schema ID header, which is required by the topic.")]
+    MissingMessageSchemaId(u128) = 2036,
+    #[error("Payload of message with ID: {0} does not match the schema with ID: {1}: {2}")]
+    InvalidMessagePayload(u128, u32, String) = 2037,
+    #[error(
+        "Schema with ID: {0} is not registered for topic with ID: {1} and stream with ID: {2}."
+    )]
+    SchemaNotRegisteredForTopic(u32, u32, u32) = 2038,
     #[error("Cannot create partition with ID: {0} for stream with ID: {1} and topic with ID: {2}")]
     CannotCreatePartition(u32, u32, u32) = 3000,
     #[error(
@@ -264,6 +326,10 @@ pub enum IggyError {
     CannotDeleteConsumerOffsetFile(String) = 3011,
     #[error("Failed to create consumer offsets directory for path: {0}")]
     CannotCreateConsumerOffsetsDirectory(String) = 3012,
+    #[error("Partitions merge is in progress for topic with ID: {0} for stream with ID: {1}")]
+    PartitionsMergeInProgress(u32, u32) = 3013,
+    #[error("Cannot merge {0} partitions of topic with ID: {1} for stream with ID: {2}, at least one partition has to remain")]
+    CannotMergePartitions(u32, u32, u32) = 3014,
     #[error("Failed to read consumers offsets from path: {0}")]
     CannotReadConsumerOffsets(String) = 3020,
     #[error("Consumer offset for consumer with ID: {0} was not found.")]
@@ -328,6 +394,14 @@ pub enum IggyError {
     InvalidKeyValueLength = 4028,
     #[error("Command length error: {0}")]
     CommandLengthError(String) = 4029,
+    #[error("Cannot fetch offloaded segment with start offset: {0} for partition with ID: {1}")]
+    CannotFetchOffloadedSegment(u64, u32) = 4030,
+    #[error("Message header: {0} was not found")]
+    MessageHeaderNotFound(String) = 4031,
+    #[error("Invalid partitions count")]
+    InvalidPartitionsCount = 4032,
+    #[error("Invalid partition ID: {0}")]
+    InvalidPartitionId(u64) = 4033,
     #[error("Cannot sed messages due to client disconnection")]
     CannotSendMessagesDueToClientDisconnection = 4050,
     #[error("Invalid offset: {0}")]
@@ -370,10 +444,26 @@ pub enum IggyError {
     CannotReadMaxTimestamp = 7003,
     #[error("Cannot read batch payload")]
     CannotReadBatchPayload = 7004,
+    #[error("Invalid batch format version: {0}")]
+    InvalidBatchFormatVersion(u8) = 7005,
+    #[error("Invalid batch checksum: {0}, expected: {1}, for base offset: {2}")]
+    InvalidBatchChecksum(u32, u32, u64) = 7006,
+    #[error("Invalid batch attributes: {0}")]
+    InvalidBatchAttributes(u16) = 7007,
     #[error("Invalid connection string")]
     InvalidConnectionString = 8000,
     #[error("Snapshot file completion failed")]
     SnapshotFileCompletionFailed = 9000,
+    #[error("Backup with ID: {0} was not found")]
+    BackupNotFound(u64) = 9001,
+    #[error("Cannot create backup")]
+    CannotCreateBackup = 9002,
+    #[error("Cannot restore backup")]
+    CannotRestoreBackup = 9003,
+    #[error("Invalid backup checksum: {0}, expected: {1}, for file: {2}")]
+    InvalidBackupChecksum(u32, u32, String) = 9004,
+    #[error("Backup restore target: {0} is not empty")]
+    BackupRestoreTargetNotEmpty(String) = 9005,
     #[error("Cannot serialize resource")]
     CannotSerializeResource = 10000,
     #[error("Cannot deserialize resource")]
@@ -417,6 +507,29 @@ impl IggyError {
         IggyError::from_repr(code).unwrap_or(IggyError::Error)
     }
 
+    /// Returns the details sent over the binary protocol as the response payload along with the error code.
+    pub fn as_payload(&self) -> Vec<u8> {
+        match self {
+            IggyError::RateLimitExceeded(retry_after) => retry_after.to_le_bytes().to_vec(),
+            IggyError::NotClusterLeader(leader_id) => leader_id.to_le_bytes().to_vec(),
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn from_code_and_payload(code: u32, payload: &[u8]) -> Self {
+        match IggyError::from_code(code) {
+            IggyError::RateLimitExceeded(_) => payload
+                .try_into()
+                .map(|retry_after| IggyError::RateLimitExceeded(u64::from_le_bytes(retry_after)))
+                .unwrap_or(IggyError::RateLimitExceeded(0)),
+            IggyError::NotClusterLeader(_) => payload
+                .try_into()
+                .map(|leader_id| IggyError::NotClusterLeader(u32::from_le_bytes(leader_id)))
+                .unwrap_or(IggyError::NotClusterLeader(0)),
+            error => error,
+        }
+    }
+
     pub fn from_code_as_string(code: u32) -> &'static str {
         IggyErrorDiscriminants::from_repr(code)
             .map(|discriminant| discriminant.into())
@@ -463,4 +576,11 @@ mod tests {
             IggyError::from_code_as_string(GROUP_NAME_ERROR_CODE)
         )
     }
+
+    #[test]
+    fn rate_limit_error_keeps_retry_after_in_payload() {
+        let error = IggyError::RateLimitExceeded(250);
+        let error = IggyError::from_code_and_payload(error.as_code(), &error.as_payload());
+        assert!(matches!(error, IggyError::RateLimitExceeded(250)));
+    }
 }
@@ -0,0 +1,50 @@
+use crate::backups::create_backup::CreateBackup;
+use crate::backups::restore_backup::RestoreBackup;
+use crate::client::BackupClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::http::HttpTransport;
+use crate::models::backup::{BackupInfo, BackupRestoreTarget};
+use crate::utils::timestamp::IggyTimestamp;
+use async_trait::async_trait;
+
+const PATH: &str = "/backups";
+
+#[async_trait]
+impl BackupClient for HttpClient {
+    async fn create_backup(&self, incremental: bool) -> Result<BackupInfo, IggyError> {
+        let response = self.post(PATH, &CreateBackup { incremental }).await?;
+        let backup = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(backup)
+    }
+
+    async fn get_backups(&self) -> Result<Vec<BackupInfo>, IggyError> {
+        let response = self.get(PATH).await?;
+        let backups = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(backups)
+    }
+
+    async fn restore_backup(
+        &self,
+        timestamp: Option<IggyTimestamp>,
+        target: BackupRestoreTarget,
+    ) -> Result<BackupInfo, IggyError> {
+        let response = self
+            .post(
+                &format!("{PATH}/restore"),
+                &RestoreBackup { timestamp, target },
+            )
+            .await?;
+        let backup = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(backup)
+    }
+}
@@ -8,6 +8,7 @@ use crate::models::identity_info::IdentityInfo;
 use crate::utils::duration::IggyDuration;
 use async_broadcast::{broadcast, Receiver, Sender};
 use async_trait::async_trait;
+use reqwest::header::RETRY_AFTER;
 use reqwest::{Response, StatusCode, Url};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
@@ -272,6 +273,15 @@ impl HttpClient {
         match status.is_success() {
             true => Ok(response),
             false => {
+                let retry_after = response
+                    .headers()
+                    .get(RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.parse::<u64>().ok());
+                if let (StatusCode::TOO_MANY_REQUESTS, Some(retry_after)) = (status, retry_after) {
+                    return Err(IggyError::RateLimitExceeded(retry_after * 1000));
+                }
+
                 let reason = response.text().await.unwrap_or("error".to_string());
                 match status {
                     StatusCode::UNAUTHORIZED => Err(IggyError::Unauthenticated),
@@ -5,7 +5,7 @@ use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
 use crate::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
-use crate::messages::poll_messages::{PollMessages, PollingStrategy};
+use crate::messages::poll_messages::{PollMessages, PolledMessagesFormat, PollingStrategy};
 use crate::messages::send_messages::{Message, Partitioning, SendMessages};
 use crate::models::messages::PolledMessages;
 use async_trait::async_trait;
@@ -33,6 +33,7 @@ impl MessageClient for HttpClient {
                     strategy: *strategy,
                     count,
                     auto_commit,
+                    format: PolledMessagesFormat::Messages,
                 },
             )
             .await?;
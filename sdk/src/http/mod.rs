@@ -5,6 +5,7 @@ use reqwest::{Response, Url};
 use serde::Serialize;
 
 #[allow(deprecated)]
+pub mod backups;
 pub mod client;
 pub mod config;
 pub mod consumer_groups;
@@ -12,8 +13,10 @@ pub mod consumer_offsets;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod streams;
 pub mod system;
+pub mod topic_templates;
 pub mod topics;
 pub mod users;
 
@@ -3,8 +3,10 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::models::partitions_merge::PartitionsMergeInfo;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::merge_partitions::MergePartitions;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -44,6 +46,59 @@ impl PartitionClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn merge_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<PartitionsMergeInfo, IggyError> {
+        let response = self
+            .post(
+                &get_merge_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &MergePartitions {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partitions_count,
+                },
+            )
+            .await?;
+        let merge = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(merge)
+    }
+
+    async fn get_partitions_merge(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Option<PartitionsMergeInfo>, IggyError> {
+        let response = self
+            .get(&get_merge_path(
+                &stream_id.as_cow_str(),
+                &topic_id.as_cow_str(),
+            ))
+            .await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let merge = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(merge))
+    }
+}
+
+fn get_merge_path(stream_id: &str, topic_id: &str) -> String {
+    format!("{}/merge", get_path(stream_id, topic_id))
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
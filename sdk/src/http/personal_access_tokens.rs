@@ -3,6 +3,7 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::models::identity_info::IdentityInfo;
+use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
@@ -26,6 +27,7 @@ impl PersonalAccessTokenClient for HttpClient {
         &self,
         name: &str,
         expiry: PersonalAccessTokenExpiry,
+        permissions: Option<Permissions>,
     ) -> Result<RawPersonalAccessToken, IggyError> {
         let response = self
             .post(
@@ -33,6 +35,7 @@ impl PersonalAccessTokenClient for HttpClient {
                 &CreatePersonalAccessToken {
                     name: name.to_string(),
                     expiry,
+                    permissions,
                 },
             )
             .await?;
@@ -0,0 +1,146 @@
+use crate::client::SchemaClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::http::HttpTransport;
+use crate::identifier::Identifier;
+use crate::models::schema::{Schema, SchemaCompatibility, SchemaType, TopicSchemas};
+use crate::schemas::register_topic_schema::RegisterTopicSchema;
+use crate::schemas::update_topic_schema_settings::UpdateTopicSchemaSettings;
+use async_trait::async_trait;
+
+const PATH: &str = "/schemas";
+
+#[async_trait]
+impl SchemaClient for HttpClient {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        let response = self.get(&format!("{PATH}/{schema_id}")).await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let schema = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(schema))
+    }
+
+    async fn get_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: Option<u32>,
+    ) -> Result<Option<Schema>, IggyError> {
+        let version = version.map_or_else(|| "latest".to_string(), |version| version.to_string());
+        let response = self
+            .get(&get_version_path(
+                &stream_id.as_cow_str(),
+                &topic_id.as_cow_str(),
+                &version,
+            ))
+            .await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let schema = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(schema))
+    }
+
+    async fn get_topic_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError> {
+        let response = self
+            .get(&get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()))
+            .await?;
+        let schemas = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(schemas)
+    }
+
+    async fn register_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        definition: &str,
+    ) -> Result<Schema, IggyError> {
+        let response = self
+            .post(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &RegisterTopicSchema {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    schema_type,
+                    definition: definition.to_string(),
+                },
+            )
+            .await?;
+        let schema = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(schema)
+    }
+
+    async fn delete_topic_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: u32,
+    ) -> Result<(), IggyError> {
+        self.delete(&get_version_path(
+            &stream_id.as_cow_str(),
+            &topic_id.as_cow_str(),
+            &version.to_string(),
+        ))
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_schema_settings(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+        validate_payloads: bool,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/settings",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &UpdateTopicSchemaSettings {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                compatibility,
+                validate_payloads,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+fn get_version_path(stream_id: &str, topic_id: &str, version: &str) -> String {
+    format!("{}/{version}", get_path(stream_id, topic_id))
+}
+
+fn get_path(stream_id: &str, topic_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/schemas")
+}
@@ -4,8 +4,10 @@ use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
 use crate::models::stream::{Stream, StreamDetails};
+use crate::models::topic_template::TopicDefaults;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::streams::update_stream_topic_defaults::UpdateStreamTopicDefaults;
 use async_trait::async_trait;
 
 const PATH: &str = "/streams";
@@ -85,8 +87,44 @@ impl StreamClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn get_stream_topic_defaults(
+        &self,
+        stream_id: &Identifier,
+    ) -> Result<TopicDefaults, IggyError> {
+        let response = self
+            .get(&get_topic_defaults_path(&stream_id.as_cow_str()))
+            .await?;
+        let topic_defaults = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(topic_defaults)
+    }
+
+    async fn update_stream_topic_defaults(
+        &self,
+        stream_id: &Identifier,
+        topic_defaults: TopicDefaults,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &get_topic_defaults_path(&stream_id.as_cow_str()),
+            &UpdateStreamTopicDefaults {
+                stream_id: stream_id.clone(),
+                message_expiry: topic_defaults.message_expiry,
+                max_topic_size: topic_defaults.max_topic_size,
+                replication_factor: topic_defaults.replication_factor,
+            },
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_details_path(stream_id: &str) -> String {
     format!("{PATH}/{stream_id}")
 }
+
+fn get_topic_defaults_path(stream_id: &str) -> String {
+    format!("{PATH}/{stream_id}/topic-defaults")
+}
@@ -0,0 +1,63 @@
+use crate::client::TopicTemplateClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::http::HttpTransport;
+use crate::models::topic_template::TopicTemplate;
+use crate::topic_templates::create_topic_template::CreateTopicTemplate;
+use async_trait::async_trait;
+
+const PATH: &str = "/topic-templates";
+
+#[async_trait]
+impl TopicTemplateClient for HttpClient {
+    async fn get_topic_template(&self, name: &str) -> Result<Option<TopicTemplate>, IggyError> {
+        let response = self.get(&get_details_path(name)).await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let template = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(template))
+    }
+
+    async fn get_topic_templates(&self) -> Result<Vec<TopicTemplate>, IggyError> {
+        let response = self.get(PATH).await?;
+        let templates = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(templates)
+    }
+
+    async fn create_topic_template(&self, template: TopicTemplate) -> Result<(), IggyError> {
+        self.post(
+            PATH,
+            &CreateTopicTemplate {
+                name: template.name,
+                partitions_count: template.partitions_count,
+                compression_algorithm: template.compression_algorithm,
+                message_expiry: template.message_expiry,
+                max_topic_size: template.max_topic_size,
+                replication_factor: template.replication_factor,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_topic_template(&self, name: &str) -> Result<(), IggyError> {
+        self.delete(&get_details_path(name)).await?;
+        Ok(())
+    }
+}
+
+fn get_details_path(name: &str) -> String {
+    format!("{PATH}/{name}")
+}
@@ -71,6 +71,37 @@ impl TopicClient for HttpClient {
                     topic_id,
                     message_expiry,
                     max_topic_size,
+                    template: None,
+                },
+            )
+            .await?;
+        let topic = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(topic)
+    }
+
+    async fn create_topic_from_template(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        template: &str,
+        topic_id: Option<u32>,
+    ) -> Result<TopicDetails, IggyError> {
+        let response = self
+            .post(
+                &get_path(&stream_id.as_cow_str()),
+                &CreateTopic {
+                    stream_id: stream_id.clone(),
+                    name: name.to_string(),
+                    partitions_count: 0,
+                    compression_algorithm: CompressionAlgorithm::None,
+                    replication_factor: None,
+                    topic_id,
+                    message_expiry: IggyExpiry::ServerDefault,
+                    max_topic_size: MaxTopicSize::ServerDefault,
+                    template: Some(template.to_string()),
                 },
             )
             .await?;
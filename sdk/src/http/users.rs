@@ -10,6 +10,7 @@ use crate::models::user_status::UserStatus;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::login_user::LoginUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
 use async_trait::async_trait;
@@ -127,6 +128,17 @@ impl UserClient for HttpClient {
         Ok(())
     }
 
+    async fn unlock_user(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        self.post(
+            &format!("{PATH}/{}/unlock", &user_id.as_cow_str()),
+            &UnlockUser {
+                user_id: user_id.clone(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn login_user(&self, username: &str, password: &str) -> Result<IdentityInfo, IggyError> {
         let response = self
             .post(
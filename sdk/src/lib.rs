@@ -1,4 +1,5 @@
 pub mod args;
+pub mod backups;
 pub mod binary;
 pub mod bytes_serializable;
 #[cfg(feature = "iggy-cli")]
@@ -29,11 +30,13 @@ pub mod partitioner;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod quic;
+pub mod schemas;
 pub mod snapshot;
 pub mod stream_builder;
 pub mod streams;
 pub mod system;
 pub mod tcp;
+pub mod topic_templates;
 pub mod topics;
 pub mod users;
 pub mod utils;
@@ -21,6 +21,7 @@ use std::str::FromStr;
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
 /// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+/// - `format` - format of the polled messages accepted by the client, used only by the binary protocol.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     /// Consumer which will poll messages. Either regular consumer or consumer group.
@@ -44,6 +45,28 @@ pub struct PollMessages {
     #[serde(default)]
     /// Whether to commit offset on the server automatically after polling the messages.
     pub auto_commit: bool,
+    #[serde(skip)]
+    /// Format of the polled messages accepted by the client, used only by the binary protocol.
+    pub format: PolledMessagesFormat,
+}
+
+/// The version of the batch format supported by the client, sent by the server along with the `Batches` format.
+pub const POLLED_BATCH_FORMAT_VERSION: u8 = 2;
+
+/// `PolledMessagesFormat` specifies the format of the polled messages accepted by the client.
+/// It has the following kinds:
+/// - `Messages` - the messages are serialized one by one, the default format.
+/// - `Batches` - the message batches can be sent as stored in the segment file, which allows the server
+///   to send them directly from the disk. The server still responds with `Messages` whenever it's not possible,
+///   so the response starts with the format code.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PolledMessagesFormat {
+    #[default]
+    /// The messages are serialized one by one.
+    Messages,
+    /// The message batches are sent as stored in the segment file.
+    Batches,
 }
 
 /// `PollingStrategy` specifies from where to start polling messages.
@@ -93,6 +116,7 @@ impl Default for PollMessages {
             strategy: default_strategy(),
             count: default_count(),
             auto_commit: false,
+            format: PolledMessagesFormat::default(),
         }
     }
 }
@@ -212,6 +236,25 @@ impl PollingKind {
     }
 }
 
+impl PolledMessagesFormat {
+    /// Returns code of the polled messages format.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PolledMessagesFormat::Messages => 0,
+            PolledMessagesFormat::Batches => 1,
+        }
+    }
+
+    /// Returns polled messages format from the specified code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            0 => Ok(PolledMessagesFormat::Messages),
+            1 => Ok(PolledMessagesFormat::Batches),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
 impl FromStr for PollingKind {
     type Err = IggyError;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
@@ -248,6 +291,7 @@ impl BytesSerializable for PollMessages {
             &self.strategy,
             self.count,
             self.auto_commit,
+            self.format,
         )
     }
 
@@ -295,6 +339,11 @@ impl BytesSerializable for PollMessages {
         );
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        // The format is optional to remain compatible with the clients which don't send it.
+        let format = match bytes.get(position + 13) {
+            Some(code) => PolledMessagesFormat::from_code(*code)?,
+            None => PolledMessagesFormat::Messages,
+        };
         let command = PollMessages {
             consumer,
             stream_id,
@@ -303,12 +352,14 @@ impl BytesSerializable for PollMessages {
             strategy,
             count,
             auto_commit,
+            format,
         };
         Ok(command)
     }
 }
 
 // This method is used by the new version of `IggyClient` to serialize `PollMessages` without cloning the args.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn as_bytes(
     stream_id: &Identifier,
     topic_id: &Identifier,
@@ -317,13 +368,14 @@ pub(crate) fn as_bytes(
     strategy: &PollingStrategy,
     count: u32,
     auto_commit: bool,
+    format: PolledMessagesFormat,
 ) -> Bytes {
     let consumer_bytes = consumer.to_bytes();
     let stream_id_bytes = stream_id.to_bytes();
     let topic_id_bytes = topic_id.to_bytes();
     let strategy_bytes = strategy.to_bytes();
     let mut bytes = BytesMut::with_capacity(
-        9 + consumer_bytes.len()
+        10 + consumer_bytes.len()
             + stream_id_bytes.len()
             + topic_id_bytes.len()
             + strategy_bytes.len(),
@@ -343,6 +395,10 @@ pub(crate) fn as_bytes(
     } else {
         bytes.put_u8(0);
     }
+    // The default format is omitted, so the servers which don't support the other formats can still parse it.
+    if format != PolledMessagesFormat::Messages {
+        bytes.put_u8(format.as_code());
+    }
 
     bytes.freeze()
 }
@@ -415,6 +471,7 @@ mod tests {
             strategy: PollingStrategy::offset(2),
             count: 3,
             auto_commit: true,
+            format: PolledMessagesFormat::Messages,
         };
 
         let bytes = command.to_bytes();
@@ -452,6 +509,26 @@ mod tests {
         assert_eq!(auto_commit, command.auto_commit);
     }
 
+    #[test]
+    fn should_be_serialized_and_deserialized_with_batches_format() {
+        let command = PollMessages {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: Some(4),
+            strategy: PollingStrategy::next(),
+            count: 3,
+            auto_commit: true,
+            format: PolledMessagesFormat::Batches,
+        };
+
+        let bytes = command.to_bytes();
+        assert_eq!(bytes.last(), Some(&PolledMessagesFormat::Batches.as_code()));
+
+        let deserialized_command = PollMessages::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized_command, command);
+    }
+
     #[test]
     fn should_be_deserialized_from_bytes() {
         let consumer = Consumer::new(Identifier::numeric(1).unwrap());
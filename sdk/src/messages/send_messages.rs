@@ -5,6 +5,7 @@ use crate::identifier::Identifier;
 use crate::messages::{MAX_HEADERS_SIZE, MAX_PAYLOAD_SIZE};
 use crate::models::header;
 use crate::models::header::{HeaderKey, HeaderValue};
+use crate::models::messages::get_key_size_bytes;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::sizeable::Sizeable;
 use crate::validatable::Validatable;
@@ -19,6 +20,10 @@ use std::str::FromStr;
 use uuid::Uuid;
 
 const EMPTY_KEY_VALUE: Vec<u8> = vec![];
+/// The flag set on the headers length when the message has a key.
+/// The key is then serialized right after the headers, prefixed with its length (1 byte),
+/// so that the messages without a key keep the same binary format.
+const MESSAGE_KEY_FLAG: u32 = 1 << 31;
 
 /// `SendMessages` command is used to send messages to a topic in a stream.
 /// It has additional payload:
@@ -71,6 +76,7 @@ impl Hash for Partitioning {
 /// - `length` - length of the payload.
 /// - `payload` - binary message payload.
 /// - `headers` - optional collection of headers.
+/// - `key` - optional key of the message (up to 255 bytes), stored along with it and returned when polling.
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct Message {
@@ -85,6 +91,11 @@ pub struct Message {
     pub payload: Bytes,
     /// Optional collection of headers.
     pub headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    /// Optional key of the message (up to 255 bytes), stored along with it and returned when polling.
+    /// The value of the `MessagesKey` partitioning is only used to calculate the partition, and isn't stored.
+    #[serde_as(as = "Option<Base64>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<Bytes>,
 }
 
 /// `PartitioningKind` is an enum which specifies the kind of partitioning and is used by `Partitioning`.
@@ -239,6 +250,11 @@ impl Validatable<IggyError> for SendMessages {
                     }
                 }
             }
+            if let Some(key) = &message.key {
+                if key.is_empty() || key.len() > 255 {
+                    return Err(IggyError::InvalidKeyValueLength);
+                }
+            }
             payload_size += message.payload.len() as u32;
             if payload_size > MAX_PAYLOAD_SIZE {
                 return Err(IggyError::TooBigMessagePayload);
@@ -287,14 +303,23 @@ impl Message {
             length: payload.len() as u32,
             payload,
             headers,
+            key: None,
         }
     }
+
+    /// Sets the key of the message, stored along with it and returned when polling.
+    pub fn with_key(mut self, key: Bytes) -> Self {
+        self.key = Some(key);
+        self
+    }
 }
 
 impl Sizeable for Message {
     fn get_size_bytes(&self) -> IggyByteSize {
-        // ID + Length + Payload + Headers
-        header::get_headers_size_bytes(&self.headers) + (16 + 4 + self.payload.len() as u64).into()
+        // ID + Length + Payload + Headers + Key
+        header::get_headers_size_bytes(&self.headers)
+            + get_key_size_bytes(&self.key)
+            + (16 + 4 + self.payload.len() as u64).into()
     }
 }
 
@@ -306,6 +331,7 @@ impl Default for Message {
             length: payload.len() as u32,
             payload,
             headers: None,
+            key: None,
         }
     }
 }
@@ -364,12 +390,21 @@ impl BytesSerializable for Message {
     fn to_bytes(&self) -> Bytes {
         let mut bytes = BytesMut::with_capacity(self.get_size_bytes().as_bytes_usize());
         bytes.put_u128_le(self.id);
+        let key_flag = match self.key {
+            Some(_) => MESSAGE_KEY_FLAG,
+            None => 0,
+        };
         if let Some(headers) = &self.headers {
             let headers_bytes = headers.to_bytes();
-            bytes.put_u32_le(headers_bytes.len() as u32);
+            bytes.put_u32_le(headers_bytes.len() as u32 | key_flag);
             bytes.put_slice(&headers_bytes);
         } else {
-            bytes.put_u32_le(0);
+            bytes.put_u32_le(key_flag);
+        }
+        if let Some(key) = &self.key {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(key.len() as u8);
+            bytes.put_slice(key);
         }
         bytes.put_u32_le(self.length);
         bytes.put_slice(&self.payload);
@@ -394,16 +429,35 @@ impl BytesSerializable for Message {
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
+        let has_key = headers_length & MESSAGE_KEY_FLAG != 0;
+        let headers_length = (headers_length & !MESSAGE_KEY_FLAG) as usize;
         let headers = if headers_length > 0 {
             Some(HashMap::from_bytes(
-                bytes.slice(20..20 + headers_length as usize),
+                bytes
+                    .get(20..20 + headers_length)
+                    .map(|headers| bytes.slice_ref(headers))
+                    .ok_or(IggyError::InvalidCommand)?,
             )?)
         } else {
             None
         };
 
+        let mut position = 20 + headers_length;
+        let key = if has_key {
+            let key_length = *bytes.get(position).ok_or(IggyError::InvalidCommand)? as usize;
+            let key = bytes
+                .get(position + 1..position + 1 + key_length)
+                .ok_or(IggyError::InvalidCommand)?;
+            position += 1 + key_length;
+            Some(bytes.slice_ref(key))
+        } else {
+            None
+        };
+
         let payload_length = u32::from_le_bytes(
-            bytes[20 + headers_length as usize..24 + headers_length as usize]
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
@@ -411,18 +465,18 @@ impl BytesSerializable for Message {
             return Err(IggyError::EmptyMessagePayload);
         }
 
-        let payload = bytes.slice(
-            24 + headers_length as usize..24 + headers_length as usize + payload_length as usize,
-        );
-        if payload.len() != payload_length as usize {
-            return Err(IggyError::InvalidMessagePayloadLength);
-        }
+        position += 4;
+        let payload = bytes
+            .get(position..position + payload_length as usize)
+            .map(|payload| bytes.slice_ref(payload))
+            .ok_or(IggyError::InvalidMessagePayloadLength)?;
 
         Ok(Message {
             id,
             length: payload_length,
             payload,
             headers,
+            key,
         })
     }
 }
@@ -472,6 +526,7 @@ impl FromStr for Message {
             length,
             payload,
             headers: None,
+            key: None,
         })
     }
 }
@@ -604,6 +659,33 @@ mod tests {
         assert_eq!(messages, command_messages);
     }
 
+    #[test]
+    fn message_with_key_should_be_serialized_and_deserialized() {
+        let headers = HashMap::from([(
+            HeaderKey::new("header").unwrap(),
+            HeaderValue::from_str("value").unwrap(),
+        )]);
+        let message = Message::new(Some(1), "hello".into(), Some(headers))
+            .with_key(Bytes::from("message-key"));
+
+        let bytes = message.to_bytes();
+        let deserialized_message = Message::from_bytes(bytes.clone()).unwrap();
+
+        assert_eq!(bytes.len(), message.get_size_bytes().as_bytes_usize());
+        assert_eq!(deserialized_message, message);
+    }
+
+    #[test]
+    fn message_without_key_should_keep_the_same_binary_format() {
+        let message = Message::new(Some(1), "hello".into(), None);
+
+        let bytes = message.to_bytes();
+
+        assert_eq!(bytes.len(), 16 + 4 + 4 + 5);
+        assert_eq!(u32::from_le_bytes(bytes[16..20].try_into().unwrap()), 0);
+        assert_eq!(Message::from_bytes(bytes).unwrap().key, None);
+    }
+
     #[test]
     fn should_be_deserialized_from_bytes() {
         let stream_id = Identifier::numeric(1).unwrap();
@@ -0,0 +1,99 @@
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `BackupInfo` represents the backup of the server data (state, segments and consumer offsets).
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the backup.
+/// - `kind`: the kind of the backup, either full or incremental.
+/// - `parent_id`: the identifier of the backup the incremental backup is based on.
+/// - `created_at`: the timestamp when the backup was created, i.e. the point in time it can be restored to.
+/// - `files_count`: the total number of files included in the backup.
+/// - `size`: the total size of the data stored by the backup, excluding the data of its parent.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BackupInfo {
+    /// The unique identifier (numeric) of the backup.
+    pub id: u64,
+    /// The kind of the backup, either full or incremental.
+    pub kind: BackupKind,
+    /// The identifier of the backup the incremental backup is based on.
+    pub parent_id: Option<u64>,
+    /// The timestamp when the backup was created, i.e. the point in time it can be restored to.
+    pub created_at: IggyTimestamp,
+    /// The total number of files included in the backup.
+    pub files_count: u32,
+    /// The total size of the data stored by the backup, excluding the data of its parent.
+    pub size: IggyByteSize,
+}
+
+/// `BackupKind` represents the kind of the backup.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupKind {
+    /// The backup contains the complete copy of all the files.
+    #[default]
+    Full,
+    /// The backup contains only the data appended since its parent backup.
+    Incremental,
+}
+
+/// `BackupRestoreTarget` represents the destination of the restored backup.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(tag = "kind", content = "path", rename_all = "snake_case")]
+pub enum BackupRestoreTarget {
+    /// The backup is restored to the empty local directory (on the server side).
+    Directory(String),
+    /// The backup is restored using the configured archiver (e.g. disk or S3).
+    Archiver,
+}
+
+impl FromStr for BackupKind {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "full" => Ok(BackupKind::Full),
+            "incremental" => Ok(BackupKind::Incremental),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for BackupKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackupKind::Full => write!(f, "full"),
+            BackupKind::Incremental => write!(f, "incremental"),
+        }
+    }
+}
+
+impl BackupKind {
+    /// Returns the code of the backup kind.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            BackupKind::Full => 1,
+            BackupKind::Incremental => 2,
+        }
+    }
+
+    /// Returns the backup kind from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(BackupKind::Full),
+            2 => Ok(BackupKind::Incremental),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for BackupRestoreTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackupRestoreTarget::Directory(path) => write!(f, "directory:{path}"),
+            BackupRestoreTarget::Archiver => write!(f, "archiver"),
+        }
+    }
+}
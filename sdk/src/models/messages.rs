@@ -15,6 +15,11 @@ use std::str::FromStr;
 
 pub const POLLED_MESSAGE_METADATA: u32 = 8 + 1 + 8 + 4;
 
+/// The flag set on the message state code when the message has a key.
+/// The key is then stored right after the headers, prefixed with its length (1 byte),
+/// so that the messages without a key keep the same binary format.
+pub const MESSAGE_KEY_FLAG: u8 = 0b1000_0000;
+
 /// The wrapper on top of the collection of messages that are polled from the partition.
 /// It consists of the following fields:
 /// - `partition_id`: the identifier of the partition.
@@ -38,6 +43,7 @@ pub struct PolledMessages {
 /// - `id`: the identifier of the message.
 /// - `checksum`: the checksum of the message, can be used to verify the integrity of the message.
 /// - `headers`: the optional headers of the message.
+/// - `key`: the optional key of the message, provided when sending it.
 /// - `length`: the length of the payload.
 /// - `payload`: the binary payload of the message.
 #[serde_as]
@@ -55,6 +61,10 @@ pub struct PolledMessage {
     pub checksum: u32,
     /// The optional headers of the message.
     pub headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    /// The optional key of the message, provided when sending it.
+    #[serde_as(as = "Option<Base64>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<Bytes>,
     /// The length of the payload.
     #[serde(skip)]
     pub length: IggyByteSize,
@@ -98,6 +108,20 @@ impl MessageState {
             _ => Err(IggyError::InvalidCommand),
         }
     }
+
+    /// Returns the code of the message state, including the flag denoting whether the message has a key.
+    pub fn as_code_with_key_flag(&self, has_key: bool) -> u8 {
+        match has_key {
+            true => self.as_code() | MESSAGE_KEY_FLAG,
+            false => self.as_code(),
+        }
+    }
+
+    /// Returns the message state and whether the message has a key from the code.
+    pub fn from_code_with_key_flag(code: u8) -> Result<(Self, bool), IggyError> {
+        let state = MessageState::from_code(code & !MESSAGE_KEY_FLAG)?;
+        Ok((state, code & MESSAGE_KEY_FLAG != 0))
+    }
 }
 
 impl Display for MessageState {
@@ -144,6 +168,7 @@ impl PolledMessage {
             length: IggyByteSize::from(payload.len() as u64),
             payload,
             headers,
+            key: None,
         }
     }
 
@@ -155,7 +180,7 @@ impl PolledMessage {
     /// Extends the provided bytes with the message.
     pub fn extend(&self, bytes: &mut BytesMut) {
         bytes.put_u64_le(self.offset);
-        bytes.put_u8(self.state.as_code());
+        bytes.put_u8(self.state.as_code_with_key_flag(self.key.is_some()));
         bytes.put_u64_le(self.timestamp);
         bytes.put_u128_le(self.id);
         bytes.put_u32_le(self.checksum);
@@ -167,6 +192,11 @@ impl PolledMessage {
         } else {
             bytes.put_u32_le(0u32);
         }
+        if let Some(key) = &self.key {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(key.len() as u8);
+            bytes.put_slice(key);
+        }
         bytes.put_u32_le(self.length.as_bytes_u64() as u32);
         bytes.put_slice(&self.payload);
     }
@@ -174,9 +204,17 @@ impl PolledMessage {
 
 impl Sizeable for PolledMessage {
     fn get_size_bytes(&self) -> IggyByteSize {
-        // Offset + State + Timestamp + ID + Checksum + Length + Payload + Headers
+        // Offset + State + Timestamp + ID + Checksum + Length + Payload + Headers + Key
         header::get_headers_size_bytes(&self.headers)
+            + get_key_size_bytes(&self.key)
             + self.length
             + IggyByteSize::from(8 + 1 + 8 + 16 + 4 + 4)
     }
 }
+
+/// Returns the size of the optional message key, including its length (1 byte).
+pub fn get_key_size_bytes(key: &Option<Bytes>) -> IggyByteSize {
+    key.as_ref()
+        .map(|key| IggyByteSize::from(1 + key.len() as u64))
+        .unwrap_or_default()
+}
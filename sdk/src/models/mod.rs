@@ -1,3 +1,4 @@
+pub mod backup;
 pub mod client_info;
 pub mod consumer_group;
 pub mod consumer_offset_info;
@@ -5,11 +6,14 @@ pub mod header;
 pub mod identity_info;
 pub mod messages;
 pub mod partition;
+pub mod partitions_merge;
 pub mod permissions;
 pub mod personal_access_token;
+pub mod schema;
 pub mod snapshot;
 pub mod stats;
 pub mod stream;
 pub mod topic;
+pub mod topic_template;
 pub mod user_info;
 pub mod user_status;
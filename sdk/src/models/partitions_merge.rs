@@ -0,0 +1,81 @@
+use crate::error::IggyError;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `PartitionsMergeInfo` represents the progress of merging the partitions of the topic.
+/// It consists of the following fields:
+/// - `stream_id`: the unique identifier (numeric) of the stream.
+/// - `topic_id`: the unique identifier (numeric) of the topic.
+/// - `status`: the status of the merge.
+/// - `partitions_count`: the number of the partitions being merged into the remaining ones.
+/// - `merged_partitions`: the number of the partitions whose messages have been already moved.
+/// - `messages_count`: the total number of the messages stored in the merged partitions.
+/// - `merged_messages`: the number of the messages that have been already moved.
+/// - `started_at`: the timestamp when the merge was started.
+/// - `finished_at`: the timestamp when the merge was completed or failed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PartitionsMergeInfo {
+    /// The unique identifier (numeric) of the stream.
+    pub stream_id: u32,
+    /// The unique identifier (numeric) of the topic.
+    pub topic_id: u32,
+    /// The status of the merge.
+    pub status: PartitionsMergeStatus,
+    /// The number of the partitions being merged into the remaining ones.
+    pub partitions_count: u32,
+    /// The number of the partitions whose messages have been already moved.
+    pub merged_partitions: u32,
+    /// The total number of the messages stored in the merged partitions.
+    pub messages_count: u64,
+    /// The number of the messages that have been already moved.
+    pub merged_messages: u64,
+    /// The timestamp when the merge was started.
+    pub started_at: IggyTimestamp,
+    /// The timestamp when the merge was completed or failed.
+    pub finished_at: Option<IggyTimestamp>,
+}
+
+/// `PartitionsMergeStatus` represents the status of the partitions merge.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionsMergeStatus {
+    /// The messages of the merged partitions are being moved.
+    #[default]
+    InProgress,
+    /// All the messages have been moved and the merged partitions have been deleted.
+    Completed,
+    /// The merge has been interrupted, e.g. because the topic has been deleted.
+    Failed,
+}
+
+impl Display for PartitionsMergeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartitionsMergeStatus::InProgress => write!(f, "in_progress"),
+            PartitionsMergeStatus::Completed => write!(f, "completed"),
+            PartitionsMergeStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl PartitionsMergeStatus {
+    /// Returns the code of the partitions merge status.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PartitionsMergeStatus::InProgress => 1,
+            PartitionsMergeStatus::Completed => 2,
+            PartitionsMergeStatus::Failed => 3,
+        }
+    }
+
+    /// Returns the partitions merge status from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(PartitionsMergeStatus::InProgress),
+            2 => Ok(PartitionsMergeStatus::Completed),
+            3 => Ok(PartitionsMergeStatus::Failed),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
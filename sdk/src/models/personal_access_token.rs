@@ -1,3 +1,4 @@
+use crate::models::permissions::Permissions;
 use crate::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
 
@@ -14,10 +15,14 @@ pub struct RawPersonalAccessToken {
 /// It consists of the following fields:
 /// - `name`: the unique name of the token.
 /// - `expiry`: the optional expiry of the token.
+/// - `permissions`: the optional permissions restricting the token, if not set, the token has all the permissions of its owner.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PersonalAccessTokenInfo {
     /// The unique name of the token.
     pub name: String,
     /// The optional expiry of the token.
     pub expiry_at: Option<IggyTimestamp>,
+    /// The optional permissions restricting the token, if not set, the token has all the permissions of its owner.
+    #[serde(default)]
+    pub permissions: Option<Permissions>,
 }
@@ -0,0 +1,225 @@
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The key of the message header containing the ID of the schema the payload was written with.
+/// The value of the header is expected to be `u32`.
+pub const SCHEMA_ID_HEADER: &str = "iggy-schema-id";
+
+/// `Schema` represents the version of the schema registered for the topic.
+/// It consists of the following fields:
+/// - `id`: the unique identifier of the schema, across all the topics.
+/// - `stream_id`: the unique identifier (numeric) of the stream.
+/// - `topic_id`: the unique identifier (numeric) of the topic.
+/// - `version`: the version of the schema in the topic, starting from 1.
+/// - `schema_type`: the type of the schema.
+/// - `definition`: the definition of the schema, e.g. JSON Schema document, Avro schema or Protobuf file.
+/// - `created_at`: the timestamp when the schema was registered.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Schema {
+    /// The unique identifier of the schema, across all the topics.
+    pub id: u32,
+    /// The unique identifier (numeric) of the stream.
+    pub stream_id: u32,
+    /// The unique identifier (numeric) of the topic.
+    pub topic_id: u32,
+    /// The version of the schema in the topic, starting from 1.
+    pub version: u32,
+    /// The type of the schema.
+    pub schema_type: SchemaType,
+    /// The definition of the schema, e.g. JSON Schema document, Avro schema or Protobuf file.
+    pub definition: String,
+    /// The timestamp when the schema was registered.
+    pub created_at: IggyTimestamp,
+}
+
+/// `TopicSchemas` represents the schema registry of the topic.
+/// It consists of the following fields:
+/// - `compatibility`: the compatibility rule the newly registered schemas are checked against.
+/// - `validate_payloads`: whether the payloads of the appended messages are validated against the schemas.
+/// - `schemas`: all the versions of the schemas registered for the topic, ordered by version.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct TopicSchemas {
+    /// The compatibility rule the newly registered schemas are checked against.
+    pub compatibility: SchemaCompatibility,
+    /// Whether the payloads of the appended messages are validated against the schemas.
+    pub validate_payloads: bool,
+    /// All the versions of the schemas registered for the topic, ordered by version.
+    pub schemas: Vec<Schema>,
+}
+
+/// `SchemaType` represents the format of the schema definition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaType {
+    /// JSON Schema document, the payloads are JSON documents.
+    #[default]
+    JsonSchema,
+    /// Avro schema in JSON format, the payloads are Avro binary encoded datums.
+    Avro,
+    /// Protobuf file (proto2 or proto3), the payloads are the binary encoded first message of the file.
+    Protobuf,
+}
+
+/// `SchemaCompatibility` represents the rule the new version of the schema is checked against the previous one.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaCompatibility {
+    /// The schemas are not checked.
+    None,
+    /// The consumers using the new schema can read the data written with the previous one.
+    #[default]
+    Backward,
+    /// The consumers using the previous schema can read the data written with the new one.
+    Forward,
+    /// Both backward and forward compatible.
+    Full,
+}
+
+impl SchemaType {
+    /// Returns the code of the schema type.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaType::JsonSchema => 1,
+            SchemaType::Avro => 2,
+            SchemaType::Protobuf => 3,
+        }
+    }
+
+    /// Returns the schema type from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaType::JsonSchema),
+            2 => Ok(SchemaType::Avro),
+            3 => Ok(SchemaType::Protobuf),
+            _ => Err(IggyError::InvalidSchemaType),
+        }
+    }
+}
+
+impl FromStr for SchemaType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json_schema" | "json" => Ok(SchemaType::JsonSchema),
+            "avro" => Ok(SchemaType::Avro),
+            "protobuf" | "proto" => Ok(SchemaType::Protobuf),
+            _ => Err(format!("Unknown schema type: {s}")),
+        }
+    }
+}
+
+impl Display for SchemaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaType::JsonSchema => write!(f, "json_schema"),
+            SchemaType::Avro => write!(f, "avro"),
+            SchemaType::Protobuf => write!(f, "protobuf"),
+        }
+    }
+}
+
+impl SchemaCompatibility {
+    /// Returns the code of the schema compatibility.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaCompatibility::None => 1,
+            SchemaCompatibility::Backward => 2,
+            SchemaCompatibility::Forward => 3,
+            SchemaCompatibility::Full => 4,
+        }
+    }
+
+    /// Returns the schema compatibility from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaCompatibility::None),
+            2 => Ok(SchemaCompatibility::Backward),
+            3 => Ok(SchemaCompatibility::Forward),
+            4 => Ok(SchemaCompatibility::Full),
+            _ => Err(IggyError::InvalidSchemaCompatibility),
+        }
+    }
+}
+
+impl FromStr for SchemaCompatibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(SchemaCompatibility::None),
+            "backward" => Ok(SchemaCompatibility::Backward),
+            "forward" => Ok(SchemaCompatibility::Forward),
+            "full" => Ok(SchemaCompatibility::Full),
+            _ => Err(format!("Unknown schema compatibility: {s}")),
+        }
+    }
+}
+
+impl Display for SchemaCompatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaCompatibility::None => write!(f, "none"),
+            SchemaCompatibility::Backward => write!(f, "backward"),
+            SchemaCompatibility::Forward => write!(f, "forward"),
+            SchemaCompatibility::Full => write!(f, "full"),
+        }
+    }
+}
+
+/// Returns the message header referencing the schema with the given ID,
+/// which can be added to the headers of the sent messages.
+pub fn schema_id_header(schema_id: u32) -> Result<(HeaderKey, HeaderValue), IggyError> {
+    Ok((
+        HeaderKey::new(SCHEMA_ID_HEADER)?,
+        HeaderValue::from_uint32(schema_id)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_type_should_be_mapped_to_and_from_code() {
+        for schema_type in [
+            SchemaType::JsonSchema,
+            SchemaType::Avro,
+            SchemaType::Protobuf,
+        ] {
+            assert_eq!(
+                SchemaType::from_code(schema_type.as_code()).unwrap(),
+                schema_type
+            );
+            assert_eq!(
+                SchemaType::from_str(&schema_type.to_string()).unwrap(),
+                schema_type
+            );
+        }
+        assert!(SchemaType::from_code(0).is_err());
+    }
+
+    #[test]
+    fn schema_compatibility_should_be_mapped_to_and_from_code() {
+        for compatibility in [
+            SchemaCompatibility::None,
+            SchemaCompatibility::Backward,
+            SchemaCompatibility::Forward,
+            SchemaCompatibility::Full,
+        ] {
+            assert_eq!(
+                SchemaCompatibility::from_code(compatibility.as_code()).unwrap(),
+                compatibility
+            );
+            assert_eq!(
+                SchemaCompatibility::from_str(&compatibility.to_string()).unwrap(),
+                compatibility
+            );
+        }
+        assert!(SchemaCompatibility::from_code(0).is_err());
+    }
+}
@@ -56,6 +56,21 @@ pub struct Stats {
     /// Cache metrics per partition
     #[serde(with = "cache_metrics_serializer")]
     pub cache_metrics: HashMap<CacheMetricsKey, CacheMetrics>,
+    /// The total number of failed login attempts since the server start.
+    #[serde(default)]
+    pub failed_login_attempts: u64,
+    /// The total number of temporary lockouts caused by the failed login attempts since the server start.
+    #[serde(default)]
+    pub login_lockouts: u64,
+    /// The total number of requests rejected due to the exceeded resource quotas since the server start.
+    #[serde(default)]
+    pub quota_violations: u64,
+    /// The number of segments repaired on startup, due to the incomplete or corrupted data at the tail of the log.
+    #[serde(default)]
+    pub recovered_segments: u64,
+    /// The number of bytes of the incomplete or corrupted data truncated from the segments on startup.
+    #[serde(default)]
+    pub truncated_segment_bytes: u64,
 }
 
 /// Key for identifying a specific partition's cache metrics
@@ -163,6 +178,11 @@ impl Default for Stats {
             iggy_server_version: "unknown_iggy_version".to_string(),
             iggy_server_semver: None,
             cache_metrics: HashMap::new(),
+            failed_login_attempts: 0,
+            login_lockouts: 0,
+            quota_violations: 0,
+            recovered_segments: 0,
+            truncated_segment_bytes: 0,
         }
     }
 }
@@ -0,0 +1,44 @@
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_size::MaxTopicSize;
+use serde::{Deserialize, Serialize};
+
+/// `TopicTemplate` represents the named set of settings which can be used to create the topics.
+/// It consists of the following fields:
+/// - `name`: the unique name of the template.
+/// - `partitions_count`: the number of partitions in the created topic.
+/// - `compression_algorithm`: the compression algorithm of the created topic.
+/// - `message_expiry`: the expiry of the messages, if `ServerDefault` then the stream or server default is used.
+/// - `max_topic_size`: the maximum size of the topic, if `ServerDefault` then the stream or server default is used.
+/// - `replication_factor`: the replication factor, if `None` then the stream or server default is used.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TopicTemplate {
+    /// The unique name of the template.
+    pub name: String,
+    /// The number of partitions in the created topic.
+    pub partitions_count: u32,
+    /// The compression algorithm of the created topic.
+    pub compression_algorithm: CompressionAlgorithm,
+    /// The expiry of the messages, if `ServerDefault` then the stream or server default is used.
+    pub message_expiry: IggyExpiry,
+    /// The maximum size of the topic, if `ServerDefault` then the stream or server default is used.
+    pub max_topic_size: MaxTopicSize,
+    /// The replication factor, if `None` then the stream or server default is used.
+    pub replication_factor: Option<u8>,
+}
+
+/// `TopicDefaults` represents the default settings of the topics created in the stream,
+/// used for the fields which are left at `ServerDefault` when creating the topic.
+/// It consists of the following fields:
+/// - `message_expiry`: the expiry of the messages, if `ServerDefault` then the server default is used.
+/// - `max_topic_size`: the maximum size of the topic, if `ServerDefault` then the server default is used.
+/// - `replication_factor`: the replication factor, if `None` then the server default is used.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq)]
+pub struct TopicDefaults {
+    /// The expiry of the messages, if `ServerDefault` then the server default is used.
+    pub message_expiry: IggyExpiry,
+    /// The maximum size of the topic, if `ServerDefault` then the server default is used.
+    pub max_topic_size: MaxTopicSize,
+    /// The replication factor, if `None` then the server default is used.
+    pub replication_factor: Option<u8>,
+}
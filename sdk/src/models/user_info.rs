@@ -35,6 +35,7 @@ pub struct UserInfo {
 /// - `status`: the status of the user.
 /// - `username`: the username of the user.
 /// - `permissions`: the optional permissions of the user.
+/// - `usage`: the optional usage of the resources created by the user.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserInfoDetails {
     /// The unique identifier (numeric) of the user.
@@ -47,4 +48,31 @@ pub struct UserInfoDetails {
     pub username: String,
     /// The optional permissions of the user.
     pub permissions: Option<Permissions>,
+    /// The optional usage of the resources created by the user, along with the configured quotas.
+    #[serde(default)]
+    pub usage: Option<UserUsage>,
+}
+
+/// `UserUsage` represents the resources created by the user and the quotas applied to them.
+/// It consists of the following fields:
+/// - `streams_count`: the number of streams created by the user.
+/// - `topics_count`: the number of topics created by the user.
+/// - `partitions_count`: the number of partitions in the topics created by the user.
+/// - `max_streams`: the maximum number of streams, `0` means unlimited.
+/// - `max_topics`: the maximum number of topics, `0` means unlimited.
+/// - `max_partitions`: the maximum number of partitions, `0` means unlimited.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct UserUsage {
+    /// The number of streams created by the user.
+    pub streams_count: u32,
+    /// The number of topics created by the user.
+    pub topics_count: u32,
+    /// The number of partitions in the topics created by the user.
+    pub partitions_count: u32,
+    /// The maximum number of streams, `0` means unlimited.
+    pub max_streams: u32,
+    /// The maximum number of topics, `0` means unlimited.
+    pub max_topics: u32,
+    /// The maximum number of partitions, `0` means unlimited.
+    pub max_partitions: u32,
 }
@@ -0,0 +1,126 @@
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::Message;
+use crate::models::header::HeaderKey;
+use crate::partitioner::{murmur2, Partitioner};
+
+/// The partitioner assigning the messages to the partitions based on the Kafka-compatible murmur2 hash of their key.
+/// The key is the value of the configured header or the message ID (big-endian) if there's no key header set.
+/// Since all the messages in a single batch are sent to the same partition, the key of the first message is used.
+#[derive(Debug)]
+pub struct ConsistentHashPartitioner {
+    partitions_count: u32,
+    key_header: Option<HeaderKey>,
+}
+
+impl ConsistentHashPartitioner {
+    /// Creates a new partitioner for the topic with the provided number of partitions.
+    pub fn new(partitions_count: u32) -> Result<Self, IggyError> {
+        if partitions_count == 0 {
+            return Err(IggyError::InvalidPartitionsCount);
+        }
+
+        Ok(Self {
+            partitions_count,
+            key_header: None,
+        })
+    }
+
+    /// Uses the value of the provided header as the key instead of the message ID.
+    pub fn with_key_header(self, key_header: HeaderKey) -> Self {
+        Self {
+            key_header: Some(key_header),
+            ..self
+        }
+    }
+
+    /// Returns the partition ID (starting from 1) for the provided key.
+    pub fn get_partition_id(&self, key: &[u8]) -> u32 {
+        murmur2::to_positive(murmur2::hash(key)) % self.partitions_count + 1
+    }
+}
+
+impl Partitioner for ConsistentHashPartitioner {
+    fn calculate_partition_id(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        messages: &[Message],
+    ) -> Result<u32, IggyError> {
+        let message = messages.first().ok_or(IggyError::InvalidMessagesCount)?;
+        let Some(key_header) = &self.key_header else {
+            return Ok(self.get_partition_id(&message.id.to_be_bytes()));
+        };
+
+        let header = message
+            .headers
+            .as_ref()
+            .and_then(|headers| headers.get(key_header))
+            .ok_or_else(|| IggyError::MessageHeaderNotFound(key_header.to_string()))?;
+        Ok(self.get_partition_id(&header.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::header::HeaderValue;
+    use bytes::Bytes;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    #[test]
+    fn partition_id_should_match_kafka_partition_shifted_by_one() {
+        let partitioner = ConsistentHashPartitioner::new(10).unwrap();
+        // murmur2("foobar") = -790332482, to_positive = 1357151166, 1357151166 % 10 = 6
+        assert_eq!(partitioner.get_partition_id(b"foobar"), 7);
+    }
+
+    #[test]
+    fn messages_with_the_same_key_header_should_be_assigned_to_the_same_partition() {
+        let key_header = HeaderKey::new("key").unwrap();
+        let partitioner = ConsistentHashPartitioner::new(3)
+            .unwrap()
+            .with_key_header(key_header.clone());
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(1).unwrap();
+        let headers = HashMap::from([(key_header, HeaderValue::from_str("customer-1").unwrap())]);
+        let first = [Message::new(
+            Some(1),
+            Bytes::from("1"),
+            Some(headers.clone()),
+        )];
+        let second = [Message::new(Some(2), Bytes::from("2"), Some(headers))];
+
+        let first_partition_id = partitioner
+            .calculate_partition_id(&stream_id, &topic_id, &first)
+            .unwrap();
+        let second_partition_id = partitioner
+            .calculate_partition_id(&stream_id, &topic_id, &second)
+            .unwrap();
+
+        assert_eq!(first_partition_id, second_partition_id);
+        assert!((1..=3).contains(&first_partition_id));
+    }
+
+    #[test]
+    fn missing_key_header_should_fail() {
+        let partitioner = ConsistentHashPartitioner::new(3)
+            .unwrap()
+            .with_key_header(HeaderKey::new("key").unwrap());
+        let messages = [Message::new(Some(1), Bytes::from("1"), None)];
+
+        let result = partitioner.calculate_partition_id(
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(1).unwrap(),
+            &messages,
+        );
+
+        assert!(matches!(result, Err(IggyError::MessageHeaderNotFound(_))));
+    }
+
+    #[test]
+    fn zero_partitions_should_fail() {
+        assert!(ConsistentHashPartitioner::new(0).is_err());
+    }
+}
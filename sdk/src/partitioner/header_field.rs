@@ -0,0 +1,115 @@
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::Message;
+use crate::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use crate::partitioner::{murmur2, Partitioner};
+
+/// The partitioner assigning the messages to the partitions based on the value of the configured header.
+/// The unsigned integer values are used directly as the partition ID, while the values of any other kind
+/// are hashed using murmur2, the same way as the keys of the `ConsistentHashPartitioner`.
+/// Since all the messages in a single batch are sent to the same partition, the header of the first message is used.
+#[derive(Debug)]
+pub struct HeaderFieldPartitioner {
+    partitions_count: u32,
+    header_key: HeaderKey,
+}
+
+impl HeaderFieldPartitioner {
+    /// Creates a new partitioner reading the provided header, for the topic with the provided number of partitions.
+    pub fn new(partitions_count: u32, header_key: HeaderKey) -> Result<Self, IggyError> {
+        if partitions_count == 0 {
+            return Err(IggyError::InvalidPartitionsCount);
+        }
+
+        Ok(Self {
+            partitions_count,
+            header_key,
+        })
+    }
+
+    fn get_partition_id(&self, value: &HeaderValue) -> Result<u32, IggyError> {
+        let partition_id = match value.kind {
+            HeaderKind::Uint8 => value.as_uint8()? as u64,
+            HeaderKind::Uint16 => value.as_uint16()? as u64,
+            HeaderKind::Uint32 => value.as_uint32()? as u64,
+            HeaderKind::Uint64 => value.as_uint64()?,
+            _ => {
+                let hash = murmur2::to_positive(murmur2::hash(&value.value));
+                return Ok(hash % self.partitions_count + 1);
+            }
+        };
+
+        if partition_id == 0 || partition_id > self.partitions_count as u64 {
+            return Err(IggyError::InvalidPartitionId(partition_id));
+        }
+
+        Ok(partition_id as u32)
+    }
+}
+
+impl Partitioner for HeaderFieldPartitioner {
+    fn calculate_partition_id(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        messages: &[Message],
+    ) -> Result<u32, IggyError> {
+        let message = messages.first().ok_or(IggyError::InvalidMessagesCount)?;
+        let value = message
+            .headers
+            .as_ref()
+            .and_then(|headers| headers.get(&self.header_key))
+            .ok_or_else(|| IggyError::MessageHeaderNotFound(self.header_key.to_string()))?;
+        self.get_partition_id(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    fn calculate_partition_id(
+        partitioner: &HeaderFieldPartitioner,
+        value: HeaderValue,
+    ) -> Result<u32, IggyError> {
+        let headers = HashMap::from([(HeaderKey::new("partition").unwrap(), value)]);
+        let messages = [Message::new(Some(1), Bytes::from("1"), Some(headers))];
+        partitioner.calculate_partition_id(
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(1).unwrap(),
+            &messages,
+        )
+    }
+
+    fn create_partitioner() -> HeaderFieldPartitioner {
+        HeaderFieldPartitioner::new(3, HeaderKey::new("partition").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn unsigned_integer_value_should_be_used_as_partition_id() {
+        let partitioner = create_partitioner();
+        let partition_id =
+            calculate_partition_id(&partitioner, HeaderValue::from_uint32(2).unwrap()).unwrap();
+        assert_eq!(partition_id, 2);
+    }
+
+    #[test]
+    fn unsigned_integer_value_out_of_range_should_fail() {
+        let partitioner = create_partitioner();
+        let result = calculate_partition_id(&partitioner, HeaderValue::from_uint64(4).unwrap());
+        assert!(matches!(result, Err(IggyError::InvalidPartitionId(4))));
+    }
+
+    #[test]
+    fn other_values_should_be_hashed() {
+        let partitioner = create_partitioner();
+        let value = HeaderValue::from_str("customer-1").unwrap();
+        let first_partition_id = calculate_partition_id(&partitioner, value.clone()).unwrap();
+        let second_partition_id = calculate_partition_id(&partitioner, value).unwrap();
+        assert_eq!(first_partition_id, second_partition_id);
+        assert!((1..=3).contains(&first_partition_id));
+    }
+}
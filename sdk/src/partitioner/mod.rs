@@ -1,3 +1,9 @@
+pub mod consistent_hash;
+pub mod header_field;
+pub mod murmur2;
+pub mod partitioner_kind;
+pub mod sticky_batch;
+
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::messages::send_messages::Message;
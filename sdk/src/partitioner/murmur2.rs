@@ -0,0 +1,72 @@
+const SEED: u32 = 0x9747_b28c;
+const M: u32 = 0x5bd1_e995;
+const R: u32 = 24;
+
+/// Calculates the 32-bit murmur2 hash of the provided data, compatible with the one used by the Kafka clients,
+/// so that the same keys are assigned to the same partitions (when counting them from 0).
+pub fn hash(data: &[u8]) -> i32 {
+    let length = data.len();
+    let mut h = SEED ^ length as u32;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in chunks.by_ref() {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(M);
+        k ^= k >> R;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M);
+        h ^= k;
+    }
+
+    let remainder = chunks.remainder();
+    if remainder.len() >= 3 {
+        h ^= (remainder[2] as u32) << 16;
+    }
+    if remainder.len() >= 2 {
+        h ^= (remainder[1] as u32) << 8;
+    }
+    if !remainder.is_empty() {
+        h ^= remainder[0] as u32;
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+    h as i32
+}
+
+/// Converts the hash to a positive number the same way as the Kafka clients do (by clearing the sign bit).
+pub fn to_positive(hash: i32) -> u32 {
+    (hash & 0x7fff_ffff) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_should_be_compatible_with_kafka() {
+        let cases = [
+            ("21", -973932308),
+            ("foobar", -790332482),
+            ("a-little-bit-long-string", -985981536),
+            ("a-little-bit-longer-string", -1486304829),
+            (
+                "lkjh234lh9fiuh90y23oiuhsafujhadof229phr9h19h89h8",
+                -58897971,
+            ),
+            ("abc", 479470107),
+        ];
+
+        for (data, expected_hash) in cases {
+            assert_eq!(hash(data.as_bytes()), expected_hash);
+        }
+    }
+
+    #[test]
+    fn to_positive_should_clear_the_sign_bit() {
+        assert_eq!(to_positive(-1), 0x7fff_ffff);
+        assert_eq!(to_positive(i32::MIN), 0);
+        assert_eq!(to_positive(123), 123);
+    }
+}
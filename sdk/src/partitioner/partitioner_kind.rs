@@ -0,0 +1,41 @@
+use crate::error::IggyError;
+use crate::models::header::HeaderKey;
+use crate::partitioner::consistent_hash::ConsistentHashPartitioner;
+use crate::partitioner::header_field::HeaderFieldPartitioner;
+use crate::partitioner::sticky_batch::StickyBatchPartitioner;
+use crate::partitioner::Partitioner;
+use std::sync::Arc;
+
+/// The built-in partitioner implementations, which can be selected by the producer.
+/// The partitioner is created once the number of partitions of the topic is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartitionerKind {
+    /// The Kafka-compatible murmur2 hash of the message ID or the value of the optional key header.
+    ConsistentHash { key_header: Option<HeaderKey> },
+    /// Each batch is sent to a single partition, switching to the next one for the next batch.
+    StickyBatch,
+    /// The partition is selected based on the value of the header.
+    HeaderField { header_key: HeaderKey },
+}
+
+impl PartitionerKind {
+    /// Creates the partitioner for the topic with the provided number of partitions.
+    pub fn create(&self, partitions_count: u32) -> Result<Arc<dyn Partitioner>, IggyError> {
+        Ok(match self {
+            PartitionerKind::ConsistentHash { key_header } => {
+                let partitioner = ConsistentHashPartitioner::new(partitions_count)?;
+                match key_header {
+                    Some(key_header) => Arc::new(partitioner.with_key_header(key_header.clone())),
+                    None => Arc::new(partitioner),
+                }
+            }
+            PartitionerKind::StickyBatch => {
+                Arc::new(StickyBatchPartitioner::new(partitions_count)?)
+            }
+            PartitionerKind::HeaderField { header_key } => Arc::new(HeaderFieldPartitioner::new(
+                partitions_count,
+                header_key.clone(),
+            )?),
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::Message;
+use crate::partitioner::Partitioner;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// The partitioner sending each batch of messages as a whole to a single partition, and switching to the next partition
+/// (in the round-robin manner) for the next batch. Contrary to the balanced partitioning done by the server,
+/// the batches are never split, which results in fewer and larger appends.
+#[derive(Debug)]
+pub struct StickyBatchPartitioner {
+    partitions_count: u32,
+    batches_count: AtomicU32,
+}
+
+impl StickyBatchPartitioner {
+    /// Creates a new partitioner for the topic with the provided number of partitions.
+    pub fn new(partitions_count: u32) -> Result<Self, IggyError> {
+        if partitions_count == 0 {
+            return Err(IggyError::InvalidPartitionsCount);
+        }
+
+        Ok(Self {
+            partitions_count,
+            batches_count: AtomicU32::new(0),
+        })
+    }
+}
+
+impl Partitioner for StickyBatchPartitioner {
+    fn calculate_partition_id(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        _messages: &[Message],
+    ) -> Result<u32, IggyError> {
+        let batch = self.batches_count.fetch_add(1, Ordering::Relaxed);
+        Ok(batch % self.partitions_count + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_batch_should_be_assigned_to_the_next_partition() {
+        let partitioner = StickyBatchPartitioner::new(3).unwrap();
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(1).unwrap();
+
+        let partition_ids = (0..4)
+            .map(|_| {
+                partitioner
+                    .calculate_partition_id(&stream_id, &topic_id, &[])
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(partition_ids, vec![1, 2, 3, 1]);
+    }
+}
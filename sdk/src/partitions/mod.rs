@@ -1,4 +1,6 @@
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod get_partitions_merge;
+pub mod merge_partitions;
 
 const MAX_PARTITIONS_COUNT: u32 = 1000;
@@ -1,6 +1,7 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{Command, CREATE_PERSONAL_ACCESS_TOKEN_CODE};
 use crate::error::IggyError;
+use crate::models::permissions::Permissions;
 use crate::users::defaults::*;
 use crate::utils::expiry::IggyExpiry;
 use crate::validatable::Validatable;
@@ -13,12 +14,17 @@ use std::str::from_utf8;
 /// It has additional payload:
 /// - `name` - unique name of the token, must be between 3 and 30 characters long.
 /// - `expiry` - expiry of the token.
+/// - `permissions` - optional permissions restricting the token. If not provided, the token has all the permissions of its owner.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreatePersonalAccessToken {
     /// Unique name of the token, must be between 3 and 30 characters long.
     pub name: String,
     /// Expiry of the token.
     pub expiry: IggyExpiry,
+    /// Optional permissions restricting the token. If not provided, the token has all the permissions of its owner.
+    /// The token can never exceed the permissions of its owner, even if they are changed after the token was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>,
 }
 
 impl Command for CreatePersonalAccessToken {
@@ -32,6 +38,7 @@ impl Default for CreatePersonalAccessToken {
         CreatePersonalAccessToken {
             name: "token".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            permissions: None,
         }
     }
 }
@@ -56,6 +63,15 @@ impl BytesSerializable for CreatePersonalAccessToken {
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
         bytes.put_u64_le(self.expiry.into());
+        if let Some(permissions) = &self.permissions {
+            bytes.put_u8(1);
+            let permissions = permissions.to_bytes();
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u32_le(permissions.len() as u32);
+            bytes.put_slice(&permissions);
+        } else {
+            bytes.put_u8(0);
+        }
         bytes.freeze()
     }
 
@@ -72,34 +88,76 @@ impl BytesSerializable for CreatePersonalAccessToken {
             return Err(IggyError::InvalidCommand);
         }
 
-        let position = 1 + name_length as usize;
+        let mut position = 1 + name_length as usize;
         let expiry = u64::from_le_bytes(
             bytes[position..position + 8]
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
         let expiry: IggyExpiry = expiry.into();
+        position += 8;
+
+        // The tokens created before the permissions were introduced have no trailing flag.
+        let has_permissions = bytes.get(position).copied().unwrap_or(0);
+        if has_permissions > 1 {
+            return Err(IggyError::InvalidCommand);
+        }
 
-        let command = CreatePersonalAccessToken { name, expiry };
+        position += 1;
+        let permissions = if has_permissions == 1 {
+            if bytes.len() < position + 4 {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            let permissions_length = u32::from_le_bytes(
+                bytes[position..position + 4]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            position += 4;
+            if bytes.len() < position + permissions_length as usize {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            Some(Permissions::from_bytes(
+                bytes.slice(position..position + permissions_length as usize),
+            )?)
+        } else {
+            None
+        };
+
+        let command = CreatePersonalAccessToken {
+            name,
+            expiry,
+            permissions,
+        };
         Ok(command)
     }
 }
 
 impl Display for CreatePersonalAccessToken {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.name, self.expiry)
+        let permissions = if let Some(permissions) = &self.permissions {
+            permissions.to_string()
+        } else {
+            "owner_permissions".to_string()
+        };
+        write!(f, "{}|{}|{}", self.name, self.expiry, permissions)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::permissions::{GlobalPermissions, StreamPermissions};
+    use ahash::AHashMap;
 
     #[test]
     fn should_be_serialized_as_bytes() {
         let command = CreatePersonalAccessToken {
             name: "test".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            permissions: None,
         };
 
         let bytes = command.to_bytes();
@@ -114,6 +172,7 @@ mod tests {
         assert!(!bytes.is_empty());
         assert_eq!(name, command.name);
         assert_eq!(expiry, command.expiry);
+        assert_eq!(bytes[9 + name_length as usize], 0);
     }
 
     #[test]
@@ -133,4 +192,39 @@ mod tests {
         assert_eq!(command.name, name);
         assert_eq!(command.expiry, expiry);
     }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_permissions_flag() {
+        let name = "test";
+        let mut bytes = BytesMut::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+        bytes.put_u64_le(IggyExpiry::NeverExpire.into());
+
+        let command = CreatePersonalAccessToken::from_bytes(bytes.freeze()).unwrap();
+        assert_eq!(command.name, name);
+        assert!(command.permissions.is_none());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_permissions() {
+        let command = CreatePersonalAccessToken {
+            name: "test".to_string(),
+            expiry: IggyExpiry::NeverExpire,
+            permissions: Some(Permissions {
+                global: GlobalPermissions::default(),
+                streams: Some(AHashMap::from([(
+                    1,
+                    StreamPermissions {
+                        send_messages: true,
+                        ..Default::default()
+                    },
+                )])),
+            }),
+        };
+
+        let deserialized = CreatePersonalAccessToken::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
 }
@@ -209,7 +209,10 @@ impl QuicClient {
                 IggyError::from_code_as_string(status)
             );
 
-            return Err(IggyError::from_code(status));
+            let payload = buffer
+                .get(RESPONSE_INITIAL_BYTES_LENGTH..)
+                .unwrap_or_default();
+            return Err(IggyError::from_code_and_payload(status, payload));
         }
 
         let length = u32::from_le_bytes(
@@ -0,0 +1,114 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, DELETE_TOPIC_SCHEMA_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `DeleteTopicSchema` command is used to delete the version of the schema registered for the topic.
+/// The messages referencing the deleted schema are no longer accepted, if the payloads are validated.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `version` - version of the schema to delete.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DeleteTopicSchema {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Version of the schema to delete.
+    #[serde(skip)]
+    pub version: u32,
+}
+
+impl Command for DeleteTopicSchema {
+    fn code(&self) -> u32 {
+        DELETE_TOPIC_SCHEMA_CODE
+    }
+}
+
+impl Default for DeleteTopicSchema {
+    fn default() -> Self {
+        DeleteTopicSchema {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            version: 1,
+        }
+    }
+}
+
+impl Validatable<IggyError> for DeleteTopicSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.version == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for DeleteTopicSchema {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(4 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.version);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<DeleteTopicSchema, IggyError> {
+        if bytes.len() < 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let version = u32::from_le_bytes(
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(DeleteTopicSchema {
+            stream_id,
+            topic_id,
+            version,
+        })
+    }
+}
+
+impl Display for DeleteTopicSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}|{}", self.stream_id, self.topic_id, self.version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = DeleteTopicSchema {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            version: 3,
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = DeleteTopicSchema::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_SCHEMA_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetSchema` command is used to retrieve the schema by its unique ID, e.g. the one referenced in the message header.
+/// It has additional payload:
+/// - `schema_id` - unique schema ID, across all the topics.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetSchema {
+    /// Unique schema ID, across all the topics.
+    #[serde(skip)]
+    pub schema_id: u32,
+}
+
+impl Command for GetSchema {
+    fn code(&self) -> u32 {
+        GET_SCHEMA_CODE
+    }
+}
+
+impl Default for GetSchema {
+    fn default() -> Self {
+        GetSchema { schema_id: 1 }
+    }
+}
+
+impl Validatable<IggyError> for GetSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.schema_id == 0 {
+            return Err(IggyError::SchemaNotFound(self.schema_id));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetSchema {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.schema_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetSchema, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let schema_id = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(GetSchema { schema_id })
+    }
+}
+
+impl Display for GetSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.schema_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = GetSchema { schema_id: 7 };
+
+        let bytes = command.to_bytes();
+        assert_eq!(bytes.len(), 4);
+
+        let deserialized = GetSchema::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_TOPIC_SCHEMA_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetTopicSchema` command is used to retrieve the version of the schema registered for the topic.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `version` - version of the schema, if `None` then the latest version is returned.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct GetTopicSchema {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Version of the schema, if `None` then the latest version is returned.
+    #[serde(skip)]
+    pub version: Option<u32>,
+}
+
+impl Command for GetTopicSchema {
+    fn code(&self) -> u32 {
+        GET_TOPIC_SCHEMA_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetTopicSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetTopicSchema {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(4 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.version.unwrap_or(0));
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetTopicSchema, IggyError> {
+        if bytes.len() < 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let version = u32::from_le_bytes(
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let version = match version {
+            0 => None,
+            version => Some(version),
+        };
+        Ok(GetTopicSchema {
+            stream_id,
+            topic_id,
+            version,
+        })
+    }
+}
+
+impl Display for GetTopicSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.version
+                .map_or_else(|| "latest".to_string(), |version| version.to_string())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = GetTopicSchema {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            version: Some(3),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = GetTopicSchema::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn latest_version_should_be_serialized_as_zero() {
+        let command = GetTopicSchema {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            version: None,
+        };
+
+        let bytes = command.to_bytes();
+        assert_eq!(
+            u32::from_le_bytes(bytes[bytes.len() - 4..].try_into().unwrap()),
+            0
+        );
+
+        let deserialized = GetTopicSchema::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
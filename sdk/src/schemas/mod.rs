@@ -0,0 +1,8 @@
+pub mod delete_topic_schema;
+pub mod get_schema;
+pub mod get_topic_schema;
+pub mod get_topic_schemas;
+pub mod register_topic_schema;
+pub mod update_topic_schema_settings;
+
+const MAX_DEFINITION_LENGTH: usize = 1024 * 1024;
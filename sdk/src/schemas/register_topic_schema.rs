@@ -0,0 +1,164 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, REGISTER_TOPIC_SCHEMA_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::schema::SchemaType;
+use crate::schemas::MAX_DEFINITION_LENGTH;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `RegisterTopicSchema` command is used to register the new version of the schema for the topic.
+/// The schema is checked against the latest version using the compatibility rule of the topic.
+/// If the same definition is already registered for the topic, the existing schema is returned.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `schema_type` - type of the schema, it must be the same for all the versions.
+/// - `definition` - definition of the schema, max length is 1 MB.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct RegisterTopicSchema {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Type of the schema, it must be the same for all the versions.
+    pub schema_type: SchemaType,
+    /// Definition of the schema, max length is 1 MB.
+    pub definition: String,
+}
+
+impl Command for RegisterTopicSchema {
+    fn code(&self) -> u32 {
+        REGISTER_TOPIC_SCHEMA_CODE
+    }
+}
+
+impl Default for RegisterTopicSchema {
+    fn default() -> Self {
+        RegisterTopicSchema {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            schema_type: SchemaType::JsonSchema,
+            definition: r#"{"type":"object"}"#.to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for RegisterTopicSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.definition.trim().is_empty() {
+            return Err(IggyError::InvalidSchema(
+                "Schema definition cannot be empty".to_string(),
+            ));
+        }
+
+        if self.definition.len() > MAX_DEFINITION_LENGTH {
+            return Err(IggyError::InvalidSchema(format!(
+                "Schema definition cannot be longer than {MAX_DEFINITION_LENGTH} bytes"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RegisterTopicSchema {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            5 + stream_id_bytes.len() + topic_id_bytes.len() + self.definition.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.schema_type.as_code());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(self.definition.len() as u32);
+        bytes.put_slice(self.definition.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RegisterTopicSchema, IggyError> {
+        if bytes.len() < 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let schema_type =
+            SchemaType::from_code(*bytes.get(position).ok_or(IggyError::InvalidCommand)?)?;
+        position += 1;
+        let definition_length = u32::from_le_bytes(
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 4;
+        let definition = from_utf8(
+            bytes
+                .get(position..position + definition_length)
+                .ok_or(IggyError::InvalidCommand)?,
+        )
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+        Ok(RegisterTopicSchema {
+            stream_id,
+            topic_id,
+            schema_type,
+            definition,
+        })
+    }
+}
+
+impl Display for RegisterTopicSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.schema_type,
+            self.definition.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = RegisterTopicSchema {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            schema_type: SchemaType::Avro,
+            definition: r#"{"type":"record","name":"Order","fields":[]}"#.to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = RegisterTopicSchema::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn empty_definition_should_be_invalid() {
+        let command = RegisterTopicSchema {
+            definition: " ".to_string(),
+            ..Default::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_TOPIC_SCHEMA_SETTINGS_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::schema::SchemaCompatibility;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateTopicSchemaSettings` command is used to update the schema registry settings of the topic.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `compatibility` - compatibility rule the newly registered schemas are checked against.
+/// - `validate_payloads` - whether the payloads of the appended messages are validated against the schemas.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateTopicSchemaSettings {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Compatibility rule the newly registered schemas are checked against.
+    pub compatibility: SchemaCompatibility,
+    /// Whether the payloads of the appended messages are validated against the schemas.
+    pub validate_payloads: bool,
+}
+
+impl Command for UpdateTopicSchemaSettings {
+    fn code(&self) -> u32 {
+        UPDATE_TOPIC_SCHEMA_SETTINGS_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateTopicSchemaSettings {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateTopicSchemaSettings {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(2 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.compatibility.as_code());
+        bytes.put_u8(u8::from(self.validate_payloads));
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdateTopicSchemaSettings, IggyError> {
+        if bytes.len() < 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let compatibility =
+            SchemaCompatibility::from_code(*bytes.get(position).ok_or(IggyError::InvalidCommand)?)?;
+        let validate_payloads = match bytes.get(position + 1) {
+            Some(0) => false,
+            Some(1) => true,
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        Ok(UpdateTopicSchemaSettings {
+            stream_id,
+            topic_id,
+            compatibility,
+            validate_payloads,
+        })
+    }
+}
+
+impl Display for UpdateTopicSchemaSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.compatibility, self.validate_payloads
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = UpdateTopicSchemaSettings {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            compatibility: SchemaCompatibility::Full,
+            validate_payloads: true,
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateTopicSchemaSettings::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
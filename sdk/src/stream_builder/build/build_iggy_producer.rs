@@ -52,6 +52,10 @@ pub(crate) async fn build_iggy_producer(
             MaxTopicSize::ServerDefault,
         );
 
+    if let Some(partitioner) = config.partitioner() {
+        builder = builder.partitioner_kind(partitioner.to_owned());
+    }
+
     if let Some(encryptor) = config.encryptor() {
         builder = builder.encryptor(encryptor);
     }
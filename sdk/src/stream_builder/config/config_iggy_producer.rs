@@ -3,6 +3,7 @@ use bon::Builder;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::messages::send_messages::Partitioning;
+use crate::partitioner::partitioner_kind::PartitionerKind;
 use crate::utils::crypto::EncryptorKind;
 use crate::utils::duration::IggyDuration;
 use std::str::FromStr;
@@ -29,6 +30,8 @@ pub struct IggyProducerConfig {
     send_interval: IggyDuration,
     /// Specifies to which partition the messages should be sent.
     partitioning: Partitioning,
+    /// Sets the optional built-in partitioner, which takes precedence over the `partitioning`.
+    partitioner: Option<PartitionerKind>,
     /// Sets the maximum number of send retries in case of a message sending failure.
     send_retries_count: Option<u32>,
     /// Sets the interval between send retries in case of a message sending failure.
@@ -51,6 +54,7 @@ impl Default for IggyProducerConfig {
             batch_size: 100,
             send_interval: IggyDuration::from_str("5ms").unwrap(),
             partitioning: Partitioning::balanced(),
+            partitioner: None,
             topic_partitions_count: 1,
             topic_replication_factor: None,
             encryptor: None,
@@ -74,6 +78,7 @@ impl IggyProducerConfig {
     /// * `batch_size` - The max number of messages to send in a batch.
     /// * `send_interval` - The interval between messages sent.
     /// * `partitioning` - The partitioning strategy to use.
+    /// * `partitioner` - The built-in partitioner to use.
     /// * `encryptor` - The encryptor to use.
     /// * `send_retries_count` - The number of retries to send messages.
     /// * `send_retries_interval` - The interval between retries.
@@ -92,6 +97,7 @@ impl IggyProducerConfig {
         batch_size: u32,
         send_interval: IggyDuration,
         partitioning: Partitioning,
+        partitioner: Option<PartitionerKind>,
         encryptor: Option<Arc<EncryptorKind>>,
         send_retries_count: Option<u32>,
         send_retries_interval: Option<IggyDuration>,
@@ -106,6 +112,7 @@ impl IggyProducerConfig {
             batch_size,
             send_interval,
             partitioning,
+            partitioner,
             encryptor,
             send_retries_count,
             send_retries_interval,
@@ -142,6 +149,7 @@ impl IggyProducerConfig {
             batch_size,
             send_interval,
             partitioning: Partitioning::balanced(),
+            partitioner: None,
             topic_partitions_count: 1,
             topic_replication_factor: None,
             encryptor: None,
@@ -180,6 +188,10 @@ impl IggyProducerConfig {
         &self.partitioning
     }
 
+    pub fn partitioner(&self) -> Option<&PartitionerKind> {
+        self.partitioner.as_ref()
+    }
+
     pub fn topic_partitions_count(&self) -> u32 {
         self.topic_partitions_count
     }
@@ -265,6 +277,7 @@ mod tests {
             IggyDuration::from_str("5ms").unwrap()
         );
         assert_eq!(config.partitioning(), &Partitioning::balanced());
+        assert_eq!(config.partitioner(), None);
         assert_eq!(config.topic_partitions_count(), 1);
         assert_eq!(config.topic_replication_factor(), None);
         assert_eq!(config.send_retries_count(), Some(3));
@@ -289,6 +302,7 @@ mod tests {
             100,
             IggyDuration::from_str("5ms").unwrap(),
             Partitioning::balanced(),
+            Some(PartitionerKind::StickyBatch),
             None,
             None,
             None,
@@ -303,6 +317,7 @@ mod tests {
             IggyDuration::from_str("5ms").unwrap()
         );
         assert_eq!(config.partitioning(), &Partitioning::balanced());
+        assert_eq!(config.partitioner(), Some(&PartitionerKind::StickyBatch));
         assert_eq!(config.topic_partitions_count(), 3);
         assert_eq!(config.topic_replication_factor(), None);
         assert_eq!(config.send_retries_count(), None);
@@ -0,0 +1,67 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_STREAM_TOPIC_DEFAULTS_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetStreamTopicDefaults` command is used to retrieve the default settings of the topics created in the stream.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct GetStreamTopicDefaults {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+}
+
+impl Command for GetStreamTopicDefaults {
+    fn code(&self) -> u32 {
+        GET_STREAM_TOPIC_DEFAULTS_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetStreamTopicDefaults {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetStreamTopicDefaults {
+    fn to_bytes(&self) -> Bytes {
+        self.stream_id.to_bytes()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<GetStreamTopicDefaults, IggyError> {
+        if bytes.len() < 3 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_bytes(bytes)?;
+        Ok(GetStreamTopicDefaults { stream_id })
+    }
+}
+
+impl Display for GetStreamTopicDefaults {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.stream_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = GetStreamTopicDefaults {
+            stream_id: Identifier::numeric(1).unwrap(),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = GetStreamTopicDefaults::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
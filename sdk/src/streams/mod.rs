@@ -1,8 +1,10 @@
 pub mod create_stream;
 pub mod delete_stream;
 pub mod get_stream;
+pub mod get_stream_topic_defaults;
 pub mod get_streams;
 pub mod purge_stream;
 pub mod update_stream;
+pub mod update_stream_topic_defaults;
 
 const MAX_NAME_LENGTH: usize = 255;
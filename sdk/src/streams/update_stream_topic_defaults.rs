@@ -0,0 +1,139 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_STREAM_TOPIC_DEFAULTS_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::sizeable::Sizeable;
+use crate::utils::topic_size::MaxTopicSize;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateStreamTopicDefaults` command is used to set the default settings of the topics created in the stream,
+/// which are used when the topic is created with the fields left at `ServerDefault`.
+/// Setting all the fields to `ServerDefault` (or `None`) clears the stream defaults.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `message_expiry` - message expiry, if `ServerDefault` then the server default is used.
+/// - `max_topic_size` - maximum size of the topic, if `ServerDefault` then the server default is used.
+/// - `replication_factor` - replication factor, if `None` then the server default is used.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateStreamTopicDefaults {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Message expiry, if `ServerDefault` then the server default is used.
+    pub message_expiry: IggyExpiry,
+    /// Max topic size, if `ServerDefault` then the server default is used.
+    pub max_topic_size: MaxTopicSize,
+    /// Replication factor, if `None` then the server default is used.
+    pub replication_factor: Option<u8>,
+}
+
+impl Command for UpdateStreamTopicDefaults {
+    fn code(&self) -> u32 {
+        UPDATE_STREAM_TOPIC_DEFAULTS_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateStreamTopicDefaults {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(replication_factor) = self.replication_factor {
+            if replication_factor == 0 {
+                return Err(IggyError::InvalidReplicationFactor);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateStreamTopicDefaults {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(17 + stream_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_u64_le(self.message_expiry.into());
+        bytes.put_u64_le(self.max_topic_size.into());
+        bytes.put_u8(self.replication_factor.unwrap_or(0));
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<UpdateStreamTopicDefaults, IggyError> {
+        if bytes.len() < 20 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        let position = stream_id.get_size_bytes().as_bytes_usize();
+        let bytes = bytes
+            .get(position..position + 17)
+            .ok_or(IggyError::InvalidCommand)?;
+        let message_expiry = u64::from_le_bytes(
+            bytes[0..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let max_topic_size = u64::from_le_bytes(
+            bytes[8..16]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let replication_factor = match bytes[16] {
+            0 => None,
+            factor => Some(factor),
+        };
+        Ok(UpdateStreamTopicDefaults {
+            stream_id,
+            message_expiry: message_expiry.into(),
+            max_topic_size: max_topic_size.into(),
+            replication_factor,
+        })
+    }
+}
+
+impl Display for UpdateStreamTopicDefaults {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id,
+            self.message_expiry,
+            self.max_topic_size,
+            self.replication_factor.unwrap_or(0)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::byte_size::IggyByteSize;
+    use crate::utils::duration::IggyDuration;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = UpdateStreamTopicDefaults {
+            stream_id: Identifier::named("stream").unwrap(),
+            message_expiry: IggyExpiry::ExpireDuration(IggyDuration::from_str("1d").unwrap()),
+            max_topic_size: MaxTopicSize::Custom(IggyByteSize::from_str("1GB").unwrap()),
+            replication_factor: Some(3),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateStreamTopicDefaults::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = UpdateStreamTopicDefaults {
+            stream_id: Identifier::numeric(1).unwrap(),
+            ..Default::default()
+        };
+        let bytes = command.to_bytes();
+        assert!(UpdateStreamTopicDefaults::from_bytes(bytes.slice(..bytes.len() - 1)).is_err());
+    }
+}
@@ -6,6 +6,7 @@ use crate::client::{
 use crate::command::Command;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::{IggyError, IggyErrorDiscriminants};
+use crate::messages::poll_messages::PolledMessagesFormat;
 use crate::tcp::config::TcpClientConfig;
 use crate::utils::duration::IggyDuration;
 use crate::utils::timestamp::IggyTimestamp;
@@ -289,6 +290,14 @@ impl BinaryTransport for TcpClient {
     fn get_heartbeat_interval(&self) -> IggyDuration {
         self.config.heartbeat_interval
     }
+
+    fn get_polled_messages_format(&self) -> PolledMessagesFormat {
+        if self.config.zero_copy_polling && !self.config.tls_enabled {
+            PolledMessagesFormat::Batches
+        } else {
+            PolledMessagesFormat::Messages
+        }
+    }
 }
 
 impl BinaryClient for TcpClient {}
@@ -373,7 +382,15 @@ impl TcpClient {
                 );
             }
 
-            return Err(IggyError::from_code(status));
+            if length == 0 {
+                return Err(IggyError::from_code(status));
+            }
+
+            // The error details, such as the retry-after hint, must be read to keep the stream consistent.
+            let mut payload = BytesMut::with_capacity(length as usize);
+            payload.put_bytes(0, length as usize);
+            stream.read(&mut payload).await?;
+            return Err(IggyError::from_code_and_payload(status, &payload));
         }
 
         trace!("Status: OK. Response length: {}", length);
@@ -21,6 +21,9 @@ pub struct TcpClientConfig {
     pub heartbeat_interval: IggyDuration,
     /// Disable Nagle algorithm for the TCP socket.
     pub nodelay: bool,
+    /// Whether to accept the polled messages as stored on the server disk, allowing it to send them without copying.
+    /// Applies only to the non-TLS connections.
+    pub zero_copy_polling: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -42,6 +45,7 @@ impl Default for TcpClientConfig {
             auto_login: AutoLogin::Disabled,
             reconnection: TcpClientReconnectionConfig::default(),
             nodelay: false,
+            zero_copy_polling: false,
         }
     }
 }
@@ -128,6 +132,12 @@ impl TcpClientConfigBuilder {
         self
     }
 
+    /// Sets the zero-copy polling, so the server can send the polled messages directly from the disk.
+    pub fn with_zero_copy_polling(mut self) -> Self {
+        self.config.zero_copy_polling = true;
+        self
+    }
+
     /// Builds the TCP client configuration.
     pub fn build(self) -> TcpClientConfig {
         self.config
@@ -0,0 +1,180 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, CREATE_TOPIC_TEMPLATE_CODE};
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::error::IggyError;
+use crate::topic_templates::{MAX_NAME_LENGTH, MAX_PARTITIONS_COUNT};
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_size::MaxTopicSize;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `CreateTopicTemplate` command is used to create a new named template with the topic settings.
+/// It has additional payload:
+/// - `name` - unique template name, max length is 255 characters.
+/// - `partitions_count` - number of partitions in the created topic, max value is 1000.
+/// - `compression_algorithm` - compression algorithm of the created topic.
+/// - `message_expiry` - message expiry, if `ServerDefault` then the stream or server default is used.
+/// - `max_topic_size` - maximum size of the topic, if `ServerDefault` then the stream or server default is used.
+/// - `replication_factor` - replication factor, if `None` then the stream or server default is used.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateTopicTemplate {
+    /// Unique template name, max length is 255 characters.
+    pub name: String,
+    /// Number of partitions in the created topic, max value is 1000.
+    pub partitions_count: u32,
+    /// Compression algorithm of the created topic.
+    pub compression_algorithm: CompressionAlgorithm,
+    /// Message expiry, if `ServerDefault` then the stream or server default is used.
+    pub message_expiry: IggyExpiry,
+    /// Max topic size, if `ServerDefault` then the stream or server default is used.
+    pub max_topic_size: MaxTopicSize,
+    /// Replication factor, if `None` then the stream or server default is used.
+    pub replication_factor: Option<u8>,
+}
+
+impl Command for CreateTopicTemplate {
+    fn code(&self) -> u32 {
+        CREATE_TOPIC_TEMPLATE_CODE
+    }
+}
+
+impl Default for CreateTopicTemplate {
+    fn default() -> Self {
+        CreateTopicTemplate {
+            name: "template".to_string(),
+            partitions_count: 1,
+            compression_algorithm: CompressionAlgorithm::None,
+            message_expiry: IggyExpiry::ServerDefault,
+            max_topic_size: MaxTopicSize::ServerDefault,
+            replication_factor: None,
+        }
+    }
+}
+
+impl Validatable<IggyError> for CreateTopicTemplate {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.name.is_empty() || self.name.len() > MAX_NAME_LENGTH {
+            return Err(IggyError::InvalidTopicTemplateName);
+        }
+
+        if self.partitions_count > MAX_PARTITIONS_COUNT {
+            return Err(IggyError::TooManyPartitions);
+        }
+
+        if let Some(replication_factor) = self.replication_factor {
+            if replication_factor == 0 {
+                return Err(IggyError::InvalidReplicationFactor);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for CreateTopicTemplate {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(23 + self.name.len());
+        bytes.put_u32_le(self.partitions_count);
+        bytes.put_u8(self.compression_algorithm.as_code());
+        bytes.put_u64_le(self.message_expiry.into());
+        bytes.put_u64_le(self.max_topic_size.into());
+        bytes.put_u8(self.replication_factor.unwrap_or(0));
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<CreateTopicTemplate, IggyError> {
+        if bytes.len() < 24 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partitions_count = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let compression_algorithm = CompressionAlgorithm::from_code(bytes[4])?;
+        let message_expiry = u64::from_le_bytes(
+            bytes[5..13]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let max_topic_size = u64::from_le_bytes(
+            bytes[13..21]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let replication_factor = match bytes[21] {
+            0 => None,
+            factor => Some(factor),
+        };
+        let name_length = bytes[22] as usize;
+        let name = from_utf8(
+            bytes
+                .get(23..23 + name_length)
+                .ok_or(IggyError::InvalidCommand)?,
+        )
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+        Ok(CreateTopicTemplate {
+            name,
+            partitions_count,
+            compression_algorithm,
+            message_expiry: message_expiry.into(),
+            max_topic_size: max_topic_size.into(),
+            replication_factor,
+        })
+    }
+}
+
+impl Display for CreateTopicTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.name,
+            self.partitions_count,
+            self.compression_algorithm,
+            self.message_expiry,
+            self.max_topic_size,
+            self.replication_factor.unwrap_or(0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::duration::IggyDuration;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = CreateTopicTemplate {
+            name: "test".to_string(),
+            partitions_count: 3,
+            compression_algorithm: CompressionAlgorithm::Gzip,
+            message_expiry: IggyExpiry::ExpireDuration(IggyDuration::from_str("1h").unwrap()),
+            max_topic_size: MaxTopicSize::Unlimited,
+            replication_factor: Some(2),
+        };
+
+        let bytes = command.to_bytes();
+        assert_eq!(bytes.len(), 23 + command.name.len());
+
+        let deserialized = CreateTopicTemplate::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = CreateTopicTemplate::default();
+        let bytes = command.to_bytes();
+        assert!(CreateTopicTemplate::from_bytes(bytes.slice(..bytes.len() - 1)).is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, DELETE_TOPIC_TEMPLATE_CODE};
+use crate::error::IggyError;
+use crate::topic_templates::MAX_NAME_LENGTH;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `DeleteTopicTemplate` command is used to delete the topic template, the topics created from it are not affected.
+/// It has additional payload:
+/// - `name` - unique template name, max length is 255 characters.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DeleteTopicTemplate {
+    /// Unique template name, max length is 255 characters.
+    pub name: String,
+}
+
+impl Command for DeleteTopicTemplate {
+    fn code(&self) -> u32 {
+        DELETE_TOPIC_TEMPLATE_CODE
+    }
+}
+
+impl Default for DeleteTopicTemplate {
+    fn default() -> Self {
+        DeleteTopicTemplate {
+            name: "template".to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for DeleteTopicTemplate {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.name.is_empty() || self.name.len() > MAX_NAME_LENGTH {
+            return Err(IggyError::InvalidTopicTemplateName);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for DeleteTopicTemplate {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1 + self.name.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<DeleteTopicTemplate, IggyError> {
+        if bytes.len() < 2 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[0] as usize;
+        let name = from_utf8(
+            bytes
+                .get(1..1 + name_length)
+                .ok_or(IggyError::InvalidCommand)?,
+        )
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+        Ok(DeleteTopicTemplate { name })
+    }
+}
+
+impl Display for DeleteTopicTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = DeleteTopicTemplate {
+            name: "test".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        assert_eq!(bytes[0] as usize, command.name.len());
+
+        let deserialized = DeleteTopicTemplate::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_TOPIC_TEMPLATE_CODE};
+use crate::error::IggyError;
+use crate::topic_templates::MAX_NAME_LENGTH;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `GetTopicTemplate` command is used to retrieve the topic template by its name.
+/// It has additional payload:
+/// - `name` - unique template name, max length is 255 characters.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetTopicTemplate {
+    /// Unique template name, max length is 255 characters.
+    pub name: String,
+}
+
+impl Command for GetTopicTemplate {
+    fn code(&self) -> u32 {
+        GET_TOPIC_TEMPLATE_CODE
+    }
+}
+
+impl Default for GetTopicTemplate {
+    fn default() -> Self {
+        GetTopicTemplate {
+            name: "template".to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for GetTopicTemplate {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.name.is_empty() || self.name.len() > MAX_NAME_LENGTH {
+            return Err(IggyError::InvalidTopicTemplateName);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetTopicTemplate {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1 + self.name.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetTopicTemplate, IggyError> {
+        if bytes.len() < 2 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[0] as usize;
+        let name = from_utf8(
+            bytes
+                .get(1..1 + name_length)
+                .ok_or(IggyError::InvalidCommand)?,
+        )
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+        Ok(GetTopicTemplate { name })
+    }
+}
+
+impl Display for GetTopicTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = GetTopicTemplate {
+            name: "test".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        assert_eq!(bytes[0] as usize, command.name.len());
+
+        let deserialized = GetTopicTemplate::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
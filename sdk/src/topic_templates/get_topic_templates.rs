@@ -0,0 +1,62 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_TOPIC_TEMPLATES_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetTopicTemplates` command is used to retrieve all the topic templates.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct GetTopicTemplates {}
+
+impl Command for GetTopicTemplates {
+    fn code(&self) -> u32 {
+        GET_TOPIC_TEMPLATES_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetTopicTemplates {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetTopicTemplates {
+    fn to_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetTopicTemplates, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(GetTopicTemplates {})
+    }
+}
+
+impl Display for GetTopicTemplates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = GetTopicTemplates {};
+        let bytes = command.to_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let command = GetTopicTemplates::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod create_topic_template;
+pub mod delete_topic_template;
+pub mod get_topic_template;
+pub mod get_topic_templates;
+
+const MAX_NAME_LENGTH: usize = 255;
+const MAX_PARTITIONS_COUNT: u32 = 1000;
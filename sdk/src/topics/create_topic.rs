@@ -23,6 +23,7 @@ use std::str::from_utf8;
 ///                      Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
 /// - `name` - unique topic name, max length is 255 characters.
+/// - `template` - optional name of the topic template, which overrides the partitions count and compression algorithm, and fills the fields left at `ServerDefault`.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateTopic {
     /// Unique stream ID (numeric or name).
@@ -43,6 +44,10 @@ pub struct CreateTopic {
     pub replication_factor: Option<u8>,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
+    /// Optional name of the topic template, which provides the partitions count and compression algorithm
+    /// (overriding the ones in the command), and the settings for the fields left at `ServerDefault`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
 }
 
 impl Command for CreateTopic {
@@ -62,6 +67,7 @@ impl Default for CreateTopic {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: None,
             name: "topic".to_string(),
+            template: None,
         }
     }
 }
@@ -88,6 +94,12 @@ impl Validatable<IggyError> for CreateTopic {
             }
         }
 
+        if let Some(template) = &self.template {
+            if template.is_empty() || template.len() > MAX_NAME_LENGTH {
+                return Err(IggyError::InvalidTopicTemplateName);
+            }
+        }
+
         Ok(())
     }
 }
@@ -95,7 +107,12 @@ impl Validatable<IggyError> for CreateTopic {
 impl BytesSerializable for CreateTopic {
     fn to_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.to_bytes();
-        let mut bytes = BytesMut::with_capacity(23 + stream_id_bytes.len() + self.name.len());
+        let template_length = self
+            .template
+            .as_ref()
+            .map_or(0, |template| 1 + template.len());
+        let mut bytes =
+            BytesMut::with_capacity(23 + stream_id_bytes.len() + self.name.len() + template_length);
         bytes.put_slice(&stream_id_bytes);
         bytes.put_u32_le(self.topic_id.unwrap_or(0));
         bytes.put_u32_le(self.partitions_count);
@@ -109,6 +126,12 @@ impl BytesSerializable for CreateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        // The template is optional, hence appended at the end to keep the format compatible.
+        if let Some(template) = &self.template {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(template.len() as u8);
+            bytes.put_slice(template.as_bytes());
+        }
         bytes.freeze()
     }
 
@@ -154,6 +177,19 @@ impl BytesSerializable for CreateTopic {
         if name.len() != name_length as usize {
             return Err(IggyError::InvalidCommand);
         }
+        position += 27 + name_length as usize;
+        let template = match bytes.get(position) {
+            Some(&template_length) => Some(
+                from_utf8(
+                    bytes
+                        .get(position + 1..position + 1 + template_length as usize)
+                        .ok_or(IggyError::InvalidCommand)?,
+                )
+                .map_err(|_| IggyError::InvalidUtf8)?
+                .to_string(),
+            ),
+            None => None,
+        };
         let command = CreateTopic {
             stream_id,
             topic_id,
@@ -163,6 +199,7 @@ impl BytesSerializable for CreateTopic {
             max_topic_size,
             replication_factor,
             name,
+            template,
         };
         Ok(command)
     }
@@ -200,6 +237,7 @@ mod tests {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
             name: "test".to_string(),
+            template: None,
         };
         let bytes = command.to_bytes();
         let mut position = 0;
@@ -269,5 +307,24 @@ mod tests {
         assert_eq!(command.max_topic_size, max_topic_size);
         assert_eq!(command.replication_factor.unwrap(), replication_factor);
         assert_eq!(command.partitions_count, partitions_count);
+        assert!(command.template.is_none());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_template() {
+        let command = CreateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: None,
+            partitions_count: 0,
+            compression_algorithm: CompressionAlgorithm::None,
+            message_expiry: IggyExpiry::ServerDefault,
+            max_topic_size: MaxTopicSize::ServerDefault,
+            replication_factor: None,
+            name: "test".to_string(),
+            template: Some("template".to_string()),
+        };
+
+        let deserialized = CreateTopic::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
     }
 }
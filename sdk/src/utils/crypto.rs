@@ -3,22 +3,53 @@ use crate::utils::text;
 use aes_gcm::aead::generic_array::GenericArray;
 use aes_gcm::aead::{Aead, OsRng};
 use aes_gcm::{AeadCore, Aes256Gcm, KeyInit};
+use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::sync::RwLock;
+
+const NONCE_LENGTH: usize = 12;
 
 #[derive(Debug)]
 pub enum EncryptorKind {
     Aes256Gcm(Aes256GcmEncryptor),
+    Keyring(KeyringEncryptor),
 }
 
 impl EncryptorKind {
     pub fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
         match self {
             EncryptorKind::Aes256Gcm(e) => e.encrypt(data),
+            EncryptorKind::Keyring(e) => e.encrypt(data),
         }
     }
     pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
         match self {
             EncryptorKind::Aes256Gcm(e) => e.decrypt(data),
+            EncryptorKind::Keyring(e) => e.decrypt(data),
+        }
+    }
+
+    /// Returns the ID of the key used for the encryption, or `None` if the encryptor uses a single key.
+    pub fn current_key_id(&self) -> Option<u32> {
+        match self {
+            EncryptorKind::Aes256Gcm(_) => None,
+            EncryptorKind::Keyring(e) => Some(e.current_key_id()),
+        }
+    }
+
+    /// Encrypts the data with the key of the given ID, or the current one if the ID is not provided.
+    pub fn encrypt_with_key(&self, data: &[u8], key_id: Option<u32>) -> Result<Vec<u8>, IggyError> {
+        match (self, key_id) {
+            (EncryptorKind::Keyring(e), Some(key_id)) => e.encrypt_with_key(data, key_id),
+            _ => self.encrypt(data),
+        }
+    }
+
+    /// Decrypts the data with the key of the given ID, or any known key if the ID is not provided.
+    pub fn decrypt_with_key(&self, data: &[u8], key_id: Option<u32>) -> Result<Vec<u8>, IggyError> {
+        match self {
+            EncryptorKind::Aes256Gcm(e) => e.decrypt(data),
+            EncryptorKind::Keyring(e) => e.decrypt_with_key(data, key_id),
         }
     }
 }
@@ -65,8 +96,11 @@ impl Encryptor for Aes256GcmEncryptor {
     }
 
     fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
-        let nonce = GenericArray::from_slice(&data[0..12]);
-        let payload = self.cipher.decrypt(nonce, &data[12..]);
+        if data.len() < NONCE_LENGTH {
+            return Err(IggyError::CannotDecryptData);
+        }
+        let nonce = GenericArray::from_slice(&data[0..NONCE_LENGTH]);
+        let payload = self.cipher.decrypt(nonce, &data[NONCE_LENGTH..]);
         if payload.is_err() {
             return Err(IggyError::CannotDecryptData);
         }
@@ -74,6 +108,123 @@ impl Encryptor for Aes256GcmEncryptor {
     }
 }
 
+/// Encrypts the data with the current key of the keyring, using AES-256-GCM.
+/// The ID of the key isn't a part of the encrypted data, so it's stored by the caller (e.g. in the batch header)
+/// and used to decrypt the data after the rotation, as long as the key remains in the keyring.
+/// Without the key ID, all the keys are tried, starting with the current one, and the data encrypted
+/// with the legacy single key can still be decrypted, if the legacy key is provided.
+pub struct KeyringEncryptor {
+    keys: RwLock<KeyringKeys>,
+    legacy: Option<Aes256GcmEncryptor>,
+}
+
+struct KeyringKeys {
+    current_key_id: u32,
+    encryptors: BTreeMap<u32, Aes256GcmEncryptor>,
+}
+
+impl Debug for KeyringEncryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyringEncryptor")
+            .field("current_key_id", &self.current_key_id())
+            .finish()
+    }
+}
+
+impl KeyringEncryptor {
+    pub fn new(
+        keys: &[(u32, Vec<u8>)],
+        current_key_id: u32,
+        legacy: Option<Aes256GcmEncryptor>,
+    ) -> Result<Self, IggyError> {
+        let mut encryptors = BTreeMap::new();
+        for (id, key) in keys {
+            encryptors.insert(*id, Aes256GcmEncryptor::new(key)?);
+        }
+        if !encryptors.contains_key(&current_key_id) {
+            return Err(IggyError::EncryptionKeyNotFound(current_key_id));
+        }
+
+        Ok(Self {
+            keys: RwLock::new(KeyringKeys {
+                current_key_id,
+                encryptors,
+            }),
+            legacy,
+        })
+    }
+
+    /// Generates a random 32 bytes key.
+    pub fn generate_key() -> Vec<u8> {
+        Aes256Gcm::generate_key(&mut OsRng).to_vec()
+    }
+
+    pub fn current_key_id(&self) -> u32 {
+        self.keys.read().unwrap().current_key_id
+    }
+
+    /// Adds the key to the keyring and uses it to encrypt the data from now on.
+    pub fn rotate(&self, id: u32, key: &[u8]) -> Result<(), IggyError> {
+        let encryptor = Aes256GcmEncryptor::new(key)?;
+        let mut keys = self.keys.write().unwrap();
+        keys.encryptors.insert(id, encryptor);
+        keys.current_key_id = id;
+        Ok(())
+    }
+
+    /// Encrypts the data with the key of the given ID, which might no longer be the current one.
+    pub fn encrypt_with_key(&self, data: &[u8], key_id: u32) -> Result<Vec<u8>, IggyError> {
+        self.keys
+            .read()
+            .unwrap()
+            .encryptors
+            .get(&key_id)
+            .ok_or(IggyError::EncryptionKeyNotFound(key_id))?
+            .encrypt(data)
+    }
+
+    /// Decrypts the data with the key of the given ID, or `None` if the ID is unknown,
+    /// e.g. for the data encrypted with the legacy key, in which case all the keys are tried.
+    pub fn decrypt_with_key(&self, data: &[u8], key_id: Option<u32>) -> Result<Vec<u8>, IggyError> {
+        let keys = self.keys.read().unwrap();
+        if let Some(key_id) = key_id {
+            return keys
+                .encryptors
+                .get(&key_id)
+                .ok_or(IggyError::EncryptionKeyNotFound(key_id))?
+                .decrypt(data);
+        }
+
+        let current = keys.encryptors.get(&keys.current_key_id);
+        let previous = keys
+            .encryptors
+            .iter()
+            .rev()
+            .filter(|(id, _)| **id != keys.current_key_id)
+            .map(|(_, encryptor)| encryptor);
+        for encryptor in current.into_iter().chain(previous).chain(&self.legacy) {
+            if let Ok(payload) = encryptor.decrypt(data) {
+                return Ok(payload);
+            }
+        }
+        Err(IggyError::CannotDecryptData)
+    }
+}
+
+impl Encryptor for KeyringEncryptor {
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        let keys = self.keys.read().unwrap();
+        keys.encryptors
+            .get(&keys.current_key_id)
+            .ok_or(IggyError::EncryptionKeyNotFound(keys.current_key_id))?
+            .encrypt(data)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        self.decrypt_with_key(data, None)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,4 +258,54 @@ mod tests {
         let error = decrypted_data.err().unwrap();
         assert_eq!(error.as_code(), IggyError::CannotDecryptData.as_code());
     }
+
+    #[test]
+    fn data_encrypted_before_key_rotation_should_be_decrypted_correctly() {
+        let encryptor = KeyringEncryptor::new(&[(1, vec![1; 32])], 1, None).unwrap();
+        let data = b"Hello World!";
+        let first_encrypted_data = encryptor.encrypt(data).unwrap();
+        encryptor.rotate(2, &[2; 32]).unwrap();
+        let second_encrypted_data = encryptor.encrypt(data).unwrap();
+
+        assert_eq!(encryptor.current_key_id(), 2);
+        let decrypted_data = encryptor
+            .decrypt_with_key(&first_encrypted_data, Some(1))
+            .unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+        assert!(encryptor
+            .decrypt_with_key(&first_encrypted_data, Some(2))
+            .is_err());
+        let decrypted_data = encryptor.decrypt(&first_encrypted_data).unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+        let decrypted_data = encryptor
+            .decrypt_with_key(&second_encrypted_data, Some(2))
+            .unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+    }
+
+    #[test]
+    fn data_encrypted_with_legacy_key_should_be_decrypted_by_keyring() {
+        let legacy_encryptor = Aes256GcmEncryptor::new(&[1; 32]).unwrap();
+        let data = b"Hello World!";
+        let encrypted_data = legacy_encryptor.encrypt(data).unwrap();
+        let encryptor = KeyringEncryptor::new(
+            &[(1, vec![2; 32])],
+            1,
+            Some(Aes256GcmEncryptor::new(&[1; 32]).unwrap()),
+        )
+        .unwrap();
+
+        let decrypted_data = encryptor.decrypt_with_key(&encrypted_data, None).unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+    }
+
+    #[test]
+    fn keyring_without_current_key_should_not_be_created() {
+        let result = KeyringEncryptor::new(&[(1, vec![1; 32])], 2, None);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().as_code(),
+            IggyError::EncryptionKeyNotFound(2).as_code()
+        );
+    }
 }
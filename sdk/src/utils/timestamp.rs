@@ -1,4 +1,5 @@
-use chrono::{DateTime, Local, Utc};
+use crate::error::IggyError;
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
 use core::fmt;
 use serde::{
     de::{self, Visitor},
@@ -6,6 +7,7 @@ use serde::{
 };
 use std::{
     ops::Add,
+    str::FromStr,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
@@ -91,6 +93,29 @@ impl fmt::Display for IggyTimestamp {
     }
 }
 
+/// Parses the timestamp either from the number of microseconds since the Unix epoch,
+/// the RFC 3339 date time (e.g. `2024-01-01T10:00:00Z`) or the UTC date time in `UTC_TIME_FORMAT`.
+impl FromStr for IggyTimestamp {
+    type Err = IggyError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if let Ok(micros) = value.parse::<u64>() {
+            return Ok(IggyTimestamp::from(micros));
+        }
+
+        let date_time = match DateTime::parse_from_rfc3339(value) {
+            Ok(date_time) => date_time.with_timezone(&Utc),
+            Err(_) => NaiveDateTime::parse_from_str(value, UTC_TIME_FORMAT)
+                .map_err(|_| IggyError::InvalidFormat)?
+                .and_utc(),
+        };
+        let micros =
+            u64::try_from(date_time.timestamp_micros()).map_err(|_| IggyError::InvalidFormat)?;
+        Ok(IggyTimestamp::from(micros))
+    }
+}
+
 impl Serialize for IggyTimestamp {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -156,4 +181,22 @@ mod tests {
         let timestamp = IggyTimestamp::from(1663472051111);
         assert_eq!(timestamp.as_micros(), 1663472051111);
     }
+
+    #[test]
+    fn test_timestamp_from_str() {
+        let expected = IggyTimestamp::from(1694968446000000);
+        assert_eq!(
+            "1694968446000000".parse::<IggyTimestamp>().unwrap(),
+            expected
+        );
+        assert_eq!(
+            "2023-09-17T16:34:06Z".parse::<IggyTimestamp>().unwrap(),
+            expected
+        );
+        assert_eq!(
+            "2023-09-17 16:34:06".parse::<IggyTimestamp>().unwrap(),
+            expected
+        );
+        assert!("yesterday".parse::<IggyTimestamp>().is_err());
+    }
 }
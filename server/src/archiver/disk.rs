@@ -77,4 +77,32 @@ impl Archiver for DiskArchiver {
 
         Ok(())
     }
+
+    async fn fetch(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+        destination: &str,
+    ) -> Result<(), ArchiverError> {
+        debug!("Fetching file: {file} from disk to: {destination}");
+        let base_directory = base_directory.as_deref().unwrap_or_default();
+        let source = Path::new(&self.config.path).join(base_directory).join(file);
+        if !source.exists() {
+            return Err(ArchiverError::ArchivedFileNotFound {
+                file_path: file.to_string(),
+            });
+        }
+
+        let destination = Path::new(destination);
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create directory for fetched file: {file}")
+            })?;
+        }
+        fs::copy(&source, destination).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to copy archived file: {file} to destination: {}", destination.display())
+        })?;
+        debug!("Fetched file: {file} to: {}", destination.display());
+        Ok(())
+    }
 }
@@ -1,7 +1,10 @@
 pub mod disk;
+pub mod object_storage;
 pub mod s3;
 
-use crate::configs::server::{DiskArchiverConfig, S3ArchiverConfig};
+use crate::configs::server::{
+    AzureArchiverConfig, DiskArchiverConfig, GcsArchiverConfig, S3ArchiverConfig,
+};
 use crate::server_error::ArchiverError;
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
@@ -10,6 +13,7 @@ use std::future::Future;
 use std::str::FromStr;
 
 use crate::archiver::disk::DiskArchiver;
+use crate::archiver::object_storage::ObjectStoreArchiver;
 use crate::archiver::s3::S3Archiver;
 
 pub const COMPONENT: &str = "ARCHIVER";
@@ -22,6 +26,10 @@ pub enum ArchiverKindType {
     Disk,
     #[display("s3")]
     S3,
+    #[display("azure")]
+    Azure,
+    #[display("gcs")]
+    Gcs,
 }
 
 impl FromStr for ArchiverKindType {
@@ -30,6 +38,8 @@ impl FromStr for ArchiverKindType {
         match s.to_lowercase().as_str() {
             "disk" => Ok(ArchiverKindType::Disk),
             "s3" => Ok(ArchiverKindType::S3),
+            "azure" => Ok(ArchiverKindType::Azure),
+            "gcs" => Ok(ArchiverKindType::Gcs),
             _ => Err(format!("Unknown archiver kind: {}", s)),
         }
     }
@@ -47,12 +57,19 @@ pub trait Archiver: Send {
         files: &[&str],
         base_directory: Option<String>,
     ) -> impl Future<Output = Result<(), ArchiverError>> + Send;
+    fn fetch(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+        destination: &str,
+    ) -> impl Future<Output = Result<(), ArchiverError>> + Send;
 }
 
 #[derive(Debug)]
 pub enum ArchiverKind {
     Disk(DiskArchiver),
     S3(S3Archiver),
+    ObjectStore(ObjectStoreArchiver),
 }
 
 impl ArchiverKind {
@@ -65,10 +82,25 @@ impl ArchiverKind {
         Ok(Self::S3(archiver))
     }
 
+    pub fn get_azure_archiver(config: AzureArchiverConfig) -> Result<Self, ArchiverError> {
+        let archiver = ObjectStoreArchiver::new_azure(config)?;
+        Ok(Self::ObjectStore(archiver))
+    }
+
+    pub fn get_gcs_archiver(config: GcsArchiverConfig) -> Result<Self, ArchiverError> {
+        let archiver = ObjectStoreArchiver::new_gcs(config)?;
+        Ok(Self::ObjectStore(archiver))
+    }
+
+    pub fn get_in_memory_archiver() -> Self {
+        Self::ObjectStore(ObjectStoreArchiver::new_in_memory())
+    }
+
     pub async fn init(&self) -> Result<(), ArchiverError> {
         match self {
             Self::Disk(a) => a.init().await,
             Self::S3(a) => a.init().await,
+            Self::ObjectStore(a) => a.init().await,
         }
     }
 
@@ -80,6 +112,7 @@ impl ArchiverKind {
         match self {
             Self::Disk(d) => d.is_archived(file, base_directory).await,
             Self::S3(d) => d.is_archived(file, base_directory).await,
+            Self::ObjectStore(d) => d.is_archived(file, base_directory).await,
         }
     }
 
@@ -91,6 +124,20 @@ impl ArchiverKind {
         match self {
             Self::Disk(d) => d.archive(files, base_directory).await,
             Self::S3(d) => d.archive(files, base_directory).await,
+            Self::ObjectStore(d) => d.archive(files, base_directory).await,
+        }
+    }
+
+    pub async fn fetch(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+        destination: &str,
+    ) -> Result<(), ArchiverError> {
+        match self {
+            Self::Disk(d) => d.fetch(file, base_directory, destination).await,
+            Self::S3(d) => d.fetch(file, base_directory, destination).await,
+            Self::ObjectStore(d) => d.fetch(file, base_directory, destination).await,
         }
     }
 }
@@ -0,0 +1,222 @@
+use crate::archiver::{Archiver, COMPONENT};
+use crate::configs::server::{AzureArchiverConfig, GcsArchiverConfig};
+use crate::server_error::ArchiverError;
+use crate::streaming::utils::file;
+use error_set::ErrContext;
+use futures::StreamExt;
+use object_store::azure::MicrosoftAzureBuilder;
+use object_store::buffered::BufWriter;
+use object_store::gcp::GoogleCloudStorageBuilder;
+use object_store::memory::InMemory;
+use object_store::path::Path as ObjectPath;
+use object_store::ObjectStore;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tracing::{debug, error, info};
+
+/// Archives the files in the object storage built on the `object_store` abstraction,
+/// such as Azure Blob Storage or Google Cloud Storage.
+/// The in-memory store keeps the files only as long as the archiver exists, and is meant for testing.
+#[derive(Debug)]
+pub struct ObjectStoreArchiver {
+    kind: &'static str,
+    store: Arc<dyn ObjectStore>,
+}
+
+impl ObjectStoreArchiver {
+    pub fn new_azure(config: AzureArchiverConfig) -> Result<Self, ArchiverError> {
+        let mut builder = MicrosoftAzureBuilder::new()
+            .with_account(&config.account)
+            .with_access_key(&config.access_key)
+            .with_container_name(&config.container);
+        if let Some(endpoint) = config.endpoint.filter(|endpoint| !endpoint.is_empty()) {
+            builder = builder
+                .with_allow_http(endpoint.starts_with("http://"))
+                .with_endpoint(endpoint);
+        }
+
+        let store = builder.build().map_err(|error| {
+            error!("Cannot create Azure archiver: {error}");
+            ArchiverError::CannotInitializeObjectStoreArchiver {
+                kind: "azure".to_owned(),
+            }
+        })?;
+        Ok(Self {
+            kind: "azure",
+            store: Arc::new(store),
+        })
+    }
+
+    pub fn new_gcs(config: GcsArchiverConfig) -> Result<Self, ArchiverError> {
+        let mut builder = GoogleCloudStorageBuilder::new().with_bucket_name(&config.bucket);
+        if let Some(path) = config.service_account_path.filter(|path| !path.is_empty()) {
+            builder = builder.with_service_account_path(path);
+        }
+
+        let store = builder.build().map_err(|error| {
+            error!("Cannot create GCS archiver: {error}");
+            ArchiverError::CannotInitializeObjectStoreArchiver {
+                kind: "gcs".to_owned(),
+            }
+        })?;
+        Ok(Self {
+            kind: "gcs",
+            store: Arc::new(store),
+        })
+    }
+
+    pub fn new_in_memory() -> Self {
+        Self {
+            kind: "memory",
+            store: Arc::new(InMemory::new()),
+        }
+    }
+
+    fn get_object_path(file: &str, base_directory: Option<String>) -> ObjectPath {
+        let base_directory = base_directory.unwrap_or_default();
+        let path = Path::new(&base_directory).join(file);
+        ObjectPath::from(path.to_str().unwrap_or_default())
+    }
+}
+
+impl Archiver for ObjectStoreArchiver {
+    async fn init(&self) -> Result<(), ArchiverError> {
+        if let Err(error) = self.store.list_with_delimiter(None).await {
+            error!("Cannot initialize {} archiver: {error}", self.kind);
+            return Err(ArchiverError::CannotInitializeObjectStoreArchiver {
+                kind: self.kind.to_owned(),
+            });
+        }
+
+        info!("Initialized {} archiver.", self.kind);
+        Ok(())
+    }
+
+    async fn is_archived(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+    ) -> Result<bool, ArchiverError> {
+        debug!("Checking if file: {file} is archived on {}.", self.kind);
+        let path = Self::get_object_path(file, base_directory);
+        match self.store.head(&path).await {
+            Ok(_) => {
+                debug!("File: {file} is archived on {}.", self.kind);
+                Ok(true)
+            }
+            Err(object_store::Error::NotFound { .. }) => {
+                debug!("File: {file} is not archived on {}.", self.kind);
+                Ok(false)
+            }
+            Err(error) => {
+                error!(
+                    "Cannot check if file: {file} is archived on {}: {error}",
+                    self.kind
+                );
+                Ok(false)
+            }
+        }
+    }
+
+    async fn archive(
+        &self,
+        files: &[&str],
+        base_directory: Option<String>,
+    ) -> Result<(), ArchiverError> {
+        for path in files {
+            if !Path::new(path).exists() {
+                return Err(ArchiverError::FileToArchiveNotFound {
+                    file_path: path.to_string(),
+                });
+            }
+
+            debug!("Archiving file: {path} on {}.", self.kind);
+            let mut source = file::open(path).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to open source file: {path} for archiving")
+            })?;
+            let destination = Self::get_object_path(path, base_directory.clone());
+            // The file is uploaded in parts, so the segments don't have to be loaded into memory.
+            let mut writer = BufWriter::new(self.store.clone(), destination);
+            let result = match tokio::io::copy(&mut source, &mut writer).await {
+                Ok(_) => writer.shutdown().await,
+                Err(error) => Err(error),
+            };
+            if let Err(error) = result {
+                error!("Cannot archive file: {path} on {}: {error}", self.kind);
+                if let Err(error) = writer.abort().await {
+                    error!(
+                        "Cannot abort the upload of file: {path} on {}: {error}",
+                        self.kind
+                    );
+                }
+                return Err(ArchiverError::CannotArchiveFile {
+                    file_path: path.to_string(),
+                });
+            }
+
+            debug!("Archived file: {path} on {}.", self.kind);
+        }
+        Ok(())
+    }
+
+    async fn fetch(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+        destination: &str,
+    ) -> Result<(), ArchiverError> {
+        debug!("Fetching file: {file} from {} to: {destination}", self.kind);
+        let source = Self::get_object_path(file, base_directory);
+        let response = match self.store.get(&source).await {
+            Ok(response) => response,
+            Err(object_store::Error::NotFound { .. }) => {
+                return Err(ArchiverError::ArchivedFileNotFound {
+                    file_path: file.to_string(),
+                });
+            }
+            Err(error) => {
+                error!("Cannot fetch file: {file} from {}: {error}", self.kind);
+                return Err(ArchiverError::CannotFetchFile {
+                    file_path: file.to_string(),
+                });
+            }
+        };
+
+        if let Some(parent) = Path::new(destination).parent() {
+            fs::create_dir_all(parent).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create directory for fetched file: {file}")
+            })?;
+        }
+
+        let mut output = fs::File::create(destination).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to create file: {destination} for fetched file: {file}")
+        })?;
+        let mut stream = response.into_stream();
+        while let Some(chunk) = stream.next().await {
+            let result = match chunk {
+                Ok(chunk) => output
+                    .write_all(&chunk)
+                    .await
+                    .map_err(|error| error.to_string()),
+                Err(error) => Err(error.to_string()),
+            };
+            if let Err(error) = result {
+                error!("Cannot fetch file: {file} from {}: {error}", self.kind);
+                fs::remove_file(destination).await.with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to remove file: {destination} after {} failure", self.kind)
+                })?;
+                return Err(ArchiverError::CannotFetchFile {
+                    file_path: file.to_string(),
+                });
+            }
+        }
+
+        output.flush().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to flush fetched file: {destination}")
+        })?;
+        debug!("Fetched file: {file} from {} to: {destination}", self.kind);
+        Ok(())
+    }
+}
@@ -176,4 +176,60 @@ impl Archiver for S3Archiver {
         }
         Ok(())
     }
+
+    async fn fetch(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+        destination: &str,
+    ) -> Result<(), ArchiverError> {
+        debug!("Fetching file: {file} from S3 to: {destination}");
+        let base_directory = base_directory.as_deref().unwrap_or_default();
+        let source = Path::new(&base_directory).join(file);
+        let source_path = source.to_str().unwrap_or_default().to_owned();
+        if let Some(parent) = Path::new(destination).parent() {
+            fs::create_dir_all(parent).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create directory for fetched file: {file}")
+            })?;
+        }
+
+        let mut output = fs::File::create(destination).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to create file: {destination} for fetched file: {file}")
+        })?;
+        let response = self
+            .bucket
+            .get_object_to_writer(&source_path, &mut output)
+            .await;
+        let status = match response {
+            Ok(status) => status,
+            Err(error) => {
+                error!("Cannot fetch file: {file} from S3: {error}");
+                fs::remove_file(destination).await.with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to remove file: {destination} after S3 failure")
+                })?;
+                return Err(ArchiverError::CannotFetchFile {
+                    file_path: file.to_string(),
+                });
+            }
+        };
+
+        if status == 200 {
+            debug!("Fetched file: {file} from S3 to: {destination}");
+            return Ok(());
+        }
+
+        error!("Cannot fetch file: {file} from S3, received an invalid status code: {status}.");
+        fs::remove_file(destination).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to remove file: {destination} after invalid status code")
+        })?;
+        if status == 404 {
+            return Err(ArchiverError::ArchivedFileNotFound {
+                file_path: file.to_string(),
+            });
+        }
+
+        Err(ArchiverError::CannotFetchFile {
+            file_path: file.to_string(),
+        })
+    }
 }
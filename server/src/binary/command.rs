@@ -1,3 +1,6 @@
+use crate::binary::handlers::backups::{
+    create_backup_handler, get_backups_handler, restore_backup_handler,
+};
 use crate::binary::handlers::consumer_groups::{
     create_consumer_group_handler, delete_consumer_group_handler, get_consumer_group_handler,
     get_consumer_groups_handler, join_consumer_group_handler, leave_consumer_group_handler,
@@ -9,13 +12,21 @@ use crate::binary::handlers::personal_access_tokens::{
     create_personal_access_token_handler, delete_personal_access_token_handler,
     get_personal_access_tokens_handler, login_with_personal_access_token_handler,
 };
+use crate::binary::handlers::schemas::{
+    delete_topic_schema_handler, get_schema_handler, get_topic_schema_handler,
+    get_topic_schemas_handler, register_topic_schema_handler, update_topic_schema_settings_handler,
+};
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
+use crate::binary::handlers::topic_templates::{
+    create_topic_template_handler, delete_topic_template_handler, get_topic_template_handler,
+    get_topic_templates_handler,
+};
 use crate::binary::handlers::topics::*;
 use crate::binary::handlers::users::{
     change_password_handler, create_user_handler, delete_user_handler, get_user_handler,
-    get_users_handler, login_user_handler, logout_user_handler, update_permissions_handler,
-    update_user_handler,
+    get_users_handler, login_user_handler, logout_user_handler, unlock_user_handler,
+    update_permissions_handler, update_user_handler,
 };
 use crate::binary::sender::SenderKind;
 use crate::binary::COMPONENT;
@@ -70,6 +81,10 @@ async fn try_handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("Handling command '{command}', session: {session}...");
+    if command.changes_state() {
+        system.read().await.ensure_cluster_leader()?;
+    }
+
     match command {
         ServerCommand::Ping(command) => {
             ping_handler::handle(command, sender, session, system).await
@@ -107,6 +122,9 @@ async fn try_handle(
         ServerCommand::ChangePassword(command) => {
             change_password_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::UnlockUser(command) => {
+            unlock_user_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::LoginUser(command) => {
             login_user_handler::handle(command, sender, session, system).await
         }
@@ -158,6 +176,12 @@ async fn try_handle(
         ServerCommand::PurgeStream(command) => {
             purge_stream_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::GetStreamTopicDefaults(command) => {
+            get_stream_topic_defaults_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::UpdateStreamTopicDefaults(command) => {
+            update_stream_topic_defaults_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetTopic(command) => {
             get_topic_handler::handle(command, sender, session, system).await
         }
@@ -176,12 +200,48 @@ async fn try_handle(
         ServerCommand::PurgeTopic(command) => {
             purge_topic_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::GetTopicTemplate(command) => {
+            get_topic_template_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetTopicTemplates(command) => {
+            get_topic_templates_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::CreateTopicTemplate(command) => {
+            create_topic_template_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::DeleteTopicTemplate(command) => {
+            delete_topic_template_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetSchema(command) => {
+            get_schema_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetTopicSchema(command) => {
+            get_topic_schema_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetTopicSchemas(command) => {
+            get_topic_schemas_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::RegisterTopicSchema(command) => {
+            register_topic_schema_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::DeleteTopicSchema(command) => {
+            delete_topic_schema_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::UpdateTopicSchemaSettings(command) => {
+            update_topic_schema_settings_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::CreatePartitions(command) => {
             create_partitions_handler::handle(command, sender, session, system).await
         }
         ServerCommand::DeletePartitions(command) => {
             delete_partitions_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::MergePartitions(command) => {
+            merge_partitions_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetPartitionsMerge(command) => {
+            get_partitions_merge_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetConsumerGroup(command) => {
             get_consumer_group_handler::handle(command, sender, session, system).await
         }
@@ -206,5 +266,14 @@ async fn try_handle(
         ServerCommand::GetSnapshotFile(command) => {
             get_snapshot::handle(command, sender, session, system).await
         }
+        ServerCommand::CreateBackup(command) => {
+            create_backup_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetBackups(command) => {
+            get_backups_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::RestoreBackup(command) => {
+            restore_backup_handler::handle(command, sender, session, system).await
+        }
     }
 }
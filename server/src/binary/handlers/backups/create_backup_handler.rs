@@ -0,0 +1,39 @@
+use crate::binary::handlers::backups::COMPONENT;
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use error_set::ErrContext;
+use iggy::backups::create_backup::CreateBackup;
+use iggy::error::IggyError;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_create_backup", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle(
+    command: CreateBackup,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+
+    // The files are captured while the system is locked, and copied once the lock is downgraded.
+    let system = system.write().await;
+    let backup = system
+        .prepare_backup(session, command.incremental)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to prepare backup, session: {session}")
+        })?;
+
+    let system = system.downgrade();
+    let backup = system
+        .create_backup(backup)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to create backup, session: {session}")
+        })?;
+    let backup = mapper::map_backup(&backup);
+    sender.send_ok_response(&backup).await?;
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use crate::binary::handlers::backups::COMPONENT;
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use error_set::ErrContext;
+use iggy::backups::get_backups::GetBackups;
+use iggy::error::IggyError;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetBackups,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let backups = system
+        .get_backups(session)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get backups, session: {session}")
+        })?;
+    let backups = mapper::map_backups(&backups);
+    sender.send_ok_response(&backups).await?;
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod create_backup_handler;
+pub mod get_backups_handler;
+pub mod restore_backup_handler;
+
+pub const COMPONENT: &str = "BACKUP_HANDLER";
@@ -0,0 +1,32 @@
+use crate::binary::handlers::backups::COMPONENT;
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use error_set::ErrContext;
+use iggy::backups::restore_backup::RestoreBackup;
+use iggy::error::IggyError;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_restore_backup", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle(
+    command: RestoreBackup,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let backup = system
+        .restore_backup(session, command.timestamp, &command.target)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to restore backup, target: {}, session: {session}",
+                command.target
+            )
+        })?;
+    let backup = mapper::map_backup(&backup);
+    sender.send_ok_response(&backup).await?;
+    Ok(())
+}
@@ -7,6 +7,7 @@ use anyhow::Result;
 use error_set::ErrContext;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::error::IggyError;
+use iggy::identifier::Identifier;
 use tracing::{debug, instrument};
 
 #[instrument(skip_all, name = "trace_create_consumer_group", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = command.stream_id.as_string(), iggy_topic_id = command.topic_id.as_string()))]
@@ -18,6 +19,35 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
 
+    if let Some(cluster_node) = system.cluster_node().await {
+        let stream_id = command.stream_id.clone();
+        let topic_id = command.topic_id.clone();
+        let group_id = command.group_id;
+        let name = command.name.clone();
+        system
+            .replicate(&cluster_node, session, EntryCommand::CreateConsumerGroup(command),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate create consumer group for stream_id: {}, topic_id: {}, group_id: {:?}, session: {}",
+                    stream_id, topic_id, group_id, session
+                )
+            })?;
+        let response = {
+            let system = system.read().await;
+            let consumer_group = system
+                .get_stream(&stream_id)?
+                .get_topic(&topic_id)?
+                .get_consumer_group(&Identifier::named(&name)?)?
+                .read()
+                .await;
+            mapper::map_consumer_group(&consumer_group).await
+        };
+        sender.send_ok_response(&response).await?;
+        return Ok(());
+    }
+
     let mut system = system.write().await;
     let consumer_group = system
             .create_consumer_group(
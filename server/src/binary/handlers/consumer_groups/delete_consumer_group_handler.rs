@@ -16,6 +16,19 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
+    let stream_id = command.stream_id.clone();
+    let topic_id = command.topic_id.clone();
+    let group_id = command.group_id.clone();
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .replicate(&cluster_node, session, EntryCommand::DeleteConsumerGroup(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete consumer group with ID: {group_id} for topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
 
     let mut system = system.write().await;
     system
@@ -31,9 +44,6 @@ pub async fn handle(
             ))?;
 
     let system = system.downgrade();
-    let stream_id = command.stream_id.clone();
-    let topic_id = command.topic_id.clone();
-    let group_id = command.group_id.clone();
 
     system
         .state
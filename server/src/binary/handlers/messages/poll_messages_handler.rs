@@ -1,13 +1,14 @@
 use crate::binary::handlers::messages::COMPONENT;
 use crate::binary::mapper;
-use crate::binary::sender::SenderKind;
+use crate::binary::sender::{Sender, SenderKind};
 use crate::streaming::session::Session;
-use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::auto_create::AutoCreateTrigger;
+use crate::streaming::systems::messages::{PolledMessagesOrBatches, PollingArgs};
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use error_set::ErrContext;
 use iggy::error::IggyError;
-use iggy::messages::poll_messages::PollMessages;
+use iggy::messages::poll_messages::{PollMessages, PolledMessagesFormat};
 use tracing::debug;
 
 pub async fn handle(
@@ -17,7 +18,59 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
+    system
+        .auto_create(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            AutoCreateTrigger::PollMessages,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to auto create stream_id: {}, topic_id: {}, session: {}",
+                command.stream_id, command.topic_id, session
+            )
+        })?;
     let system = system.read().await;
+    if command.format == PolledMessagesFormat::Batches {
+        if let Some(zero_copy_sender) = sender.as_zero_copy_sender() {
+            let polled = system
+                .poll_message_batches(
+                    session,
+                    &command.consumer,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partition_id,
+                    PollingArgs::new(command.strategy, command.count, command.auto_commit),
+                )
+                .await
+                .with_error_context(|error| format!(
+                    "{COMPONENT} (error: {error}) - failed to poll message batches for consumer: {}, stream_id: {}, topic_id: {}, partition_id: {:?}, session: {}.",
+                    command.consumer, command.stream_id, command.topic_id, command.partition_id, session
+                ))?;
+            match polled {
+                PolledMessagesOrBatches::Batches(batches) => {
+                    let header = mapper::map_polled_message_batches_header(&batches);
+                    let file_range = &batches.file_range;
+                    zero_copy_sender
+                        .send_ok_file_range_response(
+                            &header,
+                            &file_range.file,
+                            file_range.position,
+                            file_range.length,
+                        )
+                        .await?;
+                }
+                PolledMessagesOrBatches::Messages(messages) => {
+                    let messages = mapper::map_polled_messages_with_format(&messages);
+                    zero_copy_sender.send_ok_response(&messages).await?;
+                }
+            }
+            return Ok(());
+        }
+    }
+
     let messages = system
         .poll_messages(
             session,
@@ -32,7 +85,10 @@ pub async fn handle(
             "{COMPONENT} (error: {error}) - failed to poll messages for consumer: {}, stream_id: {}, topic_id: {}, partition_id: {:?}, session: {}.",
             command.consumer, command.stream_id, command.topic_id, command.partition_id, session
         ))?;
-    let messages = mapper::map_polled_messages(&messages);
+    let messages = match command.format {
+        PolledMessagesFormat::Messages => mapper::map_polled_messages(&messages),
+        PolledMessagesFormat::Batches => mapper::map_polled_messages_with_format(&messages),
+    };
     sender.send_ok_response(&messages).await?;
     Ok(())
 }
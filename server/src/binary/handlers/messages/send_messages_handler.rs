@@ -1,6 +1,7 @@
 use crate::binary::handlers::messages::COMPONENT;
 use crate::binary::sender::SenderKind;
 use crate::streaming::session::Session;
+use crate::streaming::systems::auto_create::AutoCreateTrigger;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use error_set::ErrContext;
@@ -15,6 +16,20 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
+    system
+        .auto_create(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            AutoCreateTrigger::SendMessages,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to auto create stream_id: {}, topic_id: {}, session: {}",
+                command.stream_id, command.topic_id, session
+            )
+        })?;
     let system = system.read().await;
     let stream_id = command.stream_id.clone();
     let topic_id = command.topic_id.clone();
@@ -1,9 +1,12 @@
+pub mod backups;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod streams;
 pub mod system;
+pub mod topic_templates;
 pub mod topics;
 pub mod users;
@@ -16,6 +16,18 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
+    let stream_id = command.stream_id.clone();
+    let topic_id = command.topic_id.clone();
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .replicate(&cluster_node, session, EntryCommand::CreatePartitions(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate create partitions for stream_id: {stream_id}, topic_id: {topic_id}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
 
     let mut system = system.write().await;
     system
@@ -34,8 +46,6 @@ pub async fn handle(
             })?;
 
     let system = system.downgrade();
-    let stream_id = command.stream_id.clone();
-    let topic_id = command.topic_id.clone();
 
     system
         .state
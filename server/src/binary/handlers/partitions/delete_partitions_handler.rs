@@ -18,6 +18,16 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let stream_id = command.stream_id.clone();
     let topic_id = command.topic_id.clone();
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .replicate(&cluster_node, session, EntryCommand::DeletePartitions(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete partitions for topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
 
     let mut system = system.write().await;
     system
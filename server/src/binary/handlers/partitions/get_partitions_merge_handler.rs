@@ -0,0 +1,36 @@
+use crate::binary::mapper;
+use crate::binary::{handlers::partitions::COMPONENT, sender::SenderKind};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::partitions::get_partitions_merge::GetPartitionsMerge;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetPartitionsMerge,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let partitions_merge = system
+        .get_partitions_merge(session, &command.stream_id, &command.topic_id)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get partitions merge for topic with ID: {} in stream with ID: {}, session: {session}",
+                command.topic_id, command.stream_id
+            )
+        })?;
+    let Some(partitions_merge) = partitions_merge else {
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    };
+
+    let partitions_merge = mapper::map_partitions_merge(&partitions_merge);
+    sender.send_ok_response(&partitions_merge).await?;
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::binary::mapper;
+use crate::binary::{handlers::partitions::COMPONENT, sender::SenderKind};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::partitions::merge_partitions::MergePartitions;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_merge_partitions", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = command.stream_id.as_string(), iggy_topic_id = command.topic_id.as_string()))]
+pub async fn handle(
+    command: MergePartitions,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let stream_id = command.stream_id.clone();
+    let topic_id = command.topic_id.clone();
+
+    // The messages of the merged partitions are stored locally, so they can't be moved consistently on every node.
+    if system.cluster_node().await.is_some() {
+        return Err(IggyError::FeatureUnavailable);
+    }
+
+    // The partitions are detached while the system is locked, and their messages are moved in the background.
+    // The state is updated only once the merged partitions are deleted.
+    let merge = system
+        .write()
+        .await
+        .merge_partitions(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partitions_count,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to merge partitions for topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}",
+            )
+        })?;
+
+    let partitions_merge = mapper::map_partitions_merge(&merge.get_info().await);
+    tokio::spawn(merge.run(system.clone()));
+    sender.send_ok_response(&partitions_merge).await?;
+    Ok(())
+}
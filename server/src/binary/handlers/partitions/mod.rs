@@ -1,4 +1,6 @@
 pub mod create_partitions_handler;
 pub mod delete_partitions_handler;
+pub mod get_partitions_merge_handler;
+pub mod merge_partitions_handler;
 
 pub const COMPONENT: &str = "PARTITIONS_HANDLER";
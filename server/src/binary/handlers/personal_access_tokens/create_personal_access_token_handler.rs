@@ -20,9 +20,46 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
 
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .read()
+            .await
+            .validate_personal_access_token_creation(session, &command.name)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to create personal access token with name: {}, session: {session}",
+                    command.name
+                )
+            })?;
+        let token = PersonalAccessToken::generate_token();
+        let token_hash = PersonalAccessToken::hash_token(&token);
+        let name = command.name.clone();
+        system
+            .replicate(&cluster_node, session, EntryCommand::CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash {
+                    command,
+                    hash: token_hash,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate personal access token with name: {name}, session: {session}"
+                )
+            })?;
+        sender
+            .send_ok_response(&mapper::map_raw_pat(&token))
+            .await?;
+        return Ok(());
+    }
+
     let mut system = system.write().await;
     let token = system
-            .create_personal_access_token(session, &command.name, command.expiry)
+            .create_personal_access_token(
+                session,
+                &command.name,
+                command.expiry,
+                command.permissions.clone(),
+            )
             .await
             .with_error_context(|error| {
                 format!(
@@ -42,6 +79,7 @@ pub async fn handle(
                 command: CreatePersonalAccessToken {
                     name: command.name.to_owned(),
                     expiry: command.expiry,
+                    permissions: command.permissions,
                 },
                 hash: token_hash,
             }),
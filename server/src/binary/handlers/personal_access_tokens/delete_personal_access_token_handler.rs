@@ -18,6 +18,17 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let token_name = command.name.clone();
 
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .replicate(&cluster_node, session, EntryCommand::DeletePersonalAccessToken(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete personal access token with name: {token_name}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
+
     let mut system = system.write().await;
     system
             .delete_personal_access_token(session, &command.name)
@@ -0,0 +1,26 @@
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::schemas::get_schema::GetSchema;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetSchema,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let Ok(schema) = system.get_schema(session, command.schema_id) else {
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    };
+
+    let response = mapper::map_schema(schema);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
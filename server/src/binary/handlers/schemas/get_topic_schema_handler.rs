@@ -0,0 +1,35 @@
+use crate::binary::mapper;
+use crate::binary::{handlers::schemas::COMPONENT, sender::SenderKind};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::schemas::get_topic_schema::GetTopicSchema;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetTopicSchema,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let schema = system
+        .get_topic_schema(session, &command.stream_id, &command.topic_id, command.version)
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get schema for topic with ID: {} in stream with ID: {}, session: {session}",
+                command.topic_id, command.stream_id
+            )
+        })?;
+    let Some(schema) = schema else {
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    };
+
+    let response = mapper::map_schema(schema);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::binary::mapper;
+use crate::binary::{handlers::schemas::COMPONENT, sender::SenderKind};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::schemas::get_topic_schemas::GetTopicSchemas;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetTopicSchemas,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let topic_schemas = system
+        .get_topic_schemas(session, &command.stream_id, &command.topic_id)
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get schemas for topic with ID: {} in stream with ID: {}, session: {session}",
+                command.topic_id, command.stream_id
+            )
+        })?;
+    let response = mapper::map_topic_schemas(&topic_schemas);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -0,0 +1,8 @@
+pub mod delete_topic_schema_handler;
+pub mod get_schema_handler;
+pub mod get_topic_schema_handler;
+pub mod get_topic_schemas_handler;
+pub mod register_topic_schema_handler;
+pub mod update_topic_schema_settings_handler;
+
+pub const COMPONENT: &str = "SCHEMA_HANDLER";
@@ -0,0 +1,98 @@
+use crate::binary::mapper;
+use crate::binary::{handlers::schemas::COMPONENT, sender::SenderKind};
+use crate::state::command::EntryCommand;
+use crate::state::models::RegisterTopicSchemaWithId;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::schemas::register_topic_schema::RegisterTopicSchema;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_register_topic_schema", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle(
+    command: RegisterTopicSchema,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let stream_id = command.stream_id.clone();
+    let topic_id = command.topic_id.clone();
+    if let Some(cluster_node) = system.cluster_node().await {
+        let schema_type = command.schema_type;
+        let definition = command.definition.clone();
+        // The schema ID and version are assigned by every node once the entry is committed.
+        system
+            .replicate(&cluster_node, session, EntryCommand::RegisterTopicSchema(RegisterTopicSchemaWithId {
+                    command,
+                    schema_id: 0,
+                    version: 0,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate register schema for topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}"
+                )
+            })?;
+        let response = {
+            let system = system.read().await;
+            let topic = system.find_topic(session, &stream_id, &topic_id)?;
+            let schema = topic
+                .schemas
+                .find_identical(schema_type, &definition)
+                .ok_or_else(|| IggyError::ResourceNotFound(topic_id.to_string()))?;
+            mapper::map_schema(schema)
+        };
+        sender.send_ok_response(&response).await?;
+        return Ok(());
+    }
+
+    let mut system = system.write().await;
+    let (schema, registered) = system
+        .register_topic_schema(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.schema_type,
+            &command.definition,
+            None,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to register schema for topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}"
+            )
+        })?;
+    let response = mapper::map_schema(&schema);
+
+    let system = system.downgrade();
+    if registered {
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::RegisterTopicSchema(RegisterTopicSchemaWithId {
+                    command: RegisterTopicSchema {
+                        stream_id: Identifier::numeric(schema.stream_id)?,
+                        topic_id: Identifier::numeric(schema.topic_id)?,
+                        schema_type: command.schema_type,
+                        definition: command.definition,
+                    },
+                    schema_id: schema.id,
+                    version: schema.version,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to apply register schema with ID: {} for topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}",
+                    schema.id
+                )
+            })?;
+    }
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::binary::{handlers::schemas::COMPONENT, sender::SenderKind};
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::schemas::update_topic_schema_settings::UpdateTopicSchemaSettings;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_update_topic_schema_settings", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle(
+    command: UpdateTopicSchemaSettings,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let stream_id = command.stream_id.clone();
+    let topic_id = command.topic_id.clone();
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .replicate(&cluster_node, session, EntryCommand::UpdateTopicSchemaSettings(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate update schema settings for topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
+
+    let mut system = system.write().await;
+    system
+        .update_topic_schema_settings(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.compatibility,
+            command.validate_payloads,
+        )
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to update schema settings for topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::UpdateTopicSchemaSettings(command),
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to apply update schema settings for topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}")
+        })?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
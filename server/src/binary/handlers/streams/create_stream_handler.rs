@@ -6,6 +6,7 @@ use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::identifier::Identifier;
 use iggy::streams::create_stream::CreateStream;
 use tracing::{debug, instrument};
 
@@ -19,6 +20,25 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let stream_id = command.stream_id;
 
+    if let Some(cluster_node) = system.cluster_node().await {
+        let name = command.name.clone();
+        system
+            .replicate(&cluster_node, session, EntryCommand::CreateStream(command))
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate create stream for id: {:?}, session: {session}",
+                    stream_id
+                )
+            })?;
+        let response = {
+            let system = system.read().await;
+            mapper::map_stream(system.get_stream(&Identifier::named(&name)?)?)
+        };
+        sender.send_ok_response(&response).await?;
+        return Ok(());
+    }
+
     let mut system = system.write().await;
     let stream = system
             .create_stream(session, command.stream_id, &command.name)
@@ -0,0 +1,30 @@
+use crate::binary::mapper;
+use crate::binary::{handlers::streams::COMPONENT, sender::SenderKind};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::streams::get_stream_topic_defaults::GetStreamTopicDefaults;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetStreamTopicDefaults,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let topic_defaults = system
+        .get_stream_topic_defaults(session, &command.stream_id)
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get topic defaults of stream with id: {}, session: {session}",
+                command.stream_id
+            )
+        })?;
+    let response = mapper::map_topic_defaults(&topic_defaults);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
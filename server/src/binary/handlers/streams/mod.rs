@@ -1,8 +1,10 @@
 pub mod create_stream_handler;
 pub mod delete_stream_handler;
 pub mod get_stream_handler;
+pub mod get_stream_topic_defaults_handler;
 pub mod get_streams_handler;
 pub mod purge_stream_handler;
 pub mod update_stream_handler;
+pub mod update_stream_topic_defaults_handler;
 
 pub const COMPONENT: &str = "STREAM_HANDLER";
@@ -0,0 +1,60 @@
+use crate::binary::{handlers::streams::COMPONENT, sender::SenderKind};
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::topic_template::TopicDefaults;
+use iggy::streams::update_stream_topic_defaults::UpdateStreamTopicDefaults;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_update_stream_topic_defaults", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = command.stream_id.as_string()))]
+pub async fn handle(
+    command: UpdateStreamTopicDefaults,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let stream_id = command.stream_id.clone();
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .replicate(&cluster_node, session, EntryCommand::UpdateStreamTopicDefaults(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate update topic defaults of stream with id: {stream_id}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
+
+    let mut system = system.write().await;
+    system
+        .update_stream_topic_defaults(
+            session,
+            &command.stream_id,
+            TopicDefaults {
+                message_expiry: command.message_expiry,
+                max_topic_size: command.max_topic_size,
+                replication_factor: command.replication_factor,
+            },
+        )
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to update topic defaults of stream with id: {stream_id}, session: {session}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::UpdateStreamTopicDefaults(command),
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to apply update topic defaults of stream with id: {stream_id}, session: {session}")
+        })?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
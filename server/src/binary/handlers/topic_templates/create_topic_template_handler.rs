@@ -0,0 +1,65 @@
+use crate::binary::{handlers::topic_templates::COMPONENT, sender::SenderKind};
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::topic_template::TopicTemplate;
+use iggy::topic_templates::create_topic_template::CreateTopicTemplate;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_create_topic_template", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle(
+    command: CreateTopicTemplate,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let name = command.name.clone();
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .replicate(&cluster_node, session, EntryCommand::CreateTopicTemplate(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate create topic template with name: {name}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
+
+    let mut system = system.write().await;
+    system
+        .create_topic_template(
+            session,
+            TopicTemplate {
+                name: command.name.clone(),
+                partitions_count: command.partitions_count,
+                compression_algorithm: command.compression_algorithm,
+                message_expiry: command.message_expiry,
+                max_topic_size: command.max_topic_size,
+                replication_factor: command.replication_factor,
+            },
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create topic template with name: {}, session: {session}",
+                command.name
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::CreateTopicTemplate(command),
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to apply create topic template with name: {name}, session: {session}")
+        })?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::binary::{handlers::topic_templates::COMPONENT, sender::SenderKind};
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::topic_templates::delete_topic_template::DeleteTopicTemplate;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_delete_topic_template", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle(
+    command: DeleteTopicTemplate,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let name = command.name.clone();
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .replicate(&cluster_node, session, EntryCommand::DeleteTopicTemplate(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete topic template with name: {name}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
+
+    let mut system = system.write().await;
+    system
+        .delete_topic_template(session, &command.name)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to delete topic template with name: {name}, session: {session}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::DeleteTopicTemplate(command),
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to apply delete topic template with name: {name}, session: {session}")
+        })?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
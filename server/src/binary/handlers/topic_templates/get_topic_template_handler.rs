@@ -0,0 +1,26 @@
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::topic_templates::get_topic_template::GetTopicTemplate;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetTopicTemplate,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let Ok(template) = system.get_topic_template(session, &command.name) else {
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    };
+
+    let response = mapper::map_topic_template(template);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use crate::binary::mapper;
+use crate::binary::{handlers::topic_templates::COMPONENT, sender::SenderKind};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::topic_templates::get_topic_templates::GetTopicTemplates;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetTopicTemplates,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let templates = system
+        .get_topic_templates(session)
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get topic templates, session: {session}"
+            )
+        })?;
+    let response = mapper::map_topic_templates(&templates);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
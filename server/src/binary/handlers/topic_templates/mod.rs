@@ -0,0 +1,6 @@
+pub mod create_topic_template_handler;
+pub mod delete_topic_template_handler;
+pub mod get_topic_template_handler;
+pub mod get_topic_templates_handler;
+
+pub const COMPONENT: &str = "TOPIC_TEMPLATE_HANDLER";
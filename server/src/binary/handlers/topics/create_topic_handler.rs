@@ -6,6 +6,7 @@ use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::identifier::Identifier;
 use iggy::topics::create_topic::CreateTopic;
 use tracing::{debug, instrument};
 
@@ -19,7 +20,41 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let stream_id = command.stream_id.clone();
     let topic_id = command.topic_id;
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .read()
+            .await
+            .resolve_topic_settings(session, &mut command)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to resolve topic settings for stream_id: {stream_id}")
+            })?;
+        let name = command.name.clone();
+        system
+            .replicate(&cluster_node, session, EntryCommand::CreateTopic(command))
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate create topic for stream_id: {stream_id}, topic_id: {:?}",
+                    topic_id
+                )
+            })?;
+        let response = {
+            let system = system.read().await;
+            let topic = system
+                .get_stream(&stream_id)?
+                .get_topic(&Identifier::named(&name)?)?;
+            mapper::map_topic(topic).await
+        };
+        sender.send_ok_response(&response).await?;
+        return Ok(());
+    }
+
     let mut system = system.write().await;
+    system
+        .resolve_topic_settings(session, &mut command)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to resolve topic settings for stream_id: {stream_id}")
+        })?;
     let topic = system
             .create_topic(
                 session,
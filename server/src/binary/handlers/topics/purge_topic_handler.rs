@@ -16,6 +16,19 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
+    let topic_id = command.topic_id.clone();
+    let stream_id = command.stream_id.clone();
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .replicate(&cluster_node, session, EntryCommand::PurgeTopic(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate purge topic with id: {topic_id}, stream_id: {stream_id}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
+
     let system = system.read().await;
     system
         .purge_topic(session, &command.stream_id, &command.topic_id)
@@ -27,8 +40,6 @@ pub async fn handle(
             )
         })?;
 
-    let topic_id = command.topic_id.clone();
-    let stream_id = command.stream_id.clone();
     system
         .state
         .apply(session.get_user_id(), EntryCommand::PurgeTopic(command))
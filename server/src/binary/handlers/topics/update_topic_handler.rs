@@ -16,6 +16,18 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
+    let topic_id = command.topic_id.clone();
+    let stream_id = command.stream_id.clone();
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .replicate(&cluster_node, session, EntryCommand::UpdateTopic(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate update topic with id: {topic_id}, stream_id: {stream_id}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
 
     let mut system = system.write().await;
 
@@ -38,8 +50,6 @@ pub async fn handle(
     command.message_expiry = topic.message_expiry;
     command.max_topic_size = topic.max_topic_size;
 
-    let topic_id = command.topic_id.clone();
-    let stream_id = command.stream_id.clone();
     let system = system.downgrade();
 
     system
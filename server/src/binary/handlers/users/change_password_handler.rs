@@ -18,6 +18,43 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
 
+    if let Some(cluster_node) = system.cluster_node().await {
+        // For the security of the system, we hash the password before storing it in metadata.
+        let new_password_hash = {
+            let system = system.read().await;
+            system
+                .validate_password_change(
+                    session,
+                    &command.user_id,
+                    &command.current_password,
+                    &command.new_password,
+                )
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to change password for user_id: {}, session: {session}",
+                        command.user_id
+                    )
+                })?;
+            crypto::hash_password(&command.new_password, &system.config.password.hashing)
+        };
+        system
+            .replicate(&cluster_node, session, EntryCommand::ChangePassword(ChangePassword {
+                    user_id: command.user_id.to_owned(),
+                    current_password: "".into(),
+                    new_password: new_password_hash,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate change password for user_id: {}, session: {session}",
+                    command.user_id
+                )
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
+
     let mut system = system.write().await;
     system
             .change_password(
@@ -43,7 +80,10 @@ pub async fn handle(
             EntryCommand::ChangePassword(ChangePassword {
                 user_id: command.user_id.to_owned(),
                 current_password: "".into(),
-                new_password: crypto::hash_password(&command.new_password),
+                new_password: crypto::hash_password(
+                    &command.new_password,
+                    &system.config.password.hashing,
+                ),
             }),
         )
         .await
@@ -7,6 +7,7 @@ use crate::streaming::utils::crypto;
 use anyhow::Result;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::identifier::Identifier;
 use iggy::users::create_user::CreateUser;
 use tracing::{debug, instrument};
 
@@ -19,6 +20,44 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
 
+    if let Some(cluster_node) = system.cluster_node().await {
+        // For the security of the system, we hash the password before storing it in metadata.
+        let password_hash = {
+            let system = system.read().await;
+            system
+                .validate_user_creation(session, &command.username, &command.password)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to create user with name: {}, session: {session}",
+                        command.username
+                    )
+                })?;
+            crypto::hash_password(&command.password, &system.config.password.hashing)
+        };
+        system
+            .replicate(&cluster_node, session, EntryCommand::CreateUser(CreateUser {
+                    username: command.username.to_owned(),
+                    password: password_hash,
+                    status: command.status,
+                    permissions: command.permissions.clone(),
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate create user with name: {}, session: {session}",
+                    command.username
+                )
+            })?;
+        let response = {
+            let system = system.read().await;
+            let user = system.get_user(&Identifier::named(&command.username)?)?;
+            mapper::map_user(user, None)
+        };
+        sender.send_ok_response(&response).await?;
+        return Ok(());
+    }
+
     let mut system = system.write().await;
     let user = system
             .create_user(
@@ -35,7 +74,7 @@ pub async fn handle(
                     command.username
                 )
             })?;
-    let response = mapper::map_user(user);
+    let response = mapper::map_user(user, None);
 
     // For the security of the system, we hash the password before storing it in metadata.
     let system = system.downgrade();
@@ -45,7 +84,7 @@ pub async fn handle(
             session.get_user_id(),
             EntryCommand::CreateUser(CreateUser {
                 username: command.username.to_owned(),
-                password: crypto::hash_password(&command.password),
+                password: crypto::hash_password(&command.password, &system.config.password.hashing),
                 status: command.status,
                 permissions: command.permissions.clone(),
             }),
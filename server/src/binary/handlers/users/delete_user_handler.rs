@@ -16,6 +16,17 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
+    let user_id = command.user_id.clone();
+    if let Some(cluster_node) = system.cluster_node().await {
+        system
+            .replicate(&cluster_node, session, EntryCommand::DeleteUser(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete user with ID: {user_id}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
 
     let mut system = system.write().await;
     system
@@ -29,7 +40,6 @@ pub async fn handle(
             })?;
 
     let system = system.downgrade();
-    let user_id = command.user_id.clone();
     system
         .state
         .apply(session.get_user_id(), EntryCommand::DeleteUser(command))
@@ -23,7 +23,8 @@ pub async fn handle(
         return Ok(());
     };
 
-    let bytes = mapper::map_user(user);
+    let usage = system.get_user_usage(user.id);
+    let bytes = mapper::map_user(user, Some(&usage));
     sender.send_ok_response(&bytes).await?;
     Ok(())
 }
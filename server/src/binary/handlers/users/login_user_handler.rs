@@ -2,6 +2,7 @@ use crate::binary::mapper;
 use crate::binary::{handlers::users::COMPONENT, sender::SenderKind};
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::crypto;
 use anyhow::Result;
 use error_set::ErrContext;
 use iggy::error::IggyError;
@@ -16,17 +17,40 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
-    let system = system.read().await;
-    let user = system
-        .login_user(&command.username, &command.password, Some(session))
-        .await
-        .with_error_context(|error| {
-            format!(
-                "{COMPONENT} (error: {error}) - failed to login user with name: {}, session: {session}",
-                command.username
+    let (user_id, needs_rehash) = {
+        let system = system.read().await;
+        let user = system
+            .login_user(
+                &command.username,
+                &command.password,
+                session.ip_address.ip(),
+                Some(session),
             )
-        })?;
-    let identity_info = mapper::map_identity_info(user.id);
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to login user with name: {}, session: {session}",
+                    command.username
+                )
+            })?;
+        (
+            user.id,
+            crypto::needs_rehash(&user.password, &system.config.password.hashing),
+        )
+    };
+
+    if needs_rehash {
+        system
+            .rehash_password_if_needed(user_id, &command.password)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to rehash password for user with ID: {user_id}, session: {session}"
+                )
+            })?;
+    }
+
+    let identity_info = mapper::map_identity_info(user_id);
     sender.send_ok_response(&identity_info).await?;
     Ok(())
 }
@@ -0,0 +1,30 @@
+use crate::binary::{handlers::users::COMPONENT, sender::SenderKind};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::users::unlock_user::UnlockUser;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_unlock_user", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle(
+    command: UnlockUser,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    system
+        .unlock_user(session, &command.user_id)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to unlock user with ID: {}, session: {session}",
+                command.user_id
+            )
+        })?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
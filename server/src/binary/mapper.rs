@@ -1,4 +1,6 @@
+use crate::streaming::batching::message_batch::RETAINED_BATCH_FORMAT_VERSION;
 use crate::streaming::clients::client_manager::{Client, Transport};
+use crate::streaming::models::messages::PolledMessageBatches;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::streams::stream::Stream;
@@ -8,10 +10,15 @@ use crate::streaming::users::user::User;
 use bytes::{BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::messages::poll_messages::PolledMessagesFormat;
+use iggy::models::backup::BackupInfo;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
 use iggy::models::messages::PolledMessages;
+use iggy::models::partitions_merge::PartitionsMergeInfo;
+use iggy::models::schema::{Schema, TopicSchemas};
 use iggy::models::stats::Stats;
-use iggy::models::user_info::UserId;
+use iggy::models::topic_template::{TopicDefaults, TopicTemplate};
+use iggy::models::user_info::{UserId, UserUsage};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::sizeable::Sizeable;
 use tokio::sync::RwLock;
@@ -61,6 +68,12 @@ pub fn map_stats(stats: &Stats) -> Bytes {
         bytes.put_f32_le(metrics.hit_ratio);
     }
 
+    bytes.put_u64_le(stats.failed_login_attempts);
+    bytes.put_u64_le(stats.login_lockouts);
+    bytes.put_u64_le(stats.quota_violations);
+    bytes.put_u64_le(stats.recovered_segments);
+    bytes.put_u64_le(stats.truncated_segment_bytes);
+
     bytes.freeze()
 }
 
@@ -92,7 +105,7 @@ pub async fn map_clients(clients: &[IggySharedMut<Client>]) -> Bytes {
     bytes.freeze()
 }
 
-pub fn map_user(user: &User) -> Bytes {
+pub fn map_user(user: &User, usage: Option<&UserUsage>) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_user(user, &mut bytes);
     if let Some(permissions) = &user.permissions {
@@ -104,6 +117,17 @@ pub fn map_user(user: &User) -> Bytes {
     } else {
         bytes.put_u32_le(0);
     }
+    if let Some(usage) = usage {
+        bytes.put_u8(1);
+        bytes.put_u32_le(usage.streams_count);
+        bytes.put_u32_le(usage.topics_count);
+        bytes.put_u32_le(usage.partitions_count);
+        bytes.put_u32_le(usage.max_streams);
+        bytes.put_u32_le(usage.max_topics);
+        bytes.put_u32_le(usage.max_partitions);
+    } else {
+        bytes.put_u8(0);
+    }
     bytes.freeze()
 }
 
@@ -136,6 +160,77 @@ pub fn map_personal_access_tokens(personal_access_tokens: &[&PersonalAccessToken
     bytes.freeze()
 }
 
+pub fn map_backup(backup: &BackupInfo) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_backup(backup, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_backups(backups: &[BackupInfo]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for backup in backups {
+        extend_backup(backup, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_topic_template(template: &TopicTemplate) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_topic_template(template, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_topic_templates(templates: &[&TopicTemplate]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for template in templates {
+        extend_topic_template(template, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_schema(schema: &Schema) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_schema(schema, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_topic_schemas(topic_schemas: &TopicSchemas) -> Bytes {
+    let mut bytes = BytesMut::new();
+    bytes.put_u8(topic_schemas.compatibility.as_code());
+    bytes.put_u8(topic_schemas.validate_payloads as u8);
+    for schema in &topic_schemas.schemas {
+        extend_schema(schema, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_topic_defaults(topic_defaults: &TopicDefaults) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(17);
+    bytes.put_u64_le(topic_defaults.message_expiry.into());
+    bytes.put_u64_le(topic_defaults.max_topic_size.into());
+    bytes.put_u8(topic_defaults.replication_factor.unwrap_or(0));
+    bytes.freeze()
+}
+
+pub fn map_partitions_merge(partitions_merge: &PartitionsMergeInfo) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(49);
+    bytes.put_u32_le(partitions_merge.stream_id);
+    bytes.put_u32_le(partitions_merge.topic_id);
+    bytes.put_u8(partitions_merge.status.as_code());
+    bytes.put_u32_le(partitions_merge.partitions_count);
+    bytes.put_u32_le(partitions_merge.merged_partitions);
+    bytes.put_u64_le(partitions_merge.messages_count);
+    bytes.put_u64_le(partitions_merge.merged_messages);
+    bytes.put_u64_le(partitions_merge.started_at.into());
+    bytes.put_u64_le(
+        partitions_merge
+            .finished_at
+            .map(|finished_at| finished_at.as_micros())
+            .unwrap_or_default(),
+    );
+    bytes.freeze()
+}
+
 pub fn map_polled_messages(polled_messages: &PolledMessages) -> Bytes {
     let messages_count = polled_messages.messages.len() as u32;
     let messages_size = polled_messages
@@ -155,6 +250,29 @@ pub fn map_polled_messages(polled_messages: &PolledMessages) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_polled_messages_with_format(polled_messages: &PolledMessages) -> Bytes {
+    let messages = map_polled_messages(polled_messages);
+    let mut bytes = BytesMut::with_capacity(1 + messages.len());
+    bytes.put_u8(PolledMessagesFormat::Messages.as_code());
+    bytes.put_slice(&messages);
+    bytes.freeze()
+}
+
+// The batches are sent directly from the segment file, right after this header, so their format version
+// is sent as well, for the client to reject the batches it's not able to read.
+pub fn map_polled_message_batches_header(polled_batches: &PolledMessageBatches) -> Bytes {
+    let file_range = &polled_batches.file_range;
+    let mut bytes = BytesMut::with_capacity(34);
+    bytes.put_u8(PolledMessagesFormat::Batches.as_code());
+    bytes.put_u8(RETAINED_BATCH_FORMAT_VERSION);
+    bytes.put_u32_le(polled_batches.partition_id);
+    bytes.put_u64_le(polled_batches.current_offset);
+    bytes.put_u32_le(file_range.messages_count());
+    bytes.put_u64_le(file_range.start_offset);
+    bytes.put_u64_le(file_range.end_offset);
+    bytes.freeze()
+}
+
 pub fn map_stream(stream: &Stream) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_stream(stream, &mut bytes);
@@ -278,6 +396,36 @@ fn extend_user(user: &User, bytes: &mut BytesMut) {
     bytes.put_slice(user.username.as_bytes());
 }
 
+fn extend_backup(backup: &BackupInfo, bytes: &mut BytesMut) {
+    bytes.put_u64_le(backup.id);
+    bytes.put_u8(backup.kind.as_code());
+    bytes.put_u64_le(backup.parent_id.unwrap_or_default());
+    bytes.put_u64_le(backup.created_at.into());
+    bytes.put_u32_le(backup.files_count);
+    bytes.put_u64_le(backup.size.as_bytes_u64());
+}
+
+fn extend_topic_template(template: &TopicTemplate, bytes: &mut BytesMut) {
+    bytes.put_u32_le(template.partitions_count);
+    bytes.put_u8(template.compression_algorithm.as_code());
+    bytes.put_u64_le(template.message_expiry.into());
+    bytes.put_u64_le(template.max_topic_size.into());
+    bytes.put_u8(template.replication_factor.unwrap_or(0));
+    bytes.put_u8(template.name.len() as u8);
+    bytes.put_slice(template.name.as_bytes());
+}
+
+fn extend_schema(schema: &Schema, bytes: &mut BytesMut) {
+    bytes.put_u32_le(schema.id);
+    bytes.put_u32_le(schema.stream_id);
+    bytes.put_u32_le(schema.topic_id);
+    bytes.put_u32_le(schema.version);
+    bytes.put_u8(schema.schema_type.as_code());
+    bytes.put_u64_le(schema.created_at.into());
+    bytes.put_u32_le(schema.definition.len() as u32);
+    bytes.put_slice(schema.definition.as_bytes());
+}
+
 fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut BytesMut) {
     bytes.put_u8(personal_access_token.name.len() as u8);
     bytes.put_slice(personal_access_token.name.as_bytes());
@@ -289,4 +437,12 @@ fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut BytesMut)
             bytes.put_u64_le(0);
         }
     }
+    if let Some(permissions) = &personal_access_token.permissions {
+        bytes.put_u8(1);
+        let permissions = permissions.to_bytes();
+        bytes.put_u32_le(permissions.len() as u32);
+        bytes.put_slice(&permissions);
+    } else {
+        bytes.put_u8(0);
+    }
 }
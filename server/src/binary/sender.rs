@@ -49,8 +49,11 @@ pub enum SenderKind {
 }
 
 impl SenderKind {
-    pub fn get_tcp_sender(stream: TcpStream) -> Self {
-        Self::Tcp(TcpSender { stream })
+    pub fn get_tcp_sender(stream: TcpStream, zero_copy_polling: bool) -> Self {
+        Self::Tcp(TcpSender {
+            stream,
+            zero_copy_polling,
+        })
     }
 
     pub fn get_tcp_tls_sender(stream: TlsStream<TcpStream>) -> Self {
@@ -64,6 +67,14 @@ impl SenderKind {
         })
     }
 
+    /// Returns the sender which can send the message batches directly from the segment files, if enabled.
+    pub fn as_zero_copy_sender(&mut self) -> Option<&mut TcpSender> {
+        match self {
+            Self::Tcp(sender) if sender.zero_copy_polling => Some(sender),
+            _ => None,
+        }
+    }
+
     forward_async_methods! {
         async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError>;
         async fn send_empty_ok_response(&mut self) -> Result<(), IggyError>;
@@ -4,6 +4,7 @@ use crate::streaming::systems::system::SharedSystem;
 use flume::Sender;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
+use std::path::Path;
 use tokio::time;
 use tracing::{error, info, instrument, warn};
 
@@ -72,9 +73,13 @@ impl ServerCommand<ArchiveStateCommand> for ArchiveStateExecutor {
         };
         let state_log_path = system.config.get_state_log_path();
         let state_info_path = system.config.get_state_info_path();
+        let state_snapshot_path = system.config.get_state_snapshot_path();
         info!("Archiving state...");
         let archiver = system.archiver.as_ref().unwrap();
-        let files = [state_info_path.as_ref(), state_log_path.as_ref()];
+        let mut files = vec![state_info_path.as_str(), state_log_path.as_str()];
+        if Path::new(&state_snapshot_path).exists() {
+            files.push(state_snapshot_path.as_str());
+        }
         if let Err(error) = archiver.archive(&files, base_directory).await {
             error!("Failed to archive state. Error: {}", error);
             return;
@@ -63,29 +63,37 @@ impl ServerCommand<CleanPersonalAccessTokensCommand> for CleanPersonalAccessToke
         // TODO: System write lock, investigate if it's necessary.
         let mut system = system.write().await;
         let now = IggyTimestamp::now();
-        let mut deleted_tokens_count = 0;
+        let mut deleted_tokens = Vec::new();
         for (_, user) in system.users.iter_mut() {
             let expired_tokens = user
                 .personal_access_tokens
                 .values()
                 .filter(|token| token.is_expired(now))
-                .map(|token| token.token.clone())
+                .map(|token| (token.token.clone(), token.name.clone()))
                 .collect::<Vec<_>>();
 
-            for token in expired_tokens {
+            for (token, name) in expired_tokens {
                 debug!(
                     "Personal access token: {token} for user with ID: {} is expired.",
                     user.id
                 );
-                deleted_tokens_count += 1;
                 user.personal_access_tokens.remove(&token);
+                deleted_tokens.push((user.id, name));
                 debug!(
                     "Deleted personal access token: {token} for user with ID: {}.",
                     user.id
                 );
             }
         }
-        info!("Deleted {deleted_tokens_count} expired personal access tokens.");
+        for (user_id, name) in deleted_tokens.iter() {
+            system
+                .permissioner
+                .delete_permissions_for_personal_access_token(*user_id, name);
+        }
+        info!(
+            "Deleted {} expired personal access tokens.",
+            deleted_tokens.len()
+        );
     }
 
     fn start_command_sender(
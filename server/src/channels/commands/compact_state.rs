@@ -0,0 +1,105 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::StateMaintenanceConfig;
+use crate::streaming::systems::system::SharedSystem;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{error, info, instrument};
+
+pub struct StateCompactor {
+    enabled: bool,
+    min_entries: u64,
+    interval: IggyDuration,
+    sender: Sender<CompactStateCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CompactStateCommand {
+    min_entries: u64,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CompactStateExecutor;
+
+impl StateCompactor {
+    pub fn new(config: &StateMaintenanceConfig, sender: Sender<CompactStateCommand>) -> Self {
+        Self {
+            enabled: config.compaction_enabled,
+            min_entries: config.compaction_min_entries,
+            interval: config.compaction_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("State compactor is disabled.");
+            return;
+        }
+
+        let min_entries = self.min_entries;
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("State compactor is enabled, state log will be compacted every: {interval}, if it contains at least: {min_entries} entries.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(CompactStateCommand { min_entries })
+                    .unwrap_or_else(|err| {
+                        error!("Failed to send CompactStateCommand. Error: {}", err);
+                    });
+            }
+        });
+    }
+}
+
+impl ServerCommand<CompactStateCommand> for CompactStateExecutor {
+    #[instrument(skip_all, name = "trace_compact_state")]
+    async fn execute(&mut self, system: &SharedSystem, command: CompactStateCommand) {
+        let system = system.read().await;
+        match system
+            .state
+            .compact(command.min_entries, system.config.password.policy.history)
+            .await
+        {
+            Ok(Some(index)) => info!("State log compacted up to index: {index}."),
+            Ok(None) => {}
+            Err(error) => error!("Failed to compact state log. Error: {}", error),
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<CompactStateCommand>,
+    ) {
+        if !config.data_maintenance.state.compaction_enabled {
+            return;
+        }
+
+        let state_compactor = StateCompactor::new(&config.data_maintenance.state, sender);
+        state_compactor.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<CompactStateCommand>,
+    ) {
+        if !config.data_maintenance.state.compaction_enabled {
+            return;
+        }
+
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("State compactor receiver stopped.");
+        });
+    }
+}
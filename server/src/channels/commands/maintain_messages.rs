@@ -117,8 +117,44 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
                     continue;
                 }
 
+                let offloaded_segments = if system.storage.tiered.is_some() {
+                    handle_offloaded_segments(
+                        topic,
+                        archiver.clone(),
+                        system.config.tiered_storage.offload_after,
+                        command.clean_messages,
+                    )
+                    .await
+                } else {
+                    Ok(HandledSegments::none())
+                };
+                if offloaded_segments.is_err() {
+                    error!(
+                        "Failed to offload segments for stream ID: {}, topic ID: {}",
+                        topic.stream_id, topic.topic_id
+                    );
+                    continue;
+                }
+
                 let deleted_expired_segments = expired_segments.unwrap();
                 let deleted_oldest_segments = oldest_segments.unwrap();
+                let offloaded_segments = offloaded_segments.unwrap();
+                if offloaded_segments.segments_count > 0 {
+                    info!(
+                        "Offloaded {} segments and {} messages for stream ID: {}, topic ID: {}",
+                        offloaded_segments.segments_count,
+                        offloaded_segments.messages_count,
+                        topic.stream_id,
+                        topic.topic_id
+                    );
+                    system
+                        .metrics
+                        .decrement_segments(offloaded_segments.segments_count);
+                    system
+                        .metrics
+                        .decrement_messages(offloaded_segments.messages_count);
+                }
+
                 let deleted_segments = HandledSegments {
                     segments_count: deleted_expired_segments.segments_count
                         + deleted_oldest_segments.segments_count,
@@ -395,6 +431,148 @@ async fn get_oldest_segments(topic: &Topic) -> Vec<SegmentsToHandle> {
     oldest_segments
 }
 
+async fn handle_offloaded_segments(
+    topic: &Topic,
+    archiver: Option<Arc<ArchiverKind>>,
+    offload_after: IggyDuration,
+    clean: bool,
+) -> Result<HandledSegments, IggyError> {
+    let now = IggyTimestamp::now();
+    if clean {
+        for partition in topic.partitions.values() {
+            let mut partition = partition.write().await;
+            let deleted_segments = partition
+                .delete_expired_offloaded_segments(now)
+                .await
+                .with_error_context(|error| {
+                    format!("CHANNEL_COMMAND - failed to delete expired offloaded segments for stream ID: {}, topic ID: {}. {error}", topic.stream_id, topic.topic_id)
+                })?;
+            if deleted_segments > 0 {
+                info!(
+                    "Deleted {} expired offloaded segments for stream ID: {}, topic ID: {}, partition ID: {}",
+                    deleted_segments, topic.stream_id, topic.topic_id, partition.partition_id
+                );
+            }
+        }
+    }
+
+    let Some(archiver) = archiver else {
+        return Ok(HandledSegments::none());
+    };
+
+    let segments_to_offload = get_segments_to_offload(topic, archiver, offload_after, now).await;
+    if segments_to_offload.is_empty() {
+        return Ok(HandledSegments::none());
+    }
+
+    offload_segments(topic, &segments_to_offload).await
+}
+
+async fn get_segments_to_offload(
+    topic: &Topic,
+    archiver: Arc<ArchiverKind>,
+    offload_after: IggyDuration,
+    now: IggyTimestamp,
+) -> Vec<SegmentsToHandle> {
+    let mut segments_to_offload = Vec::new();
+    for partition in topic.partitions.values() {
+        let mut start_offsets = Vec::new();
+        let partition = partition.read().await;
+        let segments = partition.get_segments();
+        // The last segment is never offloaded, as the new messages are appended to it.
+        for segment in segments.iter().take(segments.len().saturating_sub(1)) {
+            if !segment.is_offloadable(offload_after, now).await {
+                continue;
+            }
+
+            // Only the segments whose both files have been archived can be fetched back.
+            let mut is_archived = true;
+            for file in [&segment.index_path, &segment.log_path] {
+                match archiver.is_archived(file, None).await {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        is_archived = false;
+                        break;
+                    }
+                    Err(error) => {
+                        error!(
+                            "Failed to check if segment file: {file} is archived for stream ID: {}, topic ID: {}, partition ID: {}. Error: {}",
+                            topic.stream_id, topic.topic_id, partition.partition_id, error
+                        );
+                        is_archived = false;
+                        break;
+                    }
+                }
+            }
+
+            if !is_archived {
+                debug!(
+                    "Segment with start offset: {} is not archived yet and won't be offloaded for stream ID: {}, topic ID: {}, partition ID: {}",
+                    segment.start_offset, topic.stream_id, topic.topic_id, partition.partition_id
+                );
+                continue;
+            }
+
+            start_offsets.push(segment.start_offset);
+        }
+
+        if !start_offsets.is_empty() {
+            segments_to_offload.push(SegmentsToHandle {
+                partition_id: partition.partition_id,
+                start_offsets,
+            });
+        }
+    }
+
+    segments_to_offload
+}
+
+async fn offload_segments(
+    topic: &Topic,
+    segments_to_offload: &[SegmentsToHandle],
+) -> Result<HandledSegments, IggyError> {
+    info!(
+        "Offloading {} segments for stream ID: {}, topic ID: {}...",
+        segments_to_offload.len(),
+        topic.stream_id,
+        topic.topic_id
+    );
+
+    let mut segments_count = 0;
+    let mut messages_count = 0;
+    for segment_to_offload in segments_to_offload {
+        match topic.get_partition(segment_to_offload.partition_id) {
+            Ok(partition) => {
+                let mut partition = partition.write().await;
+                for start_offset in &segment_to_offload.start_offsets {
+                    // The segment might have been deleted in the meantime, e.g. by the cleaner.
+                    if partition.get_segment(*start_offset).is_none() {
+                        continue;
+                    }
+
+                    let offloaded_segment = partition.offload_segment(*start_offset).await.with_error_context(|error| {
+                        format!("CHANNEL_COMMAND - failed to offload segment for stream with ID: {}, topic with ID: {}. {error}", topic.stream_id, topic.topic_id)
+                    })?;
+                    segments_count += 1;
+                    messages_count += offloaded_segment.messages_count;
+                }
+            }
+            Err(error) => {
+                error!(
+                    "Partition with ID: {} was not found for stream with ID: {}, topic with ID: {}. {error}",
+                    segment_to_offload.partition_id, topic.stream_id, topic.topic_id
+                );
+                continue;
+            }
+        }
+    }
+
+    Ok(HandledSegments {
+        segments_count,
+        messages_count,
+    })
+}
+
 #[derive()]
 struct SegmentsToHandle {
     partition_id: u32,
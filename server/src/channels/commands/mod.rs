@@ -1,6 +1,8 @@
 pub mod archive_state;
 pub mod clean_personal_access_tokens;
+pub mod compact_state;
 pub mod maintain_messages;
 pub mod print_sysinfo;
+pub mod rotate_encryption_keys;
 pub mod save_messages;
 pub mod verify_heartbeats;
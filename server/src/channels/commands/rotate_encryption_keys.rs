@@ -0,0 +1,194 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::KeyringConfig;
+use crate::map_toggle_str;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::segments::ReencryptedSegment;
+use crate::streaming::systems::system::SharedSystem;
+use flume::Sender;
+use iggy::error::IggyError;
+use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::utils::crypto::KeyringEncryptor;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{error, info, instrument, warn};
+
+pub struct EncryptionKeysRotator {
+    reencryption_enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<RotateEncryptionKeysCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RotateEncryptionKeysCommand {
+    reencrypt_segments: bool,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RotateEncryptionKeysExecutor;
+
+impl EncryptionKeysRotator {
+    pub fn new(config: &KeyringConfig, sender: Sender<RotateEncryptionKeysCommand>) -> Self {
+        Self {
+            reencryption_enabled: config.reencryption_enabled,
+            interval: config.rotation_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        let interval = self.interval;
+        let reencrypt_segments = self.reencryption_enabled;
+        let sender = self.sender.clone();
+        info!(
+            "Encryption keys rotator is enabled, the key will be rotated every: {interval}, re-encryption of segments is {}.",
+            map_toggle_str(reencrypt_segments)
+        );
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            // The first tick completes immediately, and the key shouldn't be rotated on every startup.
+            interval_timer.tick().await;
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(RotateEncryptionKeysCommand { reencrypt_segments })
+                    .unwrap_or_else(|err| {
+                        error!("Failed to send RotateEncryptionKeysCommand. Error: {}", err);
+                    });
+            }
+        });
+    }
+}
+
+impl ServerCommand<RotateEncryptionKeysCommand> for RotateEncryptionKeysExecutor {
+    #[instrument(skip_all, name = "trace_rotate_encryption_keys")]
+    async fn execute(&mut self, system: &SharedSystem, command: RotateEncryptionKeysCommand) {
+        let system = system.read().await;
+        let Some(keyring) = system.keyring.clone() else {
+            return;
+        };
+
+        if let Err(error) = keyring.rotate() {
+            error!("Failed to rotate the encryption key. Error: {}", error);
+            return;
+        }
+
+        if !command.reencrypt_segments {
+            return;
+        }
+
+        let mut reencrypted_segments = 0;
+        let mut reencrypted_messages = 0;
+        for stream in system.get_streams() {
+            for topic in stream.get_topics() {
+                for partition in topic.get_partitions() {
+                    match reencrypt_segments(&partition, keyring.keyring_encryptor()).await {
+                        Ok((segments_count, messages_count)) => {
+                            reencrypted_segments += segments_count;
+                            reencrypted_messages += messages_count;
+                        }
+                        Err(error) => {
+                            error!(
+                                "Failed to re-encrypt segments for stream ID: {}, topic ID: {}. Error: {}",
+                                topic.stream_id, topic.topic_id, error
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        info!(
+            "Re-encrypted {reencrypted_messages} messages in {reencrypted_segments} segments with the key ID: {}.",
+            keyring.current_key_id()
+        );
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<RotateEncryptionKeysCommand>,
+    ) {
+        let encryption = &config.system.encryption;
+        if !encryption.enabled
+            || !encryption.keyring.enabled
+            || !encryption.keyring.rotation_enabled
+        {
+            return;
+        }
+
+        let rotator = EncryptionKeysRotator::new(&encryption.keyring, sender);
+        rotator.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<RotateEncryptionKeysCommand>,
+    ) {
+        let encryption = &config.system.encryption;
+        if !encryption.enabled
+            || !encryption.keyring.enabled
+            || !encryption.keyring.rotation_enabled
+        {
+            return;
+        }
+
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Encryption keys rotator receiver stopped.");
+        });
+    }
+}
+
+/// The closed segments are re-encrypted while the partition is locked for reading,
+/// and only the replacement of the files requires the write lock.
+async fn reencrypt_segments(
+    partition: &IggySharedMut<Partition>,
+    encryptor: &KeyringEncryptor,
+) -> Result<(u64, u64), IggyError> {
+    let mut reencrypted_segments: Vec<ReencryptedSegment> = Vec::new();
+    {
+        let partition = partition.read().await;
+        for segment in partition.get_segments() {
+            match segment.reencrypt(encryptor).await {
+                Ok(Some(reencrypted)) => reencrypted_segments.push(reencrypted),
+                Ok(None) => {}
+                Err(error) => {
+                    warn!(
+                        "Failed to re-encrypt segment with start offset: {} for partition with ID: {}. Error: {}",
+                        segment.start_offset, segment.partition_id, error
+                    );
+                }
+            }
+        }
+    }
+
+    if reencrypted_segments.is_empty() {
+        return Ok((0, 0));
+    }
+
+    let mut segments_count = 0;
+    let mut messages_count = 0;
+    let mut partition = partition.write().await;
+    for reencrypted in reencrypted_segments {
+        // The segment might have been deleted in the meantime.
+        let Some(segment) = partition.get_segment_mut(reencrypted.start_offset) else {
+            reencrypted.remove().await;
+            continue;
+        };
+
+        if let Err(error) = segment.replace_reencrypted(&reencrypted).await {
+            reencrypted.remove().await;
+            return Err(error);
+        }
+
+        segments_count += 1;
+        messages_count += reencrypted.reencrypted_messages;
+    }
+    Ok((segments_count, messages_count))
+}
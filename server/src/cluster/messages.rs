@@ -0,0 +1,354 @@
+use crate::cluster::raft::{Members, NodeId, RaftEntry};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use std::fmt::{Display, Formatter};
+use std::str::from_utf8;
+
+const REQUEST_VOTE_KIND: u8 = 1;
+const REQUEST_VOTE_RESPONSE_KIND: u8 = 2;
+const APPEND_ENTRIES_KIND: u8 = 3;
+const APPEND_ENTRIES_RESPONSE_KIND: u8 = 4;
+
+/// Messages exchanged between the cluster nodes, the sender is known from the handshake of the connection.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RaftMessage {
+    RequestVote {
+        term: u64,
+        last_log_index: u64,
+        last_log_term: u64,
+    },
+    RequestVoteResponse {
+        term: u64,
+        vote_granted: bool,
+    },
+    AppendEntries {
+        term: u64,
+        prev_log_index: u64,
+        prev_log_term: u64,
+        entries: Vec<RaftEntry>,
+        leader_commit: u64,
+    },
+    /// On success, `last_index` is the index of the last entry matching the leader's log,
+    /// otherwise it's the hint from which index the leader should retry.
+    AppendEntriesResponse {
+        term: u64,
+        success: bool,
+        last_index: u64,
+    },
+}
+
+/// The first message sent over the connection, authenticating the node which opened it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Handshake {
+    pub node_id: NodeId,
+    pub secret: String,
+}
+
+impl BytesSerializable for RaftMessage {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        match self {
+            RaftMessage::RequestVote {
+                term,
+                last_log_index,
+                last_log_term,
+            } => {
+                bytes.put_u8(REQUEST_VOTE_KIND);
+                bytes.put_u64_le(*term);
+                bytes.put_u64_le(*last_log_index);
+                bytes.put_u64_le(*last_log_term);
+            }
+            RaftMessage::RequestVoteResponse { term, vote_granted } => {
+                bytes.put_u8(REQUEST_VOTE_RESPONSE_KIND);
+                bytes.put_u64_le(*term);
+                bytes.put_u8(*vote_granted as u8);
+            }
+            RaftMessage::AppendEntries {
+                term,
+                prev_log_index,
+                prev_log_term,
+                entries,
+                leader_commit,
+            } => {
+                bytes.put_u8(APPEND_ENTRIES_KIND);
+                bytes.put_u64_le(*term);
+                bytes.put_u64_le(*prev_log_index);
+                bytes.put_u64_le(*prev_log_term);
+                bytes.put_u64_le(*leader_commit);
+                bytes.put_u32_le(entries.len() as u32);
+                for entry in entries {
+                    put_entry(&mut bytes, entry);
+                }
+            }
+            RaftMessage::AppendEntriesResponse {
+                term,
+                success,
+                last_index,
+            } => {
+                bytes.put_u8(APPEND_ENTRIES_RESPONSE_KIND);
+                bytes.put_u64_le(*term);
+                bytes.put_u8(*success as u8);
+                bytes.put_u64_le(*last_index);
+            }
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(mut bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let message = match get_u8(&mut bytes)? {
+            REQUEST_VOTE_KIND => RaftMessage::RequestVote {
+                term: get_u64(&mut bytes)?,
+                last_log_index: get_u64(&mut bytes)?,
+                last_log_term: get_u64(&mut bytes)?,
+            },
+            REQUEST_VOTE_RESPONSE_KIND => RaftMessage::RequestVoteResponse {
+                term: get_u64(&mut bytes)?,
+                vote_granted: get_u8(&mut bytes)? == 1,
+            },
+            APPEND_ENTRIES_KIND => {
+                let term = get_u64(&mut bytes)?;
+                let prev_log_index = get_u64(&mut bytes)?;
+                let prev_log_term = get_u64(&mut bytes)?;
+                let leader_commit = get_u64(&mut bytes)?;
+                let entries_count = get_u32(&mut bytes)?;
+                let mut entries = Vec::new();
+                for _ in 0..entries_count {
+                    entries.push(get_entry(&mut bytes)?);
+                }
+                RaftMessage::AppendEntries {
+                    term,
+                    prev_log_index,
+                    prev_log_term,
+                    entries,
+                    leader_commit,
+                }
+            }
+            APPEND_ENTRIES_RESPONSE_KIND => RaftMessage::AppendEntriesResponse {
+                term: get_u64(&mut bytes)?,
+                success: get_u8(&mut bytes)? == 1,
+                last_index: get_u64(&mut bytes)?,
+            },
+            _ => return Err(IggyError::InvalidCommand),
+        };
+
+        if bytes.has_remaining() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(message)
+    }
+}
+
+impl Display for RaftMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RaftMessage::RequestVote {
+                term,
+                last_log_index,
+                last_log_term,
+            } => write!(
+                f,
+                "RequestVote {{ term: {term}, last_log_index: {last_log_index}, last_log_term: {last_log_term} }}"
+            ),
+            RaftMessage::RequestVoteResponse { term, vote_granted } => write!(
+                f,
+                "RequestVoteResponse {{ term: {term}, vote_granted: {vote_granted} }}"
+            ),
+            RaftMessage::AppendEntries {
+                term,
+                prev_log_index,
+                prev_log_term,
+                entries,
+                leader_commit,
+            } => write!(
+                f,
+                "AppendEntries {{ term: {term}, prev_log_index: {prev_log_index}, prev_log_term: {prev_log_term}, entries: {}, leader_commit: {leader_commit} }}",
+                entries.len()
+            ),
+            RaftMessage::AppendEntriesResponse {
+                term,
+                success,
+                last_index,
+            } => write!(
+                f,
+                "AppendEntriesResponse {{ term: {term}, success: {success}, last_index: {last_index} }}"
+            ),
+        }
+    }
+}
+
+impl BytesSerializable for Handshake {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4 + 4 + self.secret.len());
+        bytes.put_u32_le(self.node_id);
+        put_string(&mut bytes, &self.secret);
+        bytes.freeze()
+    }
+
+    fn from_bytes(mut bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let node_id = get_u32(&mut bytes)?;
+        let secret = get_string(&mut bytes)?;
+        Ok(Handshake { node_id, secret })
+    }
+}
+
+fn put_entry(bytes: &mut BytesMut, entry: &RaftEntry) {
+    bytes.put_u64_le(entry.term);
+    match &entry.members {
+        Some(members) => {
+            bytes.put_u8(1);
+            bytes.put_u32_le(members.len() as u32);
+            for (id, address) in members {
+                bytes.put_u32_le(*id);
+                put_string(bytes, address);
+            }
+        }
+        None => bytes.put_u8(0),
+    }
+    bytes.put_u32_le(entry.payload.len() as u32);
+    bytes.put_slice(&entry.payload);
+}
+
+fn get_entry(bytes: &mut Bytes) -> Result<RaftEntry, IggyError> {
+    let term = get_u64(bytes)?;
+    let members = match get_u8(bytes)? {
+        0 => None,
+        _ => {
+            let members_count = get_u32(bytes)?;
+            let mut members = Members::new();
+            for _ in 0..members_count {
+                let id = get_u32(bytes)?;
+                members.insert(id, get_string(bytes)?);
+            }
+            Some(members)
+        }
+    };
+    let payload_length = get_u32(bytes)? as usize;
+    if bytes.remaining() < payload_length {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let payload = bytes.split_to(payload_length);
+    Ok(RaftEntry {
+        term,
+        members,
+        payload,
+    })
+}
+
+fn put_string(bytes: &mut BytesMut, value: &str) {
+    bytes.put_u32_le(value.len() as u32);
+    bytes.put_slice(value.as_bytes());
+}
+
+fn get_string(bytes: &mut Bytes) -> Result<String, IggyError> {
+    let length = get_u32(bytes)? as usize;
+    if bytes.remaining() < length {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let value = bytes.split_to(length);
+    Ok(from_utf8(&value)
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string())
+}
+
+fn get_u8(bytes: &mut Bytes) -> Result<u8, IggyError> {
+    if bytes.remaining() < 1 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    Ok(bytes.get_u8())
+}
+
+fn get_u32(bytes: &mut Bytes) -> Result<u32, IggyError> {
+    if bytes.remaining() < 4 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    Ok(bytes.get_u32_le())
+}
+
+fn get_u64(bytes: &mut Bytes) -> Result<u64, IggyError> {
+    if bytes.remaining() < 8 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    Ok(bytes.get_u64_le())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_entries_should_be_serialized_and_deserialized() {
+        let message = RaftMessage::AppendEntries {
+            term: 3,
+            prev_log_index: 10,
+            prev_log_term: 2,
+            entries: vec![
+                RaftEntry {
+                    term: 3,
+                    members: None,
+                    payload: Bytes::from_static(b"entry"),
+                },
+                RaftEntry {
+                    term: 3,
+                    members: Some(Members::from([
+                        (1, "127.0.0.1:8070".to_string()),
+                        (2, "127.0.0.1:8071".to_string()),
+                    ])),
+                    payload: Bytes::new(),
+                },
+            ],
+            leader_commit: 9,
+        };
+
+        let deserialized = RaftMessage::from_bytes(message.to_bytes()).unwrap();
+        assert_eq!(deserialized, message);
+    }
+
+    #[test]
+    fn responses_should_be_serialized_and_deserialized() {
+        let messages = [
+            RaftMessage::RequestVote {
+                term: 1,
+                last_log_index: 2,
+                last_log_term: 1,
+            },
+            RaftMessage::RequestVoteResponse {
+                term: 1,
+                vote_granted: true,
+            },
+            RaftMessage::AppendEntriesResponse {
+                term: 4,
+                success: false,
+                last_index: 7,
+            },
+        ];
+
+        for message in messages {
+            let deserialized = RaftMessage::from_bytes(message.to_bytes()).unwrap();
+            assert_eq!(deserialized, message);
+        }
+    }
+
+    #[test]
+    fn truncated_message_should_not_be_deserialized() {
+        let message = RaftMessage::RequestVote {
+            term: 1,
+            last_log_index: 2,
+            last_log_term: 1,
+        };
+        let bytes = message.to_bytes();
+        assert!(RaftMessage::from_bytes(bytes.slice(..bytes.len() - 1)).is_err());
+    }
+}
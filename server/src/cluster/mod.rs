@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+pub mod messages;
+pub mod node;
+pub mod raft;
+pub mod transport;
+
+pub const COMPONENT: &str = "CLUSTER";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClusterInfo {
+    pub node_id: u32,
+    pub role: String,
+    pub term: u64,
+    pub leader_id: Option<u32>,
+    pub commit_index: u64,
+    pub applied_index: u64,
+    pub members: Vec<ClusterMember>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClusterMember {
+    pub id: u32,
+    pub address: String,
+}
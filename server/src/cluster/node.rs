@@ -0,0 +1,744 @@
+use crate::cluster::messages::RaftMessage;
+use crate::cluster::raft::{HardState, Members, NodeId, RaftCore, RaftEntry, Ready};
+use crate::cluster::{transport, ClusterInfo, ClusterMember, COMPONENT};
+use crate::configs::cluster::ClusterConfig;
+use crate::state::command::EntryCommand;
+use crate::state::entry::StateEntry;
+use crate::state::file::FileState;
+use crate::state::models::ElectClusterLeader;
+use crate::state::snapshot::StateSnapshot;
+use crate::state::State;
+use crate::streaming::persistence::persister::PersisterKind;
+use crate::streaming::systems::system::SharedSystem;
+use ahash::{AHashMap, AHashSet};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use error_set::ErrContext;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use std::collections::VecDeque;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{oneshot, Mutex};
+use tracing::{debug, error, info, warn};
+
+const METADATA_LENGTH: usize = 8 + 4 + 8;
+const MIN_TICK_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Node of the cluster replicating the state log using the Raft consensus algorithm.
+///
+/// The leader appends the command to the log without changing its in-memory state, and once the entry is committed
+/// by the majority of the nodes, every node (including the leader) applies it in the log order, on behalf of the user
+/// who issued it. The leader responds to the client only after the entry has been applied to its in-memory state.
+/// Until the node is started, the entries are appended only to the local log (e.g. the root user created on bootstrap).
+#[derive(Debug)]
+pub struct ClusterNode {
+    id: NodeId,
+    config: ClusterConfig,
+    state: FileState,
+    core: Mutex<RaftCore>,
+    transport: transport::ClusterTransport,
+    persister: Arc<PersisterKind>,
+    metadata_path: String,
+    /// Path of the file with the indexes of the committed entries which have been rejected when applied,
+    /// as they remain in the log, they must be skipped when the state is restored.
+    rejected_entries_path: String,
+    metadata: std::sync::Mutex<ClusterMetadata>,
+    metadata_lock: Mutex<()>,
+    pending: std::sync::Mutex<AHashMap<u64, PendingProposal>>,
+    leader_id: AtomicU32,
+    /// Index of the entry appended by this node once elected, 0 if it's not the leader.
+    leader_entry_index: AtomicU64,
+    applied_index: AtomicU64,
+    started: AtomicBool,
+    started_at: Instant,
+    committed_sender: flume::Sender<CommittedEntry>,
+    committed_receiver: flume::Receiver<CommittedEntry>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct ClusterMetadata {
+    hard_state: HardState,
+    applied_index: u64,
+}
+
+type ProposalSender = oneshot::Sender<Result<(), IggyError>>;
+
+#[derive(Debug)]
+struct PendingProposal {
+    term: u64,
+    sender: Option<ProposalSender>,
+}
+
+/// Committed entry to be applied to the in-memory state, the result is sent back to the client who proposed it.
+#[derive(Debug)]
+struct CommittedEntry {
+    index: u64,
+    entry: Option<StateEntry>,
+    sender: Option<ProposalSender>,
+}
+
+impl ClusterNode {
+    pub fn new(
+        config: ClusterConfig,
+        state: FileState,
+        persister: Arc<PersisterKind>,
+        state_path: &str,
+    ) -> Self {
+        let members = Self::initial_members(&config);
+        let core = RaftCore::new(
+            config.node_id,
+            members,
+            config.election_timeout.as_micros(),
+            config.heartbeat_interval.as_micros(),
+            0,
+        );
+        let (committed_sender, committed_receiver) = flume::unbounded();
+        Self {
+            id: config.node_id,
+            transport: transport::ClusterTransport::new(config.node_id, &config.secret),
+            config,
+            state,
+            core: Mutex::new(core),
+            persister,
+            metadata_path: format!("{state_path}/cluster"),
+            rejected_entries_path: format!("{state_path}/cluster_rejected"),
+            metadata: std::sync::Mutex::new(ClusterMetadata::default()),
+            metadata_lock: Mutex::new(()),
+            pending: std::sync::Mutex::new(AHashMap::new()),
+            leader_id: AtomicU32::new(0),
+            leader_entry_index: AtomicU64::new(0),
+            applied_index: AtomicU64::new(0),
+            started: AtomicBool::new(false),
+            started_at: Instant::now(),
+            committed_sender,
+            committed_receiver,
+        }
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn leader_id(&self) -> NodeId {
+        self.leader_id.load(Ordering::SeqCst)
+    }
+
+    /// Returns an error if this node can't accept the commands changing the state,
+    /// i.e. it's not the leader or it hasn't applied all the entries committed by the previous leaders yet.
+    pub fn ensure_leader(&self) -> Result<(), IggyError> {
+        if !self.started.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let leader_id = self.leader_id();
+        if leader_id != self.id {
+            return Err(IggyError::NotClusterLeader(leader_id));
+        }
+
+        let leader_entry_index = self.leader_entry_index.load(Ordering::SeqCst);
+        if leader_entry_index == 0 || self.applied_index.load(Ordering::SeqCst) < leader_entry_index
+        {
+            return Err(IggyError::NotClusterLeader(0));
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_info(&self) -> ClusterInfo {
+        let core = self.core.lock().await;
+        ClusterInfo {
+            node_id: self.id,
+            role: core.role().to_string(),
+            term: core.term(),
+            leader_id: core.leader_id(),
+            commit_index: core.commit_index(),
+            applied_index: self.applied_index.load(Ordering::SeqCst),
+            members: core
+                .members()
+                .iter()
+                .map(|(id, address)| ClusterMember {
+                    id: *id,
+                    address: address.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Starts the communication with the other nodes and the replication of the state log.
+    /// Must be invoked once the system has been initialized. Returns the address the node is listening on.
+    pub async fn start(self: &Arc<Self>, system: SharedSystem) -> Result<SocketAddr, IggyError> {
+        let entries = self
+            .state
+            .load_entries()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load state entries")
+            })?;
+        let mut members = Self::initial_members(&self.config);
+        let mut log = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let entry_members = match entry.command()? {
+                EntryCommand::AddClusterNode(command) => {
+                    members.insert(command.node_id, command.address);
+                    Some(members.clone())
+                }
+                EntryCommand::RemoveClusterNode(command) => {
+                    members.remove(&command.node_id);
+                    Some(members.clone())
+                }
+                _ => None,
+            };
+            log.push(RaftEntry {
+                term: entry.term,
+                members: entry_members,
+                payload: self.state.to_persisted_entry(&entry)?.to_bytes(),
+            });
+        }
+
+        {
+            let mut core = self.core.lock().await;
+            let metadata = *self.metadata.lock().unwrap();
+            core.restore(
+                metadata.hard_state,
+                log,
+                self.applied_index.load(Ordering::SeqCst),
+            );
+            self.update_status(&core);
+            info!(
+                "{COMPONENT} - starting node with ID: {}, term: {}, last index: {}, commit index: {}, members: {}",
+                self.id,
+                core.term(),
+                core.last_index(),
+                core.commit_index(),
+                core.members().len()
+            );
+        }
+        self.started.store(true, Ordering::SeqCst);
+
+        let (messages_sender, messages_receiver) = flume::unbounded();
+        let address = transport::listen(
+            &self.config.address,
+            self.config.secret.clone(),
+            messages_sender,
+        )
+        .await;
+
+        let node = self.clone();
+        let tick_interval = self
+            .config
+            .heartbeat_interval
+            .get_duration()
+            .checked_div(5)
+            .unwrap_or_default()
+            .max(MIN_TICK_INTERVAL);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(tick_interval);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        node.tick().await;
+                    }
+                    message = messages_receiver.recv_async() => {
+                        let Ok((from, message)) = message else {
+                            break;
+                        };
+                        node.step(from, message).await;
+                    }
+                }
+            }
+        });
+
+        let node = self.clone();
+        tokio::spawn(async move {
+            while let Ok(committed) = node.committed_receiver.recv_async().await {
+                let result = match committed.entry {
+                    Some(entry) => {
+                        let result = system.write().await.apply_replicated_entry(&entry).await;
+                        if let Err(error) = &result {
+                            // The command is validated when it's applied, so it's rejected by all the nodes.
+                            warn!("{COMPONENT} - state entry: {entry} has been rejected, error: {error}");
+                            node.save_rejected_entry(entry.index).await;
+                        }
+                        result
+                    }
+                    None => Ok(()),
+                };
+                node.set_applied_index(committed.index).await;
+                if let Some(sender) = committed.sender {
+                    let _ = sender.send(result);
+                }
+            }
+        });
+
+        Ok(address)
+    }
+
+    async fn tick(&self) {
+        let mut core = self.core.lock().await;
+        let ready = core.tick(self.now());
+        if let Err(error) = self.handle_ready(&mut core, ready).await {
+            error!("{COMPONENT} - failed to handle the tick, error: {error}");
+        }
+    }
+
+    async fn step(&self, from: NodeId, message: RaftMessage) {
+        debug!("{COMPONENT} - received message: {message} from node with ID: {from}");
+        let mut core = self.core.lock().await;
+        let ready = core.step(from, message, self.now());
+        if let Err(error) = self.handle_ready(&mut core, ready).await {
+            error!(
+                "{COMPONENT} - failed to handle message from node with ID: {from}, error: {error}"
+            );
+        }
+    }
+
+    /// Proposes the command changing the state and waits until it's committed and applied to the in-memory state
+    /// of this node, no lock of the system must be held by the caller, as the committed entries are applied in order.
+    /// The command is applied with the given permissions, so the caller must check that the user is allowed to invoke it.
+    pub async fn replicate(
+        &self,
+        user_id: u32,
+        permissions_id: u32,
+        command: EntryCommand,
+    ) -> Result<(), IggyError> {
+        self.replicate_with_context(user_id, permissions_id, Bytes::new(), command)
+            .await
+    }
+
+    /// Replicates the command like [`ClusterNode::replicate`], along with the context describing how it must be applied.
+    pub async fn replicate_with_context(
+        &self,
+        user_id: u32,
+        permissions_id: u32,
+        context: Bytes,
+        command: EntryCommand,
+    ) -> Result<(), IggyError> {
+        let receiver = {
+            let mut core = self.core.lock().await;
+            if !core.is_leader() {
+                return Err(IggyError::NotClusterLeader(
+                    core.leader_id().unwrap_or_default(),
+                ));
+            }
+
+            let members = Self::resolve_members(&core, &command)?;
+            let term = core.term();
+            let entry = self.state.create_entry(
+                core.last_index(),
+                term,
+                self.id,
+                user_id,
+                permissions_id,
+                context,
+                &command,
+            )?;
+            let (index, ready) = core.propose(
+                RaftEntry {
+                    term,
+                    members,
+                    payload: entry.to_bytes(),
+                },
+                self.now(),
+            )?;
+            let (sender, receiver) = oneshot::channel();
+            self.pending.lock().unwrap().insert(
+                index,
+                PendingProposal {
+                    term,
+                    sender: Some(sender),
+                },
+            );
+            self.handle_ready(&mut core, ready).await?;
+            receiver
+        };
+
+        match tokio::time::timeout(self.config.commit_timeout.get_duration(), receiver).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(IggyError::NotClusterLeader(self.leader_id())),
+            Err(_) => {
+                warn!(
+                    "{COMPONENT} - state entry was not committed and applied within: {}",
+                    self.config.commit_timeout
+                );
+                Err(IggyError::ClusterCommitTimeout)
+            }
+        }
+    }
+
+    async fn handle_ready(&self, core: &mut RaftCore, ready: Ready) -> Result<(), IggyError> {
+        let mut queue = VecDeque::from([ready]);
+        while let Some(ready) = queue.pop_front() {
+            if let Some(hard_state) = ready.hard_state {
+                self.metadata.lock().unwrap().hard_state = hard_state;
+                self.save_metadata().await?;
+            }
+
+            if let Some(index) = ready.truncate_from {
+                // The raft indexes start from 1, while the state entries from 0.
+                self.state.truncate(index - 1).await?;
+                self.reject_pending(|pending_index| pending_index >= index, core.leader_id());
+            }
+
+            for (_, entry) in ready.entries {
+                self.state
+                    .append_entry(&StateEntry::from_bytes(entry.payload)?)
+                    .await?;
+            }
+
+            self.update_status(core);
+            for (to, message) in ready.messages {
+                let address = core.members().get(&to).cloned().or_else(|| {
+                    self.config
+                        .nodes
+                        .iter()
+                        .find(|node| node.id == to)
+                        .map(|node| node.address.clone())
+                });
+                match address {
+                    Some(address) => self.transport.send(to, &address, &message),
+                    None => debug!("{COMPONENT} - unknown address of node with ID: {to}"),
+                }
+            }
+
+            let leader_id = core.leader_id().unwrap_or_default();
+            for (index, entry) in ready.committed {
+                self.commit(index, entry, leader_id);
+            }
+
+            if ready.stepped_down {
+                info!(
+                    "{COMPONENT} - node with ID: {} is no longer the leader, term: {}",
+                    self.id,
+                    core.term()
+                );
+                self.leader_entry_index.store(0, Ordering::SeqCst);
+                self.reject_pending(|_| true, core.leader_id());
+            }
+
+            if ready.elected {
+                info!(
+                    "{COMPONENT} - node with ID: {} has been elected as the leader, term: {}",
+                    self.id,
+                    core.term()
+                );
+                let term = core.term();
+                let entry = self.state.create_entry(
+                    core.last_index(),
+                    term,
+                    self.id,
+                    0,
+                    0,
+                    Bytes::new(),
+                    &EntryCommand::ElectClusterLeader(ElectClusterLeader { node_id: self.id }),
+                )?;
+                let (index, ready) = core.propose(
+                    RaftEntry {
+                        term,
+                        members: None,
+                        payload: entry.to_bytes(),
+                    },
+                    self.now(),
+                )?;
+                self.pending
+                    .lock()
+                    .unwrap()
+                    .insert(index, PendingProposal { term, sender: None });
+                self.leader_entry_index.store(index, Ordering::SeqCst);
+                queue.push_back(ready);
+            }
+        }
+        Ok(())
+    }
+
+    fn commit(&self, index: u64, entry: RaftEntry, leader_id: NodeId) {
+        let mut sender = None;
+        if let Some(pending) = self.pending.lock().unwrap().remove(&index) {
+            if pending.term == entry.term {
+                sender = pending.sender;
+            } else if let Some(pending_sender) = pending.sender {
+                // The proposed entry was replaced by the one from the next leader.
+                let _ = pending_sender.send(Err(IggyError::NotClusterLeader(leader_id)));
+            }
+        }
+
+        let entry = match StateEntry::from_bytes(entry.payload)
+            .and_then(|entry| self.state.from_persisted_entry(entry))
+        {
+            Ok(entry) => Some(entry),
+            Err(error) => {
+                error!("{COMPONENT} - failed to read committed state entry with index: {index}, error: {error}");
+                if let Some(sender) = sender.take() {
+                    let _ = sender.send(Err(error));
+                }
+                None
+            }
+        };
+        let _ = self.committed_sender.send(CommittedEntry {
+            index,
+            entry,
+            sender,
+        });
+    }
+
+    /// Notifies the clients waiting for the proposed entries that they might not be committed,
+    /// the entries are still tracked, as they might be committed by the next leader.
+    fn reject_pending(&self, filter: impl Fn(u64) -> bool, leader_id: Option<NodeId>) {
+        let mut pending = self.pending.lock().unwrap();
+        for (index, proposal) in pending.iter_mut() {
+            if !filter(*index) {
+                continue;
+            }
+
+            if let Some(sender) = proposal.sender.take() {
+                let _ = sender.send(Err(IggyError::NotClusterLeader(
+                    leader_id.unwrap_or_default(),
+                )));
+            }
+        }
+    }
+
+    fn update_status(&self, core: &RaftCore) {
+        let leader_id = core.leader_id().unwrap_or_default();
+        self.leader_id.store(leader_id, Ordering::SeqCst);
+        self.state.set_leader(core.term(), leader_id);
+    }
+
+    async fn set_applied_index(&self, index: u64) {
+        self.applied_index.store(index, Ordering::SeqCst);
+        self.metadata.lock().unwrap().applied_index = index;
+        if let Err(error) = self.save_metadata().await {
+            error!("{COMPONENT} - failed to save applied index: {index}, error: {error}");
+        }
+    }
+
+    async fn save_rejected_entry(&self, index: u64) {
+        if let Err(error) = self
+            .persister
+            .append(&self.rejected_entries_path, &index.to_le_bytes())
+            .await
+        {
+            error!("{COMPONENT} - failed to save rejected state entry with index: {index}, error: {error}");
+        }
+    }
+
+    async fn load_rejected_entries(&self) -> Result<AHashSet<u64>, IggyError> {
+        if !Path::new(&self.rejected_entries_path).exists() {
+            self.persister
+                .overwrite(&self.rejected_entries_path, &[])
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to create rejected state entries file, path: {}",
+                        self.rejected_entries_path
+                    )
+                })?;
+            return Ok(AHashSet::new());
+        }
+
+        let bytes = tokio::fs::read(&self.rejected_entries_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read rejected state entries, path: {}",
+                    self.rejected_entries_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        // The last index might be incomplete if the node crashed while saving it (before the entry was marked
+        // as applied), so it's discarded and the entry is applied again.
+        let length = bytes.len() - bytes.len() % 8;
+        if length < bytes.len() {
+            self.persister
+                .overwrite(&self.rejected_entries_path, &bytes[..length])
+                .await?;
+        }
+
+        Ok(bytes[..length]
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect())
+    }
+
+    async fn load_metadata(&self) -> Result<(), IggyError> {
+        if !Path::new(&self.metadata_path).exists() {
+            return Ok(());
+        }
+
+        let bytes = tokio::fs::read(&self.metadata_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read cluster metadata, path: {}",
+                    self.metadata_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        if bytes.len() != METADATA_LENGTH {
+            return Err(IggyError::StateFileCorrupted);
+        }
+
+        let mut bytes = Bytes::from(bytes);
+        let term = bytes.get_u64_le();
+        let voted_for = bytes.get_u32_le();
+        let applied_index = bytes.get_u64_le();
+        *self.metadata.lock().unwrap() = ClusterMetadata {
+            hard_state: HardState {
+                term,
+                voted_for: (voted_for > 0).then_some(voted_for),
+            },
+            applied_index,
+        };
+        Ok(())
+    }
+
+    async fn save_metadata(&self) -> Result<(), IggyError> {
+        let _guard = self.metadata_lock.lock().await;
+        let metadata = *self.metadata.lock().unwrap();
+        let mut bytes = BytesMut::with_capacity(METADATA_LENGTH);
+        bytes.put_u64_le(metadata.hard_state.term);
+        bytes.put_u32_le(metadata.hard_state.voted_for.unwrap_or_default());
+        bytes.put_u64_le(metadata.applied_index);
+        self.persister
+            .overwrite(&self.metadata_path, &bytes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save cluster metadata, path: {}",
+                    self.metadata_path
+                )
+            })
+    }
+
+    fn resolve_members(
+        core: &RaftCore,
+        command: &EntryCommand,
+    ) -> Result<Option<Members>, IggyError> {
+        let mut members = core.members().clone();
+        match command {
+            EntryCommand::AddClusterNode(command) => {
+                if members.contains_key(&command.node_id) {
+                    return Err(IggyError::ClusterNodeAlreadyExists(command.node_id));
+                }
+
+                members.insert(command.node_id, command.address.clone());
+                Ok(Some(members))
+            }
+            EntryCommand::RemoveClusterNode(command) => {
+                if members.remove(&command.node_id).is_none() {
+                    return Err(IggyError::ClusterNodeNotFound(command.node_id));
+                }
+
+                if members.is_empty() {
+                    return Err(IggyError::InvalidCommand);
+                }
+
+                Ok(Some(members))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn initial_members(config: &ClusterConfig) -> Members {
+        config
+            .nodes
+            .iter()
+            .map(|node| (node.id, node.address.clone()))
+            .collect()
+    }
+
+    fn now(&self) -> u64 {
+        self.started_at.elapsed().as_micros() as u64
+    }
+}
+
+impl State for ClusterNode {
+    /// Returns only the entries which have been already applied (except the rejected ones),
+    /// the remaining ones are applied once committed.
+    async fn init(&self) -> Result<Vec<StateEntry>, IggyError> {
+        let mut entries = self.state.init().await?;
+        self.load_metadata().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to load cluster metadata")
+        })?;
+        // The entries appended before the cluster was started (with term 0) are always applied.
+        let bootstrap_entries = entries.iter().take_while(|entry| entry.term == 0).count() as u64;
+        let applied_index = self
+            .metadata
+            .lock()
+            .unwrap()
+            .applied_index
+            .max(bootstrap_entries);
+        self.applied_index.store(applied_index, Ordering::SeqCst);
+        let rejected_entries = self.load_rejected_entries().await?;
+        entries.retain(|entry| {
+            entry.index < applied_index && !rejected_entries.contains(&entry.index)
+        });
+        Ok(entries)
+    }
+
+    async fn load_entries(&self) -> Result<Vec<StateEntry>, IggyError> {
+        self.state.load_entries().await
+    }
+
+    async fn load_snapshot(&self) -> Result<Option<StateSnapshot>, IggyError> {
+        self.state.load_snapshot().await
+    }
+
+    async fn apply(&self, user_id: u32, command: EntryCommand) -> Result<(), IggyError> {
+        if !self.started.load(Ordering::SeqCst) {
+            self.state.apply(user_id, command).await?;
+            self.applied_index
+                .store(self.state.current_index() + 1, Ordering::SeqCst);
+            return Ok(());
+        }
+
+        self.replicate(user_id, user_id, command).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::persistence::persister::FileWithSyncPersister;
+    use crate::versioning::SemanticVersion;
+    use std::str::FromStr;
+
+    fn create_node(path: &str) -> ClusterNode {
+        let persister = Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {}));
+        let version = SemanticVersion::from_str("1.2.3").unwrap();
+        let state = FileState::new(&format!("{path}/log"), &version, persister.clone(), None);
+        ClusterNode::new(ClusterConfig::default(), state, persister, path)
+    }
+
+    #[tokio::test]
+    async fn init_should_skip_rejected_entries() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().to_str().unwrap();
+        let node = create_node(path);
+        node.init().await.unwrap();
+        for node_id in 1..=3 {
+            node.apply(
+                0,
+                EntryCommand::ElectClusterLeader(ElectClusterLeader { node_id }),
+            )
+            .await
+            .unwrap();
+        }
+        node.save_rejected_entry(1).await;
+        // The index saved partially before the crash must be discarded.
+        node.persister
+            .append(&node.rejected_entries_path, &[2, 0, 0])
+            .await
+            .unwrap();
+
+        let node = create_node(path);
+        let entries = node.init().await.unwrap();
+
+        let indexes = entries.iter().map(|entry| entry.index).collect::<Vec<_>>();
+        assert_eq!(indexes, vec![0, 2]);
+        let rejected_entries = tokio::fs::read(&node.rejected_entries_path).await.unwrap();
+        assert_eq!(rejected_entries, 1u64.to_le_bytes());
+    }
+}
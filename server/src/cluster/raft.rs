@@ -0,0 +1,1008 @@
+use crate::cluster::messages::RaftMessage;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+pub type NodeId = u32;
+
+/// Voting members of the cluster with their addresses.
+pub type Members = BTreeMap<NodeId, String>;
+
+/// Maximum number of entries sent to the follower in a single message.
+const MAX_ENTRIES_PER_MESSAGE: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Follower,
+    Candidate,
+    Leader,
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Role::Follower => write!(f, "follower"),
+            Role::Candidate => write!(f, "candidate"),
+            Role::Leader => write!(f, "leader"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RaftEntry {
+    pub term: u64,
+    /// Members of the cluster starting from this entry, set only for the membership changes.
+    pub members: Option<Members>,
+    /// State entry in its persisted form.
+    pub payload: Bytes,
+}
+
+/// Part of the node state which must be persisted before sending any message.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HardState {
+    pub term: u64,
+    pub voted_for: Option<NodeId>,
+}
+
+/// Outcome of the state transition, to be handled by the caller in the following order:
+/// persist the hard state, truncate and append the log entries, send the messages and apply the committed entries.
+#[derive(Debug, Default)]
+pub struct Ready {
+    pub hard_state: Option<HardState>,
+    /// Index of the first entry to be removed from the log, before appending the new entries.
+    pub truncate_from: Option<u64>,
+    pub entries: Vec<(u64, RaftEntry)>,
+    pub messages: Vec<(NodeId, RaftMessage)>,
+    pub committed: Vec<(u64, RaftEntry)>,
+    pub elected: bool,
+    pub stepped_down: bool,
+}
+
+/// Deterministic implementation of the Raft consensus algorithm, without any I/O.
+/// The indexes start from 1, the time is expressed in microseconds and provided by the caller.
+/// The membership changes are applied one node at a time, as soon as the entry is appended to the log,
+/// and only a single uncommitted membership change is allowed.
+#[derive(Debug)]
+pub struct RaftCore {
+    id: NodeId,
+    role: Role,
+    term: u64,
+    voted_for: Option<NodeId>,
+    leader_id: Option<NodeId>,
+    log: Vec<RaftEntry>,
+    commit_index: u64,
+    initial_members: Members,
+    members: Members,
+    votes: BTreeSet<NodeId>,
+    next_index: BTreeMap<NodeId, u64>,
+    match_index: BTreeMap<NodeId, u64>,
+    election_timeout: u64,
+    heartbeat_interval: u64,
+    election_deadline: u64,
+    heartbeat_deadline: u64,
+    last_leader_contact: Option<u64>,
+    random_state: u64,
+}
+
+impl RaftCore {
+    pub fn new(
+        id: NodeId,
+        members: Members,
+        election_timeout: u64,
+        heartbeat_interval: u64,
+        now: u64,
+    ) -> Self {
+        let mut core = Self {
+            id,
+            role: Role::Follower,
+            term: 0,
+            voted_for: None,
+            leader_id: None,
+            log: Vec::new(),
+            commit_index: 0,
+            initial_members: members.clone(),
+            members,
+            votes: BTreeSet::new(),
+            next_index: BTreeMap::new(),
+            match_index: BTreeMap::new(),
+            election_timeout,
+            heartbeat_interval,
+            election_deadline: 0,
+            heartbeat_deadline: 0,
+            last_leader_contact: None,
+            random_state: (id as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ now,
+        };
+        core.reset_election_deadline(now);
+        core
+    }
+
+    /// Restores the state loaded from the disk, the entries up to the commit index must have been already applied.
+    pub fn restore(&mut self, hard_state: HardState, log: Vec<RaftEntry>, commit_index: u64) {
+        self.term = hard_state.term;
+        self.voted_for = hard_state.voted_for;
+        self.log = log;
+        self.commit_index = commit_index.min(self.last_index());
+        self.update_members();
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    pub fn term(&self) -> u64 {
+        self.term
+    }
+
+    pub fn leader_id(&self) -> Option<NodeId> {
+        self.leader_id
+    }
+
+    pub fn is_leader(&self) -> bool {
+        self.role == Role::Leader
+    }
+
+    pub fn commit_index(&self) -> u64 {
+        self.commit_index
+    }
+
+    pub fn last_index(&self) -> u64 {
+        self.log.len() as u64
+    }
+
+    pub fn members(&self) -> &Members {
+        &self.members
+    }
+
+    pub fn hard_state(&self) -> HardState {
+        HardState {
+            term: self.term,
+            voted_for: self.voted_for,
+        }
+    }
+
+    /// Returns `true` if there's a membership change which hasn't been committed yet.
+    pub fn has_pending_membership_change(&self) -> bool {
+        self.log[self.commit_index as usize..]
+            .iter()
+            .any(|entry| entry.members.is_some())
+    }
+
+    pub fn tick(&mut self, now: u64) -> Ready {
+        let hard_state = self.hard_state();
+        let mut ready = Ready::default();
+        if self.role == Role::Leader {
+            if now >= self.heartbeat_deadline {
+                self.broadcast_append_entries(&mut ready);
+                self.heartbeat_deadline = now + self.heartbeat_interval;
+            }
+        } else if now >= self.election_deadline {
+            if self.members.contains_key(&self.id) {
+                self.start_election(now, &mut ready);
+            } else {
+                self.reset_election_deadline(now);
+            }
+        }
+        self.finish(hard_state, ready)
+    }
+
+    /// Appends the entry to the log of the leader, returns its index.
+    pub fn propose(&mut self, entry: RaftEntry, now: u64) -> Result<(u64, Ready), IggyError> {
+        if self.role != Role::Leader {
+            return Err(IggyError::NotClusterLeader(
+                self.leader_id.unwrap_or_default(),
+            ));
+        }
+
+        if entry.members.is_some() && self.has_pending_membership_change() {
+            return Err(IggyError::ClusterMembershipChangeInProgress);
+        }
+
+        let hard_state = self.hard_state();
+        let mut ready = Ready::default();
+        let is_membership_change = entry.members.is_some();
+        self.log.push(RaftEntry {
+            term: self.term,
+            ..entry
+        });
+        let index = self.last_index();
+        ready
+            .entries
+            .push((index, self.log[index as usize - 1].clone()));
+        if is_membership_change {
+            self.update_members();
+            self.update_peers();
+        }
+
+        self.advance_commit_index(&mut ready);
+        self.broadcast_append_entries(&mut ready);
+        self.heartbeat_deadline = now + self.heartbeat_interval;
+        Ok((index, self.finish(hard_state, ready)))
+    }
+
+    pub fn step(&mut self, from: NodeId, message: RaftMessage, now: u64) -> Ready {
+        let hard_state = self.hard_state();
+        let mut ready = Ready::default();
+        match message {
+            RaftMessage::RequestVote {
+                term,
+                last_log_index,
+                last_log_term,
+            } => {
+                self.handle_request_vote(from, term, last_log_index, last_log_term, now, &mut ready)
+            }
+            RaftMessage::RequestVoteResponse { term, vote_granted } => {
+                self.handle_request_vote_response(from, term, vote_granted, now, &mut ready)
+            }
+            RaftMessage::AppendEntries {
+                term,
+                prev_log_index,
+                prev_log_term,
+                entries,
+                leader_commit,
+            } => self.handle_append_entries(
+                from,
+                term,
+                prev_log_index,
+                prev_log_term,
+                entries,
+                leader_commit,
+                now,
+                &mut ready,
+            ),
+            RaftMessage::AppendEntriesResponse {
+                term,
+                success,
+                last_index,
+            } => self
+                .handle_append_entries_response(from, term, success, last_index, now, &mut ready),
+        }
+        self.finish(hard_state, ready)
+    }
+
+    fn handle_request_vote(
+        &mut self,
+        from: NodeId,
+        term: u64,
+        last_log_index: u64,
+        last_log_term: u64,
+        now: u64,
+        ready: &mut Ready,
+    ) {
+        // Ignore the candidates (e.g. the removed or partitioned nodes) while the current leader is alive.
+        if term > self.term && self.is_leader_alive(now) {
+            ready.messages.push((
+                from,
+                RaftMessage::RequestVoteResponse {
+                    term: self.term,
+                    vote_granted: false,
+                },
+            ));
+            return;
+        }
+
+        if term > self.term {
+            self.become_follower(term, None, now, ready);
+        }
+
+        let is_log_up_to_date = last_log_term > self.last_term()
+            || (last_log_term == self.last_term() && last_log_index >= self.last_index());
+        let vote_granted = term == self.term
+            && self.voted_for.is_none_or(|voted_for| voted_for == from)
+            && is_log_up_to_date;
+        if vote_granted {
+            self.voted_for = Some(from);
+            self.reset_election_deadline(now);
+        }
+
+        ready.messages.push((
+            from,
+            RaftMessage::RequestVoteResponse {
+                term: self.term,
+                vote_granted,
+            },
+        ));
+    }
+
+    fn handle_request_vote_response(
+        &mut self,
+        from: NodeId,
+        term: u64,
+        vote_granted: bool,
+        now: u64,
+        ready: &mut Ready,
+    ) {
+        if term > self.term {
+            self.become_follower(term, None, now, ready);
+            return;
+        }
+
+        if self.role != Role::Candidate || term != self.term || !vote_granted {
+            return;
+        }
+
+        self.votes.insert(from);
+        if self.has_quorum(|id| self.votes.contains(&id)) {
+            self.become_leader(now, ready);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn handle_append_entries(
+        &mut self,
+        from: NodeId,
+        term: u64,
+        prev_log_index: u64,
+        prev_log_term: u64,
+        entries: Vec<RaftEntry>,
+        leader_commit: u64,
+        now: u64,
+        ready: &mut Ready,
+    ) {
+        if term < self.term {
+            self.respond_to_append_entries(from, false, self.last_index(), ready);
+            return;
+        }
+
+        if term > self.term || self.role != Role::Follower {
+            self.become_follower(term, Some(from), now, ready);
+        }
+        self.leader_id = Some(from);
+        self.last_leader_contact = Some(now);
+        self.reset_election_deadline(now);
+
+        if prev_log_index > self.last_index() {
+            self.respond_to_append_entries(from, false, self.last_index(), ready);
+            return;
+        }
+
+        if self.term_at(prev_log_index) != Some(prev_log_term) {
+            self.respond_to_append_entries(from, false, prev_log_index - 1, ready);
+            return;
+        }
+
+        let mut index = prev_log_index;
+        let mut members_changed = false;
+        for entry in entries {
+            index += 1;
+            match self.term_at(index) {
+                Some(term) if term == entry.term => continue,
+                Some(_) => {
+                    // The committed entries can never be overwritten.
+                    debug_assert!(index > self.commit_index);
+                    members_changed |= self.log[index as usize - 1..]
+                        .iter()
+                        .any(|entry| entry.members.is_some());
+                    self.log.truncate(index as usize - 1);
+                    ready.truncate_from.get_or_insert(index);
+                }
+                None => {}
+            }
+
+            members_changed |= entry.members.is_some();
+            ready.entries.push((index, entry.clone()));
+            self.log.push(entry);
+        }
+
+        if members_changed {
+            self.update_members();
+        }
+
+        let commit_index = leader_commit.min(index);
+        if commit_index > self.commit_index {
+            self.commit_to(commit_index, ready);
+        }
+
+        self.respond_to_append_entries(from, true, index, ready);
+    }
+
+    fn handle_append_entries_response(
+        &mut self,
+        from: NodeId,
+        term: u64,
+        success: bool,
+        last_index: u64,
+        now: u64,
+        ready: &mut Ready,
+    ) {
+        if term > self.term {
+            self.become_follower(term, None, now, ready);
+            return;
+        }
+
+        if self.role != Role::Leader || term != self.term || !self.next_index.contains_key(&from) {
+            return;
+        }
+
+        if success {
+            let match_index = self.match_index.entry(from).or_default();
+            *match_index = (*match_index).max(last_index);
+            let next_index = *match_index + 1;
+            self.next_index.insert(from, next_index);
+            self.advance_commit_index(ready);
+            if next_index <= self.last_index() && self.role == Role::Leader {
+                ready.messages.push((from, self.append_entries_for(from)));
+            }
+            return;
+        }
+
+        let next_index = self.next_index.entry(from).or_insert(1);
+        *next_index = (*next_index - 1).min(last_index + 1).max(1);
+        ready.messages.push((from, self.append_entries_for(from)));
+    }
+
+    fn respond_to_append_entries(
+        &self,
+        to: NodeId,
+        success: bool,
+        last_index: u64,
+        ready: &mut Ready,
+    ) {
+        ready.messages.push((
+            to,
+            RaftMessage::AppendEntriesResponse {
+                term: self.term,
+                success,
+                last_index,
+            },
+        ));
+    }
+
+    fn start_election(&mut self, now: u64, ready: &mut Ready) {
+        self.term += 1;
+        self.role = Role::Candidate;
+        self.voted_for = Some(self.id);
+        self.leader_id = None;
+        self.votes = BTreeSet::from([self.id]);
+        self.reset_election_deadline(now);
+        if self.has_quorum(|id| self.votes.contains(&id)) {
+            self.become_leader(now, ready);
+            return;
+        }
+
+        for peer in self.peers() {
+            ready.messages.push((
+                peer,
+                RaftMessage::RequestVote {
+                    term: self.term,
+                    last_log_index: self.last_index(),
+                    last_log_term: self.last_term(),
+                },
+            ));
+        }
+    }
+
+    fn become_leader(&mut self, now: u64, ready: &mut Ready) {
+        self.role = Role::Leader;
+        self.leader_id = Some(self.id);
+        self.votes.clear();
+        self.next_index.clear();
+        self.match_index.clear();
+        self.update_peers();
+        ready.elected = true;
+        self.broadcast_append_entries(ready);
+        self.heartbeat_deadline = now + self.heartbeat_interval;
+    }
+
+    fn become_follower(
+        &mut self,
+        term: u64,
+        leader_id: Option<NodeId>,
+        now: u64,
+        ready: &mut Ready,
+    ) {
+        if term > self.term {
+            self.term = term;
+            self.voted_for = None;
+        }
+        if self.role == Role::Leader {
+            ready.stepped_down = true;
+        }
+
+        self.role = Role::Follower;
+        self.leader_id = leader_id;
+        self.votes.clear();
+        self.reset_election_deadline(now);
+    }
+
+    fn broadcast_append_entries(&self, ready: &mut Ready) {
+        for peer in self.peers() {
+            ready.messages.push((peer, self.append_entries_for(peer)));
+        }
+    }
+
+    fn append_entries_for(&self, peer: NodeId) -> RaftMessage {
+        let next_index = self
+            .next_index
+            .get(&peer)
+            .copied()
+            .unwrap_or(self.last_index() + 1);
+        let prev_log_index = next_index - 1;
+        let last_index = (prev_log_index as usize + MAX_ENTRIES_PER_MESSAGE).min(self.log.len());
+        RaftMessage::AppendEntries {
+            term: self.term,
+            prev_log_index,
+            prev_log_term: self.term_at(prev_log_index).unwrap_or_default(),
+            entries: self.log[prev_log_index as usize..last_index].to_vec(),
+            leader_commit: self.commit_index,
+        }
+    }
+
+    /// Commits the latest entry from the current term which is stored by the majority of the members.
+    fn advance_commit_index(&mut self, ready: &mut Ready) {
+        if self.role != Role::Leader {
+            return;
+        }
+
+        for index in (self.commit_index + 1..=self.last_index()).rev() {
+            if self.term_at(index) != Some(self.term) {
+                break;
+            }
+
+            if self.has_quorum(|id| self.match_index_of(id) >= index) {
+                self.commit_to(index, ready);
+                break;
+            }
+        }
+    }
+
+    fn commit_to(&mut self, index: u64, ready: &mut Ready) {
+        for committed_index in self.commit_index + 1..=index {
+            ready.committed.push((
+                committed_index,
+                self.log[committed_index as usize - 1].clone(),
+            ));
+        }
+        self.commit_index = index;
+
+        // The leader removed from the cluster steps down once the change is committed.
+        if self.role == Role::Leader
+            && !self.members.contains_key(&self.id)
+            && !self.has_pending_membership_change()
+        {
+            self.role = Role::Follower;
+            self.leader_id = None;
+            ready.stepped_down = true;
+        }
+    }
+
+    fn has_quorum(&self, is_acknowledged: impl Fn(NodeId) -> bool) -> bool {
+        let acknowledged = self
+            .members
+            .keys()
+            .filter(|id| is_acknowledged(**id))
+            .count();
+        acknowledged > self.members.len() / 2
+    }
+
+    fn match_index_of(&self, id: NodeId) -> u64 {
+        if id == self.id {
+            return self.last_index();
+        }
+
+        self.match_index.get(&id).copied().unwrap_or_default()
+    }
+
+    fn is_leader_alive(&self, now: u64) -> bool {
+        match self.role {
+            Role::Leader => true,
+            Role::Candidate => false,
+            Role::Follower => {
+                self.leader_id.is_some()
+                    && self
+                        .last_leader_contact
+                        .is_some_and(|contact| now.saturating_sub(contact) < self.election_timeout)
+            }
+        }
+    }
+
+    fn peers(&self) -> Vec<NodeId> {
+        self.members
+            .keys()
+            .filter(|id| **id != self.id)
+            .copied()
+            .collect()
+    }
+
+    fn update_members(&mut self) {
+        self.members = self
+            .log
+            .iter()
+            .rev()
+            .find_map(|entry| entry.members.clone())
+            .unwrap_or_else(|| self.initial_members.clone());
+    }
+
+    fn update_peers(&mut self) {
+        let next_index = self.last_index() + 1;
+        for peer in self.peers() {
+            self.next_index.entry(peer).or_insert(next_index);
+            self.match_index.entry(peer).or_default();
+        }
+        self.next_index
+            .retain(|id, _| self.members.contains_key(id));
+        self.match_index
+            .retain(|id, _| self.members.contains_key(id));
+    }
+
+    fn term_at(&self, index: u64) -> Option<u64> {
+        if index == 0 {
+            return Some(0);
+        }
+
+        self.log.get(index as usize - 1).map(|entry| entry.term)
+    }
+
+    fn last_term(&self) -> u64 {
+        self.term_at(self.last_index()).unwrap_or_default()
+    }
+
+    fn reset_election_deadline(&mut self, now: u64) {
+        // xorshift is good enough to spread the elections of the nodes over time.
+        self.random_state ^= self.random_state << 13;
+        self.random_state ^= self.random_state >> 7;
+        self.random_state ^= self.random_state << 17;
+        let jitter = self.random_state % self.election_timeout.max(1);
+        self.election_deadline = now + self.election_timeout + jitter;
+    }
+
+    fn finish(&self, hard_state: HardState, mut ready: Ready) -> Ready {
+        if self.hard_state() != hard_state {
+            ready.hard_state = Some(self.hard_state());
+        }
+        ready
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    const ELECTION_TIMEOUT: u64 = 300_000;
+    const HEARTBEAT_INTERVAL: u64 = 50_000;
+
+    struct Cluster {
+        nodes: BTreeMap<NodeId, RaftCore>,
+        messages: VecDeque<(NodeId, NodeId, RaftMessage)>,
+        committed: BTreeMap<NodeId, Vec<(u64, RaftEntry)>>,
+        isolated: BTreeSet<NodeId>,
+        now: u64,
+    }
+
+    impl Cluster {
+        fn new(ids: &[NodeId]) -> Self {
+            let members = ids
+                .iter()
+                .map(|id| (*id, format!("127.0.0.1:{}", 8070 + id)))
+                .collect::<Members>();
+            Self {
+                nodes: ids
+                    .iter()
+                    .map(|id| {
+                        (
+                            *id,
+                            RaftCore::new(
+                                *id,
+                                members.clone(),
+                                ELECTION_TIMEOUT,
+                                HEARTBEAT_INTERVAL,
+                                0,
+                            ),
+                        )
+                    })
+                    .collect(),
+                messages: VecDeque::new(),
+                committed: BTreeMap::new(),
+                isolated: BTreeSet::new(),
+                now: 0,
+            }
+        }
+
+        fn handle(&mut self, id: NodeId, ready: Ready) {
+            for (to, message) in ready.messages {
+                self.messages.push_back((id, to, message));
+            }
+            self.committed
+                .entry(id)
+                .or_default()
+                .extend(ready.committed);
+        }
+
+        fn deliver(&mut self) {
+            while let Some((from, to, message)) = self.messages.pop_front() {
+                if self.isolated.contains(&from) || self.isolated.contains(&to) {
+                    continue;
+                }
+                let Some(node) = self.nodes.get_mut(&to) else {
+                    continue;
+                };
+                let ready = node.step(from, message, self.now);
+                self.handle(to, ready);
+            }
+        }
+
+        fn advance(&mut self, micros: u64) {
+            let target = self.now + micros;
+            while self.now < target {
+                self.now += HEARTBEAT_INTERVAL / 5;
+                let ids = self.nodes.keys().copied().collect::<Vec<_>>();
+                for id in ids {
+                    let ready = self.nodes.get_mut(&id).unwrap().tick(self.now);
+                    self.handle(id, ready);
+                }
+                self.deliver();
+            }
+        }
+
+        /// Advances the time until there's a single leader known by all the other nodes.
+        fn elect_leader(&mut self) -> NodeId {
+            for _ in 0..20 {
+                self.advance(ELECTION_TIMEOUT);
+                let leaders = self.leaders();
+                if leaders.len() == 1
+                    && self
+                        .nodes
+                        .values()
+                        .filter(|node| !self.isolated.contains(&node.id()))
+                        .all(|node| node.leader_id() == Some(leaders[0]))
+                {
+                    return leaders[0];
+                }
+            }
+            panic!("Leader should be elected");
+        }
+
+        fn leaders(&self) -> Vec<NodeId> {
+            self.nodes
+                .values()
+                .filter(|node| node.is_leader() && !self.isolated.contains(&node.id()))
+                .map(|node| node.id())
+                .collect()
+        }
+
+        fn propose(&mut self, id: NodeId, payload: &'static [u8]) -> Result<u64, IggyError> {
+            let now = self.now;
+            let (index, ready) = self.nodes.get_mut(&id).unwrap().propose(
+                RaftEntry {
+                    term: 0,
+                    members: None,
+                    payload: Bytes::from_static(payload),
+                },
+                now,
+            )?;
+            self.handle(id, ready);
+            self.deliver();
+            Ok(index)
+        }
+
+        fn committed_payloads(&self, id: NodeId) -> Vec<Bytes> {
+            self.committed
+                .get(&id)
+                .map(|entries| {
+                    entries
+                        .iter()
+                        .map(|(_, entry)| entry.payload.clone())
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+    }
+
+    #[test]
+    fn single_node_should_become_leader_and_commit_immediately() {
+        let mut cluster = Cluster::new(&[1]);
+        cluster.advance(2 * ELECTION_TIMEOUT);
+        assert_eq!(cluster.leaders(), vec![1]);
+
+        let index = cluster.propose(1, b"entry").unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(cluster.nodes[&1].commit_index(), 1);
+        assert_eq!(cluster.committed_payloads(1), vec![Bytes::from("entry")]);
+    }
+
+    #[test]
+    fn cluster_should_elect_single_leader_and_replicate_entries() {
+        let mut cluster = Cluster::new(&[1, 2, 3]);
+        let leader = cluster.elect_leader();
+        let term = cluster.nodes[&leader].term();
+        for node in cluster.nodes.values() {
+            assert_eq!(node.term(), term);
+            assert_eq!(node.leader_id(), Some(leader));
+        }
+
+        cluster.propose(leader, b"first").unwrap();
+        cluster.propose(leader, b"second").unwrap();
+        cluster.advance(HEARTBEAT_INTERVAL * 2);
+        for id in [1, 2, 3] {
+            assert_eq!(
+                cluster.committed_payloads(id),
+                vec![Bytes::from("first"), Bytes::from("second")]
+            );
+        }
+    }
+
+    #[test]
+    fn follower_should_reject_proposals() {
+        let mut cluster = Cluster::new(&[1, 2, 3]);
+        let leader = cluster.elect_leader();
+        let follower = [1, 2, 3].into_iter().find(|id| *id != leader).unwrap();
+
+        assert_eq!(
+            cluster.propose(follower, b"entry"),
+            Err(IggyError::NotClusterLeader(leader))
+        );
+    }
+
+    #[test]
+    fn new_leader_should_be_elected_after_leader_failure_and_overwrite_uncommitted_entries() {
+        let mut cluster = Cluster::new(&[1, 2, 3]);
+        let old_leader = cluster.elect_leader();
+        cluster.propose(old_leader, b"committed").unwrap();
+        cluster.advance(HEARTBEAT_INTERVAL);
+
+        cluster.isolated.insert(old_leader);
+        cluster.propose(old_leader, b"lost").unwrap();
+        let new_leader = cluster.elect_leader();
+        assert_ne!(new_leader, old_leader);
+        assert!(cluster.nodes[&new_leader].term() > cluster.nodes[&old_leader].term());
+
+        cluster.propose(new_leader, b"replacement").unwrap();
+        cluster.isolated.clear();
+        cluster.advance(3 * HEARTBEAT_INTERVAL);
+        assert!(!cluster.nodes[&old_leader].is_leader());
+        for id in [1, 2, 3] {
+            assert_eq!(
+                cluster.committed_payloads(id),
+                vec![Bytes::from("committed"), Bytes::from("replacement")]
+            );
+        }
+    }
+
+    #[test]
+    fn vote_should_not_be_granted_to_candidate_with_stale_log() {
+        let members = Members::from([(1, "a".to_string()), (2, "b".to_string())]);
+        let mut node = RaftCore::new(1, members, ELECTION_TIMEOUT, HEARTBEAT_INTERVAL, 0);
+        node.restore(
+            HardState {
+                term: 2,
+                voted_for: None,
+            },
+            vec![RaftEntry {
+                term: 2,
+                members: None,
+                payload: Bytes::new(),
+            }],
+            0,
+        );
+
+        let ready = node.step(
+            2,
+            RaftMessage::RequestVote {
+                term: 3,
+                last_log_index: 5,
+                last_log_term: 1,
+            },
+            0,
+        );
+        assert_eq!(
+            ready.messages,
+            vec![(
+                2,
+                RaftMessage::RequestVoteResponse {
+                    term: 3,
+                    vote_granted: false
+                }
+            )]
+        );
+        assert_eq!(
+            ready.hard_state,
+            Some(HardState {
+                term: 3,
+                voted_for: None
+            })
+        );
+    }
+
+    #[test]
+    fn vote_should_not_be_granted_while_leader_is_alive() {
+        let mut cluster = Cluster::new(&[1, 2, 3]);
+        let leader = cluster.elect_leader();
+        let follower = [1, 2, 3].into_iter().find(|id| *id != leader).unwrap();
+        let term = cluster.nodes[&follower].term();
+        let now = cluster.now;
+
+        let ready = cluster.nodes.get_mut(&follower).unwrap().step(
+            4,
+            RaftMessage::RequestVote {
+                term: term + 10,
+                last_log_index: 100,
+                last_log_term: term + 10,
+            },
+            now,
+        );
+        assert_eq!(cluster.nodes[&follower].term(), term);
+        assert_eq!(
+            ready.messages,
+            vec![(
+                4,
+                RaftMessage::RequestVoteResponse {
+                    term,
+                    vote_granted: false
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn only_single_membership_change_should_be_in_progress() {
+        let mut cluster = Cluster::new(&[1, 2, 3]);
+        let leader = cluster.elect_leader();
+        let mut members = cluster.nodes[&leader].members().clone();
+        members.insert(4, "127.0.0.1:8074".to_string());
+        cluster
+            .isolated
+            .extend([1, 2, 3].into_iter().filter(|id| *id != leader));
+
+        let now = cluster.now;
+        let node = cluster.nodes.get_mut(&leader).unwrap();
+        let entry = RaftEntry {
+            term: 0,
+            members: Some(members),
+            payload: Bytes::new(),
+        };
+        node.propose(entry.clone(), now).unwrap();
+        assert_eq!(node.members().len(), 4);
+        assert_eq!(
+            node.propose(entry, now).map(|(index, _)| index),
+            Err(IggyError::ClusterMembershipChangeInProgress)
+        );
+    }
+
+    #[test]
+    fn added_node_should_receive_the_whole_log() {
+        let mut cluster = Cluster::new(&[1, 2, 3]);
+        let leader = cluster.elect_leader();
+        cluster.propose(leader, b"before").unwrap();
+
+        let mut members = cluster.nodes[&leader].members().clone();
+        members.insert(4, "127.0.0.1:8074".to_string());
+        cluster.nodes.insert(
+            4,
+            RaftCore::new(
+                4,
+                Members::new(),
+                ELECTION_TIMEOUT,
+                HEARTBEAT_INTERVAL,
+                cluster.now,
+            ),
+        );
+        let now = cluster.now;
+        let (_, ready) = cluster
+            .nodes
+            .get_mut(&leader)
+            .unwrap()
+            .propose(
+                RaftEntry {
+                    term: 0,
+                    members: Some(members),
+                    payload: Bytes::from_static(b"add"),
+                },
+                now,
+            )
+            .unwrap();
+        cluster.handle(leader, ready);
+        cluster.advance(3 * HEARTBEAT_INTERVAL);
+
+        assert_eq!(cluster.nodes[&4].members().len(), 4);
+        assert_eq!(
+            cluster.committed_payloads(4),
+            vec![Bytes::from("before"), Bytes::from("add")]
+        );
+        assert!(!cluster.nodes[&leader].has_pending_membership_change());
+    }
+}
@@ -0,0 +1,214 @@
+use crate::cluster::messages::{Handshake, RaftMessage};
+use crate::cluster::raft::NodeId;
+use crate::cluster::COMPONENT;
+use bytes::Bytes;
+use dashmap::DashMap;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
+
+/// Maximum size of a single frame, large enough for the batch of the biggest state entries.
+const MAX_FRAME_SIZE: usize = 64 * 1024 * 1024;
+/// Maximum number of messages queued for a single node, the newer ones are dropped once it's reached.
+const MAX_QUEUED_MESSAGES: usize = 1024;
+const RECONNECT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Sends the messages to the other nodes over the TCP connections, each prefixed with its length.
+/// The messages might be lost, e.g. when the node is unavailable, which is tolerated by the consensus algorithm.
+#[derive(Debug)]
+pub struct ClusterTransport {
+    node_id: NodeId,
+    secret: String,
+    peers: DashMap<NodeId, PeerConnection>,
+}
+
+#[derive(Debug)]
+struct PeerConnection {
+    address: String,
+    sender: mpsc::Sender<Bytes>,
+}
+
+impl ClusterTransport {
+    pub fn new(node_id: NodeId, secret: &str) -> Self {
+        Self {
+            node_id,
+            secret: secret.to_owned(),
+            peers: DashMap::new(),
+        }
+    }
+
+    pub fn send(&self, to: NodeId, address: &str, message: &RaftMessage) {
+        let mut peer = self
+            .peers
+            .entry(to)
+            .or_insert_with(|| self.connect(to, address));
+        if peer.address != address || peer.sender.is_closed() {
+            *peer = self.connect(to, address);
+        }
+
+        if peer.sender.try_send(message.to_bytes()).is_err() {
+            debug!("{COMPONENT} - dropped message: {message} to node with ID: {to}, the queue is full.");
+        }
+    }
+
+    fn connect(&self, to: NodeId, address: &str) -> PeerConnection {
+        let (sender, receiver) = mpsc::channel(MAX_QUEUED_MESSAGES);
+        let handshake = Handshake {
+            node_id: self.node_id,
+            secret: self.secret.clone(),
+        };
+        tokio::spawn(send_messages(to, address.to_owned(), handshake, receiver));
+        PeerConnection {
+            address: address.to_owned(),
+            sender,
+        }
+    }
+}
+
+async fn send_messages(
+    node_id: NodeId,
+    address: String,
+    handshake: Handshake,
+    mut receiver: mpsc::Receiver<Bytes>,
+) {
+    let mut stream: Option<TcpStream> = None;
+    while let Some(message) = receiver.recv().await {
+        if stream.is_none() {
+            match TcpStream::connect(&address).await {
+                Ok(mut connected) => {
+                    if write_frame(&mut connected, &handshake.to_bytes())
+                        .await
+                        .is_ok()
+                    {
+                        info!("{COMPONENT} - connected to node with ID: {node_id}, address: {address}");
+                        stream = Some(connected);
+                    }
+                }
+                Err(error) => {
+                    debug!("{COMPONENT} - failed to connect to node with ID: {node_id}, address: {address}, error: {error}");
+                    tokio::time::sleep(RECONNECT_INTERVAL).await;
+                    continue;
+                }
+            }
+        }
+
+        let Some(connected) = stream.as_mut() else {
+            continue;
+        };
+
+        if let Err(error) = write_frame(connected, &message).await {
+            warn!("{COMPONENT} - connection to node with ID: {node_id}, address: {address} was lost, error: {error}");
+            stream = None;
+        }
+    }
+}
+
+/// Starts listening for the connections from the other nodes, the received messages are passed to the sender.
+pub async fn listen(
+    address: &str,
+    secret: String,
+    sender: flume::Sender<(NodeId, RaftMessage)>,
+) -> SocketAddr {
+    let listener = TcpListener::bind(address)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to bind to cluster address {address}"));
+    let address = listener
+        .local_addr()
+        .expect("Failed to get local address for cluster listener");
+    info!("{COMPONENT} - listening for the cluster connections on: {address}");
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, remote_address)) => {
+                    tokio::spawn(receive_messages(
+                        stream,
+                        remote_address,
+                        secret.clone(),
+                        sender.clone(),
+                    ));
+                }
+                Err(error) => error!("{COMPONENT} - failed to accept connection, error: {error}"),
+            }
+        }
+    });
+    address
+}
+
+async fn receive_messages(
+    mut stream: TcpStream,
+    remote_address: SocketAddr,
+    secret: String,
+    sender: flume::Sender<(NodeId, RaftMessage)>,
+) {
+    let handshake = match read_frame(&mut stream)
+        .await
+        .and_then(Handshake::from_bytes)
+    {
+        Ok(handshake) if handshake.secret == secret => handshake,
+        Ok(handshake) => {
+            error!(
+                "{COMPONENT} - rejected connection from node with ID: {}, address: {remote_address}, invalid secret.",
+                handshake.node_id
+            );
+            return;
+        }
+        Err(error) => {
+            error!("{COMPONENT} - rejected connection from address: {remote_address}, invalid handshake, error: {error}");
+            return;
+        }
+    };
+
+    let node_id = handshake.node_id;
+    debug!(
+        "{COMPONENT} - accepted connection from node with ID: {node_id}, address: {remote_address}"
+    );
+    loop {
+        let message = match read_frame(&mut stream).await {
+            Ok(frame) => RaftMessage::from_bytes(frame),
+            Err(_) => {
+                debug!("{COMPONENT} - connection from node with ID: {node_id} was closed.");
+                return;
+            }
+        };
+
+        match message {
+            Ok(message) => {
+                if sender.send_async((node_id, message)).await.is_err() {
+                    return;
+                }
+            }
+            Err(error) => {
+                error!("{COMPONENT} - received invalid message from node with ID: {node_id}, error: {error}");
+                return;
+            }
+        }
+    }
+}
+
+async fn write_frame(stream: &mut TcpStream, payload: &[u8]) -> Result<(), std::io::Error> {
+    stream.write_u32_le(payload.len() as u32).await?;
+    stream.write_all(payload).await?;
+    stream.flush().await
+}
+
+async fn read_frame(stream: &mut TcpStream) -> Result<Bytes, IggyError> {
+    let length = stream
+        .read_u32_le()
+        .await
+        .map_err(|_| IggyError::ConnectionClosed)? as usize;
+    if length > MAX_FRAME_SIZE {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let mut payload = vec![0; length];
+    stream
+        .read_exact(&mut payload)
+        .await
+        .map_err(|_| IggyError::ConnectionClosed)?;
+    Ok(Bytes::from(payload))
+}
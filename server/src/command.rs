@@ -1,5 +1,8 @@
 use bytes::{BufMut, Bytes, BytesMut};
 use error_set::ErrContext;
+use iggy::backups::create_backup::CreateBackup;
+use iggy::backups::get_backups::GetBackups;
+use iggy::backups::restore_backup::RestoreBackup;
 use iggy::command::*;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
@@ -15,22 +18,36 @@ use iggy::messages::poll_messages::PollMessages;
 use iggy::messages::send_messages::SendMessages;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::get_partitions_merge::GetPartitionsMerge;
+use iggy::partitions::merge_partitions::MergePartitions;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use iggy::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy::schemas::delete_topic_schema::DeleteTopicSchema;
+use iggy::schemas::get_schema::GetSchema;
+use iggy::schemas::get_topic_schema::GetTopicSchema;
+use iggy::schemas::get_topic_schemas::GetTopicSchemas;
+use iggy::schemas::register_topic_schema::RegisterTopicSchema;
+use iggy::schemas::update_topic_schema_settings::UpdateTopicSchemaSettings;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
+use iggy::streams::get_stream_topic_defaults::GetStreamTopicDefaults;
 use iggy::streams::get_streams::GetStreams;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::streams::update_stream_topic_defaults::UpdateStreamTopicDefaults;
 use iggy::system::get_client::GetClient;
 use iggy::system::get_clients::GetClients;
 use iggy::system::get_me::GetMe;
 use iggy::system::get_snapshot::GetSnapshot;
 use iggy::system::get_stats::GetStats;
 use iggy::system::ping::Ping;
+use iggy::topic_templates::create_topic_template::CreateTopicTemplate;
+use iggy::topic_templates::delete_topic_template::DeleteTopicTemplate;
+use iggy::topic_templates::get_topic_template::GetTopicTemplate;
+use iggy::topic_templates::get_topic_templates::GetTopicTemplates;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::get_topic::GetTopic;
@@ -44,6 +61,7 @@ use iggy::users::get_user::GetUser;
 use iggy::users::get_users::GetUsers;
 use iggy::users::login_user::LoginUser;
 use iggy::users::logout_user::LogoutUser;
+use iggy::users::unlock_user::UnlockUser;
 use iggy::users::update_permissions::UpdatePermissions;
 use iggy::users::update_user::UpdateUser;
 use iggy::validatable::Validatable;
@@ -68,6 +86,7 @@ pub enum ServerCommand {
     UpdateUser(UpdateUser),
     UpdatePermissions(UpdatePermissions),
     ChangePassword(ChangePassword),
+    UnlockUser(UnlockUser),
     LoginUser(LoginUser),
     LogoutUser(LogoutUser),
     GetPersonalAccessTokens(GetPersonalAccessTokens),
@@ -86,14 +105,28 @@ pub enum ServerCommand {
     DeleteStream(DeleteStream),
     UpdateStream(UpdateStream),
     PurgeStream(PurgeStream),
+    GetStreamTopicDefaults(GetStreamTopicDefaults),
+    UpdateStreamTopicDefaults(UpdateStreamTopicDefaults),
     GetTopic(GetTopic),
     GetTopics(GetTopics),
     CreateTopic(CreateTopic),
     DeleteTopic(DeleteTopic),
     UpdateTopic(UpdateTopic),
     PurgeTopic(PurgeTopic),
+    GetTopicTemplate(GetTopicTemplate),
+    GetTopicTemplates(GetTopicTemplates),
+    CreateTopicTemplate(CreateTopicTemplate),
+    DeleteTopicTemplate(DeleteTopicTemplate),
+    GetSchema(GetSchema),
+    GetTopicSchema(GetTopicSchema),
+    GetTopicSchemas(GetTopicSchemas),
+    RegisterTopicSchema(RegisterTopicSchema),
+    DeleteTopicSchema(DeleteTopicSchema),
+    UpdateTopicSchemaSettings(UpdateTopicSchemaSettings),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
+    MergePartitions(MergePartitions),
+    GetPartitionsMerge(GetPartitionsMerge),
     GetConsumerGroup(GetConsumerGroup),
     GetConsumerGroups(GetConsumerGroups),
     CreateConsumerGroup(CreateConsumerGroup),
@@ -101,6 +134,44 @@ pub enum ServerCommand {
     JoinConsumerGroup(JoinConsumerGroup),
     LeaveConsumerGroup(LeaveConsumerGroup),
     GetSnapshotFile(GetSnapshot),
+    CreateBackup(CreateBackup),
+    GetBackups(GetBackups),
+    RestoreBackup(RestoreBackup),
+}
+
+impl ServerCommand {
+    /// Returns `true` if the command changes the metadata state, thus must be handled by the cluster leader.
+    pub fn changes_state(&self) -> bool {
+        matches!(
+            self,
+            ServerCommand::CreateUser(_)
+                | ServerCommand::DeleteUser(_)
+                | ServerCommand::UpdateUser(_)
+                | ServerCommand::UpdatePermissions(_)
+                | ServerCommand::ChangePassword(_)
+                | ServerCommand::CreatePersonalAccessToken(_)
+                | ServerCommand::DeletePersonalAccessToken(_)
+                | ServerCommand::CreateStream(_)
+                | ServerCommand::DeleteStream(_)
+                | ServerCommand::UpdateStream(_)
+                | ServerCommand::PurgeStream(_)
+                | ServerCommand::UpdateStreamTopicDefaults(_)
+                | ServerCommand::CreateTopic(_)
+                | ServerCommand::DeleteTopic(_)
+                | ServerCommand::UpdateTopic(_)
+                | ServerCommand::PurgeTopic(_)
+                | ServerCommand::CreateTopicTemplate(_)
+                | ServerCommand::DeleteTopicTemplate(_)
+                | ServerCommand::RegisterTopicSchema(_)
+                | ServerCommand::DeleteTopicSchema(_)
+                | ServerCommand::UpdateTopicSchemaSettings(_)
+                | ServerCommand::CreatePartitions(_)
+                | ServerCommand::DeletePartitions(_)
+                | ServerCommand::MergePartitions(_)
+                | ServerCommand::CreateConsumerGroup(_)
+                | ServerCommand::DeleteConsumerGroup(_)
+        )
+    }
 }
 
 impl BytesSerializable for ServerCommand {
@@ -118,6 +189,7 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::UpdateUser(payload) => as_bytes(payload),
             ServerCommand::UpdatePermissions(payload) => as_bytes(payload),
             ServerCommand::ChangePassword(payload) => as_bytes(payload),
+            ServerCommand::UnlockUser(payload) => as_bytes(payload),
             ServerCommand::LoginUser(payload) => as_bytes(payload),
             ServerCommand::LogoutUser(payload) => as_bytes(payload),
             ServerCommand::GetPersonalAccessTokens(payload) => as_bytes(payload),
@@ -135,14 +207,28 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::DeleteStream(payload) => as_bytes(payload),
             ServerCommand::UpdateStream(payload) => as_bytes(payload),
             ServerCommand::PurgeStream(payload) => as_bytes(payload),
+            ServerCommand::GetStreamTopicDefaults(payload) => as_bytes(payload),
+            ServerCommand::UpdateStreamTopicDefaults(payload) => as_bytes(payload),
             ServerCommand::GetTopic(payload) => as_bytes(payload),
             ServerCommand::GetTopics(payload) => as_bytes(payload),
             ServerCommand::CreateTopic(payload) => as_bytes(payload),
             ServerCommand::DeleteTopic(payload) => as_bytes(payload),
             ServerCommand::UpdateTopic(payload) => as_bytes(payload),
             ServerCommand::PurgeTopic(payload) => as_bytes(payload),
+            ServerCommand::GetTopicTemplate(payload) => as_bytes(payload),
+            ServerCommand::GetTopicTemplates(payload) => as_bytes(payload),
+            ServerCommand::CreateTopicTemplate(payload) => as_bytes(payload),
+            ServerCommand::DeleteTopicTemplate(payload) => as_bytes(payload),
+            ServerCommand::GetSchema(payload) => as_bytes(payload),
+            ServerCommand::GetTopicSchema(payload) => as_bytes(payload),
+            ServerCommand::GetTopicSchemas(payload) => as_bytes(payload),
+            ServerCommand::RegisterTopicSchema(payload) => as_bytes(payload),
+            ServerCommand::DeleteTopicSchema(payload) => as_bytes(payload),
+            ServerCommand::UpdateTopicSchemaSettings(payload) => as_bytes(payload),
             ServerCommand::CreatePartitions(payload) => as_bytes(payload),
             ServerCommand::DeletePartitions(payload) => as_bytes(payload),
+            ServerCommand::MergePartitions(payload) => as_bytes(payload),
+            ServerCommand::GetPartitionsMerge(payload) => as_bytes(payload),
             ServerCommand::GetConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::GetConsumerGroups(payload) => as_bytes(payload),
             ServerCommand::CreateConsumerGroup(payload) => as_bytes(payload),
@@ -151,6 +237,9 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::LeaveConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::FlushUnsavedBuffer(payload) => as_bytes(payload),
             ServerCommand::GetSnapshotFile(payload) => as_bytes(payload),
+            ServerCommand::CreateBackup(payload) => as_bytes(payload),
+            ServerCommand::GetBackups(payload) => as_bytes(payload),
+            ServerCommand::RestoreBackup(payload) => as_bytes(payload),
         }
     }
 
@@ -179,6 +268,7 @@ impl BytesSerializable for ServerCommand {
             CHANGE_PASSWORD_CODE => Ok(ServerCommand::ChangePassword(ChangePassword::from_bytes(
                 payload,
             )?)),
+            UNLOCK_USER_CODE => Ok(ServerCommand::UnlockUser(UnlockUser::from_bytes(payload)?)),
             LOGIN_USER_CODE => Ok(ServerCommand::LoginUser(LoginUser::from_bytes(payload)?)),
             LOGOUT_USER_CODE => Ok(ServerCommand::LogoutUser(LogoutUser::from_bytes(payload)?)),
             GET_PERSONAL_ACCESS_TOKENS_CODE => Ok(ServerCommand::GetPersonalAccessTokens(
@@ -227,6 +317,12 @@ impl BytesSerializable for ServerCommand {
             PURGE_STREAM_CODE => Ok(ServerCommand::PurgeStream(PurgeStream::from_bytes(
                 payload,
             )?)),
+            GET_STREAM_TOPIC_DEFAULTS_CODE => Ok(ServerCommand::GetStreamTopicDefaults(
+                GetStreamTopicDefaults::from_bytes(payload)?,
+            )),
+            UPDATE_STREAM_TOPIC_DEFAULTS_CODE => Ok(ServerCommand::UpdateStreamTopicDefaults(
+                UpdateStreamTopicDefaults::from_bytes(payload)?,
+            )),
             GET_TOPIC_CODE => Ok(ServerCommand::GetTopic(GetTopic::from_bytes(payload)?)),
             GET_TOPICS_CODE => Ok(ServerCommand::GetTopics(GetTopics::from_bytes(payload)?)),
             CREATE_TOPIC_CODE => Ok(ServerCommand::CreateTopic(CreateTopic::from_bytes(
@@ -239,12 +335,46 @@ impl BytesSerializable for ServerCommand {
                 payload,
             )?)),
             PURGE_TOPIC_CODE => Ok(ServerCommand::PurgeTopic(PurgeTopic::from_bytes(payload)?)),
+            GET_TOPIC_TEMPLATE_CODE => Ok(ServerCommand::GetTopicTemplate(
+                GetTopicTemplate::from_bytes(payload)?,
+            )),
+            GET_TOPIC_TEMPLATES_CODE => Ok(ServerCommand::GetTopicTemplates(
+                GetTopicTemplates::from_bytes(payload)?,
+            )),
+            CREATE_TOPIC_TEMPLATE_CODE => Ok(ServerCommand::CreateTopicTemplate(
+                CreateTopicTemplate::from_bytes(payload)?,
+            )),
+            DELETE_TOPIC_TEMPLATE_CODE => Ok(ServerCommand::DeleteTopicTemplate(
+                DeleteTopicTemplate::from_bytes(payload)?,
+            )),
+            GET_SCHEMA_CODE => Ok(ServerCommand::GetSchema(GetSchema::from_bytes(payload)?)),
+            GET_TOPIC_SCHEMA_CODE => Ok(ServerCommand::GetTopicSchema(GetTopicSchema::from_bytes(
+                payload,
+            )?)),
+            GET_TOPIC_SCHEMAS_CODE => Ok(ServerCommand::GetTopicSchemas(
+                GetTopicSchemas::from_bytes(payload)?,
+            )),
+            REGISTER_TOPIC_SCHEMA_CODE => Ok(ServerCommand::RegisterTopicSchema(
+                RegisterTopicSchema::from_bytes(payload)?,
+            )),
+            DELETE_TOPIC_SCHEMA_CODE => Ok(ServerCommand::DeleteTopicSchema(
+                DeleteTopicSchema::from_bytes(payload)?,
+            )),
+            UPDATE_TOPIC_SCHEMA_SETTINGS_CODE => Ok(ServerCommand::UpdateTopicSchemaSettings(
+                UpdateTopicSchemaSettings::from_bytes(payload)?,
+            )),
             CREATE_PARTITIONS_CODE => Ok(ServerCommand::CreatePartitions(
                 CreatePartitions::from_bytes(payload)?,
             )),
             DELETE_PARTITIONS_CODE => Ok(ServerCommand::DeletePartitions(
                 DeletePartitions::from_bytes(payload)?,
             )),
+            MERGE_PARTITIONS_CODE => Ok(ServerCommand::MergePartitions(
+                MergePartitions::from_bytes(payload)?,
+            )),
+            GET_PARTITIONS_MERGE_CODE => Ok(ServerCommand::GetPartitionsMerge(
+                GetPartitionsMerge::from_bytes(payload)?,
+            )),
             GET_CONSUMER_GROUP_CODE => Ok(ServerCommand::GetConsumerGroup(
                 GetConsumerGroup::from_bytes(payload)?,
             )),
@@ -266,6 +396,13 @@ impl BytesSerializable for ServerCommand {
             GET_SNAPSHOT_FILE_CODE => Ok(ServerCommand::GetSnapshotFile(GetSnapshot::from_bytes(
                 payload,
             )?)),
+            CREATE_BACKUP_CODE => Ok(ServerCommand::CreateBackup(CreateBackup::from_bytes(
+                payload,
+            )?)),
+            GET_BACKUPS_CODE => Ok(ServerCommand::GetBackups(GetBackups::from_bytes(payload)?)),
+            RESTORE_BACKUP_CODE => Ok(ServerCommand::RestoreBackup(RestoreBackup::from_bytes(
+                payload,
+            )?)),
             _ => {
                 error!("Invalid server command: {code}");
                 Err(IggyError::InvalidCommand)
@@ -297,6 +434,7 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::UpdateUser(command) => command.validate(),
             ServerCommand::UpdatePermissions(command) => command.validate(),
             ServerCommand::ChangePassword(command) => command.validate(),
+            ServerCommand::UnlockUser(command) => command.validate(),
             ServerCommand::LoginUser(command) => command.validate(),
             ServerCommand::LogoutUser(command) => command.validate(),
             ServerCommand::GetPersonalAccessTokens(command) => command.validate(),
@@ -314,14 +452,28 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::DeleteStream(command) => command.validate(),
             ServerCommand::UpdateStream(command) => command.validate(),
             ServerCommand::PurgeStream(command) => command.validate(),
+            ServerCommand::GetStreamTopicDefaults(command) => command.validate(),
+            ServerCommand::UpdateStreamTopicDefaults(command) => command.validate(),
             ServerCommand::GetTopic(command) => command.validate(),
             ServerCommand::GetTopics(command) => command.validate(),
             ServerCommand::CreateTopic(command) => command.validate(),
             ServerCommand::DeleteTopic(command) => command.validate(),
             ServerCommand::UpdateTopic(command) => command.validate(),
             ServerCommand::PurgeTopic(command) => command.validate(),
+            ServerCommand::GetTopicTemplate(command) => command.validate(),
+            ServerCommand::GetTopicTemplates(command) => command.validate(),
+            ServerCommand::CreateTopicTemplate(command) => command.validate(),
+            ServerCommand::DeleteTopicTemplate(command) => command.validate(),
+            ServerCommand::GetSchema(command) => command.validate(),
+            ServerCommand::GetTopicSchema(command) => command.validate(),
+            ServerCommand::GetTopicSchemas(command) => command.validate(),
+            ServerCommand::RegisterTopicSchema(command) => command.validate(),
+            ServerCommand::DeleteTopicSchema(command) => command.validate(),
+            ServerCommand::UpdateTopicSchemaSettings(command) => command.validate(),
             ServerCommand::CreatePartitions(command) => command.validate(),
             ServerCommand::DeletePartitions(command) => command.validate(),
+            ServerCommand::MergePartitions(command) => command.validate(),
+            ServerCommand::GetPartitionsMerge(command) => command.validate(),
             ServerCommand::GetConsumerGroup(command) => command.validate(),
             ServerCommand::GetConsumerGroups(command) => command.validate(),
             ServerCommand::CreateConsumerGroup(command) => command.validate(),
@@ -330,6 +482,9 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::LeaveConsumerGroup(command) => command.validate(),
             ServerCommand::FlushUnsavedBuffer(command) => command.validate(),
             ServerCommand::GetSnapshotFile(command) => command.validate(),
+            ServerCommand::CreateBackup(command) => command.validate(),
+            ServerCommand::GetBackups(command) => command.validate(),
+            ServerCommand::RestoreBackup(command) => command.validate(),
         }
     }
 }
@@ -353,6 +508,7 @@ impl Display for ServerCommand {
             ServerCommand::ChangePassword(payload) => {
                 write!(formatter, "{CHANGE_PASSWORD}|{payload}")
             }
+            ServerCommand::UnlockUser(payload) => write!(formatter, "{UNLOCK_USER}|{payload}"),
             ServerCommand::LoginUser(payload) => write!(formatter, "{LOGIN_USER}|{payload}"),
             ServerCommand::LogoutUser(_) => write!(formatter, "{LOGOUT_USER}"),
             ServerCommand::GetPersonalAccessTokens(_) => {
@@ -373,18 +529,56 @@ impl Display for ServerCommand {
             ServerCommand::DeleteStream(payload) => write!(formatter, "{DELETE_STREAM}|{payload}"),
             ServerCommand::UpdateStream(payload) => write!(formatter, "{UPDATE_STREAM}|{payload}"),
             ServerCommand::PurgeStream(payload) => write!(formatter, "{PURGE_STREAM}|{payload}"),
+            ServerCommand::GetStreamTopicDefaults(payload) => {
+                write!(formatter, "{GET_STREAM_TOPIC_DEFAULTS}|{payload}")
+            }
+            ServerCommand::UpdateStreamTopicDefaults(payload) => {
+                write!(formatter, "{UPDATE_STREAM_TOPIC_DEFAULTS}|{payload}")
+            }
             ServerCommand::GetTopic(payload) => write!(formatter, "{GET_TOPIC}|{payload}"),
             ServerCommand::GetTopics(payload) => write!(formatter, "{GET_TOPICS}|{payload}"),
             ServerCommand::CreateTopic(payload) => write!(formatter, "{CREATE_TOPIC}|{payload}"),
             ServerCommand::DeleteTopic(payload) => write!(formatter, "{DELETE_TOPIC}|{payload}"),
             ServerCommand::UpdateTopic(payload) => write!(formatter, "{UPDATE_TOPIC}|{payload}"),
             ServerCommand::PurgeTopic(payload) => write!(formatter, "{PURGE_TOPIC}|{payload}"),
+            ServerCommand::GetTopicTemplate(payload) => {
+                write!(formatter, "{GET_TOPIC_TEMPLATE}|{payload}")
+            }
+            ServerCommand::GetTopicTemplates(_) => write!(formatter, "{GET_TOPIC_TEMPLATES}"),
+            ServerCommand::CreateTopicTemplate(payload) => {
+                write!(formatter, "{CREATE_TOPIC_TEMPLATE}|{payload}")
+            }
+            ServerCommand::DeleteTopicTemplate(payload) => {
+                write!(formatter, "{DELETE_TOPIC_TEMPLATE}|{payload}")
+            }
+            ServerCommand::GetSchema(payload) => write!(formatter, "{GET_SCHEMA}|{payload}"),
+            ServerCommand::GetTopicSchema(payload) => {
+                write!(formatter, "{GET_TOPIC_SCHEMA}|{payload}")
+            }
+            ServerCommand::GetTopicSchemas(payload) => {
+                write!(formatter, "{GET_TOPIC_SCHEMAS}|{payload}")
+            }
+            ServerCommand::RegisterTopicSchema(payload) => {
+                write!(formatter, "{REGISTER_TOPIC_SCHEMA}|{payload}")
+            }
+            ServerCommand::DeleteTopicSchema(payload) => {
+                write!(formatter, "{DELETE_TOPIC_SCHEMA}|{payload}")
+            }
+            ServerCommand::UpdateTopicSchemaSettings(payload) => {
+                write!(formatter, "{UPDATE_TOPIC_SCHEMA_SETTINGS}|{payload}")
+            }
             ServerCommand::CreatePartitions(payload) => {
                 write!(formatter, "{CREATE_PARTITIONS}|{payload}")
             }
             ServerCommand::DeletePartitions(payload) => {
                 write!(formatter, "{DELETE_PARTITIONS}|{payload}")
             }
+            ServerCommand::MergePartitions(payload) => {
+                write!(formatter, "{MERGE_PARTITIONS}|{payload}")
+            }
+            ServerCommand::GetPartitionsMerge(payload) => {
+                write!(formatter, "{GET_PARTITIONS_MERGE}|{payload}")
+            }
             ServerCommand::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
             ServerCommand::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
             ServerCommand::StoreConsumerOffset(payload) => {
@@ -420,6 +614,11 @@ impl Display for ServerCommand {
             ServerCommand::GetSnapshotFile(payload) => {
                 write!(formatter, "{GET_SNAPSHOT_FILE}|{payload}")
             }
+            ServerCommand::CreateBackup(payload) => write!(formatter, "{CREATE_BACKUP}|{payload}"),
+            ServerCommand::GetBackups(_) => write!(formatter, "{GET_BACKUPS}"),
+            ServerCommand::RestoreBackup(payload) => {
+                write!(formatter, "{RESTORE_BACKUP}|{payload}")
+            }
         }
     }
 }
@@ -490,6 +689,11 @@ mod tests {
             CHANGE_PASSWORD_CODE,
             &ChangePassword::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UnlockUser(UnlockUser::default()),
+            UNLOCK_USER_CODE,
+            &UnlockUser::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::LoginUser(LoginUser::default()),
             LOGIN_USER_CODE,
@@ -570,6 +774,16 @@ mod tests {
             PURGE_STREAM_CODE,
             &PurgeStream::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetStreamTopicDefaults(GetStreamTopicDefaults::default()),
+            GET_STREAM_TOPIC_DEFAULTS_CODE,
+            &GetStreamTopicDefaults::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateStreamTopicDefaults(UpdateStreamTopicDefaults::default()),
+            UPDATE_STREAM_TOPIC_DEFAULTS_CODE,
+            &UpdateStreamTopicDefaults::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetTopic(GetTopic::default()),
             GET_TOPIC_CODE,
@@ -600,6 +814,56 @@ mod tests {
             PURGE_TOPIC_CODE,
             &PurgeTopic::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetTopicTemplate(GetTopicTemplate::default()),
+            GET_TOPIC_TEMPLATE_CODE,
+            &GetTopicTemplate::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetTopicTemplates(GetTopicTemplates::default()),
+            GET_TOPIC_TEMPLATES_CODE,
+            &GetTopicTemplates::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CreateTopicTemplate(CreateTopicTemplate::default()),
+            CREATE_TOPIC_TEMPLATE_CODE,
+            &CreateTopicTemplate::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DeleteTopicTemplate(DeleteTopicTemplate::default()),
+            DELETE_TOPIC_TEMPLATE_CODE,
+            &DeleteTopicTemplate::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSchema(GetSchema::default()),
+            GET_SCHEMA_CODE,
+            &GetSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetTopicSchema(GetTopicSchema::default()),
+            GET_TOPIC_SCHEMA_CODE,
+            &GetTopicSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetTopicSchemas(GetTopicSchemas::default()),
+            GET_TOPIC_SCHEMAS_CODE,
+            &GetTopicSchemas::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RegisterTopicSchema(RegisterTopicSchema::default()),
+            REGISTER_TOPIC_SCHEMA_CODE,
+            &RegisterTopicSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DeleteTopicSchema(DeleteTopicSchema::default()),
+            DELETE_TOPIC_SCHEMA_CODE,
+            &DeleteTopicSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateTopicSchemaSettings(UpdateTopicSchemaSettings::default()),
+            UPDATE_TOPIC_SCHEMA_SETTINGS_CODE,
+            &UpdateTopicSchemaSettings::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::CreatePartitions(CreatePartitions::default()),
             CREATE_PARTITIONS_CODE,
@@ -610,6 +874,16 @@ mod tests {
             DELETE_PARTITIONS_CODE,
             &DeletePartitions::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::MergePartitions(MergePartitions::default()),
+            MERGE_PARTITIONS_CODE,
+            &MergePartitions::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetPartitionsMerge(GetPartitionsMerge::default()),
+            GET_PARTITIONS_MERGE_CODE,
+            &GetPartitionsMerge::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetConsumerGroup(GetConsumerGroup::default()),
             GET_CONSUMER_GROUP_CODE,
@@ -645,6 +919,21 @@ mod tests {
             FLUSH_UNSAVED_BUFFER_CODE,
             &FlushUnsavedBuffer::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CreateBackup(CreateBackup::default()),
+            CREATE_BACKUP_CODE,
+            &CreateBackup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetBackups(GetBackups::default()),
+            GET_BACKUPS_CODE,
+            &GetBackups::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RestoreBackup(RestoreBackup::default()),
+            RESTORE_BACKUP_CODE,
+            &RestoreBackup::default(),
+        );
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
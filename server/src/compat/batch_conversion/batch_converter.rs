@@ -0,0 +1,334 @@
+use crate::compat::index_rebuilding::index_rebuilder::IndexRebuilder;
+use crate::server_error::CompatError;
+use crate::streaming::batching::batch_attributes::BatchAttributes;
+use crate::streaming::batching::message_batch::{
+    RetainedBatchHeader, RetainedMessageBatch, RETAINED_BATCH_HEADER_LEN,
+};
+use crate::streaming::segments::{Index, SparseTimeIndexer};
+use crate::streaming::utils::file;
+use bytes::Bytes;
+use iggy::utils::byte_size::IggyByteSize;
+use std::path::Path;
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+
+const BUF_CAPACITY_BYTES: usize = 512 * 1000;
+const CONVERTED_EXTENSION: &str = "converted";
+/// Base offset (8) + length (4) + last offset delta (4) + max timestamp (8).
+const LEGACY_BATCH_HEADER_LEN: u64 = 8 + 4 + 4 + 8;
+/// Length (4) + offset (8) + state (1), after which the timestamp of the first message is stored.
+const FIRST_TIMESTAMP_POSITION: usize = 4 + 8 + 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchFormat {
+    /// The header without the version, containing only the base offset, length, last offset delta and max timestamp.
+    Legacy,
+    /// The versioned header with the CRC, attributes and first timestamp.
+    Current,
+}
+
+pub struct BatchConverter {
+    pub log_path: String,
+    pub index_path: String,
+    pub time_index_path: String,
+    pub time_index_interval: u64,
+    pub start_offset: u64,
+}
+
+impl BatchConverter {
+    pub fn new(
+        log_path: String,
+        index_path: String,
+        time_index_path: String,
+        time_index_interval: u64,
+        start_offset: u64,
+    ) -> Self {
+        Self {
+            log_path,
+            index_path,
+            time_index_path,
+            time_index_interval,
+            start_offset,
+        }
+    }
+
+    /// Detects the format of the log by sampling its first batch, which is considered to be stored
+    /// in the current format only if both its version and CRC are valid. Returns `None` for the empty log.
+    pub async fn detect_format(&self) -> Result<Option<BatchFormat>, CompatError> {
+        let mut reader = BufReader::new(file::open(&self.log_path).await?);
+        let log_size = reader.get_ref().metadata().await?.len();
+        if log_size == 0 {
+            return Ok(None);
+        }
+
+        if log_size < RETAINED_BATCH_HEADER_LEN {
+            return Ok(Some(BatchFormat::Legacy));
+        }
+
+        let mut header_bytes = [0u8; RETAINED_BATCH_HEADER_LEN as usize];
+        reader.read_exact(&mut header_bytes).await?;
+        let Ok(header) = RetainedBatchHeader::from_bytes(&header_bytes) else {
+            return Ok(Some(BatchFormat::Legacy));
+        };
+        if log_size - RETAINED_BATCH_HEADER_LEN < header.length as u64 {
+            return Ok(Some(BatchFormat::Legacy));
+        }
+
+        let mut messages = vec![0u8; header.length as usize];
+        reader.read_exact(&mut messages).await?;
+        match header.verify_crc(&header_bytes, &messages) {
+            Ok(_) => Ok(Some(BatchFormat::Current)),
+            Err(_) => Ok(Some(BatchFormat::Legacy)),
+        }
+    }
+
+    /// Rewrites the legacy batches of the log in the current format and rebuilds the index and the time index,
+    /// as the positions of the batches change. The original log and indexes are copied to the `backup_path` first,
+    /// if provided. The legacy batches are marked as `encrypted` depending on the encryption state of the topic,
+    /// as the payloads are stored in the same way as before, and the incomplete batch at the tail of the log
+    /// is dropped. Returns the number of the converted batches.
+    pub async fn convert(
+        &self,
+        backup_path: Option<&str>,
+        encrypted: bool,
+    ) -> Result<u64, CompatError> {
+        if let Some(backup_path) = backup_path {
+            self.backup(backup_path).await?;
+        }
+
+        let converted_path = format!("{}.{CONVERTED_EXTENSION}", self.log_path);
+        let mut reader =
+            BufReader::with_capacity(BUF_CAPACITY_BYTES, file::open(&self.log_path).await?);
+        let log_size = reader.get_ref().metadata().await?.len();
+        let mut writer =
+            BufWriter::with_capacity(BUF_CAPACITY_BYTES, File::create(&converted_path).await?);
+        let attributes = BatchAttributes::default().with_encrypted(encrypted);
+        let mut indexes = Vec::new();
+        let mut position = 0;
+        let mut converted_position = 0;
+        while log_size - position >= LEGACY_BATCH_HEADER_LEN {
+            let base_offset = reader.read_u64_le().await?;
+            let length = reader.read_u32_le().await? as u64;
+            let last_offset_delta = reader.read_u32_le().await?;
+            let max_timestamp = reader.read_u64_le().await?;
+            if log_size - position - LEGACY_BATCH_HEADER_LEN < length {
+                break;
+            }
+
+            let mut messages = vec![0u8; length as usize];
+            reader.read_exact(&mut messages).await?;
+            let first_timestamp = messages
+                .get(FIRST_TIMESTAMP_POSITION..FIRST_TIMESTAMP_POSITION + 8)
+                .map_or(max_timestamp, |bytes| {
+                    u64::from_le_bytes(bytes.try_into().unwrap())
+                });
+            let batch = RetainedMessageBatch::new(
+                base_offset,
+                last_offset_delta,
+                first_timestamp,
+                max_timestamp,
+                IggyByteSize::from(length),
+                Bytes::from(messages),
+            )
+            .with_attributes(attributes);
+            writer.write_all(&batch.header_as_bytes()).await?;
+            writer.write_all(&batch.bytes).await?;
+            indexes.push(Index {
+                offset: (batch.get_last_offset() - self.start_offset) as u32,
+                position: converted_position as u32,
+                timestamp: max_timestamp,
+            });
+            position += LEGACY_BATCH_HEADER_LEN + length;
+            converted_position += RETAINED_BATCH_HEADER_LEN + length;
+        }
+        writer.flush().await?;
+        writer.get_ref().sync_all().await?;
+
+        file::rename(&converted_path, &self.log_path).await?;
+        if file::exists(&self.index_path).await? {
+            file::remove(&self.index_path).await?;
+        }
+        IndexRebuilder::new(
+            self.log_path.clone(),
+            self.index_path.clone(),
+            self.start_offset,
+        )
+        .rebuild()
+        .await?;
+        self.rebuild_time_index(&indexes).await?;
+        Ok(indexes.len() as u64)
+    }
+
+    async fn rebuild_time_index(&self, indexes: &[Index]) -> Result<(), CompatError> {
+        let mut indexer = SparseTimeIndexer::new(self.time_index_interval);
+        let bytes = indexes
+            .iter()
+            .filter_map(|index| indexer.next(index))
+            .flat_map(|time_index| time_index.to_bytes())
+            .collect::<Vec<_>>();
+        let mut writer = File::create(&self.time_index_path).await?;
+        writer.write_all(&bytes).await?;
+        writer.sync_all().await?;
+        Ok(())
+    }
+
+    async fn backup(&self, backup_path: &str) -> Result<(), CompatError> {
+        tokio::fs::create_dir_all(backup_path).await?;
+        for path in [&self.log_path, &self.index_path, &self.time_index_path] {
+            if !file::exists(path).await? {
+                continue;
+            }
+
+            let file_name = Path::new(path).file_name().unwrap().to_string_lossy();
+            tokio::fs::copy(path, format!("{backup_path}/{file_name}")).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::batching::iterator::IntoMessagesIterator;
+    use crate::streaming::batching::message_batch::{
+        NO_PRODUCER_EPOCH, NO_PRODUCER_ID, NO_SEQUENCE,
+    };
+    use crate::streaming::models::messages::RetainedMessage;
+    use bytes::BytesMut;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::models::messages::MessageState;
+    use iggy::utils::checksum;
+
+    fn messages(base_offset: u64, count: u64) -> BytesMut {
+        let mut bytes = BytesMut::new();
+        for offset in base_offset..base_offset + count {
+            let payload = Bytes::from(format!("message-{offset}"));
+            RetainedMessage {
+                id: offset as u128,
+                offset,
+                timestamp: 1000 + offset,
+                checksum: checksum::calculate(&payload),
+                message_state: MessageState::Available,
+                headers: None,
+                key: None,
+                payload,
+            }
+            .extend(&mut bytes);
+        }
+        bytes
+    }
+
+    fn legacy_batch(base_offset: u64, count: u64) -> Vec<u8> {
+        let messages = messages(base_offset, count);
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&base_offset.to_le_bytes());
+        bytes.extend_from_slice(&(messages.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(count as u32 - 1).to_le_bytes());
+        bytes.extend_from_slice(&(1000 + base_offset + count - 1).to_le_bytes());
+        bytes.extend_from_slice(&messages);
+        bytes
+    }
+
+    async fn converter(name: &str) -> (String, BatchConverter) {
+        let directory = std::env::temp_dir()
+            .join(format!(
+                "iggy_batch_conversion_{name}_{}",
+                std::process::id()
+            ))
+            .to_string_lossy()
+            .to_string();
+        tokio::fs::create_dir_all(&directory).await.unwrap();
+        let converter = BatchConverter::new(
+            format!("{directory}/00000000000000000000.log"),
+            format!("{directory}/00000000000000000000.index"),
+            format!("{directory}/00000000000000000000.timeidx"),
+            0,
+            0,
+        );
+        (directory, converter)
+    }
+
+    #[tokio::test]
+    async fn legacy_log_should_be_converted_and_backed_up() {
+        let (directory, converter) = converter("legacy").await;
+        let mut log = legacy_batch(0, 2);
+        log.extend_from_slice(&legacy_batch(2, 3));
+        // The incomplete batch at the tail is dropped.
+        log.extend_from_slice(&legacy_batch(5, 1)[..10]);
+        tokio::fs::write(&converter.log_path, &log).await.unwrap();
+
+        assert_eq!(
+            converter.detect_format().await.unwrap(),
+            Some(BatchFormat::Legacy)
+        );
+        let backup_path = format!("{directory}/backup");
+        let batches_count = converter.convert(Some(&backup_path), true).await.unwrap();
+
+        assert_eq!(batches_count, 2);
+        assert_eq!(
+            converter.detect_format().await.unwrap(),
+            Some(BatchFormat::Current)
+        );
+        let backup = tokio::fs::read(format!("{backup_path}/00000000000000000000.log"))
+            .await
+            .unwrap();
+        assert_eq!(backup, log);
+
+        let log = tokio::fs::read(&converter.log_path).await.unwrap();
+        let header_len = RETAINED_BATCH_HEADER_LEN as usize;
+        let first = RetainedBatchHeader::from_bytes(&log[..header_len]).unwrap();
+        let first_end = header_len + first.length as usize;
+        first
+            .verify_crc(&log[..header_len], &log[header_len..first_end])
+            .unwrap();
+        assert_eq!(first.base_offset, 0);
+        assert_eq!(first.first_timestamp, 1000);
+        assert_eq!(first.max_timestamp, 1001);
+        assert!(first.attributes.is_encrypted());
+        assert_eq!(
+            first.attributes.compression().unwrap(),
+            CompressionAlgorithm::None
+        );
+        assert!(!first.attributes.is_transactional());
+        assert!(!first.attributes.is_control());
+        assert_eq!(first.producer_id, NO_PRODUCER_ID);
+        assert_eq!(first.producer_epoch, NO_PRODUCER_EPOCH);
+        assert_eq!(first.base_sequence, NO_SEQUENCE);
+        let second = RetainedBatchHeader::from_bytes(&log[first_end..]).unwrap();
+        let batch = RetainedMessageBatch::from_header(
+            &second,
+            Bytes::copy_from_slice(&log[first_end + header_len..]),
+        );
+        assert_eq!(batch.first_timestamp, 1002);
+        assert_eq!(batch.get_last_offset(), 4);
+        assert_eq!(batch.into_messages_iter().count(), 3);
+
+        let index = tokio::fs::read(&converter.index_path).await.unwrap();
+        assert_eq!(index.len(), 2 * 16);
+        assert_eq!(
+            u32::from_le_bytes(index[20..24].try_into().unwrap()),
+            first_end as u32
+        );
+
+        // With the zero interval, each batch is indexed with the maximum timestamp of the previous ones.
+        let time_index = tokio::fs::read(&converter.time_index_path).await.unwrap();
+        assert_eq!(time_index.len(), 2 * 12);
+        assert_eq!(
+            u64::from_le_bytes(time_index[12..20].try_into().unwrap()),
+            1001
+        );
+        assert_eq!(
+            u32::from_le_bytes(time_index[20..24].try_into().unwrap()),
+            first_end as u32
+        );
+        tokio::fs::remove_dir_all(&directory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn empty_log_should_not_be_converted() {
+        let (directory, converter) = converter("empty").await;
+        tokio::fs::write(&converter.log_path, []).await.unwrap();
+        assert_eq!(converter.detect_format().await.unwrap(), None);
+        tokio::fs::remove_dir_all(&directory).await.unwrap();
+    }
+}
@@ -1,18 +1,11 @@
 use crate::streaming::utils::file;
 use crate::{
-    server_error::CompatError, streaming::batching::message_batch::RETAINED_BATCH_HEADER_LEN,
+    server_error::CompatError,
+    streaming::batching::message_batch::{RetainedBatchHeader, RETAINED_BATCH_HEADER_LEN},
 };
 use std::io::SeekFrom;
 use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader, BufWriter};
 
-// Same struct as RetainedMessageBatch, but without payload
-pub struct BatchHeader {
-    base_offset: u64,
-    last_offset_delta: u32,
-    max_timestamp: u64,
-    length: u32,
-}
-
 pub struct IndexRebuilder {
     pub log_path: String,
     pub index_path: String,
@@ -30,23 +23,15 @@ impl IndexRebuilder {
 
     async fn read_batch_header(
         reader: &mut BufReader<tokio::fs::File>,
-    ) -> Result<BatchHeader, std::io::Error> {
-        let base_offset = reader.read_u64_le().await?;
-        let length = reader.read_u32_le().await?;
-        let last_offset_delta = reader.read_u32_le().await?;
-        let max_timestamp = reader.read_u64_le().await?;
-
-        Ok(BatchHeader {
-            base_offset,
-            length,
-            last_offset_delta,
-            max_timestamp,
-        })
+    ) -> Result<RetainedBatchHeader, CompatError> {
+        let mut header = [0u8; RETAINED_BATCH_HEADER_LEN as usize];
+        reader.read_exact(&mut header).await?;
+        Ok(RetainedBatchHeader::from_bytes(&header)?)
     }
 
     async fn write_index_entry(
         writer: &mut BufWriter<tokio::fs::File>,
-        header: &BatchHeader,
+        header: &RetainedBatchHeader,
         position: u32,
         start_offset: u64,
     ) -> Result<(), CompatError> {
@@ -85,8 +70,10 @@ impl IndexRebuilder {
                     // Update position for next iteration
                     position = next_position;
                 }
-                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
-                Err(e) => return Err(e.into()),
+                Err(CompatError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                    break
+                }
+                Err(e) => return Err(e),
             }
         }
 
@@ -1 +1,2 @@
+pub mod batch_conversion;
 pub mod index_rebuilding;
@@ -0,0 +1,61 @@
+use iggy::utils::duration::IggyDuration;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ClusterConfig {
+    pub enabled: bool,
+    pub node_id: u32,
+    pub address: String,
+    pub secret: String,
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub nodes: Vec<ClusterNodeConfig>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub election_timeout: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub heartbeat_interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub commit_timeout: IggyDuration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterNodeConfig {
+    pub id: u32,
+    pub address: String,
+}
+
+impl FromStr for ClusterNodeConfig {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((id, address)) = s.split_once('=') else {
+            return Err(format!(
+                "Invalid cluster node: {s}, expected format: ID=address"
+            ));
+        };
+
+        let id = id
+            .trim()
+            .parse::<u32>()
+            .map_err(|error| format!("Invalid cluster node ID: {id}, error: {error}"))?;
+        let address = address.trim();
+        if address.is_empty() {
+            return Err(format!("Missing address of the cluster node with ID: {id}"));
+        }
+
+        Ok(ClusterNodeConfig {
+            id,
+            address: address.to_string(),
+        })
+    }
+}
+
+impl Display for ClusterNodeConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.id, self.address)
+    }
+}
@@ -12,13 +12,14 @@ use tracing::debug;
 
 const DEFAULT_CONFIG_PROVIDER: &str = "file";
 const DEFAULT_CONFIG_PATH: &str = "configs/server.toml";
-const SECRET_KEYS: [&str; 6] = [
+const SECRET_KEYS: [&str; 7] = [
     IGGY_ROOT_PASSWORD_ENV,
     "IGGY_DATA_MAINTENANCE_ARCHIVER_S3_KEY_SECRET",
     "IGGY_HTTP_JWT_ENCODING_SECRET",
     "IGGY_HTTP_JWT_DECODING_SECRET",
     "IGGY_TCP_TLS_PASSWORD",
     "IGGY_SYSTEM_ENCRYPTION_KEY",
+    "IGGY_CLUSTER_SECRET",
 ];
 
 pub enum ConfigProviderKind {
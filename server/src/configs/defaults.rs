@@ -1,20 +1,23 @@
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 
+use crate::configs::cluster::ClusterConfig;
 use crate::configs::http::{
     HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
 };
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    ArchiverConfig, DataMaintenanceConfig, HeartbeatConfig, MessageSaverConfig,
-    MessagesMaintenanceConfig, PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig,
-    ServerConfig, StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig,
-    TelemetryTracesConfig,
+    ArchiverConfig, DataMaintenanceConfig, HeartbeatConfig, LoginProtectionConfig,
+    MessageSaverConfig, MessagesMaintenanceConfig, PersonalAccessTokenCleanerConfig,
+    PersonalAccessTokenConfig, RateLimitConfig, ServerConfig, StateMaintenanceConfig,
+    TelemetryConfig, TelemetryLogsConfig, TelemetryTracesConfig,
 };
 use crate::configs::system::{
-    BackupConfig, CacheConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig,
-    LoggingConfig, MessageDeduplicationConfig, PartitionConfig, RecoveryConfig, RuntimeConfig,
-    SegmentConfig, StateConfig, StreamConfig, SystemConfig, TopicConfig,
+    AutoCreateConfig, BackupConfig, CacheConfig, CompatibilityConfig, CompressionConfig,
+    EncryptionConfig, IoUringConfig, KeyringConfig, LoggingConfig, MessageDeduplicationConfig,
+    PartitionConfig, PasswordConfig, PasswordHashingConfig, PasswordPolicyConfig, QuotasConfig,
+    RecoveryConfig, RuntimeConfig, SegmentConfig, StateConfig, StreamConfig, SystemConfig,
+    ThroughputQuotaConfig, TieredStorageConfig, TopicConfig,
 };
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
 use std::sync::Arc;
@@ -34,6 +37,9 @@ impl Default for ServerConfig {
             heartbeat: HeartbeatConfig::default(),
             message_saver: MessageSaverConfig::default(),
             personal_access_token: PersonalAccessTokenConfig::default(),
+            login_protection: LoginProtectionConfig::default(),
+            rate_limit: RateLimitConfig::default(),
+            cluster: ClusterConfig::default(),
             system: Arc::new(SystemConfig::default()),
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
@@ -55,6 +61,8 @@ impl Default for ArchiverConfig {
                 .unwrap(),
             disk: None,
             s3: None,
+            azure: None,
+            gcs: None,
         }
     }
 }
@@ -85,6 +93,15 @@ impl Default for StateMaintenanceConfig {
                 .interval
                 .parse()
                 .unwrap(),
+            compaction_enabled: SERVER_CONFIG.data_maintenance.state.compaction_enabled,
+            compaction_min_entries: SERVER_CONFIG.data_maintenance.state.compaction_min_entries
+                as u64,
+            compaction_interval: SERVER_CONFIG
+                .data_maintenance
+                .state
+                .compaction_interval
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -126,6 +143,7 @@ impl Default for TcpConfig {
             enabled: SERVER_CONFIG.tcp.enabled,
             address: SERVER_CONFIG.tcp.address.parse().unwrap(),
             ipv6: SERVER_CONFIG.tcp.ipv_6,
+            zero_copy_polling: SERVER_CONFIG.tcp.zero_copy_polling,
             tls: TcpTlsConfig::default(),
             socket: TcpSocketConfig::default(),
         }
@@ -289,6 +307,63 @@ impl Default for PersonalAccessTokenCleanerConfig {
     }
 }
 
+impl Default for LoginProtectionConfig {
+    fn default() -> LoginProtectionConfig {
+        LoginProtectionConfig {
+            enabled: SERVER_CONFIG.login_protection.enabled,
+            max_failed_attempts_per_user: SERVER_CONFIG
+                .login_protection
+                .max_failed_attempts_per_user as u32,
+            max_failed_attempts_per_ip: SERVER_CONFIG.login_protection.max_failed_attempts_per_ip
+                as u32,
+            lockout_duration: SERVER_CONFIG
+                .login_protection
+                .lockout_duration
+                .parse()
+                .unwrap(),
+            max_lockout_duration: SERVER_CONFIG
+                .login_protection
+                .max_lockout_duration
+                .parse()
+                .unwrap(),
+            reset_after: SERVER_CONFIG.login_protection.reset_after.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> RateLimitConfig {
+        RateLimitConfig {
+            enabled: SERVER_CONFIG.rate_limit.enabled,
+            key: SERVER_CONFIG.rate_limit.key.parse().unwrap(),
+            mode: SERVER_CONFIG.rate_limit.mode.parse().unwrap(),
+            requests_per_second: SERVER_CONFIG.rate_limit.requests_per_second as u64,
+            bytes_per_second: SERVER_CONFIG.rate_limit.bytes_per_second.parse().unwrap(),
+            max_delay: SERVER_CONFIG.rate_limit.max_delay.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for ClusterConfig {
+    fn default() -> ClusterConfig {
+        ClusterConfig {
+            enabled: SERVER_CONFIG.cluster.enabled,
+            node_id: SERVER_CONFIG.cluster.node_id as u32,
+            address: SERVER_CONFIG.cluster.address.parse().unwrap(),
+            secret: SERVER_CONFIG.cluster.secret.parse().unwrap(),
+            nodes: SERVER_CONFIG
+                .cluster
+                .nodes
+                .iter()
+                .map(|s| s.parse().unwrap())
+                .collect(),
+            election_timeout: SERVER_CONFIG.cluster.election_timeout.parse().unwrap(),
+            heartbeat_interval: SERVER_CONFIG.cluster.heartbeat_interval.parse().unwrap(),
+            commit_timeout: SERVER_CONFIG.cluster.commit_timeout.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for SystemConfig {
     fn default() -> SystemConfig {
         SystemConfig {
@@ -299,6 +374,7 @@ impl Default for SystemConfig {
             cache: CacheConfig::default(),
             stream: StreamConfig::default(),
             encryption: EncryptionConfig::default(),
+            password: PasswordConfig::default(),
             topic: TopicConfig::default(),
             partition: PartitionConfig::default(),
             segment: SegmentConfig::default(),
@@ -306,6 +382,9 @@ impl Default for SystemConfig {
             compression: CompressionConfig::default(),
             message_deduplication: MessageDeduplicationConfig::default(),
             recovery: RecoveryConfig::default(),
+            quotas: QuotasConfig::default(),
+            tiered_storage: TieredStorageConfig::default(),
+            auto_create: AutoCreateConfig::default(),
         }
     }
 }
@@ -395,6 +474,170 @@ impl Default for EncryptionConfig {
         EncryptionConfig {
             enabled: SERVER_CONFIG.system.encryption.enabled,
             key: SERVER_CONFIG.system.encryption.key.parse().unwrap(),
+            keyring: KeyringConfig::default(),
+        }
+    }
+}
+
+impl Default for KeyringConfig {
+    fn default() -> KeyringConfig {
+        KeyringConfig {
+            enabled: SERVER_CONFIG.system.encryption.keyring.enabled,
+            path: SERVER_CONFIG
+                .system
+                .encryption
+                .keyring
+                .path
+                .parse()
+                .unwrap(),
+            key_encryption_key: SERVER_CONFIG
+                .system
+                .encryption
+                .keyring
+                .key_encryption_key
+                .parse()
+                .unwrap(),
+            rotation_enabled: SERVER_CONFIG.system.encryption.keyring.rotation_enabled,
+            rotation_interval: SERVER_CONFIG
+                .system
+                .encryption
+                .keyring
+                .rotation_interval
+                .parse()
+                .unwrap(),
+            reencryption_enabled: SERVER_CONFIG.system.encryption.keyring.reencryption_enabled,
+        }
+    }
+}
+
+impl Default for PasswordPolicyConfig {
+    fn default() -> PasswordPolicyConfig {
+        PasswordPolicyConfig {
+            min_length: SERVER_CONFIG.system.password.policy.min_length as u32,
+            require_uppercase: SERVER_CONFIG.system.password.policy.require_uppercase,
+            require_lowercase: SERVER_CONFIG.system.password.policy.require_lowercase,
+            require_digit: SERVER_CONFIG.system.password.policy.require_digit,
+            require_special_character: SERVER_CONFIG
+                .system
+                .password
+                .policy
+                .require_special_character,
+            history: SERVER_CONFIG.system.password.policy.history as u32,
+            expiry: SERVER_CONFIG.system.password.policy.expiry.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for PasswordHashingConfig {
+    fn default() -> PasswordHashingConfig {
+        PasswordHashingConfig {
+            algorithm: SERVER_CONFIG
+                .system
+                .password
+                .hashing
+                .algorithm
+                .parse()
+                .unwrap(),
+            bcrypt_cost: SERVER_CONFIG.system.password.hashing.bcrypt_cost as u32,
+            argon2_memory_cost: SERVER_CONFIG.system.password.hashing.argon_2_memory_cost as u32,
+            argon2_time_cost: SERVER_CONFIG.system.password.hashing.argon_2_time_cost as u32,
+            argon2_parallelism: SERVER_CONFIG.system.password.hashing.argon_2_parallelism as u32,
+        }
+    }
+}
+
+impl Default for TieredStorageConfig {
+    fn default() -> TieredStorageConfig {
+        TieredStorageConfig {
+            enabled: SERVER_CONFIG.system.tiered_storage.enabled,
+            offload_after: SERVER_CONFIG
+                .system
+                .tiered_storage
+                .offload_after
+                .parse()
+                .unwrap(),
+            path: SERVER_CONFIG.system.tiered_storage.path.parse().unwrap(),
+            cache_size: SERVER_CONFIG
+                .system
+                .tiered_storage
+                .cache_size
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for AutoCreateConfig {
+    fn default() -> AutoCreateConfig {
+        AutoCreateConfig {
+            enabled: SERVER_CONFIG.system.auto_create.enabled,
+            on_send: SERVER_CONFIG.system.auto_create.on_send,
+            on_poll: SERVER_CONFIG.system.auto_create.on_poll,
+            streams_pattern: SERVER_CONFIG
+                .system
+                .auto_create
+                .streams_pattern
+                .parse()
+                .unwrap(),
+            topics_pattern: SERVER_CONFIG
+                .system
+                .auto_create
+                .topics_pattern
+                .parse()
+                .unwrap(),
+            template: SERVER_CONFIG.system.auto_create.template.parse().unwrap(),
+            partitions_count: SERVER_CONFIG.system.auto_create.partitions_count as u32,
+        }
+    }
+}
+
+impl Default for QuotasConfig {
+    fn default() -> QuotasConfig {
+        QuotasConfig {
+            enabled: SERVER_CONFIG.system.quotas.enabled,
+            max_streams_per_user: SERVER_CONFIG.system.quotas.max_streams_per_user as u32,
+            max_topics_per_user: SERVER_CONFIG.system.quotas.max_topics_per_user as u32,
+            max_partitions_per_user: SERVER_CONFIG.system.quotas.max_partitions_per_user as u32,
+            max_stream_size: SERVER_CONFIG.system.quotas.max_stream_size.parse().unwrap(),
+            throughput: ThroughputQuotaConfig::default(),
+        }
+    }
+}
+
+impl Default for ThroughputQuotaConfig {
+    fn default() -> ThroughputQuotaConfig {
+        ThroughputQuotaConfig {
+            scope: SERVER_CONFIG
+                .system
+                .quotas
+                .throughput
+                .scope
+                .parse()
+                .unwrap(),
+            max_send_bytes_per_second: SERVER_CONFIG
+                .system
+                .quotas
+                .throughput
+                .max_send_bytes_per_second
+                .parse()
+                .unwrap(),
+            max_send_messages_per_second: SERVER_CONFIG
+                .system
+                .quotas
+                .throughput
+                .max_send_messages_per_second as u64,
+            max_poll_bytes_per_second: SERVER_CONFIG
+                .system
+                .quotas
+                .throughput
+                .max_poll_bytes_per_second
+                .parse()
+                .unwrap(),
+            max_poll_messages_per_second: SERVER_CONFIG
+                .system
+                .quotas
+                .throughput
+                .max_poll_messages_per_second as u64,
         }
     }
 }
@@ -425,6 +668,32 @@ impl Default for PartitionConfig {
                 as u32,
             enforce_fsync: SERVER_CONFIG.system.partition.enforce_fsync,
             validate_checksum: SERVER_CONFIG.system.partition.validate_checksum,
+            io_backend: SERVER_CONFIG.system.partition.io_backend.parse().unwrap(),
+            io_uring: IoUringConfig::default(),
+        }
+    }
+}
+
+impl Default for IoUringConfig {
+    fn default() -> IoUringConfig {
+        IoUringConfig {
+            direct_io: SERVER_CONFIG.system.partition.io_uring.direct_io,
+            queue_depth: SERVER_CONFIG.system.partition.io_uring.queue_depth as u32,
+            registered_buffers: SERVER_CONFIG.system.partition.io_uring.registered_buffers as u32,
+            registered_buffer_size: SERVER_CONFIG
+                .system
+                .partition
+                .io_uring
+                .registered_buffer_size
+                .parse()
+                .unwrap(),
+            group_commit_interval: SERVER_CONFIG
+                .system
+                .partition
+                .io_uring
+                .group_commit_interval
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -433,7 +702,13 @@ impl Default for SegmentConfig {
     fn default() -> SegmentConfig {
         SegmentConfig {
             size: SERVER_CONFIG.system.segment.size.parse().unwrap(),
-            cache_indexes: SERVER_CONFIG.system.segment.cache_indexes,
+            cache_indexes: SERVER_CONFIG.system.segment.cache_indexes.parse().unwrap(),
+            time_index_interval: SERVER_CONFIG
+                .system
+                .segment
+                .time_index_interval
+                .parse()
+                .unwrap(),
             message_expiry: SERVER_CONFIG.system.segment.message_expiry.parse().unwrap(),
             archive_expired: SERVER_CONFIG.system.segment.archive_expired,
             server_confirmation: SERVER_CONFIG
@@ -476,6 +751,8 @@ impl Default for RecoveryConfig {
     fn default() -> RecoveryConfig {
         RecoveryConfig {
             recreate_missing_state: SERVER_CONFIG.system.recovery.recreate_missing_state,
+            validate_active_segments: SERVER_CONFIG.system.recovery.validate_active_segments,
+            validated_batches_count: SERVER_CONFIG.system.recovery.validated_batches_count as u32,
         }
     }
 }
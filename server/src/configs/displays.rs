@@ -1,7 +1,9 @@
+use crate::configs::cluster::ClusterConfig;
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    ArchiverConfig, DataMaintenanceConfig, DiskArchiverConfig, HeartbeatConfig,
-    MessagesMaintenanceConfig, S3ArchiverConfig, StateMaintenanceConfig, TelemetryConfig,
+    ArchiverConfig, AzureArchiverConfig, DataMaintenanceConfig, DiskArchiverConfig,
+    GcsArchiverConfig, HeartbeatConfig, LoginProtectionConfig, MessagesMaintenanceConfig,
+    RateLimitConfig, S3ArchiverConfig, StateMaintenanceConfig, TelemetryConfig,
     TelemetryLogsConfig, TelemetryTracesConfig,
 };
 use crate::configs::system::MessageDeduplicationConfig;
@@ -10,8 +12,10 @@ use crate::configs::{
     resource_quota::MemoryResourceQuota,
     server::{MessageSaverConfig, ServerConfig},
     system::{
-        CacheConfig, CompressionConfig, EncryptionConfig, LoggingConfig, PartitionConfig,
-        SegmentConfig, StateConfig, StreamConfig, SystemConfig, TopicConfig,
+        AutoCreateConfig, CacheConfig, CompressionConfig, EncryptionConfig, IoUringConfig,
+        KeyringConfig, LoggingConfig, PartitionConfig, PasswordConfig, PasswordHashingConfig,
+        PasswordPolicyConfig, QuotasConfig, RecoveryConfig, SegmentConfig, StateConfig,
+        StreamConfig, SystemConfig, ThroughputQuotaConfig, TieredStorageConfig, TopicConfig,
     },
     tcp::{TcpConfig, TcpSocketConfig, TcpTlsConfig},
 };
@@ -135,9 +139,17 @@ impl Display for ArchiverConfig {
             .s3
             .as_ref()
             .map_or("none".to_string(), |s3| s3.to_string());
+        let azure = self
+            .azure
+            .as_ref()
+            .map_or("none".to_string(), |azure| azure.to_string());
+        let gcs = self
+            .gcs
+            .as_ref()
+            .map_or("none".to_string(), |gcs| gcs.to_string());
         write!(
             f,
-            "{{ enabled: {}, kind: {}, disk: {disk}, s3: {s3} }}",
+            "{{ enabled: {}, kind: {}, disk: {disk}, s3: {s3}, azure: {azure}, gcs: {gcs} }}",
             self.enabled, self.kind,
         )
     }
@@ -162,6 +174,29 @@ impl Display for S3ArchiverConfig {
     }
 }
 
+impl Display for AzureArchiverConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ account: {}, container: {}, endpoint: {} }}",
+            self.account,
+            self.container,
+            self.endpoint.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+impl Display for GcsArchiverConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ bucket: {}, service_account_path: {} }}",
+            self.bucket,
+            self.service_account_path.as_deref().unwrap_or_default()
+        )
+    }
+}
+
 impl Display for MessagesMaintenanceConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -176,8 +211,13 @@ impl Display for StateMaintenanceConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ archiver_enabled: {}, overwrite: {}, interval: {} }}",
-            self.archiver_enabled, self.overwrite, self.interval
+            "{{ archiver_enabled: {}, overwrite: {}, interval: {}, compaction_enabled: {}, compaction_min_entries: {}, compaction_interval: {} }}",
+            self.archiver_enabled,
+            self.overwrite,
+            self.interval,
+            self.compaction_enabled,
+            self.compaction_min_entries,
+            self.compaction_interval
         )
     }
 }
@@ -186,8 +226,8 @@ impl Display for ServerConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, system: {}, quic: {}, tcp: {}, http: {}, telemetry: {} }}",
-            self.data_maintenance, self.message_saver, self.heartbeat, self.system, self.quic, self.tcp, self.http, self.telemetry
+            "{{ data_maintenance: {}, message_saver: {}, login_protection: {}, rate_limit: {}, cluster: {}, heartbeat: {}, system: {}, quic: {}, tcp: {}, http: {}, telemetry: {} }}",
+            self.data_maintenance, self.message_saver, self.login_protection, self.rate_limit, self.cluster, self.heartbeat, self.system, self.quic, self.tcp, self.http, self.telemetry
         )
     }
 }
@@ -218,9 +258,159 @@ impl Display for HeartbeatConfig {
     }
 }
 
+impl Display for LoginProtectionConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, max_failed_attempts_per_user: {}, max_failed_attempts_per_ip: {}, lockout_duration: {}, max_lockout_duration: {}, reset_after: {} }}",
+            self.enabled,
+            self.max_failed_attempts_per_user,
+            self.max_failed_attempts_per_ip,
+            self.lockout_duration,
+            self.max_lockout_duration,
+            self.reset_after
+        )
+    }
+}
+
+impl Display for RateLimitConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, key: {}, mode: {}, requests_per_second: {}, bytes_per_second: {}, max_delay: {} }}",
+            self.enabled,
+            self.key,
+            self.mode,
+            self.requests_per_second,
+            self.bytes_per_second,
+            self.max_delay
+        )
+    }
+}
+
+impl Display for ClusterConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| node.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            f,
+            "{{ enabled: {}, node_id: {}, address: {}, nodes: [{}], election_timeout: {}, heartbeat_interval: {}, commit_timeout: {} }}",
+            self.enabled,
+            self.node_id,
+            self.address,
+            nodes,
+            self.election_timeout,
+            self.heartbeat_interval,
+            self.commit_timeout
+        )
+    }
+}
+
 impl Display for EncryptionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ enabled: {} }}", self.enabled)
+        write!(
+            f,
+            "{{ enabled: {}, keyring: {} }}",
+            self.enabled, self.keyring
+        )
+    }
+}
+
+impl Display for KeyringConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, path: {}, key_encryption_key: {}, rotation_enabled: {}, rotation_interval: {}, reencryption_enabled: {} }}",
+            self.enabled,
+            self.path,
+            if self.key_encryption_key.is_empty() { "none" } else { "provided" },
+            self.rotation_enabled,
+            self.rotation_interval,
+            self.reencryption_enabled
+        )
+    }
+}
+
+impl Display for PasswordConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ policy: {}, hashing: {} }}",
+            self.policy, self.hashing
+        )
+    }
+}
+
+impl Display for PasswordPolicyConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ min_length: {}, require_uppercase: {}, require_lowercase: {}, require_digit: {}, require_special_character: {}, history: {}, expiry: {} }}",
+            self.min_length,
+            self.require_uppercase,
+            self.require_lowercase,
+            self.require_digit,
+            self.require_special_character,
+            self.history,
+            self.expiry
+        )
+    }
+}
+
+impl Display for PasswordHashingConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ algorithm: {}, bcrypt_cost: {}, argon2_memory_cost: {}, argon2_time_cost: {}, argon2_parallelism: {} }}",
+            self.algorithm,
+            self.bcrypt_cost,
+            self.argon2_memory_cost,
+            self.argon2_time_cost,
+            self.argon2_parallelism
+        )
+    }
+}
+
+impl Display for RecoveryConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ recreate_missing_state: {}, validate_active_segments: {}, validated_batches_count: {} }}",
+            self.recreate_missing_state, self.validate_active_segments, self.validated_batches_count
+        )
+    }
+}
+
+impl Display for QuotasConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, max_streams_per_user: {}, max_topics_per_user: {}, max_partitions_per_user: {}, max_stream_size: {}, throughput: {} }}",
+            self.enabled,
+            self.max_streams_per_user,
+            self.max_topics_per_user,
+            self.max_partitions_per_user,
+            self.max_stream_size,
+            self.throughput
+        )
+    }
+}
+
+impl Display for ThroughputQuotaConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ scope: {}, max_send_bytes_per_second: {}, max_send_messages_per_second: {}, max_poll_bytes_per_second: {}, max_poll_messages_per_second: {} }}",
+            self.scope,
+            self.max_send_bytes_per_second,
+            self.max_send_messages_per_second,
+            self.max_poll_bytes_per_second,
+            self.max_poll_messages_per_second
+        )
     }
 }
 
@@ -244,15 +434,31 @@ impl Display for PartitionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {} }}",
+          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {}, io_backend: {}, io_uring: {} }}",
           self.path,
           self.messages_required_to_save,
           self.enforce_fsync,
-          self.validate_checksum
+          self.validate_checksum,
+          self.io_backend,
+          self.io_uring
       )
     }
 }
 
+impl Display for IoUringConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ direct_io: {}, queue_depth: {}, registered_buffers: {}, registered_buffer_size: {}, group_commit_interval: {} }}",
+            self.direct_io,
+            self.queue_depth,
+            self.registered_buffers,
+            self.registered_buffer_size,
+            self.group_commit_interval
+        )
+    }
+}
+
 impl Display for MessageDeduplicationConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -267,8 +473,8 @@ impl Display for SegmentConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ size_bytes: {}, cache_indexes: {}, message_expiry: {}, archive_expired: {}, server_confirmation: {} }}",
-            self.size, self.cache_indexes, self.message_expiry, self.archive_expired, self.server_confirmation,
+            "{{ size_bytes: {}, cache_indexes: {}, time_index_interval: {}, message_expiry: {}, archive_expired: {}, server_confirmation: {} }}",
+            self.size, self.cache_indexes, self.time_index_interval, self.message_expiry, self.archive_expired, self.server_confirmation,
         )
     }
 }
@@ -290,8 +496,8 @@ impl Display for TcpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, ipv6: {}, tls: {}, socket: {} }}",
-            self.enabled, self.address, self.ipv6, self.tls, self.socket,
+            "{{ enabled: {}, address: {}, ipv6: {}, zero_copy_polling: {}, tls: {}, socket: {} }}",
+            self.enabled, self.address, self.ipv6, self.zero_copy_polling, self.tls, self.socket,
         )
     }
 }
@@ -360,7 +566,7 @@ impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, state: {} }}",
+          "{{ path: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, password: {}, state: {}, recovery: {}, quotas: {}, tiered_storage: {}, auto_create: {} }}",
           self.path,
           self.logging,
           self.cache,
@@ -369,7 +575,38 @@ impl Display for SystemConfig {
           self.partition,
           self.segment,
           self.encryption,
+          self.password,
           self.state,
+          self.recovery,
+          self.quotas,
+          self.tiered_storage,
+          self.auto_create,
       )
     }
 }
+
+impl Display for AutoCreateConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, on_send: {}, on_poll: {}, streams_pattern: {}, topics_pattern: {}, template: {}, partitions_count: {} }}",
+            self.enabled,
+            self.on_send,
+            self.on_poll,
+            self.streams_pattern,
+            self.topics_pattern,
+            self.template,
+            self.partitions_count
+        )
+    }
+}
+
+impl Display for TieredStorageConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, offload_after: {}, path: {}, cache_size: {} }}",
+            self.enabled, self.offload_after, self.path, self.cache_size
+        )
+    }
+}
@@ -1,6 +1,7 @@
 pub mod server;
 pub mod system;
 
+pub mod cluster;
 pub mod http;
 pub mod quic;
 pub mod tcp;
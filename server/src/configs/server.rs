@@ -1,4 +1,5 @@
 use crate::archiver::ArchiverKindType;
+use crate::configs::cluster::ClusterConfig;
 use crate::configs::config_provider::ConfigProviderKind;
 use crate::configs::http::HttpConfig;
 use crate::configs::quic::QuicConfig;
@@ -8,6 +9,7 @@ use crate::configs::COMPONENT;
 use crate::server_error::ConfigError;
 use derive_more::Display;
 use error_set::ErrContext;
+use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use iggy::validatable::Validatable;
 use serde::{Deserialize, Serialize};
@@ -21,6 +23,9 @@ pub struct ServerConfig {
     pub data_maintenance: DataMaintenanceConfig,
     pub message_saver: MessageSaverConfig,
     pub personal_access_token: PersonalAccessTokenConfig,
+    pub login_protection: LoginProtectionConfig,
+    pub rate_limit: RateLimitConfig,
+    pub cluster: ClusterConfig,
     pub heartbeat: HeartbeatConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
@@ -43,6 +48,8 @@ pub struct ArchiverConfig {
     pub kind: ArchiverKindType,
     pub disk: Option<DiskArchiverConfig>,
     pub s3: Option<S3ArchiverConfig>,
+    pub azure: Option<AzureArchiverConfig>,
+    pub gcs: Option<GcsArchiverConfig>,
 }
 
 #[serde_as]
@@ -61,6 +68,10 @@ pub struct StateMaintenanceConfig {
     pub overwrite: bool,
     #[serde_as(as = "DisplayFromStr")]
     pub interval: IggyDuration,
+    pub compaction_enabled: bool,
+    pub compaction_min_entries: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub compaction_interval: IggyDuration,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -78,6 +89,20 @@ pub struct S3ArchiverConfig {
     pub tmp_upload_dir: String,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AzureArchiverConfig {
+    pub account: String,
+    pub access_key: String,
+    pub container: String,
+    pub endpoint: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GcsArchiverConfig {
+    pub bucket: String,
+    pub service_account_path: Option<String>,
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct MessageSaverConfig {
@@ -101,6 +126,78 @@ pub struct PersonalAccessTokenCleanerConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LoginProtectionConfig {
+    pub enabled: bool,
+    pub max_failed_attempts_per_user: u32,
+    pub max_failed_attempts_per_ip: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub lockout_duration: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_lockout_duration: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub reset_after: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RateLimitConfig {
+    pub enabled: bool,
+    pub key: RateLimitKeyKind,
+    pub mode: RateLimitMode,
+    pub requests_per_second: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub bytes_per_second: IggyByteSize,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_delay: IggyDuration,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum RateLimitKeyKind {
+    #[default]
+    #[display("client")]
+    Client,
+    #[display("user")]
+    User,
+    #[display("ip")]
+    Ip,
+}
+
+impl FromStr for RateLimitKeyKind {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "client" => Ok(RateLimitKeyKind::Client),
+            "user" => Ok(RateLimitKeyKind::User),
+            "ip" => Ok(RateLimitKeyKind::Ip),
+            _ => Err(format!("Unknown rate limit key: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum RateLimitMode {
+    #[default]
+    #[display("reject")]
+    Reject,
+    #[display("delay")]
+    Delay,
+}
+
+impl FromStr for RateLimitMode {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "reject" => Ok(RateLimitMode::Reject),
+            "delay" => Ok(RateLimitMode::Delay),
+            _ => Err(format!("Unknown rate limit mode: {}", s)),
+        }
+    }
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct HeartbeatConfig {
@@ -1,4 +1,5 @@
 use crate::configs::resource_quota::MemoryResourceQuota;
+use derive_more::Display;
 use iggy::confirmation::Confirmation;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
@@ -9,6 +10,7 @@ use iggy::{
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::str::FromStr;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SystemConfig {
@@ -23,9 +25,13 @@ pub struct SystemConfig {
     pub partition: PartitionConfig,
     pub segment: SegmentConfig,
     pub encryption: EncryptionConfig,
+    pub password: PasswordConfig,
     pub compression: CompressionConfig,
     pub message_deduplication: MessageDeduplicationConfig,
     pub recovery: RecoveryConfig,
+    pub quotas: QuotasConfig,
+    pub tiered_storage: TieredStorageConfig,
+    pub auto_create: AutoCreateConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -77,6 +83,113 @@ pub struct CacheConfig {
 pub struct EncryptionConfig {
     pub enabled: bool,
     pub key: String,
+    pub keyring: KeyringConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KeyringConfig {
+    pub enabled: bool,
+    pub path: String,
+    pub key_encryption_key: String,
+    pub rotation_enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub rotation_interval: IggyDuration,
+    pub reencryption_enabled: bool,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct PasswordConfig {
+    pub policy: PasswordPolicyConfig,
+    pub hashing: PasswordHashingConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PasswordPolicyConfig {
+    pub min_length: u32,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    pub require_special_character: bool,
+    pub history: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub expiry: IggyExpiry,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PasswordHashingConfig {
+    pub algorithm: PasswordHashingAlgorithm,
+    pub bcrypt_cost: u32,
+    pub argon2_memory_cost: u32,
+    pub argon2_time_cost: u32,
+    pub argon2_parallelism: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum PasswordHashingAlgorithm {
+    #[default]
+    #[display("bcrypt")]
+    Bcrypt,
+    #[display("argon2id")]
+    Argon2id,
+}
+
+impl FromStr for PasswordHashingAlgorithm {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "bcrypt" => Ok(PasswordHashingAlgorithm::Bcrypt),
+            "argon2id" => Ok(PasswordHashingAlgorithm::Argon2id),
+            _ => Err(format!("Unknown password hashing algorithm: {}", s)),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct QuotasConfig {
+    pub enabled: bool,
+    pub max_streams_per_user: u32,
+    pub max_topics_per_user: u32,
+    pub max_partitions_per_user: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_stream_size: IggyByteSize,
+    pub throughput: ThroughputQuotaConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ThroughputQuotaConfig {
+    pub scope: ThroughputQuotaScope,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_send_bytes_per_second: IggyByteSize,
+    pub max_send_messages_per_second: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_poll_bytes_per_second: IggyByteSize,
+    pub max_poll_messages_per_second: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ThroughputQuotaScope {
+    #[default]
+    #[display("user")]
+    User,
+    #[display("client")]
+    Client,
+}
+
+impl FromStr for ThroughputQuotaScope {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "user" => Ok(ThroughputQuotaScope::User),
+            "client" => Ok(ThroughputQuotaScope::Client),
+            _ => Err(format!("Unknown throughput quota scope: {}", s)),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -99,6 +212,40 @@ pub struct PartitionConfig {
     pub messages_required_to_save: u32,
     pub enforce_fsync: bool,
     pub validate_checksum: bool,
+    pub io_backend: IoBackend,
+    pub io_uring: IoUringConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum IoBackend {
+    #[default]
+    #[display("tokio")]
+    Tokio,
+    #[display("io_uring")]
+    IoUring,
+}
+
+impl FromStr for IoBackend {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "tokio" => Ok(IoBackend::Tokio),
+            "io_uring" => Ok(IoBackend::IoUring),
+            _ => Err(format!("Unknown I/O backend: {}", s)),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IoUringConfig {
+    pub direct_io: bool,
+    pub queue_depth: u32,
+    pub registered_buffers: u32,
+    pub registered_buffer_size: IggyByteSize,
+    #[serde_as(as = "DisplayFromStr")]
+    pub group_commit_interval: IggyDuration,
 }
 
 #[serde_as]
@@ -113,13 +260,17 @@ pub struct MessageDeduplicationConfig {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct RecoveryConfig {
     pub recreate_missing_state: bool,
+    pub validate_active_segments: bool,
+    pub validated_batches_count: u32,
 }
 
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SegmentConfig {
     pub size: IggyByteSize,
-    pub cache_indexes: bool,
+    pub cache_indexes: IndexCacheMode,
+    #[serde_as(as = "DisplayFromStr")]
+    pub time_index_interval: IggyByteSize,
     #[serde_as(as = "DisplayFromStr")]
     pub message_expiry: IggyExpiry,
     pub archive_expired: bool,
@@ -127,6 +278,51 @@ pub struct SegmentConfig {
     pub server_confirmation: Confirmation,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexCacheMode {
+    #[default]
+    #[display("eager")]
+    Eager,
+    #[display("mmap")]
+    Mmap,
+    #[display("on_demand")]
+    OnDemand,
+}
+
+impl FromStr for IndexCacheMode {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "eager" => Ok(IndexCacheMode::Eager),
+            "mmap" => Ok(IndexCacheMode::Mmap),
+            "on_demand" => Ok(IndexCacheMode::OnDemand),
+            _ => Err(format!("Unknown index cache mode: {}", s)),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TieredStorageConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub offload_after: IggyDuration,
+    pub path: String,
+    pub cache_size: IggyByteSize,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AutoCreateConfig {
+    pub enabled: bool,
+    pub on_send: bool,
+    pub on_poll: bool,
+    pub streams_pattern: String,
+    pub topics_pattern: String,
+    pub template: String,
+    pub partitions_count: u32,
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct StateConfig {
@@ -149,6 +345,10 @@ impl SystemConfig {
         format!("{}/log", self.get_state_path())
     }
 
+    pub fn get_state_snapshot_path(&self) -> String {
+        format!("{}/snapshot", self.get_state_path())
+    }
+
     pub fn get_state_info_path(&self) -> String {
         format!("{}/info", self.get_state_path())
     }
@@ -168,6 +368,18 @@ impl SystemConfig {
         )
     }
 
+    pub fn get_keyring_path(&self) -> String {
+        format!(
+            "{}/{}",
+            self.get_system_path(),
+            self.encryption.keyring.path
+        )
+    }
+
+    pub fn get_tiered_storage_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.tiered_storage.path)
+    }
+
     pub fn get_runtime_path(&self) -> String {
         format!("{}/{}", self.get_system_path(), self.runtime.path)
     }
@@ -8,6 +8,7 @@ pub struct TcpConfig {
     pub enabled: bool,
     pub address: String,
     pub ipv6: bool,
+    pub zero_copy_polling: bool,
     pub tls: TcpTlsConfig,
     pub socket: TcpSocketConfig,
 }
@@ -6,19 +6,30 @@ use super::server::{
 };
 use super::system::CompressionConfig;
 use crate::archiver::ArchiverKindType;
-use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
-use crate::configs::system::{CacheConfig, SegmentConfig};
+use crate::configs::cluster::ClusterConfig;
+use crate::configs::server::{
+    LoginProtectionConfig, PersonalAccessTokenConfig, RateLimitConfig, RateLimitMode, ServerConfig,
+};
+use crate::configs::system::{
+    AutoCreateConfig, CacheConfig, EncryptionConfig, IoBackend, PartitionConfig, PasswordConfig,
+    PasswordHashingAlgorithm, SegmentConfig, TieredStorageConfig,
+};
 use crate::configs::COMPONENT;
 use crate::server_error::ConfigError;
 use crate::streaming::segments::*;
+use crate::streaming::utils::crypto;
 use error_set::ErrContext;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::users::defaults::{MAX_PASSWORD_LENGTH, MIN_PASSWORD_LENGTH};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
 use iggy::validatable::Validatable;
 use sysinfo::{Pid, ProcessesToUpdate, System};
 
+const MIN_BCRYPT_COST: u32 = 4;
+const MAX_BCRYPT_COST: u32 = 31;
+
 impl Validatable<ConfigError> for ServerConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         self.data_maintenance
@@ -31,12 +42,64 @@ impl Validatable<ConfigError> for ServerConfig {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to validate personal access token config")
             })?;
+        self.login_protection
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate login protection config")
+            })?;
+        self.rate_limit.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate rate limit config")
+        })?;
+        self.cluster.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate cluster config")
+        })?;
+        // The replicated log can't be compacted, as the other nodes might still need its entries.
+        if self.cluster.enabled && self.data_maintenance.state.compaction_enabled {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+        // The offloaded segments can be only fetched back from the archiver they were archived with.
+        if self.system.tiered_storage.enabled
+            && (!self.data_maintenance.archiver.enabled
+                || !self.data_maintenance.messages.archiver_enabled)
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+        self.system
+            .tiered_storage
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate tiered storage config")
+            })?;
+        self.system
+            .auto_create
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate auto create config")
+            })?;
+        self.system
+            .encryption
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate encryption config")
+            })?;
+        self.system
+            .partition
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate partition config")
+            })?;
         self.system.segment.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate segment config")
         })?;
         self.system.cache.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate cache config")
         })?;
+        self.system
+            .password
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate password config")
+            })?;
         self.system
             .compression
             .validate()
@@ -150,6 +213,36 @@ impl Validatable<ConfigError> for CacheConfig {
     }
 }
 
+impl Validatable<ConfigError> for PartitionConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.io_backend != IoBackend::IoUring {
+            return Ok(());
+        }
+
+        if !cfg!(target_os = "linux") {
+            println!("Partition configuration -> io_uring I/O backend is available only on Linux.");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        let io_uring = &self.io_uring;
+        if !io_uring.queue_depth.is_power_of_two() || io_uring.registered_buffers == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        // The registered buffers are also used for the direct I/O, which requires the block aligned writes.
+        let buffer_size = io_uring.registered_buffer_size.as_bytes_u64();
+        if buffer_size == 0 || !buffer_size.is_multiple_of(4096) {
+            println!(
+                "Partition configuration -> registered buffer size: {} must be a multiple of 4 KiB.",
+                io_uring.registered_buffer_size
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigError> for SegmentConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         if self.size > SEGMENT_MAX_SIZE_BYTES {
@@ -232,6 +325,29 @@ impl Validatable<ConfigError> for ArchiverConfig {
                 }
                 Ok(())
             }
+            ArchiverKindType::Azure => {
+                let Some(azure) = self.azure.as_ref() else {
+                    return Err(ConfigError::InvalidConfiguration);
+                };
+
+                if azure.account.is_empty()
+                    || azure.access_key.is_empty()
+                    || azure.container.is_empty()
+                {
+                    return Err(ConfigError::InvalidConfiguration);
+                }
+                Ok(())
+            }
+            ArchiverKindType::Gcs => {
+                let Some(gcs) = self.gcs.as_ref() else {
+                    return Err(ConfigError::InvalidConfiguration);
+                };
+
+                if gcs.bucket.is_empty() {
+                    return Err(ConfigError::InvalidConfiguration);
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -246,12 +362,76 @@ impl Validatable<ConfigError> for MessagesMaintenanceConfig {
     }
 }
 
+impl Validatable<ConfigError> for EncryptionConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        // Without the keyring, the single static key is used to encrypt all the data.
+        if !self.keyring.enabled {
+            if self.key.is_empty() {
+                return Err(ConfigError::InvalidConfiguration);
+            }
+            return Ok(());
+        }
+
+        if self.keyring.path.is_empty() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.keyring.rotation_enabled && self.keyring.rotation_interval.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.keyring.reencryption_enabled && !self.keyring.rotation_enabled {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for TieredStorageConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.path.is_empty() || self.cache_size.as_bytes_u64() == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for AutoCreateConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.template.is_empty() && self.partitions_count == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigError> for StateMaintenanceConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         if self.archiver_enabled && self.interval.is_zero() {
             return Err(ConfigError::InvalidConfiguration);
         }
 
+        if self.compaction_enabled
+            && (self.compaction_interval.is_zero() || self.compaction_min_entries == 0)
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
         Ok(())
     }
 }
@@ -269,3 +449,100 @@ impl Validatable<ConfigError> for PersonalAccessTokenConfig {
         Ok(())
     }
 }
+
+impl Validatable<ConfigError> for LoginProtectionConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.max_failed_attempts_per_user == 0 || self.max_failed_attempts_per_ip == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.lockout_duration.is_zero()
+            || self.max_lockout_duration.as_micros() < self.lockout_duration.as_micros()
+            || self.reset_after.is_zero()
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ClusterConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.node_id == 0 || self.secret.is_empty() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        let mut node_ids = self.nodes.iter().map(|node| node.id).collect::<Vec<_>>();
+        node_ids.sort_unstable();
+        node_ids.dedup();
+        if node_ids.len() != self.nodes.len() || node_ids.contains(&0) {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        // The intervals are shorter than a second, so they're compared in microseconds.
+        if self.heartbeat_interval.as_micros() == 0
+            || self.heartbeat_interval.as_micros() * 2 >= self.election_timeout.as_micros()
+            || self.commit_timeout.as_micros() == 0
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for RateLimitConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.requests_per_second == 0 && self.bytes_per_second.as_bytes_u64() == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.mode == RateLimitMode::Delay && self.max_delay.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for PasswordConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if (self.policy.min_length as usize) < MIN_PASSWORD_LENGTH
+            || self.policy.min_length as usize > MAX_PASSWORD_LENGTH
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if let IggyExpiry::ServerDefault = self.policy.expiry {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        match self.hashing.algorithm {
+            PasswordHashingAlgorithm::Bcrypt => {
+                if !(MIN_BCRYPT_COST..=MAX_BCRYPT_COST).contains(&self.hashing.bcrypt_cost) {
+                    return Err(ConfigError::InvalidConfiguration);
+                }
+            }
+            PasswordHashingAlgorithm::Argon2id => {
+                if !crypto::is_valid_argon2_config(&self.hashing) {
+                    return Err(ConfigError::InvalidConfiguration);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
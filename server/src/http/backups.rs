@@ -0,0 +1,112 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use crate::streaming::session::Session;
+use axum::extract::State;
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy::backups::create_backup::CreateBackup;
+use iggy::backups::restore_backup::RestoreBackup;
+use iggy::models::backup::BackupInfo;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/backups", get(get_backups).post(create_backup))
+        .route("/backups/restore", post(restore_backup))
+        .with_state(state)
+}
+
+async fn get_backups(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<BackupInfo>>, CustomError> {
+    let system = state.system.read().await;
+    let backups = system
+        .get_backups(&Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get backups, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(backups))
+}
+
+#[instrument(skip_all, name = "trace_create_backup", fields(iggy_user_id = identity.user_id))]
+async fn create_backup(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreateBackup>,
+) -> Result<Json<BackupInfo>, CustomError> {
+    command.validate()?;
+
+    // The files are captured while the system is locked, and copied once the lock is downgraded.
+    let system = state.system.write().await;
+    let backup = system
+        .prepare_backup(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            command.incremental,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to prepare backup, user ID: {}",
+                identity.user_id
+            )
+        })?;
+
+    let system = system.downgrade();
+    let backup = system
+        .create_backup(backup)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create backup, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(backup))
+}
+
+#[instrument(skip_all, name = "trace_restore_backup", fields(iggy_user_id = identity.user_id))]
+async fn restore_backup(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<RestoreBackup>,
+) -> Result<Json<BackupInfo>, CustomError> {
+    command.validate()?;
+
+    let system = state.system.read().await;
+    let backup = system
+        .restore_backup(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            command.timestamp,
+            &command.target,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to restore backup, target: {}, user ID: {}",
+                command.target, identity.user_id
+            )
+        })?;
+    Ok(Json(backup))
+}
@@ -0,0 +1,144 @@
+use crate::cluster::{ClusterInfo, ClusterMember};
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use crate::state::command::EntryCommand;
+use crate::state::models::{AddClusterNode, RemoveClusterNode};
+use crate::streaming::session::Session;
+use axum::body::Body;
+use axum::extract::{Path, State};
+use axum::http::{Method, Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get, post};
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+
+/// The paths which don't change the metadata state, thus can be handled by any node of the cluster.
+const LOCAL_PATHS: [&str; 7] = [
+    "/users/login",
+    "/users/logout",
+    "/users/refresh-token",
+    "/personal-access-tokens/login",
+    "/snapshot",
+    "/backups",
+    "/backups/restore",
+];
+const LOCAL_PATH_SEGMENTS: [&str; 3] = ["messages", "consumer-offsets", "unlock"];
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/cluster", get(get_cluster))
+        .route("/cluster/nodes", post(add_cluster_node))
+        .route("/cluster/nodes/{node_id}", delete(remove_cluster_node))
+        .with_state(state)
+}
+
+/// Rejects the requests changing the metadata state, unless this node is the cluster leader.
+pub async fn ensure_cluster_leader(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if !changes_state(request.method(), request.uri().path()) {
+        return Ok(next.run(request).await);
+    }
+
+    if let Err(error) = state.system.read().await.ensure_cluster_leader() {
+        return Ok(CustomError::from(error).into_response());
+    }
+
+    Ok(next.run(request).await)
+}
+
+fn changes_state(method: &Method, path: &str) -> bool {
+    if method != Method::POST && method != Method::PUT && method != Method::DELETE {
+        return false;
+    }
+
+    let path = path.trim_end_matches('/');
+    !LOCAL_PATHS.contains(&path)
+        && !path
+            .split('/')
+            .any(|segment| LOCAL_PATH_SEGMENTS.contains(&segment))
+}
+
+async fn get_cluster(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<ClusterInfo>, CustomError> {
+    let system = state.system.read().await;
+    system.permissioner.get_cluster(identity.permissions_id)?;
+    let Some(node) = system.cluster_node() else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(node.get_info().await))
+}
+
+async fn add_cluster_node(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(member): Json<ClusterMember>,
+) -> Result<StatusCode, CustomError> {
+    let command = AddClusterNode {
+        node_id: member.id,
+        address: member.address,
+    };
+    command.validate()?;
+    let Some(cluster_node) = state.system.cluster_node().await else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    let node_id = command.node_id;
+    state
+        .system
+        .replicate(
+            &cluster_node,
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            EntryCommand::AddClusterNode(command),
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to add cluster node with ID: {node_id}")
+        })?;
+    Ok(StatusCode::CREATED)
+}
+
+async fn remove_cluster_node(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(node_id): Path<u32>,
+) -> Result<StatusCode, CustomError> {
+    let command = RemoveClusterNode { node_id };
+    command.validate()?;
+    let Some(cluster_node) = state.system.cluster_node().await else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    state
+        .system
+        .replicate(
+            &cluster_node,
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            EntryCommand::RemoveClusterNode(command),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to remove cluster node with ID: {node_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
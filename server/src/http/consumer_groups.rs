@@ -41,7 +41,11 @@ async fn get_consumer_group(
     let identifier_group_id = Identifier::from_str_value(&group_id)?;
     let system = state.system.read().await;
     let Ok(consumer_group) = system.get_consumer_group(
-        &Session::stateless(identity.user_id, identity.ip_address),
+        &Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ),
         &identifier_stream_id,
         &identifier_topic_id,
         &identifier_group_id,
@@ -66,7 +70,11 @@ async fn get_consumer_groups(
     let topic_id = Identifier::from_str_value(&topic_id)?;
     let system = state.system.read().await;
     let consumer_groups = system.get_consumer_groups(
-        &Session::stateless(identity.user_id, identity.ip_address),
+        &Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ),
         &stream_id,
         &topic_id,
     )?;
@@ -84,10 +92,39 @@ async fn create_consumer_group(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        let group_id = command.group_id;
+        let name = command.name.clone();
+        let group_stream_id = command.stream_id.clone();
+        let group_topic_id = command.topic_id.clone();
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::CreateConsumerGroup(command))
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to replicate create consumer group, stream ID: {}, topic ID: {}, group ID: {:?}", stream_id, topic_id, group_id))?;
+        let system = state.system.read().await;
+        let consumer_group = system
+            .get_stream(&group_stream_id)?
+            .get_topic(&group_topic_id)?
+            .get_consumer_group(&Identifier::named(&name)?)?
+            .read()
+            .await;
+        let consumer_group_details = mapper::map_consumer_group(&consumer_group).await;
+        return Ok((StatusCode::CREATED, Json(consumer_group_details)));
+    }
     let mut system = state.system.write().await;
     let consumer_group = system
             .create_consumer_group(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
                 &command.stream_id,
                 &command.topic_id,
                 command.group_id,
@@ -118,10 +155,38 @@ async fn delete_consumer_group(
     let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
     let identifier_group_id = Identifier::from_str_value(&group_id)?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(
+                &cluster_node,
+                &session,
+                EntryCommand::DeleteConsumerGroup(DeleteConsumerGroup {
+                    stream_id: identifier_stream_id,
+                    topic_id: identifier_topic_id,
+                    group_id: identifier_group_id,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete consumer group with ID: {group_id} for topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let mut system = state.system.write().await;
     system
             .delete_consumer_group(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
                 &identifier_stream_id,
                 &identifier_topic_id,
                 &identifier_group_id,
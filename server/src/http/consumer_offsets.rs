@@ -43,7 +43,11 @@ async fn get_consumer_offset(
     let system = state.system.read().await;
     let Ok(offset) = system
         .get_consumer_offset(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             &consumer,
             &query.0.stream_id,
             &query.0.topic_id,
@@ -74,7 +78,11 @@ async fn store_consumer_offset(
     let system = state.system.read().await;
     system
         .store_consumer_offset(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             consumer,
             &command.0.stream_id,
             &command.0.topic_id,
@@ -96,7 +104,11 @@ async fn delete_consumer_offset(
     let system = state.system.read().await;
     system
         .delete_consumer_offset(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             consumer,
             &query.stream_id,
             &query.topic_id,
@@ -38,11 +38,26 @@ impl IntoResponse for CustomError {
                     IggyError::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerOffsetNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::BackupNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::TopicTemplateNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::SchemaNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::TopicSchemaVersionNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::Unauthenticated => StatusCode::UNAUTHORIZED,
                     IggyError::AccessTokenMissing => StatusCode::UNAUTHORIZED,
                     IggyError::InvalidAccessToken => StatusCode::UNAUTHORIZED,
                     IggyError::InvalidPersonalAccessToken => StatusCode::UNAUTHORIZED,
                     IggyError::Unauthorized => StatusCode::FORBIDDEN,
+                    IggyError::TooManyFailedLoginAttempts(_) => StatusCode::TOO_MANY_REQUESTS,
+                    IggyError::SendThroughputQuotaExceeded => StatusCode::TOO_MANY_REQUESTS,
+                    IggyError::PollThroughputQuotaExceeded => StatusCode::TOO_MANY_REQUESTS,
+                    IggyError::RateLimitExceeded(_) => StatusCode::TOO_MANY_REQUESTS,
+                    IggyError::NotClusterLeader(_) => StatusCode::MISDIRECTED_REQUEST,
+                    IggyError::ClusterCommitTimeout => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::ClusterNodeNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::StreamsQuotaExceeded(_, _) => StatusCode::FORBIDDEN,
+                    IggyError::TopicsQuotaExceeded(_, _) => StatusCode::FORBIDDEN,
+                    IggyError::PartitionsQuotaExceeded(_, _) => StatusCode::FORBIDDEN,
+                    IggyError::StreamSizeQuotaExceeded(_, _) => StatusCode::FORBIDDEN,
                     _ => StatusCode::BAD_REQUEST,
                 };
                 (status_code, Json(ErrorResponse::from_error(error)))
@@ -89,6 +104,8 @@ impl ErrorResponse {
                 IggyError::ConsumerGroupNameAlreadyExists(_, _) => Some("name".to_string()),
                 IggyError::UserAlreadyExists => Some("username".to_string()),
                 IggyError::PersonalAccessTokenAlreadyExists(_, _) => Some("name".to_string()),
+                IggyError::InvalidTopicTemplateName => Some("name".to_string()),
+                IggyError::TopicTemplateAlreadyExists(_) => Some("name".to_string()),
                 _ => None,
             },
         }
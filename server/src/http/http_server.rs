@@ -1,9 +1,11 @@
 use crate::configs::http::{HttpConfig, HttpCorsConfig};
+use crate::http::cluster::ensure_cluster_leader;
 use crate::http::diagnostics::request_diagnostics;
 use crate::http::jwt::cleaner::start_expired_tokens_cleaner;
 use crate::http::jwt::jwt_manager::JwtManager;
 use crate::http::jwt::middleware::jwt_auth;
 use crate::http::metrics::metrics;
+use crate::http::rate_limit::rate_limit;
 use crate::http::shared::AppState;
 use crate::http::*;
 use crate::streaming::systems::system::SharedSystem;
@@ -33,13 +35,25 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .merge(users::router(app_state.clone()))
         .merge(streams::router(app_state.clone()))
         .merge(topics::router(app_state.clone()))
+        .merge(topic_templates::router(app_state.clone()))
+        .merge(schemas::router(app_state.clone()))
         .merge(consumer_groups::router(app_state.clone()))
         .merge(consumer_offsets::router(app_state.clone()))
         .merge(partitions::router(app_state.clone()))
         .merge(messages::router(app_state.clone()))
+        .merge(backups::router(app_state.clone()))
+        .merge(cluster::router(app_state.clone()))
+        .layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            ensure_cluster_leader,
+        ))
         .layer(DefaultBodyLimit::max(
             config.max_request_size.as_bytes_u64() as usize,
         ))
+        .layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            rate_limit,
+        ))
         .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
 
     if config.cors.enabled {
@@ -7,6 +7,8 @@ pub struct Identity {
     pub token_id: String,
     pub token_expiry: u64,
     pub user_id: UserId,
+    /// The ID used for the permission checks, differs from the user ID only when authenticated with the scoped personal access token.
+    pub permissions_id: UserId,
     pub ip_address: SocketAddr,
 }
 
@@ -19,6 +21,9 @@ pub struct JwtClaims {
     pub iat: u64,
     pub exp: u64,
     pub nbf: u64,
+    /// The name of the personal access token used to obtain the JWT, present only for the scoped tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pat: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
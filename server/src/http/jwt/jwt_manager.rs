@@ -151,7 +151,13 @@ impl JwtManager {
         Ok(())
     }
 
-    pub fn generate(&self, user_id: UserId) -> Result<GeneratedToken, IggyError> {
+    /// Generates the access token, the name of the scoped personal access token is embedded,
+    /// so that the permissions of the token can be resolved for each request.
+    pub fn generate(
+        &self,
+        user_id: UserId,
+        personal_access_token: Option<String>,
+    ) -> Result<GeneratedToken, IggyError> {
         let header = Header::new(self.issuer.algorithm);
         let now = IggyTimestamp::now().to_secs();
         let iat = now;
@@ -170,6 +176,7 @@ impl JwtManager {
             iat,
             exp,
             nbf,
+            pat: personal_access_token,
         };
 
         let access_token = encode::<JwtClaims>(&header, &claims, &self.issuer.key);
@@ -215,7 +222,7 @@ impl JwtManager {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to save revoked access token: {id}")
             })?;
-        self.generate(jwt_claims.claims.sub)
+        self.generate(jwt_claims.claims.sub, jwt_claims.claims.pat)
     }
 
     pub fn decode(
@@ -72,11 +72,27 @@ pub async fn jwt_auth(
         return Err(StatusCode::UNAUTHORIZED);
     }
 
+    let user_id = jwt_claims.claims.sub;
+    let permissions_id = match &jwt_claims.claims.pat {
+        // The scoped token might have been deleted or expired in the meantime.
+        Some(name) => state
+            .system
+            .read()
+            .await
+            .get_personal_access_token_permissions_id(user_id, name)
+            .ok_or(UNAUTHORIZED)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - scoped personal access token: {name} for user with ID: {user_id} no longer exists")
+            })?,
+        None => user_id,
+    };
+
     let request_details = request.extensions().get::<RequestDetails>().unwrap();
     let identity = Identity {
         token_id: jwt_claims.claims.jti,
         token_expiry: jwt_claims.claims.exp,
-        user_id: jwt_claims.claims.sub,
+        user_id,
+        permissions_id,
         ip_address: request_details.ip_address,
     };
     request.extensions_mut().insert(identity);
@@ -13,7 +13,7 @@ use iggy::models::identity_info::{IdentityInfo, TokenInfo};
 use iggy::models::personal_access_token::PersonalAccessTokenInfo;
 use iggy::models::stream::StreamDetails;
 use iggy::models::topic::TopicDetails;
-use iggy::models::user_info::{UserInfo, UserInfoDetails};
+use iggy::models::user_info::{UserInfo, UserInfoDetails, UserUsage};
 use iggy::utils::sizeable::Sizeable;
 use tokio::sync::RwLock;
 
@@ -102,13 +102,14 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
     topic_details
 }
 
-pub fn map_user(user: &User) -> UserInfoDetails {
+pub fn map_user(user: &User, usage: Option<UserUsage>) -> UserInfoDetails {
     UserInfoDetails {
         id: user.id,
         username: user.username.clone(),
         created_at: user.created_at,
         status: user.status,
         permissions: user.permissions.clone(),
+        usage,
     }
 }
 
@@ -135,6 +136,7 @@ pub fn map_personal_access_tokens(
         let personal_access_token = PersonalAccessTokenInfo {
             name: personal_access_token.name.clone(),
             expiry_at: personal_access_token.expiry_at,
+            permissions: personal_access_token.permissions.clone(),
         };
         personal_access_tokens_data.push(personal_access_token);
     }
@@ -3,6 +3,7 @@ use crate::http::jwt::json_web_token::Identity;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
 use crate::streaming::session::Session;
+use crate::streaming::systems::auto_create::AutoCreateTrigger;
 use crate::streaming::systems::messages::PollingArgs;
 use crate::streaming::utils::random_id;
 use axum::extract::{Path, Query, State};
@@ -43,10 +44,24 @@ async fn poll_messages(
     query.validate()?;
 
     let consumer = Consumer::new(query.0.consumer.id);
+    let session = Session::stateless(
+        identity.user_id,
+        identity.permissions_id,
+        identity.ip_address,
+    );
+    state
+        .system
+        .auto_create(
+            &session,
+            &query.0.stream_id,
+            &query.0.topic_id,
+            AutoCreateTrigger::PollMessages,
+        )
+        .await?;
     let system = state.system.read().await;
     let polled_messages = system
         .poll_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &session,
             &consumer,
             &query.0.stream_id,
             &query.0.topic_id,
@@ -83,11 +98,25 @@ async fn send_messages(
     let command_stream_id = command.stream_id;
     let command_topic_id = command.topic_id;
     let partitioning = command.partitioning;
+    let session = Session::stateless(
+        identity.user_id,
+        identity.permissions_id,
+        identity.ip_address,
+    );
+    state
+        .system
+        .auto_create(
+            &session,
+            &command_stream_id,
+            &command_topic_id,
+            AutoCreateTrigger::SendMessages,
+        )
+        .await?;
     let system = state.system.read().await;
     // TODO(haze): Add confirmation level after testing is complete
     system
         .append_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &session,
             command_stream_id,
             command_topic_id,
             partitioning,
@@ -115,7 +144,11 @@ async fn flush_unsaved_buffer(
     let system = state.system.read().await;
     system
         .flush_unsaved_buffer(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             stream_id,
             topic_id,
             partition_id,
@@ -1,3 +1,5 @@
+pub mod backups;
+pub mod cluster;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod diagnostics;
@@ -9,9 +11,12 @@ pub mod messages;
 pub mod metrics;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod rate_limit;
+pub mod schemas;
 mod shared;
 pub mod streams;
 pub mod system;
+pub mod topic_templates;
 pub mod topics;
 pub mod users;
 
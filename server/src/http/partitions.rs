@@ -6,12 +6,15 @@ use crate::state::command::EntryCommand;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::post;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
+use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::models::partitions_merge::PartitionsMergeInfo;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::merge_partitions::MergePartitions;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 use tracing::instrument;
@@ -22,6 +25,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/partitions",
             post(create_partitions).delete(delete_partitions),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/partitions/merge",
+            get(get_partitions_merge).post(merge_partitions),
+        )
         .with_state(state)
 }
 
@@ -36,10 +43,30 @@ async fn create_partitions(
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::CreatePartitions(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate create partitions, stream ID: {stream_id}, topic ID: {topic_id}")
+            })?;
+        return Ok(StatusCode::CREATED);
+    }
+
     let mut system = state.system.write().await;
     system
             .create_partitions(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
                 &command.stream_id,
                 &command.topic_id,
                 command.partitions_count,
@@ -77,10 +104,30 @@ async fn delete_partitions(
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::DeletePartitions(query.0))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete partitions for topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let mut system = state.system.write().await;
     system
             .delete_partitions(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
                 &query.stream_id.clone(),
                 &query.topic_id.clone(),
                 query.partitions_count,
@@ -113,3 +160,78 @@ async fn delete_partitions(
         })?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn get_partitions_merge(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<Json<PartitionsMergeInfo>, CustomError> {
+    let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
+    let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read().await;
+    let partitions_merge = system
+        .get_partitions_merge(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &identifier_stream_id,
+            &identifier_topic_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get partitions merge, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+    let Some(partitions_merge) = partitions_merge else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(partitions_merge))
+}
+
+#[instrument(skip_all, name = "trace_merge_partitions", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn merge_partitions(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<MergePartitions>,
+) -> Result<Json<PartitionsMergeInfo>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    // The messages of the merged partitions are stored locally, so they can't be moved consistently on every node.
+    if state.system.cluster_node().await.is_some() {
+        return Err(IggyError::FeatureUnavailable.into());
+    }
+
+    let merge = state
+        .system
+        .write()
+        .await
+        .merge_partitions(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &command.stream_id,
+            &command.topic_id,
+            command.partitions_count,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to merge partitions, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+
+    let partitions_merge = merge.get_info().await;
+    tokio::spawn(merge.run(state.system.clone()));
+    Ok(Json(partitions_merge))
+}
@@ -2,7 +2,7 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_access_token_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::http::COMPONENT;
 use crate::state::command::EntryCommand;
 use crate::state::models::CreatePersonalAccessTokenWithHash;
@@ -45,7 +45,11 @@ async fn get_personal_access_tokens(
 ) -> Result<Json<Vec<PersonalAccessTokenInfo>>, CustomError> {
     let system = state.system.read().await;
     let personal_access_tokens = system
-        .get_personal_access_tokens(&Session::stateless(identity.user_id, identity.ip_address))
+        .get_personal_access_tokens(&Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ))
         .await
         .with_error_context(|error| {
             format!(
@@ -65,12 +69,63 @@ async fn create_personal_access_token(
 ) -> Result<Json<RawPersonalAccessToken>, CustomError> {
     command.validate()?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .read()
+            .await
+            .validate_personal_access_token_creation(
+                &Session::stateless(
+                    identity.user_id,
+                    identity.permissions_id,
+                    identity.ip_address,
+                ),
+                &command.name,
+            )
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to create personal access token, user ID: {}",
+                    identity.user_id
+                )
+            })?;
+        let token = PersonalAccessToken::generate_token();
+        let token_hash = PersonalAccessToken::hash_token(&token);
+        state
+            .system
+            .replicate(
+                &cluster_node,
+                &session,
+                EntryCommand::CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash {
+                    command,
+                    hash: token_hash,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate create personal access token with hash, user ID: {}",
+                    identity.user_id
+                )
+            })?;
+        return Ok(Json(RawPersonalAccessToken { token }));
+    }
+
     let mut system = state.system.write().await;
     let token = system
             .create_personal_access_token(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
                 &command.name,
                 command.expiry,
+                command.permissions.clone(),
             )
             .await
             .with_error_context(|error| {
@@ -107,10 +162,37 @@ async fn delete_personal_access_token(
     Extension(identity): Extension<Identity>,
     Path(name): Path<String>,
 ) -> Result<StatusCode, CustomError> {
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(
+                &cluster_node,
+                &session,
+                EntryCommand::DeletePersonalAccessToken(DeletePersonalAccessToken { name }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate delete personal access token, user ID: {}",
+                    identity.user_id
+                )
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let mut system = state.system.write().await;
     system
             .delete_personal_access_token(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
                 &name,
             )
             .await
@@ -141,16 +223,22 @@ async fn delete_personal_access_token(
 #[instrument(skip_all, name = "trace_login_with_personal_access_token")]
 async fn login_with_personal_access_token(
     State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
     Json(command): Json<LoginWithPersonalAccessToken>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
     let system = state.system.read().await;
     let user = system
-        .login_with_personal_access_token(&command.token, None)
+        .login_with_personal_access_token(&command.token, request_details.ip_address.ip(), None)
         .await
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to login with personal access token")
         })?;
-    let tokens = state.jwt_manager.generate(user.id)?;
+    let personal_access_token = user
+        .personal_access_tokens
+        .get(&PersonalAccessToken::hash_token(&command.token))
+        .filter(|token| token.permissions.is_some())
+        .map(|token| token.name.clone());
+    let tokens = state.jwt_manager.generate(user.id, personal_access_token)?;
     Ok(Json(map_generated_access_token_to_identity_info(tokens)))
 }
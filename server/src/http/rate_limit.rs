@@ -0,0 +1,71 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::{AppState, RequestDetails};
+use crate::streaming::clients::rate_limiter::RateLimitDecision;
+use crate::streaming::session::Session;
+use axum::body::Body;
+use axum::http::header::{CONTENT_LENGTH, RETRY_AFTER};
+use axum::http::HeaderValue;
+use axum::response::IntoResponse;
+use axum::{
+    extract::State,
+    http::{Request, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use iggy::error::IggyError;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::debug;
+
+const PING_PATH: &str = "/ping";
+
+pub async fn rate_limit(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if request.uri().path() == PING_PATH {
+        return Ok(next.run(request).await);
+    }
+
+    let request_details = request.extensions().get::<RequestDetails>().unwrap();
+    let user_id = request
+        .extensions()
+        .get::<Identity>()
+        .map(|identity| identity.user_id)
+        .unwrap_or_default();
+    // The HTTP clients are stateless, thus the IP address is used instead of the client ID.
+    let session = Session::stateless(user_id, user_id, request_details.ip_address);
+    let bytes = request
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or_default();
+
+    let decision = state
+        .system
+        .read()
+        .await
+        .acquire_rate_limit(&session, bytes);
+    match decision {
+        RateLimitDecision::Allow => {}
+        RateLimitDecision::Delay(delay) => {
+            debug!("Delaying an HTTP request by {delay} µs due to the rate limit.");
+            sleep(Duration::from_micros(delay)).await;
+        }
+        RateLimitDecision::Reject(retry_after) => {
+            let error = IggyError::RateLimitExceeded(retry_after.div_ceil(1000));
+            let mut response = CustomError::from(error).into_response();
+            response.headers_mut().insert(
+                RETRY_AFTER,
+                HeaderValue::from(retry_after.div_ceil(1_000_000)),
+            );
+            return Ok(response);
+        }
+    }
+
+    Ok(next.run(request).await)
+}
@@ -0,0 +1,341 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use crate::state::command::EntryCommand;
+use crate::state::models::RegisterTopicSchemaWithId;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, put};
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::schema::{Schema, TopicSchemas};
+use iggy::schemas::delete_topic_schema::DeleteTopicSchema;
+use iggy::schemas::register_topic_schema::RegisterTopicSchema;
+use iggy::schemas::update_topic_schema_settings::UpdateTopicSchemaSettings;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/schemas/{schema_id}", get(get_schema))
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/schemas",
+            get(get_topic_schemas).post(register_topic_schema),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/schemas/settings",
+            put(update_topic_schema_settings),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/schemas/{version}",
+            get(get_topic_schema).delete(delete_topic_schema),
+        )
+        .with_state(state)
+}
+
+async fn get_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(schema_id): Path<u32>,
+) -> Result<Json<Schema>, CustomError> {
+    let system = state.system.read().await;
+    let schema = system
+        .get_schema(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            schema_id,
+        )
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get schema, schema ID: {schema_id}")
+        })?;
+    Ok(Json(schema.clone()))
+}
+
+async fn get_topic_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, version)): Path<(String, String, String)>,
+) -> Result<Json<Schema>, CustomError> {
+    let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
+    let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
+    // The latest version is returned if the version is not specified.
+    let version = match version.as_str() {
+        "latest" => None,
+        version => Some(
+            version
+                .parse::<u32>()
+                .map_err(|_| IggyError::InvalidCommand)?,
+        ),
+    };
+    let system = state.system.read().await;
+    let schema = system
+        .get_topic_schema(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &identifier_stream_id,
+            &identifier_topic_id,
+            version,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get topic schema, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?;
+    let Some(schema) = schema else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(schema.clone()))
+}
+
+async fn get_topic_schemas(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<Json<TopicSchemas>, CustomError> {
+    let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
+    let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read().await;
+    let topic_schemas = system
+        .get_topic_schemas(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &identifier_stream_id,
+            &identifier_topic_id,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get topic schemas, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?;
+    Ok(Json(topic_schemas))
+}
+
+#[instrument(skip_all, name = "trace_register_topic_schema", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn register_topic_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<RegisterTopicSchema>,
+) -> Result<Json<Schema>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        let schema_stream_id = command.stream_id.clone();
+        let schema_topic_id = command.topic_id.clone();
+        let schema_type = command.schema_type;
+        let definition = command.definition.clone();
+        // The schema ID and version are assigned by every node once the entry is committed.
+        state
+            .system
+            .replicate(
+                &cluster_node,
+                &session,
+                EntryCommand::RegisterTopicSchema(RegisterTopicSchemaWithId {
+                    command,
+                    schema_id: 0,
+                    version: 0,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate register topic schema, stream ID: {stream_id}, topic ID: {topic_id}"
+                )
+            })?;
+        let system = state.system.read().await;
+        let schema = system
+            .get_stream(&schema_stream_id)?
+            .get_topic(&schema_topic_id)?
+            .schemas
+            .find_identical(schema_type, &definition)
+            .ok_or(CustomError::ResourceNotFound)?;
+        return Ok(Json(schema.clone()));
+    }
+
+    let mut system = state.system.write().await;
+    let (schema, registered) = system
+        .register_topic_schema(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &command.stream_id,
+            &command.topic_id,
+            command.schema_type,
+            &command.definition,
+            None,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to register topic schema, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?;
+
+    let system = system.downgrade();
+    if registered {
+        command.stream_id = Identifier::numeric(schema.stream_id)?;
+        command.topic_id = Identifier::numeric(schema.topic_id)?;
+        system
+            .state
+            .apply(
+                identity.user_id,
+                EntryCommand::RegisterTopicSchema(RegisterTopicSchemaWithId {
+                    command,
+                    schema_id: schema.id,
+                    version: schema.version,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to apply register topic schema, stream ID: {stream_id}, topic ID: {topic_id}"
+                )
+            })?;
+    }
+    Ok(Json(schema))
+}
+
+#[instrument(skip_all, name = "trace_delete_topic_schema", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn delete_topic_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, version)): Path<(String, String, u32)>,
+) -> Result<StatusCode, CustomError> {
+    let command = DeleteTopicSchema {
+        stream_id: Identifier::from_str_value(&stream_id)?,
+        topic_id: Identifier::from_str_value(&topic_id)?,
+        version,
+    };
+    command.validate()?;
+
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::DeleteTopicSchema(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete topic schema, stream ID: {stream_id}, topic ID: {topic_id}, version: {version}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
+    let mut system = state.system.write().await;
+    system
+        .delete_topic_schema(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &command.stream_id,
+            &command.topic_id,
+            version,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to delete topic schema, stream ID: {stream_id}, topic ID: {topic_id}, version: {version}"
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::DeleteTopicSchema(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply delete topic schema, stream ID: {stream_id}, topic ID: {topic_id}, version: {version}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_update_topic_schema_settings", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn update_topic_schema_settings(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<UpdateTopicSchemaSettings>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::UpdateTopicSchemaSettings(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate update topic schema settings, stream ID: {stream_id}, topic ID: {topic_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
+    let mut system = state.system.write().await;
+    system
+        .update_topic_schema_settings(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &command.stream_id,
+            &command.topic_id,
+            command.compatibility,
+            command.validate_payloads,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update topic schema settings, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::UpdateTopicSchemaSettings(command),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update topic schema settings, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
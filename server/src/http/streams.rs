@@ -11,10 +11,12 @@ use axum::{Extension, Json, Router};
 use error_set::ErrContext;
 use iggy::identifier::Identifier;
 use iggy::models::stream::{Stream, StreamDetails};
+use iggy::models::topic_template::TopicDefaults;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::streams::update_stream_topic_defaults::UpdateStreamTopicDefaults;
 use iggy::validatable::Validatable;
 
 use crate::state::command::EntryCommand;
@@ -29,6 +31,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             get(get_stream).put(update_stream).delete(delete_stream),
         )
         .route("/streams/{stream_id}/purge", delete(purge_stream))
+        .route(
+            "/streams/{stream_id}/topic-defaults",
+            get(get_stream_topic_defaults).put(update_stream_topic_defaults),
+        )
         .with_state(state)
 }
 
@@ -40,7 +46,11 @@ async fn get_stream(
     let system = state.system.read().await;
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let Ok(stream) = system.try_find_stream(
-        &Session::stateless(identity.user_id, identity.ip_address),
+        &Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ),
         &stream_id,
     ) else {
         return Err(CustomError::ResourceNotFound);
@@ -59,7 +69,11 @@ async fn get_streams(
 ) -> Result<Json<Vec<Stream>>, CustomError> {
     let system = state.system.read().await;
     let streams = system
-        .find_streams(&Session::stateless(identity.user_id, identity.ip_address))
+        .find_streams(&Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ))
         .with_error_context(|error| {
             format!(
                 "{COMPONENT} (error: {error}) - failed to find streams, user ID: {}",
@@ -78,10 +92,37 @@ async fn create_stream(
 ) -> Result<Json<StreamDetails>, CustomError> {
     command.validate()?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        let stream_id = command.stream_id;
+        let name = command.name.clone();
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::CreateStream(command))
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate create stream, stream ID: {:?}",
+                    stream_id
+                )
+            })?;
+        let system = state.system.read().await;
+        let stream = system.get_stream(&Identifier::named(&name)?)?;
+        return Ok(Json(mapper::map_stream(stream)));
+    }
+
     let mut system = state.system.write().await;
     let stream = system
         .create_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             command.stream_id,
             &command.name,
         )
@@ -119,10 +160,30 @@ async fn update_stream(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.validate()?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::UpdateStream(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate update stream, stream ID: {stream_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let mut system = state.system.write().await;
     system
         .update_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             &command.stream_id,
             &command.name,
         )
@@ -148,6 +209,96 @@ async fn update_stream(
     Ok(StatusCode::NO_CONTENT)
 }
 
+async fn get_stream_topic_defaults(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(stream_id): Path<String>,
+) -> Result<Json<TopicDefaults>, CustomError> {
+    let system = state.system.read().await;
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_defaults = system
+        .get_stream_topic_defaults(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &stream_id,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get stream topic defaults, stream ID: {}",
+                stream_id
+            )
+        })?;
+    Ok(Json(topic_defaults))
+}
+
+#[instrument(skip_all, name = "trace_update_stream_topic_defaults", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
+async fn update_stream_topic_defaults(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(stream_id): Path<String>,
+    Json(mut command): Json<UpdateStreamTopicDefaults>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.validate()?;
+
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::UpdateStreamTopicDefaults(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate update stream topic defaults, stream ID: {stream_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
+    let mut system = state.system.write().await;
+    system
+        .update_stream_topic_defaults(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &command.stream_id,
+            TopicDefaults {
+                message_expiry: command.message_expiry,
+                max_topic_size: command.max_topic_size,
+                replication_factor: command.replication_factor,
+            },
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update stream topic defaults, stream ID: {}",
+                stream_id
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::UpdateStreamTopicDefaults(command),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update stream topic defaults, stream ID: {}",
+                stream_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[instrument(skip_all, name = "trace_delete_stream", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn delete_stream(
     State(state): State<Arc<AppState>>,
@@ -156,10 +307,36 @@ async fn delete_stream(
 ) -> Result<StatusCode, CustomError> {
     let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(
+                &cluster_node,
+                &session,
+                EntryCommand::DeleteStream(DeleteStream {
+                    stream_id: identifier_stream_id,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete stream with ID: {stream_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let mut system = state.system.write().await;
     system
         .delete_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             &identifier_stream_id,
         )
         .await
@@ -192,10 +369,36 @@ async fn purge_stream(
     Path(stream_id): Path<String>,
 ) -> Result<StatusCode, CustomError> {
     let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(
+                &cluster_node,
+                &session,
+                EntryCommand::PurgeStream(PurgeStream {
+                    stream_id: identifier_stream_id,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate purge stream, stream ID: {stream_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let system = state.system.read().await;
     system
         .purge_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             &identifier_stream_id,
         )
         .await
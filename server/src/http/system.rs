@@ -60,7 +60,11 @@ async fn get_client(
     let system = state.system.read().await;
     let Ok(client) = system
         .get_client(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             client_id,
         )
         .await
@@ -88,7 +92,11 @@ async fn get_clients(
 ) -> Result<Json<Vec<ClientInfo>>, CustomError> {
     let system = state.system.read().await;
     let clients = system
-        .get_clients(&Session::stateless(identity.user_id, identity.ip_address))
+        .get_clients(&Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ))
         .await
         .with_error_context(|error| {
             format!(
@@ -107,7 +115,11 @@ async fn get_snapshot(
 ) -> Result<impl IntoResponse, CustomError> {
     command.validate()?;
 
-    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let session = Session::stateless(
+        identity.user_id,
+        identity.permissions_id,
+        identity.ip_address,
+    );
     let system = state.system.read().await;
 
     let snapshot = system
@@ -0,0 +1,195 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy::models::topic_template::TopicTemplate;
+use iggy::topic_templates::create_topic_template::CreateTopicTemplate;
+use iggy::topic_templates::delete_topic_template::DeleteTopicTemplate;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route(
+            "/topic-templates",
+            get(get_topic_templates).post(create_topic_template),
+        )
+        .route(
+            "/topic-templates/{name}",
+            get(get_topic_template).delete(delete_topic_template),
+        )
+        .with_state(state)
+}
+
+async fn get_topic_template(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(name): Path<String>,
+) -> Result<Json<TopicTemplate>, CustomError> {
+    let system = state.system.read().await;
+    let template = system
+        .get_topic_template(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &name,
+        )
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get topic template, name: {name}")
+        })?;
+    Ok(Json(template.clone()))
+}
+
+async fn get_topic_templates(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<TopicTemplate>>, CustomError> {
+    let system = state.system.read().await;
+    let mut templates = system
+        .get_topic_templates(&Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ))
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get topic templates, user ID: {}",
+                identity.user_id
+            )
+        })?
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    templates.sort_by(|x, y| x.name.cmp(&y.name));
+    Ok(Json(templates))
+}
+
+#[instrument(skip_all, name = "trace_create_topic_template", fields(iggy_user_id = identity.user_id))]
+async fn create_topic_template(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreateTopicTemplate>,
+) -> Result<StatusCode, CustomError> {
+    command.validate()?;
+
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        let name = command.name.clone();
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::CreateTopicTemplate(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate create topic template, name: {name}")
+            })?;
+        return Ok(StatusCode::CREATED);
+    }
+
+    let mut system = state.system.write().await;
+    system
+        .create_topic_template(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            TopicTemplate {
+                name: command.name.clone(),
+                partitions_count: command.partitions_count,
+                compression_algorithm: command.compression_algorithm,
+                message_expiry: command.message_expiry,
+                max_topic_size: command.max_topic_size,
+                replication_factor: command.replication_factor,
+            },
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create topic template, name: {}",
+                command.name
+            )
+        })?;
+
+    let system = system.downgrade();
+    let name = command.name.clone();
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::CreateTopicTemplate(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply create topic template, name: {name}"
+            )
+        })?;
+    Ok(StatusCode::CREATED)
+}
+
+#[instrument(skip_all, name = "trace_delete_topic_template", fields(iggy_user_id = identity.user_id))]
+async fn delete_topic_template(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let command = DeleteTopicTemplate { name };
+    command.validate()?;
+
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        let name = command.name.clone();
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::DeleteTopicTemplate(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete topic template, name: {name}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
+    let mut system = state.system.write().await;
+    system
+        .delete_topic_template(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &command.name,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to delete topic template, name: {}",
+                command.name
+            )
+        })?;
+
+    let system = system.downgrade();
+    let name = command.name.clone();
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::DeleteTopicTemplate(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply delete topic template, name: {name}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
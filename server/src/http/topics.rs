@@ -46,7 +46,11 @@ async fn get_topic(
     let identity_stream_id = Identifier::from_str_value(&stream_id)?;
     let identity_topic_id = Identifier::from_str_value(&topic_id)?;
     let Ok(topic) = system.try_find_topic(
-        &Session::stateless(identity.user_id, identity.ip_address),
+        &Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ),
         &identity_stream_id,
         &identity_topic_id,
     ) else {
@@ -69,7 +73,11 @@ async fn get_topics(
     let system = state.system.read().await;
     let topics = system
         .find_topics(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             &stream_id,
         )
         .with_error_context(|error| {
@@ -92,10 +100,54 @@ async fn create_topic(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.validate()?;
 
+    let session = Session::stateless(
+        identity.user_id,
+        identity.permissions_id,
+        identity.ip_address,
+    );
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        state
+            .system
+            .read()
+            .await
+            .resolve_topic_settings(&session, &mut command)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to resolve topic settings, stream ID: {}",
+                    stream_id
+                )
+            })?;
+        let topic_stream_id = command.stream_id.clone();
+        let name = command.name.clone();
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::CreateTopic(command))
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate create topic, stream ID: {}",
+                    stream_id
+                )
+            })?;
+        let system = state.system.read().await;
+        let topic = system
+            .get_stream(&topic_stream_id)?
+            .get_topic(&Identifier::named(&name)?)?;
+        return Ok(Json(mapper::map_topic(topic).await));
+    }
+
     let mut system = state.system.write().await;
+    system
+        .resolve_topic_settings(&session, &mut command)
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to resolve topic settings, stream ID: {}",
+                stream_id
+            )
+        })?;
     let topic = system
         .create_topic(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &session,
             &command.stream_id,
             command.topic_id,
             &command.name,
@@ -141,10 +193,30 @@ async fn update_topic(
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::UpdateTopic(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate update topic, stream ID: {stream_id}, topic ID: {topic_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let mut system = state.system.write().await;
     let topic = system
             .update_topic(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
                 &command.stream_id,
                 &command.topic_id,
                 &command.name,
@@ -186,10 +258,37 @@ async fn delete_topic(
     let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
     let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(
+                &cluster_node,
+                &session,
+                EntryCommand::DeleteTopic(DeleteTopic {
+                    stream_id: identifier_stream_id,
+                    topic_id: identifier_topic_id,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let mut system = state.system.write().await;
     system
             .delete_topic(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
                 &identifier_stream_id,
                 &identifier_topic_id,
             )
@@ -228,10 +327,37 @@ async fn purge_topic(
 ) -> Result<StatusCode, CustomError> {
     let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
     let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(
+                &cluster_node,
+                &session,
+                EntryCommand::PurgeTopic(PurgeTopic {
+                    stream_id: identifier_stream_id,
+                    topic_id: identifier_topic_id,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate purge topic, stream ID: {stream_id}, topic ID: {topic_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let system = state.system.read().await;
     system
         .purge_topic(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             &identifier_stream_id,
             &identifier_topic_id,
         )
@@ -2,7 +2,7 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_access_token_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::http::COMPONENT;
 use crate::state::command::EntryCommand;
 use crate::streaming::session::Session;
@@ -19,6 +19,7 @@ use iggy::users::change_password::ChangePassword;
 use iggy::users::create_user::CreateUser;
 use iggy::users::delete_user::DeleteUser;
 use iggy::users::login_user::LoginUser;
+use iggy::users::unlock_user::UnlockUser;
 use iggy::users::update_permissions::UpdatePermissions;
 use iggy::users::update_user::UpdateUser;
 use iggy::validatable::Validatable;
@@ -35,6 +36,7 @@ pub fn router(state: Arc<AppState>) -> Router {
         )
         .route("/users/{user_id}/permissions", put(update_permissions))
         .route("/users/{user_id}/password", put(change_password))
+        .route("/users/{user_id}/unlock", post(unlock_user))
         .route("/users/login", post(login_user))
         .route("/users/logout", delete(logout_user))
         .route("/users/refresh-token", post(refresh_token))
@@ -49,7 +51,11 @@ async fn get_user(
     let identifier_user_id = Identifier::from_str_value(&user_id)?;
     let system = state.system.read().await;
     let Ok(user) = system.find_user(
-        &Session::stateless(identity.user_id, identity.ip_address),
+        &Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ),
         &identifier_user_id,
     ) else {
         return Err(CustomError::ResourceNotFound);
@@ -58,7 +64,8 @@ async fn get_user(
         return Err(CustomError::ResourceNotFound);
     };
 
-    let user = mapper::map_user(user);
+    let usage = system.get_user_usage(user.id);
+    let user = mapper::map_user(user, Some(usage));
     Ok(Json(user))
 }
 
@@ -68,7 +75,11 @@ async fn get_users(
 ) -> Result<Json<Vec<UserInfo>>, CustomError> {
     let system = state.system.read().await;
     let users = system
-        .get_users(&Session::stateless(identity.user_id, identity.ip_address))
+        .get_users(&Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ))
         .await
         .with_error_context(|error| {
             format!(
@@ -88,10 +99,57 @@ async fn create_user(
 ) -> Result<Json<UserInfoDetails>, CustomError> {
     command.validate()?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        // For the security of the system, we hash the password before storing it in metadata.
+        let password_hash = {
+            let system = state.system.read().await;
+            system
+                .validate_user_creation(&session, &command.username, &command.password)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to create user, username: {}",
+                        command.username
+                    )
+                })?;
+            crypto::hash_password(&command.password, &system.config.password.hashing)
+        };
+        state
+            .system
+            .replicate(
+                &cluster_node,
+                &session,
+                EntryCommand::CreateUser(CreateUser {
+                    username: command.username.clone(),
+                    password: password_hash,
+                    status: command.status,
+                    permissions: command.permissions,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate create user, username: {}",
+                    command.username
+                )
+            })?;
+        let system = state.system.read().await;
+        let user = system.get_user(&Identifier::named(&command.username)?)?;
+        return Ok(Json(mapper::map_user(user, None)));
+    }
+
     let mut system = state.system.write().await;
     let user = system
         .create_user(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             &command.username,
             &command.password,
             command.status,
@@ -104,7 +162,7 @@ async fn create_user(
                 command.username
             )
         })?;
-    let response = Json(mapper::map_user(user));
+    let response = Json(mapper::map_user(user, None));
 
     // For the security of the system, we hash the password before storing it in metadata.
     let system = system.downgrade();
@@ -114,7 +172,7 @@ async fn create_user(
             identity.user_id,
             EntryCommand::CreateUser(CreateUser {
                 username: command.username.clone(),
-                password: crypto::hash_password(&command.password),
+                password: crypto::hash_password(&command.password, &system.config.password.hashing),
                 status: command.status,
                 permissions: command.permissions,
             }),
@@ -140,10 +198,30 @@ async fn update_user(
     command.user_id = Identifier::from_str_value(&user_id)?;
     command.validate()?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::UpdateUser(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate update user, user ID: {user_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let mut system = state.system.write().await;
     system
         .update_user(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             &command.user_id,
             command.username.clone(),
             command.status,
@@ -180,10 +258,30 @@ async fn update_permissions(
     command.user_id = Identifier::from_str_value(&user_id)?;
     command.validate()?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(&cluster_node, &session, EntryCommand::UpdatePermissions(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate update permissions, user ID: {user_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let mut system = state.system.write().await;
     system
         .update_permissions(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             &command.user_id,
             command.permissions.clone(),
         )
@@ -219,10 +317,63 @@ async fn change_password(
     command.user_id = Identifier::from_str_value(&user_id)?;
     command.validate()?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        // For the security of the system, we hash the password before storing it in metadata.
+        let new_password_hash = {
+            let system = state.system.read().await;
+            system
+                .validate_password_change(
+                    &Session::stateless(
+                        identity.user_id,
+                        identity.permissions_id,
+                        identity.ip_address,
+                    ),
+                    &command.user_id,
+                    &command.current_password,
+                    &command.new_password,
+                )
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to change password, user ID: {}",
+                        user_id
+                    )
+                })?;
+            crypto::hash_password(&command.new_password, &system.config.password.hashing)
+        };
+        state
+            .system
+            .replicate(
+                &cluster_node,
+                &session,
+                EntryCommand::ChangePassword(ChangePassword {
+                    user_id: command.user_id,
+                    current_password: "".into(),
+                    new_password: new_password_hash,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replicate change password, user ID: {}",
+                    user_id
+                )
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let mut system = state.system.write().await;
     system
         .change_password(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             &command.user_id,
             &command.current_password,
             &command.new_password,
@@ -244,7 +395,10 @@ async fn change_password(
             EntryCommand::ChangePassword(ChangePassword {
                 user_id: command.user_id,
                 current_password: "".into(),
-                new_password: crypto::hash_password(&command.new_password),
+                new_password: crypto::hash_password(
+                    &command.new_password,
+                    &system.config.password.hashing,
+                ),
             }),
         )
         .await
@@ -257,6 +411,33 @@ async fn change_password(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[instrument(skip_all, name = "trace_unlock_user", fields(iggy_user_id = identity.user_id, iggy_unlocked_user_id = user_id))]
+async fn unlock_user(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    Json(mut command): Json<UnlockUser>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Identifier::from_str_value(&user_id)?;
+    command.validate()?;
+
+    let system = state.system.read().await;
+    system
+        .unlock_user(
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
+            &command.user_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to unlock user with ID: {user_id}")
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[instrument(skip_all, name = "trace_delete_user", fields(iggy_user_id = identity.user_id, iggy_deleted_user_id = user_id))]
 async fn delete_user(
     State(state): State<Arc<AppState>>,
@@ -265,10 +446,36 @@ async fn delete_user(
 ) -> Result<StatusCode, CustomError> {
     let identifier_user_id = Identifier::from_str_value(&user_id)?;
 
+    if let Some(cluster_node) = state.system.cluster_node().await {
+        let session = Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        );
+        state
+            .system
+            .replicate(
+                &cluster_node,
+                &session,
+                EntryCommand::DeleteUser(DeleteUser {
+                    user_id: identifier_user_id,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replicate delete user with ID: {user_id}")
+            })?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let mut system = state.system.write().await;
     system
         .delete_user(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &Session::stateless(
+                identity.user_id,
+                identity.permissions_id,
+                identity.ip_address,
+            ),
             &identifier_user_id,
         )
         .await
@@ -295,20 +502,45 @@ async fn delete_user(
 #[instrument(skip_all, name = "trace_login_user")]
 async fn login_user(
     State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
     Json(command): Json<LoginUser>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
-    let system = state.system.read().await;
-    let user = system
-        .login_user(&command.username, &command.password, None)
-        .await
-        .with_error_context(|error| {
-            format!(
-                "{COMPONENT} (error: {error}) - failed to login, username: {}",
-                command.username
+    let (user_id, needs_rehash) = {
+        let system = state.system.read().await;
+        let user = system
+            .login_user(
+                &command.username,
+                &command.password,
+                request_details.ip_address.ip(),
+                None,
             )
-        })?;
-    let tokens = state.jwt_manager.generate(user.id)?;
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to login, username: {}",
+                    command.username
+                )
+            })?;
+        (
+            user.id,
+            crypto::needs_rehash(&user.password, &system.config.password.hashing),
+        )
+    };
+
+    if needs_rehash {
+        state
+            .system
+            .rehash_password_if_needed(user_id, &command.password)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to rehash password, user ID: {user_id}"
+                )
+            })?;
+    }
+
+    let tokens = state.jwt_manager.generate(user_id, None)?;
     Ok(Json(map_generated_access_token_to_identity_info(tokens)))
 }
 
@@ -319,7 +551,11 @@ async fn logout_user(
 ) -> Result<StatusCode, CustomError> {
     let system = state.system.read().await;
     system
-        .logout_user(&Session::stateless(identity.user_id, identity.ip_address))
+        .logout_user(&Session::stateless(
+            identity.user_id,
+            identity.permissions_id,
+            identity.ip_address,
+        ))
         .await
         .with_error_context(|error| {
             format!(
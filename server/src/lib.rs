@@ -12,8 +12,9 @@ pub mod archiver;
 pub mod args;
 pub mod binary;
 pub mod channels;
+pub mod cluster;
 mod command;
-pub(crate) mod compat;
+pub mod compat;
 pub mod configs;
 pub mod http;
 pub mod log;
@@ -5,8 +5,10 @@ use figlet_rs::FIGfont;
 use server::args::Args;
 use server::channels::commands::archive_state::ArchiveStateExecutor;
 use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use server::channels::commands::compact_state::CompactStateExecutor;
 use server::channels::commands::maintain_messages::MaintainMessagesExecutor;
 use server::channels::commands::print_sysinfo::SysInfoPrintExecutor;
+use server::channels::commands::rotate_encryption_keys::RotateEncryptionKeysExecutor;
 use server::channels::commands::save_messages::SaveMessagesExecutor;
 use server::channels::commands::verify_heartbeats::VerifyHeartbeatsExecutor;
 use server::channels::handler::ServerCommandHandler;
@@ -72,11 +74,7 @@ async fn main() -> Result<(), ServerError> {
     #[cfg(not(feature = "disable-mimalloc"))]
     info!("Using mimalloc allocator");
 
-    let system = SharedSystem::new(System::new(
-        config.system.clone(),
-        config.data_maintenance.clone(),
-        config.personal_access_token.clone(),
-    ));
+    let system = SharedSystem::new(System::new(&config));
 
     // Workaround to ensure that the statistics are initialized before the server
     // loads streams and starts accepting connections. This is necessary to
@@ -88,7 +86,9 @@ async fn main() -> Result<(), ServerError> {
         .install_handler(SaveMessagesExecutor)
         .install_handler(MaintainMessagesExecutor)
         .install_handler(ArchiveStateExecutor)
+        .install_handler(CompactStateExecutor)
         .install_handler(CleanPersonalAccessTokensExecutor)
+        .install_handler(RotateEncryptionKeysExecutor)
         .install_handler(SysInfoPrintExecutor)
         .install_handler(VerifyHeartbeatsExecutor);
 
@@ -103,6 +103,12 @@ async fn main() -> Result<(), ServerError> {
 
     let mut current_config = config.clone();
 
+    let cluster_node = system.read().await.cluster_node();
+    if let Some(cluster_node) = cluster_node {
+        let cluster_addr = cluster_node.start(system.clone()).await?;
+        current_config.cluster.address = cluster_addr.to_string();
+    }
+
     if config.http.enabled {
         let http_addr = http_server::start(config.http, system.clone()).await;
         current_config.http.address = http_addr.to_string();
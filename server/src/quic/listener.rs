@@ -3,13 +3,17 @@ use crate::binary::sender::SenderKind;
 use crate::command::ServerCommand;
 use crate::server_error::ConnectionError;
 use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::clients::rate_limiter::RateLimitDecision;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::{anyhow, Context};
 use bytes::Bytes;
+use iggy::error::IggyError;
 use iggy::validatable::Validatable;
 use iggy::{bytes_serializable::BytesSerializable, messages::MAX_PAYLOAD_SIZE};
 use quinn::{Connection, Endpoint, RecvStream, SendStream};
+use std::time::Duration;
+use tokio::time::sleep;
 use tracing::{debug, error, info};
 
 const LISTENERS_COUNT: u32 = 10;
@@ -129,6 +133,26 @@ async fn handle_stream(
     debug!("Received a QUIC command: {command}, payload size: {length}");
 
     let mut sender = SenderKind::get_quic_sender(send_stream, recv_stream);
+    if !matches!(command, ServerCommand::Ping(_)) {
+        let decision = system
+            .read()
+            .await
+            .acquire_rate_limit(session.as_ref(), length as u64);
+        match decision {
+            RateLimitDecision::Allow => {}
+            RateLimitDecision::Delay(delay) => {
+                debug!("Delaying a QUIC command: {command} by {delay} µs due to the rate limit.");
+                sleep(Duration::from_micros(delay)).await;
+            }
+            RateLimitDecision::Reject(retry_after) => {
+                return sender
+                    .send_error_response(IggyError::RateLimitExceeded(retry_after.div_ceil(1000)))
+                    .await
+                    .with_context(|| "Error when sending the QUIC rate limit response.");
+            }
+        }
+    }
+
     command::handle(command, &mut sender, session.as_ref(), system.clone())
         .await
         .with_context(|| "Error when handling the QUIC request.")
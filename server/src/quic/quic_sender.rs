@@ -32,7 +32,7 @@ impl Sender for QuicSender {
     }
 
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        self.send_response(&error.as_code().to_le_bytes(), &[])
+        self.send_response(&error.as_code().to_le_bytes(), &error.as_payload())
             .await
     }
 
@@ -41,8 +41,17 @@ error_set!(
         #[display("Invalid S3 credentials")]
         InvalidS3Credentials,
 
+        #[display("Cannot initialize {} archiver", kind)]
+        CannotInitializeObjectStoreArchiver { kind: String },
+
         #[display("Cannot archive file: {}", file_path)]
         CannotArchiveFile { file_path: String },
+
+        #[display("Archived file not found: {}", file_path)]
+        ArchivedFileNotFound { file_path: String },
+
+        #[display("Cannot fetch archived file: {}", file_path)]
+        CannotFetchFile { file_path: String },
     } || IoError;
 
     ConnectionError = {
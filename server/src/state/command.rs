@@ -1,12 +1,19 @@
-use crate::state::models::CreatePersonalAccessTokenWithHash;
+use crate::state::models::{
+    AddClusterNode, CreatePersonalAccessTokenWithHash, ElectClusterLeader,
+    RegisterTopicSchemaWithId, RehashPassword, RemoveClusterNode, ADD_CLUSTER_NODE_CODE,
+    ELECT_CLUSTER_LEADER_CODE, REHASH_PASSWORD_CODE, REMOVE_CLUSTER_NODE_CODE,
+};
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::{
     Command, CHANGE_PASSWORD_CODE, CREATE_CONSUMER_GROUP_CODE, CREATE_PARTITIONS_CODE,
-    CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_STREAM_CODE, CREATE_TOPIC_CODE, CREATE_USER_CODE,
-    DELETE_CONSUMER_GROUP_CODE, DELETE_PARTITIONS_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE,
-    DELETE_STREAM_CODE, DELETE_TOPIC_CODE, DELETE_USER_CODE, PURGE_STREAM_CODE, PURGE_TOPIC_CODE,
-    UPDATE_PERMISSIONS_CODE, UPDATE_STREAM_CODE, UPDATE_TOPIC_CODE, UPDATE_USER_CODE,
+    CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_STREAM_CODE, CREATE_TOPIC_CODE,
+    CREATE_TOPIC_TEMPLATE_CODE, CREATE_USER_CODE, DELETE_CONSUMER_GROUP_CODE,
+    DELETE_PARTITIONS_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE, DELETE_STREAM_CODE,
+    DELETE_TOPIC_CODE, DELETE_TOPIC_SCHEMA_CODE, DELETE_TOPIC_TEMPLATE_CODE, DELETE_USER_CODE,
+    PURGE_STREAM_CODE, PURGE_TOPIC_CODE, REGISTER_TOPIC_SCHEMA_CODE, UPDATE_PERMISSIONS_CODE,
+    UPDATE_STREAM_CODE, UPDATE_STREAM_TOPIC_DEFAULTS_CODE, UPDATE_TOPIC_CODE,
+    UPDATE_TOPIC_SCHEMA_SETTINGS_CODE, UPDATE_USER_CODE,
 };
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
@@ -14,10 +21,15 @@ use iggy::error::IggyError;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use iggy::schemas::delete_topic_schema::DeleteTopicSchema;
+use iggy::schemas::update_topic_schema_settings::UpdateTopicSchemaSettings;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::streams::update_stream_topic_defaults::UpdateStreamTopicDefaults;
+use iggy::topic_templates::create_topic_template::CreateTopicTemplate;
+use iggy::topic_templates::delete_topic_template::DeleteTopicTemplate;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::purge_topic::PurgeTopic;
@@ -35,10 +47,16 @@ pub enum EntryCommand {
     UpdateStream(UpdateStream),
     DeleteStream(DeleteStream),
     PurgeStream(PurgeStream),
+    UpdateStreamTopicDefaults(UpdateStreamTopicDefaults),
     CreateTopic(CreateTopic),
     UpdateTopic(UpdateTopic),
     DeleteTopic(DeleteTopic),
     PurgeTopic(PurgeTopic),
+    CreateTopicTemplate(CreateTopicTemplate),
+    DeleteTopicTemplate(DeleteTopicTemplate),
+    RegisterTopicSchema(RegisterTopicSchemaWithId),
+    DeleteTopicSchema(DeleteTopicSchema),
+    UpdateTopicSchemaSettings(UpdateTopicSchemaSettings),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
     CreateConsumerGroup(CreateConsumerGroup),
@@ -47,9 +65,13 @@ pub enum EntryCommand {
     UpdateUser(UpdateUser),
     DeleteUser(DeleteUser),
     ChangePassword(ChangePassword),
+    RehashPassword(RehashPassword),
     UpdatePermissions(UpdatePermissions),
     CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash),
     DeletePersonalAccessToken(DeletePersonalAccessToken),
+    AddClusterNode(AddClusterNode),
+    RemoveClusterNode(RemoveClusterNode),
+    ElectClusterLeader(ElectClusterLeader),
 }
 
 impl BytesSerializable for EntryCommand {
@@ -59,10 +81,20 @@ impl BytesSerializable for EntryCommand {
             EntryCommand::UpdateStream(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteStream(command) => (command.code(), command.to_bytes()),
             EntryCommand::PurgeStream(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateStreamTopicDefaults(command) => {
+                (command.code(), command.to_bytes())
+            }
             EntryCommand::CreateTopic(command) => (command.code(), command.to_bytes()),
             EntryCommand::UpdateTopic(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteTopic(command) => (command.code(), command.to_bytes()),
             EntryCommand::PurgeTopic(command) => (command.code(), command.to_bytes()),
+            EntryCommand::CreateTopicTemplate(command) => (command.code(), command.to_bytes()),
+            EntryCommand::DeleteTopicTemplate(command) => (command.code(), command.to_bytes()),
+            EntryCommand::RegisterTopicSchema(command) => (command.code(), command.to_bytes()),
+            EntryCommand::DeleteTopicSchema(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateTopicSchemaSettings(command) => {
+                (command.code(), command.to_bytes())
+            }
             EntryCommand::CreatePartitions(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeletePartitions(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreateConsumerGroup(command) => (command.code(), command.to_bytes()),
@@ -71,6 +103,7 @@ impl BytesSerializable for EntryCommand {
             EntryCommand::UpdateUser(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteUser(command) => (command.code(), command.to_bytes()),
             EntryCommand::ChangePassword(command) => (command.code(), command.to_bytes()),
+            EntryCommand::RehashPassword(command) => (command.code(), command.to_bytes()),
             EntryCommand::UpdatePermissions(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreatePersonalAccessToken(command) => {
                 (command.code(), command.to_bytes())
@@ -78,6 +111,9 @@ impl BytesSerializable for EntryCommand {
             EntryCommand::DeletePersonalAccessToken(command) => {
                 (command.code(), command.to_bytes())
             }
+            EntryCommand::AddClusterNode(command) => (command.code(), command.to_bytes()),
+            EntryCommand::RemoveClusterNode(command) => (command.code(), command.to_bytes()),
+            EntryCommand::ElectClusterLeader(command) => (command.code(), command.to_bytes()),
         };
 
         let mut bytes = BytesMut::with_capacity(4 + 4 + command.len());
@@ -105,10 +141,28 @@ impl BytesSerializable for EntryCommand {
                 payload,
             )?)),
             PURGE_STREAM_CODE => Ok(EntryCommand::PurgeStream(PurgeStream::from_bytes(payload)?)),
+            UPDATE_STREAM_TOPIC_DEFAULTS_CODE => Ok(EntryCommand::UpdateStreamTopicDefaults(
+                UpdateStreamTopicDefaults::from_bytes(payload)?,
+            )),
             CREATE_TOPIC_CODE => Ok(EntryCommand::CreateTopic(CreateTopic::from_bytes(payload)?)),
             UPDATE_TOPIC_CODE => Ok(EntryCommand::UpdateTopic(UpdateTopic::from_bytes(payload)?)),
             DELETE_TOPIC_CODE => Ok(EntryCommand::DeleteTopic(DeleteTopic::from_bytes(payload)?)),
             PURGE_TOPIC_CODE => Ok(EntryCommand::PurgeTopic(PurgeTopic::from_bytes(payload)?)),
+            CREATE_TOPIC_TEMPLATE_CODE => Ok(EntryCommand::CreateTopicTemplate(
+                CreateTopicTemplate::from_bytes(payload)?,
+            )),
+            DELETE_TOPIC_TEMPLATE_CODE => Ok(EntryCommand::DeleteTopicTemplate(
+                DeleteTopicTemplate::from_bytes(payload)?,
+            )),
+            REGISTER_TOPIC_SCHEMA_CODE => Ok(EntryCommand::RegisterTopicSchema(
+                RegisterTopicSchemaWithId::from_bytes(payload)?,
+            )),
+            DELETE_TOPIC_SCHEMA_CODE => Ok(EntryCommand::DeleteTopicSchema(
+                DeleteTopicSchema::from_bytes(payload)?,
+            )),
+            UPDATE_TOPIC_SCHEMA_SETTINGS_CODE => Ok(EntryCommand::UpdateTopicSchemaSettings(
+                UpdateTopicSchemaSettings::from_bytes(payload)?,
+            )),
             CREATE_PARTITIONS_CODE => Ok(EntryCommand::CreatePartitions(
                 CreatePartitions::from_bytes(payload)?,
             )),
@@ -127,6 +181,9 @@ impl BytesSerializable for EntryCommand {
             CHANGE_PASSWORD_CODE => Ok(EntryCommand::ChangePassword(ChangePassword::from_bytes(
                 payload,
             )?)),
+            REHASH_PASSWORD_CODE => Ok(EntryCommand::RehashPassword(RehashPassword::from_bytes(
+                payload,
+            )?)),
             UPDATE_PERMISSIONS_CODE => Ok(EntryCommand::UpdatePermissions(
                 UpdatePermissions::from_bytes(payload)?,
             )),
@@ -136,6 +193,15 @@ impl BytesSerializable for EntryCommand {
             DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(EntryCommand::DeletePersonalAccessToken(
                 DeletePersonalAccessToken::from_bytes(payload)?,
             )),
+            ADD_CLUSTER_NODE_CODE => Ok(EntryCommand::AddClusterNode(AddClusterNode::from_bytes(
+                payload,
+            )?)),
+            REMOVE_CLUSTER_NODE_CODE => Ok(EntryCommand::RemoveClusterNode(
+                RemoveClusterNode::from_bytes(payload)?,
+            )),
+            ELECT_CLUSTER_LEADER_CODE => Ok(EntryCommand::ElectClusterLeader(
+                ElectClusterLeader::from_bytes(payload)?,
+            )),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -148,10 +214,28 @@ impl Display for EntryCommand {
             EntryCommand::UpdateStream(command) => write!(f, "UpdateStream({})", command),
             EntryCommand::DeleteStream(command) => write!(f, "DeleteStream({})", command),
             EntryCommand::PurgeStream(command) => write!(f, "PurgeStream({})", command),
+            EntryCommand::UpdateStreamTopicDefaults(command) => {
+                write!(f, "UpdateStreamTopicDefaults({})", command)
+            }
             EntryCommand::CreateTopic(command) => write!(f, "CreateTopic({})", command),
             EntryCommand::UpdateTopic(command) => write!(f, "UpdateTopic({})", command),
             EntryCommand::DeleteTopic(command) => write!(f, "DeleteTopic({})", command),
             EntryCommand::PurgeTopic(command) => write!(f, "PurgeTopic({})", command),
+            EntryCommand::CreateTopicTemplate(command) => {
+                write!(f, "CreateTopicTemplate({})", command)
+            }
+            EntryCommand::DeleteTopicTemplate(command) => {
+                write!(f, "DeleteTopicTemplate({})", command)
+            }
+            EntryCommand::RegisterTopicSchema(command) => {
+                write!(f, "RegisterTopicSchema({})", command)
+            }
+            EntryCommand::DeleteTopicSchema(command) => {
+                write!(f, "DeleteTopicSchema({})", command)
+            }
+            EntryCommand::UpdateTopicSchemaSettings(command) => {
+                write!(f, "UpdateTopicSchemaSettings({})", command)
+            }
             EntryCommand::CreatePartitions(command) => write!(f, "CreatePartitions({})", command),
             EntryCommand::DeletePartitions(command) => write!(f, "DeletePartitions({})", command),
             EntryCommand::CreateConsumerGroup(command) => {
@@ -164,6 +248,7 @@ impl Display for EntryCommand {
             EntryCommand::UpdateUser(command) => write!(f, "UpdateUser({})", command),
             EntryCommand::DeleteUser(command) => write!(f, "DeleteUser({})", command),
             EntryCommand::ChangePassword(command) => write!(f, "ChangePassword({})", command),
+            EntryCommand::RehashPassword(command) => write!(f, "RehashPassword({})", command),
             EntryCommand::UpdatePermissions(command) => write!(f, "UpdatePermissions({})", command),
             EntryCommand::CreatePersonalAccessToken(command) => {
                 write!(f, "CreatePersonalAccessToken({})", command)
@@ -171,6 +256,13 @@ impl Display for EntryCommand {
             EntryCommand::DeletePersonalAccessToken(command) => {
                 write!(f, "DeletePersonalAccessToken({})", command)
             }
+            EntryCommand::AddClusterNode(command) => write!(f, "AddClusterNode({})", command),
+            EntryCommand::RemoveClusterNode(command) => {
+                write!(f, "RemoveClusterNode({})", command)
+            }
+            EntryCommand::ElectClusterLeader(command) => {
+                write!(f, "ElectClusterLeader({})", command)
+            }
         }
     }
 }
@@ -14,6 +14,7 @@ use std::fmt::{Display, Formatter};
 /// - `flags` - Reserved for future use
 /// - `timestamp` - Timestamp when the command was issued
 /// - `user_id` - User ID of the user who issued the command
+/// - `permissions_id` - ID of the permissions the command was issued with, e.g. of the personal access token
 /// - `checksum` - Checksum of the entry
 /// - `code` - Command code
 /// - `command` - Payload of the command
@@ -27,6 +28,7 @@ pub struct StateEntry {
     pub flags: u64,
     pub timestamp: IggyTimestamp,
     pub user_id: u32,
+    pub permissions_id: u32,
     pub checksum: u32,
     pub context: Bytes,
     pub command: Bytes,
@@ -42,6 +44,7 @@ impl StateEntry {
         flags: u64,
         timestamp: IggyTimestamp,
         user_id: u32,
+        permissions_id: u32,
         checksum: u32,
         context: Bytes,
         command: Bytes,
@@ -54,6 +57,7 @@ impl StateEntry {
             flags,
             timestamp,
             user_id,
+            permissions_id,
             checksum,
             context,
             command,
@@ -73,11 +77,13 @@ impl StateEntry {
         flags: u64,
         timestamp: IggyTimestamp,
         user_id: u32,
+        permissions_id: u32,
         context: &Bytes,
         command: &Bytes,
     ) -> u32 {
-        let mut bytes =
-            BytesMut::with_capacity(8 + 8 + 4 + 4 + 8 + 8 + 4 + 4 + context.len() + command.len());
+        let mut bytes = BytesMut::with_capacity(
+            8 + 8 + 4 + 4 + 8 + 8 + 4 + 4 + 4 + context.len() + command.len(),
+        );
         bytes.put_u64_le(index);
         bytes.put_u64_le(term);
         bytes.put_u32_le(leader_id);
@@ -85,6 +91,7 @@ impl StateEntry {
         bytes.put_u64_le(flags);
         bytes.put_u64_le(timestamp.into());
         bytes.put_u32_le(user_id);
+        bytes.put_u32_le(permissions_id);
         bytes.put_u32_le(context.len() as u32);
         bytes.put_slice(context);
         bytes.extend(command);
@@ -96,7 +103,7 @@ impl Display for StateEntry {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "StateEntry {{ index: {}, term: {}, leader ID: {}, version: {}, flags: {}, timestamp: {}, user ID: {}, checksum: {} }}",
+            "StateEntry {{ index: {}, term: {}, leader ID: {}, version: {}, flags: {}, timestamp: {}, user ID: {}, permissions ID: {}, checksum: {} }}",
             self.index,
             self.term,
             self.leader_id,
@@ -104,6 +111,7 @@ impl Display for StateEntry {
             self.flags,
             self.timestamp,
             self.user_id,
+            self.permissions_id,
             self.checksum,
         )
     }
@@ -112,7 +120,7 @@ impl Display for StateEntry {
 impl BytesSerializable for StateEntry {
     fn to_bytes(&self) -> Bytes {
         let mut bytes = BytesMut::with_capacity(
-            8 + 8 + 4 + 4 + 8 + 8 + 4 + 4 + 4 + self.context.len() + self.command.len(),
+            8 + 8 + 4 + 4 + 8 + 8 + 4 + 4 + 4 + 4 + self.context.len() + self.command.len(),
         );
         bytes.put_u64_le(self.index);
         bytes.put_u64_le(self.term);
@@ -121,6 +129,7 @@ impl BytesSerializable for StateEntry {
         bytes.put_u64_le(self.flags);
         bytes.put_u64_le(self.timestamp.into());
         bytes.put_u32_le(self.user_id);
+        bytes.put_u32_le(self.permissions_id);
         bytes.put_u32_le(self.checksum);
         bytes.put_u32_le(self.context.len() as u32);
         bytes.put_slice(&self.context);
@@ -139,10 +148,11 @@ impl BytesSerializable for StateEntry {
         let flags = bytes.slice(24..32).get_u64_le();
         let timestamp = IggyTimestamp::from(bytes.slice(32..40).get_u64_le());
         let user_id = bytes.slice(40..44).get_u32_le();
-        let checksum = bytes.slice(44..48).get_u32_le();
-        let context_length = bytes.slice(48..52).get_u32_le() as usize;
-        let context = bytes.slice(52..52 + context_length);
-        let command = bytes.slice(52 + context_length..);
+        let permissions_id = bytes.slice(44..48).get_u32_le();
+        let checksum = bytes.slice(48..52).get_u32_le();
+        let context_length = bytes.slice(52..56).get_u32_le() as usize;
+        let context = bytes.slice(56..56 + context_length);
+        let command = bytes.slice(56 + context_length..);
 
         Ok(StateEntry {
             index,
@@ -152,6 +162,7 @@ impl BytesSerializable for StateEntry {
             flags,
             timestamp,
             user_id,
+            permissions_id,
             checksum,
             context,
             command,
@@ -1,4 +1,6 @@
 use crate::state::command::EntryCommand;
+use crate::state::snapshot::{StateSnapshot, StateSnapshotHeader};
+use crate::state::system::SystemState;
 use crate::state::{State, StateEntry, COMPONENT};
 use crate::streaming::persistence::persister::PersisterKind;
 use crate::streaming::utils::file;
@@ -15,21 +17,29 @@ use std::path::Path;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::io::{AsyncReadExt, BufReader};
+use tokio::sync::Mutex;
 use tracing::{debug, error, info};
 
 pub const BUF_READER_CAPACITY_BYTES: usize = 512 * 1000;
 const FILE_STATE_PARSE_ERROR: &str = "STATE - failed to parse file state";
+/// Length of the persisted entry fields preceding its context, including the context length.
+const ENTRY_HEADER_LENGTH: usize = 56;
+const SNAPSHOT_FILE_NAME: &str = "snapshot";
 
 #[derive(Debug)]
 pub struct FileState {
     current_index: AtomicU64,
+    /// Number of all the entries, including the ones replaced by the snapshot.
     entries_count: AtomicU64,
+    compacted_entries_count: AtomicU64,
     current_leader: AtomicU32,
     term: AtomicU64,
     version: u32,
     path: String,
+    snapshot_path: String,
     persister: Arc<PersisterKind>,
     encryptor: Option<Arc<EncryptorKind>>,
+    write_lock: Mutex<()>,
 }
 
 impl FileState {
@@ -39,15 +49,23 @@ impl FileState {
         persister: Arc<PersisterKind>,
         encryptor: Option<Arc<EncryptorKind>>,
     ) -> Self {
+        // The snapshot is stored next to the log file.
+        let snapshot_path = Path::new(path)
+            .with_file_name(SNAPSHOT_FILE_NAME)
+            .to_string_lossy()
+            .to_string();
         Self {
             current_index: AtomicU64::new(0),
             entries_count: AtomicU64::new(0),
+            compacted_entries_count: AtomicU64::new(0),
             current_leader: AtomicU32::new(0),
             term: AtomicU64::new(0),
             path: path.into(),
+            snapshot_path,
             persister,
             encryptor,
             version: version.get_numeric_version().expect("Invalid version"),
+            write_lock: Mutex::new(()),
         }
     }
 
@@ -59,9 +77,355 @@ impl FileState {
         self.entries_count.load(Ordering::SeqCst)
     }
 
+    /// Returns the number of entries replaced by the snapshot, which are no longer stored in the log.
+    pub fn compacted_entries_count(&self) -> u64 {
+        self.compacted_entries_count.load(Ordering::SeqCst)
+    }
+
     pub fn term(&self) -> u64 {
         self.term.load(Ordering::SeqCst)
     }
+
+    pub fn current_leader(&self) -> u32 {
+        self.current_leader.load(Ordering::SeqCst)
+    }
+
+    /// Sets the election term and the leader ID stored in the subsequently created entries.
+    pub fn set_leader(&self, term: u64, leader_id: u32) {
+        self.term.store(term, Ordering::SeqCst);
+        self.current_leader.store(leader_id, Ordering::SeqCst);
+    }
+
+    /// Creates the entry in the form in which it's persisted, i.e. with the command encrypted (if enabled),
+    /// while the checksum is always calculated using the plain command.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_entry(
+        &self,
+        index: u64,
+        term: u64,
+        leader_id: u32,
+        user_id: u32,
+        permissions_id: u32,
+        context: Bytes,
+        command: &EntryCommand,
+    ) -> Result<StateEntry, IggyError> {
+        let timestamp = IggyTimestamp::now();
+        let flags = 0;
+        let command = command.to_bytes();
+        let checksum = StateEntry::calculate_checksum(
+            index,
+            term,
+            leader_id,
+            self.version,
+            flags,
+            timestamp,
+            user_id,
+            permissions_id,
+            &context,
+            &command,
+        );
+        let command = self.encrypt_command(index, command)?;
+        Ok(StateEntry::new(
+            index,
+            term,
+            leader_id,
+            self.version,
+            flags,
+            timestamp,
+            user_id,
+            permissions_id,
+            checksum,
+            context,
+            command,
+        ))
+    }
+
+    /// Converts the entry loaded from the log (with the plain command) into its persisted form.
+    pub fn to_persisted_entry(&self, entry: &StateEntry) -> Result<StateEntry, IggyError> {
+        Ok(StateEntry::new(
+            entry.index,
+            entry.term,
+            entry.leader_id,
+            entry.version,
+            entry.flags,
+            entry.timestamp,
+            entry.user_id,
+            entry.permissions_id,
+            entry.checksum,
+            entry.context.clone(),
+            self.encrypt_command(entry.index, entry.command.clone())?,
+        ))
+    }
+
+    /// Converts the persisted entry (e.g. received from the other node) into the entry with the plain command,
+    /// and verifies its checksum.
+    pub fn from_persisted_entry(&self, entry: StateEntry) -> Result<StateEntry, IggyError> {
+        let command = self.decrypt_command(entry.index, entry.command)?;
+        EntryCommand::from_bytes(command.clone()).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to parse entry command from bytes")
+        })?;
+        let calculated_checksum = StateEntry::calculate_checksum(
+            entry.index,
+            entry.term,
+            entry.leader_id,
+            entry.version,
+            entry.flags,
+            entry.timestamp,
+            entry.user_id,
+            entry.permissions_id,
+            &entry.context,
+            &command,
+        );
+        if calculated_checksum != entry.checksum {
+            return Err(IggyError::InvalidStateEntryChecksum(
+                calculated_checksum,
+                entry.checksum,
+                entry.index,
+            ));
+        }
+
+        Ok(StateEntry::new(
+            entry.index,
+            entry.term,
+            entry.leader_id,
+            entry.version,
+            entry.flags,
+            entry.timestamp,
+            entry.user_id,
+            entry.permissions_id,
+            entry.checksum,
+            entry.context,
+            command,
+        ))
+    }
+
+    /// Appends the entry, which must be already in its persisted form, to the end of the log.
+    pub async fn append_entry(&self, entry: &StateEntry) -> Result<(), IggyError> {
+        self.persist_entry(entry).await?;
+        self.current_index.store(entry.index, Ordering::SeqCst);
+        self.entries_count.fetch_add(1, Ordering::SeqCst);
+        debug!("Appended state entry: {entry}");
+        Ok(())
+    }
+
+    async fn persist_entry(&self, entry: &StateEntry) -> Result<(), IggyError> {
+        let bytes = entry.to_bytes();
+        self.persister
+            .append(&self.path, &bytes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to append state entry data to file, path: {}, data size: {}",
+                    self.path,
+                    bytes.len()
+                )
+            })?;
+        Ok(())
+    }
+
+    /// Removes the entry with the given index and all the subsequent ones from the log.
+    pub async fn truncate(&self, from_index: u64) -> Result<(), IggyError> {
+        let bytes = tokio::fs::read(&self.path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read state file, path: {}",
+                    self.path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let mut position = 0;
+        let mut entries_count = 0;
+        let mut last_index = None;
+        while position + ENTRY_HEADER_LENGTH <= bytes.len() {
+            let index = u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap());
+            if index >= from_index {
+                break;
+            }
+
+            let context_length =
+                u32::from_le_bytes(bytes[position + 52..position + 56].try_into().unwrap())
+                    as usize;
+            let command_position = position + ENTRY_HEADER_LENGTH + context_length;
+            let command_length = u32::from_le_bytes(
+                bytes
+                    .get(command_position + 4..command_position + 8)
+                    .ok_or(IggyError::StateFileCorrupted)?
+                    .try_into()
+                    .unwrap(),
+            ) as usize;
+            position = command_position + 8 + command_length;
+            entries_count += 1;
+            last_index = Some(index);
+        }
+
+        if position == bytes.len() {
+            return Ok(());
+        }
+
+        self.persister
+            .overwrite(&self.path, &bytes[..position])
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to truncate state file, path: {}",
+                    self.path
+                )
+            })?;
+        let compacted_entries_count = self.compacted_entries_count();
+        self.entries_count
+            .store(compacted_entries_count + entries_count, Ordering::SeqCst);
+        self.current_index.store(
+            last_index.unwrap_or(compacted_entries_count.saturating_sub(1)),
+            Ordering::SeqCst,
+        );
+        info!("Truncated state entries starting from index: {from_index}, remaining entries: {entries_count}");
+        Ok(())
+    }
+
+    /// Saves the state materialized from the previous snapshot and all the entries of the log into the new snapshot,
+    /// and removes these entries from the log. Returns the index of the last entry covered by the snapshot,
+    /// unless there are fewer than `min_entries` entries in the log. At most `password_history` previous
+    /// password hashes of each user are kept in the snapshot.
+    pub async fn compact(
+        &self,
+        min_entries: u64,
+        password_history: u32,
+    ) -> Result<Option<u64>, IggyError> {
+        let _guard = self.write_lock.lock().await;
+        let log_entries_count = self.entries_count() - self.compacted_entries_count();
+        if log_entries_count == 0 || log_entries_count < min_entries {
+            debug!("State log contains {log_entries_count} entries, compaction is not required.");
+            return Ok(None);
+        }
+
+        let snapshot = self.load_snapshot().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to load state snapshot")
+        })?;
+        let mut entries = self.load_entries().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to load entries")
+        })?;
+        let state = match snapshot {
+            Some(snapshot) => {
+                entries.retain(|entry| entry.index > snapshot.index);
+                snapshot.state
+            }
+            None => SystemState::default(),
+        };
+        let Some(last_entry) = entries.last() else {
+            return Ok(None);
+        };
+
+        let index = last_entry.index;
+        let term = last_entry.term;
+        let snapshot = StateSnapshot {
+            index,
+            term,
+            timestamp: IggyTimestamp::now(),
+            state: SystemState::restore(state, entries, password_history)?,
+        };
+        let bytes = snapshot.to_bytes(self.encryptor.as_deref())?;
+        // The snapshot is replaced atomically, so that the previous one remains valid if the server stops while saving it.
+        let temp_snapshot_path = format!("{}.tmp", self.snapshot_path);
+        self.persister
+            .overwrite(&temp_snapshot_path, &bytes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save state snapshot, path: {temp_snapshot_path}"
+                )
+            })?;
+        file::rename(&temp_snapshot_path, &self.snapshot_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replace state snapshot, path: {}",
+                    self.snapshot_path
+                )
+            })
+            .map_err(|_| IggyError::CannotOverwriteFile)?;
+        // If the server stops before the log is cleared, the entries covered by the snapshot are skipped on startup.
+        self.persister
+            .overwrite(&self.path, &[])
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to clear state file, path: {}",
+                    self.path
+                )
+            })?;
+        self.compacted_entries_count
+            .store(index + 1, Ordering::SeqCst);
+        info!(
+            "Compacted state log into the snapshot, index: {index}, size: {}",
+            IggyByteSize::from(bytes.len() as u64).as_human_string()
+        );
+        Ok(Some(index))
+    }
+
+    async fn load_snapshot_header(&self) -> Result<Option<StateSnapshotHeader>, IggyError> {
+        let Some(bytes) = self.read_snapshot().await? else {
+            return Ok(None);
+        };
+
+        StateSnapshot::verify(&bytes).map(Some)
+    }
+
+    async fn read_snapshot(&self) -> Result<Option<Bytes>, IggyError> {
+        if !Path::new(&self.snapshot_path).exists() {
+            return Ok(None);
+        }
+
+        let bytes = tokio::fs::read(&self.snapshot_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read state snapshot, path: {}",
+                    self.snapshot_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        Ok(Some(Bytes::from(bytes)))
+    }
+
+    fn encrypt_command(&self, index: u64, command: Bytes) -> Result<Bytes, IggyError> {
+        let Some(encryptor) = &self.encryptor else {
+            return Ok(command);
+        };
+
+        debug!("Encrypting state entry command with index: {index}");
+        let command_code = command.slice(0..4).get_u32_le();
+        let command_length = command.slice(4..8).get_u32_le() as usize;
+        let command_payload = command.slice(8..8 + command_length);
+        let encrypted_command_payload = encryptor
+            .encrypt(&command_payload)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to encrypt state entry command, index: {index}"
+                )
+            })?;
+        let mut command_bytes = BytesMut::with_capacity(4 + 4 + encrypted_command_payload.len());
+        command_bytes.put_u32_le(command_code);
+        command_bytes.put_u32_le(encrypted_command_payload.len() as u32);
+        command_bytes.extend(encrypted_command_payload);
+        Ok(command_bytes.freeze())
+    }
+
+    fn decrypt_command(&self, index: u64, command: Bytes) -> Result<Bytes, IggyError> {
+        let Some(encryptor) = &self.encryptor else {
+            return Ok(command);
+        };
+
+        debug!("Decrypting state entry with index: {index}");
+        let command_code = command.slice(0..4).get_u32_le();
+        let command_length = command.slice(4..8).get_u32_le() as usize;
+        let command_payload = encryptor.decrypt(&command.slice(8..8 + command_length))?;
+        let mut command_bytes = BytesMut::with_capacity(4 + 4 + command_payload.len());
+        command_bytes.put_u32_le(command_code);
+        command_bytes.put_u32_le(command_payload.len() as u32);
+        command_bytes.extend(command_payload);
+        Ok(command_bytes.freeze())
+    }
 }
 
 impl State for FileState {
@@ -79,21 +443,63 @@ impl State for FileState {
                 })?;
         }
 
-        let entries = self.load_entries().await.with_error_context(|error| {
+        let snapshot_header = self
+            .load_snapshot_header()
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to load state snapshot, path: {}",
+                    self.snapshot_path
+                )
+            })?;
+        let mut entries = self.load_entries().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to load entries")
         })?;
-        let entries_count = entries.len() as u64;
-        self.entries_count.store(entries_count, Ordering::SeqCst);
-        if entries_count == 0 {
-            self.current_index.store(0, Ordering::SeqCst);
-        } else {
-            let last_index = entries[entries_count as usize - 1].index;
-            self.current_index.store(last_index, Ordering::SeqCst);
+        let mut compacted_entries_count = 0;
+        if let Some(snapshot_header) = snapshot_header {
+            info!("Loaded state snapshot: {snapshot_header}");
+            // The log might still contain the entries covered by the snapshot, if it wasn't cleared after saving it.
+            entries.retain(|entry| entry.index > snapshot_header.index);
+            if let Some(first_entry) = entries.first() {
+                if first_entry.index != snapshot_header.index + 1 {
+                    error!(
+                        "State file is corrupted, expected index: {}, got: {}",
+                        snapshot_header.index + 1,
+                        first_entry.index
+                    );
+                    return Err(IggyError::StateFileCorrupted);
+                }
+            }
+            compacted_entries_count = snapshot_header.index + 1;
         }
 
+        let entries_count = compacted_entries_count + entries.len() as u64;
+        self.compacted_entries_count
+            .store(compacted_entries_count, Ordering::SeqCst);
+        self.entries_count.store(entries_count, Ordering::SeqCst);
+        let current_index = match entries.last() {
+            Some(entry) => entry.index,
+            None => compacted_entries_count.saturating_sub(1),
+        };
+        self.current_index.store(current_index, Ordering::SeqCst);
         Ok(entries)
     }
 
+    async fn load_snapshot(&self) -> Result<Option<StateSnapshot>, IggyError> {
+        let Some(bytes) = self.read_snapshot().await? else {
+            return Ok(None);
+        };
+
+        StateSnapshot::from_bytes(bytes, self.encryptor.as_deref())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read state snapshot, path: {}",
+                    self.snapshot_path
+                )
+            })
+            .map(Some)
+    }
+
     async fn load_entries(&self) -> Result<Vec<StateEntry>, IggyError> {
         if !Path::new(&self.path).exists() {
             return Err(IggyError::StateFileNotFound);
@@ -191,6 +597,14 @@ impl State for FileState {
                 .with_error_context(|error| format!("{FILE_STATE_PARSE_ERROR} user_id. {error}"))
                 .map_err(|_| IggyError::InvalidNumberEncoding)?;
             total_size += 4;
+            let permissions_id = reader
+                .read_u32_le()
+                .await
+                .with_error_context(|error| {
+                    format!("{FILE_STATE_PARSE_ERROR} permissions_id. {error}")
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?;
+            total_size += 4;
             let checksum = reader
                 .read_u32_le()
                 .await
@@ -254,7 +668,16 @@ impl State for FileState {
                 format!("{COMPONENT} (error: {error}) - failed to parse entry command from bytes")
             })?;
             let calculated_checksum = StateEntry::calculate_checksum(
-                index, term, leader_id, version, flags, timestamp, user_id, &context, &command,
+                index,
+                term,
+                leader_id,
+                version,
+                flags,
+                timestamp,
+                user_id,
+                permissions_id,
+                &context,
+                &command,
             );
             let entry = StateEntry::new(
                 index,
@@ -264,6 +687,7 @@ impl State for FileState {
                 flags,
                 timestamp,
                 user_id,
+                permissions_id,
                 calculated_checksum,
                 context,
                 command,
@@ -289,75 +713,24 @@ impl State for FileState {
 
     async fn apply(&self, user_id: u32, command: EntryCommand) -> Result<(), IggyError> {
         debug!("Applying state entry with command: {command}, user ID: {user_id}");
-        let timestamp = IggyTimestamp::now();
+        let _guard = self.write_lock.lock().await;
         let index = if self.entries_count.load(Ordering::SeqCst) == 0 {
             0
         } else {
             self.current_index.fetch_add(1, Ordering::SeqCst) + 1
         };
-        let term = self.term.load(Ordering::SeqCst);
-        let current_leader = self.current_leader.load(Ordering::SeqCst);
-        let version = self.version;
-        let flags = 0;
-        let context = Bytes::new();
-        let mut command = command.to_bytes();
-        let checksum = StateEntry::calculate_checksum(
+        // The command is validated before it's applied, so the permissions of the user are not needed to replay it.
+        let entry = self.create_entry(
             index,
-            term,
-            current_leader,
-            version,
-            flags,
-            timestamp,
+            self.term(),
+            self.current_leader(),
             user_id,
-            &context,
-            &command,
-        );
-
-        if let Some(encryptor) = &self.encryptor {
-            debug!("Encrypting state entry command with index: {index}");
-            let command_code = command.slice(0..4).get_u32_le();
-            let mut command_length = command.slice(4..8).get_u32_le() as usize;
-            let command_payload = command.slice(8..8 + command_length);
-            let encrypted_command_payload = encryptor
-                .encrypt(&command_payload)
-                .with_error_context(|error| {
-                    format!(
-                        "{COMPONENT} (error: {error}) - failed to encrypt state entry command, index: {}",
-                        index
-                    )
-                })?;
-            command_length = encrypted_command_payload.len();
-            let mut command_bytes = BytesMut::with_capacity(4 + 4 + command_length);
-            command_bytes.put_u32_le(command_code);
-            command_bytes.put_u32_le(command_length as u32);
-            command_bytes.extend(encrypted_command_payload);
-            command = command_bytes.freeze();
-        }
-
-        let entry = StateEntry::new(
-            index,
-            term,
-            current_leader,
-            version,
-            flags,
-            timestamp,
             user_id,
-            checksum,
-            context,
-            command,
-        );
-        let bytes = entry.to_bytes();
+            Bytes::new(),
+            &command,
+        )?;
         self.entries_count.fetch_add(1, Ordering::SeqCst);
-        self.persister
-            .append(&self.path, &bytes)
-            .await
-            .with_error_context(|error| {
-                format!(
-                    "{COMPONENT} (error: {error}) - failed to append state entry data to file, path: {}, data size: {}",
-                    self.path,
-                    bytes.len()
-                )
-            })?;
+        self.persist_entry(&entry).await?;
         debug!("Applied state entry: {entry}");
         Ok(())
     }
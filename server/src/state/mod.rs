@@ -1,15 +1,19 @@
+use crate::cluster::node::ClusterNode;
 use crate::state::command::EntryCommand;
 use crate::state::entry::StateEntry;
+use crate::state::snapshot::StateSnapshot;
 use iggy::error::IggyError;
 #[cfg(test)]
 use mockall::automock;
 use std::fmt::Debug;
 use std::future::Future;
+use std::sync::Arc;
 
 pub mod command;
 pub mod entry;
 pub mod file;
 pub mod models;
+pub mod snapshot;
 pub mod system;
 
 pub const COMPONENT: &str = "STATE";
@@ -17,6 +21,7 @@ pub const COMPONENT: &str = "STATE";
 #[derive(Debug)]
 pub enum StateKind {
     File(file::FileState),
+    Cluster(Arc<ClusterNode>),
     #[cfg(test)]
     Mock(MockState),
 }
@@ -25,6 +30,9 @@ pub enum StateKind {
 pub trait State: Send {
     fn init(&self) -> impl Future<Output = Result<Vec<StateEntry>, IggyError>> + Send;
     fn load_entries(&self) -> impl Future<Output = Result<Vec<StateEntry>, IggyError>> + Send;
+    fn load_snapshot(
+        &self,
+    ) -> impl Future<Output = Result<Option<StateSnapshot>, IggyError>> + Send;
     fn apply(
         &self,
         user_id: u32,
@@ -36,6 +44,7 @@ impl StateKind {
     pub async fn init(&self) -> Result<Vec<StateEntry>, IggyError> {
         match self {
             Self::File(s) => s.init().await,
+            Self::Cluster(s) => s.init().await,
             #[cfg(test)]
             Self::Mock(s) => s.init().await,
         }
@@ -44,16 +53,54 @@ impl StateKind {
     pub async fn load_entries(&self) -> Result<Vec<StateEntry>, IggyError> {
         match self {
             Self::File(s) => s.load_entries().await,
+            Self::Cluster(s) => s.load_entries().await,
             #[cfg(test)]
             Self::Mock(s) => s.load_entries().await,
         }
     }
 
+    pub async fn load_snapshot(&self) -> Result<Option<StateSnapshot>, IggyError> {
+        match self {
+            Self::File(s) => s.load_snapshot().await,
+            Self::Cluster(s) => s.load_snapshot().await,
+            #[cfg(test)]
+            Self::Mock(s) => s.load_snapshot().await,
+        }
+    }
+
     pub async fn apply(&self, user_id: u32, command: EntryCommand) -> Result<(), IggyError> {
         match self {
             Self::File(s) => s.apply(user_id, command).await,
+            Self::Cluster(s) => s.apply(user_id, command).await,
             #[cfg(test)]
             Self::Mock(s) => s.apply(user_id, command).await,
         }
     }
+
+    /// Returns an error if the state can't be changed by this server, i.e. it's not the cluster leader.
+    pub fn ensure_leader(&self) -> Result<(), IggyError> {
+        match self {
+            Self::Cluster(s) => s.ensure_leader(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Compacts the state log into the snapshot, unless it's replicated to the other nodes of the cluster.
+    pub async fn compact(
+        &self,
+        min_entries: u64,
+        password_history: u32,
+    ) -> Result<Option<u64>, IggyError> {
+        match self {
+            Self::File(s) => s.compact(min_entries, password_history).await,
+            _ => Ok(None),
+        }
+    }
+
+    pub fn cluster_node(&self) -> Option<&Arc<ClusterNode>> {
+        match self {
+            Self::Cluster(s) => Some(s),
+            _ => None,
+        }
+    }
 }
@@ -5,12 +5,20 @@ use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::Command;
 use iggy::error::IggyError;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use iggy::schemas::register_topic_schema::RegisterTopicSchema;
 use iggy::validatable::Validatable;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::str::from_utf8;
 
+/// The codes of the commands which are used only by the cluster, hence not available in the SDK.
+pub const ADD_CLUSTER_NODE_CODE: u32 = 900;
+pub const REMOVE_CLUSTER_NODE_CODE: u32 = 901;
+pub const ELECT_CLUSTER_LEADER_CODE: u32 = 902;
+/// The code of the password rehash applied by the server itself on the login, hence not available in the SDK.
+pub const REHASH_PASSWORD_CODE: u32 = 910;
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreatePersonalAccessTokenWithHash {
     pub command: CreatePersonalAccessToken,
@@ -87,3 +95,328 @@ impl Display for CreatePersonalAccessTokenWithHash {
         )
     }
 }
+
+/// The registered schema, along with the ID and the version assigned to it by the server.
+/// Both are 0 if the schema is replicated in the cluster, as they are assigned once the entry is committed.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RegisterTopicSchemaWithId {
+    pub command: RegisterTopicSchema,
+    pub schema_id: u32,
+    pub version: u32,
+}
+
+impl Validatable<IggyError> for RegisterTopicSchemaWithId {
+    fn validate(&self) -> Result<(), IggyError> {
+        if (self.schema_id == 0) != (self.version == 0) {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        self.command.validate()
+    }
+}
+
+impl Command for RegisterTopicSchemaWithId {
+    fn code(&self) -> u32 {
+        self.command.code()
+    }
+}
+
+impl BytesSerializable for RegisterTopicSchemaWithId {
+    fn to_bytes(&self) -> Bytes {
+        let command_bytes = self.command.to_bytes();
+        let mut bytes = BytesMut::with_capacity(4 + command_bytes.len() + 4 + 4);
+        bytes.put_u32_le(command_bytes.len() as u32);
+        bytes.put_slice(&command_bytes);
+        bytes.put_u32_le(self.schema_id);
+        bytes.put_u32_le(self.version);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let command_length = u32::from_le_bytes(
+            bytes
+                .get(0..4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        let position = 4 + command_length;
+        if bytes.len() != position + 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = RegisterTopicSchema::from_bytes(bytes.slice(4..position))
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to parse register topic schema command"
+                )
+            })?;
+        let schema_id = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let version = u32::from_le_bytes(
+            bytes[position + 4..position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(Self {
+            command,
+            schema_id,
+            version,
+        })
+    }
+}
+
+impl Display for RegisterTopicSchemaWithId {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "RegisterTopicSchemaWithId {{ command: {}, schema_id: {}, version: {} }}",
+            self.command, self.schema_id, self.version
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AddClusterNode {
+    pub node_id: u32,
+    pub address: String,
+}
+
+impl Validatable<IggyError> for AddClusterNode {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.node_id == 0 || self.address.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl Command for AddClusterNode {
+    fn code(&self) -> u32 {
+        ADD_CLUSTER_NODE_CODE
+    }
+}
+
+impl BytesSerializable for AddClusterNode {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4 + 4 + self.address.len());
+        bytes.put_u32_le(self.node_id);
+        bytes.put_u32_le(self.address.len() as u32);
+        bytes.put_slice(self.address.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        if bytes.len() < 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let node_id = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let address_length = u32::from_le_bytes(
+            bytes[4..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        let address = from_utf8(
+            bytes
+                .get(8..8 + address_length)
+                .ok_or(IggyError::InvalidCommand)?,
+        )
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+        Ok(Self { node_id, address })
+    }
+}
+
+impl Display for AddClusterNode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "AddClusterNode {{ node_id: {}, address: {} }}",
+            self.node_id, self.address
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RemoveClusterNode {
+    pub node_id: u32,
+}
+
+impl Validatable<IggyError> for RemoveClusterNode {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.node_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl Command for RemoveClusterNode {
+    fn code(&self) -> u32 {
+        REMOVE_CLUSTER_NODE_CODE
+    }
+}
+
+impl BytesSerializable for RemoveClusterNode {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.node_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let node_id = u32::from_le_bytes(
+            bytes
+                .get(0..4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(Self { node_id })
+    }
+}
+
+impl Display for RemoveClusterNode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "RemoveClusterNode {{ node_id: {} }}", self.node_id)
+    }
+}
+
+/// Appended by the newly elected leader, to commit the entries from the previous terms.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ElectClusterLeader {
+    pub node_id: u32,
+}
+
+impl Validatable<IggyError> for ElectClusterLeader {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.node_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl Command for ElectClusterLeader {
+    fn code(&self) -> u32 {
+        ELECT_CLUSTER_LEADER_CODE
+    }
+}
+
+impl BytesSerializable for ElectClusterLeader {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.node_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let node_id = u32::from_le_bytes(
+            bytes
+                .get(0..4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(Self { node_id })
+    }
+}
+
+impl Display for ElectClusterLeader {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "ElectClusterLeader {{ node_id: {} }}", self.node_id)
+    }
+}
+
+/// Replaces the password hash with the one using the currently configured hashing parameters, while the password itself is unchanged.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RehashPassword {
+    pub user_id: u32,
+    pub password_hash: String,
+}
+
+impl Validatable<IggyError> for RehashPassword {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.password_hash.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl Command for RehashPassword {
+    fn code(&self) -> u32 {
+        REHASH_PASSWORD_CODE
+    }
+}
+
+impl BytesSerializable for RehashPassword {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4 + 4 + self.password_hash.len());
+        bytes.put_u32_le(self.user_id);
+        bytes.put_u32_le(self.password_hash.len() as u32);
+        bytes.put_slice(self.password_hash.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        if bytes.len() < 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let user_id = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let password_hash_length = u32::from_le_bytes(
+            bytes[4..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        let password_hash = from_utf8(
+            bytes
+                .get(8..8 + password_hash_length)
+                .ok_or(IggyError::InvalidCommand)?,
+        )
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+        Ok(Self {
+            user_id,
+            password_hash,
+        })
+    }
+}
+
+impl Display for RehashPassword {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "RehashPassword {{ user_id: {} }}", self.user_id)
+    }
+}
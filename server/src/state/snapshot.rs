@@ -0,0 +1,356 @@
+use crate::state::system::{StreamState, SystemState, UserState};
+use ahash::AHashMap;
+use anyhow::Context;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy::error::IggyError;
+use iggy::models::topic_template::{TopicDefaults, TopicTemplate};
+use iggy::utils::checksum;
+use iggy::utils::crypto::EncryptorKind;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+const SNAPSHOT_VERSION: u32 = 3;
+/// Version of the snapshots created before the topic schemas were part of the state.
+const SNAPSHOT_VERSION_WITHOUT_TOPIC_SCHEMAS: u32 = 2;
+/// Version of the snapshots created before the topic templates and the stream topic defaults were part of the state.
+const SNAPSHOT_VERSION_WITHOUT_TOPIC_TEMPLATES: u32 = 1;
+/// Length of the persisted snapshot fields preceding its payload.
+pub const SNAPSHOT_HEADER_LENGTH: usize = 4 + 8 + 8 + 8 + 4 + 4;
+
+/// Snapshot of the materialized state, covering all the entries of the log up to (and including) the `index`.
+/// - `index` - Index of the last entry applied to the state
+/// - `term` - Election term of the last entry applied to the state
+/// - `timestamp` - Timestamp when the snapshot was created
+/// - `state` - Materialized state, serialized (and encrypted, if enabled) as the payload of the snapshot
+#[derive(Debug)]
+pub struct StateSnapshot {
+    pub index: u64,
+    pub term: u64,
+    pub timestamp: IggyTimestamp,
+    pub state: SystemState,
+}
+
+/// Header of the persisted snapshot, the checksum is calculated using the persisted payload,
+/// thus it can be verified without decrypting the state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StateSnapshotHeader {
+    pub version: u32,
+    pub index: u64,
+    pub term: u64,
+    pub timestamp: IggyTimestamp,
+    pub checksum: u32,
+    pub payload_length: u32,
+}
+
+impl StateSnapshot {
+    pub fn to_bytes(&self, encryptor: Option<&EncryptorKind>) -> Result<Bytes, IggyError> {
+        let payload = bincode::serialize(&self.state)
+            .with_context(|| format!("Failed to serialize state snapshot, index: {}", self.index))
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        let payload = match encryptor {
+            Some(encryptor) => encryptor.encrypt(&payload)?,
+            None => payload,
+        };
+
+        let mut bytes = BytesMut::with_capacity(SNAPSHOT_HEADER_LENGTH + payload.len());
+        bytes.put_u32_le(SNAPSHOT_VERSION);
+        bytes.put_u64_le(self.index);
+        bytes.put_u64_le(self.term);
+        bytes.put_u64_le(self.timestamp.into());
+        bytes.put_u32_le(Self::calculate_checksum(
+            SNAPSHOT_VERSION,
+            self.index,
+            self.term,
+            self.timestamp,
+            &payload,
+        ));
+        bytes.put_u32_le(payload.len() as u32);
+        bytes.put_slice(&payload);
+        Ok(bytes.freeze())
+    }
+
+    pub fn from_bytes(bytes: Bytes, encryptor: Option<&EncryptorKind>) -> Result<Self, IggyError> {
+        let header = Self::verify(&bytes)?;
+        let payload = bytes.slice(SNAPSHOT_HEADER_LENGTH..);
+        let payload = match encryptor {
+            Some(encryptor) => encryptor.decrypt(&payload)?,
+            None => payload.to_vec(),
+        };
+        let state = match header.version {
+            SNAPSHOT_VERSION_WITHOUT_TOPIC_TEMPLATES => {
+                bincode::deserialize::<SystemStateWithoutTopicTemplates>(&payload)
+                    .map(SystemState::from)
+            }
+            SNAPSHOT_VERSION_WITHOUT_TOPIC_SCHEMAS => {
+                bincode::deserialize::<SystemStateWithoutTopicSchemas>(&payload)
+                    .map(SystemState::from)
+            }
+            _ => bincode::deserialize(&payload),
+        }
+        .with_context(|| {
+            format!(
+                "Failed to deserialize state snapshot, index: {}",
+                header.index
+            )
+        })
+        .map_err(|_| IggyError::CannotDeserializeResource)?;
+        Ok(StateSnapshot {
+            index: header.index,
+            term: header.term,
+            timestamp: header.timestamp,
+            state,
+        })
+    }
+
+    /// Reads the header of the persisted snapshot and verifies the checksum of its payload.
+    pub fn verify(bytes: &[u8]) -> Result<StateSnapshotHeader, IggyError> {
+        if bytes.len() < SNAPSHOT_HEADER_LENGTH {
+            return Err(IggyError::StateFileCorrupted);
+        }
+
+        let mut header = &bytes[..SNAPSHOT_HEADER_LENGTH];
+        let header = StateSnapshotHeader {
+            version: header.get_u32_le(),
+            index: header.get_u64_le(),
+            term: header.get_u64_le(),
+            timestamp: IggyTimestamp::from(header.get_u64_le()),
+            checksum: header.get_u32_le(),
+            payload_length: header.get_u32_le(),
+        };
+        if !(SNAPSHOT_VERSION_WITHOUT_TOPIC_TEMPLATES..=SNAPSHOT_VERSION).contains(&header.version)
+        {
+            return Err(IggyError::StateFileCorrupted);
+        }
+
+        let payload = &bytes[SNAPSHOT_HEADER_LENGTH..];
+        if payload.len() != header.payload_length as usize {
+            return Err(IggyError::StateFileCorrupted);
+        }
+
+        let calculated_checksum = Self::calculate_checksum(
+            header.version,
+            header.index,
+            header.term,
+            header.timestamp,
+            payload,
+        );
+        if calculated_checksum != header.checksum {
+            return Err(IggyError::InvalidStateSnapshotChecksum(
+                calculated_checksum,
+                header.checksum,
+                header.index,
+            ));
+        }
+
+        Ok(header)
+    }
+
+    fn calculate_checksum(
+        version: u32,
+        index: u64,
+        term: u64,
+        timestamp: IggyTimestamp,
+        payload: &[u8],
+    ) -> u32 {
+        let mut bytes = BytesMut::with_capacity(SNAPSHOT_HEADER_LENGTH + payload.len());
+        bytes.put_u32_le(version);
+        bytes.put_u64_le(index);
+        bytes.put_u64_le(term);
+        bytes.put_u64_le(timestamp.into());
+        bytes.put_u32_le(payload.len() as u32);
+        bytes.put_slice(payload);
+        checksum::calculate(&bytes.freeze())
+    }
+}
+
+/// The state persisted by the snapshots of version 1, which can still be loaded.
+#[derive(Debug, Serialize, Deserialize)]
+struct SystemStateWithoutTopicTemplates {
+    streams: AHashMap<u32, StreamState>,
+    users: AHashMap<u32, UserState>,
+    current_stream_id: u32,
+    current_user_id: u32,
+}
+
+impl From<SystemStateWithoutTopicTemplates> for SystemState {
+    fn from(state: SystemStateWithoutTopicTemplates) -> Self {
+        SystemState {
+            streams: state.streams,
+            users: state.users,
+            current_stream_id: state.current_stream_id,
+            current_user_id: state.current_user_id,
+            topic_templates: AHashMap::new(),
+            stream_topic_defaults: AHashMap::new(),
+            topic_schemas: AHashMap::new(),
+            current_schema_id: 0,
+        }
+    }
+}
+
+/// The state persisted by the snapshots of version 2, which can still be loaded.
+#[derive(Debug, Serialize, Deserialize)]
+struct SystemStateWithoutTopicSchemas {
+    streams: AHashMap<u32, StreamState>,
+    users: AHashMap<u32, UserState>,
+    current_stream_id: u32,
+    current_user_id: u32,
+    topic_templates: AHashMap<String, TopicTemplate>,
+    stream_topic_defaults: AHashMap<u32, TopicDefaults>,
+}
+
+impl From<SystemStateWithoutTopicSchemas> for SystemState {
+    fn from(state: SystemStateWithoutTopicSchemas) -> Self {
+        SystemState {
+            streams: state.streams,
+            users: state.users,
+            current_stream_id: state.current_stream_id,
+            current_user_id: state.current_user_id,
+            topic_templates: state.topic_templates,
+            stream_topic_defaults: state.stream_topic_defaults,
+            topic_schemas: AHashMap::new(),
+            current_schema_id: 0,
+        }
+    }
+}
+
+impl Display for StateSnapshotHeader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "StateSnapshot {{ version: {}, index: {}, term: {}, timestamp: {}, checksum: {}, payload length: {} }}",
+            self.version,
+            self.index,
+            self.term,
+            self.timestamp,
+            self.checksum,
+            self.payload_length,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> StateSnapshot {
+        let mut state = SystemState {
+            current_stream_id: 1,
+            ..Default::default()
+        };
+        state.streams.insert(
+            1,
+            StreamState {
+                id: 1,
+                name: "stream".to_string(),
+                created_at: IggyTimestamp::from(1000),
+                created_by: 1,
+                topics: AHashMap::new(),
+                current_topic_id: 0,
+            },
+        );
+        StateSnapshot {
+            index: 10,
+            term: 2,
+            timestamp: IggyTimestamp::from(2000),
+            state,
+        }
+    }
+
+    #[test]
+    fn snapshot_should_be_serialized_and_deserialized() {
+        let bytes = snapshot().to_bytes(None).unwrap();
+        let header = StateSnapshot::verify(&bytes).unwrap();
+        assert_eq!(header.index, 10);
+        assert_eq!(header.term, 2);
+
+        let deserialized = StateSnapshot::from_bytes(bytes, None).unwrap();
+        assert_eq!(deserialized.index, 10);
+        assert_eq!(deserialized.timestamp, IggyTimestamp::from(2000));
+        assert_eq!(deserialized.state.current_stream_id, 1);
+        assert_eq!(deserialized.state.streams.get(&1).unwrap().name, "stream");
+    }
+
+    #[test]
+    fn corrupted_snapshot_should_fail_checksum_verification() {
+        let mut bytes = snapshot().to_bytes(None).unwrap().to_vec();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        assert!(matches!(
+            StateSnapshot::verify(&bytes),
+            Err(IggyError::InvalidStateSnapshotChecksum(_, _, 10))
+        ));
+    }
+
+    #[test]
+    fn truncated_snapshot_should_be_rejected() {
+        let bytes = snapshot().to_bytes(None).unwrap();
+        assert!(StateSnapshot::verify(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    fn legacy_snapshot_bytes(version: u32, payload: &[u8]) -> Bytes {
+        let timestamp = IggyTimestamp::from(2000);
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(version);
+        bytes.put_u64_le(10);
+        bytes.put_u64_le(2);
+        bytes.put_u64_le(timestamp.into());
+        bytes.put_u32_le(StateSnapshot::calculate_checksum(
+            version, 10, 2, timestamp, payload,
+        ));
+        bytes.put_u32_le(payload.len() as u32);
+        bytes.put_slice(payload);
+        bytes.freeze()
+    }
+
+    #[test]
+    fn snapshot_without_topic_templates_should_be_deserialized() {
+        let state = SystemStateWithoutTopicTemplates {
+            streams: snapshot().state.streams,
+            users: AHashMap::new(),
+            current_stream_id: 1,
+            current_user_id: 0,
+        };
+        let payload = bincode::serialize(&state).unwrap();
+        let bytes = legacy_snapshot_bytes(SNAPSHOT_VERSION_WITHOUT_TOPIC_TEMPLATES, &payload);
+
+        let deserialized = StateSnapshot::from_bytes(bytes, None).unwrap();
+        assert_eq!(deserialized.index, 10);
+        assert_eq!(deserialized.state.streams.get(&1).unwrap().name, "stream");
+        assert!(deserialized.state.topic_templates.is_empty());
+        assert!(deserialized.state.stream_topic_defaults.is_empty());
+        assert!(deserialized.state.topic_schemas.is_empty());
+    }
+
+    #[test]
+    fn snapshot_without_topic_schemas_should_be_deserialized() {
+        let mut topic_templates = AHashMap::new();
+        topic_templates.insert(
+            "template".to_string(),
+            TopicTemplate {
+                name: "template".to_string(),
+                partitions_count: 1,
+                compression_algorithm: Default::default(),
+                message_expiry: Default::default(),
+                max_topic_size: Default::default(),
+                replication_factor: None,
+            },
+        );
+        let state = SystemStateWithoutTopicSchemas {
+            streams: snapshot().state.streams,
+            users: AHashMap::new(),
+            current_stream_id: 1,
+            current_user_id: 0,
+            topic_templates,
+            stream_topic_defaults: AHashMap::new(),
+        };
+        let payload = bincode::serialize(&state).unwrap();
+        let bytes = legacy_snapshot_bytes(SNAPSHOT_VERSION_WITHOUT_TOPIC_SCHEMAS, &payload);
+
+        let deserialized = StateSnapshot::from_bytes(bytes, None).unwrap();
+        assert_eq!(deserialized.state.streams.get(&1).unwrap().name, "stream");
+        assert!(deserialized.state.topic_templates.contains_key("template"));
+        assert!(deserialized.state.topic_schemas.is_empty());
+        assert_eq!(deserialized.state.current_schema_id, 0);
+    }
+}
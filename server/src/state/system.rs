@@ -6,29 +6,41 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::models::permissions::Permissions;
+use iggy::models::schema::{Schema, SchemaCompatibility};
+use iggy::models::topic_template::{TopicDefaults, TopicTemplate};
 use iggy::models::user_status::UserStatus;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::timestamp::IggyTimestamp;
 use iggy::utils::topic_size::MaxTopicSize;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use tracing::debug;
 
-#[derive(Debug)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct SystemState {
     pub streams: AHashMap<u32, StreamState>,
     pub users: AHashMap<u32, UserState>,
+    pub current_stream_id: u32,
+    pub current_user_id: u32,
+    pub topic_templates: AHashMap<String, TopicTemplate>,
+    /// Default settings of the topics created in the streams, only the streams with the defaults set are included.
+    pub stream_topic_defaults: AHashMap<u32, TopicDefaults>,
+    /// Schema registries of the topics by stream ID and topic ID, only the topics with the registry changed are included.
+    pub topic_schemas: AHashMap<u32, AHashMap<u32, TopicSchemasState>>,
+    pub current_schema_id: u32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct StreamState {
     pub id: u32,
     pub name: String,
     pub created_at: IggyTimestamp,
+    pub created_by: u32,
     pub topics: AHashMap<u32, TopicState>,
     pub current_topic_id: u32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TopicState {
     pub id: u32,
     pub name: String,
@@ -39,44 +51,73 @@ pub struct TopicState {
     pub max_topic_size: MaxTopicSize,
     pub replication_factor: Option<u8>,
     pub created_at: IggyTimestamp,
+    pub created_by: u32,
     pub current_consumer_group_id: u32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TopicSchemasState {
+    pub compatibility: SchemaCompatibility,
+    pub validate_payloads: bool,
+    pub current_version: u32,
+    pub schemas: Vec<Schema>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PartitionState {
     pub id: u32,
     pub created_at: IggyTimestamp,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PersonalAccessTokenState {
     pub name: String,
     pub token_hash: String,
     pub expiry_at: Option<IggyTimestamp>,
+    pub permissions: Option<Permissions>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct UserState {
     pub id: u32,
     pub username: String,
     pub password_hash: String,
+    pub password_changed_at: IggyTimestamp,
+    /// Hashes of the previous passwords, limited to the configured history size, the most recent one is the last.
+    pub password_history: Vec<String>,
     pub status: UserStatus,
     pub permissions: Option<Permissions>,
     pub personal_access_tokens: AHashMap<String, PersonalAccessTokenState>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ConsumerGroupState {
     pub id: u32,
     pub name: String,
 }
 
 impl SystemState {
-    pub async fn init(entries: Vec<StateEntry>) -> Result<Self, IggyError> {
-        let mut streams = AHashMap::new();
-        let mut users = AHashMap::new();
-        let mut current_stream_id = 0;
-        let mut current_user_id = 0;
+    pub async fn init(entries: Vec<StateEntry>, password_history: u32) -> Result<Self, IggyError> {
+        Self::restore(SystemState::default(), entries, password_history)
+    }
+
+    /// Applies the entries to the state, e.g. the one loaded from the snapshot.
+    /// At most `password_history` previous password hashes are kept for each user.
+    pub fn restore(
+        state: SystemState,
+        entries: Vec<StateEntry>,
+        password_history: u32,
+    ) -> Result<Self, IggyError> {
+        let SystemState {
+            mut streams,
+            mut users,
+            mut current_stream_id,
+            mut current_user_id,
+            mut topic_templates,
+            mut stream_topic_defaults,
+            mut topic_schemas,
+            mut current_schema_id,
+        } = state;
         for entry in entries {
             debug!("Processing state entry: {entry}",);
             match entry.command().with_error_context(|error| {
@@ -93,6 +134,7 @@ impl SystemState {
                         topics: AHashMap::new(),
                         current_topic_id: 0,
                         created_at: entry.timestamp,
+                        created_by: entry.user_id,
                     };
                     streams.insert(stream.id, stream);
                 }
@@ -106,6 +148,8 @@ impl SystemState {
                 EntryCommand::DeleteStream(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
                     streams.remove(&stream_id);
+                    stream_topic_defaults.remove(&stream_id);
+                    topic_schemas.remove(&stream_id);
                 }
                 EntryCommand::PurgeStream(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
@@ -114,6 +158,19 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
                     // It only affects the segments which are not part of the state
                 }
+                EntryCommand::UpdateStreamTopicDefaults(command) => {
+                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let topic_defaults = TopicDefaults {
+                        message_expiry: command.message_expiry,
+                        max_topic_size: command.max_topic_size,
+                        replication_factor: command.replication_factor,
+                    };
+                    if topic_defaults == TopicDefaults::default() {
+                        stream_topic_defaults.remove(&stream_id);
+                    } else {
+                        stream_topic_defaults.insert(stream_id, topic_defaults);
+                    }
+                }
                 EntryCommand::CreateTopic(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
                     let stream = streams
@@ -133,6 +190,7 @@ impl SystemState {
                         max_topic_size: command.max_topic_size,
                         replication_factor: command.replication_factor,
                         created_at: entry.timestamp,
+                        created_by: entry.user_id,
                         partitions: if command.partitions_count > 0 {
                             let mut partitions = AHashMap::new();
                             for i in 1..=command.partitions_count {
@@ -174,6 +232,9 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
                     let topic_id = find_topic_id(&stream.topics, &command.topic_id);
                     stream.topics.remove(&topic_id);
+                    if let Some(stream_schemas) = topic_schemas.get_mut(&stream_id) {
+                        stream_schemas.remove(&topic_id);
+                    }
                 }
                 EntryCommand::PurgeTopic(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
@@ -187,6 +248,66 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
                     // It only affects the segments which are not part of the state
                 }
+                EntryCommand::CreateTopicTemplate(command) => {
+                    let template = TopicTemplate {
+                        name: command.name,
+                        partitions_count: command.partitions_count,
+                        compression_algorithm: command.compression_algorithm,
+                        message_expiry: command.message_expiry,
+                        max_topic_size: command.max_topic_size,
+                        replication_factor: command.replication_factor,
+                    };
+                    topic_templates.insert(template.name.clone(), template);
+                }
+                EntryCommand::DeleteTopicTemplate(command) => {
+                    topic_templates.remove(&command.name);
+                }
+                EntryCommand::RegisterTopicSchema(command) => {
+                    let (stream_id, topic_id) = find_stream_and_topic_id(
+                        &streams,
+                        &command.command.stream_id,
+                        &command.command.topic_id,
+                    );
+                    let registry = topic_schemas
+                        .entry(stream_id)
+                        .or_default()
+                        .entry(topic_id)
+                        .or_default();
+                    registry.current_version = registry.current_version.max(command.version);
+                    registry.schemas.push(Schema {
+                        id: command.schema_id,
+                        stream_id,
+                        topic_id,
+                        version: command.version,
+                        schema_type: command.command.schema_type,
+                        definition: command.command.definition,
+                        created_at: entry.timestamp,
+                    });
+                    current_schema_id = current_schema_id.max(command.schema_id);
+                }
+                EntryCommand::DeleteTopicSchema(command) => {
+                    let (stream_id, topic_id) =
+                        find_stream_and_topic_id(&streams, &command.stream_id, &command.topic_id);
+                    if let Some(registry) = topic_schemas
+                        .get_mut(&stream_id)
+                        .and_then(|stream_schemas| stream_schemas.get_mut(&topic_id))
+                    {
+                        registry
+                            .schemas
+                            .retain(|schema| schema.version != command.version);
+                    }
+                }
+                EntryCommand::UpdateTopicSchemaSettings(command) => {
+                    let (stream_id, topic_id) =
+                        find_stream_and_topic_id(&streams, &command.stream_id, &command.topic_id);
+                    let registry = topic_schemas
+                        .entry(stream_id)
+                        .or_default()
+                        .entry(topic_id)
+                        .or_default();
+                    registry.compatibility = command.compatibility;
+                    registry.validate_payloads = command.validate_payloads;
+                }
                 EntryCommand::CreatePartitions(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
                     let stream = streams
@@ -283,6 +404,8 @@ impl SystemState {
                         id: current_user_id,
                         username: command.username,
                         password_hash: command.password, // This is already hashed
+                        password_changed_at: entry.timestamp,
+                        password_history: Vec::new(),
                         status: command.status,
                         permissions: command.permissions,
                         personal_access_tokens: AHashMap::new(),
@@ -310,7 +433,21 @@ impl SystemState {
                     let user = users
                         .get_mut(&user_id)
                         .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
-                    user.password_hash = command.new_password // This is already hashed
+                    let previous_password_hash =
+                        std::mem::replace(&mut user.password_hash, command.new_password); // This is already hashed
+                    user.password_history.push(previous_password_hash);
+                    let excess = user
+                        .password_history
+                        .len()
+                        .saturating_sub(password_history as usize);
+                    user.password_history.drain(..excess);
+                    user.password_changed_at = entry.timestamp;
+                }
+                EntryCommand::RehashPassword(command) => {
+                    let user = users.get_mut(&command.user_id).unwrap_or_else(|| {
+                        panic!("{}", format!("User: {} not found", command.user_id))
+                    });
+                    user.password_hash = command.password_hash;
                 }
                 EntryCommand::UpdatePermissions(command) => {
                     let user_id = find_user_id(&users, &command.user_id);
@@ -350,6 +487,7 @@ impl SystemState {
                             name: command.command.name,
                             token_hash,
                             expiry_at,
+                            permissions: command.command.permissions,
                         },
                     );
                 }
@@ -368,10 +506,24 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
                     user.personal_access_tokens.remove(&command.name);
                 }
+                EntryCommand::AddClusterNode(_)
+                | EntryCommand::RemoveClusterNode(_)
+                | EntryCommand::ElectClusterLeader(_) => {
+                    // The cluster membership is tracked by the cluster node itself.
+                }
             }
         }
 
-        let state = SystemState { streams, users };
+        let state = SystemState {
+            streams,
+            users,
+            current_stream_id,
+            current_user_id,
+            topic_templates,
+            stream_topic_defaults,
+            topic_schemas,
+            current_schema_id,
+        };
         debug!("+++ State +++");
         debug!("{state}");
         debug!("+++ State +++");
@@ -415,6 +567,18 @@ fn find_topic_id(topics: &AHashMap<u32, TopicState>, topic_id: &Identifier) -> u
     }
 }
 
+fn find_stream_and_topic_id(
+    streams: &AHashMap<u32, StreamState>,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+) -> (u32, u32) {
+    let stream_id = find_stream_id(streams, stream_id);
+    let stream = streams
+        .get(&stream_id)
+        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+    (stream_id, find_topic_id(&stream.topics, topic_id))
+}
+
 fn find_consumer_group_id(
     groups: &AHashMap<u32, ConsumerGroupState>,
     group_id: &Identifier,
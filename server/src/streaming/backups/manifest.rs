@@ -0,0 +1,158 @@
+use crate::streaming::backups::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::backup::{BackupInfo, BackupKind};
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tracing::warn;
+
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+pub const DATA_DIRECTORY_NAME: &str = "data";
+
+/// Describes the backup and all the files it consists of. The manifest is saved as the last file of the backup,
+/// so the backup directory without the manifest is incomplete (e.g. the server stopped while creating it) and ignored.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub id: u64,
+    pub kind: BackupKind,
+    pub parent_id: Option<u64>,
+    pub created_at: IggyTimestamp,
+    pub files: Vec<BackupFile>,
+}
+
+/// The file included in the backup.
+/// - `path` - Path of the file relative to the system directory
+/// - `kind` - Kind of the file, used to trim it when restoring to the point in time
+/// - `length` - Length of the file at the moment the backup was created
+/// - `offset` - Position in the file the stored chunk starts at, the preceding bytes are stored by the parent backup
+/// - `checksum` - Checksum of the whole file, i.e. the bytes `0..length`
+/// - `chunk_checksum` - Checksum of the stored chunk, i.e. the bytes `offset..length`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupFile {
+    pub path: String,
+    pub kind: BackupFileKind,
+    pub length: u64,
+    pub offset: u64,
+    pub checksum: u32,
+    pub chunk_checksum: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupFileKind {
+    StateLog,
+    StateSnapshot,
+    State,
+    SegmentLog,
+    SegmentIndex,
+    OffloadedSegment,
+    ConsumerOffset,
+}
+
+impl BackupFile {
+    pub fn chunk_length(&self) -> u64 {
+        self.length - self.offset
+    }
+}
+
+impl BackupManifest {
+    pub fn get_file(&self, path: &str) -> Option<&BackupFile> {
+        self.files.iter().find(|file| file.path == path)
+    }
+
+    pub fn to_info(&self) -> BackupInfo {
+        BackupInfo {
+            id: self.id,
+            kind: self.kind,
+            parent_id: self.parent_id,
+            created_at: self.created_at,
+            files_count: self.files.len() as u32,
+            size: IggyByteSize::from(self.files.iter().map(BackupFile::chunk_length).sum::<u64>()),
+        }
+    }
+
+    pub async fn load(backup_path: &str) -> Result<BackupManifest, IggyError> {
+        let path = format!("{backup_path}/{MANIFEST_FILE_NAME}");
+        let bytes = tokio::fs::read(&path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read backup manifest, path: {path}"
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        serde_json::from_slice(&bytes)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to parse backup manifest, path: {path}"
+                )
+            })
+            .map_err(|_| IggyError::CannotDeserializeResource)
+    }
+
+    pub async fn save(&self, backup_path: &str) -> Result<(), IggyError> {
+        let path = format!("{backup_path}/{MANIFEST_FILE_NAME}");
+        let bytes = serde_json::to_vec_pretty(self)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to serialize backup manifest, ID: {}",
+                    self.id
+                )
+            })
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        tokio::fs::write(&path, bytes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save backup manifest, path: {path}"
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+
+    /// Loads the manifests of all the complete backups stored in the directory, sorted by ID (creation time).
+    /// The directories which are not named by the numeric backup ID (e.g. used by the compatibility conversion) are skipped.
+    pub async fn load_all(backups_path: &str) -> Result<Vec<BackupManifest>, IggyError> {
+        if !Path::new(backups_path).exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut directories = tokio::fs::read_dir(backups_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read backups directory, path: {backups_path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let mut manifests = Vec::new();
+        while let Some(entry) = directories
+            .next_entry()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read backups directory entry, path: {backups_path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?
+        {
+            if !entry.path().is_dir() {
+                continue;
+            }
+
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.parse::<u64>().is_err() {
+                continue;
+            }
+
+            let backup_path = format!("{backups_path}/{name}");
+            if !Path::new(&format!("{backup_path}/{MANIFEST_FILE_NAME}")).exists() {
+                warn!("Backup directory: {backup_path} has no manifest, the backup is incomplete and will be skipped.");
+                continue;
+            }
+
+            manifests.push(BackupManifest::load(&backup_path).await?);
+        }
+
+        manifests.sort_by_key(|manifest| manifest.id);
+        Ok(manifests)
+    }
+}
@@ -0,0 +1,544 @@
+pub mod manifest;
+
+use crate::streaming::backups::manifest::{
+    BackupFile, BackupFileKind, BackupManifest, DATA_DIRECTORY_NAME,
+};
+use crate::streaming::batching::message_batch::{RetainedBatchHeader, RETAINED_BATCH_HEADER_LEN};
+use crate::streaming::segments::INDEX_SIZE;
+use crate::streaming::utils::file;
+use crc32fast::Hasher;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::io::{ErrorKind, SeekFrom};
+use std::path::Path;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader, BufWriter};
+
+pub const COMPONENT: &str = "STREAMING_BACKUPS";
+
+const BUF_CAPACITY_BYTES: usize = 512 * 1000;
+/// Length of the persisted state entry fields preceding its context, including the context length.
+const STATE_ENTRY_HEADER_LENGTH: usize = 56;
+
+/// The backup captured while the system was locked, whose files are yet to be copied.
+#[derive(Debug)]
+pub struct PendingBackup {
+    pub id: u64,
+    pub created_at: IggyTimestamp,
+    pub parent: Option<BackupManifest>,
+    pub files: Vec<PendingBackupFile>,
+}
+
+/// The file to be included in the backup, with its length captured while the system was locked,
+/// so that all the files represent the same, consistent point in time.
+#[derive(Debug)]
+pub struct PendingBackupFile {
+    pub source_path: String,
+    pub path: String,
+    pub kind: BackupFileKind,
+    pub length: u64,
+}
+
+/// Returns the length of the state log containing only the complete entries,
+/// optionally stopping at the first entry issued after `max_timestamp`.
+pub fn get_state_log_length(bytes: &[u8], max_timestamp: Option<u64>) -> usize {
+    let mut position = 0;
+    while position + STATE_ENTRY_HEADER_LENGTH <= bytes.len() {
+        let timestamp = u64::from_le_bytes(bytes[position + 32..position + 40].try_into().unwrap());
+        if max_timestamp.is_some_and(|max_timestamp| timestamp > max_timestamp) {
+            break;
+        }
+
+        let context_length =
+            u32::from_le_bytes(bytes[position + 52..position + 56].try_into().unwrap()) as usize;
+        let command_position = position + STATE_ENTRY_HEADER_LENGTH + context_length;
+        let Some(command_length) = bytes.get(command_position + 4..command_position + 8) else {
+            break;
+        };
+
+        let end_position =
+            command_position + 8 + u32::from_le_bytes(command_length.try_into().unwrap()) as usize;
+        if end_position > bytes.len() {
+            break;
+        }
+        position = end_position;
+    }
+    position
+}
+
+/// Returns the length of the index containing only the subsequent entries pointing to the batches within the log.
+pub fn get_index_length(bytes: &[u8], log_length: u64) -> u64 {
+    let mut last_position = None;
+    let mut length = 0;
+    for chunk in bytes.chunks_exact(INDEX_SIZE as usize) {
+        let position = u32::from_le_bytes(chunk[4..8].try_into().unwrap());
+        if position as u64 >= log_length || last_position.is_some_and(|last| position <= last) {
+            break;
+        }
+
+        last_position = Some(position);
+        length += INDEX_SIZE;
+    }
+    length
+}
+
+/// Returns the length of the segment log containing only the complete batches, starting the walk at `start_position`,
+/// optionally stopping at the first batch containing the messages created after `max_timestamp`.
+pub async fn get_segment_log_length(
+    log_path: &str,
+    start_position: u64,
+    max_timestamp: Option<u64>,
+) -> Result<u64, IggyError> {
+    let mut file = file::open(log_path)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to open log file: {log_path}")
+        })
+        .map_err(|_| IggyError::CannotReadFile)?;
+    let log_size = file
+        .metadata()
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get metadata of log file: {log_path}")
+        })
+        .map_err(|_| IggyError::CannotReadFileMetadata)?
+        .len();
+    let mut position = start_position.min(log_size);
+    let mut header = [0u8; RETAINED_BATCH_HEADER_LEN as usize];
+    while log_size - position >= RETAINED_BATCH_HEADER_LEN {
+        file.seek(SeekFrom::Start(position))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to seek log file: {log_path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        match file.read_exact(&mut header).await {
+            Ok(_) => {}
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => break,
+            Err(error) => {
+                return Err(error)
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to read log file: {log_path}"
+                        )
+                    })
+                    .map_err(|_| IggyError::CannotReadFile)
+            }
+        }
+
+        let Ok(batch_header) = RetainedBatchHeader::from_bytes(&header) else {
+            break;
+        };
+        let length = batch_header.length as u64;
+        if length == 0
+            || log_size - position - RETAINED_BATCH_HEADER_LEN < length
+            || max_timestamp.is_some_and(|max_timestamp| batch_header.max_timestamp > max_timestamp)
+        {
+            break;
+        }
+
+        position += RETAINED_BATCH_HEADER_LEN + length;
+    }
+    Ok(position)
+}
+
+/// Copies the first `length` bytes of the file into the backup. If the parent backup contains the same file
+/// and it's still the prefix of the current one (the file has only been appended to), only the new bytes are stored.
+pub async fn backup_file(
+    pending_file: &PendingBackupFile,
+    backup_path: &str,
+    parent_file: Option<&BackupFile>,
+) -> Result<BackupFile, IggyError> {
+    let source_path = &pending_file.source_path;
+    let length = pending_file.length;
+    let mut reader = BufReader::with_capacity(
+        BUF_CAPACITY_BYTES,
+        file::open(source_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to open file to backup: {source_path}"
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?,
+    );
+
+    let mut hasher = Hasher::new();
+    let mut offset = 0;
+    if let Some(parent_file) = parent_file.filter(|parent_file| parent_file.length <= length) {
+        copy_range(
+            &mut reader,
+            source_path,
+            parent_file.length,
+            &mut [&mut hasher],
+            None,
+        )
+        .await?;
+        if hasher.clone().finalize() == parent_file.checksum {
+            offset = parent_file.length;
+        } else {
+            hasher = Hasher::new();
+            reader
+                .seek(SeekFrom::Start(0))
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to seek file to backup: {source_path}")
+                })
+                .map_err(|_| IggyError::CannotReadFile)?;
+        }
+    }
+
+    let destination_path = get_data_path(backup_path, &pending_file.path);
+    let mut writer = create_file(&destination_path).await?;
+    let mut chunk_hasher = Hasher::new();
+    copy_range(
+        &mut reader,
+        source_path,
+        length - offset,
+        &mut [&mut hasher, &mut chunk_hasher],
+        Some((&mut writer, &destination_path)),
+    )
+    .await?;
+    flush_file(writer, &destination_path).await?;
+
+    Ok(BackupFile {
+        path: pending_file.path.clone(),
+        kind: pending_file.kind,
+        length,
+        offset,
+        checksum: hasher.finalize(),
+        chunk_checksum: chunk_hasher.finalize(),
+    })
+}
+
+/// Reconstructs the file stored by the backup (the first of `manifests`, followed by its ancestors) in the destination,
+/// verifying the checksums of all the stored chunks and of the whole file.
+pub async fn restore_file(
+    backups_path: &str,
+    manifests: &[&BackupManifest],
+    file: &BackupFile,
+    destination_path: &str,
+) -> Result<(), IggyError> {
+    let mut chunks = vec![(manifests[0].id, file)];
+    let mut current_file = file;
+    for manifest in &manifests[1..] {
+        if current_file.offset == 0 {
+            break;
+        }
+
+        let Some(parent_file) = manifest
+            .get_file(&current_file.path)
+            .filter(|parent_file| parent_file.length == current_file.offset)
+        else {
+            break;
+        };
+        chunks.push((manifest.id, parent_file));
+        current_file = parent_file;
+    }
+
+    if current_file.offset != 0 {
+        return Err(IggyError::CannotRestoreBackup).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - missing the chunk of file: {} preceding offset: {}",
+                current_file.path, current_file.offset
+            )
+        });
+    }
+
+    let mut writer = create_file(destination_path).await?;
+    let mut hasher = Hasher::new();
+    for (backup_id, chunk) in chunks.into_iter().rev() {
+        let chunk_path = get_data_path(&format!("{backups_path}/{backup_id}"), &chunk.path);
+        let mut reader = BufReader::with_capacity(
+            BUF_CAPACITY_BYTES,
+            file::open(&chunk_path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to open backup file: {chunk_path}"
+                    )
+                })
+                .map_err(|_| IggyError::CannotReadFile)?,
+        );
+        let mut chunk_hasher = Hasher::new();
+        copy_range(
+            &mut reader,
+            &chunk_path,
+            chunk.chunk_length(),
+            &mut [&mut hasher, &mut chunk_hasher],
+            Some((&mut writer, destination_path)),
+        )
+        .await?;
+        verify_checksum(chunk_hasher.finalize(), chunk.chunk_checksum, &chunk_path)?;
+        verify_checksum(hasher.clone().finalize(), chunk.checksum, &chunk.path)?;
+    }
+    flush_file(writer, destination_path).await
+}
+
+/// Truncates the file to the given length, if it's longer.
+pub async fn truncate_file(path: &str, length: u64) -> Result<(), IggyError> {
+    let file = OpenOptions::new()
+        .write(true)
+        .open(path)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to open file to truncate: {path}")
+        })
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    let file_length = file
+        .metadata()
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get metadata of file: {path}")
+        })
+        .map_err(|_| IggyError::CannotReadFileMetadata)?
+        .len();
+    if file_length <= length {
+        return Ok(());
+    }
+
+    file.set_len(length)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to truncate file: {path}")
+        })
+        .map_err(|_| IggyError::CannotWriteToFile)
+}
+
+fn get_data_path(backup_path: &str, path: &str) -> String {
+    format!("{backup_path}/{DATA_DIRECTORY_NAME}/{path}")
+}
+
+fn verify_checksum(checksum: u32, expected_checksum: u32, path: &str) -> Result<(), IggyError> {
+    if checksum != expected_checksum {
+        return Err(IggyError::InvalidBackupChecksum(
+            checksum,
+            expected_checksum,
+            path.to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+async fn create_file(path: &str) -> Result<BufWriter<File>, IggyError> {
+    if let Some(parent) = Path::new(path).parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create directory: {parent:?}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+    }
+
+    let file = File::create(path)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to create file: {path}")
+        })
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    Ok(BufWriter::with_capacity(BUF_CAPACITY_BYTES, file))
+}
+
+async fn flush_file(mut writer: BufWriter<File>, path: &str) -> Result<(), IggyError> {
+    writer
+        .flush()
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to flush file: {path}")
+        })
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    writer
+        .get_ref()
+        .sync_all()
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to fsync file: {path}")
+        })
+        .map_err(|_| IggyError::CannotWriteToFile)
+}
+
+/// Reads exactly `length` bytes, updating all the hashers and writing the bytes to the optional writer.
+async fn copy_range(
+    reader: &mut BufReader<File>,
+    path: &str,
+    length: u64,
+    hashers: &mut [&mut Hasher],
+    mut writer: Option<(&mut BufWriter<File>, &str)>,
+) -> Result<(), IggyError> {
+    let mut buffer = vec![0u8; BUF_CAPACITY_BYTES.min(length as usize)];
+    let mut remaining = length;
+    while remaining > 0 {
+        let chunk_length = buffer.len().min(remaining as usize);
+        let chunk = &mut buffer[..chunk_length];
+        reader
+            .read_exact(chunk)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read {length} bytes from file: {path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        for hasher in hashers.iter_mut() {
+            hasher.update(chunk);
+        }
+        if let Some((writer, writer_path)) = writer.as_mut() {
+            writer
+                .write_all(chunk)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to write file: {writer_path}")
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+        }
+        remaining -= chunk_length as u64;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::backup::BackupKind;
+
+    fn state_entry(timestamp: u64, context: &[u8], command: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0u8; 32];
+        bytes.extend_from_slice(&timestamp.to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 12]);
+        bytes.extend_from_slice(&(context.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(context);
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&(command.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(command);
+        bytes
+    }
+
+    fn index(offset: u32, position: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&offset.to_le_bytes());
+        bytes.extend_from_slice(&position.to_le_bytes());
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes
+    }
+
+    fn manifest(id: u64, parent_id: Option<u64>, files: Vec<BackupFile>) -> BackupManifest {
+        BackupManifest {
+            id,
+            kind: match parent_id {
+                Some(_) => BackupKind::Incremental,
+                None => BackupKind::Full,
+            },
+            parent_id,
+            created_at: IggyTimestamp::from(id),
+            files,
+        }
+    }
+
+    #[test]
+    fn state_log_length_should_skip_incomplete_and_later_entries() {
+        let first = state_entry(10, b"ctx", b"first");
+        let second = state_entry(20, b"", b"second");
+        let mut bytes = [first.clone(), second.clone()].concat();
+        bytes.extend_from_slice(&state_entry(30, b"", b"torn")[..40]);
+
+        assert_eq!(
+            get_state_log_length(&bytes, None),
+            first.len() + second.len()
+        );
+        assert_eq!(get_state_log_length(&bytes, Some(15)), first.len());
+    }
+
+    #[test]
+    fn index_length_should_include_only_entries_within_log() {
+        let bytes = [index(0, 0), index(1, 100), index(2, 200)].concat();
+
+        assert_eq!(get_index_length(&bytes, 300), 3 * INDEX_SIZE);
+        assert_eq!(get_index_length(&bytes, 200), 2 * INDEX_SIZE);
+        assert_eq!(get_index_length(&bytes[..40], 300), 2 * INDEX_SIZE);
+    }
+
+    #[tokio::test]
+    async fn incremental_backup_should_be_restored_with_verified_checksums() {
+        let directory = tempfile::tempdir().unwrap();
+        let base_path = directory.path().to_str().unwrap();
+        let source_path = format!("{base_path}/source.log");
+        let backups_path = format!("{base_path}/backups");
+        let pending_file = |length| PendingBackupFile {
+            source_path: source_path.clone(),
+            path: "source.log".to_string(),
+            kind: BackupFileKind::SegmentLog,
+            length,
+        };
+
+        tokio::fs::write(&source_path, b"hello").await.unwrap();
+        let full = backup_file(&pending_file(5), &format!("{backups_path}/1"), None)
+            .await
+            .unwrap();
+        tokio::fs::write(&source_path, b"hello world")
+            .await
+            .unwrap();
+        let incremental = backup_file(&pending_file(11), &format!("{backups_path}/2"), Some(&full))
+            .await
+            .unwrap();
+        assert_eq!(incremental.offset, 5);
+        assert_eq!(incremental.chunk_length(), 6);
+
+        let parent = manifest(1, None, vec![full]);
+        let child = manifest(2, Some(1), vec![incremental.clone()]);
+        let restored_path = format!("{base_path}/restored.log");
+        restore_file(
+            &backups_path,
+            &[&child, &parent],
+            &incremental,
+            &restored_path,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            tokio::fs::read(&restored_path).await.unwrap(),
+            b"hello world"
+        );
+
+        tokio::fs::write(format!("{backups_path}/2/data/source.log"), b" wOrld")
+            .await
+            .unwrap();
+        let result = restore_file(
+            &backups_path,
+            &[&child, &parent],
+            &incremental,
+            &restored_path,
+        )
+        .await;
+        assert!(matches!(result, Err(IggyError::InvalidBackupChecksum(..))));
+    }
+
+    #[tokio::test]
+    async fn backup_should_store_full_file_given_modified_prefix() {
+        let directory = tempfile::tempdir().unwrap();
+        let base_path = directory.path().to_str().unwrap();
+        let source_path = format!("{base_path}/offset");
+        let pending_file = PendingBackupFile {
+            source_path: source_path.clone(),
+            path: "offset".to_string(),
+            kind: BackupFileKind::ConsumerOffset,
+            length: 8,
+        };
+
+        tokio::fs::write(&source_path, 1u64.to_le_bytes())
+            .await
+            .unwrap();
+        let full = backup_file(&pending_file, &format!("{base_path}/1"), None)
+            .await
+            .unwrap();
+        tokio::fs::write(&source_path, 2u64.to_le_bytes())
+            .await
+            .unwrap();
+        let next = backup_file(&pending_file, &format!("{base_path}/2"), Some(&full))
+            .await
+            .unwrap();
+
+        assert_eq!(next.offset, 0);
+        assert_eq!(next.chunk_length(), 8);
+        assert_ne!(next.checksum, full.checksum);
+    }
+}
@@ -4,6 +4,7 @@ use iggy::utils::byte_size::IggyByteSize;
 pub struct AppendableBatchInfo {
     pub batch_size: IggyByteSize,
     pub partition_id: u32,
+    pub encryption_key_id: Option<u32>,
 }
 
 impl AppendableBatchInfo {
@@ -11,6 +12,14 @@ impl AppendableBatchInfo {
         Self {
             batch_size,
             partition_id,
+            encryption_key_id: None,
+        }
+    }
+
+    pub fn with_encryption_key_id(self, encryption_key_id: Option<u32>) -> Self {
+        Self {
+            encryption_key_id,
+            ..self
         }
     }
 }
@@ -12,6 +12,7 @@ pub struct BatchAccumulator {
     current_offset: u64,
     current_timestamp: u64,
     messages: Vec<Arc<RetainedMessage>>,
+    encryption_key_id: Option<u32>,
 }
 
 impl BatchAccumulator {
@@ -22,11 +23,23 @@ impl BatchAccumulator {
             current_offset: 0,
             current_timestamp: 0,
             messages: Vec::with_capacity(capacity),
+            encryption_key_id: None,
         }
     }
 
-    pub fn append(&mut self, batch_size: IggyByteSize, items: &[Arc<RetainedMessage>]) {
+    /// All the accumulated items must be encrypted with the same key, as its ID is stored in the batch header.
+    pub fn append(
+        &mut self,
+        batch_size: IggyByteSize,
+        items: &[Arc<RetainedMessage>],
+        encryption_key_id: Option<u32>,
+    ) {
         assert!(!items.is_empty());
+        // The accumulator is reused after materializing the batch, so the next batch starts with the first appended item.
+        if self.messages.is_empty() {
+            self.base_offset = items[0].offset;
+            self.encryption_key_id = encryption_key_id;
+        }
         self.current_size += batch_size;
         self.current_offset = items.last().unwrap().offset;
         self.current_timestamp = items.last().unwrap().timestamp;
@@ -79,16 +92,16 @@ impl BatchAccumulator {
         self.base_offset
     }
 
+    pub fn encryption_key_id(&self) -> Option<u32> {
+        self.encryption_key_id
+    }
+
     pub fn materialize_batch_and_update_state(&mut self) -> RetainedMessageBatch {
         let batch_base_offset = self.base_offset;
         let batch_last_offset_delta = (self.current_offset - self.base_offset) as u32;
 
-        let num_messages = self.messages.len();
-        let last_batch_timestamp = if num_messages > 0 {
-            self.messages[num_messages - 1].timestamp
-        } else {
-            0
-        };
+        let first_batch_timestamp = self.messages.first().map_or(0, |message| message.timestamp);
+        let last_batch_timestamp = self.messages.last().map_or(0, |message| message.timestamp);
 
         let messages = std::mem::take(&mut self.messages);
         let mut bytes = BytesMut::with_capacity(self.current_size.as_bytes_u64() as usize);
@@ -106,10 +119,12 @@ impl BatchAccumulator {
         RetainedMessageBatch::new(
             batch_base_offset,
             batch_last_offset_delta,
+            first_batch_timestamp,
             last_batch_timestamp,
             batch_payload_len,
             batch_payload,
         )
+        .with_encryption_key_id(self.encryption_key_id)
     }
 }
 
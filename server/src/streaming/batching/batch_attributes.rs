@@ -0,0 +1,81 @@
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+
+const COMPRESSION_MASK: u16 = 0b111;
+const ENCRYPTED: u16 = 1 << 3;
+const TRANSACTIONAL: u16 = 1 << 4;
+const CONTROL: u16 = 1 << 5;
+const KNOWN_FLAGS: u16 = COMPRESSION_MASK | ENCRYPTED | TRANSACTIONAL | CONTROL;
+
+/// The attribute flags of the batch stored in its header.
+/// - bits 0-2 - compression algorithm of the messages (0 - none, 1 - gzip)
+/// - bit 3 - messages payloads are encrypted
+/// - bit 4 - batch is a part of the transaction
+/// - bit 5 - batch contains the control records instead of the messages
+///
+/// The remaining bits are reserved, so the batch with any of them set is rejected.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BatchAttributes(u16);
+
+impl BatchAttributes {
+    pub fn from_code(code: u16) -> Result<Self, IggyError> {
+        if code & !KNOWN_FLAGS != 0 {
+            return Err(IggyError::InvalidBatchAttributes(code));
+        }
+
+        let attributes = BatchAttributes(code);
+        attributes.compression()?;
+        Ok(attributes)
+    }
+
+    pub fn as_code(&self) -> u16 {
+        self.0
+    }
+
+    pub fn compression(&self) -> Result<CompressionAlgorithm, IggyError> {
+        match self.0 & COMPRESSION_MASK {
+            0 => Ok(CompressionAlgorithm::None),
+            1 => Ok(CompressionAlgorithm::Gzip),
+            _ => Err(IggyError::InvalidBatchAttributes(self.0)),
+        }
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.0 & ENCRYPTED != 0
+    }
+
+    pub fn is_transactional(&self) -> bool {
+        self.0 & TRANSACTIONAL != 0
+    }
+
+    pub fn is_control(&self) -> bool {
+        self.0 & CONTROL != 0
+    }
+
+    pub fn with_compression(self, compression: CompressionAlgorithm) -> Self {
+        let code = match compression {
+            CompressionAlgorithm::None => 0,
+            CompressionAlgorithm::Gzip => 1,
+        };
+        BatchAttributes(self.0 & !COMPRESSION_MASK | code)
+    }
+
+    pub fn with_encrypted(self, encrypted: bool) -> Self {
+        self.with_flag(ENCRYPTED, encrypted)
+    }
+
+    pub fn with_transactional(self, transactional: bool) -> Self {
+        self.with_flag(TRANSACTIONAL, transactional)
+    }
+
+    pub fn with_control(self, control: bool) -> Self {
+        self.with_flag(CONTROL, control)
+    }
+
+    fn with_flag(self, flag: u16, enabled: bool) -> Self {
+        match enabled {
+            true => BatchAttributes(self.0 | flag),
+            false => BatchAttributes(self.0 & !flag),
+        }
+    }
+}
@@ -1,16 +1,107 @@
+use crate::streaming::batching::batch_attributes::BatchAttributes;
 use crate::streaming::batching::batch_filter::BatchItemizer;
 use crate::streaming::batching::iterator::IntoMessagesIterator;
 use crate::streaming::models::messages::RetainedMessage;
 use bytes::Bytes;
+use iggy::error::IggyError;
 use iggy::utils::{byte_size::IggyByteSize, sizeable::Sizeable};
 
-pub const RETAINED_BATCH_HEADER_LEN: u64 = 8 + 8 + 4 + 4;
+pub const RETAINED_BATCH_FORMAT_VERSION: u8 = 2;
+/// Base offset (8) + length (4) + version (1) + CRC (4) + attributes (2) + last offset delta (4) + first timestamp (8)
+/// + max timestamp (8) + producer ID (8) + producer epoch (2) + base sequence (4) + encryption key ID (4).
+pub const RETAINED_BATCH_HEADER_LEN: u64 = 8 + 4 + 1 + 4 + 2 + 4 + 8 + 8 + 8 + 2 + 4 + 4;
+/// The batches aren't assigned to the idempotent or transactional producers yet, so the producer fields are zeroed.
+pub const NO_PRODUCER_ID: u64 = 0;
+pub const NO_PRODUCER_EPOCH: u16 = 0;
+pub const NO_SEQUENCE: u32 = 0;
+/// The keyring keys start with the ID 1, so 0 is stored when the payloads aren't encrypted with the keyring key.
+const NO_ENCRYPTION_KEY_ID: u32 = 0;
+/// The CRC covers the part of the header following the CRC itself and the messages of the batch.
+const CRC_COVERED_HEADER_START: usize = 17;
+
+/// The header of the batch stored in the segment log, read without the messages.
+/// The base offset and the length are placed first, as in the legacy format, so the batches can be skipped the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetainedBatchHeader {
+    pub base_offset: u64,
+    pub length: u32,
+    pub version: u8,
+    pub crc: u32,
+    pub attributes: BatchAttributes,
+    pub last_offset_delta: u32,
+    pub first_timestamp: u64,
+    pub max_timestamp: u64,
+    pub producer_id: u64,
+    pub producer_epoch: u16,
+    pub base_sequence: u32,
+    pub encryption_key_id: Option<u32>,
+}
+
+impl RetainedBatchHeader {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, IggyError> {
+        if bytes.len() < RETAINED_BATCH_HEADER_LEN as usize {
+            return Err(IggyError::CannotReadBatchLength);
+        }
+
+        let version = bytes[12];
+        if version != RETAINED_BATCH_FORMAT_VERSION {
+            return Err(IggyError::InvalidBatchFormatVersion(version));
+        }
+
+        Ok(RetainedBatchHeader {
+            base_offset: u64::from_le_bytes(bytes[0..8].try_into().unwrap()),
+            length: u32::from_le_bytes(bytes[8..12].try_into().unwrap()),
+            version,
+            crc: u32::from_le_bytes(bytes[13..17].try_into().unwrap()),
+            attributes: BatchAttributes::from_code(u16::from_le_bytes(
+                bytes[17..19].try_into().unwrap(),
+            ))?,
+            last_offset_delta: u32::from_le_bytes(bytes[19..23].try_into().unwrap()),
+            first_timestamp: u64::from_le_bytes(bytes[23..31].try_into().unwrap()),
+            max_timestamp: u64::from_le_bytes(bytes[31..39].try_into().unwrap()),
+            producer_id: u64::from_le_bytes(bytes[39..47].try_into().unwrap()),
+            producer_epoch: u16::from_le_bytes(bytes[47..49].try_into().unwrap()),
+            base_sequence: u32::from_le_bytes(bytes[49..53].try_into().unwrap()),
+            encryption_key_id: match u32::from_le_bytes(bytes[53..57].try_into().unwrap()) {
+                NO_ENCRYPTION_KEY_ID => None,
+                key_id => Some(key_id),
+            },
+        })
+    }
+
+    /// Verifies the CRC of the header bytes, from which the header has been read, and the messages of the batch.
+    pub fn verify_crc(&self, header: &[u8], messages: &[u8]) -> Result<(), IggyError> {
+        let crc = calculate_crc(header, messages);
+        if crc != self.crc {
+            return Err(IggyError::InvalidBatchChecksum(
+                crc,
+                self.crc,
+                self.base_offset,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn calculate_crc(header: &[u8], messages: &[u8]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(&header[CRC_COVERED_HEADER_START..RETAINED_BATCH_HEADER_LEN as usize]);
+    hasher.update(messages);
+    hasher.finalize()
+}
 
 #[derive(Debug)]
 pub struct RetainedMessageBatch {
     pub base_offset: u64,
     pub last_offset_delta: u32,
+    pub first_timestamp: u64,
     pub max_timestamp: u64,
+    pub attributes: BatchAttributes,
+    pub producer_id: u64,
+    pub producer_epoch: u16,
+    pub base_sequence: u32,
+    pub encryption_key_id: Option<u32>,
     pub length: IggyByteSize,
     pub bytes: Bytes,
 }
@@ -19,6 +110,7 @@ impl RetainedMessageBatch {
     pub fn new(
         base_offset: u64,
         last_offset_delta: u32,
+        first_timestamp: u64,
         max_timestamp: u64,
         length: IggyByteSize,
         bytes: Bytes,
@@ -26,12 +118,54 @@ impl RetainedMessageBatch {
         RetainedMessageBatch {
             base_offset,
             last_offset_delta,
+            first_timestamp,
             max_timestamp,
+            attributes: BatchAttributes::default(),
+            producer_id: NO_PRODUCER_ID,
+            producer_epoch: NO_PRODUCER_EPOCH,
+            base_sequence: NO_SEQUENCE,
+            encryption_key_id: None,
             length,
             bytes,
         }
     }
 
+    pub fn from_header(header: &RetainedBatchHeader, bytes: Bytes) -> Self {
+        RetainedMessageBatch {
+            base_offset: header.base_offset,
+            last_offset_delta: header.last_offset_delta,
+            first_timestamp: header.first_timestamp,
+            max_timestamp: header.max_timestamp,
+            attributes: header.attributes,
+            producer_id: header.producer_id,
+            producer_epoch: header.producer_epoch,
+            base_sequence: header.base_sequence,
+            encryption_key_id: header.encryption_key_id,
+            length: IggyByteSize::from(bytes.len() as u64),
+            bytes,
+        }
+    }
+
+    pub fn with_attributes(self, attributes: BatchAttributes) -> Self {
+        Self { attributes, ..self }
+    }
+
+    pub fn with_producer(self, producer_id: u64, producer_epoch: u16, base_sequence: u32) -> Self {
+        Self {
+            producer_id,
+            producer_epoch,
+            base_sequence,
+            ..self
+        }
+    }
+
+    pub fn with_encryption_key_id(self, encryption_key_id: Option<u32>) -> Self {
+        Self {
+            encryption_key_id,
+            ..self
+        }
+    }
+
     pub fn is_contained_or_overlapping_within_offset_range(
         &self,
         start_offset: u64,
@@ -46,13 +180,27 @@ impl RetainedMessageBatch {
         self.base_offset + self.last_offset_delta as u64
     }
 
-    pub fn header_as_bytes(&self) -> [u8; 24] {
-        let mut header: [u8; 24] = [0u8; 24];
+    pub fn header_as_bytes(&self) -> [u8; RETAINED_BATCH_HEADER_LEN as usize] {
+        let mut header = [0u8; RETAINED_BATCH_HEADER_LEN as usize];
 
         header[0..8].copy_from_slice(&self.base_offset.to_le_bytes());
         header[8..12].copy_from_slice(&(self.length.as_bytes_u64() as u32).to_le_bytes());
-        header[12..16].copy_from_slice(&self.last_offset_delta.to_le_bytes());
-        header[16..24].copy_from_slice(&self.max_timestamp.to_le_bytes());
+        header[12] = RETAINED_BATCH_FORMAT_VERSION;
+        header[17..19].copy_from_slice(&self.attributes.as_code().to_le_bytes());
+        header[19..23].copy_from_slice(&self.last_offset_delta.to_le_bytes());
+        header[23..31].copy_from_slice(&self.first_timestamp.to_le_bytes());
+        header[31..39].copy_from_slice(&self.max_timestamp.to_le_bytes());
+        header[39..47].copy_from_slice(&self.producer_id.to_le_bytes());
+        header[47..49].copy_from_slice(&self.producer_epoch.to_le_bytes());
+        header[49..53].copy_from_slice(&self.base_sequence.to_le_bytes());
+        header[53..57].copy_from_slice(
+            &self
+                .encryption_key_id
+                .unwrap_or(NO_ENCRYPTION_KEY_ID)
+                .to_le_bytes(),
+        );
+        let crc = calculate_crc(&header, &self.bytes);
+        header[13..17].copy_from_slice(&crc.to_le_bytes());
 
         header
     }
@@ -1,5 +1,6 @@
 pub mod appendable_batch_info;
 pub mod batch_accumulator;
+pub mod batch_attributes;
 pub mod batch_filter;
 pub mod iterator;
 pub mod message_batch;
@@ -0,0 +1,259 @@
+use crate::configs::server::{RateLimitConfig, RateLimitKeyKind, RateLimitMode};
+use dashmap::DashMap;
+use iggy::models::user_info::UserId;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::net::IpAddr;
+
+/// Once the number of tracked keys exceeds this value, the idle entries are removed on the next request.
+const CLEANUP_THRESHOLD: usize = 1024;
+const MICROS_IN_SECOND: f64 = 1_000_000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+    Client(u32),
+    User(UserId),
+    Ip(IpAddr),
+}
+
+/// Outcome of acquiring the rate limit for a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitDecision {
+    /// The request can be handled immediately.
+    Allow,
+    /// The request must be delayed by the given number of microseconds before being handled.
+    Delay(u64),
+    /// The request must be rejected, contains the number of microseconds after which it can be retried.
+    Reject(u64),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RateLimitBuckets {
+    requests: f64,
+    bytes: f64,
+    updated_at: u64,
+}
+
+/// Limits the number of requests and bytes received per second using the token buckets,
+/// refilled at the configured rate, with the capacity of a single second.
+/// The request is allowed as long as none of the buckets is empty, its cost is consumed immediately,
+/// thus the buckets might go into debt, e.g. when a single request is larger than the bytes limit.
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: DashMap<RateLimitKey, RateLimitBuckets>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: DashMap::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Resolves the key the limits are applied to, falling back to the IP address when the client ID is unknown
+    /// (`0` for the stateless HTTP clients) or the user ID is unknown (`0` for the unauthenticated clients).
+    pub fn resolve_key(&self, client_id: u32, user_id: UserId, ip_address: IpAddr) -> RateLimitKey {
+        match self.config.key {
+            RateLimitKeyKind::Client if client_id > 0 => RateLimitKey::Client(client_id),
+            RateLimitKeyKind::User if user_id > 0 => RateLimitKey::User(user_id),
+            _ => RateLimitKey::Ip(ip_address),
+        }
+    }
+
+    /// Acquires the rate limit for a single request of the given size.
+    pub fn acquire(&self, key: RateLimitKey, bytes: u64, now: IggyTimestamp) -> RateLimitDecision {
+        if !self.config.enabled {
+            return RateLimitDecision::Allow;
+        }
+
+        let now = now.as_micros();
+        if self.buckets.len() > CLEANUP_THRESHOLD {
+            self.remove_idle(now);
+        }
+
+        let requests_per_second = self.config.requests_per_second as f64;
+        let bytes_per_second = self.config.bytes_per_second.as_bytes_u64() as f64;
+        let mut buckets = self.buckets.entry(key).or_insert(RateLimitBuckets {
+            requests: requests_per_second,
+            bytes: bytes_per_second,
+            updated_at: now,
+        });
+        let elapsed = now.saturating_sub(buckets.updated_at) as f64 / MICROS_IN_SECOND;
+        buckets.requests =
+            (buckets.requests + elapsed * requests_per_second).min(requests_per_second);
+        buckets.bytes = (buckets.bytes + elapsed * bytes_per_second).min(bytes_per_second);
+        buckets.updated_at = buckets.updated_at.max(now);
+
+        // Time needed for both of the buckets to become non-empty again.
+        let wait = Self::time_to_refill(buckets.requests, requests_per_second)
+            .max(Self::time_to_refill(buckets.bytes, bytes_per_second));
+        if wait > 0 {
+            match self.config.mode {
+                RateLimitMode::Reject => return RateLimitDecision::Reject(wait),
+                RateLimitMode::Delay if wait > self.config.max_delay.as_micros() => {
+                    return RateLimitDecision::Reject(wait)
+                }
+                RateLimitMode::Delay => {}
+            }
+        }
+
+        if requests_per_second > 0.0 {
+            buckets.requests -= 1.0;
+        }
+        if bytes_per_second > 0.0 {
+            buckets.bytes -= bytes as f64;
+        }
+
+        match wait {
+            0 => RateLimitDecision::Allow,
+            wait => RateLimitDecision::Delay(wait),
+        }
+    }
+
+    fn time_to_refill(tokens: f64, rate: f64) -> u64 {
+        if rate == 0.0 || tokens > 0.0 {
+            return 0;
+        }
+
+        // The bucket must be refilled above zero, hence the additional microsecond.
+        (-tokens / rate * MICROS_IN_SECOND).ceil() as u64 + 1
+    }
+
+    fn remove_idle(&self, now: u64) {
+        // The buckets which haven't been used for more than a second are full again, unless they were in debt.
+        let idle_after = MICROS_IN_SECOND as u64 + self.config.max_delay.as_micros();
+        self.buckets.retain(|_, buckets| {
+            buckets.requests < 0.0
+                || buckets.bytes < 0.0
+                || now.saturating_sub(buckets.updated_at) <= idle_after
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::byte_size::IggyByteSize;
+    use iggy::utils::duration::IggyDuration;
+    use std::net::Ipv4Addr;
+
+    const MILLISECOND: u64 = 1_000;
+
+    fn config(mode: RateLimitMode) -> RateLimitConfig {
+        RateLimitConfig {
+            enabled: true,
+            key: RateLimitKeyKind::Client,
+            mode,
+            requests_per_second: 10,
+            bytes_per_second: IggyByteSize::from(1000),
+            max_delay: IggyDuration::from(500 * MILLISECOND),
+        }
+    }
+
+    fn at(millis: u64) -> IggyTimestamp {
+        IggyTimestamp::from(1_000_000 * MILLISECOND + millis * MILLISECOND)
+    }
+
+    #[test]
+    fn requests_over_the_limit_should_be_rejected_with_retry_after() {
+        let limiter = RateLimiter::new(config(RateLimitMode::Reject));
+        let key = RateLimitKey::Client(1);
+        for _ in 0..10 {
+            assert_eq!(limiter.acquire(key, 1, at(0)), RateLimitDecision::Allow);
+        }
+
+        let RateLimitDecision::Reject(retry_after) = limiter.acquire(key, 1, at(0)) else {
+            panic!("Request should be rejected");
+        };
+        assert!(retry_after > 0 && retry_after <= 100 * MILLISECOND);
+        assert_eq!(limiter.acquire(key, 1, at(101)), RateLimitDecision::Allow);
+    }
+
+    #[test]
+    fn request_larger_than_bytes_limit_should_be_allowed_and_repaid() {
+        let limiter = RateLimiter::new(config(RateLimitMode::Reject));
+        let key = RateLimitKey::Client(1);
+        assert_eq!(limiter.acquire(key, 1500, at(0)), RateLimitDecision::Allow);
+        assert!(matches!(
+            limiter.acquire(key, 1, at(400)),
+            RateLimitDecision::Reject(_)
+        ));
+        assert_eq!(limiter.acquire(key, 1, at(600)), RateLimitDecision::Allow);
+    }
+
+    #[test]
+    fn requests_over_the_limit_should_be_delayed_up_to_max_delay() {
+        let limiter = RateLimiter::new(config(RateLimitMode::Delay));
+        let key = RateLimitKey::Client(1);
+        assert_eq!(limiter.acquire(key, 1200, at(0)), RateLimitDecision::Allow);
+
+        let RateLimitDecision::Delay(delay) = limiter.acquire(key, 400, at(0)) else {
+            panic!("Request should be delayed");
+        };
+        assert!(delay > 200 * MILLISECOND && delay <= 200 * MILLISECOND + 1);
+
+        assert!(matches!(
+            limiter.acquire(key, 1, at(0)),
+            RateLimitDecision::Reject(_)
+        ));
+    }
+
+    #[test]
+    fn limits_should_be_tracked_separately_per_key() {
+        let limiter = RateLimiter::new(config(RateLimitMode::Reject));
+        limiter.acquire(RateLimitKey::Client(1), 1000, at(0));
+        assert!(matches!(
+            limiter.acquire(RateLimitKey::Client(1), 1, at(0)),
+            RateLimitDecision::Reject(_)
+        ));
+        assert_eq!(
+            limiter.acquire(RateLimitKey::Client(2), 1, at(0)),
+            RateLimitDecision::Allow
+        );
+    }
+
+    #[test]
+    fn user_key_should_fall_back_to_ip_address_for_unauthenticated_clients() {
+        let mut config = config(RateLimitMode::Reject);
+        config.key = RateLimitKeyKind::User;
+        let limiter = RateLimiter::new(config);
+        let ip_address = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        assert_eq!(limiter.resolve_key(1, 2, ip_address), RateLimitKey::User(2));
+        assert_eq!(
+            limiter.resolve_key(1, 0, ip_address),
+            RateLimitKey::Ip(ip_address)
+        );
+    }
+
+    #[test]
+    fn client_key_should_fall_back_to_ip_address_for_stateless_clients() {
+        let limiter = RateLimiter::new(config(RateLimitMode::Reject));
+        let ip_address = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        assert_eq!(
+            limiter.resolve_key(1, 2, ip_address),
+            RateLimitKey::Client(1)
+        );
+        assert_eq!(
+            limiter.resolve_key(0, 2, ip_address),
+            RateLimitKey::Ip(ip_address)
+        );
+    }
+
+    #[test]
+    fn disabled_limiter_should_always_allow() {
+        let mut config = config(RateLimitMode::Reject);
+        config.enabled = false;
+        let limiter = RateLimiter::new(config);
+        for _ in 0..100 {
+            assert_eq!(
+                limiter.acquire(RateLimitKey::Client(1), 1000, at(0)),
+                RateLimitDecision::Allow
+            );
+        }
+    }
+}
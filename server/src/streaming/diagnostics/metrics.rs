@@ -8,6 +8,11 @@ use tracing::error;
 pub(crate) struct Metrics {
     registry: Registry,
     http_requests: Counter,
+    failed_logins: Counter,
+    login_lockouts: Counter,
+    quota_violations: Counter,
+    recovered_segments: Counter,
+    truncated_segment_bytes: Counter,
     streams: Gauge,
     topics: Gauge,
     partitions: Gauge,
@@ -22,6 +27,11 @@ impl Metrics {
         let mut metrics = Metrics {
             registry: <Registry>::default(),
             http_requests: Counter::default(),
+            failed_logins: Counter::default(),
+            login_lockouts: Counter::default(),
+            quota_violations: Counter::default(),
+            recovered_segments: Counter::default(),
+            truncated_segment_bytes: Counter::default(),
             streams: Gauge::default(),
             topics: Gauge::default(),
             partitions: Gauge::default(),
@@ -32,6 +42,14 @@ impl Metrics {
         };
 
         metrics.register_counter("http_requests", metrics.http_requests.clone());
+        metrics.register_counter("failed_logins", metrics.failed_logins.clone());
+        metrics.register_counter("login_lockouts", metrics.login_lockouts.clone());
+        metrics.register_counter("quota_violations", metrics.quota_violations.clone());
+        metrics.register_counter("recovered_segments", metrics.recovered_segments.clone());
+        metrics.register_counter(
+            "truncated_segment_bytes",
+            metrics.truncated_segment_bytes.clone(),
+        );
         metrics.register_gauge("streams", metrics.streams.clone());
         metrics.register_gauge("topics", metrics.topics.clone());
         metrics.register_gauge("partitions", metrics.partitions.clone());
@@ -65,6 +83,46 @@ impl Metrics {
         self.http_requests.inc();
     }
 
+    pub fn increment_failed_logins(&self) {
+        self.failed_logins.inc();
+    }
+
+    pub fn get_failed_logins(&self) -> u64 {
+        self.failed_logins.get()
+    }
+
+    pub fn increment_login_lockouts(&self, count: u64) {
+        self.login_lockouts.inc_by(count);
+    }
+
+    pub fn get_login_lockouts(&self) -> u64 {
+        self.login_lockouts.get()
+    }
+
+    pub fn increment_quota_violations(&self) {
+        self.quota_violations.inc();
+    }
+
+    pub fn get_quota_violations(&self) -> u64 {
+        self.quota_violations.get()
+    }
+
+    pub fn increment_recovered_segments(&self, count: u64) {
+        self.recovered_segments.inc_by(count);
+    }
+
+    pub fn get_recovered_segments(&self) -> u64 {
+        self.recovered_segments.get()
+    }
+
+    pub fn increment_truncated_segment_bytes(&self, count: u64) {
+        self.truncated_segment_bytes.inc_by(count);
+    }
+
+    pub fn get_truncated_segment_bytes(&self) -> u64 {
+        self.truncated_segment_bytes.get()
+    }
+
     pub fn increment_streams(&self, count: u32) {
         self.streams.inc_by(count as i64);
     }
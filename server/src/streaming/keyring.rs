@@ -0,0 +1,239 @@
+use crate::configs::system::SystemConfig;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor, EncryptorKind, KeyringEncryptor};
+use iggy::utils::text;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tracing::info;
+
+const COMPONENT: &str = "STREAMING_KEYRING";
+
+/// Manages the encryption keys stored in the keyring file.
+/// The keys are identified by the ID, which is stored in the header of the encrypted batch, so the previous keys
+/// are kept in the keyring after the rotation, and the data encrypted with them can still be decrypted.
+/// If the key encryption key is provided, the keys are stored in the file encrypted with it.
+#[derive(Debug)]
+pub struct Keyring {
+    path: String,
+    key_encryption_key: Option<Aes256GcmEncryptor>,
+    keys: Mutex<Vec<KeyringKey>>,
+    encryptor: Arc<EncryptorKind>,
+}
+
+struct KeyringKey {
+    id: u32,
+    created_at: IggyTimestamp,
+    key: Vec<u8>,
+}
+
+impl std::fmt::Debug for KeyringKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyringKey")
+            .field("id", &self.id)
+            .field("created_at", &self.created_at)
+            .finish()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeyringFile {
+    current_key_id: u32,
+    keys: Vec<KeyringFileEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeyringFileEntry {
+    id: u32,
+    created_at: IggyTimestamp,
+    key: String,
+    wrapped: bool,
+}
+
+impl Keyring {
+    /// Loads the keyring file, or creates it with a random key if it doesn't exist yet.
+    /// The legacy `encryption.key` (if provided) is used to decrypt the data encrypted before the keyring was enabled.
+    pub fn load(config: &SystemConfig) -> Result<Self, IggyError> {
+        let path = config.get_keyring_path();
+        let keyring_config = &config.encryption.keyring;
+        let key_encryption_key = match keyring_config.key_encryption_key.is_empty() {
+            true => None,
+            false => Some(Aes256GcmEncryptor::from_base64_key(
+                &keyring_config.key_encryption_key,
+            )?),
+        };
+        let legacy = match config.encryption.key.is_empty() {
+            true => None,
+            false => Some(Aes256GcmEncryptor::from_base64_key(&config.encryption.key)?),
+        };
+
+        let (keys, current_key_id, save) = if Path::new(&path).exists() {
+            let file = Self::read_file(&path)?;
+            let save = key_encryption_key.is_some() && file.keys.iter().any(|key| !key.wrapped);
+            let mut keys = Vec::with_capacity(file.keys.len());
+            for entry in file.keys {
+                let key = text::from_base64_as_bytes(&entry.key)?;
+                let key = match (entry.wrapped, &key_encryption_key) {
+                    (false, _) => key,
+                    (true, Some(key_encryption_key)) => key_encryption_key.decrypt(&key)?,
+                    (true, None) => {
+                        return Err(IggyError::InvalidEncryptionKey).with_error_context(|error| {
+                            format!("{COMPONENT} (error: {error}) - key with ID: {} is encrypted, but the key encryption key is missing", entry.id)
+                        });
+                    }
+                };
+                keys.push(KeyringKey {
+                    id: entry.id,
+                    created_at: entry.created_at,
+                    key,
+                });
+            }
+            (keys, file.current_key_id, save)
+        } else {
+            info!("Keyring file: {path} doesn't exist, creating the new keyring.");
+            let key = KeyringKey {
+                id: 1,
+                created_at: IggyTimestamp::now(),
+                key: KeyringEncryptor::generate_key(),
+            };
+            (vec![key], 1, true)
+        };
+
+        let encryptor = KeyringEncryptor::new(
+            &keys
+                .iter()
+                .map(|key| (key.id, key.key.clone()))
+                .collect::<Vec<_>>(),
+            current_key_id,
+            legacy,
+        )?;
+        let keyring = Self {
+            path,
+            key_encryption_key,
+            keys: Mutex::new(keys),
+            encryptor: Arc::new(EncryptorKind::Keyring(encryptor)),
+        };
+        if save {
+            keyring.save(&keyring.keys.lock().unwrap(), current_key_id)?;
+        }
+
+        info!(
+            "Loaded keyring with current key ID: {current_key_id}, keys: {}.",
+            keyring.keys.lock().unwrap().len()
+        );
+        Ok(keyring)
+    }
+
+    pub fn encryptor(&self) -> Arc<EncryptorKind> {
+        self.encryptor.clone()
+    }
+
+    pub fn keyring_encryptor(&self) -> &KeyringEncryptor {
+        match self.encryptor.as_ref() {
+            EncryptorKind::Keyring(encryptor) => encryptor,
+            _ => unreachable!("Keyring always uses the keyring encryptor."),
+        }
+    }
+
+    pub fn current_key_id(&self) -> u32 {
+        self.keyring_encryptor().current_key_id()
+    }
+
+    /// Generates the new key and uses it for the encryption from now on.
+    /// The keyring file is saved before the key is used, so that no data is encrypted with the key that could be lost.
+    pub fn rotate(&self) -> Result<u32, IggyError> {
+        let mut keys = self.keys.lock().unwrap();
+        let id = keys.iter().map(|key| key.id).max().unwrap_or_default() + 1;
+        keys.push(KeyringKey {
+            id,
+            created_at: IggyTimestamp::now(),
+            key: KeyringEncryptor::generate_key(),
+        });
+        if let Err(error) = self.save(&keys, id) {
+            keys.pop();
+            return Err(error);
+        }
+
+        self.keyring_encryptor()
+            .rotate(id, &keys.last().unwrap().key)?;
+        info!("Rotated the encryption key, current key ID: {id}.");
+        Ok(id)
+    }
+
+    fn read_file(path: &str) -> Result<KeyringFile, IggyError> {
+        let bytes = std::fs::read(path)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read keyring file: {path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        serde_json::from_slice(&bytes)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to parse keyring file: {path}")
+            })
+            .map_err(|_| IggyError::CannotDeserializeResource)
+    }
+
+    // The file is replaced atomically, so it's never left with the partially written keys.
+    fn save(&self, keys: &[KeyringKey], current_key_id: u32) -> Result<(), IggyError> {
+        let mut entries = Vec::with_capacity(keys.len());
+        for key in keys {
+            let (value, wrapped) = match &self.key_encryption_key {
+                Some(key_encryption_key) => (key_encryption_key.encrypt(&key.key)?, true),
+                None => (key.key.clone(), false),
+            };
+            entries.push(KeyringFileEntry {
+                id: key.id,
+                created_at: key.created_at,
+                key: text::as_base64(&value),
+                wrapped,
+            });
+        }
+
+        let bytes = serde_json::to_vec_pretty(&KeyringFile {
+            current_key_id,
+            keys: entries,
+        })
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to serialize keyring")
+        })
+        .map_err(|_| IggyError::CannotSerializeResource)?;
+
+        let path = &self.path;
+        if let Some(parent) = Path::new(path).parent() {
+            std::fs::create_dir_all(parent)
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to create keyring directory for path: {path}")
+                })
+                .map_err(|_| IggyError::CannotCreateBaseDirectory(parent.display().to_string()))?;
+        }
+
+        let tmp_path = format!("{path}.tmp");
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(&tmp_path)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create keyring file: {tmp_path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        file.write_all(&bytes)
+            .and_then(|_| file.sync_all())
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to write keyring file: {tmp_path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        std::fs::rename(&tmp_path, path)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replace keyring file: {path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        Ok(())
+    }
+}
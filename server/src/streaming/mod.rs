@@ -1,19 +1,23 @@
+pub mod backups;
 pub mod batching;
 pub mod cache;
 pub mod clients;
 mod deduplication;
 pub mod diagnostics;
+pub mod keyring;
 pub mod local_sizeable;
 pub mod models;
 pub mod partitions;
 pub mod persistence;
 pub mod personal_access_tokens;
 pub mod polling_consumer;
+pub mod schemas;
 pub mod segments;
 pub mod session;
 pub mod storage;
 pub mod streams;
 pub mod systems;
+pub mod tiered_storage;
 pub mod topics;
 pub mod users;
 pub mod utils;
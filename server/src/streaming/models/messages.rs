@@ -1,6 +1,7 @@
 use crate::streaming::local_sizeable::LocalSizeable;
 use crate::streaming::local_sizeable::RealSize;
 use crate::streaming::models::COMPONENT;
+use crate::streaming::segments::SegmentFileRange;
 use bytes::{BufMut, Bytes, BytesMut};
 use error_set::ErrContext;
 use iggy::bytes_serializable::BytesSerializable;
@@ -24,6 +25,14 @@ pub struct PolledMessages {
     pub messages: Vec<Arc<PolledMessage>>,
 }
 
+// The message batches polled from the segment file, which are sent directly from the disk.
+#[derive(Debug)]
+pub struct PolledMessageBatches {
+    pub partition_id: u32,
+    pub current_offset: u64,
+    pub file_range: SegmentFileRange,
+}
+
 #[derive(Debug)]
 pub struct RetainedMessage {
     pub id: u128,
@@ -32,6 +41,7 @@ pub struct RetainedMessage {
     pub checksum: u32,
     pub message_state: MessageState,
     pub headers: Option<Bytes>,
+    pub key: Option<Bytes>,
     pub payload: Bytes,
 }
 
@@ -45,6 +55,7 @@ impl RetainedMessage {
             id: self.id,
             checksum: self.checksum,
             headers,
+            key: self.key.clone(),
             length: IggyByteSize::from(self.payload.len() as u64),
             payload: self.payload.clone(),
         };
@@ -62,6 +73,7 @@ impl RetainedMessage {
             id: message.id,
             payload: message.payload,
             headers: message.headers.map(|h| h.to_bytes()),
+            key: message.key,
         }
     }
 
@@ -74,10 +86,11 @@ impl RetainedMessage {
         let checksum = self.checksum;
         let message_state = self.message_state;
         let headers = &self.headers;
+        let key = &self.key;
 
         bytes.put_u32_le(length.as_bytes_u64() as u32);
         bytes.put_u64_le(offset);
-        bytes.put_u8(message_state.as_code());
+        bytes.put_u8(message_state.as_code_with_key_flag(key.is_some()));
         bytes.put_u64_le(timestamp);
         bytes.put_u128_le(id);
         bytes.put_u32_le(checksum);
@@ -88,6 +101,11 @@ impl RetainedMessage {
         } else {
             bytes.put_u32_le(0u32);
         }
+        if let Some(key) = key {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(key.len() as u8);
+            bytes.put_slice(key);
+        }
         bytes.put_slice(&payload);
     }
 
@@ -100,9 +118,10 @@ impl RetainedMessage {
                 })
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
-        let message_state = MessageState::from_code(bytes[8]).with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to parse message state")
-        })?;
+        let (message_state, has_key) = MessageState::from_code_with_key_flag(bytes[8])
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to parse message state")
+            })?;
         let timestamp = u64::from_le_bytes(
             bytes[9..17]
                 .try_into()
@@ -140,7 +159,22 @@ impl RetainedMessage {
         } else {
             None
         };
-        let position = 41 + headers_length as usize;
+        let mut position = 41 + headers_length as usize;
+        let key = if has_key {
+            let key_length = *bytes
+                .get(position)
+                .ok_or(IggyError::InvalidMessagePayloadLength)?
+                as usize;
+            if position + 1 + key_length > bytes.len() {
+                return Err(IggyError::InvalidMessagePayloadLength);
+            }
+
+            let key = bytes.slice(position + 1..position + 1 + key_length);
+            position += 1 + key_length;
+            Some(key)
+        } else {
+            None
+        };
         let payload = bytes.slice(position..);
 
         Ok(RetainedMessage {
@@ -150,6 +184,7 @@ impl RetainedMessage {
             checksum,
             message_state,
             headers,
+            key,
             payload,
         })
     }
@@ -158,7 +193,8 @@ impl RetainedMessage {
 impl Sizeable for RetainedMessage {
     fn get_size_bytes(&self) -> IggyByteSize {
         let headers_len = self.headers.as_ref().map(|h| 4 + h.len()).unwrap_or(4);
-        let size = 16 + 8 + 8 + 4 + 1 + headers_len + self.payload.len();
+        let key_len = self.key.as_ref().map(|k| 1 + k.len()).unwrap_or(0);
+        let size = 16 + 8 + 8 + 4 + 1 + headers_len + key_len + self.payload.len();
         IggyByteSize::from(size as u64)
     }
 }
@@ -179,6 +215,12 @@ impl RealSize for RetainedMessage {
             total_size += mem::size_of::<Bytes>() * 2; // Bytes overhead
         }
 
+        total_size += mem::size_of::<Option<Bytes>>(); // key
+        if let Some(key) = &self.key {
+            total_size += key.len(); // key length
+            total_size += mem::size_of::<Bytes>() * 2; // Bytes overhead
+        }
+
         total_size += self.payload.len(); // payload length
         total_size += mem::size_of::<Bytes>() * 2; // Bytes overhead
 
@@ -199,7 +241,8 @@ where
 {
     fn get_size_bytes(&self) -> IggyByteSize {
         let headers_len = self.headers.as_ref().map(|h| 4 + h.len()).unwrap_or(4);
-        let size = 16 + 8 + 8 + 4 + 1 + headers_len + self.payload.len();
+        let key_len = self.key.as_ref().map(|k| 1 + k.len()).unwrap_or(0);
+        let size = 16 + 8 + 8 + 4 + 1 + headers_len + key_len + self.payload.len();
         IggyByteSize::from(size as u64)
     }
 }
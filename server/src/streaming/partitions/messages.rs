@@ -8,6 +8,7 @@ use crate::streaming::segments::*;
 use error_set::ErrContext;
 use iggy::confirmation::Confirmation;
 use iggy::error::IggyError;
+use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
 use iggy::messages::send_messages::Message;
 use iggy::models::messages::POLLED_MESSAGE_METADATA;
 use iggy::utils::timestamp::IggyTimestamp;
@@ -37,6 +38,41 @@ impl Partition {
         let mut messages = Vec::new();
         let mut remaining = count as usize;
 
+        if let Some(tiered_storage) = self.storage.tiered.as_ref() {
+            for offloaded_segment in &self.offloaded_segments {
+                if offloaded_segment.end_timestamp < query_ts {
+                    continue;
+                }
+
+                let segment = tiered_storage
+                    .get_segment(offloaded_segment)
+                    .await
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to get offloaded segment, \
+                            partition: {}, segment start: {}, end: {}",
+                            self, offloaded_segment.start_offset, offloaded_segment.end_offset
+                        )
+                    })?;
+                let segment_messages = segment
+                    .get_messages_by_timestamp(query_ts, remaining)
+                    .await
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to get messages from offloaded segment, \
+                            partition: {}, segment start: {}, end: {}",
+                            self, segment.start_offset, segment.end_offset
+                        )
+                    })?;
+
+                remaining -= segment_messages.len();
+                messages.extend(segment_messages);
+                if remaining == 0 {
+                    return Ok(messages);
+                }
+            }
+        }
+
         for segment in &self.segments {
             if segment.end_timestamp < query_ts {
                 continue;
@@ -80,6 +116,21 @@ impl Partition {
             return Ok(Vec::new());
         }
 
+        if self.is_offloaded(start_offset) {
+            return self
+                .get_offloaded_messages_by_offset(start_offset, count)
+                .await;
+        }
+
+        self.get_local_messages_by_offset(start_offset, count).await
+    }
+
+    // Retrieves messages by offset (up to a specified count) from the segments stored on the local disk.
+    async fn get_local_messages_by_offset(
+        &self,
+        start_offset: u64,
+        count: u32,
+    ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
         let end_offset = self.get_end_offset(start_offset, count);
         if let Some(cached) = self.try_get_messages_from_cache(start_offset, end_offset) {
             return Ok(cached);
@@ -97,6 +148,82 @@ impl Partition {
         }
     }
 
+    // Checks whether the offset precedes the local segments and can be read from the offloaded ones.
+    fn is_offloaded(&self, offset: u64) -> bool {
+        if self.storage.tiered.is_none() {
+            return false;
+        }
+
+        let Some(last_offloaded_segment) = self.offloaded_segments.last() else {
+            return false;
+        };
+
+        offset < self.segments[0].start_offset && offset <= last_offloaded_segment.end_offset
+    }
+
+    // Retrieves messages by offset (up to a specified count) from the offloaded segments fetched from the archiver,
+    // followed by the local segments, if the offloaded ones don't contain enough messages.
+    async fn get_offloaded_messages_by_offset(
+        &self,
+        start_offset: u64,
+        count: u32,
+    ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
+        let tiered_storage = self.storage.tiered.as_ref().unwrap();
+        let mut messages = Vec::with_capacity(count as usize);
+        let mut offset = start_offset;
+        for offloaded_segment in &self.offloaded_segments {
+            let remaining_count = count - messages.len() as u32;
+            if remaining_count == 0 {
+                return Ok(messages);
+            }
+
+            if offloaded_segment.end_offset < offset {
+                continue;
+            }
+
+            let segment = tiered_storage
+                .get_segment(offloaded_segment)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get offloaded segment, partition: {}, \
+                         segment start: {}, end: {}",
+                        self, offloaded_segment.start_offset, offloaded_segment.end_offset
+                    )
+                })?;
+            let segment_messages = segment
+                .get_messages_by_offset(offset, remaining_count)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get messages from offloaded segment, segment: {}, \
+                         offset: {}, count: {}",
+                        segment, offset, remaining_count
+                    )
+                })?;
+            if let Some(message) = segment_messages.last() {
+                offset = message.offset + 1;
+            }
+            messages.extend(segment_messages);
+        }
+
+        let remaining_count = count - messages.len() as u32;
+        if remaining_count == 0 {
+            return Ok(messages);
+        }
+
+        let offset = offset.max(self.segments[0].start_offset);
+        if offset > self.current_offset {
+            return Ok(messages);
+        }
+
+        let local_messages = self
+            .get_local_messages_by_offset(offset, remaining_count)
+            .await?;
+        messages.extend(local_messages);
+        Ok(messages)
+    }
+
     // Retrieves the first messages (up to a specified count).
     pub async fn get_first_messages(
         &self,
@@ -162,6 +289,70 @@ impl Partition {
         self.get_messages_by_offset(offset, count).await
     }
 
+    // Retrieves the range of the segment file holding the messages for the polling strategy (up to a specified count),
+    // if they're stored only on the local disk and can be sent directly from the file.
+    pub async fn get_file_range(
+        &self,
+        consumer: PollingConsumer,
+        strategy: PollingStrategy,
+        count: u32,
+    ) -> Result<Option<SegmentFileRange>, IggyError> {
+        if self.segments.is_empty() || count == 0 {
+            return Ok(None);
+        }
+
+        let (start_offset, count) = match strategy.kind {
+            PollingKind::Offset => (strategy.value, count),
+            PollingKind::First => (0, count),
+            PollingKind::Last => {
+                let count = std::cmp::min(count as u64, self.current_offset + 1);
+                (1 + self.current_offset - count, count as u32)
+            }
+            PollingKind::Next => {
+                let consumer_offset = match consumer {
+                    PollingConsumer::Consumer(consumer_id, _) => {
+                        self.consumer_offsets.get(&consumer_id)
+                    }
+                    PollingConsumer::ConsumerGroup(group_id, _) => {
+                        self.consumer_group_offsets.get(&group_id)
+                    }
+                };
+                match consumer_offset {
+                    Some(consumer_offset) => (consumer_offset.offset + 1, count),
+                    None => (0, count),
+                }
+            }
+            PollingKind::Timestamp => return Ok(None),
+        };
+
+        if start_offset > self.current_offset || self.is_offloaded(start_offset) {
+            return Ok(None);
+        }
+
+        if let Some(cache) = self.cache.as_ref() {
+            if !cache.is_empty() && start_offset >= cache[0].offset {
+                return Ok(None);
+            }
+        }
+
+        let end_offset = self.get_end_offset(start_offset, count);
+        let segments = self.filter_segments_by_offsets(start_offset, end_offset);
+        let Some(segment) = segments.first() else {
+            return Ok(None);
+        };
+
+        segment
+            .get_file_range_by_offset(start_offset, end_offset)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to get file range from segment, segment: {}, \
+                     start offset: {}, end offset: {}",
+                    segment, start_offset, end_offset
+                )
+            })
+    }
+
     fn get_end_offset(&self, offset: u64, count: u32) -> u64 {
         let mut end_offset = offset + (count - 1) as u64;
         let segment = self.segments.last().unwrap();
@@ -355,6 +546,20 @@ impl Partition {
     ) -> Result<(), IggyError> {
         {
             let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+            // The batch is encrypted with a single key, so the unsaved messages encrypted with the previous one are saved first.
+            let encryption_key_id = appendable_batch_info.encryption_key_id;
+            if last_segment
+                .unsaved_messages
+                .as_ref()
+                .is_some_and(|unsaved| {
+                    !unsaved.is_empty() && unsaved.encryption_key_id() != encryption_key_id
+                })
+            {
+                last_segment.persist_messages(None).await.with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to persist messages encrypted with the previous key, segment: {last_segment}")
+                })?;
+                self.unsaved_messages_count = 0;
+            }
             if last_segment.is_closed {
                 let start_offset = last_segment.end_offset + 1;
                 trace!(
@@ -417,7 +622,12 @@ impl Partition {
         {
             let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
             last_segment
-                .append_batch(batch_size, messages_count, &retained_messages)
+                .append_batch(
+                    batch_size,
+                    messages_count,
+                    &retained_messages,
+                    appendable_batch_info.encryption_key_id,
+                )
                 .await
                 .with_error_context(|error| {
                     format!(
@@ -496,13 +706,13 @@ mod tests {
         let (mut partition, _tempdir) = create_partition(false).await;
         let messages = create_messages();
         let messages_count = messages.len() as u32;
-        let appendable_batch_info = AppendableBatchInfo {
-            batch_size: messages
+        let appendable_batch_info = AppendableBatchInfo::new(
+            messages
                 .iter()
                 .map(|m| m.get_size_bytes())
                 .sum::<IggyByteSize>(),
-            partition_id: partition.partition_id,
-        };
+            partition.partition_id,
+        );
         partition
             .append_messages(appendable_batch_info, messages, None)
             .await
@@ -521,13 +731,13 @@ mod tests {
         let messages = create_messages();
         let messages_count = messages.len() as u32;
         let unique_messages_count = 3;
-        let appendable_batch_info = AppendableBatchInfo {
-            batch_size: messages
+        let appendable_batch_info = AppendableBatchInfo::new(
+            messages
                 .iter()
                 .map(|m| m.get_size_bytes())
                 .sum::<IggyByteSize>(),
-            partition_id: partition.partition_id,
-        };
+            partition.partition_id,
+        );
         partition
             .append_messages(appendable_batch_info, messages, None)
             .await
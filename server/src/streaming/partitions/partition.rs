@@ -45,6 +45,8 @@ pub struct Partition {
     pub(crate) consumer_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) consumer_group_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) segments: Vec<Segment>,
+    pub(crate) offloaded_segments: Vec<OffloadedSegment>,
+    pub(crate) recovery: Option<SegmentRecovery>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
 }
@@ -128,6 +130,8 @@ impl Partition {
                 false => None,
             },
             segments: vec![],
+            offloaded_segments: vec![],
+            recovery: None,
             current_offset: 0,
             unsaved_messages_count: 0,
             should_increment_offset: false,
@@ -47,6 +47,11 @@ impl Partition {
                 .fetch_sub(1, Ordering::SeqCst);
         }
         self.segments.clear();
+        self.delete_offloaded_segments()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to delete offloaded segments in partition: {self}")
+            })?;
         self.storage
             .partition
             .delete_consumer_offsets(&self.consumer_offsets_path)
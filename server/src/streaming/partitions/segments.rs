@@ -5,7 +5,9 @@ use crate::streaming::partitions::COMPONENT;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::timestamp::IggyTimestamp;
+use tokio::fs::remove_file;
 use tracing::info;
 
 pub struct DeletedSegment {
@@ -112,4 +114,92 @@ impl Partition {
         );
         Ok(deleted_segment)
     }
+
+    pub fn get_offloaded_segments(&self) -> &Vec<OffloadedSegment> {
+        &self.offloaded_segments
+    }
+
+    /// Deletes the closed (and already archived) segment from the local disk, while keeping its metadata,
+    /// so that its messages can be fetched back from the archiver by the tiered storage.
+    pub async fn offload_segment(
+        &mut self,
+        start_offset: u64,
+    ) -> Result<DeletedSegment, IggyError> {
+        let Some(segment) = self.get_segment(start_offset) else {
+            return Err(IggyError::SegmentNotFound);
+        };
+
+        let offloaded_segment = segment.to_offloaded().await.with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to describe offloaded segment: {segment}"
+            )
+        })?;
+        offloaded_segment
+            .save(&offloaded_segment.get_path(&self.config))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to save offloaded segment with start offset: {start_offset}")
+            })?;
+        let deleted_segment = self.delete_segment(start_offset).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to delete offloaded segment with start offset: {start_offset}")
+        })?;
+
+        self.offloaded_segments.push(offloaded_segment);
+        self.offloaded_segments
+            .sort_by_key(|segment| segment.start_offset);
+        info!(
+            "Segment with start offset: {} has been offloaded from partition with ID: {}, stream with ID: {}, topic with ID: {}",
+            start_offset, self.partition_id, self.stream_id, self.topic_id
+        );
+        Ok(deleted_segment)
+    }
+
+    /// Deletes the offloaded segments containing only the expired messages, returns the number of deleted segments.
+    pub async fn delete_expired_offloaded_segments(
+        &mut self,
+        now: IggyTimestamp,
+    ) -> Result<u32, IggyError> {
+        let message_expiry = match self.message_expiry {
+            IggyExpiry::ServerDefault => self.config.segment.message_expiry,
+            message_expiry => message_expiry,
+        };
+        let (expired_segments, offloaded_segments): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.offloaded_segments)
+                .into_iter()
+                .partition(|segment| segment.is_expired(message_expiry, now));
+        self.offloaded_segments = offloaded_segments;
+        for segment in &expired_segments {
+            self.delete_offloaded_segment(segment).await?;
+        }
+
+        Ok(expired_segments.len() as u32)
+    }
+
+    pub(crate) async fn delete_offloaded_segments(&mut self) -> Result<(), IggyError> {
+        for segment in std::mem::take(&mut self.offloaded_segments) {
+            self.delete_offloaded_segment(&segment).await?;
+        }
+
+        Ok(())
+    }
+
+    // The segment files stored by the archiver are not deleted, just like the ones of the archived local segments.
+    async fn delete_offloaded_segment(&self, segment: &OffloadedSegment) -> Result<(), IggyError> {
+        let path = segment.get_path(&self.config);
+        remove_file(&path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to delete offloaded segment, path: {path}")
+            })
+            .map_err(|_| IggyError::CannotDeleteFile)?;
+        if let Some(tiered_storage) = self.storage.tiered.as_ref() {
+            tiered_storage.remove_segment(segment).await;
+        }
+
+        info!(
+            "Offloaded segment with start offset: {} has been deleted from partition with ID: {}, stream with ID: {}, topic with ID: {}",
+            segment.start_offset, self.partition_id, self.stream_id, self.topic_id
+        );
+        Ok(())
+    }
 }
@@ -1,4 +1,6 @@
+use crate::compat::batch_conversion::batch_converter::{BatchConverter, BatchFormat};
 use crate::compat::index_rebuilding::index_rebuilder::IndexRebuilder;
+use crate::configs::system::IndexCacheMode;
 use crate::state::system::PartitionState;
 use crate::streaming::batching::batch_accumulator::BatchAccumulator;
 use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
@@ -57,10 +59,24 @@ impl PartitionStorage for FilePartitionStorage {
                 return Err(IggyError::CannotReadPartitions);
             }
 
+        let mut start_offsets = Vec::new();
         let mut dir_entries = dir_entries.unwrap();
         while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
             let path = dir_entry.path();
             let extension = path.extension();
+            if extension.is_some_and(|extension| extension == OFFLOADED_EXTENSION) {
+                let offloaded_segment = OffloadedSegment::load(&path.to_string_lossy())
+                    .await
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to load offloaded segment, path: {}",
+                            path.display()
+                        )
+                    })?;
+                partition.offloaded_segments.push(offloaded_segment);
+                continue;
+            }
+
             if extension.is_none() || extension.unwrap() != LOG_EXTENSION {
                 continue;
             }
@@ -76,6 +92,34 @@ impl PartitionStorage for FilePartitionStorage {
                 .replace(&format!(".{}", LOG_EXTENSION), "");
 
             let start_offset = log_file_name.parse::<u64>().unwrap();
+            start_offsets.push(start_offset);
+        }
+
+        start_offsets.sort();
+        // The segment is deleted after its offloaded metadata is saved, so both of them exist if the server stopped in between.
+        let (stale_offloaded_segments, offloaded_segments): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut partition.offloaded_segments)
+                .into_iter()
+                .partition(|offloaded_segment| {
+                    start_offsets.contains(&offloaded_segment.start_offset)
+                });
+        partition.offloaded_segments = offloaded_segments;
+        for offloaded_segment in stale_offloaded_segments {
+            let path = offloaded_segment.get_path(&partition.config);
+            warn!(
+                "Segment with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {} exists locally, removing its offloaded metadata: {path}",
+                offloaded_segment.start_offset, partition.partition_id, partition.stream_id, partition.topic_id
+            );
+            fs::remove_file(&path)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to remove offloaded segment, path: {path}")
+                })
+                .map_err(|_| IggyError::CannotDeleteFile)?;
+        }
+        // Only the last segment is being written to, thus it's the only one which might contain the incomplete data.
+        let active_start_offset = start_offsets.last().copied();
+        for start_offset in start_offsets {
             let mut segment = Segment::create(
                 partition.stream_id,
                 partition.topic_id,
@@ -95,7 +139,80 @@ impl PartitionStorage for FilePartitionStorage {
             let log_path = segment.log_path.to_owned();
             let time_index_path = index_path.replace(INDEX_EXTENSION, "timeindex");
 
-            let index_cache_enabled = partition.config.segment.cache_indexes;
+            // Convert the segments written before the versioned batch format, before they're validated or loaded.
+            let batch_converter = BatchConverter::new(
+                log_path.clone(),
+                index_path.clone(),
+                segment.time_index_path.clone(),
+                partition.config.segment.time_index_interval.as_bytes_u64(),
+                start_offset,
+            );
+            let batch_format = batch_converter.detect_format().await.unwrap_or_else(|e| {
+                panic!(
+                    "Failed to detect batch format for partition with ID: {} for
+                    stream with ID: {} and topic with ID: {}. Error: {e}",
+                    partition.partition_id, partition.stream_id, partition.topic_id,
+                )
+            });
+            if batch_format == Some(BatchFormat::Legacy) {
+                warn!(
+                    "Log at path {} contains the legacy batches, converting them...",
+                    log_path
+                );
+                let now = tokio::time::Instant::now();
+                let backup_path = format!(
+                    "{}/{}/{}/{}",
+                    partition.config.get_compatibility_backup_path(),
+                    partition.stream_id,
+                    partition.topic_id,
+                    partition.partition_id
+                );
+                let batches_count = batch_converter
+                    .convert(Some(&backup_path), partition.config.encryption.enabled)
+                    .await
+                    .unwrap_or_else(|e| {
+                        panic!(
+                            "Failed to convert batches for partition with ID: {} for
+                    stream with ID: {} and topic with ID: {}. Error: {e}",
+                            partition.partition_id, partition.stream_id, partition.topic_id,
+                        )
+                    });
+                info!(
+                    "Converting {} batches of log at path {} finished, the original log is stored in {}, it took {} ms",
+                    batches_count,
+                    log_path,
+                    backup_path,
+                    now.elapsed().as_millis()
+                );
+            }
+
+            let index_cache_enabled =
+                partition.config.segment.cache_indexes != IndexCacheMode::OnDemand;
+
+            if partition.config.recovery.validate_active_segments
+                && Some(start_offset) == active_start_offset
+            {
+                let recovery = segment
+                    .recover(partition.config.recovery.validated_batches_count)
+                    .await
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to recover segment: {segment}"
+                        )
+                    })?;
+                if recovery.is_repaired() {
+                    warn!(
+                        "Repaired segment with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}, truncated bytes: {}, rebuilt indexes: {}.",
+                        start_offset,
+                        partition.partition_id,
+                        partition.stream_id,
+                        partition.topic_id,
+                        recovery.truncated_bytes,
+                        recovery.rebuilt_indexes
+                    );
+                }
+                partition.recovery = Some(recovery);
+            }
 
             let index_path_exists = tokio::fs::try_exists(&index_path).await.unwrap();
             let time_index_path_exists = tokio::fs::try_exists(&time_index_path).await.unwrap();
@@ -178,6 +295,18 @@ impl PartitionStorage for FilePartitionStorage {
         partition
             .segments
             .sort_by(|a, b| a.start_offset.cmp(&b.start_offset));
+        partition
+            .offloaded_segments
+            .sort_by_key(|segment| segment.start_offset);
+        if !partition.offloaded_segments.is_empty() {
+            info!(
+                "Loaded {} offloaded segments for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+                partition.offloaded_segments.len(),
+                partition.partition_id,
+                partition.stream_id,
+                partition.topic_id
+            );
+        }
 
         let end_offsets = partition
             .segments
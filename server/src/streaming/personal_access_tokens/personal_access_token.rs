@@ -1,4 +1,5 @@
 use crate::streaming::utils::hash;
+use iggy::models::permissions::Permissions;
 use iggy::models::user_info::UserId;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::text::as_base64;
@@ -13,6 +14,8 @@ pub struct PersonalAccessToken {
     pub name: String,
     pub token: String,
     pub expiry_at: Option<IggyTimestamp>,
+    /// Restricts the token to a subset of the owner's permissions, `None` means all the permissions of the owner.
+    pub permissions: Option<Permissions>,
 }
 
 impl PersonalAccessToken {
@@ -22,11 +25,9 @@ impl PersonalAccessToken {
         name: &str,
         now: IggyTimestamp,
         expiry: IggyExpiry,
+        permissions: Option<Permissions>,
     ) -> (Self, String) {
-        let mut buffer: [u8; SIZE] = [0; SIZE];
-        let system_random = ring::rand::SystemRandom::new();
-        system_random.fill(&mut buffer).unwrap();
-        let token = as_base64(&buffer);
+        let token = Self::generate_token();
         let token_hash = Self::hash_token(&token);
         (
             Self {
@@ -34,22 +35,33 @@ impl PersonalAccessToken {
                 name: name.to_string(),
                 token: token_hash,
                 expiry_at: Self::calculate_expiry_at(now, expiry),
+                permissions,
             },
             token,
         )
     }
 
+    /// Generates the random token, only its hash is stored by the server.
+    pub fn generate_token() -> String {
+        let mut buffer: [u8; SIZE] = [0; SIZE];
+        let system_random = ring::rand::SystemRandom::new();
+        system_random.fill(&mut buffer).unwrap();
+        as_base64(&buffer)
+    }
+
     pub fn raw(
         user_id: UserId,
         name: &str,
         token_hash: &str,
         expiry_at: Option<IggyTimestamp>,
+        permissions: Option<Permissions>,
     ) -> Self {
         Self {
             user_id,
             name: name.into(),
             token: token_hash.into(),
             expiry_at,
+            permissions,
         }
     }
 
@@ -87,7 +99,7 @@ mod tests {
         let now = IggyTimestamp::now();
         let name = "test_token";
         let (personal_access_token, raw_token) =
-            PersonalAccessToken::new(user_id, name, now, IggyExpiry::NeverExpire);
+            PersonalAccessToken::new(user_id, name, now, IggyExpiry::NeverExpire, None);
         assert_eq!(personal_access_token.name, name);
         assert!(!personal_access_token.token.is_empty());
         assert!(!raw_token.is_empty());
@@ -105,7 +117,7 @@ mod tests {
         let expiry_ms = 10;
         let expiry = IggyExpiry::ExpireDuration(IggyDuration::from(expiry_ms));
         let name = "test_token";
-        let (personal_access_token, _) = PersonalAccessToken::new(user_id, name, now, expiry);
+        let (personal_access_token, _) = PersonalAccessToken::new(user_id, name, now, expiry, None);
         let later = IggyTimestamp::from(now.as_micros() + expiry_ms + 1);
         assert!(personal_access_token.is_expired(later));
     }
@@ -0,0 +1,449 @@
+use ahash::AHashSet;
+use apache_avro::schema::{Name, ResolvedSchema};
+use apache_avro::Schema;
+use std::collections::HashMap;
+
+static INT: Schema = Schema::Int;
+static LONG: Schema = Schema::Long;
+static BYTES: Schema = Schema::Bytes;
+static STRING: Schema = Schema::String;
+
+/// Parsed Avro schema, with all the named types (records, enums and fixed) resolved by their full names.
+#[derive(Debug)]
+pub struct AvroDefinition {
+    schema: Schema,
+    names: HashMap<Name, Schema>,
+}
+
+impl AvroDefinition {
+    pub fn parse(definition: &str) -> Result<Self, String> {
+        let schema = Schema::parse_str(definition).map_err(|error| error.to_string())?;
+        let names = ResolvedSchema::try_from(&schema)
+            .map_err(|error| error.to_string())?
+            .get_names()
+            .iter()
+            .map(|(name, schema)| (name.clone(), (*schema).clone()))
+            .collect();
+        let definition = AvroDefinition { schema, names };
+        definition.ensure_not_recursive(&definition.schema, &mut Vec::new())?;
+        Ok(definition)
+    }
+
+    /// Decodes the binary encoded datum, which must consume the whole payload.
+    pub fn validate(&self, payload: &[u8]) -> Result<(), String> {
+        let mut reader = payload;
+        apache_avro::from_avro_datum(&self.schema, &mut reader, None)
+            .map_err(|error| error.to_string())?;
+        if !reader.is_empty() {
+            return Err(format!("{} trailing byte(s) after the datum", reader.len()));
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the data written with the `writer` schema can be read with the `reader` schema,
+    /// following the Avro schema resolution rules.
+    pub fn can_read(reader: &AvroDefinition, writer: &AvroDefinition) -> Result<(), String> {
+        let mut resolver = Resolver {
+            reader,
+            writer,
+            visited: AHashSet::new(),
+        };
+        resolver.resolve(&reader.schema, &writer.schema, "")
+    }
+
+    /// The decoder has no limit of the nesting, so the recursive types are rejected, as the deeply nested
+    /// payload of such a type could overflow the stack of the server while being validated.
+    fn ensure_not_recursive<'a>(
+        &'a self,
+        schema: &'a Schema,
+        ancestors: &mut Vec<&'a Name>,
+    ) -> Result<(), String> {
+        match schema {
+            Schema::Ref { name } => {
+                if ancestors.contains(&name) {
+                    return Err(format!(
+                        "recursive type '{}' is not supported",
+                        name.fullname(None)
+                    ));
+                }
+                self.ensure_not_recursive(self.underlying(schema)?, ancestors)
+            }
+            Schema::Record(record) => {
+                ancestors.push(&record.name);
+                for field in &record.fields {
+                    self.ensure_not_recursive(&field.schema, ancestors)?;
+                }
+                ancestors.pop();
+                Ok(())
+            }
+            Schema::Array(array) => self.ensure_not_recursive(&array.items, ancestors),
+            Schema::Map(map) => self.ensure_not_recursive(&map.types, ancestors),
+            Schema::Union(union) => union
+                .variants()
+                .iter()
+                .try_for_each(|branch| self.ensure_not_recursive(branch, ancestors)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Resolves the references to the named types and replaces the logical types with their underlying types.
+    fn underlying<'a>(&'a self, schema: &'a Schema) -> Result<&'a Schema, String> {
+        match schema {
+            Schema::Ref { name } => self
+                .names
+                .get(name)
+                .ok_or_else(|| format!("unknown type '{}'", name.fullname(None))),
+            Schema::Decimal(decimal) => Ok(&decimal.inner),
+            Schema::Date | Schema::TimeMillis => Ok(&INT),
+            Schema::TimeMicros
+            | Schema::TimestampMillis
+            | Schema::TimestampMicros
+            | Schema::TimestampNanos
+            | Schema::LocalTimestampMillis
+            | Schema::LocalTimestampMicros
+            | Schema::LocalTimestampNanos => Ok(&LONG),
+            Schema::BigDecimal => Ok(&BYTES),
+            Schema::Uuid => Ok(&STRING),
+            _ => Ok(schema),
+        }
+    }
+}
+
+struct Resolver<'a> {
+    reader: &'a AvroDefinition,
+    writer: &'a AvroDefinition,
+    /// Pairs of the reader and writer records already resolved, to skip the named types used more than once.
+    visited: AHashSet<(String, String)>,
+}
+
+impl Resolver<'_> {
+    fn resolve(&mut self, reader: &Schema, writer: &Schema, path: &str) -> Result<(), String> {
+        let reader = self.reader.underlying(reader)?;
+        let writer = self.writer.underlying(writer)?;
+        if let Schema::Union(writer_union) = writer {
+            for branch in writer_union.variants() {
+                self.resolve(reader, branch, path)?;
+            }
+            return Ok(());
+        }
+
+        if let Schema::Union(reader_union) = reader {
+            for branch in reader_union.variants() {
+                if self.clone_visited().resolve(branch, writer, path).is_ok() {
+                    // Resolve again to keep the visited records of the matching branch.
+                    return self.resolve(branch, writer, path);
+                }
+            }
+            return Err(format!(
+                "{}: no union branch matches the writer type {}",
+                display_path(path),
+                type_name(writer)
+            ));
+        }
+
+        match (reader, writer) {
+            (Schema::Null, Schema::Null)
+            | (Schema::Boolean, Schema::Boolean)
+            | (Schema::Int, Schema::Int)
+            | (Schema::Long, Schema::Int | Schema::Long)
+            | (Schema::Float, Schema::Int | Schema::Long | Schema::Float)
+            | (Schema::Double, Schema::Int | Schema::Long | Schema::Float | Schema::Double)
+            | (Schema::Bytes | Schema::String, Schema::Bytes | Schema::String)
+            | (Schema::Duration, Schema::Duration) => Ok(()),
+            (Schema::Array(reader_array), Schema::Array(writer_array)) => self.resolve(
+                &reader_array.items,
+                &writer_array.items,
+                &format!("{path}[]"),
+            ),
+            (Schema::Map(reader_map), Schema::Map(writer_map)) => {
+                self.resolve(&reader_map.types, &writer_map.types, &format!("{path}{{}}"))
+            }
+            (Schema::Record(reader_record), Schema::Record(writer_record)) => {
+                let reader_name = reader_record.name.fullname(None);
+                let writer_name = writer_record.name.fullname(None);
+                if !self
+                    .visited
+                    .insert((reader_name.clone(), writer_name.clone()))
+                {
+                    return Ok(());
+                }
+
+                ensure_names_match(
+                    &reader_record.name,
+                    reader_record.aliases.as_deref(),
+                    &writer_record.name,
+                    path,
+                )?;
+                for reader_field in &reader_record.fields {
+                    let field_path = if path.is_empty() {
+                        reader_field.name.clone()
+                    } else {
+                        format!("{path}.{}", reader_field.name)
+                    };
+                    let writer_field = writer_record.fields.iter().find(|writer_field| {
+                        writer_field.name == reader_field.name
+                            || reader_field
+                                .aliases
+                                .as_ref()
+                                .is_some_and(|aliases| aliases.contains(&writer_field.name))
+                    });
+                    match writer_field {
+                        Some(writer_field) => {
+                            self.resolve(&reader_field.schema, &writer_field.schema, &field_path)?
+                        }
+                        None if reader_field.default.is_some() => {}
+                        None => {
+                            return Err(format!(
+                                "field '{field_path}' is missing in the writer schema and has no default"
+                            ))
+                        }
+                    }
+                }
+                Ok(())
+            }
+            (Schema::Enum(reader_enum), Schema::Enum(writer_enum)) => {
+                ensure_names_match(
+                    &reader_enum.name,
+                    reader_enum.aliases.as_deref(),
+                    &writer_enum.name,
+                    path,
+                )?;
+                if reader_enum.default.is_none() {
+                    if let Some(symbol) = writer_enum
+                        .symbols
+                        .iter()
+                        .find(|symbol| !reader_enum.symbols.contains(symbol))
+                    {
+                        return Err(format!(
+                            "{}: symbol '{symbol}' of enum '{}' is missing and there is no default",
+                            display_path(path),
+                            writer_enum.name.fullname(None)
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            (Schema::Fixed(reader_fixed), Schema::Fixed(writer_fixed)) => {
+                ensure_names_match(
+                    &reader_fixed.name,
+                    reader_fixed.aliases.as_deref(),
+                    &writer_fixed.name,
+                    path,
+                )?;
+                if reader_fixed.size != writer_fixed.size {
+                    return Err(format!(
+                        "{}: fixed size changed from {} to {}",
+                        display_path(path),
+                        writer_fixed.size,
+                        reader_fixed.size
+                    ));
+                }
+                Ok(())
+            }
+            _ => Err(format!(
+                "{}: type {} cannot be read as {}",
+                display_path(path),
+                type_name(writer),
+                type_name(reader)
+            )),
+        }
+    }
+
+    fn clone_visited(&self) -> Self {
+        Resolver {
+            reader: self.reader,
+            writer: self.writer,
+            visited: self.visited.clone(),
+        }
+    }
+}
+
+/// The unqualified names of the reader and writer types must match, unless the reader has an alias of the writer.
+fn ensure_names_match(
+    reader_name: &Name,
+    reader_aliases: Option<&[apache_avro::schema::Alias]>,
+    writer_name: &Name,
+    path: &str,
+) -> Result<(), String> {
+    let writer_full_name = writer_name.fullname(None);
+    if reader_name.name == writer_name.name
+        || reader_aliases.is_some_and(|aliases| {
+            aliases
+                .iter()
+                .any(|alias| alias.fullname(reader_name.namespace.clone()) == writer_full_name)
+        })
+    {
+        return Ok(());
+    }
+
+    Err(format!(
+        "{}: type '{writer_full_name}' cannot be read as '{}'",
+        display_path(path),
+        reader_name.fullname(None)
+    ))
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() {
+        "<root>"
+    } else {
+        path
+    }
+}
+
+fn type_name(schema: &Schema) -> String {
+    match schema {
+        Schema::Record(record) => format!("'{}'", record.name.fullname(None)),
+        Schema::Enum(enum_schema) => format!("'{}'", enum_schema.name.fullname(None)),
+        Schema::Fixed(fixed) => format!("'{}'", fixed.name.fullname(None)),
+        Schema::Ref { name } => format!("'{}'", name.fullname(None)),
+        Schema::Array(array) => format!("array<{}>", type_name(&array.items)),
+        Schema::Map(map) => format!("map<{}>", type_name(&map.types)),
+        Schema::Union(union) => format!(
+            "[{}]",
+            union
+                .variants()
+                .iter()
+                .map(type_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        _ => serde_json::to_string(schema).unwrap_or_else(|_| format!("{schema:?}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER_V1: &str = r#"{
+        "type": "record",
+        "name": "User",
+        "namespace": "iggy",
+        "fields": [
+            {"name": "id", "type": "int"},
+            {"name": "name", "type": "string"},
+            {"name": "tags", "type": {"type": "array", "items": "string"}}
+        ]
+    }"#;
+
+    const USER_V2: &str = r#"{
+        "type": "record",
+        "name": "User",
+        "namespace": "iggy",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "name", "type": "string"},
+            {"name": "tags", "type": {"type": "array", "items": "string"}},
+            {"name": "email", "type": ["null", "string"], "default": null}
+        ]
+    }"#;
+
+    fn zigzag(value: i64) -> Vec<u8> {
+        let mut value = ((value << 1) ^ (value >> 63)) as u64;
+        let mut bytes = Vec::new();
+        loop {
+            if value & !0x7f == 0 {
+                bytes.push(value as u8);
+                return bytes;
+            }
+            bytes.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+    }
+
+    fn encode_user(id: i64, name: &str, tags: &[&str]) -> Vec<u8> {
+        let mut bytes = zigzag(id);
+        bytes.extend(zigzag(name.len() as i64));
+        bytes.extend(name.as_bytes());
+        if !tags.is_empty() {
+            bytes.extend(zigzag(tags.len() as i64));
+            for tag in tags {
+                bytes.extend(zigzag(tag.len() as i64));
+                bytes.extend(tag.as_bytes());
+            }
+        }
+        bytes.extend(zigzag(0));
+        bytes
+    }
+
+    #[test]
+    fn valid_datum_should_be_decoded() {
+        let schema = AvroDefinition::parse(USER_V1).unwrap();
+        assert!(schema
+            .validate(&encode_user(1, "user", &["a", "b"]))
+            .is_ok());
+        assert!(schema.validate(&encode_user(-5, "", &[])).is_ok());
+    }
+
+    #[test]
+    fn invalid_datum_should_be_rejected() {
+        let schema = AvroDefinition::parse(USER_V1).unwrap();
+        let mut payload = encode_user(1, "user", &["a"]);
+        assert!(schema.validate(&payload[..payload.len() - 2]).is_err());
+        payload.push(0);
+        assert!(schema.validate(&payload).is_err());
+        assert!(schema.validate(b"not avro").is_err());
+    }
+
+    #[test]
+    fn recursive_schema_should_be_rejected() {
+        assert!(AvroDefinition::parse(
+            r#"{"type": "record", "name": "Node", "fields": [
+                {"name": "value", "type": "int"},
+                {"name": "next", "type": ["null", "Node"]}
+            ]}"#,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn reused_named_type_should_be_parsed() {
+        let schema = AvroDefinition::parse(
+            r#"{"type": "record", "name": "Route", "fields": [
+                {"name": "from", "type": {"type": "record", "name": "Point", "fields": [
+                    {"name": "x", "type": "int"}
+                ]}},
+                {"name": "to", "type": "Point"}
+            ]}"#,
+        )
+        .unwrap();
+        // from: {x: 1}, to: {x: 2}
+        assert!(schema.validate(&[2, 4]).is_ok());
+        assert!(AvroDefinition::can_read(&schema, &schema).is_ok());
+    }
+
+    #[test]
+    fn invalid_schema_should_be_rejected() {
+        assert!(AvroDefinition::parse("{").is_err());
+        assert!(AvroDefinition::parse(r#"{"type": "record", "name": "A"}"#).is_err());
+        assert!(AvroDefinition::parse(r#""Unknown""#).is_err());
+        assert!(AvroDefinition::parse(r#"{"type": "enum", "name": "E", "symbols": [1]}"#).is_err());
+    }
+
+    #[test]
+    fn added_field_with_default_and_promoted_type_should_be_backward_compatible() {
+        let v1 = AvroDefinition::parse(USER_V1).unwrap();
+        let v2 = AvroDefinition::parse(USER_V2).unwrap();
+        assert!(AvroDefinition::can_read(&v2, &v1).is_ok());
+        // The old reader cannot read the long written as the new ID.
+        assert!(AvroDefinition::can_read(&v1, &v2).is_err());
+    }
+
+    #[test]
+    fn added_field_without_default_should_not_be_backward_compatible() {
+        let v1 = AvroDefinition::parse(USER_V1).unwrap();
+        let v2 = AvroDefinition::parse(
+            r#"{"type": "record", "name": "User", "namespace": "iggy", "fields": [
+                {"name": "id", "type": "int"},
+                {"name": "name", "type": "string"},
+                {"name": "tags", "type": {"type": "array", "items": "string"}},
+                {"name": "age", "type": "int"}
+            ]}"#,
+        )
+        .unwrap();
+        assert!(AvroDefinition::can_read(&v2, &v1).is_err());
+        assert!(AvroDefinition::can_read(&v1, &v2).is_ok());
+    }
+}
@@ -0,0 +1,306 @@
+use jsonschema::Validator;
+use serde_json::{Map, Value};
+
+/// JSON Schema document, compiled into the validator of the payloads.
+pub struct JsonSchemaDefinition {
+    schema: Value,
+    validator: Validator,
+}
+
+impl std::fmt::Debug for JsonSchemaDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonSchemaDefinition")
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
+
+impl JsonSchemaDefinition {
+    pub fn parse(definition: &str) -> Result<Self, String> {
+        let schema = serde_json::from_str::<Value>(definition)
+            .map_err(|error| format!("invalid JSON: {error}"))?;
+        let validator = jsonschema::validator_for(&schema).map_err(|error| error.to_string())?;
+        Ok(JsonSchemaDefinition { schema, validator })
+    }
+
+    /// Parses the payload as JSON document and validates it against the schema.
+    pub fn validate(&self, payload: &[u8]) -> Result<(), String> {
+        let document = serde_json::from_slice::<Value>(payload)
+            .map_err(|error| format!("invalid JSON: {error}"))?;
+        self.validator.validate(&document).map_err(|error| {
+            let path = error.instance_path.to_string();
+            if path.is_empty() {
+                error.to_string()
+            } else {
+                format!("{path}: {error}")
+            }
+        })
+    }
+
+    /// Checks if the documents valid against the `writer` schema are also valid against the `reader` schema.
+    /// The check is structural and covers the commonly evolved keywords: `type`, `properties`, `required`,
+    /// `additionalProperties`, `enum` and `items`, the other keywords are not compared.
+    pub fn can_read(
+        reader: &JsonSchemaDefinition,
+        writer: &JsonSchemaDefinition,
+    ) -> Result<(), String> {
+        can_read(&reader.schema, &writer.schema, "")
+    }
+}
+
+fn can_read(reader: &Value, writer: &Value, path: &str) -> Result<(), String> {
+    let (reader, writer) = match (reader, writer) {
+        (Value::Bool(true), _) | (_, Value::Bool(false)) => return Ok(()),
+        (Value::Bool(false), _) => {
+            return Err(format!("{}: no document is accepted", display_path(path)))
+        }
+        (Value::Object(reader), Value::Object(writer)) => (reader, writer),
+        // The writer accepting any document can only be read by the reader without any constraints.
+        (Value::Object(reader), Value::Bool(true)) => {
+            if reader.keys().all(|keyword| is_annotation(keyword)) {
+                return Ok(());
+            }
+            return Err(format!(
+                "{}: any document is accepted by the writer schema",
+                display_path(path)
+            ));
+        }
+        _ => return Err(format!("{}: invalid schema", display_path(path))),
+    };
+
+    if let Some(reader_types) = types(reader) {
+        let Some(writer_types) = types(writer) else {
+            return Err(format!(
+                "{}: type is restricted to {}",
+                display_path(path),
+                reader_types.join(", ")
+            ));
+        };
+        for writer_type in &writer_types {
+            let accepted = reader_types.contains(writer_type)
+                || (writer_type == "integer" && reader_types.iter().any(|kind| kind == "number"));
+            if !accepted {
+                return Err(format!(
+                    "{}: type '{writer_type}' is no longer accepted",
+                    display_path(path)
+                ));
+            }
+        }
+    }
+
+    if let Some(Value::Array(reader_values)) = reader.get("enum") {
+        let Some(Value::Array(writer_values)) = writer.get("enum") else {
+            return Err(format!(
+                "{}: values are restricted by enum",
+                display_path(path)
+            ));
+        };
+        if let Some(value) = writer_values
+            .iter()
+            .find(|value| !reader_values.contains(value))
+        {
+            return Err(format!(
+                "{}: enum value {value} is no longer accepted",
+                display_path(path)
+            ));
+        }
+    }
+
+    let empty = Map::new();
+    let reader_properties = properties(reader).unwrap_or(&empty);
+    let writer_properties = properties(writer).unwrap_or(&empty);
+    let writer_required = required(writer);
+    for name in required(reader) {
+        if !writer_required.contains(&name) {
+            return Err(format!(
+                "{}: property '{name}' is required, but it's optional in the writer schema",
+                display_path(path)
+            ));
+        }
+    }
+
+    for (name, reader_property) in reader_properties {
+        let property_path = format!("{path}/{name}");
+        match writer_properties.get(name) {
+            Some(writer_property) => can_read(reader_property, writer_property, &property_path)?,
+            // The property not described by the writer is compared with its additional properties schema (if any),
+            // otherwise adding the optional property is considered compatible, as in the other formats.
+            None => {
+                if let Some(writer_additional @ Value::Object(_)) =
+                    writer.get("additionalProperties")
+                {
+                    can_read(reader_property, writer_additional, &property_path)?
+                }
+            }
+        }
+    }
+
+    match reader.get("additionalProperties") {
+        Some(Value::Bool(false)) => {
+            if let Some(name) = writer_properties
+                .keys()
+                .find(|name| !reader_properties.contains_key(*name))
+            {
+                return Err(format!(
+                    "{}: property '{name}' is not allowed",
+                    display_path(path)
+                ));
+            }
+            if !matches!(writer.get("additionalProperties"), Some(Value::Bool(false))) {
+                return Err(format!(
+                    "{}: additional properties are not allowed",
+                    display_path(path)
+                ));
+            }
+        }
+        Some(reader_additional @ Value::Object(_)) => {
+            for (name, writer_property) in writer_properties {
+                if !reader_properties.contains_key(name) {
+                    can_read(
+                        reader_additional,
+                        writer_property,
+                        &format!("{path}/{name}"),
+                    )?;
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if let Some(reader_items) = reader.get("items") {
+        let writer_items = writer.get("items").unwrap_or(&Value::Bool(true));
+        can_read(reader_items, writer_items, &format!("{path}/items"))?;
+    }
+
+    Ok(())
+}
+
+fn types(schema: &Map<String, Value>) -> Option<Vec<String>> {
+    match schema.get("type")? {
+        Value::String(kind) => Some(vec![kind.clone()]),
+        Value::Array(kinds) => Some(
+            kinds
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn properties(schema: &Map<String, Value>) -> Option<&Map<String, Value>> {
+    schema.get("properties").and_then(Value::as_object)
+}
+
+fn required(schema: &Map<String, Value>) -> Vec<String> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| {
+            required
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The keywords which don't restrict the accepted documents.
+fn is_annotation(keyword: &str) -> bool {
+    matches!(
+        keyword,
+        "$schema"
+            | "$id"
+            | "$comment"
+            | "title"
+            | "description"
+            | "default"
+            | "examples"
+            | "deprecated"
+            | "readOnly"
+            | "writeOnly"
+    )
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() {
+        "/"
+    } else {
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER_V1: &str = r#"{
+        "type": "object",
+        "properties": {
+            "id": {"type": "integer"},
+            "status": {"type": "string", "enum": ["new", "paid"]}
+        },
+        "required": ["id"]
+    }"#;
+
+    #[test]
+    fn valid_document_should_pass_validation() {
+        let schema = JsonSchemaDefinition::parse(ORDER_V1).unwrap();
+        assert!(schema.validate(br#"{"id": 1, "status": "new"}"#).is_ok());
+    }
+
+    #[test]
+    fn invalid_document_should_fail_validation() {
+        let schema = JsonSchemaDefinition::parse(ORDER_V1).unwrap();
+        assert!(schema.validate(br#"{"status": "new"}"#).is_err());
+        assert!(schema.validate(br#"{"id": "1"}"#).is_err());
+        assert!(schema.validate(b"not json").is_err());
+    }
+
+    #[test]
+    fn invalid_schema_should_be_rejected() {
+        assert!(JsonSchemaDefinition::parse("{").is_err());
+        assert!(JsonSchemaDefinition::parse(r#"{"type": 1}"#).is_err());
+    }
+
+    #[test]
+    fn added_optional_property_and_enum_value_should_be_backward_compatible() {
+        let v1 = JsonSchemaDefinition::parse(ORDER_V1).unwrap();
+        let v2 = JsonSchemaDefinition::parse(
+            r#"{
+                "type": "object",
+                "properties": {
+                    "id": {"type": "number"},
+                    "status": {"type": "string", "enum": ["new", "paid", "shipped"]},
+                    "note": {"type": "string"}
+                },
+                "required": ["id"]
+            }"#,
+        )
+        .unwrap();
+        assert!(JsonSchemaDefinition::can_read(&v2, &v1).is_ok());
+        // The old reader doesn't accept the new enum value.
+        assert!(JsonSchemaDefinition::can_read(&v1, &v2).is_err());
+    }
+
+    #[test]
+    fn added_required_property_should_not_be_backward_compatible() {
+        let v1 = JsonSchemaDefinition::parse(ORDER_V1).unwrap();
+        let v2 = JsonSchemaDefinition::parse(
+            r#"{
+                "type": "object",
+                "properties": {
+                    "id": {"type": "integer"},
+                    "status": {"type": "string", "enum": ["new", "paid"]},
+                    "customer": {"type": "string"}
+                },
+                "required": ["id", "customer"]
+            }"#,
+        )
+        .unwrap();
+        assert!(JsonSchemaDefinition::can_read(&v2, &v1).is_err());
+        assert!(JsonSchemaDefinition::can_read(&v1, &v2).is_ok());
+    }
+}
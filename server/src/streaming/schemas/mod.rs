@@ -0,0 +1,81 @@
+pub mod avro;
+pub mod json_schema;
+pub mod protobuf;
+pub mod registry;
+
+use crate::streaming::schemas::avro::AvroDefinition;
+use crate::streaming::schemas::json_schema::JsonSchemaDefinition;
+use crate::streaming::schemas::protobuf::ProtobufDefinition;
+use iggy::error::IggyError;
+use iggy::models::schema::{SchemaCompatibility, SchemaType};
+
+/// Compiled schema definition, used to validate the payloads and check the compatibility of the schemas.
+#[derive(Debug)]
+pub enum SchemaValidator {
+    JsonSchema(JsonSchemaDefinition),
+    Avro(AvroDefinition),
+    Protobuf(ProtobufDefinition),
+}
+
+impl SchemaValidator {
+    pub fn compile(schema_type: SchemaType, definition: &str) -> Result<Self, IggyError> {
+        let validator = match schema_type {
+            SchemaType::JsonSchema => JsonSchemaDefinition::parse(definition).map(Self::JsonSchema),
+            SchemaType::Avro => AvroDefinition::parse(definition).map(Self::Avro),
+            SchemaType::Protobuf => ProtobufDefinition::parse(definition).map(Self::Protobuf),
+        };
+        validator.map_err(IggyError::InvalidSchema)
+    }
+
+    pub fn schema_type(&self) -> SchemaType {
+        match self {
+            SchemaValidator::JsonSchema(_) => SchemaType::JsonSchema,
+            SchemaValidator::Avro(_) => SchemaType::Avro,
+            SchemaValidator::Protobuf(_) => SchemaType::Protobuf,
+        }
+    }
+
+    pub fn validate(&self, payload: &[u8]) -> Result<(), String> {
+        match self {
+            SchemaValidator::JsonSchema(schema) => schema.validate(payload),
+            SchemaValidator::Avro(schema) => schema.validate(payload),
+            SchemaValidator::Protobuf(schema) => schema.validate(payload),
+        }
+    }
+
+    /// Checks the new version of the schema against the previous one using the compatibility rule.
+    pub fn check_compatibility(
+        &self,
+        previous: &SchemaValidator,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), String> {
+        match compatibility {
+            SchemaCompatibility::None => Ok(()),
+            SchemaCompatibility::Backward => self.can_read(previous),
+            SchemaCompatibility::Forward => previous.can_read(self),
+            SchemaCompatibility::Full => {
+                self.can_read(previous)?;
+                previous.can_read(self)
+            }
+        }
+    }
+
+    fn can_read(&self, writer: &SchemaValidator) -> Result<(), String> {
+        match (self, writer) {
+            (SchemaValidator::JsonSchema(reader), SchemaValidator::JsonSchema(writer)) => {
+                JsonSchemaDefinition::can_read(reader, writer)
+            }
+            (SchemaValidator::Avro(reader), SchemaValidator::Avro(writer)) => {
+                AvroDefinition::can_read(reader, writer)
+            }
+            (SchemaValidator::Protobuf(reader), SchemaValidator::Protobuf(writer)) => {
+                ProtobufDefinition::can_read(reader, writer)
+            }
+            _ => Err(format!(
+                "schema type changed from {} to {}",
+                writer.schema_type(),
+                self.schema_type()
+            )),
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use ahash::AHashSet;
+use prost_reflect::{
+    Cardinality, DescriptorPool, DynamicMessage, Kind, MessageDescriptor, ReflectMessage, Value,
+};
+use protox::file::{ChainFileResolver, File, FileResolver, GoogleFileResolver};
+use protox::Compiler;
+
+/// Name of the in-memory file holding the schema definition, which can import only the well-known types.
+const SCHEMA_FILE_NAME: &str = "schema.proto";
+
+/// Protobuf file (proto2 or proto3) compiled into the descriptors of its messages.
+#[derive(Debug)]
+pub struct ProtobufDefinition {
+    /// The first message in the file, which is the type of the payloads.
+    root: MessageDescriptor,
+}
+
+struct SchemaFileResolver {
+    definition: String,
+}
+
+impl FileResolver for SchemaFileResolver {
+    fn open_file(&self, name: &str) -> Result<File, protox::Error> {
+        if name != SCHEMA_FILE_NAME {
+            return Err(protox::Error::file_not_found(name));
+        }
+
+        File::from_source(name, &self.definition)
+    }
+}
+
+impl ProtobufDefinition {
+    pub fn parse(definition: &str) -> Result<Self, String> {
+        let mut resolver = ChainFileResolver::new();
+        resolver.add(SchemaFileResolver {
+            definition: definition.to_string(),
+        });
+        resolver.add(GoogleFileResolver::new());
+        let pool: DescriptorPool = Compiler::with_file_resolver(resolver)
+            .open_file(SCHEMA_FILE_NAME)
+            .map_err(|error| error.to_string())?
+            .descriptor_pool();
+        let root = pool
+            .get_file_by_name(SCHEMA_FILE_NAME)
+            .and_then(|file| file.messages().next())
+            .ok_or_else(|| "no message is defined".to_string())?;
+        Ok(ProtobufDefinition { root })
+    }
+
+    /// Decodes the payload as the binary encoded root message.
+    pub fn validate(&self, payload: &[u8]) -> Result<(), String> {
+        let message = DynamicMessage::decode(self.root.clone(), payload)
+            .map_err(|error| error.to_string())?;
+        ensure_required_fields(&message)
+    }
+
+    /// Checks if the data written with the `writer` schema can be read with the `reader` schema,
+    /// the fields are matched by their numbers and their types must be wire compatible.
+    pub fn can_read(
+        reader: &ProtobufDefinition,
+        writer: &ProtobufDefinition,
+    ) -> Result<(), String> {
+        let mut visited = AHashSet::new();
+        can_read_message(&reader.root, &writer.root, &mut visited)
+    }
+}
+
+/// The decoder doesn't check the proto2 required fields, so they are checked on the decoded message.
+fn ensure_required_fields(message: &DynamicMessage) -> Result<(), String> {
+    for field in message.descriptor().fields() {
+        if field.cardinality() == Cardinality::Required && !message.has_field(&field) {
+            return Err(format!("required field '{}' is missing", field.full_name()));
+        }
+    }
+
+    for (_, value) in message.fields() {
+        match value {
+            Value::Message(message) => ensure_required_fields(message)?,
+            Value::List(values) => {
+                for value in values {
+                    if let Value::Message(message) = value {
+                        ensure_required_fields(message)?;
+                    }
+                }
+            }
+            Value::Map(values) => {
+                for value in values.values() {
+                    if let Value::Message(message) = value {
+                        ensure_required_fields(message)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn can_read_message(
+    reader: &MessageDescriptor,
+    writer: &MessageDescriptor,
+    visited: &mut AHashSet<(String, String)>,
+) -> Result<(), String> {
+    if !visited.insert((
+        reader.full_name().to_string(),
+        writer.full_name().to_string(),
+    )) {
+        return Ok(());
+    }
+
+    for reader_field in reader.fields() {
+        let path = reader_field.full_name();
+        let Some(writer_field) = writer.get_field(reader_field.number()) else {
+            if reader_field.cardinality() == Cardinality::Required {
+                return Err(format!(
+                    "{path}: required field {} is missing in the writer schema",
+                    reader_field.number()
+                ));
+            }
+            continue;
+        };
+
+        if reader_field.is_list() != writer_field.is_list() {
+            return Err(format!(
+                "{path}: field {} changed between repeated and singular",
+                reader_field.number()
+            ));
+        }
+        if reader_field.cardinality() == Cardinality::Required
+            && writer_field.cardinality() != Cardinality::Required
+        {
+            return Err(format!(
+                "{path}: field {} is required, but it's optional in the writer schema",
+                reader_field.number()
+            ));
+        }
+        if reader_field.is_map() != writer_field.is_map() {
+            return Err(format!(
+                "{path}: field {} changed between map and non-map",
+                reader_field.number()
+            ));
+        }
+        if let (Kind::Message(reader_entry), Kind::Message(writer_entry)) =
+            (reader_field.kind(), writer_field.kind())
+        {
+            if reader_field.is_map() {
+                if compatibility_group(&reader_entry.map_entry_key_field().kind())
+                    != compatibility_group(&writer_entry.map_entry_key_field().kind())
+                {
+                    return Err(format!("{path}: map key type changed"));
+                }
+                can_read_kind(
+                    &reader_entry.map_entry_value_field().kind(),
+                    &writer_entry.map_entry_value_field().kind(),
+                    path,
+                    visited,
+                )?;
+                continue;
+            }
+        }
+        can_read_kind(&reader_field.kind(), &writer_field.kind(), path, visited)?;
+    }
+    Ok(())
+}
+
+fn can_read_kind(
+    reader_kind: &Kind,
+    writer_kind: &Kind,
+    path: &str,
+    visited: &mut AHashSet<(String, String)>,
+) -> Result<(), String> {
+    if let (Kind::Message(reader_message), Kind::Message(writer_message)) =
+        (reader_kind, writer_kind)
+    {
+        return can_read_message(reader_message, writer_message, visited);
+    }
+
+    match (
+        compatibility_group(reader_kind),
+        compatibility_group(writer_kind),
+    ) {
+        (Some(reader_group), Some(writer_group)) if reader_group == writer_group => Ok(()),
+        _ => Err(format!(
+            "{path}: type changed from {} to {}",
+            kind_name(writer_kind),
+            kind_name(reader_kind)
+        )),
+    }
+}
+
+/// The scalars (and enums) sharing the same group can be changed into each other without breaking the readers.
+fn compatibility_group(kind: &Kind) -> Option<u8> {
+    let group = match kind {
+        Kind::Int32 | Kind::Int64 | Kind::Uint32 | Kind::Uint64 | Kind::Bool | Kind::Enum(_) => 0,
+        Kind::Sint32 | Kind::Sint64 => 1,
+        Kind::Fixed32 | Kind::Sfixed32 => 2,
+        Kind::Fixed64 | Kind::Sfixed64 => 3,
+        Kind::String | Kind::Bytes => 4,
+        Kind::Float => 5,
+        Kind::Double => 6,
+        Kind::Message(_) => return None,
+    };
+    Some(group)
+}
+
+fn kind_name(kind: &Kind) -> String {
+    match kind {
+        Kind::Message(message) => message.full_name().to_string(),
+        Kind::Enum(enum_descriptor) => enum_descriptor.full_name().to_string(),
+        _ => format!("{kind:?}").to_lowercase(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER_V1: &str = r#"
+        syntax = "proto3";
+        package shop;
+
+        // The payload of the topic.
+        message Order {
+            uint64 id = 1;
+            string customer = 2;
+            repeated Item items = 3;
+            Status status = 4;
+            map<string, int32> counters = 5;
+
+            message Item {
+                string sku = 1;
+                uint32 quantity = 2;
+            }
+        }
+
+        enum Status {
+            NEW = 0;
+            PAID = 1;
+        }
+    "#;
+
+    fn encode_key(number: u64, wire_type: u64) -> u8 {
+        ((number << 3) | wire_type) as u8
+    }
+
+    fn encode_order() -> Vec<u8> {
+        let item = [encode_key(1, 2), 1, b'a', encode_key(2, 0), 3];
+        let counter = [encode_key(1, 2), 1, b'k', encode_key(2, 0), 7];
+        let mut payload = vec![encode_key(1, 0), 42, encode_key(2, 2), 2, b'j', b'd'];
+        payload.extend([encode_key(3, 2), item.len() as u8]);
+        payload.extend(item);
+        payload.extend([encode_key(4, 0), 1]);
+        payload.extend([encode_key(5, 2), counter.len() as u8]);
+        payload.extend(counter);
+        payload
+    }
+
+    #[test]
+    fn valid_message_should_be_decoded() {
+        let schema = ProtobufDefinition::parse(ORDER_V1).unwrap();
+        assert!(schema.validate(&encode_order()).is_ok());
+        assert!(schema.validate(&[]).is_ok());
+    }
+
+    #[test]
+    fn invalid_message_should_be_rejected() {
+        let schema = ProtobufDefinition::parse(ORDER_V1).unwrap();
+        let payload = encode_order();
+        assert!(schema.validate(&payload[..payload.len() - 1]).is_err());
+        // The customer encoded as varint instead of string.
+        assert!(schema.validate(&[encode_key(2, 0), 1]).is_err());
+        // The item with invalid UTF-8 SKU.
+        assert!(schema
+            .validate(&[encode_key(3, 2), 3, encode_key(1, 2), 1, 0xff])
+            .is_err());
+    }
+
+    #[test]
+    fn required_field_should_be_present() {
+        let schema = ProtobufDefinition::parse(
+            "syntax = \"proto2\"; message Event { required int32 id = 1; optional string name = 2; }",
+        )
+        .unwrap();
+        assert!(schema.validate(&[encode_key(1, 0), 1]).is_ok());
+        assert!(schema.validate(&[encode_key(2, 2), 1, b'a']).is_err());
+    }
+
+    #[test]
+    fn deeply_nested_message_should_be_rejected() {
+        let schema =
+            ProtobufDefinition::parse("syntax = \"proto3\"; message Node { Node next = 1; }")
+                .unwrap();
+        let nest = |depth: usize| {
+            let mut payload = Vec::new();
+            for _ in 0..depth {
+                let mut length = payload.len() as u64;
+                let mut nested = vec![encode_key(1, 2)];
+                while length >= 0x80 {
+                    nested.push((length as u8 & 0x7f) | 0x80);
+                    length >>= 7;
+                }
+                nested.push(length as u8);
+                nested.extend(payload);
+                payload = nested;
+            }
+            payload
+        };
+        assert!(schema.validate(&nest(50)).is_ok());
+        assert!(schema.validate(&nest(10_000)).is_err());
+    }
+
+    #[test]
+    fn invalid_schema_should_be_rejected() {
+        assert!(ProtobufDefinition::parse("syntax = \"proto3\";").is_err());
+        assert!(ProtobufDefinition::parse("message A { Unknown value = 1; }").is_err());
+        assert!(ProtobufDefinition::parse("message A { int32 a = 1; int32 b = 1; }").is_err());
+        assert!(ProtobufDefinition::parse("message A { int32 a = 1 }").is_err());
+    }
+
+    #[test]
+    fn added_field_and_compatible_type_change_should_be_compatible() {
+        let v1 = ProtobufDefinition::parse(ORDER_V1).unwrap();
+        let v2 = ProtobufDefinition::parse(
+            &ORDER_V1.replace("uint64 id = 1;", "int64 id = 1; optional string note = 6;"),
+        )
+        .unwrap();
+        assert!(ProtobufDefinition::can_read(&v2, &v1).is_ok());
+        assert!(ProtobufDefinition::can_read(&v1, &v2).is_ok());
+    }
+
+    #[test]
+    fn changed_field_type_should_not_be_compatible() {
+        let v1 = ProtobufDefinition::parse(ORDER_V1).unwrap();
+        let v2 = ProtobufDefinition::parse(
+            &ORDER_V1.replace("string customer = 2;", "double customer = 2;"),
+        )
+        .unwrap();
+        assert!(ProtobufDefinition::can_read(&v2, &v1).is_err());
+        assert!(ProtobufDefinition::can_read(&v1, &v2).is_err());
+    }
+}
@@ -0,0 +1,256 @@
+use crate::streaming::schemas::SchemaValidator;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::Message;
+use iggy::models::header::{HeaderKey, HeaderKind};
+use iggy::models::schema::{
+    Schema, SchemaCompatibility, SchemaType, TopicSchemas, SCHEMA_ID_HEADER,
+};
+use std::collections::BTreeMap;
+
+/// Registered version of the schema, along with its compiled definition.
+#[derive(Debug)]
+pub struct RegisteredSchema {
+    pub schema: Schema,
+    pub validator: SchemaValidator,
+}
+
+/// Schema registry of the topic, holding all the registered versions of the schema.
+/// - `compatibility` - Rule the newly registered versions are checked against the latest one
+/// - `validate_payloads` - Whether the payloads of the appended messages are validated
+/// - `current_version` - The last assigned version, the versions are never reused, even if deleted
+/// - `schemas` - The registered versions of the schema, ordered by version
+#[derive(Debug, Default)]
+pub struct TopicSchemaRegistry {
+    pub compatibility: SchemaCompatibility,
+    pub validate_payloads: bool,
+    pub current_version: u32,
+    pub schemas: BTreeMap<u32, RegisteredSchema>,
+}
+
+impl TopicSchemaRegistry {
+    pub fn is_empty(&self) -> bool {
+        self.schemas.is_empty()
+    }
+
+    pub fn latest(&self) -> Option<&RegisteredSchema> {
+        self.schemas.values().next_back()
+    }
+
+    /// Returns the given version of the schema, or the latest one if the version is not specified.
+    pub fn get(&self, version: Option<u32>) -> Option<&RegisteredSchema> {
+        match version {
+            Some(version) => self.schemas.get(&version),
+            None => self.latest(),
+        }
+    }
+
+    pub fn get_by_id(&self, schema_id: u32) -> Option<&RegisteredSchema> {
+        self.schemas
+            .values()
+            .find(|registered| registered.schema.id == schema_id)
+    }
+
+    /// Returns the already registered version with the same type and definition (if any).
+    pub fn find_identical(&self, schema_type: SchemaType, definition: &str) -> Option<&Schema> {
+        self.schemas
+            .values()
+            .map(|registered| &registered.schema)
+            .find(|schema| schema.schema_type == schema_type && schema.definition == definition)
+    }
+
+    /// Checks the new version of the schema against the latest one, using the compatibility rule of the topic.
+    pub fn check_compatibility(&self, validator: &SchemaValidator) -> Result<(), IggyError> {
+        let Some(latest) = self.latest() else {
+            return Ok(());
+        };
+
+        // The type of the schema can't be changed, regardless of the compatibility rule.
+        let compatibility = match latest.validator.schema_type() == validator.schema_type() {
+            true => self.compatibility,
+            false => SchemaCompatibility::Full,
+        };
+        validator
+            .check_compatibility(&latest.validator, compatibility)
+            .map_err(|reason| IggyError::IncompatibleSchema(latest.schema.version, reason))
+    }
+
+    pub fn register(&mut self, schema: Schema, validator: SchemaValidator) {
+        if schema.version > self.current_version {
+            self.current_version = schema.version;
+        }
+        self.schemas
+            .insert(schema.version, RegisteredSchema { schema, validator });
+    }
+
+    pub fn delete(&mut self, version: u32) -> Option<RegisteredSchema> {
+        self.schemas.remove(&version)
+    }
+
+    /// Validates the payloads of the messages against the schemas referenced by their headers.
+    /// The schema ID header can be either `u32` or the string containing the number.
+    pub fn validate_messages(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        messages: &[Message],
+    ) -> Result<(), IggyError> {
+        if !self.validate_payloads {
+            return Ok(());
+        }
+
+        let schema_id_header = HeaderKey::new(SCHEMA_ID_HEADER)?;
+        for message in messages {
+            let schema_id = message
+                .headers
+                .as_ref()
+                .and_then(|headers| headers.get(&schema_id_header))
+                .and_then(|value| match value.kind {
+                    HeaderKind::Uint32 => value.as_uint32().ok(),
+                    HeaderKind::String => value.as_str().ok()?.trim().parse().ok(),
+                    _ => None,
+                })
+                .ok_or(IggyError::MissingMessageSchemaId(message.id))?;
+            let registered =
+                self.get_by_id(schema_id)
+                    .ok_or(IggyError::SchemaNotRegisteredForTopic(
+                        schema_id, topic_id, stream_id,
+                    ))?;
+            registered
+                .validator
+                .validate(&message.payload)
+                .map_err(|reason| {
+                    IggyError::InvalidMessagePayload(message.id, schema_id, reason)
+                })?;
+        }
+        Ok(())
+    }
+
+    pub fn to_topic_schemas(&self) -> TopicSchemas {
+        TopicSchemas {
+            compatibility: self.compatibility,
+            validate_payloads: self.validate_payloads,
+            schemas: self
+                .schemas
+                .values()
+                .map(|registered| registered.schema.clone())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::timestamp::IggyTimestamp;
+
+    fn register(
+        registry: &mut TopicSchemaRegistry,
+        id: u32,
+        definition: &str,
+    ) -> Result<(), IggyError> {
+        let validator = SchemaValidator::compile(SchemaType::JsonSchema, definition)?;
+        registry.check_compatibility(&validator)?;
+        let version = registry.current_version + 1;
+        registry.register(
+            Schema {
+                id,
+                stream_id: 1,
+                topic_id: 1,
+                version,
+                schema_type: SchemaType::JsonSchema,
+                definition: definition.to_string(),
+                created_at: IggyTimestamp::now(),
+            },
+            validator,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn compatible_versions_should_be_registered() {
+        let mut registry = TopicSchemaRegistry::default();
+        register(
+            &mut registry,
+            1,
+            r#"{"type": "object", "required": ["id"]}"#,
+        )
+        .unwrap();
+        register(&mut registry, 2, r#"{"type": "object"}"#).unwrap();
+        assert_eq!(registry.current_version, 2);
+        assert_eq!(registry.latest().unwrap().schema.id, 2);
+        assert_eq!(registry.get(Some(1)).unwrap().schema.id, 1);
+        assert_eq!(registry.get_by_id(2).unwrap().schema.version, 2);
+    }
+
+    #[test]
+    fn incompatible_version_should_be_rejected() {
+        let mut registry = TopicSchemaRegistry::default();
+        register(&mut registry, 1, r#"{"type": "object"}"#).unwrap();
+        let result = register(
+            &mut registry,
+            2,
+            r#"{"type": "object", "required": ["id"]}"#,
+        );
+        assert!(matches!(result, Err(IggyError::IncompatibleSchema(1, _))));
+
+        registry.compatibility = SchemaCompatibility::None;
+        register(
+            &mut registry,
+            2,
+            r#"{"type": "object", "required": ["id"]}"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn messages_should_be_validated_against_referenced_schema() {
+        let mut registry = TopicSchemaRegistry {
+            validate_payloads: true,
+            ..Default::default()
+        };
+        register(
+            &mut registry,
+            7,
+            r#"{"type": "object", "required": ["id"]}"#,
+        )
+        .unwrap();
+        let message = |payload: &str, schema_id: Option<u32>| {
+            let headers = schema_id.map(|schema_id| {
+                let (key, value) = iggy::models::schema::schema_id_header(schema_id).unwrap();
+                std::collections::HashMap::from([(key, value)])
+            });
+            Message::new(Some(1), bytes::Bytes::from(payload.to_string()), headers)
+        };
+
+        assert!(registry
+            .validate_messages(1, 1, &[message(r#"{"id": 1}"#, Some(7))])
+            .is_ok());
+        assert!(matches!(
+            registry.validate_messages(1, 1, &[message(r#"{}"#, Some(7))]),
+            Err(IggyError::InvalidMessagePayload(1, 7, _))
+        ));
+        assert!(matches!(
+            registry.validate_messages(1, 1, &[message(r#"{"id": 1}"#, None)]),
+            Err(IggyError::MissingMessageSchemaId(1))
+        ));
+        assert!(matches!(
+            registry.validate_messages(1, 1, &[message(r#"{"id": 1}"#, Some(8))]),
+            Err(IggyError::SchemaNotRegisteredForTopic(8, 1, 1))
+        ));
+
+        registry.validate_payloads = false;
+        assert!(registry
+            .validate_messages(1, 1, &[message(r#"{}"#, None)])
+            .is_ok());
+    }
+
+    #[test]
+    fn deleted_version_should_not_be_reused() {
+        let mut registry = TopicSchemaRegistry::default();
+        register(&mut registry, 1, r#"{"type": "object"}"#).unwrap();
+        registry.delete(1);
+        assert!(registry.is_empty());
+        register(&mut registry, 2, r#"{"type": "object"}"#).unwrap();
+        assert_eq!(registry.latest().unwrap().schema.version, 2);
+    }
+}
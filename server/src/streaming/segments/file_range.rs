@@ -0,0 +1,117 @@
+use crate::streaming::segments::segment::Segment;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use std::fs::File;
+use std::sync::Arc;
+use tracing::trace;
+
+const COMPONENT: &str = "STREAMING_SEGMENT";
+
+/// The contiguous range of the log file holding the message batches, as they're stored on the disk.
+/// It can be sent directly from the file, but the first and the last batches might contain the messages
+/// outside the requested offsets, so `start_offset` and `end_offset` have to be sent along with it.
+#[derive(Debug, Clone)]
+pub struct SegmentFileRange {
+    pub file: Arc<File>,
+    pub position: u64,
+    pub length: u64,
+    pub start_offset: u64,
+    pub end_offset: u64,
+}
+
+impl SegmentFileRange {
+    pub fn messages_count(&self) -> u32 {
+        (self.end_offset - self.start_offset + 1) as u32
+    }
+}
+
+impl Segment {
+    /// Returns the range of the log file holding the messages with the given offsets, if they're already saved.
+    /// The end offset is lowered to the last message saved in this segment, while the messages which are not
+    /// saved yet (or not indexed) make the range unavailable, so they can be loaded the regular way.
+    pub async fn get_file_range_by_offset(
+        &self,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<Option<SegmentFileRange>, IggyError> {
+        let Some(log_reader) = self.log_reader.as_ref() else {
+            return Ok(None);
+        };
+
+        let start_offset = std::cmp::max(start_offset, self.start_offset);
+        let mut end_offset = std::cmp::min(end_offset, self.current_offset);
+        if let Some(batch_accumulator) = &self.unsaved_messages {
+            if !batch_accumulator.is_empty() {
+                let first_buffer_offset = batch_accumulator.batch_base_offset();
+                if start_offset >= first_buffer_offset {
+                    return Ok(None);
+                }
+                end_offset = std::cmp::min(end_offset, first_buffer_offset - 1);
+            }
+        }
+
+        if start_offset > end_offset {
+            return Ok(None);
+        }
+
+        let relative_start_offset = (start_offset - self.start_offset) as u32;
+        let relative_end_offset = (end_offset - self.start_offset) as u32;
+        let index_range = match &self.indexes {
+            Some(indexes) => match self.load_highest_lower_bound_index(
+                indexes,
+                relative_start_offset,
+                relative_end_offset,
+            ) {
+                Ok(index_range) => index_range,
+                Err(_) => return Ok(None),
+            },
+            None => match self
+                .index_reader
+                .as_ref()
+                .unwrap()
+                .load_index_range_impl(start_offset, end_offset, self.start_offset)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to load index range, start offset: {start_offset}, end offset: {end_offset} for {self}")
+                })? {
+                Some(index_range) => index_range,
+                None => return Ok(None),
+            },
+        };
+
+        // The index holds the last offset of each batch, so the last indexed batch has to contain the start offset.
+        if index_range.end.offset < relative_start_offset
+            || index_range.start.position > index_range.end.position
+        {
+            return Ok(None);
+        }
+
+        let end_offset = std::cmp::min(
+            end_offset,
+            self.start_offset + index_range.end.offset as u64,
+        );
+        let Some(end_position) = log_reader
+            .load_batch_end_position(index_range.end.position as u64)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to load batch end position for {self}"
+                )
+            })?
+        else {
+            return Ok(None);
+        };
+
+        let position = index_range.start.position as u64;
+        trace!(
+            "Found file range at position: {position}, end position: {end_position} for offsets: {start_offset} - {end_offset} in {self}."
+        );
+        Ok(Some(SegmentFileRange {
+            file: log_reader.file(),
+            position,
+            length: end_position - position,
+            start_offset,
+            end_offset,
+        }))
+    }
+}
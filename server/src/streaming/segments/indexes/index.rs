@@ -80,7 +80,7 @@ impl IndexRange {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::configs::system::{SegmentConfig, SystemConfig};
+    use crate::configs::system::{IndexCacheMode, SegmentConfig, SystemConfig};
     use iggy::utils::expiry::IggyExpiry;
     use std::sync::atomic::AtomicU64;
     use std::sync::Arc;
@@ -92,7 +92,7 @@ mod tests {
         let start_offset = 0;
         let config = Arc::new(SystemConfig {
             segment: SegmentConfig {
-                cache_indexes: true,
+                cache_indexes: IndexCacheMode::Eager,
                 ..Default::default()
             },
             ..Default::default()
@@ -4,29 +4,37 @@ use super::{
 };
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use memmap2::Mmap;
 use std::{
     fs::{File, OpenOptions},
     io::ErrorKind,
     os::unix::fs::FileExt,
     sync::{
         atomic::{AtomicU64, Ordering},
-        Arc,
+        Arc, Mutex,
     },
 };
 use tokio::task::spawn_blocking;
 use tracing::{error, trace};
 
 /// A dedicated struct for reading from the index file.
+/// The indexes are either read from the file with the positioned reads or, if `mmap` is enabled,
+/// from the memory map of the file, in which case the OS decides which pages are kept in memory.
 #[derive(Debug)]
 pub struct SegmentIndexReader {
     file_path: String,
     file: Arc<File>,
     index_size_bytes: Arc<AtomicU64>,
+    mmap: Option<Mutex<Option<Arc<Mmap>>>>,
 }
 
 impl SegmentIndexReader {
-    /// Opens the index file in read-only mode.
-    pub async fn new(file_path: &str, index_size_bytes: Arc<AtomicU64>) -> Result<Self, IggyError> {
+    /// Opens the index file in read-only mode, the file is mapped lazily on the first read if `mmap` is enabled.
+    pub async fn new(
+        file_path: &str,
+        index_size_bytes: Arc<AtomicU64>,
+        mmap: bool,
+    ) -> Result<Self, IggyError> {
         let file = OpenOptions::new()
             .read(true)
             .open(file_path)
@@ -48,6 +56,7 @@ impl SegmentIndexReader {
             file_path: file_path.to_string(),
             file: Arc::new(file),
             index_size_bytes,
+            mmap: mmap.then(|| Mutex::new(None)),
         })
     }
 
@@ -93,6 +102,7 @@ impl SegmentIndexReader {
     }
 
     /// Loads an index range from the index file given a start/end offset.
+    /// The indexes are sorted by the offset, so only `O(log n)` of them are read by the binary search.
     pub async fn load_index_range_impl(
         &self,
         index_start_offset: u64,
@@ -115,73 +125,106 @@ impl SegmentIndexReader {
         }
         trace!("Index file length: {} bytes.", file_size);
 
+        let count = file_size / INDEX_SIZE;
         let relative_start_offset = (index_start_offset - segment_start_offset) as u32;
         let relative_end_offset = (index_end_offset - segment_start_offset) as u32;
-        let mut index_range = IndexRange::default();
+        let start = self.search_index(relative_start_offset, 0, count).await?;
+        if start == count {
+            trace!(
+                "Index for start offset {} not found in file {}.",
+                index_start_offset,
+                self.file_path
+            );
+            return Ok(None);
+        }
 
-        let buf = match self.read_at(0, file_size).await {
-            Ok(buf) => buf,
-            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
-            Err(error) => {
-                error!(
-                    "Error reading batch header at offset 0 in file {}: {error}",
-                    self.file_path
-                );
-                return Err(IggyError::CannotReadFile);
-            }
-        };
-        let mut last_index = Index::default();
-        for chunk in buf.chunks_exact(INDEX_SIZE as usize) {
-            let current_index = parse_index(chunk).with_error_context(|error| {
-                format!("Failed to parse index {}: {error}", self.file_path)
-            })?;
-            if current_index.offset >= relative_start_offset
-                && index_range.start == Index::default()
-            {
-                index_range.start = current_index;
-            }
-            if current_index.offset >= relative_end_offset {
-                index_range.end = current_index;
-                break;
+        let end = self
+            .search_index(relative_end_offset, start, count)
+            .await?
+            .min(count - 1);
+        Ok(Some(IndexRange {
+            start: self.read_index(start).await?,
+            end: self.read_index(end).await?,
+        }))
+    }
+
+    /// Returns the number of the first index with the offset not lower than the given one, within `[low, high)`.
+    async fn search_index(
+        &self,
+        offset: u32,
+        mut low: u64,
+        mut high: u64,
+    ) -> Result<u64, IggyError> {
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if self.read_index(middle).await?.offset < offset {
+                low = middle + 1;
+            } else {
+                high = middle;
             }
-            last_index = current_index;
-        }
-        if index_range.end == Index::default() {
-            index_range.end = last_index;
         }
-        Ok(Some(index_range))
+        Ok(low)
     }
 
-    pub async fn load_index_for_timestamp_impl(
-        &self,
-        timestamp: u64,
-    ) -> Result<Option<Index>, IggyError> {
-        let file_size = self.file_size();
-        if file_size == 0 {
-            trace!("Index file {} is empty.", self.file_path);
-            return Ok(Some(Index::default()));
+    async fn read_index(&self, number: u64) -> Result<Index, IggyError> {
+        if let Some(mmap) = &self.mmap {
+            let position = (number * INDEX_SIZE) as usize;
+            let mmap = self.map(mmap, position as u64 + INDEX_SIZE)?;
+            return parse_index(&mmap[position..position + INDEX_SIZE as usize])
+                .with_error_context(|error| {
+                    format!("Failed to parse index {}: {error}", self.file_path)
+                });
         }
 
-        let buf = match self.read_at(0, file_size).await {
-            Ok(buf) => buf,
-            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
-            Err(error) => {
-                error!(
-                    "Error reading batch header at offset 0 in file {}: {error}",
+        let buf = self
+            .read_at(number * INDEX_SIZE, INDEX_SIZE)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to read index {number} from file {}: {error}",
                     self.file_path
-                );
-                return Err(IggyError::CannotReadFile);
-            }
-        };
-        let mut last_index: Option<Index> = None;
-        for chunk in buf.chunks_exact(INDEX_SIZE as usize) {
-            let current = parse_index(chunk)?;
-            if current.timestamp >= timestamp {
-                return Ok(Some(last_index.unwrap_or_default()));
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        parse_index(&buf).with_error_context(|error| {
+            format!("Failed to parse index {}: {error}", self.file_path)
+        })
+    }
+
+    /// Returns the memory map covering at least `len` bytes of the index file. As the indexes of the active segment
+    /// are appended to the file, it's mapped again once the indexes stored after the previous mapping are read.
+    fn map(&self, mmap: &Mutex<Option<Arc<Mmap>>>, len: u64) -> Result<Arc<Mmap>, IggyError> {
+        let mut mmap = mmap.lock().unwrap();
+        if let Some(mapped) = mmap.as_ref() {
+            if mapped.len() as u64 >= len {
+                return Ok(mapped.clone());
             }
-            last_index = Some(current);
         }
-        Ok(None)
+
+        // SAFETY: the index file is only appended to, it's never truncated while the segment is open for reading
+        // (the rebuilt or re-encrypted indexes are written to the new file, which replaces the mapped one).
+        let mapped = unsafe { Mmap::map(self.file.as_ref()) }
+            .with_error_context(|error| {
+                format!("Failed to map index file: {}. {error}", self.file_path)
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        if (mapped.len() as u64) < len {
+            error!(
+                "Mapped {} bytes of index file {}, expected at least {len}.",
+                mapped.len(),
+                self.file_path
+            );
+            return Err(IggyError::CannotReadFile);
+        }
+
+        let mapped = Arc::new(mapped);
+        *mmap = Some(mapped.clone());
+        trace!(
+            "Mapped {} bytes of index file {}.",
+            mapped.len(),
+            self.file_path
+        );
+        Ok(mapped)
     }
 
     fn file_size(&self) -> u64 {
@@ -1,11 +1,21 @@
 mod index;
 mod index_reader;
 mod index_writer;
+mod time_index;
+mod time_index_reader;
+mod time_index_writer;
 
 /// offset: 4 bytes, position: 4 bytes, timestamp: 8 bytes
 pub const INDEX_SIZE: u64 = 16;
 
+/// timestamp: 8 bytes, position: 4 bytes
+pub const TIME_INDEX_SIZE: u64 = 12;
+
 pub use index::Index;
 pub use index::IndexRange;
 pub use index_reader::SegmentIndexReader;
 pub use index_writer::SegmentIndexWriter;
+pub use time_index::SparseTimeIndexer;
+pub use time_index::TimeIndex;
+pub use time_index_reader::SegmentTimeIndexReader;
+pub use time_index_writer::SegmentTimeIndexWriter;
@@ -0,0 +1,196 @@
+use super::{Index, SegmentTimeIndexWriter, TIME_INDEX_SIZE};
+use crate::streaming::segments::segment::Segment;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use tracing::{trace, warn};
+
+/// The entry of the sparse time index, pointing to the position of the batch in the log file.
+/// All the messages stored before the position have the timestamp not greater than `timestamp`,
+/// so the messages with the greater timestamps can be found by reading the log from this position.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub struct TimeIndex {
+    pub timestamp: u64,
+    pub position: u32,
+}
+
+impl TimeIndex {
+    pub fn to_bytes(self) -> [u8; TIME_INDEX_SIZE as usize] {
+        let mut buf = [0u8; TIME_INDEX_SIZE as usize];
+        buf[0..8].copy_from_slice(&self.timestamp.to_le_bytes());
+        buf[8..12].copy_from_slice(&self.position.to_le_bytes());
+        buf
+    }
+}
+
+/// Decides which batches are added to the time index, so that the entries are at least `interval` bytes apart.
+/// The first batch of the segment is always indexed, and the timestamps of the entries never decrease,
+/// as each of them is the maximum timestamp of all the previous batches.
+#[derive(Debug, Clone, Copy)]
+pub struct SparseTimeIndexer {
+    interval: u64,
+    last_position: Option<u32>,
+    max_timestamp: u64,
+}
+
+impl SparseTimeIndexer {
+    pub fn new(interval: u64) -> Self {
+        Self {
+            interval,
+            last_position: None,
+            max_timestamp: 0,
+        }
+    }
+
+    pub fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    /// Returns the time index entry for the batch described by the given offset index, if it has to be stored.
+    pub fn next(&mut self, index: &Index) -> Option<TimeIndex> {
+        let entry = match self.last_position {
+            Some(last_position) if ((index.position - last_position) as u64) < self.interval => {
+                None
+            }
+            _ => {
+                self.last_position = Some(index.position);
+                Some(TimeIndex {
+                    timestamp: self.max_timestamp,
+                    position: index.position,
+                })
+            }
+        };
+        self.max_timestamp = self.max_timestamp.max(index.timestamp);
+        entry
+    }
+}
+
+impl Segment {
+    /// Makes sure that the time index matches the offset indexes of the segment, so it's rebuilt
+    /// if it's missing (e.g. for the segments created by the previous versions), truncated or
+    /// created with a different interval.
+    pub(crate) async fn load_time_index(&mut self, indexes: &[Index]) -> Result<(), IggyError> {
+        let mut indexer = SparseTimeIndexer::new(self.get_time_index_interval());
+        let expected = indexes
+            .iter()
+            .filter_map(|index| indexer.next(index))
+            .flat_map(|entry| entry.to_bytes())
+            .collect::<Vec<_>>();
+        let actual = tokio::fs::read(&self.time_index_path)
+            .await
+            .unwrap_or_default();
+        if actual == expected {
+            trace!(
+                "Loaded {} time indexes for {self}.",
+                expected.len() as u64 / TIME_INDEX_SIZE
+            );
+            if let Some(time_index_writer) = self.time_index_writer.as_mut() {
+                time_index_writer.resume(indexer);
+            }
+            return Ok(());
+        }
+
+        warn!(
+            "Time index at path {} doesn't match the index, rebuilding it...",
+            self.time_index_path
+        );
+        self.rebuild_time_index(indexes).await
+    }
+
+    /// Overwrites the time index with the entries built from the given offset indexes.
+    pub(crate) async fn rebuild_time_index(&mut self, indexes: &[Index]) -> Result<(), IggyError> {
+        let interval = self.get_time_index_interval();
+        if let Some(time_index_writer) = self.time_index_writer.as_mut() {
+            return time_index_writer
+                .rebuild(indexes)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "Failed to rebuild time index: {}. {error}",
+                        self.time_index_path
+                    )
+                });
+        }
+
+        // The closed and offloaded segments don't keep the writers open.
+        let mut time_index_writer = SegmentTimeIndexWriter::new(
+            &self.time_index_path,
+            self.time_index_size_bytes.clone(),
+            false,
+            interval,
+        )
+        .await?;
+        time_index_writer
+            .rebuild(indexes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to rebuild time index: {}. {error}",
+                    self.time_index_path
+                )
+            })?;
+        time_index_writer.fsync().await
+    }
+
+    pub(crate) fn get_time_index_interval(&self) -> u64 {
+        self.config.segment.time_index_interval.as_bytes_u64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(position: u32, timestamp: u64) -> Index {
+        Index {
+            offset: position,
+            position,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn should_index_batches_at_least_interval_bytes_apart() {
+        let mut indexer = SparseTimeIndexer::new(100);
+        let entries = [
+            index(0, 10),
+            index(40, 20),
+            index(90, 30),
+            index(130, 40),
+            index(200, 50),
+            index(240, 60),
+        ]
+        .iter()
+        .filter_map(|index| indexer.next(index))
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            entries,
+            vec![
+                TimeIndex {
+                    timestamp: 0,
+                    position: 0
+                },
+                TimeIndex {
+                    timestamp: 30,
+                    position: 130
+                },
+                TimeIndex {
+                    timestamp: 50,
+                    position: 240
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_keep_maximum_timestamp_of_previous_batches() {
+        let mut indexer = SparseTimeIndexer::new(0);
+        let entries = [index(0, 50), index(10, 40), index(20, 60), index(30, 70)]
+            .iter()
+            .filter_map(|index| indexer.next(index))
+            .map(|entry| entry.timestamp)
+            .collect::<Vec<_>>();
+
+        assert_eq!(entries, vec![0, 50, 50, 60]);
+    }
+}
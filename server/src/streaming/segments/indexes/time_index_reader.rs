@@ -0,0 +1,118 @@
+use super::{TimeIndex, TIME_INDEX_SIZE};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use std::{
+    fs::{File, OpenOptions},
+    os::unix::fs::FileExt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::task::spawn_blocking;
+use tracing::trace;
+
+/// A dedicated struct for reading from the sparse time index file.
+#[derive(Debug)]
+pub struct SegmentTimeIndexReader {
+    file_path: String,
+    file: Arc<File>,
+    time_index_size_bytes: Arc<AtomicU64>,
+}
+
+impl SegmentTimeIndexReader {
+    /// Opens the time index file in read-only mode.
+    pub async fn new(
+        file_path: &str,
+        time_index_size_bytes: Arc<AtomicU64>,
+    ) -> Result<Self, IggyError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(file_path)
+            .with_error_context(|error| {
+                format!("Failed to open time index file: {file_path}. {error}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+
+        let actual_time_index_size = file
+            .metadata()
+            .with_error_context(|error| {
+                format!("Failed to get metadata of time index file: {file_path}. {error}")
+            })
+            .map_err(|_| IggyError::CannotReadFileMetadata)?
+            .len();
+
+        time_index_size_bytes.store(actual_time_index_size, Ordering::Release);
+
+        trace!("Opened time index file for reading: {file_path}, size: {actual_time_index_size}");
+        Ok(Self {
+            file_path: file_path.to_string(),
+            file: Arc::new(file),
+            time_index_size_bytes,
+        })
+    }
+
+    /// Finds the last time index record before the first message with the timestamp not lower than the given one,
+    /// by the binary search over the records read from the file, so only `O(log n)` records are read.
+    pub async fn load_time_index_for_timestamp_impl(
+        &self,
+        timestamp: u64,
+    ) -> Result<Option<TimeIndex>, IggyError> {
+        let count = self.time_index_size_bytes.load(Ordering::Acquire) / TIME_INDEX_SIZE;
+        if count == 0 {
+            trace!("Time index file {} is empty.", self.file_path);
+            return Ok(None);
+        }
+
+        // The timestamps of the records never decrease, so the search looks for the first one not lower than the given timestamp.
+        let (mut low, mut high) = (0, count);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if self.read_time_index(middle).await?.timestamp < timestamp {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        // The first record always points to the beginning of the log.
+        let time_index = self.read_time_index(low.saturating_sub(1)).await?;
+        trace!(
+            "Found time index: {time_index:?} for timestamp: {timestamp} in file {}.",
+            self.file_path
+        );
+        Ok(Some(time_index))
+    }
+
+    async fn read_time_index(&self, index: u64) -> Result<TimeIndex, IggyError> {
+        let file = self.file.clone();
+        let buf = spawn_blocking(move || {
+            let mut buf = [0u8; TIME_INDEX_SIZE as usize];
+            file.read_exact_at(&mut buf, index * TIME_INDEX_SIZE)
+                .map(|_| buf)
+        })
+        .await
+        .with_error_context(|error| {
+            format!(
+                "Failed to join time index read from file {}: {error}",
+                self.file_path
+            )
+        })
+        .map_err(|_| IggyError::CannotReadFile)?
+        .with_error_context(|error| {
+            format!(
+                "Failed to read time index {index} from file {}: {error}",
+                self.file_path
+            )
+        })
+        .map_err(|_| IggyError::CannotReadFile)?;
+        Ok(parse_time_index(&buf))
+    }
+}
+
+fn parse_time_index(chunk: &[u8; TIME_INDEX_SIZE as usize]) -> TimeIndex {
+    TimeIndex {
+        timestamp: u64::from_le_bytes(chunk[0..8].try_into().unwrap()),
+        position: u32::from_le_bytes(chunk[8..12].try_into().unwrap()),
+    }
+}
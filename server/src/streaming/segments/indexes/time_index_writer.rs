@@ -0,0 +1,147 @@
+use super::{Index, SparseTimeIndexer, TIME_INDEX_SIZE};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+use tokio::{
+    fs::{File, OpenOptions},
+    io::AsyncWriteExt,
+};
+use tracing::trace;
+
+/// A dedicated struct for writing to the sparse time index file.
+#[derive(Debug)]
+pub struct SegmentTimeIndexWriter {
+    file_path: String,
+    file: File,
+    time_index_size_bytes: Arc<AtomicU64>,
+    fsync: bool,
+    indexer: SparseTimeIndexer,
+}
+
+impl SegmentTimeIndexWriter {
+    /// Opens the time index file in write mode.
+    pub async fn new(
+        file_path: &str,
+        time_index_size_bytes: Arc<AtomicU64>,
+        fsync: bool,
+        interval: u64,
+    ) -> Result<Self, IggyError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .append(true)
+            .create(true)
+            .open(file_path)
+            .await
+            .with_error_context(|error| {
+                format!("Failed to open time index file: {file_path}. {error}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+
+        let actual_time_index_size = file
+            .metadata()
+            .await
+            .with_error_context(|error| {
+                format!("Failed to get metadata of time index file: {file_path}. {error}")
+            })
+            .map_err(|_| IggyError::CannotReadFileMetadata)?
+            .len();
+
+        time_index_size_bytes.store(actual_time_index_size, Ordering::Release);
+
+        trace!("Opened time index file for writing: {file_path}, size: {actual_time_index_size}");
+
+        Ok(Self {
+            file_path: file_path.to_string(),
+            file,
+            time_index_size_bytes,
+            fsync,
+            indexer: SparseTimeIndexer::new(interval),
+        })
+    }
+
+    /// Continues indexing after the batches already stored in the time index file.
+    pub fn resume(&mut self, indexer: SparseTimeIndexer) {
+        self.indexer = indexer;
+    }
+
+    /// Appends the time index record to the time index file, if the saved batch has to be indexed.
+    pub async fn save_index(&mut self, index: &Index) -> Result<(), IggyError> {
+        let Some(time_index) = self.indexer.next(index) else {
+            return Ok(());
+        };
+
+        self.file
+            .write_all(&time_index.to_bytes())
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to write time index to file: {}. {error}",
+                    self.file_path
+                )
+            })
+            .map_err(|_| IggyError::CannotSaveIndexToSegment)?;
+        if self.fsync {
+            let _ = self.fsync().await;
+        }
+        self.time_index_size_bytes
+            .fetch_add(TIME_INDEX_SIZE, Ordering::Release);
+        Ok(())
+    }
+
+    /// Replaces the content of the time index file with the records of the given batches.
+    pub async fn rebuild(&mut self, indexes: &[Index]) -> Result<(), IggyError> {
+        self.indexer = SparseTimeIndexer::new(self.indexer.interval());
+        let buf = indexes
+            .iter()
+            .filter_map(|index| self.indexer.next(index))
+            .flat_map(|time_index| time_index.to_bytes())
+            .collect::<Vec<_>>();
+
+        self.file
+            .set_len(0)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to truncate time index file: {}. {error}",
+                    self.file_path
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        self.time_index_size_bytes.store(0, Ordering::Release);
+        self.file
+            .write_all(&buf)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to write time indexes to file: {}. {error}",
+                    self.file_path
+                )
+            })
+            .map_err(|_| IggyError::CannotSaveIndexToSegment)?;
+        self.time_index_size_bytes
+            .store(buf.len() as u64, Ordering::Release);
+        trace!(
+            "Rebuilt time index file: {} with {} records.",
+            self.file_path,
+            buf.len() as u64 / TIME_INDEX_SIZE
+        );
+        Ok(())
+    }
+
+    pub async fn fsync(&self) -> Result<(), IggyError> {
+        self.file
+            .sync_all()
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to fsync time index file: {}. {error}",
+                    self.file_path
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        Ok(())
+    }
+}
@@ -1,13 +1,13 @@
 use crate::streaming::{
     batching::{
         iterator::IntoMessagesIterator,
-        message_batch::{RetainedMessageBatch, RETAINED_BATCH_HEADER_LEN},
+        message_batch::{RetainedBatchHeader, RetainedMessageBatch, RETAINED_BATCH_HEADER_LEN},
     },
     segments::indexes::IndexRange,
 };
-use bytes::BytesMut;
+use bytes::Bytes;
 use error_set::ErrContext;
-use iggy::{error::IggyError, utils::byte_size::IggyByteSize};
+use iggy::error::IggyError;
 use std::{
     fs::{File, OpenOptions},
     os::unix::prelude::FileExt,
@@ -91,13 +91,13 @@ impl SegmentLogReader {
             file_size = self.file_size();
             match self.read_next_batch(offset, file_size).await? {
                 Some((batch, bytes_read)) => {
-                    offset += bytes_read;
-                    let last_offset_in_batch = batch.base_offset + batch.last_offset_delta as u64;
-
-                    if last_offset_in_batch >= index_range.end.offset as u64 || offset >= file_size
+                    // The offsets of the indexes are relative to the segment, so the end of the range is determined
+                    // by the position of the last batch instead, as it can be compared with the absolute batch offsets.
+                    if offset >= index_range.end.position as u64 || offset + bytes_read >= file_size
                     {
                         last_batch_to_read = true;
                     }
+                    offset += bytes_read;
                     batches.push(batch);
                 }
                 None => {
@@ -163,12 +163,11 @@ impl SegmentLogReader {
             file_size = self.file_size();
             match self.read_next_batch(offset, file_size).await? {
                 Some((batch, bytes_read)) => {
-                    offset += bytes_read;
-                    let last_offset_in_batch = batch.base_offset + batch.last_offset_delta as u64;
-                    if offset >= file_size || last_offset_in_batch >= index_range.end.offset as u64
+                    if offset >= index_range.end.position as u64 || offset + bytes_read >= file_size
                     {
                         last_batch_to_read = true;
                     }
+                    offset += bytes_read;
                     on_batch(batch)?;
                 }
                 None => {
@@ -216,6 +215,50 @@ impl SegmentLogReader {
         Ok(())
     }
 
+    /// Returns the log file, so the stored batches can be sent directly from it.
+    pub fn file(&self) -> Arc<File> {
+        self.file.clone()
+    }
+
+    /// Loads the header of the batch at the given position and returns the position right after the batch,
+    /// or `None` if the whole batch is not stored in the file yet.
+    pub async fn load_batch_end_position(&self, position: u64) -> Result<Option<u64>, IggyError> {
+        let file_size = self.file_size();
+        if position + RETAINED_BATCH_HEADER_LEN > file_size {
+            return Ok(None);
+        }
+
+        let header_buf = match self.read_at(position, RETAINED_BATCH_HEADER_LEN).await {
+            Ok(buf) => buf,
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(error) => {
+                error!(
+                    "Error reading batch header at offset {} in file {}: {error}",
+                    position, self.file_path
+                );
+                return Err(IggyError::CannotReadBatchLength);
+            }
+        };
+
+        let batch_length = u32::from_le_bytes(
+            header_buf[8..12]
+                .try_into()
+                .with_error_context(|error| {
+                    format!(
+                        "Failed to parse batch length at offset {position} in file {}: {error}",
+                        self.file_path
+                    )
+                })
+                .map_err(|_| IggyError::CannotReadBatchLength)?,
+        );
+        let end_position = position + RETAINED_BATCH_HEADER_LEN + batch_length as u64;
+        if end_position > file_size {
+            return Ok(None);
+        }
+
+        Ok(Some(end_position))
+    }
+
     async fn read_next_batch(
         &self,
         offset: u64,
@@ -245,52 +288,14 @@ impl SegmentLogReader {
             return Ok(None);
         }
 
-        let batch_base_offset = u64::from_le_bytes(
-            header_buf[0..8]
-                .try_into()
-                .with_error_context(|error| {
-                    format!(
-                        "Failed to parse batch base offset at offset {offset} in file {}: {error}",
-                        self.file_path
-                    )
-                })
-                .map_err(|_| IggyError::CannotReadBatchBaseOffset)?,
-        );
-        let batch_length = u32::from_le_bytes(
-            header_buf[8..12]
-                .try_into()
-                .with_error_context(|error| {
-                    format!(
-                        "Failed to parse batch length at offset {offset} in file {}: {error}",
-                        self.file_path
-                    )
-                })
-                .map_err(|_| IggyError::CannotReadBatchLength)?,
-        );
-        let last_offset_delta = u32::from_le_bytes(
-            header_buf[12..16]
-                .try_into()
-                .with_error_context(|error| {
-                    format!(
-                        "Failed to parse last offset delta at offset {offset} in file {}: {error}",
-                        self.file_path
-                    )
-                })
-                .map_err(|_| IggyError::CannotReadLastOffsetDelta)?,
-        );
-        let max_timestamp = u64::from_le_bytes(
-            header_buf[16..24]
-                .try_into()
-                .with_error_context(|error| {
-                    format!(
-                        "Failed to parse max timestamp at offset {offset} in file {}: {error}",
-                        self.file_path
-                    )
-                })
-                .map_err(|_| IggyError::CannotReadMaxTimestamp)?,
-        );
+        let header = RetainedBatchHeader::from_bytes(&header_buf).with_error_context(|error| {
+            format!(
+                "Failed to parse batch header at offset {offset} in file {}: {error}",
+                self.file_path
+            )
+        })?;
 
-        let payload_len = batch_length as usize;
+        let payload_len = header.length as usize;
         let payload_offset = offset + batch_header_size;
         if payload_offset + payload_len as u64 > file_size {
             warn!(
@@ -312,14 +317,17 @@ impl SegmentLogReader {
             }
         };
 
+        header
+            .verify_crc(&header_buf, &payload_buf)
+            .with_error_context(|error| {
+                format!(
+                    "Failed to verify batch at offset {offset} in file {}: {error}",
+                    self.file_path
+                )
+            })?;
+
         let bytes_read = batch_header_size + payload_len as u64;
-        let batch = RetainedMessageBatch::new(
-            batch_base_offset,
-            last_offset_delta,
-            max_timestamp,
-            IggyByteSize::from(payload_len as u64),
-            BytesMut::from(&payload_buf[..]).freeze(),
-        );
+        let batch = RetainedMessageBatch::from_header(&header, Bytes::from(payload_buf));
 
         Ok(Some((batch, bytes_read)))
     }
@@ -1,4 +1,7 @@
+#[cfg(target_os = "linux")]
+use super::uring_writer::UringLogWriter;
 use super::PersisterTask;
+use crate::configs::system::IoUringConfig;
 use crate::streaming::batching::message_batch::RetainedMessageBatch;
 use error_set::ErrContext;
 use iggy::{
@@ -27,6 +30,9 @@ pub struct SegmentLogWriter {
     file: Option<File>,
     /// When set, asynchronous writes are handled by this persister task.
     persister_task: Option<PersisterTask>,
+    /// When set, all the writes are submitted through io_uring instead of the file and the persister task.
+    #[cfg(target_os = "linux")]
+    uring_writer: Option<UringLogWriter>,
     log_size_bytes: Arc<AtomicU64>,
     fsync: bool,
 }
@@ -37,6 +43,8 @@ impl SegmentLogWriter {
     /// If the server confirmation is set to `NoWait`, the file handle is transferred to the
     /// persister task (and stored in `persister_task`) so that writes are done asynchronously.
     /// Otherwise, the file is retained in `self.file` for synchronous writes.
+    ///
+    /// If the io_uring config is provided, the log is written through io_uring for both confirmations.
     pub async fn new(
         file_path: &str,
         log_size_bytes: Arc<AtomicU64>,
@@ -44,7 +52,24 @@ impl SegmentLogWriter {
         server_confirmation: Confirmation,
         max_file_operation_retries: u32,
         retry_delay: IggyDuration,
+        io_uring: Option<&IoUringConfig>,
     ) -> Result<Self, IggyError> {
+        #[cfg(target_os = "linux")]
+        if let Some(io_uring) = io_uring {
+            let uring_writer =
+                UringLogWriter::new(file_path, log_size_bytes.clone(), fsync, io_uring)?;
+            return Ok(Self {
+                file_path: file_path.to_string(),
+                file: None,
+                persister_task: None,
+                uring_writer: Some(uring_writer),
+                log_size_bytes,
+                fsync,
+            });
+        }
+        #[cfg(not(target_os = "linux"))]
+        let _ = io_uring;
+
         let file = OpenOptions::new()
             .write(true)
             .append(true)
@@ -86,6 +111,8 @@ impl SegmentLogWriter {
             file_path: file_path.to_string(),
             file,
             persister_task,
+            #[cfg(target_os = "linux")]
+            uring_writer: None,
             log_size_bytes,
             fsync,
         })
@@ -98,6 +125,16 @@ impl SegmentLogWriter {
         confirmation: Confirmation,
     ) -> Result<IggyByteSize, IggyError> {
         let batch_size = batch.get_size_bytes();
+        #[cfg(target_os = "linux")]
+        if let Some(uring_writer) = self.uring_writer.as_mut() {
+            uring_writer.write(batch, confirmation).await?;
+            trace!(
+                "Submitted batch of size {batch_size} bytes to io_uring for log file: {}",
+                self.file_path
+            );
+            return Ok(batch_size);
+        }
+
         match confirmation {
             Confirmation::Wait => {
                 self.write_batch(batch).await?;
@@ -148,6 +185,11 @@ impl SegmentLogWriter {
     }
 
     pub async fn fsync(&self) -> Result<(), IggyError> {
+        #[cfg(target_os = "linux")]
+        if let Some(uring_writer) = self.uring_writer.as_ref() {
+            return uring_writer.fsync().await;
+        }
+
         if let Some(file) = self.file.as_ref() {
             file.sync_all()
                 .await
@@ -164,5 +206,9 @@ impl SegmentLogWriter {
         if let Some(task) = self.persister_task {
             task.shutdown().await;
         }
+        #[cfg(target_os = "linux")]
+        if let Some(uring_writer) = self.uring_writer {
+            uring_writer.shutdown().await;
+        }
     }
 }
@@ -1,6 +1,8 @@
 mod log_reader;
 mod log_writer;
 mod persister_task;
+#[cfg(target_os = "linux")]
+mod uring_writer;
 
 pub use log_reader::SegmentLogReader;
 pub use log_writer::SegmentLogWriter;
@@ -0,0 +1,726 @@
+use crate::configs::system::IoUringConfig;
+use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use error_set::ErrContext;
+use iggy::confirmation::Confirmation;
+use iggy::error::IggyError;
+use iggy::utils::duration::IggyDuration;
+use io_uring::{opcode, types, IoUring};
+use nix::libc;
+use std::alloc::{alloc_zeroed, dealloc, Layout};
+use std::collections::{HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::os::fd::{AsRawFd, RawFd};
+use std::os::unix::fs::{FileExt, OpenOptionsExt};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
+use tokio::sync::oneshot;
+use tracing::{error, info, trace, warn};
+
+/// The alignment of the offsets, lengths and memory required by the direct I/O.
+const BLOCK_SIZE: usize = 4096;
+
+static DRIVER: OnceLock<Option<UringDriver>> = OnceLock::new();
+
+/// Writes the segment log through the io_uring driver shared by all the logs.
+///
+/// The writes are positioned rather than appended, so with the direct I/O the last partial block of the log
+/// is kept in memory and rewritten (padded with zeros) by the next write, and the padding is truncated on shutdown.
+/// Once any write has failed (even the one not awaited), the following ones would leave a hole in the log,
+/// so the writer rejects them, and the log is truncated to the last valid batch by the recovery on startup.
+#[derive(Debug)]
+pub struct UringLogWriter {
+    target: Arc<UringTarget>,
+    driver: &'static UringDriver,
+    direct_io: bool,
+    fsync: bool,
+    position: u64,
+    tail: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct UringTarget {
+    file: File,
+    file_path: String,
+    log_size_bytes: Arc<AtomicU64>,
+    failed: AtomicBool,
+}
+
+impl UringLogWriter {
+    pub fn new(
+        file_path: &str,
+        log_size_bytes: Arc<AtomicU64>,
+        fsync: bool,
+        config: &IoUringConfig,
+    ) -> Result<Self, IggyError> {
+        let driver = UringDriver::get_or_start(config)?;
+        let mut options = OpenOptions::new();
+        options.write(true).create(true);
+        if config.direct_io {
+            options.custom_flags(libc::O_DIRECT);
+        }
+        let file = options
+            .open(file_path)
+            .with_error_context(|error| {
+                format!("Failed to open log file: {file_path} for io_uring writes. {error}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let position = file
+            .metadata()
+            .map_err(|_| IggyError::CannotReadFileMetadata)?
+            .len();
+
+        let tail = match config.direct_io {
+            true => Self::read_tail(file_path, position)?,
+            false => Vec::new(),
+        };
+        log_size_bytes.store(position, Ordering::Release);
+        trace!("Opened log file for io_uring writing: {file_path}, size: {position}");
+
+        Ok(Self {
+            target: Arc::new(UringTarget {
+                file,
+                file_path: file_path.to_string(),
+                log_size_bytes,
+                failed: AtomicBool::new(false),
+            }),
+            driver,
+            direct_io: config.direct_io,
+            fsync,
+            position,
+            tail,
+        })
+    }
+
+    /// Submits the batch to the driver. With `Confirmation::Wait`, waits until the batch is written
+    /// (and fsynced, if enabled), otherwise the errors are only logged by the driver.
+    pub async fn write(
+        &mut self,
+        batch: RetainedMessageBatch,
+        confirmation: Confirmation,
+    ) -> Result<(), IggyError> {
+        self.ensure_not_failed()?;
+        let header = batch.header_as_bytes();
+        let size_bytes = (header.len() + batch.bytes.len()) as u64;
+        let (offset, data) = match self.direct_io {
+            true => {
+                let offset = self.position - self.tail.len() as u64;
+                let mut data = Vec::with_capacity(align_up(self.tail.len() + size_bytes as usize));
+                data.extend_from_slice(&self.tail);
+                data.extend_from_slice(&header);
+                data.extend_from_slice(&batch.bytes);
+                let tail_start = data.len() - data.len() % BLOCK_SIZE;
+                self.tail = data[tail_start..].to_vec();
+                data.resize(align_up(data.len()), 0);
+                (offset, data)
+            }
+            false => {
+                let mut data = Vec::with_capacity(size_bytes as usize);
+                data.extend_from_slice(&header);
+                data.extend_from_slice(&batch.bytes);
+                (self.position, data)
+            }
+        };
+        self.position += size_bytes;
+
+        let result = self
+            .driver
+            .submit(
+                WriteRequest {
+                    target: self.target.clone(),
+                    offset,
+                    data,
+                    fsync: self.fsync,
+                    size_bytes,
+                },
+                confirmation,
+            )
+            .await;
+        if result.is_err() {
+            self.target.failed.store(true, Ordering::Release);
+        }
+        result
+    }
+
+    /// Waits until all the submitted writes are completed and fsyncs the log.
+    pub async fn fsync(&self) -> Result<(), IggyError> {
+        self.ensure_not_failed()?;
+        self.driver
+            .submit(
+                WriteRequest {
+                    target: self.target.clone(),
+                    offset: self.position,
+                    data: Vec::new(),
+                    fsync: true,
+                    size_bytes: 0,
+                },
+                Confirmation::Wait,
+            )
+            .await
+    }
+
+    pub async fn shutdown(self) {
+        if let Err(error) = self.fsync().await {
+            error!(
+                "Failed to flush io_uring writes for log file: {}. {error}",
+                self.target.file_path
+            );
+            return;
+        }
+
+        if self.direct_io {
+            if let Err(error) = self.target.file.set_len(self.position) {
+                error!(
+                    "Failed to truncate the direct I/O padding of log file: {}. {error}",
+                    self.target.file_path
+                );
+            }
+        }
+        trace!(
+            "io_uring writer for log file: {} has been shut down.",
+            self.target.file_path
+        );
+    }
+
+    fn ensure_not_failed(&self) -> Result<(), IggyError> {
+        if self.target.failed.load(Ordering::Acquire) {
+            error!(
+                "Cannot write to log file: {} with io_uring, as one of the previous writes has failed.",
+                self.target.file_path
+            );
+            return Err(IggyError::CannotWriteToFile);
+        }
+
+        Ok(())
+    }
+
+    fn read_tail(file_path: &str, position: u64) -> Result<Vec<u8>, IggyError> {
+        let mut tail = vec![0; (position % BLOCK_SIZE as u64) as usize];
+        if tail.is_empty() {
+            return Ok(tail);
+        }
+
+        // The direct I/O reads would require the aligned buffer, so the tail is read through the page cache.
+        let tail_position = position - tail.len() as u64;
+        File::open(file_path)
+            .and_then(|file| file.read_exact_at(&mut tail, tail_position))
+            .with_error_context(|error| {
+                format!("Failed to read the last block of log file: {file_path}. {error}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        Ok(tail)
+    }
+}
+
+#[derive(Debug)]
+struct WriteRequest {
+    target: Arc<UringTarget>,
+    offset: u64,
+    data: Vec<u8>,
+    fsync: bool,
+    size_bytes: u64,
+}
+
+#[derive(Debug)]
+struct Submission {
+    request: WriteRequest,
+    responder: Option<oneshot::Sender<Result<(), IggyError>>>,
+}
+
+/// The writes which have been collected within the group commit interval and are submitted together.
+/// The subsequent writes to the same log are merged, so the rewritten tail block is never written concurrently,
+/// and the log is fsynced once for all of them.
+#[derive(Debug)]
+struct CommitGroup {
+    target: Arc<UringTarget>,
+    offset: u64,
+    data: Vec<u8>,
+    fsync: bool,
+    size_bytes: u64,
+    responders: Vec<oneshot::Sender<Result<(), IggyError>>>,
+    failure: Option<Failure>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Failure {
+    Write,
+    Fsync,
+}
+
+impl Failure {
+    fn as_error(self) -> IggyError {
+        match self {
+            Failure::Write => IggyError::CannotWriteToFile,
+            Failure::Fsync => IggyError::CannotSyncFile,
+        }
+    }
+}
+
+/// The single ring, running on the dedicated thread, which submits the writes of all the logs.
+/// It's started on the first use, with the configuration of the first writer.
+#[derive(Debug)]
+struct UringDriver {
+    sender: flume::Sender<Submission>,
+}
+
+impl UringDriver {
+    fn get_or_start(config: &IoUringConfig) -> Result<&'static UringDriver, IggyError> {
+        DRIVER
+            .get_or_init(|| match Self::start(config) {
+                Ok(driver) => Some(driver),
+                Err(error) => {
+                    error!("Failed to start io_uring driver. {error}");
+                    None
+                }
+            })
+            .as_ref()
+            .ok_or(IggyError::CannotWriteToFile)
+    }
+
+    fn start(config: &IoUringConfig) -> Result<Self, IggyError> {
+        let (sender, receiver) = flume::unbounded();
+        let (init_sender, init_receiver) = std::sync::mpsc::sync_channel(1);
+        let queue_depth = config.queue_depth;
+        let buffers_count = config.registered_buffers as usize;
+        let buffer_size = config.registered_buffer_size.as_bytes_u64() as usize;
+        let group_commit_interval = config.group_commit_interval;
+        std::thread::Builder::new()
+            .name("iggy-io-uring".to_string())
+            .spawn(move || {
+                // The ring and the buffers never leave the driver thread.
+                let ring = match IoUring::new(queue_depth) {
+                    Ok(ring) => ring,
+                    Err(error) => {
+                        error!("Failed to create io_uring with queue depth: {queue_depth}. {error}");
+                        let _ = init_sender.send(false);
+                        return;
+                    }
+                };
+                let mut buffers = AlignedBuffers::new(buffers_count, buffer_size);
+                // The registration might fail due to RLIMIT_MEMLOCK, the buffers can still be used without it.
+                let iovecs = buffers.iovecs();
+                if let Err(error) = unsafe { ring.submitter().register_buffers(&iovecs) } {
+                    warn!("Failed to register io_uring buffers, the unregistered buffers will be used. {error}");
+                } else {
+                    buffers.registered = true;
+                }
+                let _ = init_sender.send(true);
+                Self::run(ring, buffers, receiver, group_commit_interval);
+            })
+            .with_error_context(|error| format!("Failed to spawn io_uring thread. {error}"))
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+
+        match init_receiver.recv() {
+            Ok(true) => {
+                info!(
+                    "Started io_uring driver with queue depth: {}, registered buffers: {} of size: {}, group commit interval: {}, direct I/O: {}.",
+                    config.queue_depth,
+                    config.registered_buffers,
+                    config.registered_buffer_size,
+                    config.group_commit_interval,
+                    config.direct_io
+                );
+                Ok(Self { sender })
+            }
+            _ => Err(IggyError::CannotWriteToFile),
+        }
+    }
+
+    async fn submit(
+        &self,
+        request: WriteRequest,
+        confirmation: Confirmation,
+    ) -> Result<(), IggyError> {
+        let (responder, receiver) = match confirmation {
+            Confirmation::Wait => {
+                let (responder, receiver) = oneshot::channel();
+                (Some(responder), Some(receiver))
+            }
+            Confirmation::NoWait => (None, None),
+        };
+
+        let file_path = request.target.file_path.clone();
+        self.sender
+            .send(Submission { request, responder })
+            .with_error_context(|error| {
+                format!("Failed to submit io_uring write for log file: {file_path}. {error}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+
+        match receiver {
+            Some(receiver) => receiver.await.map_err(|_| IggyError::CannotWriteToFile)?,
+            None => Ok(()),
+        }
+    }
+
+    fn run(
+        mut ring: IoUring,
+        mut buffers: AlignedBuffers,
+        receiver: flume::Receiver<Submission>,
+        group_commit_interval: IggyDuration,
+    ) {
+        let max_submissions = ring.params().sq_entries() as usize;
+        // Once the submission fails, the entries might still be in flight, so neither their buffers
+        // nor their user data can be reused, and all the following writes are failed instead.
+        let mut broken = false;
+        while let Ok(submission) = receiver.recv() {
+            let deadline = Instant::now() + group_commit_interval.get_duration();
+            let mut fsync = submission.request.fsync;
+            let mut submissions = vec![submission];
+            while submissions.len() < max_submissions {
+                // Only the writes which have to be fsynced are worth waiting for.
+                let next = match fsync {
+                    true => receiver.recv_deadline(deadline).ok(),
+                    false => receiver.try_recv().ok(),
+                };
+                let Some(next) = next else {
+                    break;
+                };
+                fsync |= next.request.fsync;
+                submissions.push(next);
+            }
+
+            let mut groups = coalesce(submissions);
+            // The writes submitted before the previous failure of the log was noticed would leave a hole in it.
+            for group in groups
+                .iter_mut()
+                .filter(|group| group.target.failed.load(Ordering::Acquire))
+            {
+                group.failure = Some(Failure::Write);
+            }
+            if !broken {
+                let result = Self::write_groups(&mut ring, &mut buffers, &mut groups)
+                    .and_then(|_| Self::fsync_groups(&mut ring, &mut groups));
+                if let Err(error) = result {
+                    error!("Failed to submit io_uring entries, all the following writes will fail. {error}");
+                    broken = true;
+                }
+            }
+            if broken {
+                for group in groups.iter_mut() {
+                    group.failure.get_or_insert(Failure::Write);
+                }
+            }
+            for group in groups {
+                Self::complete(group);
+            }
+        }
+        trace!("io_uring driver has finished processing requests.");
+    }
+
+    fn write_groups(
+        ring: &mut IoUring,
+        buffers: &mut AlignedBuffers,
+        groups: &mut [CommitGroup],
+    ) -> Result<(), std::io::Error> {
+        // The data is split into the chunks of the buffer size, and submitted in waves limited by the free buffers.
+        let buffer_size = buffers.size;
+        let operations = groups
+            .iter()
+            .enumerate()
+            .filter(|(_, group)| group.failure.is_none())
+            .flat_map(|(index, group)| {
+                (0..group.data.len())
+                    .step_by(buffer_size)
+                    .map(move |start| (index, start, (start + buffer_size).min(group.data.len())))
+            })
+            .collect::<Vec<_>>();
+        let wave_size = buffers.count.min(ring.params().sq_entries() as usize);
+        for wave in operations.chunks(wave_size) {
+            for (slot, &(index, start, end)) in wave.iter().enumerate() {
+                let group = &groups[index];
+                let buffer = buffers.get_mut(slot);
+                buffer[..end - start].copy_from_slice(&group.data[start..end]);
+                let fd = types::Fd(group.target.file.as_raw_fd());
+                let pointer = buffer.as_ptr();
+                let entry = match buffers.registered {
+                    true => opcode::WriteFixed::new(fd, pointer, (end - start) as u32, slot as u16)
+                        .offset(group.offset + start as u64)
+                        .build(),
+                    false => opcode::Write::new(fd, pointer, (end - start) as u32)
+                        .offset(group.offset + start as u64)
+                        .build(),
+                };
+                // The wave never exceeds the capacity of the submission queue.
+                unsafe { ring.submission().push(&entry.user_data(slot as u64)) }
+                    .expect("io_uring submission queue is full");
+            }
+
+            let results = Self::submit_and_collect(ring, wave.len())?;
+            for (slot, &(index, start, end)) in wave.iter().enumerate() {
+                let group = &mut groups[index];
+                let length = end - start;
+                match results.get(&(slot as u64)) {
+                    Some(&written) if written >= 0 && written as usize == length => {}
+                    Some(&written) if written >= 0 => {
+                        // The short writes are rare for the regular files, the rest is written synchronously.
+                        let written = written as usize;
+                        let offset = group.offset + (start + written) as u64;
+                        let remaining = &buffers.get_mut(slot)[written..length];
+                        if let Err(error) = group.target.file.write_all_at(remaining, offset) {
+                            error!(
+                                "Failed to write the rest of the short io_uring write to log file: {}. {error}",
+                                group.target.file_path
+                            );
+                            group.failure = Some(Failure::Write);
+                        }
+                    }
+                    result => {
+                        error!(
+                            "Failed to write to log file: {} with io_uring. {}",
+                            group.target.file_path,
+                            describe_result(result)
+                        );
+                        group.failure = Some(Failure::Write);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn fsync_groups(ring: &mut IoUring, groups: &mut [CommitGroup]) -> Result<(), std::io::Error> {
+        let mut fds = HashSet::new();
+        let fsyncs = groups
+            .iter()
+            .filter(|group| group.fsync && group.failure.is_none())
+            .map(|group| group.target.file.as_raw_fd())
+            .filter(|fd| fds.insert(*fd))
+            .collect::<Vec<RawFd>>();
+        let wave_size = ring.params().sq_entries() as usize;
+        let mut failed = HashSet::new();
+        for wave in fsyncs.chunks(wave_size) {
+            for fd in wave {
+                let entry = opcode::Fsync::new(types::Fd(*fd))
+                    .build()
+                    .user_data(*fd as u64);
+                unsafe { ring.submission().push(&entry) }
+                    .expect("io_uring submission queue is full");
+            }
+
+            let results = match Self::submit_and_collect(ring, wave.len()) {
+                Ok(results) => results,
+                Err(error) => {
+                    for group in groups.iter_mut().filter(|group| group.fsync) {
+                        group.failure.get_or_insert(Failure::Fsync);
+                    }
+                    return Err(error);
+                }
+            };
+            for fd in wave {
+                let result = results.get(&(*fd as u64));
+                if !matches!(result, Some(result) if *result >= 0) {
+                    error!(
+                        "Failed to fsync log file with io_uring. {}",
+                        describe_result(result)
+                    );
+                    failed.insert(*fd);
+                }
+            }
+        }
+
+        for group in groups.iter_mut().filter(|group| group.fsync) {
+            if failed.contains(&group.target.file.as_raw_fd()) {
+                group.failure = Some(Failure::Fsync);
+            }
+        }
+        Ok(())
+    }
+
+    /// Submits the queued entries and waits for all of them, returning the results by the user data.
+    /// The submission rejected only temporarily is retried once the available completions are collected,
+    /// any other error is returned without the partial results, as the remaining entries might be still in flight.
+    fn submit_and_collect(
+        ring: &mut IoUring,
+        count: usize,
+    ) -> Result<HashMap<u64, i32>, std::io::Error> {
+        let mut results = HashMap::with_capacity(count);
+        while results.len() < count {
+            if let Err(error) = ring.submit_and_wait(count - results.len()) {
+                if !matches!(
+                    error.raw_os_error(),
+                    Some(libc::EINTR | libc::EAGAIN | libc::EBUSY)
+                ) {
+                    return Err(error);
+                }
+            }
+            for entry in ring.completion() {
+                results.insert(entry.user_data(), entry.result());
+            }
+        }
+        Ok(results)
+    }
+
+    fn complete(group: CommitGroup) {
+        match group.failure {
+            None => {
+                group
+                    .target
+                    .log_size_bytes
+                    .fetch_add(group.size_bytes, Ordering::AcqRel);
+                for responder in group.responders {
+                    let _ = responder.send(Ok(()));
+                }
+            }
+            Some(failure) => {
+                group.target.failed.store(true, Ordering::Release);
+                if group.responders.is_empty() {
+                    error!(
+                        "Failed to persist {} bytes to log file: {} with io_uring.",
+                        group.size_bytes, group.target.file_path
+                    );
+                }
+                for responder in group.responders {
+                    let _ = responder.send(Err(failure.as_error()));
+                }
+            }
+        }
+    }
+}
+
+fn coalesce(submissions: Vec<Submission>) -> Vec<CommitGroup> {
+    let mut groups: Vec<CommitGroup> = Vec::new();
+    let mut last_groups: HashMap<RawFd, usize> = HashMap::new();
+    for Submission {
+        request,
+        mut responder,
+    } in submissions
+    {
+        let fd = request.target.file.as_raw_fd();
+        let last_group = last_groups.get(&fd).map(|index| &mut groups[*index]);
+        let merged = match last_group {
+            // The fsync requests only wait for the previous writes.
+            Some(group) if request.data.is_empty() => {
+                group.fsync |= request.fsync;
+                group.responders.extend(responder.take());
+                true
+            }
+            Some(group)
+                if request.offset >= group.offset
+                    && request.offset <= group.offset + group.data.len() as u64 =>
+            {
+                group
+                    .data
+                    .truncate((request.offset - group.offset) as usize);
+                group.data.extend_from_slice(&request.data);
+                group.fsync |= request.fsync;
+                group.size_bytes += request.size_bytes;
+                group.responders.extend(responder.take());
+                true
+            }
+            _ => false,
+        };
+
+        if !merged {
+            last_groups.insert(fd, groups.len());
+            groups.push(CommitGroup {
+                target: request.target,
+                offset: request.offset,
+                data: request.data,
+                fsync: request.fsync,
+                size_bytes: request.size_bytes,
+                responders: responder.into_iter().collect(),
+                failure: None,
+            });
+        }
+    }
+    groups
+}
+
+fn describe_result(result: Option<&i32>) -> String {
+    match result {
+        Some(result) if *result < 0 => std::io::Error::from_raw_os_error(-result).to_string(),
+        Some(result) => format!("written: {result} bytes"),
+        None => "missing completion".to_string(),
+    }
+}
+
+fn align_up(size: usize) -> usize {
+    size.div_ceil(BLOCK_SIZE) * BLOCK_SIZE
+}
+
+/// The block aligned memory, which is required by the direct I/O, split into the buffers of the same size.
+struct AlignedBuffers {
+    pointer: *mut u8,
+    layout: Layout,
+    count: usize,
+    size: usize,
+    registered: bool,
+}
+
+impl AlignedBuffers {
+    fn new(count: usize, size: usize) -> Self {
+        let layout = Layout::from_size_align(count * size, BLOCK_SIZE)
+            .expect("Invalid io_uring buffers layout");
+        let pointer = unsafe { alloc_zeroed(layout) };
+        if pointer.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
+        Self {
+            pointer,
+            layout,
+            count,
+            size,
+            registered: false,
+        }
+    }
+
+    fn iovecs(&self) -> Vec<libc::iovec> {
+        (0..self.count)
+            .map(|index| libc::iovec {
+                iov_base: unsafe { self.pointer.add(index * self.size) } as *mut libc::c_void,
+                iov_len: self.size,
+            })
+            .collect()
+    }
+
+    fn get_mut(&mut self, index: usize) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.pointer.add(index * self.size), self.size) }
+    }
+}
+
+impl Drop for AlignedBuffers {
+    fn drop(&mut self) {
+        unsafe { dealloc(self.pointer, self.layout) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::utils::byte_size::IggyByteSize;
+
+    fn create_batch() -> RetainedMessageBatch {
+        let bytes = Bytes::from_static(&[1; 64]);
+        RetainedMessageBatch::new(0, 0, 0, 0, IggyByteSize::from(bytes.len() as u64), bytes)
+    }
+
+    #[tokio::test]
+    async fn writer_should_reject_writes_after_failed_no_wait_write() {
+        let config = IoUringConfig {
+            direct_io: false,
+            ..Default::default()
+        };
+        // Any write to /dev/full fails with ENOSPC.
+        let mut writer =
+            UringLogWriter::new("/dev/full", Arc::new(AtomicU64::new(0)), false, &config).unwrap();
+
+        writer
+            .write(create_batch(), Confirmation::NoWait)
+            .await
+            .unwrap();
+
+        // The fsync waits for the failed write, so the failure is noticed by then.
+        assert!(writer.fsync().await.is_err());
+        assert!(writer
+            .write(create_batch(), Confirmation::NoWait)
+            .await
+            .is_err());
+        assert!(writer
+            .write(create_batch(), Confirmation::Wait)
+            .await
+            .is_err());
+        assert_eq!(writer.target.log_size_bytes.load(Ordering::Acquire), 0);
+    }
+}
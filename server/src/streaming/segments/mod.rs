@@ -1,12 +1,22 @@
+mod file_range;
 mod indexes;
 mod logs;
+mod offloaded;
 mod reading_messages;
+mod recovery;
+mod reencryption;
 mod segment;
 mod writing_messages;
 
-pub use indexes::Index;
+pub use file_range::SegmentFileRange;
+pub use indexes::{Index, SparseTimeIndexer, INDEX_SIZE, TIME_INDEX_SIZE};
+pub use offloaded::{OffloadedSegment, OFFLOADED_EXTENSION};
+pub use recovery::SegmentRecovery;
+pub use reencryption::ReencryptedSegment;
 pub use segment::Segment;
 
 pub const LOG_EXTENSION: &str = "log";
 pub const INDEX_EXTENSION: &str = "index";
+/// The sparse time index, which isn't compatible with the legacy `timeindex` files removed on load.
+pub const TIME_INDEX_EXTENSION: &str = "timeidx";
 pub const SEGMENT_MAX_SIZE_BYTES: u64 = 1000 * 1000 * 1000;
@@ -0,0 +1,214 @@
+use super::indexes::SegmentIndexReader;
+use crate::configs::system::SystemConfig;
+use crate::streaming::segments::segment::Segment;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use tracing::info;
+
+pub const OFFLOADED_EXTENSION: &str = "offloaded";
+
+const COMPONENT: &str = "STREAMING_SEGMENT";
+
+/// The closed segment which has been archived and deleted from the local disk.
+/// Its metadata is kept in the partition directory (next to the local segments), so that the messages
+/// remain addressable by offset and timestamp, and the segment files can be fetched back from the archiver.
+/// - `log_path` and `index_path` - original paths of the segment files, used as the keys in the archiver
+/// - `offloaded_at` - the moment the segment was offloaded, distinguishes the segments of the recreated partitions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OffloadedSegment {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub start_offset: u64,
+    pub end_offset: u64,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub messages_count: u64,
+    pub size_bytes: u64,
+    pub log_path: String,
+    pub index_path: String,
+    pub offloaded_at: IggyTimestamp,
+}
+
+impl OffloadedSegment {
+    pub fn get_path(&self, config: &SystemConfig) -> String {
+        format!(
+            "{}.{OFFLOADED_EXTENSION}",
+            config.get_segment_path(
+                self.stream_id,
+                self.topic_id,
+                self.partition_id,
+                self.start_offset
+            )
+        )
+    }
+
+    pub fn is_expired(&self, message_expiry: IggyExpiry, now: IggyTimestamp) -> bool {
+        match message_expiry {
+            IggyExpiry::ExpireDuration(expiry) => {
+                self.end_timestamp + expiry.as_micros() <= now.as_micros()
+            }
+            _ => false,
+        }
+    }
+
+    pub async fn load(path: &str) -> Result<OffloadedSegment, IggyError> {
+        let bytes = tokio::fs::read(path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read offloaded segment, path: {path}"
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        serde_json::from_slice(&bytes)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to parse offloaded segment, path: {path}")
+            })
+            .map_err(|_| IggyError::CannotDeserializeResource)
+    }
+
+    pub async fn save(&self, path: &str) -> Result<(), IggyError> {
+        let bytes = serde_json::to_vec_pretty(self)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to serialize offloaded segment with start offset: {}",
+                    self.start_offset
+                )
+            })
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        tokio::fs::write(path, bytes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save offloaded segment, path: {path}"
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+}
+
+impl Segment {
+    /// Describes the closed segment before it's deleted from the local disk, the timestamps are based on its indexes.
+    pub async fn to_offloaded(&self) -> Result<OffloadedSegment, IggyError> {
+        let indexes = match self.indexes.as_ref() {
+            Some(indexes) => indexes.clone(),
+            None => self
+                .index_reader
+                .as_ref()
+                .unwrap()
+                .load_all_indexes_impl()
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to load indexes for {self}")
+                })
+                .map_err(|_| IggyError::CannotReadFile)?,
+        };
+
+        let (start_timestamp, end_timestamp) = match (indexes.first(), indexes.last()) {
+            (Some(first), Some(last)) => (first.timestamp, last.timestamp),
+            _ => (self.start_timestamp, self.end_timestamp),
+        };
+
+        Ok(OffloadedSegment {
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            partition_id: self.partition_id,
+            start_offset: self.start_offset,
+            end_offset: self.end_offset,
+            start_timestamp,
+            end_timestamp,
+            messages_count: self.get_messages_count(),
+            size_bytes: self.size_bytes.as_bytes_u64(),
+            log_path: self.log_path.clone(),
+            index_path: self.index_path.clone(),
+            // Truncated to the precision of the serialized timestamp, so the loaded segment is the same.
+            offloaded_at: IggyTimestamp::from(IggyTimestamp::now().as_micros()),
+        })
+    }
+
+    /// Checks whether the closed segment contains only the messages older than `offload_after`.
+    pub async fn is_offloadable(&self, offload_after: IggyDuration, now: IggyTimestamp) -> bool {
+        if !self.is_closed {
+            return false;
+        }
+
+        let Ok(last_messages) = self.get_messages_by_offset(self.current_offset, 1).await else {
+            return false;
+        };
+
+        let Some(last_message) = last_messages.first() else {
+            return false;
+        };
+
+        last_message.timestamp + offload_after.as_micros() <= now.as_micros()
+    }
+
+    /// Opens the segment files fetched from the archiver for reading only.
+    /// The segment isn't a part of the partition, so its size and messages aren't added to the parent stats.
+    pub async fn open_offloaded(
+        offloaded: &OffloadedSegment,
+        log_path: &str,
+        index_path: &str,
+        time_index_path: &str,
+        config: Arc<SystemConfig>,
+    ) -> Result<Segment, IggyError> {
+        let mut segment = Segment::create(
+            offloaded.stream_id,
+            offloaded.topic_id,
+            offloaded.partition_id,
+            offloaded.start_offset,
+            config,
+            IggyExpiry::NeverExpire,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+        );
+        segment.log_path = log_path.to_owned();
+        segment.index_path = index_path.to_owned();
+        segment.time_index_path = time_index_path.to_owned();
+        // The time index isn't archived, as it's built from the index when the segment is fetched.
+        let indexes = SegmentIndexReader::new(index_path, segment.index_size_bytes.clone(), false)
+            .await?
+            .load_all_indexes_impl()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load indexes for offloaded segment: {segment}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        segment.rebuild_time_index(&indexes).await?;
+        segment
+            .initialize_reading()
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to open offloaded segment: {segment}"
+                )
+            })?;
+        if segment.indexes.is_some() {
+            segment.indexes = Some(indexes);
+        }
+
+        segment.start_timestamp = offloaded.start_timestamp;
+        segment.end_timestamp = offloaded.end_timestamp;
+        segment.end_offset = offloaded.end_offset;
+        segment.current_offset = offloaded.end_offset;
+        segment.size_bytes = IggyByteSize::from(offloaded.size_bytes);
+        segment.is_closed = true;
+        info!(
+            "Opened offloaded segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+        Ok(segment)
+    }
+}
@@ -187,26 +187,26 @@ impl Segment {
         Ok(batches)
     }
 
-    pub async fn load_index_for_timestamp(
+    pub async fn load_time_index_for_timestamp(
         &self,
         timestamp: u64,
-    ) -> Result<Option<Index>, IggyError> {
-        trace!("Loading index for timestamp: {}", timestamp);
-        let index = self
-            .index_reader
+    ) -> Result<Option<TimeIndex>, IggyError> {
+        trace!("Loading time index for timestamp: {}", timestamp);
+        let time_index = self
+            .time_index_reader
             .as_ref()
             .unwrap()
-            .load_index_for_timestamp_impl(timestamp)
+            .load_time_index_for_timestamp_impl(timestamp)
             .await
             .with_error_context(|error| {
                 format!(
-                    "Failed to load index for timestamp: {timestamp} for {}. {error}",
+                    "Failed to load time index for timestamp: {timestamp} for {}. {error}",
                     self
                 )
             })?;
 
-        trace!("Loaded index: {:?}", index);
-        Ok(index)
+        trace!("Loaded time index: {:?}", time_index);
+        Ok(time_index)
     }
 
     async fn load_messages_from_disk_by_timestamp(
@@ -214,13 +214,17 @@ impl Segment {
         start_timestamp: u64,
         count: usize,
     ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
-        let index = self.load_index_for_timestamp(start_timestamp).await?;
-        let Some(index) = index else {
+        let time_index = self.load_time_index_for_timestamp(start_timestamp).await?;
+        let Some(time_index) = time_index else {
             return Ok(Vec::new());
         };
 
         let index_range = IndexRange {
-            start: index,
+            start: Index {
+                offset: 0,
+                position: time_index.position,
+                timestamp: time_index.timestamp,
+            },
             end: Index {
                 offset: u32::MAX,
                 position: u32::MAX,
@@ -0,0 +1,478 @@
+use super::indexes::{Index, INDEX_SIZE};
+use crate::streaming::batching::message_batch::{RetainedBatchHeader, RETAINED_BATCH_HEADER_LEN};
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::utils::file;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::checksum;
+use std::io::{ErrorKind, SeekFrom};
+use std::path::Path;
+use tokio::fs::OpenOptions;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader};
+use tracing::{info, warn};
+
+const BUF_READER_CAPACITY_BYTES: usize = 512 * 1000;
+/// Offset (8) + state (1) + timestamp (8) + ID (16) + checksum (4) + headers length (4).
+const MESSAGE_HEADER_LEN: usize = 8 + 1 + 8 + 16 + 4 + 4;
+
+/// Summary of the segment recovery performed on startup.
+/// - `validated_batches` - Number of the batches at the tail of the log which have been validated
+/// - `truncated_bytes` - Number of bytes of the incomplete or corrupted batches removed from the tail of the log
+/// - `rebuilt_indexes` - Number of the index entries which have been rewritten, based on the validated batches
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SegmentRecovery {
+    pub validated_batches: u64,
+    pub truncated_bytes: u64,
+    pub rebuilt_indexes: u64,
+}
+
+impl SegmentRecovery {
+    pub fn is_repaired(&self) -> bool {
+        self.truncated_bytes > 0 || self.rebuilt_indexes > 0
+    }
+}
+
+impl Segment {
+    /// Validates the last `batches_count` batches (or all of them, if `0`) of the log, before the segment is loaded.
+    /// The log is truncated at the first incomplete or corrupted batch, and the index entries
+    /// pointing to the validated batches are rebuilt if they are missing or don't match the log.
+    pub async fn recover(&self, batches_count: u32) -> Result<SegmentRecovery, IggyError> {
+        let mut recovery = SegmentRecovery::default();
+        if !Path::new(&self.log_path).exists() {
+            return Ok(recovery);
+        }
+
+        let log_size = tokio::fs::metadata(&self.log_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to get metadata of log file: {}. {error}",
+                    self.log_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFileMetadata)?
+            .len();
+        let index_bytes = match Path::new(&self.index_path).exists() {
+            true => tokio::fs::read(&self.index_path)
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to read index file: {}. {error}", self.index_path)
+                })
+                .map_err(|_| IggyError::CannotReadFile)?,
+            false => Vec::new(),
+        };
+
+        // Only the index entries pointing to the subsequent positions within the log can be trusted.
+        let mut indexes: Vec<Index> = Vec::new();
+        for chunk in index_bytes.chunks_exact(INDEX_SIZE as usize) {
+            let index = parse_index(chunk);
+            let is_valid = (index.position as u64) < log_size
+                && indexes.last().is_none_or(|last| {
+                    index.position > last.position && index.offset >= last.offset
+                });
+            if !is_valid {
+                break;
+            }
+            indexes.push(index);
+        }
+
+        let retained_indexes_count = match batches_count {
+            0 => 0,
+            _ => indexes.len().saturating_sub(batches_count as usize),
+        };
+        let (start_position, mut next_offset) = match retained_indexes_count {
+            0 => (0, self.start_offset),
+            count => (
+                indexes[count].position as u64,
+                self.start_offset + indexes[count - 1].offset as u64 + 1,
+            ),
+        };
+
+        let file = file::open(&self.log_path)
+            .await
+            .with_error_context(|error| {
+                format!("Failed to open log file: {}. {error}", self.log_path)
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+        reader
+            .seek(SeekFrom::Start(start_position))
+            .await
+            .with_error_context(|error| {
+                format!("Failed to seek log file: {}. {error}", self.log_path)
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+
+        let mut position = start_position;
+        let mut rebuilt_indexes = Vec::new();
+        while let Some((index, length)) = self
+            .read_valid_batch(&mut reader, position, log_size, next_offset)
+            .await?
+        {
+            next_offset = self.start_offset + index.offset as u64 + 1;
+            rebuilt_indexes.push(index);
+            position += length;
+        }
+        recovery.validated_batches = rebuilt_indexes.len() as u64;
+
+        if position < log_size {
+            recovery.truncated_bytes = log_size - position;
+            warn!(
+                "Truncating {} bytes of incomplete or corrupted data at position: {position} of log file: {}",
+                recovery.truncated_bytes, self.log_path
+            );
+            let file = OpenOptions::new()
+                .write(true)
+                .open(&self.log_path)
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to open log file: {}. {error}", self.log_path)
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+            file.set_len(position)
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to truncate log file: {}. {error}", self.log_path)
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+            file.sync_all()
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to fsync log file: {}. {error}", self.log_path)
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+        }
+
+        let retained_index_bytes = retained_indexes_count * INDEX_SIZE as usize;
+        let mut expected_index_bytes =
+            Vec::with_capacity(rebuilt_indexes.len() * INDEX_SIZE as usize);
+        for index in &rebuilt_indexes {
+            expected_index_bytes.extend_from_slice(&index.offset.to_le_bytes());
+            expected_index_bytes.extend_from_slice(&index.position.to_le_bytes());
+            expected_index_bytes.extend_from_slice(&index.timestamp.to_le_bytes());
+        }
+
+        if index_bytes.len() != retained_index_bytes + expected_index_bytes.len()
+            || index_bytes[retained_index_bytes..] != expected_index_bytes
+        {
+            recovery.rebuilt_indexes = rebuilt_indexes.len() as u64;
+            warn!(
+                "Rebuilding {} index entries starting at position: {retained_index_bytes} of index file: {}",
+                recovery.rebuilt_indexes, self.index_path
+            );
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(&self.index_path)
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to open index file: {}. {error}", self.index_path)
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+            file.set_len(retained_index_bytes as u64)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "Failed to truncate index file: {}. {error}",
+                        self.index_path
+                    )
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+            file.seek(SeekFrom::Start(retained_index_bytes as u64))
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to seek index file: {}. {error}", self.index_path)
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+            file.write_all(&expected_index_bytes)
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to write index file: {}. {error}", self.index_path)
+                })
+                .map_err(|_| IggyError::CannotSaveIndexToSegment)?;
+            file.sync_all()
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to fsync index file: {}. {error}", self.index_path)
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+        }
+
+        info!(
+            "Recovered segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}, validated batches: {}, truncated bytes: {}, rebuilt indexes: {}.",
+            self.start_offset,
+            self.partition_id,
+            self.topic_id,
+            self.stream_id,
+            recovery.validated_batches,
+            recovery.truncated_bytes,
+            recovery.rebuilt_indexes
+        );
+        Ok(recovery)
+    }
+
+    /// Returns the index entry and the length of the batch at the given position,
+    /// or `None` if the batch is incomplete, malformed or contains the messages with invalid checksums.
+    async fn read_valid_batch(
+        &self,
+        reader: &mut BufReader<tokio::fs::File>,
+        position: u64,
+        log_size: u64,
+        expected_offset: u64,
+    ) -> Result<Option<(Index, u64)>, IggyError> {
+        if log_size - position < RETAINED_BATCH_HEADER_LEN {
+            return Ok(None);
+        }
+
+        let mut header = [0u8; RETAINED_BATCH_HEADER_LEN as usize];
+        match reader.read_exact(&mut header).await {
+            Ok(_) => {}
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(error) => {
+                return Err(error)
+                    .with_error_context(|error| {
+                        format!("Failed to read log file: {}. {error}", self.log_path)
+                    })
+                    .map_err(|_| IggyError::CannotReadFile)
+            }
+        }
+
+        let Ok(batch_header) = RetainedBatchHeader::from_bytes(&header) else {
+            return Ok(None);
+        };
+        let length = batch_header.length as u64;
+        if length == 0
+            || batch_header.base_offset < expected_offset
+            || log_size - position - RETAINED_BATCH_HEADER_LEN < length
+        {
+            return Ok(None);
+        }
+
+        let mut messages = vec![0u8; length as usize];
+        match reader.read_exact(&mut messages).await {
+            Ok(_) => {}
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(error) => {
+                return Err(error)
+                    .with_error_context(|error| {
+                        format!("Failed to read log file: {}. {error}", self.log_path)
+                    })
+                    .map_err(|_| IggyError::CannotReadFile)
+            }
+        }
+
+        if batch_header.verify_crc(&header, &messages).is_err() || !are_messages_valid(&messages) {
+            return Ok(None);
+        }
+
+        let index = Index {
+            offset: (batch_header.base_offset + batch_header.last_offset_delta as u64
+                - self.start_offset) as u32,
+            position: position as u32,
+            timestamp: batch_header.max_timestamp,
+        };
+        Ok(Some((index, RETAINED_BATCH_HEADER_LEN + length)))
+    }
+}
+
+fn parse_index(bytes: &[u8]) -> Index {
+    Index {
+        offset: u32::from_le_bytes(bytes[0..4].try_into().unwrap()),
+        position: u32::from_le_bytes(bytes[4..8].try_into().unwrap()),
+        timestamp: u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
+    }
+}
+
+fn are_messages_valid(bytes: &[u8]) -> bool {
+    let mut position = 0;
+    while position < bytes.len() {
+        if bytes.len() - position < 4 {
+            return false;
+        }
+
+        let length = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap()) as usize;
+        position += 4;
+        if length < MESSAGE_HEADER_LEN || bytes.len() - position < length {
+            return false;
+        }
+
+        let message = &bytes[position..position + length];
+        let stored_checksum = u32::from_le_bytes(message[33..37].try_into().unwrap());
+        let headers_length = u32::from_le_bytes(message[37..41].try_into().unwrap()) as usize;
+        if length - MESSAGE_HEADER_LEN < headers_length {
+            return false;
+        }
+
+        let payload = &message[MESSAGE_HEADER_LEN + headers_length..];
+        if checksum::calculate(payload) != stored_checksum {
+            return false;
+        }
+
+        position += length;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::batching::message_batch::RetainedMessageBatch;
+    use crate::streaming::models::messages::RetainedMessage;
+    use bytes::{Bytes, BytesMut};
+    use iggy::models::messages::MessageState;
+    use iggy::utils::byte_size::IggyByteSize;
+    use iggy::utils::expiry::IggyExpiry;
+    use std::sync::atomic::AtomicU64;
+    use std::sync::Arc;
+
+    fn message(offset: u64, payload: &'static [u8]) -> RetainedMessage {
+        RetainedMessage {
+            id: offset as u128,
+            offset,
+            timestamp: 1000 + offset,
+            checksum: checksum::calculate(payload),
+            message_state: MessageState::Available,
+            headers: None,
+            key: None,
+            payload: Bytes::from_static(payload),
+        }
+    }
+
+    fn batch(base_offset: u64, messages: &[RetainedMessage]) -> Vec<u8> {
+        let mut payload = BytesMut::new();
+        for message in messages {
+            message.extend(&mut payload);
+        }
+        let batch = RetainedMessageBatch::new(
+            base_offset,
+            messages.len() as u32 - 1,
+            1000 + base_offset,
+            1000 + base_offset + messages.len() as u64 - 1,
+            IggyByteSize::from(payload.len() as u64),
+            payload.freeze(),
+        );
+        let mut bytes = batch.header_as_bytes().to_vec();
+        bytes.extend_from_slice(&batch.bytes);
+        bytes
+    }
+
+    async fn segment(directory: &str) -> Segment {
+        let config = Arc::new(SystemConfig {
+            path: directory.to_string(),
+            ..Default::default()
+        });
+        let segment = Segment::create(
+            1,
+            1,
+            1,
+            0,
+            config,
+            IggyExpiry::NeverExpire,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+        );
+        let partition_path = Path::new(&segment.log_path).parent().unwrap().to_owned();
+        tokio::fs::create_dir_all(partition_path).await.unwrap();
+        segment
+    }
+
+    fn test_directory(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!(
+                "iggy_segment_recovery_{name}_{}",
+                std::process::id()
+            ))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn torn_batch_should_be_truncated_and_missing_indexes_rebuilt() {
+        let directory = test_directory("torn_batch");
+        let segment = segment(&directory).await;
+        let first_batch = batch(0, &[message(0, b"first"), message(1, b"second")]);
+        let second_batch = batch(2, &[message(2, b"third")]);
+        let mut log = first_batch.clone();
+        log.extend_from_slice(&second_batch[..second_batch.len() - 3]);
+        tokio::fs::write(&segment.log_path, &log).await.unwrap();
+
+        let recovery = segment.recover(0).await.unwrap();
+
+        assert_eq!(recovery.validated_batches, 1);
+        assert_eq!(recovery.truncated_bytes, (second_batch.len() - 3) as u64);
+        assert_eq!(recovery.rebuilt_indexes, 1);
+        let log_size = tokio::fs::metadata(&segment.log_path).await.unwrap().len();
+        assert_eq!(log_size, first_batch.len() as u64);
+        let index = tokio::fs::read(&segment.index_path).await.unwrap();
+        assert_eq!(
+            parse_index(&index),
+            Index {
+                offset: 1,
+                position: 0,
+                timestamp: 1001,
+            }
+        );
+        tokio::fs::remove_dir_all(&directory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn batch_with_invalid_crc_should_be_truncated() {
+        let directory = test_directory("invalid_crc");
+        let segment = segment(&directory).await;
+        let first_batch = batch(0, &[message(0, b"first")]);
+        let mut second_batch = batch(1, &[message(1, b"second")]);
+        // The first timestamp isn't covered by the checksums of the messages, only by the CRC of the batch.
+        second_batch[23] ^= 0xff;
+        let mut log = first_batch.clone();
+        log.extend_from_slice(&second_batch);
+        tokio::fs::write(&segment.log_path, &log).await.unwrap();
+
+        let recovery = segment.recover(0).await.unwrap();
+
+        assert_eq!(recovery.validated_batches, 1);
+        assert_eq!(recovery.truncated_bytes, second_batch.len() as u64);
+        let log_size = tokio::fs::metadata(&segment.log_path).await.unwrap().len();
+        assert_eq!(log_size, first_batch.len() as u64);
+        tokio::fs::remove_dir_all(&directory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn index_pointing_past_log_end_should_be_rebuilt() {
+        let directory = test_directory("index_past_end");
+        let segment = segment(&directory).await;
+        let log = batch(0, &[message(0, b"first")]);
+        tokio::fs::write(&segment.log_path, &log).await.unwrap();
+        let mut index = Vec::new();
+        for (offset, position) in [(0u32, 0u32), (1, log.len() as u32)] {
+            index.extend_from_slice(&offset.to_le_bytes());
+            index.extend_from_slice(&position.to_le_bytes());
+            index.extend_from_slice(&1000u64.to_le_bytes());
+        }
+        tokio::fs::write(&segment.index_path, &index).await.unwrap();
+
+        let recovery = segment.recover(10).await.unwrap();
+
+        assert_eq!(recovery.truncated_bytes, 0);
+        assert_eq!(recovery.rebuilt_indexes, 1);
+        let index = tokio::fs::read(&segment.index_path).await.unwrap();
+        assert_eq!(index.len(), INDEX_SIZE as usize);
+        tokio::fs::remove_dir_all(&directory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn valid_segment_should_not_be_repaired() {
+        let directory = test_directory("valid");
+        let segment = segment(&directory).await;
+        let log = batch(0, &[message(0, b"first")]);
+        tokio::fs::write(&segment.log_path, &log).await.unwrap();
+        assert!(segment.recover(0).await.unwrap().is_repaired());
+        assert!(!segment.recover(0).await.unwrap().is_repaired());
+        tokio::fs::remove_dir_all(&directory).await.unwrap();
+    }
+}
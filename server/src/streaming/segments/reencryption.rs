@@ -0,0 +1,302 @@
+use crate::streaming::batching::iterator::IntoMessagesIterator;
+use crate::streaming::batching::message_batch::{
+    RetainedBatchHeader, RetainedMessageBatch, RETAINED_BATCH_HEADER_LEN,
+};
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::utils::file;
+use bytes::{Bytes, BytesMut};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::checksum;
+use iggy::utils::crypto::KeyringEncryptor;
+use iggy::utils::sizeable::Sizeable;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use tokio::fs::{remove_file, rename, File};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tracing::{info, warn};
+
+const BUF_CAPACITY_BYTES: usize = 512 * 1000;
+const REENCRYPTED_EXTENSION: &str = "reencrypted";
+
+/// The closed segment re-encrypted with the current key into the temporary files,
+/// which replace the original files once the partition is locked for writing.
+/// - `reencrypted_messages` - Number of the messages which have been encrypted with the previous keys
+#[derive(Debug)]
+pub struct ReencryptedSegment {
+    pub start_offset: u64,
+    pub log_path: String,
+    pub index_path: String,
+    pub size_bytes: u64,
+    pub reencrypted_messages: u64,
+}
+
+impl ReencryptedSegment {
+    pub async fn remove(&self) {
+        for path in [&self.log_path, &self.index_path] {
+            if let Err(error) = remove_file(path).await {
+                warn!("Failed to remove re-encrypted segment file: {path}. {error}");
+            }
+        }
+    }
+}
+
+impl Segment {
+    /// Writes the copy of the closed segment with all the messages encrypted with the current key,
+    /// without modifying the segment itself, so it can be done while the partition is only locked for reading.
+    /// Returns `None` if all the messages are already encrypted with the current key.
+    pub async fn reencrypt(
+        &self,
+        encryptor: &KeyringEncryptor,
+    ) -> Result<Option<ReencryptedSegment>, IggyError> {
+        if !self.is_closed {
+            return Ok(None);
+        }
+
+        let current_key_id = encryptor.current_key_id();
+        let log_path = format!("{}.{REENCRYPTED_EXTENSION}", self.log_path);
+        let index_path = format!("{}.{REENCRYPTED_EXTENSION}", self.index_path);
+        let mut reader = BufReader::with_capacity(
+            BUF_CAPACITY_BYTES,
+            file::open(&self.log_path)
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to open log file: {}. {error}", self.log_path)
+                })
+                .map_err(|_| IggyError::CannotReadFile)?,
+        );
+        let mut log_writer = BufWriter::with_capacity(
+            BUF_CAPACITY_BYTES,
+            File::create(&log_path)
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to create re-encrypted log file: {log_path}. {error}")
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?,
+        );
+
+        let mut index_bytes = BytesMut::new();
+        let mut position = 0u64;
+        let mut reencrypted_messages = 0;
+        let result = async {
+            while let Some(batch) = self.read_batch(&mut reader).await? {
+                // The batches already encrypted with the current key are copied as they are.
+                let batch = match batch.encryption_key_id == Some(current_key_id) {
+                    true => batch,
+                    false => {
+                        let mut messages =
+                            BytesMut::with_capacity(batch.length.as_bytes_u64() as usize);
+                        for mut message in batch.into_messages_iter() {
+                            // The batches saved before the encryption was enabled contain the plain payloads.
+                            let payload = match batch.attributes.is_encrypted() {
+                                true => encryptor
+                                    .decrypt_with_key(&message.payload, batch.encryption_key_id)?,
+                                false => message.payload.to_vec(),
+                            };
+                            message.payload =
+                                Bytes::from(encryptor.encrypt_with_key(&payload, current_key_id)?);
+                            message.checksum = checksum::calculate(&message.payload);
+                            message.extend(&mut messages);
+                            reencrypted_messages += 1;
+                        }
+
+                        RetainedMessageBatch::new(
+                            batch.base_offset,
+                            batch.last_offset_delta,
+                            batch.first_timestamp,
+                            batch.max_timestamp,
+                            IggyByteSize::from(messages.len() as u64),
+                            messages.freeze(),
+                        )
+                        .with_attributes(batch.attributes.with_encrypted(true))
+                        .with_producer(batch.producer_id, batch.producer_epoch, batch.base_sequence)
+                        .with_encryption_key_id(Some(current_key_id))
+                    }
+                };
+                log_writer
+                    .write_all(&batch.header_as_bytes())
+                    .await
+                    .map_err(|_| IggyError::CannotWriteToFile)?;
+                log_writer
+                    .write_all(&batch.bytes)
+                    .await
+                    .map_err(|_| IggyError::CannotWriteToFile)?;
+                index_bytes.extend_from_slice(
+                    &((batch.get_last_offset() - self.start_offset) as u32).to_le_bytes(),
+                );
+                index_bytes.extend_from_slice(&(position as u32).to_le_bytes());
+                index_bytes.extend_from_slice(&batch.max_timestamp.to_le_bytes());
+                position += batch.get_size_bytes().as_bytes_u64();
+            }
+            log_writer
+                .flush()
+                .await
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+            log_writer
+                .get_ref()
+                .sync_all()
+                .await
+                .map_err(|_| IggyError::CannotSyncFile)?;
+            Ok::<_, IggyError>(())
+        }
+        .await;
+
+        let reencrypted = ReencryptedSegment {
+            start_offset: self.start_offset,
+            log_path,
+            index_path,
+            size_bytes: position,
+            reencrypted_messages,
+        };
+        if let Err(error) = result {
+            let _ = remove_file(&reencrypted.log_path).await;
+            return Err(error).with_error_context(|error| {
+                format!("Failed to re-encrypt segment: {self}. {error}")
+            });
+        }
+
+        if reencrypted_messages == 0 {
+            let _ = remove_file(&reencrypted.log_path).await;
+            return Ok(None);
+        }
+
+        let mut index_file = File::create(&reencrypted.index_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to create re-encrypted index file: {}. {error}",
+                    reencrypted.index_path
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        index_file
+            .write_all(&index_bytes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to write re-encrypted index file: {}. {error}",
+                    reencrypted.index_path
+                )
+            })
+            .map_err(|_| IggyError::CannotSaveIndexToSegment)?;
+        index_file
+            .sync_all()
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to fsync re-encrypted index file: {}. {error}",
+                    reencrypted.index_path
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        Ok(Some(reencrypted))
+    }
+
+    /// Replaces the segment files with the re-encrypted ones and reopens the segment for reading.
+    pub async fn replace_reencrypted(
+        &mut self,
+        reencrypted: &ReencryptedSegment,
+    ) -> Result<(), IggyError> {
+        if !Path::new(&reencrypted.log_path).exists()
+            || !Path::new(&reencrypted.index_path).exists()
+        {
+            return Err(IggyError::CannotReadFile);
+        }
+
+        // The writers of the loaded segments remain open, even though nothing is written to the closed segment.
+        if self.log_writer.is_some() {
+            self.shutdown_writing().await;
+        }
+
+        rename(&reencrypted.log_path, &self.log_path)
+            .await
+            .with_error_context(|error| {
+                format!("Failed to replace log file: {}. {error}", self.log_path)
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        rename(&reencrypted.index_path, &self.index_path)
+            .await
+            .with_error_context(|error| {
+                format!("Failed to replace index file: {}. {error}", self.index_path)
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+
+        self.initialize_reading().await?;
+        // The positions of the batches have changed, so the time index has to be rebuilt as well.
+        let indexes = self
+            .index_reader
+            .as_ref()
+            .unwrap()
+            .load_all_indexes_impl()
+            .await
+            .with_error_context(|error| format!("Failed to load indexes for {self}. {error}"))
+            .map_err(|_| IggyError::CannotReadFile)?;
+        self.rebuild_time_index(&indexes).await?;
+        if self.indexes.is_some() {
+            self.indexes = Some(indexes);
+        }
+
+        // The legacy messages don't contain the key ID, so the size of the segment might have changed.
+        let previous_size_bytes = self.size_bytes.as_bytes_u64();
+        for size in [
+            &self.size_of_parent_stream,
+            &self.size_of_parent_topic,
+            &self.size_of_parent_partition,
+        ] {
+            size.fetch_sub(previous_size_bytes, Ordering::AcqRel);
+            size.fetch_add(reencrypted.size_bytes, Ordering::AcqRel);
+        }
+        self.size_bytes = IggyByteSize::from(reencrypted.size_bytes);
+        self.last_index_position = reencrypted.size_bytes as _;
+        info!(
+            "Re-encrypted {} messages in segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            reencrypted.reencrypted_messages,
+            self.start_offset,
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        Ok(())
+    }
+
+    async fn read_batch(
+        &self,
+        reader: &mut BufReader<tokio::fs::File>,
+    ) -> Result<Option<RetainedMessageBatch>, IggyError> {
+        let mut header_bytes = [0u8; RETAINED_BATCH_HEADER_LEN as usize];
+        match reader.read_exact(&mut header_bytes).await {
+            Ok(_) => {}
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(error) => {
+                return Err(error)
+                    .with_error_context(|error| {
+                        format!("Failed to read log file: {}. {error}", self.log_path)
+                    })
+                    .map_err(|_| IggyError::CannotReadFile)
+            }
+        }
+
+        let header =
+            RetainedBatchHeader::from_bytes(&header_bytes).with_error_context(|error| {
+                format!(
+                    "Failed to parse batch header in log file: {}. {error}",
+                    self.log_path
+                )
+            })?;
+        let mut messages = vec![0u8; header.length as usize];
+        reader
+            .read_exact(&mut messages)
+            .await
+            .with_error_context(|error| {
+                format!("Failed to read log file: {}. {error}", self.log_path)
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        header.verify_crc(&header_bytes, &messages)?;
+        Ok(Some(RetainedMessageBatch::from_header(
+            &header,
+            Bytes::from(messages),
+        )))
+    }
+}
@@ -1,6 +1,6 @@
 use super::indexes::*;
 use super::logs::*;
-use crate::configs::system::SystemConfig;
+use crate::configs::system::{IndexCacheMode, IoBackend, SystemConfig};
 use crate::streaming::batching::batch_accumulator::BatchAccumulator;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
@@ -24,6 +24,7 @@ pub struct Segment {
     pub end_timestamp: u64, // last message timestamp
     pub current_offset: u64,
     pub index_path: String,
+    pub time_index_path: String,
     pub log_path: String,
     pub size_bytes: IggyByteSize,
     pub last_index_position: u32,
@@ -39,12 +40,15 @@ pub struct Segment {
     pub(super) log_reader: Option<SegmentLogReader>,
     pub(super) index_writer: Option<SegmentIndexWriter>,
     pub(super) index_reader: Option<SegmentIndexReader>,
+    pub(super) time_index_writer: Option<SegmentTimeIndexWriter>,
+    pub(super) time_index_reader: Option<SegmentTimeIndexReader>,
     pub message_expiry: IggyExpiry,
     pub unsaved_messages: Option<BatchAccumulator>,
     pub config: Arc<SystemConfig>,
     pub indexes: Option<Vec<Index>>,
     pub(super) log_size_bytes: Arc<AtomicU64>,
     pub(super) index_size_bytes: Arc<AtomicU64>,
+    pub(super) time_index_size_bytes: Arc<AtomicU64>,
 }
 
 impl Segment {
@@ -66,13 +70,14 @@ impl Segment {
         let path = config.get_segment_path(stream_id, topic_id, partition_id, start_offset);
         let log_path = Self::get_log_path(&path);
         let index_path = Self::get_index_path(&path);
+        let time_index_path = Self::get_time_index_path(&path);
         let message_expiry = match message_expiry {
             IggyExpiry::ServerDefault => config.segment.message_expiry,
             _ => message_expiry,
         };
         let indexes = match config.segment.cache_indexes {
-            true => Some(Vec::new()),
-            false => None,
+            IndexCacheMode::Eager => Some(Vec::new()),
+            IndexCacheMode::Mmap | IndexCacheMode::OnDemand => None,
         };
 
         Segment {
@@ -86,6 +91,7 @@ impl Segment {
             current_offset: start_offset,
             log_path,
             index_path,
+            time_index_path,
             size_bytes: IggyByteSize::from(0),
             last_index_position: 0,
             max_size_bytes: config.segment.size,
@@ -97,6 +103,8 @@ impl Segment {
             log_reader: None,
             index_writer: None,
             index_reader: None,
+            time_index_writer: None,
+            time_index_reader: None,
             size_of_parent_stream,
             size_of_parent_partition,
             size_of_parent_topic,
@@ -106,6 +114,7 @@ impl Segment {
             config,
             log_size_bytes: Arc::new(AtomicU64::new(0)),
             index_size_bytes: Arc::new(AtomicU64::new(0)),
+            time_index_size_bytes: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -128,15 +137,18 @@ impl Segment {
         self.size_bytes = IggyByteSize::from(log_size_bytes);
         self.last_index_position = log_size_bytes as _;
 
-        self.indexes = Some(
-            self.index_reader
-                .as_ref()
-                .unwrap()
-                .load_all_indexes_impl()
-                .await
-                .with_error_context(|error| format!("Failed to load indexes for {self}. {error}"))
-                .map_err(|_| IggyError::CannotReadFile)?,
-        );
+        let indexes = self
+            .index_reader
+            .as_ref()
+            .unwrap()
+            .load_all_indexes_impl()
+            .await
+            .with_error_context(|error| format!("Failed to load indexes for {self}. {error}"))
+            .map_err(|_| IggyError::CannotReadFile)?;
+        self.load_time_index(&indexes)
+            .await
+            .with_error_context(|error| format!("Failed to load time index for {self}. {error}"))?;
+        self.indexes = Some(indexes);
 
         let last_index_offset = if self.indexes.as_ref().unwrap().is_empty() {
             0_u64
@@ -153,7 +165,7 @@ impl Segment {
               self.topic_id,
               self.stream_id);
 
-        if !self.config.segment.cache_indexes {
+        if self.config.segment.cache_indexes != IndexCacheMode::Eager {
             self.indexes = None;
         }
 
@@ -210,6 +222,11 @@ impl Segment {
         let max_file_operation_retries = self.config.state.max_file_operation_retries;
         let retry_delay = self.config.state.retry_delay;
 
+        let io_uring = match self.config.partition.io_backend {
+            IoBackend::IoUring => Some(&self.config.partition.io_uring),
+            IoBackend::Tokio => None,
+        };
+
         let log_writer = SegmentLogWriter::new(
             &self.log_path,
             self.log_size_bytes.clone(),
@@ -217,26 +234,43 @@ impl Segment {
             server_confirmation,
             max_file_operation_retries,
             retry_delay,
+            io_uring,
         )
         .await?;
 
         let index_writer =
             SegmentIndexWriter::new(&self.index_path, self.index_size_bytes.clone(), index_fsync)
                 .await?;
+        let time_index_writer = SegmentTimeIndexWriter::new(
+            &self.time_index_path,
+            self.time_index_size_bytes.clone(),
+            index_fsync,
+            self.get_time_index_interval(),
+        )
+        .await?;
 
         self.log_writer = Some(log_writer);
         self.index_writer = Some(index_writer);
+        self.time_index_writer = Some(time_index_writer);
         Ok(())
     }
 
     pub async fn initialize_reading(&mut self) -> Result<(), IggyError> {
         let log_reader = SegmentLogReader::new(&self.log_path, self.log_size_bytes.clone()).await?;
         // TODO(hubcio): there is no need to store open fd for reader if we have index cache enabled
-        let index_reader =
-            SegmentIndexReader::new(&self.index_path, self.index_size_bytes.clone()).await?;
+        let index_reader = SegmentIndexReader::new(
+            &self.index_path,
+            self.index_size_bytes.clone(),
+            self.config.segment.cache_indexes == IndexCacheMode::Mmap,
+        )
+        .await?;
+        let time_index_reader =
+            SegmentTimeIndexReader::new(&self.time_index_path, self.time_index_size_bytes.clone())
+                .await?;
 
         self.log_reader = Some(log_reader);
         self.index_reader = Some(index_reader);
+        self.time_index_reader = Some(time_index_reader);
         Ok(())
     }
 
@@ -281,6 +315,9 @@ impl Segment {
         if let Some(index_reader) = self.index_reader.take() {
             drop(index_reader);
         }
+        if let Some(time_index_reader) = self.time_index_reader.take() {
+            drop(time_index_reader);
+        }
     }
 
     pub async fn shutdown_writing(&mut self) {
@@ -304,6 +341,13 @@ impl Segment {
         } else {
             warn!("Index writer already closed when calling close()");
         }
+
+        if let Some(time_index_writer) = self.time_index_writer.take() {
+            tokio::spawn(async move {
+                let _ = time_index_writer.fsync().await;
+                drop(time_index_writer)
+            });
+        }
     }
 
     pub async fn delete(&mut self) -> Result<(), IggyError> {
@@ -330,6 +374,14 @@ impl Segment {
             .with_error_context(|error| {
                 format!("Failed to delete index file: {}. {error}", self.index_path)
             });
+        let _ = remove_file(&self.time_index_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to delete time index file: {}. {error}",
+                    self.time_index_path
+                )
+            });
 
         let segment_size_bytes = self.size_bytes.as_bytes_u64();
         self.size_of_parent_stream
@@ -360,6 +412,10 @@ impl Segment {
     fn get_index_path(path: &str) -> String {
         format!("{}.{}", path, INDEX_EXTENSION)
     }
+
+    pub(crate) fn get_time_index_path(path: &str) -> String {
+        format!("{}.{}", path, TIME_INDEX_EXTENSION)
+    }
 }
 
 impl std::fmt::Display for Segment {
@@ -397,6 +453,7 @@ mod tests {
         let path = config.get_segment_path(stream_id, topic_id, partition_id, start_offset);
         let log_path = Segment::get_log_path(&path);
         let index_path = Segment::get_index_path(&path);
+        let time_index_path = Segment::get_time_index_path(&path);
         let message_expiry = IggyExpiry::ExpireDuration(IggyDuration::from(10));
         let size_of_parent_stream = Arc::new(AtomicU64::new(0));
         let size_of_parent_topic = Arc::new(AtomicU64::new(0));
@@ -429,6 +486,7 @@ mod tests {
         assert_eq!(segment.size_bytes, 0);
         assert_eq!(segment.log_path, log_path);
         assert_eq!(segment.index_path, index_path);
+        assert_eq!(segment.time_index_path, time_index_path);
         assert_eq!(segment.message_expiry, message_expiry);
         assert!(segment.unsaved_messages.is_none());
         assert!(segment.indexes.is_some());
@@ -444,7 +502,7 @@ mod tests {
         let start_offset = 0;
         let config = Arc::new(SystemConfig {
             segment: SegmentConfig {
-                cache_indexes: false,
+                cache_indexes: IndexCacheMode::OnDemand,
                 ..Default::default()
             },
             ..Default::default()
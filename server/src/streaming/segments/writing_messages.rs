@@ -1,5 +1,6 @@
 use super::indexes::*;
 use crate::streaming::batching::batch_accumulator::BatchAccumulator;
+use crate::streaming::batching::batch_attributes::BatchAttributes;
 use crate::streaming::batching::message_batch::RETAINED_BATCH_HEADER_LEN;
 use crate::streaming::models::messages::RetainedMessage;
 use crate::streaming::segments::segment::Segment;
@@ -18,6 +19,7 @@ impl Segment {
         batch_size: IggyByteSize,
         messages_count: u32,
         batch: &[Arc<RetainedMessage>],
+        encryption_key_id: Option<u32>,
     ) -> Result<(), IggyError> {
         if self.is_closed {
             return Err(IggyError::SegmentClosed(
@@ -36,7 +38,7 @@ impl Segment {
         let batch_accumulator = self
             .unsaved_messages
             .get_or_insert_with(|| BatchAccumulator::new(batch_base_offset, messages_cap));
-        batch_accumulator.append(batch_size, batch);
+        batch_accumulator.append(batch_size, batch, encryption_key_id);
         self.end_timestamp = batch_accumulator.batch_max_timestamp();
         let curr_offset = batch_accumulator.batch_max_offset();
 
@@ -105,7 +107,11 @@ impl Segment {
             self.partition_id
         );
 
-        let batch = batch_accumulator.materialize_batch_and_update_state();
+        // The payloads are encrypted before being appended, whenever the encryption is enabled.
+        let attributes = BatchAttributes::default().with_encrypted(self.config.encryption.enabled);
+        let batch = batch_accumulator
+            .materialize_batch_and_update_state()
+            .with_attributes(attributes);
         let batch_size = batch.get_size_bytes();
         if batch_size > 0 {
             self.unsaved_messages = Some(batch_accumulator);
@@ -130,6 +136,12 @@ impl Segment {
             .save_index(index)
             .await
             .with_error_context(|error| format!("Failed to save index for {self}. {error}"))?;
+        self.time_index_writer
+            .as_mut()
+            .unwrap()
+            .save_index(&index)
+            .await
+            .with_error_context(|error| format!("Failed to save time index for {self}. {error}"))?;
 
         self.last_index_position += batch_size.as_bytes_u64() as u32;
         self.size_bytes += IggyByteSize::from(RETAINED_BATCH_HEADER_LEN);
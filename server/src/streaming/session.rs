@@ -7,6 +7,8 @@ use std::sync::atomic::{AtomicBool, Ordering};
 #[derive(Debug)]
 pub struct Session {
     user_id: AtomicUserId,
+    /// The ID used for the permission checks, differs from the user ID only when authenticated with the scoped personal access token.
+    permissions_id: AtomicUserId,
     active: AtomicBool,
     pub client_id: u32,
     pub ip_address: SocketAddr,
@@ -18,12 +20,15 @@ impl Session {
             client_id,
             active: AtomicBool::new(true),
             user_id: AtomicUserId::new(user_id),
+            permissions_id: AtomicUserId::new(user_id),
             ip_address,
         }
     }
 
-    pub fn stateless(user_id: UserId, ip_address: SocketAddr) -> Self {
-        Self::new(0, user_id, ip_address)
+    pub fn stateless(user_id: UserId, permissions_id: UserId, ip_address: SocketAddr) -> Self {
+        let session = Self::new(0, user_id, ip_address);
+        session.set_permissions_id(permissions_id);
+        session
     }
 
     pub fn from_client_id(client_id: u32, ip_address: SocketAddr) -> Self {
@@ -35,9 +40,25 @@ impl Session {
     }
 
     pub fn set_user_id(&self, user_id: UserId) {
+        self.permissions_id.store(user_id, Ordering::Release);
         self.user_id.store(user_id, Ordering::Release)
     }
 
+    /// Returns the ID that should be passed to the `Permissioner`, which for the session authenticated
+    /// with the scoped personal access token is the ID of the token principal, otherwise the user ID.
+    pub fn get_permissions_id(&self) -> UserId {
+        self.permissions_id.load(Ordering::Acquire)
+    }
+
+    /// Must be called after `set_user_id`, which resets the permissions ID to the user ID.
+    pub fn set_permissions_id(&self, permissions_id: UserId) {
+        self.permissions_id.store(permissions_id, Ordering::Release)
+    }
+
+    pub fn is_scoped(&self) -> bool {
+        self.get_permissions_id() != self.get_user_id()
+    }
+
     pub fn set_stale(&self) {
         self.active.store(false, Ordering::Release)
     }
@@ -7,6 +7,7 @@ use crate::streaming::streams::storage::FileStreamStorage;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::info::SystemInfo;
 use crate::streaming::systems::storage::FileSystemInfoStorage;
+use crate::streaming::tiered_storage::TieredStorage;
 use crate::streaming::topics::storage::FileTopicStorage;
 use crate::streaming::topics::topic::Topic;
 use iggy::consumer::ConsumerKind;
@@ -130,6 +131,7 @@ pub struct SystemStorage {
     pub topic: Arc<TopicStorageKind>,
     pub partition: Arc<PartitionStorageKind>,
     pub persister: Arc<PersisterKind>,
+    pub tiered: Option<Arc<TieredStorage>>,
 }
 
 impl SystemStorage {
@@ -145,6 +147,7 @@ impl SystemStorage {
                 persister.clone(),
             ))),
             persister,
+            tiered: None,
         }
     }
 }
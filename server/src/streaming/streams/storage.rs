@@ -32,6 +32,7 @@ impl StreamStorage for FileStreamStorage {
             return Err(IggyError::StreamIdNotFound(stream.stream_id));
         }
 
+        stream.created_by = state.created_by;
         let mut unloaded_topics = Vec::new();
         let dir_entries = fs::read_dir(&stream.topics_path).await;
         if dir_entries.is_err() {
@@ -2,6 +2,8 @@ use crate::configs::system::SystemConfig;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::topic::Topic;
 use ahash::AHashMap;
+use iggy::models::topic_template::TopicDefaults;
+use iggy::models::user_info::UserId;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::fmt::Display;
@@ -15,10 +17,12 @@ pub struct Stream {
     pub path: String,
     pub topics_path: String,
     pub created_at: IggyTimestamp,
+    pub created_by: UserId,
     pub current_topic_id: AtomicU32,
     pub size_bytes: Arc<AtomicU64>,
     pub messages_count: Arc<AtomicU64>,
     pub segments_count: Arc<AtomicU32>,
+    pub topic_defaults: TopicDefaults,
     pub(crate) topics: AHashMap<u32, Topic>,
     pub(crate) topics_ids: AHashMap<String, u32>,
     pub(crate) config: Arc<SystemConfig>,
@@ -54,10 +58,12 @@ impl Stream {
             size_bytes: Arc::new(AtomicU64::new(0)),
             messages_count: Arc::new(AtomicU64::new(0)),
             segments_count: Arc::new(AtomicU32::new(0)),
+            topic_defaults: TopicDefaults::default(),
             topics: AHashMap::new(),
             topics_ids: AHashMap::new(),
             storage,
             created_at: IggyTimestamp::now(),
+            created_by: 0,
         }
     }
 
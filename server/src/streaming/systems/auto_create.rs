@@ -0,0 +1,470 @@
+use crate::cluster::node::ClusterNode;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::systems::COMPONENT;
+use bytes::Bytes;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::{IdKind, Identifier};
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use iggy::validatable::Validatable;
+use std::fmt::Display;
+use tracing::info;
+
+/// The operation on the messages, which triggers the creation of the missing stream and topic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoCreateTrigger {
+    SendMessages,
+    PollMessages,
+}
+
+impl AutoCreateTrigger {
+    /// Returns the context of the replicated state entry, marking its command as the auto creation on this trigger.
+    pub fn to_context(self) -> Bytes {
+        let code: u8 = match self {
+            AutoCreateTrigger::SendMessages => 1,
+            AutoCreateTrigger::PollMessages => 2,
+        };
+        Bytes::copy_from_slice(&[code])
+    }
+
+    /// Returns the trigger of the auto creation from the context of the replicated state entry, if any.
+    pub fn from_context(context: &[u8]) -> Result<Option<Self>, IggyError> {
+        match context {
+            [] => Ok(None),
+            [1] => Ok(Some(AutoCreateTrigger::SendMessages)),
+            [2] => Ok(Some(AutoCreateTrigger::PollMessages)),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for AutoCreateTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutoCreateTrigger::SendMessages => write!(f, "send_messages"),
+            AutoCreateTrigger::PollMessages => write!(f, "poll_messages"),
+        }
+    }
+}
+
+impl SharedSystem {
+    /// Creates the missing stream and topic referenced by name, if allowed by the auto create policy,
+    /// and applies the created resources to the state.
+    /// The write lock is only acquired when the resources are actually missing and match the configured patterns.
+    pub async fn auto_create(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        trigger: AutoCreateTrigger,
+    ) -> Result<(), IggyError> {
+        if !self
+            .read()
+            .await
+            .is_auto_create_required(stream_id, topic_id, trigger)
+        {
+            return Ok(());
+        }
+
+        if let Some(cluster_node) = self.cluster_node().await {
+            return self
+                .replicate_auto_create(&cluster_node, session, stream_id, topic_id, trigger)
+                .await;
+        }
+
+        let mut system = self.write().await;
+        let mut commands = Vec::new();
+        let result = system
+            .auto_create_stream_and_topic(session, stream_id, topic_id, trigger, &mut commands)
+            .await;
+        let system = system.downgrade();
+        // The stream might have been created even if the topic could not be, so it has to be applied anyway.
+        for command in commands {
+            system
+                .state
+                .apply(session.get_user_id(), command)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to apply auto created resources for stream ID: {stream_id}, topic ID: {topic_id}"
+                    )
+                })?;
+        }
+        result
+    }
+
+    /// Replicates the creation of the missing stream and then of the missing topic, as the topic permissions
+    /// can only be checked once the stream exists. The commands are proposed on behalf of the user of the session,
+    /// along with the trigger, so the committed entries are validated with the same auto create permissions
+    /// and quotas as on the single node.
+    /// The resources created concurrently by another request in the meantime are not treated as an error.
+    async fn replicate_auto_create(
+        &self,
+        cluster_node: &ClusterNode,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        trigger: AutoCreateTrigger,
+    ) -> Result<(), IggyError> {
+        let command = {
+            let system = self.read().await;
+            system.ensure_authenticated(session)?;
+            if !system.is_auto_create_required(stream_id, topic_id, trigger) {
+                return Ok(());
+            }
+            system.auto_create_stream_command(session, stream_id, trigger)?
+        };
+        if let Some(command) = command {
+            let name = command.name.clone();
+            if let Err(error) = cluster_node
+                .replicate_with_context(
+                    session.get_user_id(),
+                    session.get_permissions_id(),
+                    trigger.to_context(),
+                    EntryCommand::CreateStream(command),
+                )
+                .await
+            {
+                if !matches!(self.read().await.try_get_stream(stream_id), Ok(Some(_))) {
+                    return Err(error).with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to replicate auto created stream with ID: {stream_id}")
+                    });
+                }
+            } else {
+                info!("Auto created stream with name: '{name}' on {trigger}.");
+            }
+        }
+
+        let command = {
+            let system = self.read().await;
+            if !system.is_auto_create_required(stream_id, topic_id, trigger) {
+                return Ok(());
+            }
+            let numeric_stream_id = system.get_stream(stream_id)?.stream_id;
+            system.auto_create_topic_command(
+                session,
+                numeric_stream_id,
+                stream_id,
+                topic_id,
+                trigger,
+            )?
+        };
+        let name = command.name.clone();
+        if let Err(error) = cluster_node
+            .replicate_with_context(
+                session.get_user_id(),
+                session.get_permissions_id(),
+                trigger.to_context(),
+                EntryCommand::CreateTopic(command),
+            )
+            .await
+        {
+            let system = self.read().await;
+            if system.is_auto_create_required(stream_id, topic_id, trigger) {
+                return Err(error).with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to replicate auto created topic with ID: {topic_id} in stream with ID: {stream_id}")
+                });
+            }
+        } else {
+            info!("Auto created topic with name: '{name}' in stream with ID: {stream_id} on {trigger}.");
+        }
+        Ok(())
+    }
+}
+
+impl System {
+    fn is_auto_create_required(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        trigger: AutoCreateTrigger,
+    ) -> bool {
+        let config = &self.config.auto_create;
+        let enabled_for_trigger = match trigger {
+            AutoCreateTrigger::SendMessages => config.on_send,
+            AutoCreateTrigger::PollMessages => config.on_poll,
+        };
+        if !config.enabled || !enabled_for_trigger || topic_id.kind != IdKind::String {
+            return false;
+        }
+
+        let Ok(topic_name) = topic_id.get_cow_str_value() else {
+            return false;
+        };
+        if !matches_pattern(&config.topics_pattern, &topic_name) {
+            return false;
+        }
+
+        match self.try_get_stream(stream_id) {
+            Ok(Some(stream)) => matches!(stream.try_get_topic(topic_id), Ok(None)),
+            Ok(None) => {
+                stream_id.kind == IdKind::String
+                    && stream_id.get_cow_str_value().is_ok_and(|stream_name| {
+                        matches_pattern(&config.streams_pattern, &stream_name)
+                    })
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Creates the missing stream and topic, and collects the commands to be applied to the state.
+    /// The policy is checked again, as the resources could have been created before the write lock was acquired.
+    async fn auto_create_stream_and_topic(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        trigger: AutoCreateTrigger,
+        commands: &mut Vec<EntryCommand>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        if !self.is_auto_create_required(stream_id, topic_id, trigger) {
+            return Ok(());
+        }
+
+        let numeric_stream_id = match self
+            .auto_create_stream_command(session, stream_id, trigger)?
+        {
+            Some(mut command) => {
+                let stream = self
+                    .create_stream_internal(session, None, &command.name)
+                    .await
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to auto create stream with ID: {stream_id}")
+                    })?;
+                let created_stream_id = stream.stream_id;
+                info!(
+                    "Auto created stream with ID: {created_stream_id}, name: '{}' on {trigger}.",
+                    command.name
+                );
+                command.stream_id = Some(created_stream_id);
+                commands.push(EntryCommand::CreateStream(command));
+                created_stream_id
+            }
+            None => self.get_stream(stream_id)?.stream_id,
+        };
+
+        let mut command = self.auto_create_topic_command(
+            session,
+            numeric_stream_id,
+            stream_id,
+            topic_id,
+            trigger,
+        )?;
+        let topic = self
+            .create_topic_internal(
+                session,
+                &command.stream_id,
+                None,
+                &command.name,
+                command.partitions_count,
+                command.message_expiry,
+                command.compression_algorithm,
+                command.max_topic_size,
+                command.replication_factor,
+            )
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to auto create topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        info!(
+            "Auto created topic with ID: {}, name: '{}' in stream with ID: {numeric_stream_id} on {trigger}.",
+            topic.topic_id, command.name
+        );
+        command.topic_id = Some(topic.topic_id);
+        command.message_expiry = topic.message_expiry;
+        command.max_topic_size = topic.max_topic_size;
+        commands.push(EntryCommand::CreateTopic(command));
+        Ok(())
+    }
+
+    /// Applies the replicated command creating the missing stream or topic, which is validated with the auto create
+    /// permissions of the user for the given trigger, instead of the regular ones to manage the streams and topics.
+    pub(crate) async fn apply_replicated_auto_create(
+        &mut self,
+        session: &Session,
+        command: EntryCommand,
+        trigger: AutoCreateTrigger,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        match command {
+            EntryCommand::CreateStream(command) => {
+                self.permissioner
+                    .auto_create_stream(session.get_permissions_id(), trigger)?;
+                self.create_stream_internal(session, command.stream_id, &command.name)
+                    .await?;
+            }
+            EntryCommand::CreateTopic(command) => {
+                let numeric_stream_id = self.get_stream(&command.stream_id)?.stream_id;
+                self.permissioner.auto_create_topic(
+                    session.get_permissions_id(),
+                    numeric_stream_id,
+                    trigger,
+                )?;
+                self.create_topic_internal(
+                    session,
+                    &command.stream_id,
+                    command.topic_id,
+                    &command.name,
+                    command.partitions_count,
+                    command.message_expiry,
+                    command.compression_algorithm,
+                    command.max_topic_size,
+                    command.replication_factor,
+                )
+                .await?;
+            }
+            _ => return Err(IggyError::InvalidCommand),
+        }
+        Ok(())
+    }
+
+    /// Returns the command creating the missing stream, if the user is allowed to auto create it,
+    /// or `None` if the stream already exists.
+    fn auto_create_stream_command(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        trigger: AutoCreateTrigger,
+    ) -> Result<Option<CreateStream>, IggyError> {
+        if self.try_get_stream(stream_id)?.is_some() {
+            return Ok(None);
+        }
+
+        self.permissioner
+            .auto_create_stream(session.get_permissions_id(), trigger)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to auto create stream with ID: {stream_id} on {trigger} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+        let command = CreateStream {
+            stream_id: None,
+            name: stream_id.get_string_value()?,
+        };
+        command.validate()?;
+        Ok(Some(command))
+    }
+
+    /// Returns the command creating the missing topic with the resolved settings, if the user is allowed to auto create it.
+    fn auto_create_topic_command(
+        &self,
+        session: &Session,
+        numeric_stream_id: u32,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        trigger: AutoCreateTrigger,
+    ) -> Result<CreateTopic, IggyError> {
+        self.permissioner
+            .auto_create_topic(session.get_permissions_id(), numeric_stream_id, trigger)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to auto create topic with ID: {topic_id} in stream with ID: {stream_id} on {trigger} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+        let config = &self.config.auto_create;
+        let mut command = CreateTopic {
+            stream_id: Identifier::numeric(numeric_stream_id)?,
+            topic_id: None,
+            partitions_count: config.partitions_count,
+            compression_algorithm: self.config.compression.default_algorithm,
+            message_expiry: IggyExpiry::ServerDefault,
+            max_topic_size: MaxTopicSize::ServerDefault,
+            replication_factor: None,
+            name: topic_id.get_string_value()?,
+            template: (!config.template.is_empty()).then(|| config.template.clone()),
+        };
+        command.validate()?;
+        self.resolve_topic_settings(session, &mut command)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to resolve settings of auto created topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        Ok(command)
+    }
+}
+
+/// Matches the name against the pattern, where `*` matches any sequence of characters and `?` matches any single character.
+/// Empty pattern doesn't match any name.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    if pattern.is_empty() {
+        return false;
+    }
+
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+    let mut pattern_position = 0;
+    let mut name_position = 0;
+    // The position of the last `*` in the pattern and of the name character it's currently matched up to.
+    let mut backtrack = None;
+    while name_position < name.len() {
+        match pattern.get(pattern_position) {
+            Some('*') => {
+                backtrack = Some((pattern_position, name_position));
+                pattern_position += 1;
+            }
+            Some(character) if *character == '?' || *character == name[name_position] => {
+                pattern_position += 1;
+                name_position += 1;
+            }
+            _ => {
+                let Some((star_position, matched_position)) = backtrack else {
+                    return false;
+                };
+                pattern_position = star_position + 1;
+                name_position = matched_position + 1;
+                backtrack = Some((star_position, name_position));
+            }
+        }
+    }
+
+    pattern[pattern_position..]
+        .iter()
+        .all(|character| *character == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern_should_match_names() {
+        assert!(matches_pattern("*", "orders"));
+        assert!(matches_pattern("orders", "orders"));
+        assert!(matches_pattern("orders-*", "orders-eu"));
+        assert!(matches_pattern("orders-*", "orders-"));
+        assert!(matches_pattern("*-events-*", "eu-events-2024"));
+        assert!(matches_pattern("topic-?", "topic-1"));
+        assert!(matches_pattern("a*b*c", "aXbYbZc"));
+    }
+
+    #[test]
+    fn pattern_should_not_match_names() {
+        assert!(!matches_pattern("", "orders"));
+        assert!(!matches_pattern("orders", "orders-eu"));
+        assert!(!matches_pattern("orders-*", "payments-eu"));
+        assert!(!matches_pattern("topic-?", "topic-10"));
+        assert!(!matches_pattern("a*b*c", "aXbYbZ"));
+    }
+
+    #[test]
+    fn trigger_should_be_restored_from_context() {
+        for trigger in [
+            AutoCreateTrigger::SendMessages,
+            AutoCreateTrigger::PollMessages,
+        ] {
+            assert_eq!(
+                AutoCreateTrigger::from_context(&trigger.to_context()).unwrap(),
+                Some(trigger)
+            );
+        }
+        assert_eq!(AutoCreateTrigger::from_context(&[]).unwrap(), None);
+        assert!(AutoCreateTrigger::from_context(&[3]).is_err());
+    }
+}
@@ -0,0 +1,509 @@
+use crate::state::snapshot::StateSnapshot;
+use crate::streaming::backups::manifest::{BackupFileKind, BackupManifest};
+use crate::streaming::backups::{
+    backup_file, get_index_length, get_segment_log_length, get_state_log_length, restore_file,
+    truncate_file, PendingBackup, PendingBackupFile,
+};
+use crate::streaming::segments::{INDEX_EXTENSION, INDEX_SIZE, LOG_EXTENSION};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::backup::{BackupInfo, BackupKind, BackupRestoreTarget};
+use iggy::utils::timestamp::IggyTimestamp;
+use std::path::Path;
+use tracing::{error, info};
+
+impl System {
+    pub async fn get_backups(&self, session: &Session) -> Result<Vec<BackupInfo>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_backups(session.get_permissions_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get backups for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let manifests = BackupManifest::load_all(&self.config.get_backup_path()).await?;
+        Ok(manifests.iter().map(BackupManifest::to_info).collect())
+    }
+
+    /// Persists the buffered messages and captures the lengths of the state, segment and consumer offset files.
+    /// It must be invoked while no other command can modify the system (i.e. under the write lock),
+    /// so that the captured files represent the consistent point in time.
+    pub async fn prepare_backup(
+        &self,
+        session: &Session,
+        incremental: bool,
+    ) -> Result<PendingBackup, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .create_backup(session.get_permissions_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to create backup for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+        self.persist_messages().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to persist messages before backup")
+        })?;
+
+        let created_at = IggyTimestamp::now();
+        let parent = match incremental {
+            true => BackupManifest::load_all(&self.config.get_backup_path())
+                .await?
+                .pop(),
+            false => None,
+        };
+        let mut files = self.capture_state_files().await?;
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
+                    for segment in partition.get_segments() {
+                        let index_bytes = match Path::new(&segment.index_path).exists() {
+                            true => tokio::fs::read(&segment.index_path)
+                                .await
+                                .with_error_context(|error| {
+                                    format!(
+                                        "{COMPONENT} (error: {error}) - failed to read index file: {}",
+                                        segment.index_path
+                                    )
+                                })
+                                .map_err(|_| IggyError::CannotReadFile)?,
+                            false => Vec::new(),
+                        };
+                        // The batches referenced by the index are complete, so only the ones appended after the last of them are walked.
+                        let indexes_length = get_index_length(&index_bytes, u64::MAX) as usize;
+                        let start_position = match indexes_length {
+                            0 => 0,
+                            length => u32::from_le_bytes(
+                                index_bytes[length - INDEX_SIZE as usize + 4
+                                    ..length - INDEX_SIZE as usize + 8]
+                                    .try_into()
+                                    .unwrap(),
+                            ) as u64,
+                        };
+                        let log_length =
+                            get_segment_log_length(&segment.log_path, start_position, None).await?;
+                        files.push(self.get_pending_backup_file(
+                            &segment.log_path,
+                            BackupFileKind::SegmentLog,
+                            log_length,
+                        ));
+                        files.push(self.get_pending_backup_file(
+                            &segment.index_path,
+                            BackupFileKind::SegmentIndex,
+                            get_index_length(&index_bytes, log_length),
+                        ));
+                    }
+
+                    for offloaded_segment in partition.get_offloaded_segments() {
+                        let path = offloaded_segment.get_path(&self.config);
+                        let length = tokio::fs::metadata(&path)
+                            .await
+                            .with_error_context(|error| {
+                                format!("{COMPONENT} (error: {error}) - failed to get metadata of offloaded segment: {path}")
+                            })
+                            .map_err(|_| IggyError::CannotReadFileMetadata)?
+                            .len();
+                        files.push(self.get_pending_backup_file(
+                            &path,
+                            BackupFileKind::OffloadedSegment,
+                            length,
+                        ));
+                    }
+
+                    for offsets_path in [
+                        self.config.get_consumer_offsets_path(
+                            partition.stream_id,
+                            partition.topic_id,
+                            partition.partition_id,
+                        ),
+                        self.config.get_consumer_group_offsets_path(
+                            partition.stream_id,
+                            partition.topic_id,
+                            partition.partition_id,
+                        ),
+                    ] {
+                        files.extend(
+                            self.capture_directory_files(
+                                &offsets_path,
+                                BackupFileKind::ConsumerOffset,
+                            )
+                            .await?,
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(PendingBackup {
+            id: created_at.as_micros(),
+            created_at,
+            parent,
+            files,
+        })
+    }
+
+    /// Copies the files captured by `prepare_backup` and saves the manifest, which completes the backup.
+    pub async fn create_backup(&self, backup: PendingBackup) -> Result<BackupInfo, IggyError> {
+        let backup_path = format!("{}/{}", self.config.get_backup_path(), backup.id);
+        info!(
+            "Creating backup with ID: {}, files: {}, path: {backup_path}...",
+            backup.id,
+            backup.files.len()
+        );
+        let manifest = match self.save_backup_files(&backup, &backup_path).await {
+            Ok(manifest) => manifest,
+            Err(error) => {
+                error!("Failed to create backup with ID: {}. {error}", backup.id);
+                let _ = tokio::fs::remove_dir_all(&backup_path).await;
+                return Err(IggyError::CannotCreateBackup);
+            }
+        };
+
+        let backup = manifest.to_info();
+        info!(
+            "Created {} backup with ID: {}, files: {}, size: {}.",
+            backup.kind,
+            backup.id,
+            backup.files_count,
+            backup.size.as_human_string()
+        );
+        Ok(backup)
+    }
+
+    /// Restores the earliest backup created at or after the given point in time (or the latest one),
+    /// discarding the state entries and message batches created after the point in time.
+    pub async fn restore_backup(
+        &self,
+        session: &Session,
+        timestamp: Option<IggyTimestamp>,
+        target: &BackupRestoreTarget,
+    ) -> Result<BackupInfo, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .restore_backup(session.get_permissions_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to restore backup for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let backups_path = self.config.get_backup_path();
+        let manifests = BackupManifest::load_all(&backups_path).await?;
+        let manifest = match timestamp {
+            Some(timestamp) => manifests
+                .iter()
+                .find(|manifest| manifest.created_at.as_micros() >= timestamp.as_micros())
+                .or(manifests.last()),
+            None => manifests.last(),
+        }
+        .ok_or(IggyError::BackupNotFound(
+            timestamp
+                .map(|timestamp| timestamp.as_micros())
+                .unwrap_or_default(),
+        ))?;
+
+        let mut chain = vec![manifest];
+        while let Some(parent_id) = chain.last().unwrap().parent_id {
+            let parent = manifests
+                .iter()
+                .find(|manifest| manifest.id == parent_id)
+                .ok_or(IggyError::BackupNotFound(parent_id))?;
+            chain.push(parent);
+        }
+
+        let max_timestamp = timestamp
+            .map(|timestamp| timestamp.as_micros())
+            .filter(|timestamp| *timestamp < manifest.created_at.as_micros());
+        let directory = match target {
+            BackupRestoreTarget::Directory(directory) => {
+                ensure_empty_directory(directory).await?;
+                directory.to_owned()
+            }
+            BackupRestoreTarget::Archiver => {
+                if self.archiver.is_none() {
+                    error!("Archiver is disabled, backup with ID: {} cannot be restored using archiver.", manifest.id);
+                    return Err(IggyError::CannotRestoreBackup);
+                }
+
+                let directory = format!("{backups_path}/restore/{}", manifest.id);
+                if Path::new(&directory).exists() {
+                    tokio::fs::remove_dir_all(&directory)
+                        .await
+                        .with_error_context(|error| {
+                            format!("{COMPONENT} (error: {error}) - failed to remove previous restore directory: {directory}")
+                        })
+                        .map_err(|_| IggyError::CannotRestoreBackup)?;
+                }
+                directory
+            }
+        };
+
+        info!(
+            "Restoring backup with ID: {} to point in time: {}, target: {target}...",
+            manifest.id,
+            IggyTimestamp::from(max_timestamp.unwrap_or(manifest.created_at.as_micros()))
+        );
+        let result = self
+            .restore_backup_files(&backups_path, &chain, &directory, max_timestamp, target)
+            .await;
+        if matches!(target, BackupRestoreTarget::Archiver) || result.is_err() {
+            let _ = tokio::fs::remove_dir_all(&directory).await;
+        }
+        result.with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to restore backup with ID: {}, target: {target}",
+                manifest.id
+            )
+        })?;
+
+        info!(
+            "Restored backup with ID: {}, target: {target}.",
+            manifest.id
+        );
+        Ok(manifest.to_info())
+    }
+
+    async fn save_backup_files(
+        &self,
+        backup: &PendingBackup,
+        backup_path: &str,
+    ) -> Result<BackupManifest, IggyError> {
+        tokio::fs::create_dir_all(backup_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create backup directory: {backup_path}")
+            })
+            .map_err(|_| IggyError::CannotCreateBackup)?;
+
+        let mut files = Vec::with_capacity(backup.files.len());
+        for file in &backup.files {
+            let parent_file = backup
+                .parent
+                .as_ref()
+                .and_then(|parent| parent.get_file(&file.path));
+            files.push(backup_file(file, backup_path, parent_file).await?);
+        }
+
+        let manifest = BackupManifest {
+            id: backup.id,
+            kind: match backup.parent {
+                Some(_) => BackupKind::Incremental,
+                None => BackupKind::Full,
+            },
+            parent_id: backup.parent.as_ref().map(|parent| parent.id),
+            created_at: backup.created_at,
+            files,
+        };
+        manifest.save(backup_path).await?;
+        Ok(manifest)
+    }
+
+    async fn restore_backup_files(
+        &self,
+        backups_path: &str,
+        chain: &[&BackupManifest],
+        directory: &str,
+        max_timestamp: Option<u64>,
+        target: &BackupRestoreTarget,
+    ) -> Result<(), IggyError> {
+        let manifest = chain[0];
+        for file in &manifest.files {
+            restore_file(
+                backups_path,
+                chain,
+                file,
+                &format!("{directory}/{}", file.path),
+            )
+            .await?;
+        }
+
+        if let Some(max_timestamp) = max_timestamp {
+            for file in &manifest.files {
+                let path = format!("{directory}/{}", file.path);
+                match file.kind {
+                    BackupFileKind::StateSnapshot => {
+                        let bytes = read_file(&path).await?;
+                        let header = StateSnapshot::verify(&bytes)?;
+                        if header.timestamp.as_micros() > max_timestamp {
+                            error!(
+                                "State snapshot of backup with ID: {} was created at: {}, after the point in time to restore.",
+                                manifest.id, header.timestamp
+                            );
+                            return Err(IggyError::CannotRestoreBackup);
+                        }
+                    }
+                    BackupFileKind::StateLog => {
+                        let bytes = read_file(&path).await?;
+                        let length = get_state_log_length(&bytes, Some(max_timestamp));
+                        truncate_file(&path, length as u64).await?;
+                    }
+                    BackupFileKind::SegmentLog => {
+                        let log_length =
+                            get_segment_log_length(&path, 0, Some(max_timestamp)).await?;
+                        truncate_file(&path, log_length).await?;
+                        let index_path = format!(
+                            "{}.{INDEX_EXTENSION}",
+                            path.trim_end_matches(&format!(".{LOG_EXTENSION}"))
+                        );
+                        if Path::new(&index_path).exists() {
+                            let index_bytes = read_file(&index_path).await?;
+                            truncate_file(&index_path, get_index_length(&index_bytes, log_length))
+                                .await?;
+                        }
+                    }
+                    BackupFileKind::State
+                    | BackupFileKind::SegmentIndex
+                    | BackupFileKind::OffloadedSegment
+                    | BackupFileKind::ConsumerOffset => {}
+                }
+            }
+        }
+
+        if let BackupRestoreTarget::Archiver = target {
+            let archiver = self.archiver.as_ref().unwrap();
+            let files = manifest
+                .files
+                .iter()
+                .map(|file| format!("{directory}/{}", file.path))
+                .collect::<Vec<_>>();
+            let files = files.iter().map(String::as_str).collect::<Vec<_>>();
+            archiver
+                .archive(&files, Some(format!("{}_backup", manifest.id)))
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to archive restored backup with ID: {}", manifest.id)
+                })
+                .map_err(|_| IggyError::CannotRestoreBackup)?;
+        }
+        Ok(())
+    }
+
+    async fn capture_state_files(&self) -> Result<Vec<PendingBackupFile>, IggyError> {
+        let mut files = self
+            .capture_directory_files(&self.config.get_state_path(), BackupFileKind::State)
+            .await?;
+        for file in &mut files {
+            if file.source_path == self.config.get_state_log_path() {
+                let bytes = read_file(&file.source_path).await?;
+                file.kind = BackupFileKind::StateLog;
+                file.length = get_state_log_length(&bytes, None) as u64;
+            } else if file.source_path == self.config.get_state_snapshot_path() {
+                file.kind = BackupFileKind::StateSnapshot;
+            }
+        }
+        Ok(files)
+    }
+
+    async fn capture_directory_files(
+        &self,
+        directory: &str,
+        kind: BackupFileKind,
+    ) -> Result<Vec<PendingBackupFile>, IggyError> {
+        let mut files = Vec::new();
+        if !Path::new(directory).exists() {
+            return Ok(files);
+        }
+
+        let mut entries = tokio::fs::read_dir(directory)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read directory: {directory}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read entry of directory: {directory}"
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?
+        {
+            let metadata = entry
+                .metadata()
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get metadata of: {:?}",
+                        entry.path()
+                    )
+                })
+                .map_err(|_| IggyError::CannotReadFileMetadata)?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            // The temporary files (e.g. the state snapshot being saved) are not the part of the consistent state.
+            if !metadata.is_file() || name.ends_with(".tmp") {
+                continue;
+            }
+
+            files.push(self.get_pending_backup_file(
+                &format!("{directory}/{name}"),
+                kind,
+                metadata.len(),
+            ));
+        }
+        files.sort_by(|first, second| first.path.cmp(&second.path));
+        Ok(files)
+    }
+
+    fn get_pending_backup_file(
+        &self,
+        source_path: &str,
+        kind: BackupFileKind,
+        length: u64,
+    ) -> PendingBackupFile {
+        let system_path = self.config.get_system_path();
+        let path = source_path
+            .strip_prefix(&system_path)
+            .unwrap_or(source_path)
+            .trim_start_matches('/')
+            .to_owned();
+        PendingBackupFile {
+            source_path: source_path.to_owned(),
+            path,
+            kind,
+            length,
+        }
+    }
+}
+
+async fn read_file(path: &str) -> Result<Vec<u8>, IggyError> {
+    tokio::fs::read(path)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to read file: {path}")
+        })
+        .map_err(|_| IggyError::CannotReadFile)
+}
+
+async fn ensure_empty_directory(directory: &str) -> Result<(), IggyError> {
+    if !Path::new(directory).exists() {
+        return Ok(());
+    }
+
+    let mut entries = tokio::fs::read_dir(directory)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to read restore directory: {directory}")
+        })
+        .map_err(|_| IggyError::BackupRestoreTargetNotEmpty(directory.to_owned()))?;
+    let is_empty = entries
+        .next_entry()
+        .await
+        .map(|entry| entry.is_none())
+        .unwrap_or(false);
+    if !is_empty {
+        return Err(IggyError::BackupRestoreTargetNotEmpty(directory.to_owned()));
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::streaming::clients::client_manager::{Client, Transport};
+use crate::streaming::clients::rate_limiter::RateLimitDecision;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::systems::COMPONENT;
@@ -7,9 +8,10 @@ use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tracing::{error, info};
+use tracing::{debug, error, info};
 
 impl System {
     pub async fn add_client(&self, address: &SocketAddr, transport: Transport) -> Arc<Session> {
@@ -20,6 +22,26 @@ impl System {
         session
     }
 
+    /// Acquires the rate limit for the request of the given size sent by the client of the session.
+    pub fn acquire_rate_limit(&self, session: &Session, bytes: u64) -> RateLimitDecision {
+        if !self.rate_limiter.is_enabled() {
+            return RateLimitDecision::Allow;
+        }
+
+        let key = self.rate_limiter.resolve_key(
+            session.client_id,
+            session.get_user_id(),
+            session.ip_address.ip(),
+        );
+        let decision = self.rate_limiter.acquire(key, bytes, IggyTimestamp::now());
+        if let RateLimitDecision::Reject(retry_after) = decision {
+            debug!(
+                "Request from session: {session} was rate limited, retry after: {retry_after} µs."
+            );
+        }
+        decision
+    }
+
     pub async fn delete_client(&self, client_id: u32) {
         let consumer_groups: Vec<(u32, u32, u32)>;
 
@@ -65,7 +87,7 @@ impl System {
     ) -> Result<Option<IggySharedMut<Client>>, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .get_client(session.get_user_id())
+            .get_client(session.get_permissions_id())
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get client with ID: {client_id} by user ID: {}",
@@ -83,7 +105,7 @@ impl System {
     ) -> Result<Vec<IggySharedMut<Client>>, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .get_clients(session.get_user_id())
+            .get_clients(session.get_permissions_id())
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - failed to get clients by user ID {}",
@@ -22,7 +22,7 @@ impl System {
         };
 
         self.permissioner
-            .get_consumer_group(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .get_consumer_group(session.get_permissions_id(), topic.stream_id, topic.topic_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get consumer group with ID: {group_id} for user with ID: {} in topic with ID: {topic_id} and stream with ID: {stream_id}",
@@ -44,7 +44,7 @@ impl System {
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
 
         self.permissioner
-            .get_consumer_groups(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .get_consumer_groups(session.get_permissions_id(), topic.stream_id, topic.topic_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get consumer groups in topic with ID: {topic_id} and stream with ID: {stream_id} for user with ID: {}",
@@ -69,7 +69,7 @@ impl System {
                 .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
 
             self.permissioner.create_consumer_group(
-                session.get_user_id(),
+                session.get_permissions_id(),
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to create consumer group for user {} on stream_id: {}, topic_id: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
@@ -102,7 +102,7 @@ impl System {
                 .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
 
             self.permissioner.delete_consumer_group(
-                session.get_user_id(),
+                session.get_permissions_id(),
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to delete consumer group for user {} on stream_id: {}, topic_id: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
@@ -163,7 +163,7 @@ impl System {
                 })?;
 
             self.permissioner.join_consumer_group(
-                session.get_user_id(),
+                session.get_permissions_id(),
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to join consumer group for user {} on stream_id: {}, topic_id: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
@@ -234,7 +234,7 @@ impl System {
                 })?;
 
             self.permissioner.leave_consumer_group(
-                session.get_user_id(),
+                session.get_permissions_id(),
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to leave consumer group for user {} on stream_id: {}, topic_id: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
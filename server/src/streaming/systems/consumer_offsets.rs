@@ -21,7 +21,7 @@ impl System {
         let topic = self.find_topic(session, stream_id, topic_id)
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
         self.permissioner.store_consumer_offset(
-            session.get_user_id(),
+            session.get_permissions_id(),
             topic.stream_id,
             topic.topic_id,
         )?;
@@ -45,7 +45,7 @@ impl System {
         };
 
         self.permissioner.get_consumer_offset(
-            session.get_user_id(),
+            session.get_permissions_id(),
             topic.stream_id,
             topic.topic_id,
         ).with_error_context(|error| {
@@ -72,7 +72,7 @@ impl System {
         let topic = self.find_topic(session, stream_id, topic_id)
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
         self.permissioner.delete_consumer_offset(
-            session.get_user_id(),
+            session.get_permissions_id(),
             topic.stream_id,
             topic.topic_id,
         ).with_error_context(|error| {
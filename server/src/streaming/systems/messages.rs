@@ -1,7 +1,11 @@
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::models::messages::PolledMessageBatches;
+use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::users::quotas::ThroughputDirection;
 use bytes::Bytes;
 use error_set::ErrContext;
 use iggy::confirmation::Confirmation;
@@ -13,6 +17,7 @@ use iggy::models::messages::{PolledMessage, PolledMessages};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::sizeable::Sizeable;
 use iggy::{error::IggyError, identifier::Identifier};
+use std::sync::atomic::Ordering;
 use tracing::{error, trace};
 
 impl System {
@@ -25,6 +30,81 @@ impl System {
         partition_id: Option<u32>,
         args: PollingArgs,
     ) -> Result<PolledMessages, IggyError> {
+        // There might be no partition assigned, if it's the consumer group member without any partitions.
+        let Some((topic, polling_consumer, partition_id)) = self
+            .resolve_polling(session, consumer, stream_id, topic_id, partition_id, &args)
+            .await?
+        else {
+            return Ok(PolledMessages {
+                messages: vec![],
+                partition_id: 0,
+                current_offset: 0,
+            });
+        };
+
+        self.get_polled_messages(session, topic, polling_consumer, partition_id, &args)
+            .await
+    }
+
+    /// Polls the messages the same way as `poll_messages`, but returns the range of the segment file
+    /// holding the message batches instead, whenever they can be sent directly from the disk,
+    /// which requires the messages not to be encrypted, cached or offloaded.
+    pub async fn poll_message_batches(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        args: PollingArgs,
+    ) -> Result<PolledMessagesOrBatches, IggyError> {
+        let Some((topic, polling_consumer, partition_id)) = self
+            .resolve_polling(session, consumer, stream_id, topic_id, partition_id, &args)
+            .await?
+        else {
+            return Ok(PolledMessagesOrBatches::Messages(PolledMessages {
+                messages: vec![],
+                partition_id: 0,
+                current_offset: 0,
+            }));
+        };
+
+        if self.encryptor.is_none() {
+            let batches = topic
+                .get_message_batches(polling_consumer, partition_id, args.strategy, args.count)
+                .await?;
+            if let Some(batches) = batches {
+                let file_range = &batches.file_range;
+                self.complete_polling(
+                    session,
+                    topic,
+                    polling_consumer,
+                    partition_id,
+                    file_range.end_offset,
+                    file_range.length,
+                    file_range.messages_count() as u64,
+                    args.auto_commit,
+                )
+                .await?;
+                return Ok(PolledMessagesOrBatches::Batches(batches));
+            }
+        }
+
+        let messages = self
+            .get_polled_messages(session, topic, polling_consumer, partition_id, &args)
+            .await?;
+        Ok(PolledMessagesOrBatches::Messages(messages))
+    }
+
+    async fn resolve_polling(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        args: &PollingArgs,
+    ) -> Result<Option<(&Topic, PollingConsumer, u32)>, IggyError> {
         self.ensure_authenticated(session)?;
         if args.count == 0 {
             return Err(IggyError::InvalidMessagesCount);
@@ -32,7 +112,7 @@ impl System {
 
         let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
         self.permissioner
-            .poll_messages(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .poll_messages(session.get_permissions_id(), topic.stream_id, topic.topic_id)
             .with_error_context(|error| format!(
                 "{COMPONENT} (error: {error}) - permission denied to poll messages for user {} on stream_id: {}, topic_id: {}",
                 session.get_user_id(),
@@ -44,18 +124,24 @@ impl System {
             return Err(IggyError::NoPartitions(topic.topic_id, topic.stream_id));
         }
 
-        // There might be no partition assigned, if it's the consumer group member without any partitions.
-        let Some((polling_consumer, partition_id)) = topic
+        self.ensure_throughput_quota(session, ThroughputDirection::Poll)?;
+
+        let polling_consumer = topic
             .resolve_consumer_with_partition_id(consumer, session.client_id, partition_id, true)
             .await
-            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer: {consumer}, client ID: {}, partition ID: {:?}", session.client_id, partition_id))? else {
-            return Ok(PolledMessages {
-                messages: vec![],
-                partition_id: 0,
-                current_offset: 0,
-            })
-        };
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer: {consumer}, client ID: {}, partition ID: {:?}", session.client_id, partition_id))?;
+        Ok(polling_consumer
+            .map(|(polling_consumer, partition_id)| (topic, polling_consumer, partition_id)))
+    }
 
+    async fn get_polled_messages(
+        &self,
+        session: &Session,
+        topic: &Topic,
+        polling_consumer: PollingConsumer,
+        partition_id: u32,
+        args: &PollingArgs,
+    ) -> Result<PolledMessages, IggyError> {
         let mut polled_messages = topic
             .get_messages(polling_consumer, partition_id, args.strategy, args.count)
             .await?;
@@ -64,14 +150,21 @@ impl System {
             return Ok(polled_messages);
         }
 
-        let offset = polled_messages.messages.last().unwrap().offset;
-        if args.auto_commit {
-            trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, stream_id, topic_id, partition_id);
-            topic
-                .store_consumer_offset_internal(polling_consumer, offset, partition_id)
-                .await
-                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset internal, polling consumer: {}, offset: {}, partition ID: {}", polling_consumer, offset, partition_id)) ?;
-        }
+        self.complete_polling(
+            session,
+            topic,
+            polling_consumer,
+            partition_id,
+            polled_messages.messages.last().unwrap().offset,
+            polled_messages
+                .messages
+                .iter()
+                .map(|message| message.get_size_bytes().as_bytes_u64())
+                .sum(),
+            polled_messages.messages.len() as u64,
+            args.auto_commit,
+        )
+        .await?;
 
         if self.encryptor.is_none() {
             return Ok(polled_messages);
@@ -92,6 +185,7 @@ impl System {
                         length: IggyByteSize::from(payload.len() as u64),
                         payload: Bytes::from(payload),
                         headers: message.headers.clone(),
+                        key: message.key.clone(),
                     });
                 }
                 Err(error) => {
@@ -104,6 +198,35 @@ impl System {
         Ok(polled_messages)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    async fn complete_polling(
+        &self,
+        session: &Session,
+        topic: &Topic,
+        polling_consumer: PollingConsumer,
+        partition_id: u32,
+        offset: u64,
+        size_bytes: u64,
+        messages_count: u64,
+        auto_commit: bool,
+    ) -> Result<(), IggyError> {
+        self.consume_throughput_quota(
+            session,
+            ThroughputDirection::Poll,
+            size_bytes,
+            messages_count,
+        );
+
+        if auto_commit {
+            trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, polling_consumer, topic.stream_id, topic.topic_id, partition_id);
+            topic
+                .store_consumer_offset_internal(polling_consumer, offset, partition_id)
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset internal, polling consumer: {}, offset: {}, partition ID: {}", polling_consumer, offset, partition_id)) ?;
+        }
+        Ok(())
+    }
+
     pub async fn append_messages(
         &self,
         session: &Session,
@@ -116,7 +239,7 @@ impl System {
         self.ensure_authenticated(session)?;
         let topic = self.find_topic(session, &stream_id, &topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
         self.permissioner.append_messages(
-            session.get_user_id(),
+            session.get_permissions_id(),
             topic.stream_id,
             topic.topic_id,
         ).with_error_context(|error| format!(
@@ -125,12 +248,28 @@ impl System {
             topic.stream_id,
             topic.topic_id
         ))?;
+        self.ensure_throughput_quota(session, ThroughputDirection::Send)?;
+        self.ensure_stream_size_quota(
+            session,
+            topic.stream_id,
+            topic.size_of_parent_stream.load(Ordering::SeqCst),
+        )?;
+
+        topic
+            .schemas
+            .validate_messages(topic.stream_id, topic.topic_id, &messages)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - invalid messages for stream_id: {}, topic_id: {}", topic.stream_id, topic.topic_id))?;
 
         let mut batch_size_bytes = IggyByteSize::default();
         let mut messages = messages;
+        // All the messages are encrypted with the same key, even if it's rotated in the meantime.
+        let encryption_key_id = self
+            .encryptor
+            .as_ref()
+            .and_then(|encryptor| encryptor.current_key_id());
         if let Some(encryptor) = &self.encryptor {
             for message in messages.iter_mut() {
-                let payload = encryptor.encrypt(&message.payload);
+                let payload = encryptor.encrypt_with_key(&message.payload, encryption_key_id);
                 match payload {
                     Ok(payload) => {
                         message.payload = Bytes::from(payload);
@@ -157,8 +296,20 @@ impl System {
         }
         let messages_count = messages.len() as u64;
         topic
-            .append_messages(batch_size_bytes, partitioning, messages, confirmation)
+            .append_messages(
+                batch_size_bytes,
+                partitioning,
+                messages,
+                encryption_key_id,
+                confirmation,
+            )
             .await?;
+        self.consume_throughput_quota(
+            session,
+            ThroughputDirection::Send,
+            batch_size_bytes.as_bytes_u64(),
+            messages_count,
+        );
         self.metrics.increment_messages(messages_count);
         Ok(())
     }
@@ -175,7 +326,7 @@ impl System {
         let topic = self.find_topic(session, &stream_id, &topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
         // Reuse those permissions as if you can append messages you can flush them
         self.permissioner.append_messages(
-            session.get_user_id(),
+            session.get_permissions_id(),
             topic.stream_id,
             topic.topic_id,
         ).with_error_context(|error| format!(
@@ -189,6 +340,12 @@ impl System {
     }
 }
 
+#[derive(Debug)]
+pub enum PolledMessagesOrBatches {
+    Messages(PolledMessages),
+    Batches(PolledMessageBatches),
+}
+
 #[derive(Debug)]
 pub struct PollingArgs {
     pub strategy: PollingStrategy,
@@ -1,15 +1,22 @@
+pub mod auto_create;
+pub mod backups;
 pub mod clients;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod info;
 pub mod messages;
 pub mod partitions;
+pub mod partitions_merge;
 pub mod personal_access_tokens;
+pub mod quotas;
+pub mod replication;
+pub mod schemas;
 pub mod snapshot;
 pub mod stats;
 pub mod storage;
 pub mod streams;
 pub mod system;
+pub mod topic_templates;
 pub mod topics;
 pub mod users;
 
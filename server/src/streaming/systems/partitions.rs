@@ -16,8 +16,10 @@ impl System {
         self.ensure_authenticated(session)?;
         {
             let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+            self.ensure_partitions_quota(topic.created_by, partitions_count)?;
+            topic.ensure_no_partitions_merge().await?;
             self.permissioner.create_partitions(
-                session.get_user_id(),
+                session.get_permissions_id(),
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!(
@@ -58,8 +60,9 @@ impl System {
         self.ensure_authenticated(session)?;
         {
             let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+            topic.ensure_no_partitions_merge().await?;
             self.permissioner.delete_partitions(
-                session.get_user_id(),
+                session.get_permissions_id(),
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!(
@@ -0,0 +1,500 @@
+use crate::state::command::EntryCommand;
+use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
+use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::topic::Topic;
+use ahash::AHashMap;
+use error_set::ErrContext;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::messages::send_messages::Message;
+use iggy::models::partitions_merge::{PartitionsMergeInfo, PartitionsMergeStatus};
+use iggy::models::user_info::UserId;
+use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::sizeable::Sizeable;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::HashMap;
+use tracing::{error, info};
+
+const MESSAGES_TO_MOVE_AT_ONCE: u32 = 1000;
+
+/// The range of the messages of the merged partition, which has been appended to the target partition at once.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MovedMessages {
+    source_partition_id: u32,
+    source_offset: u64,
+    target_offset: u64,
+    count: u64,
+}
+
+/// The partitions detached from the topic, whose messages are going to be moved to the remaining partitions.
+pub struct PartitionsMerge {
+    stream_id: u32,
+    topic_id: u32,
+    user_id: UserId,
+    remaining_partitions_count: u32,
+    partitions: Vec<IggySharedMut<Partition>>,
+    progress: IggySharedMut<PartitionsMergeInfo>,
+}
+
+impl System {
+    /// Detaches the last partitions from the topic, so that no messages can be appended to them anymore,
+    /// and returns the merge which has to be run in the background to move their messages.
+    pub async fn merge_partitions(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<PartitionsMerge, IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+            self.permissioner.delete_partitions(
+                session.get_permissions_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - permission denied to merge partitions for user {} on stream_id: {}, topic_id: {}",
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id
+            ))?;
+        }
+
+        let topic = self
+            .get_stream_mut(stream_id)?
+            .get_topic_mut(topic_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to get mutable reference to stream with id: {stream_id}"
+                )
+            })?;
+        topic.ensure_no_partitions_merge().await?;
+        let current_partitions_count = topic.get_partitions_count();
+        if partitions_count == 0 || partitions_count >= current_partitions_count {
+            return Err(IggyError::CannotMergePartitions(
+                partitions_count,
+                topic.topic_id,
+                topic.stream_id,
+            ));
+        }
+
+        let partitions = topic.detach_partitions(partitions_count);
+        topic.reassign_consumer_groups().await;
+        let mut messages_count = 0;
+        for partition in &partitions {
+            messages_count += partition.read().await.get_messages_count();
+        }
+
+        let progress = IggySharedMut::new(PartitionsMergeInfo {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            status: PartitionsMergeStatus::InProgress,
+            partitions_count,
+            merged_partitions: 0,
+            messages_count,
+            merged_messages: 0,
+            started_at: IggyTimestamp::now(),
+            finished_at: None,
+        });
+        topic.partitions_merge = Some(progress.clone());
+        info!(
+            "Started merging {partitions_count} partitions with {messages_count} messages into the remaining {} partitions of topic: {topic}.",
+            current_partitions_count - partitions_count
+        );
+        Ok(PartitionsMerge {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            user_id: session.get_user_id(),
+            remaining_partitions_count: current_partitions_count - partitions_count,
+            partitions,
+            progress,
+        })
+    }
+
+    pub async fn get_partitions_merge(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Option<PartitionsMergeInfo>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner.get_topic(
+            session.get_permissions_id(),
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| format!(
+            "{COMPONENT} (error: {error}) - permission denied to get partitions merge for user {} on stream_id: {}, topic_id: {}",
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id
+        ))?;
+
+        match &topic.partitions_merge {
+            Some(progress) => Ok(Some(progress.read().await.clone())),
+            None => Ok(None),
+        }
+    }
+}
+
+impl PartitionsMerge {
+    pub async fn get_info(&self) -> PartitionsMergeInfo {
+        self.progress.read().await.clone()
+    }
+
+    /// Appends the messages of the detached partitions to the remaining ones, remaps the consumer group offsets
+    /// and deletes the detached partitions. The messages of the partition are appended to the partition
+    /// calculated in the same way as for the messages key hash, so their order is preserved, but they get
+    /// the new offsets and timestamps. If the merge fails (or the server is stopped before it's completed),
+    /// the detached partitions are kept, thus the messages might be duplicated, but never lost.
+    pub async fn run(self, system: SharedSystem) {
+        let result = match self.move_messages(&system).await {
+            Ok(moved_messages) => self.complete(&system, &moved_messages).await,
+            Err(error) => Err(error),
+        };
+
+        let mut progress = self.progress.write().await;
+        progress.finished_at = Some(IggyTimestamp::now());
+        match result {
+            Ok(()) => {
+                progress.status = PartitionsMergeStatus::Completed;
+                info!(
+                    "Merged {} partitions with {} messages for stream with ID: {}, topic with ID: {}.",
+                    progress.partitions_count, progress.merged_messages, self.stream_id, self.topic_id
+                );
+            }
+            Err(error) => {
+                progress.status = PartitionsMergeStatus::Failed;
+                error!(
+                    "Failed to merge partitions for stream with ID: {}, topic with ID: {}. Error: {error}",
+                    self.stream_id, self.topic_id
+                );
+                drop(progress);
+                self.restore_partitions(&system).await;
+            }
+        }
+    }
+
+    async fn move_messages(&self, system: &SharedSystem) -> Result<Vec<MovedMessages>, IggyError> {
+        let mut moved_messages = Vec::new();
+        for partition in &self.partitions {
+            let partition = partition.read().await;
+            let target_partition_id =
+                get_target_partition_id(partition.partition_id, self.remaining_partitions_count);
+            let mut offset = 0;
+            loop {
+                let messages = partition
+                    .get_messages_by_offset(offset, MESSAGES_TO_MOVE_AT_ONCE)
+                    .await
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to get messages to merge from partition: {partition}, offset: {offset}"
+                        )
+                    })?;
+                let (Some(first), Some(last)) = (messages.first(), messages.last()) else {
+                    break;
+                };
+
+                let source_offset = first.offset;
+                offset = last.offset + 1;
+                let mut batch_size = IggyByteSize::default();
+                let mut messages_to_append = Vec::with_capacity(messages.len());
+                for message in messages.iter() {
+                    let headers = message
+                        .headers
+                        .clone()
+                        .map(HashMap::from_bytes)
+                        .transpose()?;
+                    let message = Message {
+                        key: message.key.clone(),
+                        ..Message::new(Some(message.id), message.payload.clone(), headers)
+                    };
+                    batch_size += message.get_size_bytes();
+                    messages_to_append.push(message);
+                }
+
+                let system = system.read().await;
+                let topic = self.get_topic(&system)?;
+                if let Some(memory_tracker) = CacheMemoryTracker::get_instance() {
+                    if !memory_tracker.will_fit_into_cache(batch_size) {
+                        system.clean_cache(batch_size).await;
+                    }
+                }
+
+                let target_partition = topic.get_partition(target_partition_id)?;
+                let mut target_partition = target_partition.write().await;
+                let target_offset = match target_partition.should_increment_offset {
+                    true => target_partition.current_offset + 1,
+                    false => 0,
+                };
+                target_partition
+                    .append_messages(
+                        AppendableBatchInfo::new(batch_size, target_partition_id),
+                        messages_to_append,
+                        None,
+                    )
+                    .await
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to append merged messages to partition: {target_partition}"
+                        )
+                    })?;
+
+                // Some of the messages might have been skipped by the deduplicator of the target partition.
+                let count = match target_partition.should_increment_offset {
+                    true if target_partition.current_offset >= target_offset => {
+                        target_partition.current_offset - target_offset + 1
+                    }
+                    _ => 0,
+                };
+                system.metrics.increment_messages(count);
+                moved_messages.push(MovedMessages {
+                    source_partition_id: partition.partition_id,
+                    source_offset,
+                    target_offset,
+                    count,
+                });
+                self.progress.write().await.merged_messages += messages.len() as u64;
+            }
+            self.progress.write().await.merged_partitions += 1;
+        }
+        Ok(moved_messages)
+    }
+
+    async fn complete(
+        &self,
+        system: &SharedSystem,
+        moved_messages: &[MovedMessages],
+    ) -> Result<(), IggyError> {
+        let system = system.write().await;
+        let topic = self.get_topic(&system)?;
+        let mut source_offsets = AHashMap::new();
+        for partition in &self.partitions {
+            let partition = partition.read().await;
+            for consumer_offset in partition.consumer_group_offsets.iter() {
+                source_offsets.insert(
+                    (partition.partition_id, *consumer_offset.key()),
+                    consumer_offset.offset,
+                );
+            }
+        }
+
+        for target_partition_id in 1..=self.remaining_partitions_count {
+            let moved_messages = moved_messages
+                .iter()
+                .filter(|moved| {
+                    get_target_partition_id(
+                        moved.source_partition_id,
+                        self.remaining_partitions_count,
+                    ) == target_partition_id
+                })
+                .copied()
+                .collect::<Vec<_>>();
+            if moved_messages.is_empty() {
+                continue;
+            }
+
+            let target_partition = topic.get_partition(target_partition_id)?;
+            let target_partition = target_partition.read().await;
+            let mut consumer_group_ids = source_offsets
+                .keys()
+                .map(|(_, consumer_group_id)| *consumer_group_id)
+                .collect::<Vec<_>>();
+            consumer_group_ids.sort_unstable();
+            consumer_group_ids.dedup();
+            for consumer_group_id in consumer_group_ids {
+                let current_offset = target_partition
+                    .consumer_group_offsets
+                    .get(&consumer_group_id)
+                    .map(|consumer_offset| consumer_offset.offset);
+                let Some(offset) =
+                    remap_offset(current_offset, &moved_messages, |source_partition_id| {
+                        source_offsets
+                            .get(&(source_partition_id, consumer_group_id))
+                            .copied()
+                    })
+                else {
+                    continue;
+                };
+
+                target_partition
+                    .store_consumer_offset(PollingConsumer::ConsumerGroup(consumer_group_id, 0), offset)
+                    .await
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to store remapped offset: {offset} for consumer group with ID: {consumer_group_id}, partition: {target_partition}"
+                        )
+                    })?;
+            }
+        }
+
+        let mut segments_count = 0;
+        let mut messages_count = 0;
+        for partition in &self.partitions {
+            let mut partition = partition.write().await;
+            segments_count += partition.get_segments_count();
+            messages_count += partition.get_messages_count();
+            partition.delete().await.with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to delete merged partition: {partition}"
+                )
+            })?;
+        }
+        let partitions_count = self.partitions.len() as u32;
+        system.metrics.decrement_partitions(partitions_count);
+        system.metrics.decrement_segments(segments_count);
+        system.metrics.decrement_messages(messages_count);
+
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                self.user_id,
+                EntryCommand::DeletePartitions(DeletePartitions {
+                    stream_id: Identifier::numeric(self.stream_id)?,
+                    topic_id: Identifier::numeric(self.topic_id)?,
+                    partitions_count,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to apply delete merged partitions for stream_id: {}, topic_id: {}",
+                    self.stream_id, self.topic_id
+                )
+            })
+    }
+
+    /// Attaches the partitions back to the topic (if it still exists), as they haven't been deleted.
+    async fn restore_partitions(&self, system: &SharedSystem) {
+        let mut system = system.write().await;
+        let Ok(stream_id) = Identifier::numeric(self.stream_id) else {
+            return;
+        };
+        let Ok(topic_id) = Identifier::numeric(self.topic_id) else {
+            return;
+        };
+        let Ok(topic) = system
+            .get_stream_mut(&stream_id)
+            .and_then(|stream| stream.get_topic_mut(&topic_id))
+        else {
+            return;
+        };
+
+        topic.attach_partitions(self.partitions.clone()).await;
+        topic.reassign_consumer_groups().await;
+    }
+
+    fn get_topic<'a>(&self, system: &'a System) -> Result<&'a Topic, IggyError> {
+        system
+            .get_stream(&Identifier::numeric(self.stream_id)?)?
+            .get_topic(&Identifier::numeric(self.topic_id)?)
+    }
+}
+
+/// Returns the ID of the remaining partition, the messages of the merged partition are appended to,
+/// in the same way as the partition ID is calculated for the messages key hash.
+fn get_target_partition_id(partition_id: u32, remaining_partitions_count: u32) -> u32 {
+    match partition_id % remaining_partitions_count {
+        0 => remaining_partitions_count,
+        partition_id => partition_id,
+    }
+}
+
+/// Returns the new offset of the consumer group for the target partition, if it has to be moved forward.
+/// The offset is moved only over the messages which have been already consumed from the merged partitions,
+/// so the messages appended to the target partition by the producers are never skipped.
+fn remap_offset(
+    current_offset: Option<u64>,
+    moved_messages: &[MovedMessages],
+    get_source_offset: impl Fn(u32) -> Option<u64>,
+) -> Option<u64> {
+    let mut next_offset = current_offset.map_or(0, |offset| offset + 1);
+    for moved in moved_messages {
+        if moved.target_offset > next_offset {
+            break;
+        }
+
+        let consumed_count = match get_source_offset(moved.source_partition_id) {
+            Some(offset) if offset >= moved.source_offset => {
+                (offset - moved.source_offset + 1).min(moved.count)
+            }
+            _ => 0,
+        };
+        next_offset = next_offset.max(moved.target_offset + consumed_count);
+        if consumed_count < moved.count {
+            break;
+        }
+    }
+
+    let offset = next_offset.checked_sub(1)?;
+    match current_offset {
+        Some(current_offset) if offset <= current_offset => None,
+        _ => Some(offset),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moved(
+        source_partition_id: u32,
+        source_offset: u64,
+        target_offset: u64,
+        count: u64,
+    ) -> MovedMessages {
+        MovedMessages {
+            source_partition_id,
+            source_offset,
+            target_offset,
+            count,
+        }
+    }
+
+    #[test]
+    fn should_calculate_target_partition_id_as_for_messages_key_hash() {
+        assert_eq!(get_target_partition_id(4, 3), 1);
+        assert_eq!(get_target_partition_id(5, 3), 2);
+        assert_eq!(get_target_partition_id(6, 3), 3);
+        assert_eq!(get_target_partition_id(7, 3), 1);
+        assert_eq!(get_target_partition_id(2, 1), 1);
+    }
+
+    #[test]
+    fn should_move_offset_over_consumed_merged_messages() {
+        let moved_messages = [moved(4, 0, 10, 5), moved(4, 5, 15, 5)];
+        let offset = remap_offset(Some(9), &moved_messages, |_| Some(7));
+        assert_eq!(offset, Some(17));
+    }
+
+    #[test]
+    fn should_not_skip_messages_not_consumed_from_target_partition() {
+        let moved_messages = [moved(4, 0, 10, 5)];
+        let offset = remap_offset(Some(5), &moved_messages, |_| Some(4));
+        assert_eq!(offset, None);
+    }
+
+    #[test]
+    fn should_stop_at_messages_appended_between_moved_messages() {
+        let moved_messages = [moved(4, 0, 0, 5), moved(4, 5, 8, 5)];
+        let offset = remap_offset(None, &moved_messages, |_| Some(9));
+        assert_eq!(offset, Some(4));
+    }
+
+    #[test]
+    fn should_not_move_offset_when_merged_messages_were_not_consumed() {
+        let moved_messages = [moved(4, 0, 10, 5)];
+        assert_eq!(remap_offset(Some(9), &moved_messages, |_| None), None);
+        assert_eq!(remap_offset(Some(12), &moved_messages, |_| Some(1)), None);
+    }
+}
@@ -5,8 +5,11 @@ use crate::streaming::systems::COMPONENT;
 use crate::streaming::users::user::User;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::models::permissions::Permissions;
+use iggy::models::user_info::UserId;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::timestamp::IggyTimestamp;
+use std::net::IpAddr;
 use tracing::{error, info};
 
 impl System {
@@ -35,30 +38,64 @@ impl System {
         session: &Session,
         name: &str,
         expiry: IggyExpiry,
+        permissions: Option<Permissions>,
     ) -> Result<String, IggyError> {
-        self.ensure_authenticated(session)?;
+        self.validate_personal_access_token_creation(session, name)?;
         let user_id = session.get_user_id();
-        let identifier = user_id.try_into()?;
-        {
-            let user = self.get_user(&identifier).with_error_context(|error| {
+        let user = self
+            .get_user_mut(&user_id.try_into()?)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
+            })?;
+        info!("Creating personal access token: {name} for user with ID: {user_id}...");
+        let (personal_access_token, token) = PersonalAccessToken::new(
+            user_id,
+            name,
+            IggyTimestamp::now(),
+            expiry,
+            permissions.clone(),
+        );
+        user.personal_access_tokens
+            .insert(personal_access_token.token.clone(), personal_access_token);
+        self.permissioner
+            .init_permissions_for_personal_access_token(user_id, name, permissions);
+        info!("Created personal access token: {name} for user with ID: {user_id}.");
+        Ok(token)
+    }
+
+    /// Checks if the personal access token can be created for the user of the session,
+    /// in the cluster it's checked by the leader before the hash of the generated token is replicated.
+    pub fn validate_personal_access_token_creation(
+        &self,
+        session: &Session,
+        name: &str,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.ensure_not_scoped(session)?;
+        self.ensure_personal_access_token_can_be_created(session.get_user_id(), name)
+    }
+
+    fn ensure_personal_access_token_can_be_created(
+        &self,
+        user_id: UserId,
+        name: &str,
+    ) -> Result<(), IggyError> {
+        let user = self
+            .get_user(&user_id.try_into()?)
+            .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
             })?;
-            let max_token_per_user = self.personal_access_token.max_tokens_per_user;
-            if user.personal_access_tokens.len() as u32 >= max_token_per_user {
-                error!(
+        let max_token_per_user = self.personal_access_token.max_tokens_per_user;
+        if user.personal_access_tokens.len() as u32 >= max_token_per_user {
+            error!(
                 "User with ID: {user_id} has reached the maximum number of personal access tokens: {max_token_per_user}.",
             );
-                return Err(IggyError::PersonalAccessTokensLimitReached(
-                    user_id,
-                    max_token_per_user,
-                ));
-            }
+            return Err(IggyError::PersonalAccessTokensLimitReached(
+                user_id,
+                max_token_per_user,
+            ));
         }
 
-        let user = self.get_user_mut(&identifier).with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
-        })?;
-
         if user
             .personal_access_tokens
             .values()
@@ -71,13 +108,38 @@ impl System {
             ));
         }
 
-        info!("Creating personal access token: {name} for user with ID: {user_id}...");
-        let (personal_access_token, token) =
-            PersonalAccessToken::new(user_id, name, IggyTimestamp::now(), expiry);
+        Ok(())
+    }
+
+    /// Creates the personal access token with the already hashed token, e.g. replicated from the cluster leader.
+    pub(crate) fn create_personal_access_token_with_hash(
+        &mut self,
+        user_id: UserId,
+        name: &str,
+        token_hash: &str,
+        created_at: IggyTimestamp,
+        expiry: IggyExpiry,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError> {
+        self.ensure_personal_access_token_can_be_created(user_id, name)?;
+        let user = self
+            .get_user_mut(&user_id.try_into()?)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
+            })?;
+        let personal_access_token = PersonalAccessToken::raw(
+            user_id,
+            name,
+            token_hash,
+            PersonalAccessToken::calculate_expiry_at(created_at, expiry),
+            permissions.clone(),
+        );
         user.personal_access_tokens
             .insert(personal_access_token.token.clone(), personal_access_token);
+        self.permissioner
+            .init_permissions_for_personal_access_token(user_id, name, permissions);
         info!("Created personal access token: {name} for user with ID: {user_id}.");
-        Ok(token)
+        Ok(())
     }
 
     pub async fn delete_personal_access_token(
@@ -86,6 +148,7 @@ impl System {
         name: &str,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        self.ensure_not_scoped(session)?;
         let user_id = session.get_user_id();
         let user = self
             .get_user_mut(&user_id.try_into()?)
@@ -112,6 +175,8 @@ impl System {
 
         info!("Deleting personal access token: {name} for user with ID: {user_id}...");
         user.personal_access_tokens.remove(&token);
+        self.permissioner
+            .delete_permissions_for_personal_access_token(user_id, name);
         info!("Deleted personal access token: {name} for user with ID: {user_id}.");
         Ok(())
     }
@@ -119,8 +184,11 @@ impl System {
     pub async fn login_with_personal_access_token(
         &self,
         token: &str,
+        ip_address: IpAddr,
         session: Option<&Session>,
     ) -> Result<&User, IggyError> {
+        self.login_attempts
+            .ensure_not_locked(None, Some(ip_address), IggyTimestamp::now())?;
         let token_hash = PersonalAccessToken::hash_token(token);
         let mut personal_access_token = None;
         for user in self.users.values() {
@@ -132,6 +200,7 @@ impl System {
 
         if personal_access_token.is_none() {
             error!("Personal access token: {} does not exist.", token);
+            self.register_failed_login(None, ip_address);
             return Err(IggyError::ResourceNotFound(token.to_owned()));
         }
 
@@ -155,7 +224,41 @@ impl System {
                     personal_access_token.user_id
                 )
             })?;
-        self.login_user_with_credentials(&user.username, None, session)
-            .await
+        let user = self
+            .login_user_with_credentials(&user.username, None, ip_address, session)
+            .await?;
+        if let Some(session) = session {
+            if let Some(permissions_id) = self.get_personal_access_token_permissions_id(
+                personal_access_token.user_id,
+                &personal_access_token.name,
+            ) {
+                session.set_permissions_id(permissions_id);
+            }
+        }
+        Ok(user)
+    }
+
+    /// Returns the ID of the principal restricted to the permissions of the scoped personal access token,
+    /// or `None` if the token is not scoped and grants all the permissions of its owner.
+    pub fn get_personal_access_token_permissions_id(
+        &self,
+        user_id: UserId,
+        name: &str,
+    ) -> Option<UserId> {
+        self.permissioner
+            .get_personal_access_token_principal_id(user_id, name)
+    }
+
+    /// The session authenticated with the scoped personal access token must not be able to manage the tokens,
+    /// otherwise it could create a new token without any restrictions.
+    pub(crate) fn ensure_not_scoped(&self, session: &Session) -> Result<(), IggyError> {
+        if session.is_scoped() {
+            error!(
+                "{COMPONENT} - personal access tokens cannot be managed using the scoped personal access token, session: {session}"
+            );
+            return Err(IggyError::Unauthorized);
+        }
+
+        Ok(())
     }
 }
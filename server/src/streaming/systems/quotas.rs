@@ -0,0 +1,160 @@
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::quotas::ThroughputDirection;
+use iggy::error::IggyError;
+use iggy::models::user_info::{UserId, UserUsage};
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::debug;
+
+impl System {
+    pub fn get_user_usage(&self, user_id: UserId) -> UserUsage {
+        let mut usage = UserUsage {
+            max_streams: self.config.quotas.max_streams_per_user,
+            max_topics: self.config.quotas.max_topics_per_user,
+            max_partitions: self.config.quotas.max_partitions_per_user,
+            ..Default::default()
+        };
+        for stream in self.streams.values() {
+            if stream.created_by == user_id {
+                usage.streams_count += 1;
+            }
+            for topic in stream.topics.values() {
+                if topic.created_by == user_id {
+                    usage.topics_count += 1;
+                    usage.partitions_count += topic.get_partitions_count();
+                }
+            }
+        }
+        usage
+    }
+
+    pub(crate) fn ensure_streams_quota(&self, session: &Session) -> Result<(), IggyError> {
+        let user_id = session.get_user_id();
+        let max_streams = self.config.quotas.max_streams_per_user;
+        if self.is_exempt_from_quotas(user_id) || max_streams == 0 {
+            return Ok(());
+        }
+
+        if self.get_user_usage(user_id).streams_count >= max_streams {
+            return self.reject(IggyError::StreamsQuotaExceeded(user_id, max_streams));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn ensure_topics_quota(
+        &self,
+        session: &Session,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        let user_id = session.get_user_id();
+        if self.is_exempt_from_quotas(user_id) {
+            return Ok(());
+        }
+
+        let max_topics = self.config.quotas.max_topics_per_user;
+        if max_topics > 0 && self.get_user_usage(user_id).topics_count >= max_topics {
+            return self.reject(IggyError::TopicsQuotaExceeded(user_id, max_topics));
+        }
+
+        self.ensure_partitions_quota(user_id, partitions_count)
+    }
+
+    /// The partitions are accounted to the owner of the topic, regardless of who is creating them.
+    pub(crate) fn ensure_partitions_quota(
+        &self,
+        owner_id: UserId,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        let max_partitions = self.config.quotas.max_partitions_per_user;
+        if self.is_exempt_from_quotas(owner_id) || max_partitions == 0 {
+            return Ok(());
+        }
+
+        let partitions_count = self
+            .get_user_usage(owner_id)
+            .partitions_count
+            .saturating_add(partitions_count);
+        if partitions_count > max_partitions {
+            return self.reject(IggyError::PartitionsQuotaExceeded(owner_id, max_partitions));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn ensure_stream_size_quota(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        stream_size_bytes: u64,
+    ) -> Result<(), IggyError> {
+        let max_stream_size = self.config.quotas.max_stream_size.as_bytes_u64();
+        if self.is_exempt_from_quotas(session.get_user_id()) || max_stream_size == 0 {
+            return Ok(());
+        }
+
+        if stream_size_bytes >= max_stream_size {
+            return self.reject(IggyError::StreamSizeQuotaExceeded(
+                stream_id,
+                max_stream_size,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn ensure_throughput_quota(
+        &self,
+        session: &Session,
+        direction: ThroughputDirection,
+    ) -> Result<(), IggyError> {
+        let user_id = session.get_user_id();
+        if self.is_exempt_from_quotas(user_id) {
+            return Ok(());
+        }
+
+        if let Err(error) = self.throughput_quota.ensure_available(
+            user_id,
+            session.client_id,
+            direction,
+            IggyTimestamp::now(),
+        ) {
+            return self.reject(error);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn consume_throughput_quota(
+        &self,
+        session: &Session,
+        direction: ThroughputDirection,
+        bytes: u64,
+        messages: u64,
+    ) {
+        let user_id = session.get_user_id();
+        if self.is_exempt_from_quotas(user_id) {
+            return;
+        }
+
+        self.throughput_quota.consume(
+            user_id,
+            session.client_id,
+            direction,
+            bytes,
+            messages,
+            IggyTimestamp::now(),
+        );
+    }
+
+    fn is_exempt_from_quotas(&self, user_id: UserId) -> bool {
+        !self.config.quotas.enabled || user_id == DEFAULT_ROOT_USER_ID
+    }
+
+    fn reject(&self, error: IggyError) -> Result<(), IggyError> {
+        debug!("Request rejected due to the exceeded quota: {error}");
+        self.metrics.increment_quota_violations();
+        Err(error)
+    }
+}
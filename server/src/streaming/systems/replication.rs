@@ -0,0 +1,401 @@
+use crate::cluster::node::ClusterNode;
+use crate::state::command::EntryCommand;
+use crate::state::entry::StateEntry;
+use crate::streaming::session::Session;
+use crate::streaming::systems::auto_create::AutoCreateTrigger;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::topic_template::{TopicDefaults, TopicTemplate};
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use tracing::debug;
+
+impl SharedSystem {
+    /// Returns the node of the cluster, if the clustering is enabled. In such a case, the commands changing the state
+    /// must not be applied to the in-memory state directly, but replicated with [`ClusterNode::replicate`] instead.
+    pub async fn cluster_node(&self) -> Option<Arc<ClusterNode>> {
+        self.read().await.cluster_node()
+    }
+
+    /// Checks if the user of the session is allowed to invoke the command and replicates it with the permissions
+    /// of the session, e.g. the ones of the scoped personal access token.
+    pub async fn replicate(
+        &self,
+        cluster_node: &ClusterNode,
+        session: &Session,
+        command: EntryCommand,
+    ) -> Result<(), IggyError> {
+        self.read()
+            .await
+            .authorize_replicated_command(session, &command)?;
+        cluster_node
+            .replicate(session.get_user_id(), session.get_permissions_id(), command)
+            .await
+    }
+}
+
+impl System {
+    /// Applies the committed state entry on every node of the cluster (including the leader which proposed it),
+    /// using the stateless session of the user who invoked the command, so the command is validated in the log order.
+    /// Only the checks requiring the plain passwords are done by the leader, before the command is proposed.
+    pub async fn apply_replicated_entry(&mut self, entry: &StateEntry) -> Result<(), IggyError> {
+        let command = entry.command().with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to read command of replicated entry with index: {}",
+                entry.index
+            )
+        })?;
+        debug!(
+            "Applying replicated entry with index: {}, command: {command}",
+            entry.index
+        );
+        let session = Session::stateless(
+            entry.user_id,
+            entry.permissions_id,
+            SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), 0),
+        );
+        if let Some(trigger) = AutoCreateTrigger::from_context(&entry.context)? {
+            return self
+                .apply_replicated_auto_create(&session, command, trigger)
+                .await;
+        }
+
+        match command {
+            EntryCommand::CreateStream(command) => {
+                self.create_stream(&session, command.stream_id, &command.name)
+                    .await?;
+            }
+            EntryCommand::UpdateStream(command) => {
+                self.update_stream(&session, &command.stream_id, &command.name)
+                    .await?;
+            }
+            EntryCommand::DeleteStream(command) => {
+                self.delete_stream(&session, &command.stream_id).await?;
+            }
+            EntryCommand::PurgeStream(command) => {
+                self.purge_stream(&session, &command.stream_id).await?;
+            }
+            EntryCommand::UpdateStreamTopicDefaults(command) => {
+                self.update_stream_topic_defaults(
+                    &session,
+                    &command.stream_id,
+                    TopicDefaults {
+                        message_expiry: command.message_expiry,
+                        max_topic_size: command.max_topic_size,
+                        replication_factor: command.replication_factor,
+                    },
+                )?;
+            }
+            EntryCommand::CreateTopic(command) => {
+                self.create_topic(
+                    &session,
+                    &command.stream_id,
+                    command.topic_id,
+                    &command.name,
+                    command.partitions_count,
+                    command.message_expiry,
+                    command.compression_algorithm,
+                    command.max_topic_size,
+                    command.replication_factor,
+                )
+                .await?;
+            }
+            EntryCommand::UpdateTopic(command) => {
+                self.update_topic(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.name,
+                    command.message_expiry,
+                    command.compression_algorithm,
+                    command.max_topic_size,
+                    command.replication_factor,
+                )
+                .await?;
+            }
+            EntryCommand::DeleteTopic(command) => {
+                self.delete_topic(&session, &command.stream_id, &command.topic_id)
+                    .await?;
+            }
+            EntryCommand::PurgeTopic(command) => {
+                self.purge_topic(&session, &command.stream_id, &command.topic_id)
+                    .await?;
+            }
+            EntryCommand::CreateTopicTemplate(command) => {
+                self.create_topic_template(
+                    &session,
+                    TopicTemplate {
+                        name: command.name,
+                        partitions_count: command.partitions_count,
+                        compression_algorithm: command.compression_algorithm,
+                        message_expiry: command.message_expiry,
+                        max_topic_size: command.max_topic_size,
+                        replication_factor: command.replication_factor,
+                    },
+                )?;
+            }
+            EntryCommand::DeleteTopicTemplate(command) => {
+                self.delete_topic_template(&session, &command.name)?;
+            }
+            EntryCommand::RegisterTopicSchema(command) => {
+                self.register_topic_schema(
+                    &session,
+                    &command.command.stream_id,
+                    &command.command.topic_id,
+                    command.command.schema_type,
+                    &command.command.definition,
+                    (command.schema_id > 0).then_some((command.schema_id, command.version)),
+                )?;
+            }
+            EntryCommand::DeleteTopicSchema(command) => {
+                self.delete_topic_schema(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.version,
+                )?;
+            }
+            EntryCommand::UpdateTopicSchemaSettings(command) => {
+                self.update_topic_schema_settings(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.compatibility,
+                    command.validate_payloads,
+                )?;
+            }
+            EntryCommand::CreatePartitions(command) => {
+                self.create_partitions(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partitions_count,
+                )
+                .await?;
+            }
+            EntryCommand::DeletePartitions(command) => {
+                self.delete_partitions(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partitions_count,
+                )
+                .await?;
+            }
+            EntryCommand::CreateConsumerGroup(command) => {
+                self.create_consumer_group(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.group_id,
+                    &command.name,
+                )
+                .await?;
+            }
+            EntryCommand::DeleteConsumerGroup(command) => {
+                self.delete_consumer_group(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.group_id,
+                )
+                .await?;
+            }
+            EntryCommand::CreateUser(command) => {
+                self.ensure_authenticated(&session)?;
+                self.permissioner
+                    .create_user(session.get_permissions_id())
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - permission denied to create user for user with id: {}",
+                            session.get_user_id()
+                        )
+                    })?;
+                // The password is already hashed by the leader.
+                self.create_user_with_password_hash(
+                    &command.username,
+                    command.password,
+                    command.status,
+                    command.permissions,
+                    entry.timestamp,
+                )?;
+            }
+            EntryCommand::UpdateUser(command) => {
+                self.update_user(&session, &command.user_id, command.username, command.status)
+                    .await?;
+            }
+            EntryCommand::DeleteUser(command) => {
+                self.delete_user(&session, &command.user_id).await?;
+            }
+            EntryCommand::ChangePassword(command) => {
+                self.change_password_hash(&command.user_id, command.new_password, entry.timestamp)?;
+            }
+            EntryCommand::RehashPassword(command) => {
+                self.rehash_password_hash(command.user_id, command.password_hash)?;
+            }
+            EntryCommand::UpdatePermissions(command) => {
+                self.update_permissions(&session, &command.user_id, command.permissions)
+                    .await?;
+            }
+            EntryCommand::CreatePersonalAccessToken(command) => {
+                self.create_personal_access_token_with_hash(
+                    entry.user_id,
+                    &command.command.name,
+                    &command.hash,
+                    entry.timestamp,
+                    command.command.expiry,
+                    command.command.permissions,
+                )?;
+            }
+            EntryCommand::DeletePersonalAccessToken(command) => {
+                self.delete_personal_access_token(&session, &command.name)
+                    .await?;
+            }
+            EntryCommand::AddClusterNode(_)
+            | EntryCommand::RemoveClusterNode(_)
+            | EntryCommand::ElectClusterLeader(_) => {
+                // The cluster membership and leadership are handled by the cluster node itself.
+            }
+        }
+        Ok(())
+    }
+    /// Checks if the user of the session is authenticated and allowed to invoke the command, before it's proposed
+    /// by the cluster leader, so that the log doesn't contain the commands of the unauthorized users.
+    /// The command itself is validated once the committed entry is applied.
+    pub fn authorize_replicated_command(
+        &self,
+        session: &Session,
+        command: &EntryCommand,
+    ) -> Result<(), IggyError> {
+        // The expired password must still be possible to change.
+        match command {
+            EntryCommand::ChangePassword(_) => self.ensure_session_authenticated(session)?,
+            _ => self.ensure_authenticated(session)?,
+        }
+
+        let permissions_id = session.get_permissions_id();
+        match command {
+            EntryCommand::CreateStream(_) => self.permissioner.create_stream(permissions_id),
+            EntryCommand::UpdateStream(command) => {
+                let stream = self.get_stream(&command.stream_id)?;
+                self.permissioner
+                    .update_stream(permissions_id, stream.stream_id)
+            }
+            EntryCommand::UpdateStreamTopicDefaults(command) => {
+                let stream = self.get_stream(&command.stream_id)?;
+                self.permissioner
+                    .update_stream(permissions_id, stream.stream_id)
+            }
+            EntryCommand::DeleteStream(command) => {
+                let stream = self.get_stream(&command.stream_id)?;
+                self.permissioner
+                    .delete_stream(permissions_id, stream.stream_id)
+            }
+            EntryCommand::PurgeStream(command) => {
+                let stream = self.get_stream(&command.stream_id)?;
+                self.permissioner
+                    .purge_stream(permissions_id, stream.stream_id)
+            }
+            EntryCommand::CreateTopic(command) => {
+                let stream = self.get_stream(&command.stream_id)?;
+                self.permissioner
+                    .create_topic(permissions_id, stream.stream_id)
+            }
+            EntryCommand::UpdateTopic(command) => {
+                let topic = self.find_topic(session, &command.stream_id, &command.topic_id)?;
+                self.permissioner
+                    .update_topic(permissions_id, topic.stream_id, topic.topic_id)
+            }
+            EntryCommand::DeleteTopic(command) => {
+                let topic = self.find_topic(session, &command.stream_id, &command.topic_id)?;
+                self.permissioner
+                    .delete_topic(permissions_id, topic.stream_id, topic.topic_id)
+            }
+            EntryCommand::PurgeTopic(command) => {
+                let topic = self.find_topic(session, &command.stream_id, &command.topic_id)?;
+                self.permissioner
+                    .purge_topic(permissions_id, topic.stream_id, topic.topic_id)
+            }
+            EntryCommand::CreateTopicTemplate(_) | EntryCommand::DeleteTopicTemplate(_) => {
+                self.permissioner.manage_topic_templates(permissions_id)
+            }
+            EntryCommand::RegisterTopicSchema(command) => {
+                let topic = self.find_topic(
+                    session,
+                    &command.command.stream_id,
+                    &command.command.topic_id,
+                )?;
+                self.permissioner
+                    .update_topic(permissions_id, topic.stream_id, topic.topic_id)
+            }
+            EntryCommand::DeleteTopicSchema(command) => {
+                let topic = self.find_topic(session, &command.stream_id, &command.topic_id)?;
+                self.permissioner
+                    .update_topic(permissions_id, topic.stream_id, topic.topic_id)
+            }
+            EntryCommand::UpdateTopicSchemaSettings(command) => {
+                let topic = self.find_topic(session, &command.stream_id, &command.topic_id)?;
+                self.permissioner
+                    .update_topic(permissions_id, topic.stream_id, topic.topic_id)
+            }
+            EntryCommand::CreatePartitions(command) => {
+                let topic = self.find_topic(session, &command.stream_id, &command.topic_id)?;
+                self.permissioner
+                    .create_partitions(permissions_id, topic.stream_id, topic.topic_id)
+            }
+            EntryCommand::DeletePartitions(command) => {
+                let topic = self.find_topic(session, &command.stream_id, &command.topic_id)?;
+                self.permissioner
+                    .delete_partitions(permissions_id, topic.stream_id, topic.topic_id)
+            }
+            EntryCommand::CreateConsumerGroup(command) => {
+                let topic = self.find_topic(session, &command.stream_id, &command.topic_id)?;
+                self.permissioner
+                    .create_consumer_group(permissions_id, topic.stream_id, topic.topic_id)
+            }
+            EntryCommand::DeleteConsumerGroup(command) => {
+                let topic = self.find_topic(session, &command.stream_id, &command.topic_id)?;
+                self.permissioner
+                    .delete_consumer_group(permissions_id, topic.stream_id, topic.topic_id)
+            }
+            EntryCommand::CreateUser(_) => self.permissioner.create_user(permissions_id),
+            EntryCommand::UpdateUser(_) => self.permissioner.update_user(permissions_id),
+            EntryCommand::DeleteUser(_) => self.permissioner.delete_user(permissions_id),
+            EntryCommand::UpdatePermissions(_) => {
+                self.permissioner.update_permissions(permissions_id)
+            }
+            EntryCommand::ChangePassword(command) => {
+                let user = self.get_user(&command.user_id)?;
+                if user.id == session.get_user_id() {
+                    return Ok(());
+                }
+
+                self.ensure_password_not_expired(session.get_user_id())?;
+                self.permissioner.change_password(permissions_id)
+            }
+            EntryCommand::RehashPassword(command) => {
+                // The password is rehashed on the login of the user, who is the only one knowing it.
+                if command.user_id != session.get_user_id() {
+                    return Err(IggyError::Unauthorized);
+                }
+
+                Ok(())
+            }
+            EntryCommand::CreatePersonalAccessToken(_)
+            | EntryCommand::DeletePersonalAccessToken(_) => self.ensure_not_scoped(session),
+            EntryCommand::AddClusterNode(_) | EntryCommand::RemoveClusterNode(_) => {
+                self.permissioner.manage_cluster(permissions_id)
+            }
+            // The leader is elected by the cluster nodes, the entry is never proposed on behalf of the user.
+            EntryCommand::ElectClusterLeader(_) => Err(IggyError::Unauthorized),
+        }
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - permission denied to replicate command: {command} for user with ID: {}",
+                session.get_user_id()
+            )
+        })
+    }
+}
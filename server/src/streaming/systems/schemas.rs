@@ -0,0 +1,237 @@
+use crate::state::system::TopicSchemasState;
+use crate::streaming::schemas::SchemaValidator;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use ahash::AHashMap;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::schema::{Schema, SchemaCompatibility, SchemaType, TopicSchemas};
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::{error, info};
+
+impl System {
+    pub(crate) fn load_topic_schemas(
+        &mut self,
+        topic_schemas: AHashMap<u32, AHashMap<u32, TopicSchemasState>>,
+        current_schema_id: u32,
+    ) {
+        info!("Loading topic schemas...");
+        self.current_schema_id = current_schema_id;
+        let mut loaded_schemas = 0;
+        for (stream_id, stream_schemas) in topic_schemas {
+            let Some(stream) = self.streams.get_mut(&stream_id) else {
+                continue;
+            };
+
+            for (topic_id, state) in stream_schemas {
+                let Some(topic) = stream.topics.get_mut(&topic_id) else {
+                    continue;
+                };
+
+                topic.schemas.compatibility = state.compatibility;
+                topic.schemas.validate_payloads = state.validate_payloads;
+                topic.schemas.current_version = state.current_version;
+                for schema in state.schemas {
+                    match SchemaValidator::compile(schema.schema_type, &schema.definition) {
+                        Ok(validator) => {
+                            topic.schemas.register(schema, validator);
+                            loaded_schemas += 1;
+                        }
+                        Err(error) => {
+                            error!(
+                                "Cannot compile schema with ID: {}, version: {} for topic with ID: {topic_id} in stream with ID: {stream_id}. {error}",
+                                schema.id, schema.version
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        info!("Loaded {loaded_schemas} topic schema(s).");
+    }
+
+    pub fn get_schema(&self, session: &Session, schema_id: u32) -> Result<&Schema, IggyError> {
+        self.ensure_authenticated(session)?;
+        let schema = self
+            .streams
+            .values()
+            .flat_map(|stream| stream.topics.values())
+            .find_map(|topic| topic.schemas.get_by_id(schema_id))
+            .map(|registered| &registered.schema)
+            .ok_or(IggyError::SchemaNotFound(schema_id))?;
+        self.permissioner
+            .get_topic(
+                session.get_permissions_id(),
+                schema.stream_id,
+                schema.topic_id,
+            )
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get schema with ID: {schema_id} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+        Ok(schema)
+    }
+
+    /// Returns the given version of the topic schema, or the latest one if the version is not specified.
+    pub fn get_topic_schema(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: Option<u32>,
+    ) -> Result<Option<&Schema>, IggyError> {
+        let topic = self
+            .find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        Ok(topic
+            .schemas
+            .get(version)
+            .map(|registered| &registered.schema))
+    }
+
+    pub fn get_topic_schemas(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError> {
+        let topic = self
+            .find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        Ok(topic.schemas.to_topic_schemas())
+    }
+
+    /// Registers the new version of the topic schema, checked against the latest one using the compatibility rule of the topic.
+    /// If the same definition is already registered, the existing schema is returned along with `false`,
+    /// so that nothing is applied to the state. The ID and the version are assigned by the server,
+    /// unless they are provided, e.g. when the entry replicated from the cluster leader is applied.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_topic_schema(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        definition: &str,
+        assigned_id_and_version: Option<(u32, u32)>,
+    ) -> Result<(Schema, bool), IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self
+                .find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
+                })?;
+            self.permissioner
+                .update_topic(session.get_permissions_id(), topic.stream_id, topic.topic_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - permission denied to register schema for topic with ID: {topic_id} in stream with ID: {stream_id} for user with ID: {}",
+                        session.get_user_id(),
+                    )
+                })?;
+            if let Some(schema) = topic.schemas.find_identical(schema_type, definition) {
+                return Ok((schema.clone(), false));
+            }
+        }
+
+        let validator = SchemaValidator::compile(schema_type, definition)?;
+        let current_schema_id = self.current_schema_id;
+        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        topic.schemas.check_compatibility(&validator)?;
+        let (schema_id, version) = assigned_id_and_version
+            .unwrap_or((current_schema_id + 1, topic.schemas.current_version + 1));
+        let schema = Schema {
+            id: schema_id,
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            version,
+            schema_type,
+            definition: definition.to_owned(),
+            created_at: IggyTimestamp::now(),
+        };
+        topic.schemas.register(schema.clone(), validator);
+        self.current_schema_id = self.current_schema_id.max(schema_id);
+        info!(
+            "Registered schema with ID: {schema_id}, version: {version} for topic with ID: {topic_id} in stream with ID: {stream_id}."
+        );
+        Ok((schema, true))
+    }
+
+    pub fn delete_topic_schema(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: u32,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self
+                .find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
+                })?;
+            self.permissioner
+                .update_topic(session.get_permissions_id(), topic.stream_id, topic.topic_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - permission denied to delete schema version: {version} for topic with ID: {topic_id} in stream with ID: {stream_id} for user with ID: {}",
+                        session.get_user_id(),
+                    )
+                })?;
+        }
+
+        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        if topic.schemas.delete(version).is_none() {
+            return Err(IggyError::TopicSchemaVersionNotFound(
+                version,
+                topic.topic_id,
+                topic.stream_id,
+            ));
+        }
+
+        info!("Deleted schema version: {version} for topic with ID: {topic_id} in stream with ID: {stream_id}.");
+        Ok(())
+    }
+
+    pub fn update_topic_schema_settings(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+        validate_payloads: bool,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self
+                .find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
+                })?;
+            self.permissioner
+                .update_topic(session.get_permissions_id(), topic.stream_id, topic.topic_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - permission denied to update schema settings for topic with ID: {topic_id} in stream with ID: {stream_id} for user with ID: {}",
+                        session.get_user_id(),
+                    )
+                })?;
+        }
+
+        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        topic.schemas.compatibility = compatibility;
+        topic.schemas.validate_payloads = validate_payloads;
+        info!("Updated schema settings for topic with ID: {topic_id} in stream with ID: {stream_id}, compatibility: {compatibility}, validate payloads: {validate_payloads}.");
+        Ok(())
+    }
+}
@@ -68,6 +68,11 @@ impl System {
                 .ok()
                 .and_then(|v| v.get_numeric_version().ok()),
             cache_metrics,
+            failed_login_attempts: self.metrics.get_failed_logins(),
+            login_lockouts: self.metrics.get_login_lockouts(),
+            quota_violations: self.metrics.get_quota_violations(),
+            recovered_segments: self.metrics.get_recovered_segments(),
+            truncated_segment_bytes: self.metrics.get_truncated_segment_bytes(),
             ..Default::default()
         };
 
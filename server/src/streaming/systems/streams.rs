@@ -9,6 +9,7 @@ use futures::future::try_join_all;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
+use iggy::models::topic_template::TopicDefaults;
 use std::cell::RefCell;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::fs;
@@ -131,6 +132,18 @@ impl System {
                 .increment_partitions(stream.get_partitions_count());
             self.metrics.increment_segments(stream.get_segments_count());
             self.metrics.increment_messages(stream.get_messages_count());
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let Some(recovery) = partition.read().await.recovery else {
+                        continue;
+                    };
+                    if recovery.is_repaired() {
+                        self.metrics.increment_recovered_segments(1);
+                        self.metrics
+                            .increment_truncated_segment_bytes(recovery.truncated_bytes);
+                    }
+                }
+            }
 
             self.streams_ids
                 .insert(stream.name.clone(), stream.stream_id);
@@ -148,7 +161,7 @@ impl System {
     pub fn find_streams(&self, session: &Session) -> Result<Vec<&Stream>, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .get_streams(session.get_user_id())
+            .get_streams(session.get_permissions_id())
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get streams for user {}",
@@ -167,7 +180,7 @@ impl System {
         let stream = self.get_stream(identifier);
         if let Ok(stream) = stream {
             self.permissioner
-                .get_stream(session.get_user_id(), stream.stream_id)
+                .get_stream(session.get_permissions_id(), stream.stream_id)
                 .with_error_context(|error| {
                     format!(
                         "{COMPONENT} (error: {error}) - permission denied to get stream for user {}",
@@ -191,7 +204,7 @@ impl System {
         };
 
         self.permissioner
-            .get_stream(session.get_user_id(), stream.stream_id)
+            .get_stream(session.get_permissions_id(), stream.stream_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get stream with ID: {identifier} for user with ID: {}",
@@ -276,11 +289,24 @@ impl System {
         name: &str,
     ) -> Result<&Stream, IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.create_stream(session.get_user_id())?;
+        self.permissioner
+            .create_stream(session.get_permissions_id())?;
+        self.create_stream_internal(session, stream_id, name).await
+    }
+
+    /// Creates the stream without checking the permissions, which have to be verified by the caller.
+    pub(crate) async fn create_stream_internal(
+        &mut self,
+        session: &Session,
+        stream_id: Option<u32>,
+        name: &str,
+    ) -> Result<&Stream, IggyError> {
         if self.streams_ids.contains_key(name) {
             return Err(IggyError::StreamNameAlreadyExists(name.to_owned()));
         }
 
+        self.ensure_streams_quota(session)?;
+
         let mut id;
         if stream_id.is_none() {
             id = CURRENT_STREAM_ID.fetch_add(1, Ordering::SeqCst);
@@ -302,7 +328,8 @@ impl System {
             return Err(IggyError::StreamIdAlreadyExists(id));
         }
 
-        let stream = Stream::create(id, name, self.config.clone(), self.storage.clone());
+        let mut stream = Stream::create(id, name, self.config.clone(), self.storage.clone());
+        stream.created_by = session.get_user_id();
         stream.persist().await?;
         info!("Created stream with ID: {id}, name: '{name}'.");
         self.streams_ids.insert(name.to_owned(), stream.stream_id);
@@ -327,7 +354,7 @@ impl System {
         }
 
         self.permissioner
-            .update_stream(session.get_user_id(), stream_id)
+            .update_stream(session.get_permissions_id(), stream_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - failed to update stream, user ID: {}, stream ID: {}",
@@ -363,6 +390,50 @@ impl System {
         Ok(())
     }
 
+    pub fn get_stream_topic_defaults(
+        &self,
+        session: &Session,
+        id: &Identifier,
+    ) -> Result<TopicDefaults, IggyError> {
+        let stream = self.find_stream(session, id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to find stream with ID: {id}")
+        })?;
+        Ok(stream.topic_defaults)
+    }
+
+    pub fn update_stream_topic_defaults(
+        &mut self,
+        session: &Session,
+        id: &Identifier,
+        topic_defaults: TopicDefaults,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let stream_id;
+        {
+            let stream = self.get_stream(id).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {id}")
+            })?;
+            stream_id = stream.stream_id;
+        }
+
+        self.permissioner
+            .update_stream(session.get_permissions_id(), stream_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to update stream topic defaults, user ID: {}, stream ID: {}",
+                    session.get_user_id(),
+                    stream_id
+                )
+            })?;
+
+        let stream = self.get_stream_mut(id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get mutable reference to stream with id: {id}")
+        })?;
+        stream.topic_defaults = topic_defaults;
+        info!("Topic defaults of stream with ID '{id}' updated to: {topic_defaults:?}.");
+        Ok(())
+    }
+
     pub async fn delete_stream(
         &mut self,
         session: &Session,
@@ -374,7 +445,7 @@ impl System {
         })?;
         let stream_id = stream.stream_id;
         self.permissioner
-            .delete_stream(session.get_user_id(), stream_id)
+            .delete_stream(session.get_permissions_id(), stream_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to delete stream for user {}, stream ID: {}",
@@ -416,7 +487,7 @@ impl System {
             format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
         })?;
         self.permissioner
-            .purge_stream(session.get_user_id(), stream.stream_id)
+            .purge_stream(session.get_permissions_id(), stream.stream_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to purge stream for user {}, stream ID: {}",
@@ -431,7 +502,7 @@ impl System {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
+    use crate::configs::server::ServerConfig;
     use crate::configs::system::SystemConfig;
     use crate::state::{MockState, StateKind};
     use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
@@ -458,14 +529,19 @@ mod tests {
         let stream_id = 1;
         let stream_name = "test";
         let mut system = System::create(
-            config,
+            &ServerConfig {
+                system: config,
+                ..Default::default()
+            },
             storage,
             Arc::new(StateKind::Mock(MockState::new())),
             None,
-            DataMaintenanceConfig::default(),
-            PersonalAccessTokenConfig::default(),
         );
-        let root = User::root(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD);
+        let root = User::root(
+            DEFAULT_ROOT_USERNAME,
+            DEFAULT_ROOT_PASSWORD,
+            &system.config.password.hashing,
+        );
         let permissions = root.permissions.clone();
         let session = Session::new(
             1,
@@ -1,5 +1,6 @@
 use crate::archiver::{ArchiverKind, ArchiverKindType};
-use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
+use crate::cluster::node::ClusterNode;
+use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
 use crate::configs::system::SystemConfig;
 use crate::map_toggle_str;
 use crate::state::file::FileState;
@@ -7,13 +8,19 @@ use crate::state::system::SystemState;
 use crate::state::StateKind;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::clients::client_manager::ClientManager;
+use crate::streaming::clients::rate_limiter::RateLimiter;
 use crate::streaming::diagnostics::metrics::Metrics;
+use crate::streaming::keyring::Keyring;
 use crate::streaming::persistence::persister::*;
 use crate::streaming::session::Session;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::COMPONENT;
+use crate::streaming::tiered_storage::TieredStorage;
+use crate::streaming::users::login_attempts::LoginAttemptsTracker;
+use crate::streaming::users::password_policy;
 use crate::streaming::users::permissioner::Permissioner;
+use crate::streaming::users::quotas::ThroughputQuotaTracker;
 use crate::streaming::users::user::User;
 use crate::versioning::SemanticVersion;
 use ahash::AHashMap;
@@ -21,15 +28,18 @@ use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::topic_template::TopicTemplate;
 use iggy::models::user_info::UserId;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::crypto::{Aes256GcmEncryptor, EncryptorKind};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs::{create_dir_all, remove_dir_all};
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use tokio::time::Instant;
-use tracing::{error, info, instrument, trace};
+use tracing::{error, info, instrument, trace, warn};
 
 #[derive(Debug)]
 pub struct SharedSystem {
@@ -66,13 +76,19 @@ pub struct System {
     pub(crate) storage: Arc<SystemStorage>,
     pub(crate) streams: AHashMap<u32, Stream>,
     pub(crate) streams_ids: AHashMap<String, u32>,
+    pub(crate) topic_templates: AHashMap<String, TopicTemplate>,
+    pub(crate) current_schema_id: u32,
     pub(crate) users: AHashMap<UserId, User>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) client_manager: IggySharedMut<ClientManager>,
     pub(crate) encryptor: Option<Arc<EncryptorKind>>,
+    pub(crate) keyring: Option<Arc<Keyring>>,
     pub(crate) metrics: Metrics,
     pub(crate) state: Arc<StateKind>,
     pub(crate) archiver: Option<Arc<ArchiverKind>>,
+    pub(crate) login_attempts: LoginAttemptsTracker,
+    pub(crate) throughput_quota: ThroughputQuotaTracker,
+    pub(crate) rate_limiter: RateLimiter,
     pub personal_access_token: PersonalAccessTokenConfig,
 }
 
@@ -81,41 +97,60 @@ pub struct System {
 const CACHE_OVER_EVICTION_FACTOR: u64 = 5;
 
 impl System {
-    pub fn new(
-        config: Arc<SystemConfig>,
-        data_maintenance_config: DataMaintenanceConfig,
-        pat_config: PersonalAccessTokenConfig,
-    ) -> System {
+    pub fn new(server_config: &ServerConfig) -> System {
+        let config = server_config.system.clone();
+        let cluster_config = server_config.cluster.clone();
         let version = SemanticVersion::current().expect("Invalid version");
         info!(
             "Server-side encryption is {}.",
             map_toggle_str(config.encryption.enabled)
         );
 
-        let encryptor: Option<Arc<EncryptorKind>> = match config.encryption.enabled {
-            true => Some(Arc::new(EncryptorKind::Aes256Gcm(
+        let keyring = match config.encryption.enabled && config.encryption.keyring.enabled {
+            true => Some(Arc::new(
+                Keyring::load(&config).expect("Failed to load encryption keyring"),
+            )),
+            false => None,
+        };
+        let encryptor: Option<Arc<EncryptorKind>> = match (config.encryption.enabled, &keyring) {
+            (true, Some(keyring)) => Some(keyring.encryptor()),
+            (true, None) => Some(Arc::new(EncryptorKind::Aes256Gcm(
                 Aes256GcmEncryptor::from_base64_key(&config.encryption.key).unwrap(),
             ))),
-            false => None,
+            (false, _) => None,
         };
 
         let state_persister = Self::resolve_persister(config.state.enforce_fsync);
         let partition_persister = Self::resolve_persister(config.partition.enforce_fsync);
 
-        let state = Arc::new(StateKind::File(FileState::new(
+        let file_state = FileState::new(
             &config.get_state_log_path(),
             &version,
-            state_persister,
+            state_persister.clone(),
             encryptor.clone(),
-        )));
-        Self::create(
-            config.clone(),
+        );
+        let state = if cluster_config.enabled {
+            info!(
+                "Clustering is enabled, node ID: {}, address: {}",
+                cluster_config.node_id, cluster_config.address
+            );
+            Arc::new(StateKind::Cluster(Arc::new(ClusterNode::new(
+                cluster_config,
+                file_state,
+                state_persister,
+                &config.get_state_path(),
+            ))))
+        } else {
+            Arc::new(StateKind::File(file_state))
+        };
+        let mut system = Self::create(
+            server_config,
             SystemStorage::new(config, partition_persister),
             state,
             encryptor,
-            data_maintenance_config,
-            pat_config,
-        )
+        );
+        system.keyring = keyring;
+        system
     }
 
     fn resolve_persister(enforce_fsync: bool) -> Arc<PersisterKind> {
@@ -126,14 +161,13 @@ impl System {
     }
 
     pub fn create(
-        system_config: Arc<SystemConfig>,
+        server_config: &ServerConfig,
         storage: SystemStorage,
         state: Arc<StateKind>,
         encryptor: Option<Arc<EncryptorKind>>,
-        data_maintenance_config: DataMaintenanceConfig,
-        pat_config: PersonalAccessTokenConfig,
     ) -> System {
-        let archiver_config = data_maintenance_config.archiver;
+        let system_config = server_config.system.clone();
+        let archiver_config = &server_config.data_maintenance.archiver;
         let archiver: Option<Arc<ArchiverKind>> = if archiver_config.enabled {
             info!("Archiving is enabled, kind: {}", archiver_config.kind);
             match archiver_config.kind {
@@ -152,24 +186,65 @@ impl System {
                     )
                     .expect("Failed to create S3 archiver"),
                 )),
+                ArchiverKindType::Azure => Some(Arc::new(
+                    ArchiverKind::get_azure_archiver(
+                        archiver_config
+                            .azure
+                            .clone()
+                            .expect("Azure archiver config is missing"),
+                    )
+                    .expect("Failed to create Azure archiver"),
+                )),
+                ArchiverKindType::Gcs => Some(Arc::new(
+                    ArchiverKind::get_gcs_archiver(
+                        archiver_config
+                            .gcs
+                            .clone()
+                            .expect("GCS archiver config is missing"),
+                    )
+                    .expect("Failed to create GCS archiver"),
+                )),
             }
         } else {
             info!("Archiving is disabled.");
             None
         };
 
+        let mut storage = storage;
+        if system_config.tiered_storage.enabled {
+            match archiver.as_ref() {
+                Some(archiver) => {
+                    info!("Tiered storage is enabled, offloaded segments will be fetched from the archiver.");
+                    storage.tiered = Some(Arc::new(TieredStorage::new(
+                        archiver.clone(),
+                        system_config.clone(),
+                    )));
+                }
+                None => {
+                    warn!("Tiered storage is enabled, but the archiver is disabled, segments won't be offloaded.")
+                }
+            }
+        }
+
+        let throughput_quota = ThroughputQuotaTracker::new(&system_config.quotas);
         System {
             config: system_config,
             streams: AHashMap::new(),
             streams_ids: AHashMap::new(),
+            topic_templates: AHashMap::new(),
+            current_schema_id: 0,
             storage: Arc::new(storage),
             encryptor,
+            keyring: None,
             client_manager: IggySharedMut::new(ClientManager::default()),
             permissioner: Permissioner::default(),
             metrics: Metrics::init(),
             users: AHashMap::new(),
             state,
-            personal_access_token: pat_config,
+            personal_access_token: server_config.personal_access_token.clone(),
+            login_attempts: LoginAttemptsTracker::new(server_config.login_protection.clone()),
+            throughput_quota,
+            rate_limiter: RateLimiter::new(server_config.rate_limit.clone()),
             archiver,
         }
     }
@@ -208,11 +283,21 @@ impl System {
         let state_entries = self.state.init().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to initialize state entries")
         })?;
-        let system_state = SystemState::init(state_entries)
+        let state_snapshot = self
+            .state
+            .load_snapshot()
             .await
             .with_error_context(|error| {
-                format!("{COMPONENT} (error: {error}) - failed to initialize system state")
+                format!("{COMPONENT} (error: {error}) - failed to load state snapshot")
             })?;
+        let password_history = self.config.password.policy.history;
+        let system_state = match state_snapshot {
+            Some(snapshot) => SystemState::restore(snapshot.state, state_entries, password_history),
+            None => SystemState::init(state_entries, password_history).await,
+        }
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to initialize system state")
+        })?;
         let now = Instant::now();
         self.load_version().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to load version")
@@ -227,12 +312,22 @@ impl System {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load streams")
             })?;
+        self.load_topic_templates(
+            system_state.topic_templates.into_values().collect(),
+            system_state.stream_topic_defaults,
+        );
+        self.load_topic_schemas(system_state.topic_schemas, system_state.current_schema_id);
         if let Some(archiver) = self.archiver.as_ref() {
             archiver
                 .init()
                 .await
                 .expect("Failed to initialize archiver");
         }
+        if let Some(tiered_storage) = self.storage.tiered.as_ref() {
+            tiered_storage.init().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize tiered storage")
+            })?;
+        }
         info!("Initialized system in {} ms.", now.elapsed().as_millis());
         Ok(())
     }
@@ -254,7 +349,22 @@ impl System {
         Ok(saved_messages_number)
     }
 
+    /// Returns an error if this server can't change the metadata, i.e. it's not the cluster leader.
+    pub fn ensure_cluster_leader(&self) -> Result<(), IggyError> {
+        self.state.ensure_leader()
+    }
+
+    pub fn cluster_node(&self) -> Option<Arc<ClusterNode>> {
+        self.state.cluster_node().cloned()
+    }
+
     pub fn ensure_authenticated(&self, session: &Session) -> Result<(), IggyError> {
+        self.ensure_session_authenticated(session)?;
+        self.ensure_password_not_expired(session.get_user_id())
+    }
+
+    /// Ensures that the session is authenticated, regardless of whether the user's password has expired.
+    pub fn ensure_session_authenticated(&self, session: &Session) -> Result<(), IggyError> {
         if !session.is_active() {
             error!("{COMPONENT} - session is inactive, session: {session}");
             return Err(IggyError::StaleClient);
@@ -268,6 +378,28 @@ impl System {
         }
     }
 
+    pub(crate) fn ensure_password_not_expired(&self, user_id: UserId) -> Result<(), IggyError> {
+        let policy = &self.config.password.policy;
+        if let IggyExpiry::NeverExpire = policy.expiry {
+            return Ok(());
+        }
+
+        let Some(user) = self.users.get(&user_id) else {
+            return Ok(());
+        };
+
+        if password_policy::is_password_expired(
+            user.password_changed_at,
+            IggyTimestamp::now(),
+            policy,
+        ) {
+            error!("{COMPONENT} - password for user with ID: {user_id} has expired and must be changed.");
+            return Err(IggyError::PasswordExpired(user_id));
+        }
+
+        Ok(())
+    }
+
     pub async fn clean_cache(&self, size_to_clean: IggyByteSize) {
         for stream in self.streams.values() {
             for topic in stream.get_topics() {
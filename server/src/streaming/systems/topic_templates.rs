@@ -0,0 +1,151 @@
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use ahash::AHashMap;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::topic_template::{TopicDefaults, TopicTemplate};
+use iggy::topics::create_topic::CreateTopic;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use tracing::info;
+
+impl System {
+    pub(crate) fn load_topic_templates(
+        &mut self,
+        templates: Vec<TopicTemplate>,
+        stream_topic_defaults: AHashMap<u32, TopicDefaults>,
+    ) {
+        info!("Loading topic templates...");
+        for template in templates {
+            self.topic_templates.insert(template.name.clone(), template);
+        }
+
+        for (stream_id, topic_defaults) in stream_topic_defaults {
+            if let Some(stream) = self.streams.get_mut(&stream_id) {
+                stream.topic_defaults = topic_defaults;
+            }
+        }
+        info!("Loaded {} topic template(s).", self.topic_templates.len());
+    }
+
+    pub fn get_topic_template(
+        &self,
+        session: &Session,
+        name: &str,
+    ) -> Result<&TopicTemplate, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.topic_templates
+            .get(name)
+            .ok_or_else(|| IggyError::TopicTemplateNotFound(name.to_owned()))
+    }
+
+    pub fn get_topic_templates(&self, session: &Session) -> Result<Vec<&TopicTemplate>, IggyError> {
+        self.ensure_authenticated(session)?;
+        Ok(self.topic_templates.values().collect())
+    }
+
+    pub fn create_topic_template(
+        &mut self,
+        session: &Session,
+        template: TopicTemplate,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .manage_topic_templates(session.get_permissions_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to create topic template with name: {} for user with ID: {}",
+                    template.name,
+                    session.get_user_id(),
+                )
+            })?;
+
+        if self.topic_templates.contains_key(&template.name) {
+            return Err(IggyError::TopicTemplateAlreadyExists(template.name));
+        }
+
+        info!("Created topic template: {}.", template.name);
+        self.topic_templates.insert(template.name.clone(), template);
+        Ok(())
+    }
+
+    pub fn delete_topic_template(
+        &mut self,
+        session: &Session,
+        name: &str,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .manage_topic_templates(session.get_permissions_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to delete topic template with name: {name} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+
+        if self.topic_templates.remove(name).is_none() {
+            return Err(IggyError::TopicTemplateNotFound(name.to_owned()));
+        }
+
+        info!("Deleted topic template: {name}.");
+        Ok(())
+    }
+
+    /// Resolves the topic settings of the command before the topic is created.
+    /// The template (if any) overrides the partitions count and compression algorithm, and fills the
+    /// settings left at the server default, then the remaining ones are filled from the stream defaults.
+    /// The template is cleared, so the resolved command can be applied to the state as is.
+    pub fn resolve_topic_settings(
+        &self,
+        session: &Session,
+        command: &mut CreateTopic,
+    ) -> Result<(), IggyError> {
+        if let Some(name) = command.template.take() {
+            let template = self.get_topic_template(session, &name)?;
+            command.partitions_count = template.partitions_count;
+            command.compression_algorithm = template.compression_algorithm;
+            fill_topic_settings(
+                command,
+                template.message_expiry,
+                template.max_topic_size,
+                template.replication_factor,
+            );
+        }
+
+        let stream = self
+            .get_stream(&command.stream_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to get stream with ID: {}",
+                    command.stream_id
+                )
+            })?;
+        let defaults = stream.topic_defaults;
+        fill_topic_settings(
+            command,
+            defaults.message_expiry,
+            defaults.max_topic_size,
+            defaults.replication_factor,
+        );
+        Ok(())
+    }
+}
+
+fn fill_topic_settings(
+    command: &mut CreateTopic,
+    message_expiry: IggyExpiry,
+    max_topic_size: MaxTopicSize,
+    replication_factor: Option<u8>,
+) {
+    if command.message_expiry == IggyExpiry::ServerDefault {
+        command.message_expiry = message_expiry;
+    }
+    if command.max_topic_size == MaxTopicSize::ServerDefault {
+        command.max_topic_size = max_topic_size;
+    }
+    if command.replication_factor.is_none() {
+        command.replication_factor = replication_factor;
+    }
+}
@@ -26,7 +26,7 @@ impl System {
         let topic = stream.get_topic(topic_id);
         if let Ok(topic) = topic {
             self.permissioner
-                .get_topic(session.get_user_id(), stream.stream_id, topic.topic_id)
+                .get_topic(session.get_permissions_id(), stream.stream_id, topic.topic_id)
                 .with_error_context(|error| {
                     format!(
                         "{COMPONENT} (error: {error}) - permission denied to get topic with ID: {topic_id} in stream with ID: {stream_id} for user with ID: {}",
@@ -49,7 +49,7 @@ impl System {
             format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
         })?;
         self.permissioner
-            .get_topics(session.get_user_id(), stream.stream_id)
+            .get_topics(session.get_permissions_id(), stream.stream_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get topics in stream with ID: {stream_id} for user with ID: {}",
@@ -80,7 +80,7 @@ impl System {
         };
 
         self.permissioner
-            .get_topic(session.get_user_id(), stream.stream_id, topic.topic_id)
+            .get_topic(session.get_permissions_id(), stream.stream_id, topic.topic_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get topic with ID: {topic_id} in stream with ID: {stream_id} for user with ID: {}",
@@ -109,7 +109,7 @@ impl System {
                 format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
             })?;
             self.permissioner
-                .create_topic(session.get_user_id(), stream.stream_id)
+                .create_topic(session.get_permissions_id(), stream.stream_id)
                 .with_error_context(|error| {
                     format!(
                         "{COMPONENT} (error: {error}) - permission denied to create topic with name: {name} in stream with ID: {stream_id} for user with ID: {}",
@@ -118,8 +118,37 @@ impl System {
                 })?;
         }
 
-        let created_topic_id = self
-            .get_stream_mut(stream_id)?
+        self.create_topic_internal(
+            session,
+            stream_id,
+            topic_id,
+            name,
+            partitions_count,
+            message_expiry,
+            compression_algorithm,
+            max_topic_size,
+            replication_factor,
+        )
+        .await
+    }
+
+    /// Creates the topic without checking the permissions, which have to be verified by the caller.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn create_topic_internal(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: Option<u32>,
+        name: &str,
+        partitions_count: u32,
+        message_expiry: IggyExpiry,
+        compression_algorithm: CompressionAlgorithm,
+        max_topic_size: MaxTopicSize,
+        replication_factor: Option<u8>,
+    ) -> Result<&Topic, IggyError> {
+        self.ensure_topics_quota(session, partitions_count)?;
+        let stream = self.get_stream_mut(stream_id)?;
+        let created_topic_id = stream
             .create_topic(
                 topic_id,
                 name,
@@ -133,6 +162,9 @@ impl System {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to create topic with name: {name} in stream ID: {stream_id}")
             })?;
+        stream
+            .get_topic_mut(&created_topic_id.try_into()?)?
+            .created_by = session.get_user_id();
 
         self.metrics.increment_topics(1);
         self.metrics.increment_partitions(partitions_count);
@@ -172,7 +204,7 @@ impl System {
                     )
                 })?;
             self.permissioner.update_topic(
-                session.get_user_id(),
+                session.get_permissions_id(),
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| {
@@ -229,7 +261,7 @@ impl System {
                     format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
                 })?;
             self.permissioner.delete_topic(
-                session.get_user_id(),
+                session.get_permissions_id(),
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| {
@@ -272,7 +304,7 @@ impl System {
                 format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
             })?;
         self.permissioner
-            .purge_topic(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .purge_topic(session.get_permissions_id(), topic.stream_id, topic.topic_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to purge topic with ID: {topic_id} in stream with ID: {stream_id} for user with ID: {}",
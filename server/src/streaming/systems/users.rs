@@ -1,9 +1,12 @@
+use crate::configs::system::PasswordHashingConfig;
 use crate::state::command::EntryCommand;
+use crate::state::models::RehashPassword;
 use crate::state::system::UserState;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::session::Session;
-use crate::streaming::systems::system::System;
+use crate::streaming::systems::system::{SharedSystem, System};
 use crate::streaming::systems::COMPONENT;
+use crate::streaming::users::password_policy;
 use crate::streaming::users::user::User;
 use crate::streaming::utils::crypto;
 use crate::{IGGY_ROOT_PASSWORD_ENV, IGGY_ROOT_USERNAME_ENV};
@@ -12,10 +15,14 @@ use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
 use iggy::models::permissions::Permissions;
+use iggy::models::user_info::UserId;
 use iggy::models::user_status::UserStatus;
 use iggy::users::create_user::CreateUser;
 use iggy::users::defaults::*;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::env;
+use std::net::IpAddr;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tracing::{error, info, warn};
 
@@ -27,7 +34,7 @@ impl System {
         info!("Loading users...");
         if users.is_empty() {
             info!("No users found, creating the root user...");
-            let root = Self::create_root_user();
+            let root = Self::create_root_user(&self.config.password.hashing);
             let command = CreateUser {
                 username: root.username.clone(),
                 password: root.password.clone(),
@@ -56,6 +63,14 @@ impl System {
                 user_state.status,
                 user_state.permissions,
             );
+            user.password_changed_at = user_state.password_changed_at;
+            // The history could be longer if its configured size has been reduced since.
+            let mut password_history = user_state.password_history;
+            let excess = password_history
+                .len()
+                .saturating_sub(self.config.password.policy.history as usize);
+            password_history.drain(..excess);
+            user.password_history = password_history;
 
             user.personal_access_tokens = user_state
                 .personal_access_tokens
@@ -68,6 +83,7 @@ impl System {
                             &token.name,
                             &token.token_hash,
                             token.expiry_at,
+                            token.permissions,
                         ),
                     )
                 })
@@ -85,7 +101,7 @@ impl System {
         Ok(())
     }
 
-    fn create_root_user() -> User {
+    fn create_root_user(hashing: &PasswordHashingConfig) -> User {
         let username = env::var(IGGY_ROOT_USERNAME_ENV);
         let password = env::var(IGGY_ROOT_PASSWORD_ENV);
         if (username.is_ok() && password.is_err()) || (username.is_err() && password.is_ok()) {
@@ -115,7 +131,7 @@ impl System {
             panic!("Root password is too long.");
         }
 
-        User::root(&username, &password)
+        User::root(&username, &password, hashing)
     }
 
     pub fn find_user(
@@ -130,7 +146,7 @@ impl System {
 
         let session_user_id = session.get_user_id();
         if user.id != session_user_id {
-            self.permissioner.get_user(session_user_id).with_error_context(|error| {
+            self.permissioner.get_user(session.get_permissions_id()).with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get user with ID: {user_id} for current user with ID: {session_user_id}"
                 )
@@ -179,7 +195,7 @@ impl System {
     pub async fn get_users(&self, session: &Session) -> Result<Vec<&User>, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .get_users(session.get_user_id())
+            .get_users(session.get_permissions_id())
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get users for user with id: {}",
@@ -197,9 +213,39 @@ impl System {
         status: UserStatus,
         permissions: Option<Permissions>,
     ) -> Result<&User, IggyError> {
+        self.validate_user_creation(session, username, password)?;
+        let user_id = USER_ID.fetch_add(1, Ordering::SeqCst);
+        info!("Creating user: {username} with ID: {user_id}...");
+        let user = User::new(
+            user_id,
+            username,
+            password,
+            status,
+            permissions.clone(),
+            &self.config.password.hashing,
+        );
+        self.permissioner
+            .init_permissions_for_user(user_id, permissions);
+        self.users.insert(user.id, user);
+        info!("Created user: {username} with ID: {user_id}.");
+        self.metrics.increment_users(1);
+        self.get_user(&user_id.try_into()?)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
+            })
+    }
+
+    /// Checks if the user can be created, including the password policy, which requires the plain password,
+    /// so in the cluster it's checked by the leader before the user with the hashed password is replicated.
+    pub fn validate_user_creation(
+        &self,
+        session: &Session,
+        username: &str,
+        password: &str,
+    ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .create_user(session.get_user_id())
+            .create_user(session.get_permissions_id())
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to create user for user with id: {}",
@@ -217,18 +263,46 @@ impl System {
             return Err(IggyError::UsersLimitReached);
         }
 
+        password_policy::validate_password(password, &self.config.password.policy)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - invalid password for user: {username}")
+            })
+    }
+
+    /// Creates the user with the already hashed password, e.g. replicated from the cluster leader.
+    pub(crate) fn create_user_with_password_hash(
+        &mut self,
+        username: &str,
+        password_hash: String,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+        created_at: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        if self.users.values().any(|user| user.username == username) {
+            error!("User: {username} already exists.");
+            return Err(IggyError::UserAlreadyExists);
+        }
+
+        if self.users.len() >= MAX_USERS {
+            error!("Available users limit reached.");
+            return Err(IggyError::UsersLimitReached);
+        }
+
         let user_id = USER_ID.fetch_add(1, Ordering::SeqCst);
-        info!("Creating user: {username} with ID: {user_id}...");
-        let user = User::new(user_id, username, password, status, permissions.clone());
+        let mut user = User::with_password(
+            user_id,
+            username,
+            password_hash,
+            status,
+            permissions.clone(),
+        );
+        user.password_changed_at = created_at;
         self.permissioner
             .init_permissions_for_user(user_id, permissions);
         self.users.insert(user.id, user);
-        info!("Created user: {username} with ID: {user_id}.");
         self.metrics.increment_users(1);
-        self.get_user(&user_id.try_into()?)
-            .with_error_context(|error| {
-                format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
-            })
+        info!("Created user: {username} with ID: {user_id}.");
+        Ok(())
     }
 
     pub async fn delete_user(
@@ -241,7 +315,7 @@ impl System {
         let existing_username;
         {
             self.permissioner
-                .delete_user(session.get_user_id())
+                .delete_user(session.get_permissions_id())
                 .with_error_context(|error| {
                     format!(
                         "{COMPONENT} (error: {error}) - permission denied to delete user for user with id: {}",
@@ -290,7 +364,7 @@ impl System {
     ) -> Result<&User, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .update_user(session.get_user_id())
+            .update_user(session.get_permissions_id())
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to update user for user with id: {}",
@@ -332,7 +406,7 @@ impl System {
 
         {
             self.permissioner
-                .update_permissions(session.get_user_id())
+                .update_permissions(session.get_permissions_id())
                 .with_error_context(|error| {
                     format!(
                         "{COMPONENT} (error: {error}) - permission denied to update permissions for user with id: {}", session.get_user_id()
@@ -373,21 +447,44 @@ impl System {
         current_password: &str,
         new_password: &str,
     ) -> Result<(), IggyError> {
-        self.ensure_authenticated(session)?;
-
-        {
-            let user = self.get_user(user_id).with_error_context(|error| {
-                format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
-            })?;
-            let session_user_id = session.get_user_id();
-            if user.id != session_user_id {
-                self.permissioner.change_password(session_user_id)?;
-            }
-        }
-
+        self.validate_password_change(session, user_id, current_password, new_password)?;
+        let config = self.config.clone();
         let user = self.get_user_mut(user_id).with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
         })?;
+        user.set_password(
+            crypto::hash_password(new_password, &config.password.hashing),
+            IggyTimestamp::now(),
+            config.password.policy.history,
+        );
+        info!(
+            "Changed password for user: {} with ID: {user_id}.",
+            user.username
+        );
+        Ok(())
+    }
+
+    /// Checks if the password can be changed, which requires the current and the new plain passwords,
+    /// so in the cluster it's checked by the leader before the new password hash is replicated.
+    pub fn validate_password_change(
+        &self,
+        session: &Session,
+        user_id: &Identifier,
+        current_password: &str,
+        new_password: &str,
+    ) -> Result<(), IggyError> {
+        // The expired password must still be possible to change.
+        self.ensure_session_authenticated(session)?;
+        let user = self.get_user(user_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
+        })?;
+        let session_user_id = session.get_user_id();
+        if user.id != session_user_id {
+            self.ensure_password_not_expired(session_user_id)?;
+            self.permissioner
+                .change_password(session.get_permissions_id())?;
+        }
+
         if !crypto::verify_password(current_password, &user.password) {
             error!(
                 "Invalid current password for user: {} with ID: {user_id}.",
@@ -396,7 +493,36 @@ impl System {
             return Err(IggyError::InvalidCredentials);
         }
 
-        user.password = crypto::hash_password(new_password);
+        let policy = &self.config.password.policy;
+        password_policy::validate_password(new_password, policy).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - invalid new password for user: {} with ID: {user_id}",
+                user.username
+            )
+        })?;
+        if policy.history > 0 && user.is_password_reused(new_password) {
+            error!(
+                "New password for user: {} with ID: {user_id} was used recently.",
+                user.username
+            );
+            return Err(IggyError::PasswordReused);
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the password with the already hashed one, e.g. replicated from the cluster leader.
+    pub(crate) fn change_password_hash(
+        &mut self,
+        user_id: &Identifier,
+        new_password_hash: String,
+        changed_at: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        let history = self.config.password.policy.history;
+        let user = self.get_user_mut(user_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
+        })?;
+        user.set_password(new_password_hash, changed_at, history);
         info!(
             "Changed password for user: {} with ID: {user_id}.",
             user.username
@@ -404,13 +530,65 @@ impl System {
         Ok(())
     }
 
+    /// Replaces the password hash with the one using the upgraded hashing parameters, e.g. replicated from the cluster leader.
+    /// Unlike the password change, neither the password history nor its change time are updated.
+    pub(crate) fn rehash_password_hash(
+        &mut self,
+        user_id: UserId,
+        password_hash: String,
+    ) -> Result<(), IggyError> {
+        let user = self
+            .get_user_mut(&user_id.try_into()?)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
+            })?;
+        user.password = password_hash;
+        info!(
+            "Rehashed password for user: {} with ID: {user_id}.",
+            user.username
+        );
+        Ok(())
+    }
+
+    pub async fn unlock_user(
+        &self,
+        session: &Session,
+        user_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .unlock_user(session.get_permissions_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to unlock user for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let user = self.get_user(user_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
+        })?;
+        if self
+            .login_attempts
+            .unlock_user(user.id, IggyTimestamp::now())
+        {
+            info!("Unlocked user: {} with ID: {}.", user.username, user.id);
+        } else {
+            info!(
+                "User: {} with ID: {} was not locked.",
+                user.username, user.id
+            );
+        }
+        Ok(())
+    }
+
     pub async fn login_user(
         &self,
         username: &str,
         password: &str,
+        ip_address: IpAddr,
         session: Option<&Session>,
     ) -> Result<&User, IggyError> {
-        self.login_user_with_credentials(username, Some(password), session)
+        self.login_user_with_credentials(username, Some(password), ip_address, session)
             .await
     }
 
@@ -418,16 +596,35 @@ impl System {
         &self,
         username: &str,
         password: Option<&str>,
+        ip_address: IpAddr,
         session: Option<&Session>,
     ) -> Result<&User, IggyError> {
         let user = match self.get_user(&username.try_into()?) {
             Ok(user) => user,
             Err(_) => {
+                self.login_attempts.ensure_not_locked(
+                    None,
+                    Some(ip_address),
+                    IggyTimestamp::now(),
+                )?;
                 error!("Cannot login user: {username} (not found).");
+                self.register_failed_login(None, ip_address);
                 return Err(IggyError::InvalidCredentials);
             }
         };
 
+        if let Err(error) = self.login_attempts.ensure_not_locked(
+            Some(user.id),
+            Some(ip_address),
+            IggyTimestamp::now(),
+        ) {
+            warn!(
+                "Cannot login user: {username} with ID: {} from IP address: {ip_address} (locked out).",
+                user.id
+            );
+            return Err(error);
+        }
+
         info!("Logging in user: {username} with ID: {}...", user.id);
         if !user.is_active() {
             warn!("User: {username} with ID: {} is inactive.", user.id);
@@ -440,8 +637,10 @@ impl System {
                     "Invalid password for user: {username} with ID: {}.",
                     user.id
                 );
+                self.register_failed_login(Some(user.id), ip_address);
                 return Err(IggyError::InvalidCredentials);
             }
+            self.login_attempts.register_success(user.id);
         }
 
         info!("Logged in user: {username} with ID: {}.", user.id);
@@ -474,7 +673,7 @@ impl System {
     }
 
     pub async fn logout_user(&self, session: &Session) -> Result<(), IggyError> {
-        self.ensure_authenticated(session)?;
+        self.ensure_session_authenticated(session)?;
         let user = self
             .get_user(&Identifier::numeric(session.get_user_id())?)
             .with_error_context(|error| {
@@ -498,4 +697,80 @@ impl System {
         info!("Logged out user: {} with ID: {}.", user.username, user.id);
         Ok(())
     }
+
+    pub(crate) fn register_failed_login(&self, user_id: Option<UserId>, ip_address: IpAddr) {
+        self.metrics.increment_failed_logins();
+        let outcome =
+            self.login_attempts
+                .register_failure(user_id, Some(ip_address), IggyTimestamp::now());
+        if let (Some(user_id), Some(lockout)) = (user_id, outcome.user_locked_for) {
+            warn!(
+                "User with ID: {user_id} has been locked out for {} after too many failed login attempts.",
+                IggyDuration::from(lockout)
+            );
+        }
+        if let Some(lockout) = outcome.ip_locked_for {
+            warn!(
+                "IP address: {ip_address} has been locked out for {} after too many failed login attempts.",
+                IggyDuration::from(lockout)
+            );
+        }
+        self.metrics.increment_login_lockouts(outcome.lockouts());
+    }
+}
+
+impl SharedSystem {
+    /// Rehashes the password using the currently configured hashing parameters, if the existing hash is outdated.
+    /// It's invoked after the successful login, as this is the only moment when the plain password is known.
+    pub async fn rehash_password_if_needed(
+        &self,
+        user_id: UserId,
+        password: &str,
+    ) -> Result<(), IggyError> {
+        let password_hash = {
+            let system = self.read().await;
+            // Only the cluster leader can change the state, the password will be rehashed on the login to the leader.
+            if system.ensure_cluster_leader().is_err() {
+                return Ok(());
+            }
+
+            let hashing = &system.config.password.hashing;
+            let user = system.get_user(&user_id.try_into()?)?;
+            if !crypto::needs_rehash(&user.password, hashing)
+                || !crypto::verify_password(password, &user.password)
+            {
+                return Ok(());
+            }
+
+            crypto::hash_password(password, hashing)
+        };
+
+        let command = EntryCommand::RehashPassword(RehashPassword {
+            user_id,
+            password_hash: password_hash.clone(),
+        });
+        if let Some(cluster_node) = self.cluster_node().await {
+            return cluster_node
+                .replicate(user_id, user_id, command)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to replicate rehashed password for user with ID: {user_id}"
+                    )
+                });
+        }
+
+        let mut system = self.write().await;
+        system.rehash_password_hash(user_id, password_hash)?;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(user_id, command)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to apply rehashed password for user with ID: {user_id}"
+                )
+            })
+    }
 }
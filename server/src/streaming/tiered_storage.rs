@@ -0,0 +1,238 @@
+use crate::archiver::ArchiverKind;
+use crate::compat::batch_conversion::batch_converter::{BatchConverter, BatchFormat};
+use crate::configs::system::SystemConfig;
+use crate::streaming::segments::{
+    OffloadedSegment, Segment, INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION,
+};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::fs::remove_dir_all;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+const COMPONENT: &str = "STREAMING_TIERED_STORAGE";
+
+/// Serves the segments which have been offloaded to the archiver.
+/// The segment files are fetched on the first read and kept on the local disk (at `tiered_storage.path`)
+/// for the subsequent reads, until the total size of the fetched segments exceeds `tiered_storage.cache_size`,
+/// in which case the least recently read segments are removed.
+#[derive(Debug)]
+pub struct TieredStorage {
+    archiver: Arc<ArchiverKind>,
+    config: Arc<SystemConfig>,
+    cache: Mutex<TieredStorageCache>,
+}
+
+#[derive(Debug, Default)]
+struct TieredStorageCache {
+    // Ordered from the least to the most recently read segment.
+    segments: Vec<CachedSegment>,
+    size_bytes: u64,
+}
+
+#[derive(Debug)]
+struct CachedSegment {
+    key: String,
+    segment: Arc<Segment>,
+    size_bytes: u64,
+}
+
+impl TieredStorage {
+    pub fn new(archiver: Arc<ArchiverKind>, config: Arc<SystemConfig>) -> Self {
+        Self {
+            archiver,
+            config,
+            cache: Mutex::new(TieredStorageCache::default()),
+        }
+    }
+
+    /// Removes the segments fetched before the restart, as there's no guarantee they're still offloaded.
+    pub async fn init(&self) -> Result<(), IggyError> {
+        let path = self.config.get_tiered_storage_path();
+        if Path::new(&path).exists() {
+            info!("Removing the segments fetched from the archiver, path: {path}");
+            remove_dir_all(&path)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to remove tiered storage directory, path: {path}")
+                })
+                .map_err(|_| IggyError::CannotDeleteFile)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the offloaded segment opened for reading, fetching it from the archiver if it's not cached yet.
+    pub async fn get_segment(
+        &self,
+        offloaded: &OffloadedSegment,
+    ) -> Result<Arc<Segment>, IggyError> {
+        let key = Self::get_key(offloaded);
+        let mut cache = self.cache.lock().await;
+        if let Some(position) = cache.segments.iter().position(|cached| cached.key == key) {
+            let cached = cache.segments.remove(position);
+            let segment = cached.segment.clone();
+            cache.segments.push(cached);
+            return Ok(segment);
+        }
+
+        let segment = Arc::new(self.fetch_segment(offloaded, &key).await?);
+        cache.size_bytes += offloaded.size_bytes;
+        cache.segments.push(CachedSegment {
+            key,
+            segment: segment.clone(),
+            size_bytes: offloaded.size_bytes,
+        });
+        self.evict(&mut cache).await;
+        Ok(segment)
+    }
+
+    /// Removes the cached segment, e.g. when the offloaded segment has been deleted.
+    pub async fn remove_segment(&self, offloaded: &OffloadedSegment) {
+        let key = Self::get_key(offloaded);
+        let mut cache = self.cache.lock().await;
+        let Some(position) = cache.segments.iter().position(|cached| cached.key == key) else {
+            return;
+        };
+
+        let cached = cache.segments.remove(position);
+        cache.size_bytes -= cached.size_bytes;
+        self.remove_files(&cached.key).await;
+    }
+
+    async fn fetch_segment(
+        &self,
+        offloaded: &OffloadedSegment,
+        key: &str,
+    ) -> Result<Segment, IggyError> {
+        info!(
+            "Fetching offloaded segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {} from the archiver...",
+            offloaded.start_offset, offloaded.partition_id, offloaded.topic_id, offloaded.stream_id
+        );
+        let log_path = self.get_log_path(key);
+        let index_path = self.get_index_path(key);
+        let time_index_path = self.get_time_index_path(key);
+        for (file, destination) in [
+            (&offloaded.log_path, &log_path),
+            (&offloaded.index_path, &index_path),
+        ] {
+            if let Err(error) = self.archiver.fetch(file, None, destination).await {
+                warn!("{COMPONENT} - failed to fetch offloaded segment file: {file}. {error}");
+                self.remove_files(key).await;
+                return Err(IggyError::CannotFetchOffloadedSegment(
+                    offloaded.start_offset,
+                    offloaded.partition_id,
+                ));
+            }
+        }
+
+        // The segments offloaded before the versioned batch format are converted only in the local cache.
+        let batch_converter = BatchConverter::new(
+            log_path.clone(),
+            index_path.clone(),
+            time_index_path.clone(),
+            self.config.segment.time_index_interval.as_bytes_u64(),
+            offloaded.start_offset,
+        );
+        let encrypted = self.config.encryption.enabled;
+        let conversion = match batch_converter.detect_format().await {
+            Ok(Some(BatchFormat::Legacy)) => {
+                batch_converter.convert(None, encrypted).await.map(|_| ())
+            }
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        };
+        if let Err(error) = conversion {
+            warn!(
+                "{COMPONENT} - failed to convert batches of offloaded segment: {log_path}. {error}"
+            );
+            self.remove_files(key).await;
+            return Err(IggyError::CannotFetchOffloadedSegment(
+                offloaded.start_offset,
+                offloaded.partition_id,
+            ));
+        }
+
+        match Segment::open_offloaded(
+            offloaded,
+            &log_path,
+            &index_path,
+            &time_index_path,
+            self.config.clone(),
+        )
+        .await
+        {
+            Ok(segment) => Ok(segment),
+            Err(error) => {
+                self.remove_files(key).await;
+                Err(error)
+            }
+        }
+    }
+
+    async fn evict(&self, cache: &mut TieredStorageCache) {
+        let max_size_bytes = self.config.tiered_storage.cache_size.as_bytes_u64();
+        // The most recently read segment is always kept, even if it alone exceeds the limit.
+        while cache.size_bytes > max_size_bytes && cache.segments.len() > 1 {
+            let cached = cache.segments.remove(0);
+            cache.size_bytes -= cached.size_bytes;
+            info!(
+                "Removing fetched segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {} from the tiered storage cache.",
+                cached.segment.start_offset,
+                cached.segment.partition_id,
+                cached.segment.topic_id,
+                cached.segment.stream_id
+            );
+            self.remove_files(&cached.key).await;
+        }
+    }
+
+    // The reader holding the segment keeps its file descriptors open, so it can still be read after the removal.
+    async fn remove_files(&self, key: &str) {
+        for path in [
+            self.get_log_path(key),
+            self.get_index_path(key),
+            self.get_time_index_path(key),
+        ] {
+            if Path::new(&path).exists() {
+                if let Err(error) = tokio::fs::remove_file(&path).await {
+                    warn!("{COMPONENT} - failed to remove fetched segment file: {path}. {error}");
+                }
+            }
+        }
+    }
+
+    fn get_log_path(&self, key: &str) -> String {
+        format!(
+            "{}/{key}.{LOG_EXTENSION}",
+            self.config.get_tiered_storage_path()
+        )
+    }
+
+    fn get_index_path(&self, key: &str) -> String {
+        format!(
+            "{}/{key}.{INDEX_EXTENSION}",
+            self.config.get_tiered_storage_path()
+        )
+    }
+
+    fn get_time_index_path(&self, key: &str) -> String {
+        format!(
+            "{}/{key}.{TIME_INDEX_EXTENSION}",
+            self.config.get_tiered_storage_path()
+        )
+    }
+
+    // The offload time distinguishes the segments with the same offsets of the deleted and recreated partitions.
+    fn get_key(offloaded: &OffloadedSegment) -> String {
+        format!(
+            "{}/{}/{}/{:0>20}_{}",
+            offloaded.stream_id,
+            offloaded.topic_id,
+            offloaded.partition_id,
+            offloaded.start_offset,
+            offloaded.offloaded_at.as_micros()
+        )
+    }
+}
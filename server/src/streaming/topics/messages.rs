@@ -1,5 +1,5 @@
 use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
-use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::models::messages::{PolledMessageBatches, RetainedMessage};
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::topics::COMPONENT;
@@ -73,11 +73,39 @@ impl Topic {
         })
     }
 
+    pub async fn get_message_batches(
+        &self,
+        consumer: PollingConsumer,
+        partition_id: u32,
+        strategy: PollingStrategy,
+        count: u32,
+    ) -> Result<Option<PolledMessageBatches>, IggyError> {
+        let Some(partition) = self.partitions.get(&partition_id) else {
+            return Err(IggyError::PartitionNotFound(
+                partition_id,
+                self.topic_id,
+                self.stream_id,
+            ));
+        };
+
+        let partition = partition.read().await;
+        let file_range = partition
+            .get_file_range(consumer, strategy, count)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get file range, partition ID: {partition_id}, strategy: {strategy}, count: {count}"))?;
+        Ok(file_range.map(|file_range| PolledMessageBatches {
+            partition_id,
+            current_offset: partition.current_offset,
+            file_range,
+        }))
+    }
+
     pub async fn append_messages(
         &self,
         batch_size: IggyByteSize,
         partitioning: Partitioning,
         messages: Vec<Message>,
+        encryption_key_id: Option<u32>,
         confirmation: Option<Confirmation>,
     ) -> Result<(), IggyError> {
         if !self.has_partitions() {
@@ -106,7 +134,8 @@ impl Topic {
             }
         };
 
-        let appendable_batch_info = AppendableBatchInfo::new(batch_size, partition_id);
+        let appendable_batch_info = AppendableBatchInfo::new(batch_size, partition_id)
+            .with_encryption_key_id(encryption_key_id);
         self.append_messages_to_partition(appendable_batch_info, messages, confirmation)
             .await
     }
@@ -339,7 +368,7 @@ mod tests {
                 .map(|msg| msg.get_size_bytes())
                 .sum::<IggyByteSize>();
             topic
-                .append_messages(batch_size, partitioning.clone(), messages, None)
+                .append_messages(batch_size, partitioning.clone(), messages, None, None)
                 .await
                 .unwrap();
         }
@@ -351,6 +380,7 @@ mod tests {
             let messages = partition.cache.as_ref().unwrap().to_vec();
             if partition.partition_id == partition_id {
                 assert_eq!(messages.len() as u32, messages_count);
+                assert!(messages.iter().all(|message| message.key.is_none()));
             } else {
                 assert_eq!(messages.len() as u32, 0);
             }
@@ -371,7 +401,7 @@ mod tests {
                 .map(|msg| msg.get_size_bytes())
                 .sum::<IggyByteSize>();
             topic
-                .append_messages(batch_size, partitioning, messages, None)
+                .append_messages(batch_size, partitioning, messages, None, None)
                 .await
                 .unwrap();
         }
@@ -384,6 +414,7 @@ mod tests {
             let messages = partition.cache.as_ref().unwrap().to_vec();
             read_messages_count += messages.len();
             assert!(messages.len() < messages_count as usize);
+            assert!(messages.iter().all(|message| message.key.is_none()));
         }
 
         assert_eq!(read_messages_count, messages_count as usize);
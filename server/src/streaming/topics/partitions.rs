@@ -5,6 +5,7 @@ use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::partitions_merge::PartitionsMergeStatus;
 use iggy::utils::timestamp::IggyTimestamp;
 
 const MAX_PARTITIONS_COUNT: u32 = 100_000;
@@ -89,16 +90,15 @@ impl Topic {
 
         let mut segments_count = 0;
         let mut messages_count = 0;
-        for partition_id in current_partitions_count - count + 1..=current_partitions_count {
-            let partition = self.partitions.remove(&partition_id).unwrap();
+        for partition in self.detach_partitions(count) {
             let mut partition = partition.write().await;
             let partition_messages_count = partition.get_messages_count();
             segments_count += partition.get_segments_count();
             messages_count += partition_messages_count;
             partition.delete().await.with_error_context(|error| {
                 format!(
-                    "{COMPONENT} (error: {error}) - failed to delete partition with ID: {partition_id} in topic with ID: {}",
-                    self.topic_id
+                    "{COMPONENT} (error: {error}) - failed to delete partition with ID: {} in topic with ID: {}",
+                    partition.partition_id, self.topic_id
                 )
             })?;
         }
@@ -107,6 +107,36 @@ impl Topic {
             messages_count,
         }))
     }
+
+    /// Removes the last partitions from the topic without deleting them, ordered by their IDs.
+    pub fn detach_partitions(&mut self, count: u32) -> Vec<IggySharedMut<Partition>> {
+        let current_partitions_count = self.partitions.len() as u32;
+        let count = count.min(current_partitions_count);
+        (current_partitions_count - count + 1..=current_partitions_count)
+            .filter_map(|partition_id| self.partitions.remove(&partition_id))
+            .collect()
+    }
+
+    pub async fn attach_partitions(&mut self, partitions: Vec<IggySharedMut<Partition>>) {
+        for partition in partitions {
+            let partition_id = partition.read().await.partition_id;
+            self.partitions.insert(partition_id, partition);
+        }
+    }
+
+    /// The partitions can't be created, deleted or merged until the messages of the merged partitions are moved.
+    pub async fn ensure_no_partitions_merge(&self) -> Result<(), IggyError> {
+        if let Some(partitions_merge) = &self.partitions_merge {
+            if partitions_merge.read().await.status == PartitionsMergeStatus::InProgress {
+                return Err(IggyError::PartitionsMergeInProgress(
+                    self.topic_id,
+                    self.stream_id,
+                ));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 pub struct DeletedPartitions {
@@ -38,6 +38,7 @@ impl TopicStorage for FileTopicStorage {
         let message_expiry = Topic::get_message_expiry(state.message_expiry, &topic.config);
         let max_topic_size = Topic::get_max_topic_size(state.max_topic_size, &topic.config)?;
         topic.created_at = state.created_at;
+        topic.created_by = state.created_by;
         topic.message_expiry = message_expiry;
         topic.max_topic_size = max_topic_size;
         topic.compression_algorithm = state.compression_algorithm;
@@ -1,6 +1,7 @@
 use crate::configs::system::SystemConfig;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::schemas::registry::TopicSchemaRegistry;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use ahash::AHashMap;
@@ -9,6 +10,8 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
+use iggy::models::partitions_merge::PartitionsMergeInfo;
+use iggy::models::user_info::UserId;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
@@ -40,11 +43,14 @@ pub struct Topic {
     pub(crate) consumer_groups_ids: AHashMap<String, u32>,
     pub(crate) current_consumer_group_id: AtomicU32,
     pub(crate) current_partition_id: AtomicU32,
+    pub(crate) partitions_merge: Option<IggySharedMut<PartitionsMergeInfo>>,
+    pub(crate) schemas: TopicSchemaRegistry,
     pub message_expiry: IggyExpiry,
     pub compression_algorithm: CompressionAlgorithm,
     pub max_topic_size: MaxTopicSize,
     pub replication_factor: u8,
     pub created_at: IggyTimestamp,
+    pub created_by: UserId,
 }
 
 impl Topic {
@@ -113,12 +119,15 @@ impl Topic {
             consumer_groups_ids: AHashMap::new(),
             current_consumer_group_id: AtomicU32::new(1),
             current_partition_id: AtomicU32::new(1),
+            partitions_merge: None,
+            schemas: TopicSchemaRegistry::default(),
             message_expiry: Topic::get_message_expiry(message_expiry, &config),
             max_topic_size: Topic::get_max_topic_size(max_topic_size, &config)?,
             compression_algorithm,
             replication_factor,
             config,
             created_at: IggyTimestamp::now(),
+            created_by: 0,
         };
 
         info!(
@@ -0,0 +1,297 @@
+use crate::configs::server::LoginProtectionConfig;
+use dashmap::DashMap;
+use iggy::error::IggyError;
+use iggy::models::user_info::UserId;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::net::IpAddr;
+
+/// Once the number of tracked keys exceeds this value, the stale entries are removed on the next failed attempt.
+const CLEANUP_THRESHOLD: usize = 1024;
+/// The exponent of the lockout multiplier is capped to avoid overflows, the lockout is bounded by `max_lockout_duration` anyway.
+const MAX_BACKOFF_EXPONENT: u32 = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum LoginAttemptsKey {
+    User(UserId),
+    Ip(IpAddr),
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct FailedLoginAttempts {
+    count: u32,
+    last_failure_at: u64,
+    locked_until: u64,
+}
+
+/// Outcome of registering a failed login attempt.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FailedLoginOutcome {
+    /// Set when the attempt caused the user to be locked, contains the lockout duration in microseconds.
+    pub user_locked_for: Option<u64>,
+    /// Set when the attempt caused the IP address to be locked, contains the lockout duration in microseconds.
+    pub ip_locked_for: Option<u64>,
+}
+
+impl FailedLoginOutcome {
+    pub fn lockouts(&self) -> u64 {
+        self.user_locked_for.is_some() as u64 + self.ip_locked_for.is_some() as u64
+    }
+}
+
+/// Tracks the consecutive failed login attempts per user and per IP address,
+/// and temporarily locks them out once the configured limits are exceeded.
+/// Each failed attempt over the limit doubles the lockout duration (exponential backoff).
+#[derive(Debug)]
+pub struct LoginAttemptsTracker {
+    config: LoginProtectionConfig,
+    attempts: DashMap<LoginAttemptsKey, FailedLoginAttempts>,
+}
+
+impl LoginAttemptsTracker {
+    pub fn new(config: LoginProtectionConfig) -> Self {
+        Self {
+            config,
+            attempts: DashMap::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Returns an error if either the user or the IP address is currently locked.
+    pub fn ensure_not_locked(
+        &self,
+        user_id: Option<UserId>,
+        ip_address: Option<IpAddr>,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let now = now.as_micros();
+        let keys = [
+            user_id.map(LoginAttemptsKey::User),
+            ip_address.map(LoginAttemptsKey::Ip),
+        ];
+        for key in keys.into_iter().flatten() {
+            if let Some(attempts) = self.attempts.get(&key) {
+                if attempts.locked_until > now {
+                    let retry_after = (attempts.locked_until - now).div_ceil(1_000_000);
+                    return Err(IggyError::TooManyFailedLoginAttempts(retry_after));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Registers a failed login attempt for the user (if known) and the IP address (if known).
+    pub fn register_failure(
+        &self,
+        user_id: Option<UserId>,
+        ip_address: Option<IpAddr>,
+        now: IggyTimestamp,
+    ) -> FailedLoginOutcome {
+        if !self.config.enabled {
+            return FailedLoginOutcome::default();
+        }
+
+        let now = now.as_micros();
+        if self.attempts.len() > CLEANUP_THRESHOLD {
+            self.remove_stale(now);
+        }
+
+        FailedLoginOutcome {
+            user_locked_for: user_id.and_then(|user_id| {
+                self.register_failure_for_key(
+                    LoginAttemptsKey::User(user_id),
+                    self.config.max_failed_attempts_per_user,
+                    now,
+                )
+            }),
+            ip_locked_for: ip_address.and_then(|ip_address| {
+                self.register_failure_for_key(
+                    LoginAttemptsKey::Ip(ip_address),
+                    self.config.max_failed_attempts_per_ip,
+                    now,
+                )
+            }),
+        }
+    }
+
+    /// Clears the failed attempts of the user after a successful login.
+    /// The IP address counter is left intact, so that logging into own account doesn't reset the guessing of others.
+    pub fn register_success(&self, user_id: UserId) {
+        if !self.config.enabled {
+            return;
+        }
+
+        self.attempts.remove(&LoginAttemptsKey::User(user_id));
+    }
+
+    /// Removes the lockout and the failed attempts of the user, returns `true` if the user was locked.
+    pub fn unlock_user(&self, user_id: UserId, now: IggyTimestamp) -> bool {
+        self.attempts
+            .remove(&LoginAttemptsKey::User(user_id))
+            .is_some_and(|(_, attempts)| attempts.locked_until > now.as_micros())
+    }
+
+    pub fn is_user_locked(&self, user_id: UserId, now: IggyTimestamp) -> bool {
+        self.attempts
+            .get(&LoginAttemptsKey::User(user_id))
+            .is_some_and(|attempts| attempts.locked_until > now.as_micros())
+    }
+
+    fn register_failure_for_key(
+        &self,
+        key: LoginAttemptsKey,
+        max_attempts: u32,
+        now: u64,
+    ) -> Option<u64> {
+        let reset_after = self.config.reset_after.as_micros();
+        let mut attempts = self.attempts.entry(key).or_default();
+        if now.saturating_sub(attempts.last_failure_at) > reset_after {
+            attempts.count = 0;
+        }
+
+        attempts.count = attempts.count.saturating_add(1);
+        attempts.last_failure_at = now;
+        if attempts.count < max_attempts {
+            return None;
+        }
+
+        let exponent = (attempts.count - max_attempts).min(MAX_BACKOFF_EXPONENT);
+        let lockout = self
+            .config
+            .lockout_duration
+            .as_micros()
+            .saturating_mul(1u64 << exponent)
+            .min(self.config.max_lockout_duration.as_micros());
+        attempts.locked_until = now + lockout;
+        Some(lockout)
+    }
+
+    fn remove_stale(&self, now: u64) {
+        let reset_after = self.config.reset_after.as_micros();
+        self.attempts.retain(|_, attempts| {
+            attempts.locked_until > now
+                || now.saturating_sub(attempts.last_failure_at) <= reset_after
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+    use std::net::Ipv4Addr;
+
+    const SECOND: u64 = 1_000_000;
+
+    fn tracker() -> LoginAttemptsTracker {
+        LoginAttemptsTracker::new(LoginProtectionConfig {
+            enabled: true,
+            max_failed_attempts_per_user: 3,
+            max_failed_attempts_per_ip: 5,
+            lockout_duration: IggyDuration::new_from_secs(1),
+            max_lockout_duration: IggyDuration::new_from_secs(10),
+            reset_after: IggyDuration::new_from_secs(60),
+        })
+    }
+
+    fn at(secs: u64) -> IggyTimestamp {
+        IggyTimestamp::from(1_000 * SECOND + secs * SECOND)
+    }
+
+    #[test]
+    fn user_should_be_locked_after_exceeding_max_failed_attempts() {
+        let tracker = tracker();
+        let user_id = Some(2);
+        for _ in 0..2 {
+            let outcome = tracker.register_failure(user_id, None, at(0));
+            assert_eq!(outcome.user_locked_for, None);
+        }
+        assert!(tracker.ensure_not_locked(user_id, None, at(0)).is_ok());
+
+        let outcome = tracker.register_failure(user_id, None, at(0));
+        assert_eq!(outcome.user_locked_for, Some(SECOND));
+        assert_eq!(
+            tracker.ensure_not_locked(user_id, None, at(0)),
+            Err(IggyError::TooManyFailedLoginAttempts(1))
+        );
+        assert!(tracker.ensure_not_locked(user_id, None, at(1)).is_ok());
+    }
+
+    #[test]
+    fn lockout_duration_should_grow_exponentially_up_to_max() {
+        let tracker = tracker();
+        let user_id = Some(2);
+        let mut lockouts = Vec::new();
+        for _ in 0..8 {
+            if let Some(lockout) = tracker
+                .register_failure(user_id, None, at(0))
+                .user_locked_for
+            {
+                lockouts.push(lockout / SECOND);
+            }
+        }
+
+        assert_eq!(lockouts, vec![1, 2, 4, 8, 10, 10]);
+    }
+
+    #[test]
+    fn ip_address_should_be_tracked_separately_from_user() {
+        let tracker = tracker();
+        let ip_address = Some(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        for user_id in 2..7 {
+            tracker.register_failure(Some(user_id), ip_address, at(0));
+        }
+
+        assert!(tracker.ensure_not_locked(Some(10), None, at(0)).is_ok());
+        assert!(tracker
+            .ensure_not_locked(Some(10), ip_address, at(0))
+            .is_err());
+    }
+
+    #[test]
+    fn failed_attempts_should_be_reset_after_inactivity() {
+        let tracker = tracker();
+        let user_id = Some(2);
+        tracker.register_failure(user_id, None, at(0));
+        tracker.register_failure(user_id, None, at(0));
+        let outcome = tracker.register_failure(user_id, None, at(120));
+        assert_eq!(outcome.user_locked_for, None);
+    }
+
+    #[test]
+    fn successful_login_and_unlock_should_clear_failed_attempts() {
+        let tracker = tracker();
+        let user_id = 2;
+        for _ in 0..3 {
+            tracker.register_failure(Some(user_id), None, at(0));
+        }
+        assert!(tracker.is_user_locked(user_id, at(0)));
+        assert!(tracker.unlock_user(user_id, at(0)));
+        assert!(!tracker.is_user_locked(user_id, at(0)));
+        assert!(!tracker.unlock_user(user_id, at(0)));
+
+        tracker.register_failure(Some(user_id), None, at(0));
+        tracker.register_failure(Some(user_id), None, at(0));
+        tracker.register_success(user_id);
+        let outcome = tracker.register_failure(Some(user_id), None, at(0));
+        assert_eq!(outcome.user_locked_for, None);
+    }
+
+    #[test]
+    fn disabled_tracker_should_never_lock() {
+        let mut config = tracker().config;
+        config.enabled = false;
+        let tracker = LoginAttemptsTracker::new(config);
+        for _ in 0..10 {
+            tracker.register_failure(Some(2), None, at(0));
+        }
+        assert!(tracker.ensure_not_locked(Some(2), None, at(0)).is_ok());
+    }
+}
@@ -1,3 +1,6 @@
+pub mod login_attempts;
+pub mod password_policy;
 pub mod permissioner;
 pub mod permissioner_rules;
+pub mod quotas;
 pub mod user;
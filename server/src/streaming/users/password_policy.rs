@@ -0,0 +1,110 @@
+use crate::configs::system::PasswordPolicyConfig;
+use iggy::error::IggyError;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp::IggyTimestamp;
+
+/// Validates the password against the length and character classes requirements of the policy.
+/// The password history is checked separately, as it requires the existing password hashes.
+pub fn validate_password(password: &str, policy: &PasswordPolicyConfig) -> Result<(), IggyError> {
+    if password.chars().count() < policy.min_length as usize {
+        return Err(IggyError::PasswordPolicyViolation(format!(
+            "must be at least {} characters long",
+            policy.min_length
+        )));
+    }
+
+    if policy.require_uppercase && !password.chars().any(char::is_uppercase) {
+        return Err(IggyError::PasswordPolicyViolation(
+            "must contain an uppercase letter".to_owned(),
+        ));
+    }
+
+    if policy.require_lowercase && !password.chars().any(char::is_lowercase) {
+        return Err(IggyError::PasswordPolicyViolation(
+            "must contain a lowercase letter".to_owned(),
+        ));
+    }
+
+    if policy.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+        return Err(IggyError::PasswordPolicyViolation(
+            "must contain a digit".to_owned(),
+        ));
+    }
+
+    if policy.require_special_character && password.chars().all(char::is_alphanumeric) {
+        return Err(IggyError::PasswordPolicyViolation(
+            "must contain a special character".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn is_password_expired(
+    password_changed_at: IggyTimestamp,
+    now: IggyTimestamp,
+    policy: &PasswordPolicyConfig,
+) -> bool {
+    match policy.expiry {
+        IggyExpiry::ExpireDuration(max_age) => {
+            password_changed_at.as_micros() + max_age.as_micros() <= now.as_micros()
+        }
+        IggyExpiry::NeverExpire | IggyExpiry::ServerDefault => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+
+    fn policy() -> PasswordPolicyConfig {
+        PasswordPolicyConfig {
+            min_length: 8,
+            require_uppercase: true,
+            require_lowercase: true,
+            require_digit: true,
+            require_special_character: true,
+            history: 3,
+            expiry: IggyExpiry::ExpireDuration(IggyDuration::new_from_secs(60)),
+        }
+    }
+
+    #[test]
+    fn password_satisfying_all_requirements_should_be_valid() {
+        assert!(validate_password("Secret123!", &policy()).is_ok());
+    }
+
+    #[test]
+    fn password_violating_any_requirement_should_be_invalid() {
+        let policy = policy();
+        for password in [
+            "Sec123!",
+            "secret123!",
+            "SECRET123!",
+            "Secret!!!",
+            "Secret123",
+        ] {
+            assert!(matches!(
+                validate_password(password, &policy),
+                Err(IggyError::PasswordPolicyViolation(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn password_should_expire_after_configured_max_age() {
+        let policy = policy();
+        let changed_at = IggyTimestamp::from(1_000_000);
+        assert!(!is_password_expired(
+            changed_at,
+            IggyTimestamp::from(60_000_000),
+            &policy
+        ));
+        assert!(is_password_expired(
+            changed_at,
+            IggyTimestamp::from(61_000_000),
+            &policy
+        ));
+    }
+}
@@ -1,8 +1,13 @@
 use crate::streaming::users::user::User;
 use ahash::{AHashMap, AHashSet};
+use iggy::error::IggyError;
 use iggy::models::permissions::{GlobalPermissions, Permissions, StreamPermissions};
 use iggy::models::user_info::UserId;
 
+/// The IDs of the principals representing the scoped personal access tokens start from this value,
+/// so that they never collide with the IDs of the users.
+const FIRST_SCOPED_PRINCIPAL_ID: UserId = 1 << 31;
+
 #[derive(Debug, Default)]
 pub struct Permissioner {
     pub(super) users_permissions: AHashMap<UserId, GlobalPermissions>,
@@ -11,12 +16,24 @@ pub struct Permissioner {
     pub(super) users_that_can_send_messages_to_all_streams: AHashSet<UserId>,
     pub(super) users_that_can_poll_messages_from_specific_streams: AHashSet<(UserId, u32)>,
     pub(super) users_that_can_send_messages_to_specific_streams: AHashSet<(UserId, u32)>,
+    /// Scoped principal ID -> owner user ID.
+    pub(super) scoped_principals: AHashMap<UserId, UserId>,
+    /// (Owner user ID, personal access token name) -> scoped principal ID.
+    pub(super) personal_access_tokens_principals: AHashMap<(UserId, String), UserId>,
+    pub(super) scoped_principals_created: u32,
 }
 
 impl Permissioner {
     pub fn init(&mut self, users: &[&User]) {
         for user in users {
             self.init_permissions_for_user(user.id, user.permissions.clone());
+            for personal_access_token in user.personal_access_tokens.values() {
+                self.init_permissions_for_personal_access_token(
+                    user.id,
+                    &personal_access_token.name,
+                    personal_access_token.permissions.clone(),
+                );
+            }
         }
     }
 
@@ -63,11 +80,26 @@ impl Permissioner {
         user_id: UserId,
         permissions: Option<Permissions>,
     ) {
-        self.delete_permissions_for_user(user_id);
+        self.clear_permissions(user_id);
         self.init_permissions_for_user(user_id, permissions);
     }
 
     pub fn delete_permissions_for_user(&mut self, user_id: UserId) {
+        self.clear_permissions(user_id);
+        let principals = self
+            .scoped_principals
+            .iter()
+            .filter(|(_, owner_id)| **owner_id == user_id)
+            .map(|(principal_id, _)| *principal_id)
+            .collect::<Vec<_>>();
+        for principal_id in principals {
+            self.delete_scoped_principal(principal_id);
+        }
+    }
+
+    /// Removes the permissions of the user (or principal) only, keeping its scoped personal access tokens,
+    /// as they're always checked against the current permissions of the owner.
+    fn clear_permissions(&mut self, user_id: UserId) {
         self.users_permissions.remove(&user_id);
         self.users_that_can_poll_messages_from_all_streams
             .remove(&user_id);
@@ -80,4 +112,179 @@ impl Permissioner {
         self.users_that_can_send_messages_to_specific_streams
             .retain(|(id, _)| *id != user_id);
     }
+
+    /// Registers the principal restricted to the given permissions, used by the sessions authenticated with the scoped personal access token.
+    /// The token without the permissions is not scoped, and grants all the permissions of its owner.
+    pub fn init_permissions_for_personal_access_token(
+        &mut self,
+        user_id: UserId,
+        name: &str,
+        permissions: Option<Permissions>,
+    ) {
+        let Some(permissions) = permissions else {
+            return;
+        };
+
+        let principal_id = FIRST_SCOPED_PRINCIPAL_ID + self.scoped_principals_created;
+        self.scoped_principals_created += 1;
+        self.scoped_principals.insert(principal_id, user_id);
+        self.personal_access_tokens_principals
+            .insert((user_id, name.to_owned()), principal_id);
+        self.init_permissions_for_user(principal_id, Some(permissions));
+    }
+
+    pub fn delete_permissions_for_personal_access_token(&mut self, user_id: UserId, name: &str) {
+        if let Some(principal_id) = self
+            .personal_access_tokens_principals
+            .get(&(user_id, name.to_owned()))
+            .copied()
+        {
+            self.delete_scoped_principal(principal_id);
+        }
+    }
+
+    /// Returns the ID of the principal that should be used for the permission checks of the session authenticated with the personal access token,
+    /// or `None` if the token is not scoped.
+    pub fn get_personal_access_token_principal_id(
+        &self,
+        user_id: UserId,
+        name: &str,
+    ) -> Option<UserId> {
+        self.personal_access_tokens_principals
+            .get(&(user_id, name.to_owned()))
+            .copied()
+    }
+
+    /// Checks the rule for the given principal and, if the principal belongs to the scoped personal access token, also for its owner,
+    /// so that the token can never exceed the current permissions of the user.
+    pub(super) fn authorize(
+        &self,
+        principal_id: UserId,
+        rule: impl Fn(UserId) -> Result<(), IggyError>,
+    ) -> Result<(), IggyError> {
+        if let Some(owner_id) = self.scoped_principals.get(&principal_id) {
+            rule(*owner_id)?;
+        }
+
+        rule(principal_id)
+    }
+
+    fn delete_scoped_principal(&mut self, principal_id: UserId) {
+        self.scoped_principals.remove(&principal_id);
+        self.personal_access_tokens_principals
+            .retain(|_, id| *id != principal_id);
+        self.clear_permissions(principal_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::systems::auto_create::AutoCreateTrigger;
+    use ahash::AHashMap;
+
+    const USER_ID: UserId = 2;
+    const TOKEN: &str = "token";
+
+    fn send_only_to_stream(stream_id: u32) -> Permissions {
+        Permissions {
+            global: GlobalPermissions::default(),
+            streams: Some(AHashMap::from([(
+                stream_id,
+                StreamPermissions {
+                    send_messages: true,
+                    ..Default::default()
+                },
+            )])),
+        }
+    }
+
+    fn scoped_permissioner(user_permissions: Permissions) -> (Permissioner, UserId) {
+        let mut permissioner = Permissioner::default();
+        permissioner.init_permissions_for_user(USER_ID, Some(user_permissions));
+        permissioner.init_permissions_for_personal_access_token(
+            USER_ID,
+            TOKEN,
+            Some(send_only_to_stream(1)),
+        );
+        let principal_id = permissioner
+            .get_personal_access_token_principal_id(USER_ID, TOKEN)
+            .unwrap();
+        (permissioner, principal_id)
+    }
+
+    #[test]
+    fn scoped_principal_should_be_restricted_to_token_permissions() {
+        let mut user_permissions = Permissions::default();
+        user_permissions.global.manage_streams = true;
+        user_permissions.global.send_messages = true;
+        user_permissions.global.poll_messages = true;
+        let (permissioner, principal_id) = scoped_permissioner(user_permissions);
+
+        assert!(permissioner.create_stream(USER_ID).is_ok());
+        assert!(permissioner.poll_messages(USER_ID, 1, 1).is_ok());
+        assert!(permissioner.append_messages(principal_id, 1, 1).is_ok());
+        assert!(permissioner.append_messages(principal_id, 2, 1).is_err());
+        assert!(permissioner.poll_messages(principal_id, 1, 1).is_err());
+        assert!(permissioner.create_stream(principal_id).is_err());
+    }
+
+    #[test]
+    fn scoped_principal_should_not_exceed_current_user_permissions() {
+        let (mut permissioner, principal_id) = scoped_permissioner(Permissions::default());
+        assert!(permissioner.append_messages(principal_id, 1, 1).is_err());
+
+        permissioner.update_permissions_for_user(USER_ID, Some(send_only_to_stream(1)));
+        assert!(permissioner.append_messages(principal_id, 1, 1).is_ok());
+
+        permissioner.update_permissions_for_user(USER_ID, None);
+        assert!(permissioner.append_messages(principal_id, 1, 1).is_err());
+    }
+
+    #[test]
+    fn auto_create_should_be_limited_to_streams_the_user_can_send_messages_to() {
+        let mut permissioner = Permissioner::default();
+        permissioner.init_permissions_for_user(USER_ID, Some(send_only_to_stream(1)));
+
+        assert!(permissioner
+            .auto_create_topic(USER_ID, 1, AutoCreateTrigger::SendMessages)
+            .is_ok());
+        assert!(permissioner
+            .auto_create_topic(USER_ID, 1, AutoCreateTrigger::PollMessages)
+            .is_err());
+        assert!(permissioner
+            .auto_create_topic(USER_ID, 2, AutoCreateTrigger::SendMessages)
+            .is_err());
+        assert!(permissioner
+            .auto_create_stream(USER_ID, AutoCreateTrigger::SendMessages)
+            .is_err());
+
+        let mut user_permissions = Permissions::default();
+        user_permissions.global.send_messages = true;
+        permissioner.update_permissions_for_user(USER_ID, Some(user_permissions));
+        assert!(permissioner
+            .auto_create_stream(USER_ID, AutoCreateTrigger::SendMessages)
+            .is_ok());
+        assert!(permissioner
+            .auto_create_stream(USER_ID, AutoCreateTrigger::PollMessages)
+            .is_err());
+        assert!(permissioner
+            .auto_create_topic(USER_ID, 2, AutoCreateTrigger::SendMessages)
+            .is_ok());
+    }
+
+    #[test]
+    fn scoped_principal_should_be_removed_with_token_or_user() {
+        let (mut permissioner, principal_id) = scoped_permissioner(send_only_to_stream(1));
+        permissioner.delete_permissions_for_personal_access_token(USER_ID, TOKEN);
+        assert!(permissioner.append_messages(principal_id, 1, 1).is_err());
+        assert!(!permissioner.scoped_principals.contains_key(&principal_id));
+
+        let (mut permissioner, principal_id) = scoped_permissioner(send_only_to_stream(1));
+        permissioner.delete_permissions_for_user(USER_ID);
+        assert!(!permissioner.scoped_principals.contains_key(&principal_id));
+        assert!(permissioner
+            .get_personal_access_token_principal_id(USER_ID, TOKEN)
+            .is_none());
+    }
 }
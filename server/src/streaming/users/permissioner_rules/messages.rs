@@ -1,3 +1,4 @@
+use crate::streaming::systems::auto_create::AutoCreateTrigger;
 use crate::streaming::users::permissioner::Permissioner;
 use iggy::error::IggyError;
 
@@ -8,58 +9,60 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
-        if self
-            .users_that_can_poll_messages_from_all_streams
-            .contains(&user_id)
-        {
-            return Ok(());
-        }
-
-        if self
-            .users_that_can_poll_messages_from_specific_streams
-            .contains(&(user_id, stream_id))
-        {
-            return Ok(());
-        }
-
-        let stream_permissions = self.users_streams_permissions.get(&(user_id, stream_id));
-        if stream_permissions.is_none() {
-            return Err(IggyError::Unauthorized);
-        }
-
-        let stream_permissions = stream_permissions.unwrap();
-        if stream_permissions.read_stream {
-            return Ok(());
-        }
-
-        if stream_permissions.manage_topics {
-            return Ok(());
-        }
-
-        if stream_permissions.read_topics {
-            return Ok(());
-        }
-
-        if stream_permissions.poll_messages {
-            return Ok(());
-        }
-
-        if stream_permissions.topics.is_none() {
-            return Err(IggyError::Unauthorized);
-        }
-
-        let topic_permissions = stream_permissions.topics.as_ref().unwrap();
-        if let Some(topic_permissions) = topic_permissions.get(&topic_id) {
-            return match topic_permissions.poll_messages
-                | topic_permissions.read_topic
-                | topic_permissions.manage_topic
+        self.authorize(user_id, |user_id| {
+            if self
+                .users_that_can_poll_messages_from_all_streams
+                .contains(&user_id)
             {
-                true => Ok(()),
-                false => Err(IggyError::Unauthorized),
-            };
-        }
+                return Ok(());
+            }
 
-        Err(IggyError::Unauthorized)
+            if self
+                .users_that_can_poll_messages_from_specific_streams
+                .contains(&(user_id, stream_id))
+            {
+                return Ok(());
+            }
+
+            let stream_permissions = self.users_streams_permissions.get(&(user_id, stream_id));
+            if stream_permissions.is_none() {
+                return Err(IggyError::Unauthorized);
+            }
+
+            let stream_permissions = stream_permissions.unwrap();
+            if stream_permissions.read_stream {
+                return Ok(());
+            }
+
+            if stream_permissions.manage_topics {
+                return Ok(());
+            }
+
+            if stream_permissions.read_topics {
+                return Ok(());
+            }
+
+            if stream_permissions.poll_messages {
+                return Ok(());
+            }
+
+            if stream_permissions.topics.is_none() {
+                return Err(IggyError::Unauthorized);
+            }
+
+            let topic_permissions = stream_permissions.topics.as_ref().unwrap();
+            if let Some(topic_permissions) = topic_permissions.get(&topic_id) {
+                return match topic_permissions.poll_messages
+                    | topic_permissions.read_topic
+                    | topic_permissions.manage_topic
+                {
+                    true => Ok(()),
+                    false => Err(IggyError::Unauthorized),
+                };
+            }
+
+            Err(IggyError::Unauthorized)
+        })
     }
 
     pub fn append_messages(
@@ -68,50 +71,127 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
-        if self
-            .users_that_can_send_messages_to_all_streams
-            .contains(&user_id)
-        {
-            return Ok(());
-        }
-
-        if self
-            .users_that_can_send_messages_to_specific_streams
-            .contains(&(user_id, stream_id))
-        {
-            return Ok(());
-        }
-
-        let stream_permissions = self.users_streams_permissions.get(&(user_id, stream_id));
-        if stream_permissions.is_none() {
-            return Err(IggyError::Unauthorized);
-        }
-
-        let stream_permissions = stream_permissions.unwrap();
-        if stream_permissions.manage_stream {
-            return Ok(());
-        }
-
-        if stream_permissions.manage_topics {
-            return Ok(());
-        }
-
-        if stream_permissions.send_messages {
-            return Ok(());
-        }
-
-        if stream_permissions.topics.is_none() {
-            return Err(IggyError::Unauthorized);
-        }
-
-        let topic_permissions = stream_permissions.topics.as_ref().unwrap();
-        if let Some(topic_permissions) = topic_permissions.get(&topic_id) {
-            return match topic_permissions.send_messages | topic_permissions.manage_topic {
+        self.authorize(user_id, |user_id| {
+            if self
+                .users_that_can_send_messages_to_all_streams
+                .contains(&user_id)
+            {
+                return Ok(());
+            }
+
+            if self
+                .users_that_can_send_messages_to_specific_streams
+                .contains(&(user_id, stream_id))
+            {
+                return Ok(());
+            }
+
+            let stream_permissions = self.users_streams_permissions.get(&(user_id, stream_id));
+            if stream_permissions.is_none() {
+                return Err(IggyError::Unauthorized);
+            }
+
+            let stream_permissions = stream_permissions.unwrap();
+            if stream_permissions.manage_stream {
+                return Ok(());
+            }
+
+            if stream_permissions.manage_topics {
+                return Ok(());
+            }
+
+            if stream_permissions.send_messages {
+                return Ok(());
+            }
+
+            if stream_permissions.topics.is_none() {
+                return Err(IggyError::Unauthorized);
+            }
+
+            let topic_permissions = stream_permissions.topics.as_ref().unwrap();
+            if let Some(topic_permissions) = topic_permissions.get(&topic_id) {
+                return match topic_permissions.send_messages | topic_permissions.manage_topic {
+                    true => Ok(()),
+                    false => Err(IggyError::Unauthorized),
+                };
+            }
+
+            Err(IggyError::Unauthorized)
+        })
+    }
+
+    /// Allows creating the missing stream on the server side to the users who can either manage the streams,
+    /// or send (poll) the messages to (from) all the streams, depending on the trigger.
+    pub fn auto_create_stream(
+        &self,
+        user_id: u32,
+        trigger: AutoCreateTrigger,
+    ) -> Result<(), IggyError> {
+        self.authorize(user_id, |user_id| {
+            if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+                if global_permissions.manage_streams {
+                    return Ok(());
+                }
+            }
+
+            let allowed = match trigger {
+                AutoCreateTrigger::SendMessages => self
+                    .users_that_can_send_messages_to_all_streams
+                    .contains(&user_id),
+                AutoCreateTrigger::PollMessages => self
+                    .users_that_can_poll_messages_from_all_streams
+                    .contains(&user_id),
+            };
+            match allowed {
                 true => Ok(()),
                 false => Err(IggyError::Unauthorized),
-            };
-        }
+            }
+        })
+    }
 
-        Err(IggyError::Unauthorized)
+    /// Allows creating the missing topic on the server side to the users who can either manage the topics of the stream,
+    /// or send (poll) the messages to (from) the whole stream, depending on the trigger.
+    pub fn auto_create_topic(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        trigger: AutoCreateTrigger,
+    ) -> Result<(), IggyError> {
+        self.authorize(user_id, |user_id| {
+            if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+                if global_permissions.manage_streams || global_permissions.manage_topics {
+                    return Ok(());
+                }
+            }
+
+            if let Some(stream_permissions) =
+                self.users_streams_permissions.get(&(user_id, stream_id))
+            {
+                if stream_permissions.manage_stream || stream_permissions.manage_topics {
+                    return Ok(());
+                }
+            }
+
+            let allowed = match trigger {
+                AutoCreateTrigger::SendMessages => {
+                    self.users_that_can_send_messages_to_all_streams
+                        .contains(&user_id)
+                        || self
+                            .users_that_can_send_messages_to_specific_streams
+                            .contains(&(user_id, stream_id))
+                }
+                AutoCreateTrigger::PollMessages => {
+                    self.users_that_can_poll_messages_from_all_streams
+                        .contains(&user_id)
+                        || self
+                            .users_that_can_poll_messages_from_specific_streams
+                            .contains(&(user_id, stream_id))
+                }
+            };
+            match allowed {
+                true => Ok(()),
+                false => Err(IggyError::Unauthorized),
+            }
+        })
     }
 }
@@ -14,13 +14,47 @@ impl Permissioner {
         self.get_server_info(user_id)
     }
 
+    pub fn get_cluster(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn get_backups(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn create_backup(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_servers(user_id)
+    }
+
+    pub fn restore_backup(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_servers(user_id)
+    }
+
+    pub fn manage_cluster(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_servers(user_id)
+    }
+
+    fn manage_servers(&self, user_id: u32) -> Result<(), IggyError> {
+        self.authorize(user_id, |user_id| {
+            if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+                if global_permissions.manage_servers {
+                    return Ok(());
+                }
+            }
+
+            Err(IggyError::Unauthorized)
+        })
+    }
+
     fn get_server_info(&self, user_id: u32) -> Result<(), IggyError> {
-        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
-            if global_permissions.manage_servers || global_permissions.read_servers {
-                return Ok(());
+        self.authorize(user_id, |user_id| {
+            if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+                if global_permissions.manage_servers || global_permissions.read_servers {
+                    return Ok(());
+                }
             }
-        }
 
-        Err(IggyError::Unauthorized)
+            Err(IggyError::Unauthorized)
+        })
     }
 }
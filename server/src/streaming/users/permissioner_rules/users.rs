@@ -30,23 +30,31 @@ impl Permissioner {
         self.manager_users(user_id)
     }
 
+    pub fn unlock_user(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manager_users(user_id)
+    }
+
     fn manager_users(&self, user_id: u32) -> Result<(), IggyError> {
-        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
-            if global_permissions.manage_users {
-                return Ok(());
+        self.authorize(user_id, |user_id| {
+            if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+                if global_permissions.manage_users {
+                    return Ok(());
+                }
             }
-        }
 
-        Err(IggyError::Unauthorized)
+            Err(IggyError::Unauthorized)
+        })
     }
 
     fn read_users(&self, user_id: u32) -> Result<(), IggyError> {
-        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
-            if global_permissions.manage_users || global_permissions.read_users {
-                return Ok(());
+        self.authorize(user_id, |user_id| {
+            if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+                if global_permissions.manage_users || global_permissions.read_users {
+                    return Ok(());
+                }
             }
-        }
 
-        Err(IggyError::Unauthorized)
+            Err(IggyError::Unauthorized)
+        })
     }
 }
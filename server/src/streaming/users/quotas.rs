@@ -0,0 +1,266 @@
+use crate::configs::system::{QuotasConfig, ThroughputQuotaScope};
+use dashmap::DashMap;
+use iggy::error::IggyError;
+use iggy::models::user_info::UserId;
+use iggy::utils::timestamp::IggyTimestamp;
+
+/// Once the number of tracked keys exceeds this value, the idle entries are removed on the next consumption.
+const CLEANUP_THRESHOLD: usize = 1024;
+const MICROS_IN_SECOND: f64 = 1_000_000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThroughputDirection {
+    Send,
+    Poll,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ThroughputKey {
+    User(UserId, ThroughputDirection),
+    Client(u32, ThroughputDirection),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ThroughputBuckets {
+    bytes: f64,
+    messages: f64,
+    updated_at: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ThroughputLimits {
+    bytes_per_second: u64,
+    messages_per_second: u64,
+}
+
+impl ThroughputLimits {
+    fn is_unlimited(&self) -> bool {
+        self.bytes_per_second == 0 && self.messages_per_second == 0
+    }
+}
+
+/// Tracks the send and poll throughput per user or per client (depending on the configured scope)
+/// using the token buckets refilled at the configured rate, with the capacity of a single second.
+/// The request is allowed as long as the bucket is not empty, and its actual cost is consumed afterwards,
+/// thus the bucket might go into debt, which delays the subsequent requests until it's repaid.
+#[derive(Debug)]
+pub struct ThroughputQuotaTracker {
+    enabled: bool,
+    scope: ThroughputQuotaScope,
+    send: ThroughputLimits,
+    poll: ThroughputLimits,
+    buckets: DashMap<ThroughputKey, ThroughputBuckets>,
+}
+
+impl ThroughputQuotaTracker {
+    pub fn new(config: &QuotasConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            scope: config.throughput.scope,
+            send: ThroughputLimits {
+                bytes_per_second: config.throughput.max_send_bytes_per_second.as_bytes_u64(),
+                messages_per_second: config.throughput.max_send_messages_per_second,
+            },
+            poll: ThroughputLimits {
+                bytes_per_second: config.throughput.max_poll_bytes_per_second.as_bytes_u64(),
+                messages_per_second: config.throughput.max_poll_messages_per_second,
+            },
+            buckets: DashMap::new(),
+        }
+    }
+
+    /// Returns an error if the throughput quota of the user or client has been already used up.
+    pub fn ensure_available(
+        &self,
+        user_id: UserId,
+        client_id: u32,
+        direction: ThroughputDirection,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        let limits = self.limits(direction);
+        if !self.enabled || limits.is_unlimited() {
+            return Ok(());
+        }
+
+        let key = self.key(user_id, client_id, direction);
+        let Some(mut buckets) = self.buckets.get_mut(&key) else {
+            return Ok(());
+        };
+
+        Self::refill(&mut buckets, &limits, now.as_micros());
+        let exhausted = (limits.bytes_per_second > 0 && buckets.bytes <= 0.0)
+            || (limits.messages_per_second > 0 && buckets.messages <= 0.0);
+        if !exhausted {
+            return Ok(());
+        }
+
+        Err(match direction {
+            ThroughputDirection::Send => IggyError::SendThroughputQuotaExceeded,
+            ThroughputDirection::Poll => IggyError::PollThroughputQuotaExceeded,
+        })
+    }
+
+    /// Consumes the actual amount of bytes and messages sent or polled by the user or client.
+    pub fn consume(
+        &self,
+        user_id: UserId,
+        client_id: u32,
+        direction: ThroughputDirection,
+        bytes: u64,
+        messages: u64,
+        now: IggyTimestamp,
+    ) {
+        let limits = self.limits(direction);
+        if !self.enabled || limits.is_unlimited() {
+            return;
+        }
+
+        let now = now.as_micros();
+        if self.buckets.len() > CLEANUP_THRESHOLD {
+            self.remove_idle(now);
+        }
+
+        let key = self.key(user_id, client_id, direction);
+        let mut buckets = self.buckets.entry(key).or_insert(ThroughputBuckets {
+            bytes: limits.bytes_per_second as f64,
+            messages: limits.messages_per_second as f64,
+            updated_at: now,
+        });
+        Self::refill(&mut buckets, &limits, now);
+        buckets.bytes -= bytes as f64;
+        buckets.messages -= messages as f64;
+    }
+
+    fn limits(&self, direction: ThroughputDirection) -> ThroughputLimits {
+        match direction {
+            ThroughputDirection::Send => self.send,
+            ThroughputDirection::Poll => self.poll,
+        }
+    }
+
+    fn key(
+        &self,
+        user_id: UserId,
+        client_id: u32,
+        direction: ThroughputDirection,
+    ) -> ThroughputKey {
+        match self.scope {
+            ThroughputQuotaScope::User => ThroughputKey::User(user_id, direction),
+            ThroughputQuotaScope::Client => ThroughputKey::Client(client_id, direction),
+        }
+    }
+
+    fn refill(buckets: &mut ThroughputBuckets, limits: &ThroughputLimits, now: u64) {
+        let elapsed = now.saturating_sub(buckets.updated_at) as f64 / MICROS_IN_SECOND;
+        buckets.bytes = (buckets.bytes + elapsed * limits.bytes_per_second as f64)
+            .min(limits.bytes_per_second as f64);
+        buckets.messages = (buckets.messages + elapsed * limits.messages_per_second as f64)
+            .min(limits.messages_per_second as f64);
+        buckets.updated_at = buckets.updated_at.max(now);
+    }
+
+    fn remove_idle(&self, now: u64) {
+        // The buckets which haven't been used for more than a second are full again, so they can be safely removed.
+        let idle_after = MICROS_IN_SECOND as u64;
+        self.buckets
+            .retain(|_, buckets| now.saturating_sub(buckets.updated_at) <= idle_after);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::ThroughputQuotaConfig;
+    use iggy::utils::byte_size::IggyByteSize;
+
+    const SECOND: u64 = 1_000_000;
+
+    fn tracker(scope: ThroughputQuotaScope) -> ThroughputQuotaTracker {
+        ThroughputQuotaTracker::new(&QuotasConfig {
+            enabled: true,
+            max_streams_per_user: 0,
+            max_topics_per_user: 0,
+            max_partitions_per_user: 0,
+            max_stream_size: IggyByteSize::default(),
+            throughput: ThroughputQuotaConfig {
+                scope,
+                max_send_bytes_per_second: IggyByteSize::from(1000),
+                max_send_messages_per_second: 0,
+                max_poll_bytes_per_second: IggyByteSize::default(),
+                max_poll_messages_per_second: 10,
+            },
+        })
+    }
+
+    fn at(millis: u64) -> IggyTimestamp {
+        IggyTimestamp::from(1_000 * SECOND + millis * 1_000)
+    }
+
+    #[test]
+    fn send_should_be_rejected_until_the_debt_is_repaid() {
+        let tracker = tracker(ThroughputQuotaScope::User);
+        let direction = ThroughputDirection::Send;
+        assert!(tracker.ensure_available(2, 1, direction, at(0)).is_ok());
+        tracker.consume(2, 1, direction, 1500, 1, at(0));
+
+        assert_eq!(
+            tracker.ensure_available(2, 1, direction, at(0)),
+            Err(IggyError::SendThroughputQuotaExceeded)
+        );
+        assert!(tracker.ensure_available(2, 1, direction, at(500)).is_err());
+        assert!(tracker.ensure_available(2, 1, direction, at(600)).is_ok());
+    }
+
+    #[test]
+    fn poll_messages_limit_should_be_tracked_separately_from_send() {
+        let tracker = tracker(ThroughputQuotaScope::User);
+        tracker.consume(2, 1, ThroughputDirection::Poll, 1_000_000, 10, at(0));
+
+        assert_eq!(
+            tracker.ensure_available(2, 1, ThroughputDirection::Poll, at(0)),
+            Err(IggyError::PollThroughputQuotaExceeded)
+        );
+        assert!(tracker
+            .ensure_available(2, 1, ThroughputDirection::Send, at(0))
+            .is_ok());
+        assert!(tracker
+            .ensure_available(2, 1, ThroughputDirection::Poll, at(100))
+            .is_ok());
+    }
+
+    #[test]
+    fn quota_should_be_shared_by_clients_of_the_same_user_in_user_scope() {
+        let tracker = tracker(ThroughputQuotaScope::User);
+        tracker.consume(2, 1, ThroughputDirection::Send, 1000, 1, at(0));
+
+        assert!(tracker
+            .ensure_available(2, 2, ThroughputDirection::Send, at(0))
+            .is_err());
+        assert!(tracker
+            .ensure_available(3, 3, ThroughputDirection::Send, at(0))
+            .is_ok());
+    }
+
+    #[test]
+    fn quota_should_be_tracked_per_client_in_client_scope() {
+        let tracker = tracker(ThroughputQuotaScope::Client);
+        tracker.consume(2, 1, ThroughputDirection::Send, 1000, 1, at(0));
+
+        assert!(tracker
+            .ensure_available(2, 1, ThroughputDirection::Send, at(0))
+            .is_err());
+        assert!(tracker
+            .ensure_available(2, 2, ThroughputDirection::Send, at(0))
+            .is_ok());
+    }
+
+    #[test]
+    fn disabled_tracker_should_never_reject() {
+        let mut tracker = tracker(ThroughputQuotaScope::User);
+        tracker.enabled = false;
+        tracker.consume(2, 1, ThroughputDirection::Send, 1_000_000, 1, at(0));
+        assert!(tracker
+            .ensure_available(2, 1, ThroughputDirection::Send, at(0))
+            .is_ok());
+    }
+}
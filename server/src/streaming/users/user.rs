@@ -1,3 +1,4 @@
+use crate::configs::system::PasswordHashingConfig;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::utils::crypto;
 use ahash::AHashMap;
@@ -12,6 +13,9 @@ pub struct User {
     pub status: UserStatus,
     pub username: String,
     pub password: String,
+    pub password_changed_at: IggyTimestamp,
+    /// Hashes of the previous passwords, the most recent one is the last.
+    pub password_history: Vec<String>,
     pub created_at: IggyTimestamp,
     pub permissions: Option<Permissions>,
     pub personal_access_tokens: AHashMap<String, PersonalAccessToken>,
@@ -24,6 +28,8 @@ impl Default for User {
             status: UserStatus::Active,
             username: "user".to_string(),
             password: "secret".to_string(),
+            password_changed_at: IggyTimestamp::now(),
+            password_history: Vec::new(),
             created_at: IggyTimestamp::now(),
             permissions: None,
             personal_access_tokens: AHashMap::new(),
@@ -45,11 +51,12 @@ impl User {
         password: &str,
         status: UserStatus,
         permissions: Option<Permissions>,
+        hashing: &PasswordHashingConfig,
     ) -> Self {
         Self::with_password(
             id,
             username,
-            crypto::hash_password(password),
+            crypto::hash_password(password, hashing),
             status,
             permissions,
         )
@@ -62,24 +69,28 @@ impl User {
         status: UserStatus,
         permissions: Option<Permissions>,
     ) -> Self {
+        let now = IggyTimestamp::now();
         Self {
             id,
             username: username.into(),
             password,
-            created_at: IggyTimestamp::now(),
+            password_changed_at: now,
+            password_history: Vec::new(),
+            created_at: now,
             status,
             permissions,
             personal_access_tokens: AHashMap::new(),
         }
     }
 
-    pub fn root(username: &str, password: &str) -> Self {
+    pub fn root(username: &str, password: &str, hashing: &PasswordHashingConfig) -> Self {
         Self::new(
             DEFAULT_ROOT_USER_ID,
             username,
             password,
             UserStatus::Active,
             Some(Permissions::root()),
+            hashing,
         )
     }
 
@@ -90,6 +101,24 @@ impl User {
     pub fn is_active(&self) -> bool {
         self.status == UserStatus::Active
     }
+
+    /// Replaces the password hash, keeping at most `history` previous hashes to prevent their reuse.
+    pub fn set_password(&mut self, password: String, changed_at: IggyTimestamp, history: u32) {
+        let previous_password = std::mem::replace(&mut self.password, password);
+        self.password_history.push(previous_password);
+        let excess = self.password_history.len().saturating_sub(history as usize);
+        self.password_history.drain(..excess);
+        self.password_changed_at = changed_at;
+    }
+
+    /// Returns `true` if the password matches the current or any of the recent passwords.
+    pub fn is_password_reused(&self, password: &str) -> bool {
+        crypto::verify_password(password, &self.password)
+            || self
+                .password_history
+                .iter()
+                .any(|hash| crypto::verify_password(password, hash))
+    }
 }
 
 #[cfg(test)]
@@ -98,7 +127,11 @@ mod tests {
 
     #[test]
     fn given_root_user_data_and_credentials_should_be_valid() {
-        let user = User::root(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD);
+        let user = User::root(
+            DEFAULT_ROOT_USERNAME,
+            DEFAULT_ROOT_PASSWORD,
+            &PasswordHashingConfig::default(),
+        );
         assert_eq!(user.id, DEFAULT_ROOT_USER_ID);
         assert_eq!(user.username, DEFAULT_ROOT_USERNAME);
         assert_ne!(user.password, DEFAULT_ROOT_PASSWORD);
@@ -113,7 +146,28 @@ mod tests {
     #[test]
     fn should_be_created_given_specific_status() {
         let status = UserStatus::Inactive;
-        let user = User::new(1, "test", "test", status, None);
+        let user = User::new(
+            1,
+            "test",
+            "test",
+            status,
+            None,
+            &PasswordHashingConfig::default(),
+        );
         assert_eq!(user.status, status);
     }
+
+    #[test]
+    fn changed_password_should_keep_only_configured_history() {
+        let mut user = User::empty(2);
+        user.password = "hash-0".to_string();
+        for i in 1..=4 {
+            user.set_password(format!("hash-{i}"), IggyTimestamp::now(), 3);
+        }
+        assert_eq!(user.password, "hash-4");
+        assert_eq!(user.password_history, vec!["hash-1", "hash-2", "hash-3"]);
+
+        user.set_password("hash-5".to_string(), IggyTimestamp::now(), 0);
+        assert!(user.password_history.is_empty());
+    }
 }
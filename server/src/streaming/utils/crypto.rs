@@ -1,9 +1,126 @@
-use bcrypt::{hash, verify};
+use crate::configs::system::{PasswordHashingAlgorithm, PasswordHashingConfig};
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use bcrypt::HashParts;
 
-pub fn hash_password(password: &str) -> String {
-    hash(password, 4).unwrap()
+const ARGON2_PREFIX: &str = "$argon2";
+
+pub fn hash_password(password: &str, config: &PasswordHashingConfig) -> String {
+    match config.algorithm {
+        PasswordHashingAlgorithm::Bcrypt => bcrypt::hash(password, config.bcrypt_cost).unwrap(),
+        PasswordHashingAlgorithm::Argon2id => {
+            let salt = SaltString::generate(&mut OsRng);
+            argon2id(config)
+                .hash_password(password.as_bytes(), &salt)
+                .unwrap()
+                .to_string()
+        }
+    }
 }
 
+/// Verifies the password against the hash created by any of the supported algorithms.
 pub fn verify_password(password: &str, hash: &str) -> bool {
-    verify(password, hash).unwrap_or(false)
+    if !hash.starts_with(ARGON2_PREFIX) {
+        return bcrypt::verify(password, hash).unwrap_or(false);
+    }
+
+    let Ok(hash) = PasswordHash::new(hash) else {
+        return false;
+    };
+    Argon2::default()
+        .verify_password(password.as_bytes(), &hash)
+        .is_ok()
+}
+
+/// Returns `true` if the hash was created with a different algorithm or parameters than the configured ones.
+pub fn needs_rehash(hash: &str, config: &PasswordHashingConfig) -> bool {
+    match config.algorithm {
+        PasswordHashingAlgorithm::Bcrypt => hash
+            .parse::<HashParts>()
+            .map_or(true, |parts| parts.get_cost() != config.bcrypt_cost),
+        PasswordHashingAlgorithm::Argon2id => {
+            let Ok(hash) = PasswordHash::new(hash) else {
+                return true;
+            };
+            if hash.algorithm != argon2::ARGON2ID_IDENT {
+                return true;
+            }
+
+            Params::try_from(&hash).map_or(true, |params| {
+                params.m_cost() != config.argon2_memory_cost
+                    || params.t_cost() != config.argon2_time_cost
+                    || params.p_cost() != config.argon2_parallelism
+            })
+        }
+    }
+}
+
+pub fn is_valid_argon2_config(config: &PasswordHashingConfig) -> bool {
+    argon2_params(config).is_ok()
+}
+
+fn argon2id(config: &PasswordHashingConfig) -> Argon2<'static> {
+    Argon2::new(
+        Algorithm::Argon2id,
+        Version::V0x13,
+        argon2_params(config).expect("Invalid Argon2 parameters"),
+    )
+}
+
+fn argon2_params(config: &PasswordHashingConfig) -> Result<Params, argon2::Error> {
+    Params::new(
+        config.argon2_memory_cost,
+        config.argon2_time_cost,
+        config.argon2_parallelism,
+        None,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(algorithm: PasswordHashingAlgorithm) -> PasswordHashingConfig {
+        PasswordHashingConfig {
+            algorithm,
+            bcrypt_cost: 4,
+            argon2_memory_cost: 1024,
+            argon2_time_cost: 1,
+            argon2_parallelism: 1,
+        }
+    }
+
+    #[test]
+    fn bcrypt_hash_should_be_verified() {
+        let config = config(PasswordHashingAlgorithm::Bcrypt);
+        let hash = hash_password("secret", &config);
+        assert!(verify_password("secret", &hash));
+        assert!(!verify_password("invalid", &hash));
+        assert!(!needs_rehash(&hash, &config));
+    }
+
+    #[test]
+    fn argon2id_hash_should_be_verified() {
+        let config = config(PasswordHashingAlgorithm::Argon2id);
+        let hash = hash_password("secret", &config);
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(verify_password("secret", &hash));
+        assert!(!verify_password("invalid", &hash));
+        assert!(!needs_rehash(&hash, &config));
+    }
+
+    #[test]
+    fn hash_should_need_rehash_when_parameters_change() {
+        let mut config = config(PasswordHashingAlgorithm::Bcrypt);
+        let hash = hash_password("secret", &config);
+        config.bcrypt_cost = 5;
+        assert!(needs_rehash(&hash, &config));
+
+        config.algorithm = PasswordHashingAlgorithm::Argon2id;
+        assert!(needs_rehash(&hash, &config));
+        let hash = hash_password("secret", &config);
+        config.argon2_time_cost = 2;
+        assert!(needs_rehash(&hash, &config));
+    }
 }
@@ -1,6 +1,7 @@
 use crate::binary::{command, sender::SenderKind};
 use crate::command::ServerCommand;
 use crate::server_error::ConnectionError;
+use crate::streaming::clients::rate_limiter::RateLimitDecision;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use bytes::{BufMut, BytesMut};
@@ -9,6 +10,8 @@ use iggy::error::IggyError;
 use iggy::validatable::Validatable;
 use std::io::ErrorKind;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
 use tracing::{debug, error, info};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
@@ -58,6 +61,30 @@ pub(crate) async fn handle_connection(
             continue;
         }
 
+        if !matches!(command, ServerCommand::Ping(_)) {
+            let decision = system
+                .read()
+                .await
+                .acquire_rate_limit(&session, length as u64);
+            match decision {
+                RateLimitDecision::Allow => {}
+                RateLimitDecision::Delay(delay) => {
+                    debug!(
+                        "Delaying a TCP command: {command} by {delay} µs due to the rate limit."
+                    );
+                    sleep(Duration::from_micros(delay)).await;
+                }
+                RateLimitDecision::Reject(retry_after) => {
+                    sender
+                        .send_error_response(IggyError::RateLimitExceeded(
+                            retry_after.div_ceil(1000),
+                        ))
+                        .await?;
+                    continue;
+                }
+            }
+        }
+
         debug!("Received a TCP command: {command}, payload size: {length}");
         command::handle(command, sender, &session, system.clone()).await?;
     }
@@ -2,7 +2,7 @@ use iggy::error::IggyError;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tracing::debug;
 
-const STATUS_OK: &[u8] = &[0; 4];
+pub(crate) const STATUS_OK: &[u8] = &[0; 4];
 
 pub(crate) async fn read<T>(stream: &mut T, buffer: &mut [u8]) -> Result<usize, IggyError>
 where
@@ -42,7 +42,7 @@ pub(crate) async fn send_error_response<T>(
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
-    send_response(stream, &error.as_code().to_le_bytes(), &[]).await
+    send_response(stream, &error.as_code().to_le_bytes(), &error.as_payload()).await
 }
 
 pub(crate) async fn send_response<T>(
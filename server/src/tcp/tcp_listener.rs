@@ -7,7 +7,12 @@ use tokio::net::TcpSocket;
 use tokio::sync::oneshot;
 use tracing::{error, info};
 
-pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> SocketAddr {
+pub async fn start(
+    address: &str,
+    socket: TcpSocket,
+    zero_copy_polling: bool,
+    system: SharedSystem,
+) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
@@ -46,7 +51,7 @@ pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> So
                     let client_id = session.client_id;
                     info!("Created new session: {session}");
                     let system = system.clone();
-                    let mut sender = SenderKind::get_tcp_sender(stream);
+                    let mut sender = SenderKind::get_tcp_sender(stream, zero_copy_polling);
                     tokio::spawn(async move {
                         if let Err(error) =
                             handle_connection(session, &mut sender, system.clone()).await
@@ -3,11 +3,14 @@ use crate::tcp::COMPONENT;
 use crate::{server_error::ServerError, tcp::sender};
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use std::fs::File;
 use tokio::{io::AsyncWriteExt, net::TcpStream};
+use tracing::debug;
 
 #[derive(Debug)]
 pub struct TcpSender {
     pub(crate) stream: TcpStream,
+    pub(crate) zero_copy_polling: bool,
 }
 
 impl Sender for TcpSender {
@@ -37,3 +40,72 @@ impl Sender for TcpSender {
             .map_err(ServerError::IoError)
     }
 }
+
+impl TcpSender {
+    /// Sends the OK response, whose payload consists of the header followed by the range of the file.
+    /// The range is copied by the kernel from the file to the socket (sendfile), without reading it into memory.
+    pub async fn send_ok_file_range_response(
+        &mut self,
+        header: &[u8],
+        file: &File,
+        position: u64,
+        length: u64,
+    ) -> Result<(), IggyError> {
+        debug!("Sending file range response, position: {position}, length: {length}...");
+        let payload_length = (header.len() as u64 + length) as u32;
+        self.stream
+            .write_all(&[sender::STATUS_OK, &payload_length.to_le_bytes(), header].concat())
+            .await
+            .map_err(|_| IggyError::TcpError)?;
+        self.send_file_range(file, position, length)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to send file range, position: {position}, length: {length}")
+            })
+            .map_err(|_| IggyError::TcpError)?;
+        debug!("Sent file range response, position: {position}, length: {length}");
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    async fn send_file_range(
+        &mut self,
+        file: &File,
+        position: u64,
+        length: u64,
+    ) -> Result<(), std::io::Error> {
+        use tokio::io::Interest;
+
+        let mut offset = position as nix::libc::off_t;
+        let mut remaining = length as usize;
+        while remaining > 0 {
+            self.stream.writable().await?;
+            let sent = self.stream.try_io(Interest::WRITABLE, || {
+                nix::sys::sendfile::sendfile(&self.stream, file, Some(&mut offset), remaining)
+                    .map_err(std::io::Error::from)
+            });
+            match sent {
+                Ok(0) => return Err(std::io::ErrorKind::UnexpectedEof.into()),
+                Ok(sent) => remaining -= sent,
+                Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => continue,
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+
+    // sendfile() has a different signature outside of Linux, so the range is read and written instead.
+    #[cfg(not(target_os = "linux"))]
+    async fn send_file_range(
+        &mut self,
+        file: &File,
+        position: u64,
+        length: u64,
+    ) -> Result<(), std::io::Error> {
+        use std::os::unix::fs::FileExt;
+
+        let mut buffer = vec![0u8; length as usize];
+        file.read_exact_at(&mut buffer, position)?;
+        self.stream.write_all(&buffer).await
+    }
+}
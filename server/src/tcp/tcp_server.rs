@@ -16,7 +16,9 @@ pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
     let socket = tcp_socket::build(config.ipv6, config.socket);
     let addr = match config.tls.enabled {
         true => tcp_tls_listener::start(&config.address, config.tls, socket, system).await,
-        false => tcp_listener::start(&config.address, socket, system).await,
+        false => {
+            tcp_listener::start(&config.address, socket, config.zero_copy_polling, system).await
+        }
     };
     info!("{server_name} server has started on: {:?}", addr);
     addr
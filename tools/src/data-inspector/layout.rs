@@ -0,0 +1,129 @@
+use anyhow::{Context, Result};
+use server::streaming::segments::{INDEX_EXTENSION, LOG_EXTENSION};
+use std::path::{Path, PathBuf};
+
+const STREAMS_DIRECTORY: &str = "streams";
+const TOPICS_DIRECTORY: &str = "topics";
+const PARTITIONS_DIRECTORY: &str = "partitions";
+
+/// Partition found on disk, along with its segments sorted by the start offset.
+#[derive(Debug)]
+pub struct PartitionLayout {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub segments: Vec<SegmentLayout>,
+}
+
+#[derive(Debug)]
+pub struct SegmentLayout {
+    pub start_offset: u64,
+    pub log_path: PathBuf,
+    pub index_path: PathBuf,
+}
+
+/// Optional filter narrowing down the partitions to the given stream, topic and partition.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PartitionFilter {
+    pub stream_id: Option<u32>,
+    pub topic_id: Option<u32>,
+    pub partition_id: Option<u32>,
+}
+
+impl PartitionLayout {
+    pub fn name(&self) -> String {
+        format!(
+            "stream: {}, topic: {}, partition: {}",
+            self.stream_id, self.topic_id, self.partition_id
+        )
+    }
+}
+
+/// Discovers the streams, topics, partitions and segments stored in the data directory,
+/// using the same layout as the server, e.g. `streams/1/topics/2/partitions/3/00000000000000000000.log`.
+pub fn discover(path: &str, filter: PartitionFilter) -> Result<Vec<PartitionLayout>> {
+    let mut partitions = Vec::new();
+    let streams_path = Path::new(path).join(STREAMS_DIRECTORY);
+    for (stream_id, stream_path) in read_numeric_directories(&streams_path, filter.stream_id)? {
+        let topics_path = stream_path.join(TOPICS_DIRECTORY);
+        for (topic_id, topic_path) in read_numeric_directories(&topics_path, filter.topic_id)? {
+            let partitions_path = topic_path.join(PARTITIONS_DIRECTORY);
+            for (partition_id, partition_path) in
+                read_numeric_directories(&partitions_path, filter.partition_id)?
+            {
+                let segments = read_segments(&partition_path)?;
+                partitions.push(PartitionLayout {
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                    segments,
+                });
+            }
+        }
+    }
+    Ok(partitions)
+}
+
+fn read_numeric_directories(path: &Path, filter: Option<u32>) -> Result<Vec<(u32, PathBuf)>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut directories = Vec::new();
+    for entry in std::fs::read_dir(path)
+        .with_context(|| format!("Failed to read directory: {}", path.display()))?
+    {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        let Some(id) = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<u32>().ok())
+        else {
+            continue;
+        };
+
+        if filter.is_some_and(|filter| filter != id) {
+            continue;
+        }
+
+        directories.push((id, entry.path()));
+    }
+    directories.sort_by_key(|(id, _)| *id);
+    Ok(directories)
+}
+
+fn read_segments(path: &Path) -> Result<Vec<SegmentLayout>> {
+    let mut segments = Vec::new();
+    for entry in std::fs::read_dir(path)
+        .with_context(|| format!("Failed to read directory: {}", path.display()))?
+    {
+        let log_path = entry?.path();
+        if log_path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            != Some(LOG_EXTENSION)
+        {
+            continue;
+        }
+
+        let Some(start_offset) = log_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<u64>().ok())
+        else {
+            continue;
+        };
+
+        segments.push(SegmentLayout {
+            start_offset,
+            index_path: log_path.with_extension(INDEX_EXTENSION),
+            log_path,
+        });
+    }
+    segments.sort_by_key(|segment| segment.start_offset);
+    Ok(segments)
+}
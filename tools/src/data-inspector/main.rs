@@ -0,0 +1,305 @@
+mod layout;
+mod segment;
+
+use crate::layout::{PartitionFilter, PartitionLayout};
+use anyhow::{anyhow, Context, Result};
+use clap::{Args, Parser, Subcommand};
+use iggy::utils::crypto::{Aes256GcmEncryptor, EncryptorKind};
+use server::compat::index_rebuilding::index_rebuilder::IndexRebuilder;
+use server::state::file::FileState;
+use server::state::State;
+use server::streaming::persistence::persister::{FilePersister, PersisterKind};
+use server::versioning::SemanticVersion;
+use std::path::Path;
+use std::process::ExitCode;
+use std::sync::Arc;
+use tracing::{error, info, warn};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry};
+
+/// Inspects and repairs the data directory of the stopped server.
+#[derive(Parser, Debug, Clone)]
+#[command(author, version, about, long_about = None)]
+pub struct DataInspectorArgs {
+    /// Path to the data directory, the same as `system.path` in the server configuration.
+    #[arg(long, default_value = "local_data")]
+    pub path: String,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// List the streams, topics, partitions and segments found on disk.
+    List(PartitionArgs),
+    /// Print the state snapshot (if any) and the entries of the state log.
+    DumpState(DumpStateArgs),
+    /// Verify the checksums of the messages and detect the torn writes at the tail of the segments.
+    Verify(PartitionArgs),
+    /// Truncate the torn writes at the tail of the segments and rebuild their indexes.
+    Repair(RepairArgs),
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct PartitionArgs {
+    #[arg(long)]
+    pub stream_id: Option<u32>,
+
+    #[arg(long)]
+    pub topic_id: Option<u32>,
+
+    #[arg(long)]
+    pub partition_id: Option<u32>,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct DumpStateArgs {
+    /// Base64 encoded key used by the server to encrypt the state, if encryption is enabled.
+    #[arg(long, default_value = "")]
+    pub encryption_key: String,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct RepairArgs {
+    #[command(flatten)]
+    pub partition: PartitionArgs,
+
+    /// Rebuild the indexes of all the segments, not only the truncated ones.
+    #[arg(long, default_value_t = false)]
+    pub rebuild_indexes: bool,
+
+    /// Only report what would be repaired, without modifying any files.
+    #[arg(long, default_value_t = false)]
+    pub dry_run: bool,
+}
+
+impl From<&PartitionArgs> for PartitionFilter {
+    fn from(args: &PartitionArgs) -> Self {
+        PartitionFilter {
+            stream_id: args.stream_id,
+            topic_id: args.topic_id,
+            partition_id: args.partition_id,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<ExitCode> {
+    let args = DataInspectorArgs::parse();
+    Registry::default()
+        .with(tracing_subscriber::fmt::layer())
+        .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+        .init();
+
+    if !Path::new(&args.path).exists() {
+        error!("Data directory: {} does not exist.", args.path);
+        return Ok(ExitCode::FAILURE);
+    }
+
+    match &args.command {
+        Command::List(command) => list(&args.path, command.into()).await,
+        Command::DumpState(command) => dump_state(&args.path, command).await,
+        Command::Verify(command) => verify(&args.path, command.into()).await,
+        Command::Repair(command) => repair(&args.path, command).await,
+    }
+}
+
+async fn list(path: &str, filter: PartitionFilter) -> Result<ExitCode> {
+    for partition in layout::discover(path, filter)? {
+        info!(
+            "{}, segments: {}",
+            partition.name(),
+            partition.segments.len()
+        );
+        for segment in &partition.segments {
+            let log_size = std::fs::metadata(&segment.log_path)?.len();
+            let index_size = match segment.index_path.exists() {
+                true => std::fs::metadata(&segment.index_path)?.len().to_string(),
+                false => "missing".to_string(),
+            };
+            info!(
+                "  segment start offset: {}, log: {log_size} B, index: {index_size} B",
+                segment.start_offset
+            );
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn dump_state(path: &str, args: &DumpStateArgs) -> Result<ExitCode> {
+    let log_path = format!("{path}/state/log");
+    if !Path::new(&log_path).exists() {
+        error!("State log: {log_path} does not exist.");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    let encryptor = match args.encryption_key.is_empty() {
+        true => None,
+        false => Some(Arc::new(EncryptorKind::Aes256Gcm(
+            Aes256GcmEncryptor::from_base64_key(&args.encryption_key)?,
+        ))),
+    };
+    let state = FileState::new(
+        &log_path,
+        &SemanticVersion::current()?,
+        Arc::new(PersisterKind::File(FilePersister)),
+        encryptor,
+    );
+
+    if let Some(snapshot) = state.load_snapshot().await? {
+        info!(
+            "State snapshot, index: {}, term: {}, timestamp: {}, streams: {}, users: {}",
+            snapshot.index,
+            snapshot.term,
+            snapshot.timestamp,
+            snapshot.state.streams.len(),
+            snapshot.state.users.len()
+        );
+    }
+
+    let entries = state.load_entries().await?;
+    for entry in &entries {
+        info!("{entry}, command: {}", entry.command()?);
+    }
+    info!("Dumped {} state entries.", entries.len());
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn verify(path: &str, filter: PartitionFilter) -> Result<ExitCode> {
+    let mut invalid_segments = 0;
+    for partition in layout::discover(path, filter)? {
+        for segment in &partition.segments {
+            if segment::is_legacy(&segment.log_path).await? {
+                warn!(
+                    "{}, segment: {} uses the legacy batch format, it will be converted by the server on startup.",
+                    partition.name(),
+                    segment.start_offset
+                );
+                continue;
+            }
+
+            let report = segment::scan(&segment.log_path).await?;
+            if report.is_valid() {
+                info!(
+                    "{}, segment: {} is valid, {report}",
+                    partition.name(),
+                    segment.start_offset
+                );
+                continue;
+            }
+
+            invalid_segments += 1;
+            error!(
+                "{}, segment: {} is invalid, {report}",
+                partition.name(),
+                segment.start_offset
+            );
+            if !report.invalid_checksums.is_empty() {
+                error!(
+                    "Messages with invalid checksums, offsets: {:?}",
+                    report.invalid_checksums
+                );
+            }
+            if !report.invalid_crcs.is_empty() {
+                error!(
+                    "Batches with invalid CRCs, base offsets: {:?}",
+                    report.invalid_crcs
+                );
+            }
+        }
+    }
+
+    if invalid_segments > 0 {
+        error!("Found {invalid_segments} invalid segment(s).");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    info!("All the segments are valid.");
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn repair(path: &str, args: &RepairArgs) -> Result<ExitCode> {
+    for partition in layout::discover(path, (&args.partition).into())? {
+        repair_partition(&partition, args).await?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn repair_partition(partition: &PartitionLayout, args: &RepairArgs) -> Result<()> {
+    for segment in &partition.segments {
+        if segment::is_legacy(&segment.log_path).await? {
+            warn!(
+                "{}, segment: {} uses the legacy batch format, skipping it, as it will be converted by the server on startup.",
+                partition.name(),
+                segment.start_offset
+            );
+            continue;
+        }
+
+        let report = segment::scan(&segment.log_path).await?;
+        if !report.invalid_checksums.is_empty() {
+            // The corrupted payload can't be restored, but the batch itself is still readable.
+            warn!(
+                "{}, segment: {} contains messages with invalid checksums, offsets: {:?}",
+                partition.name(),
+                segment.start_offset,
+                report.invalid_checksums
+            );
+        }
+
+        let truncate = report.torn_bytes > 0;
+        if !truncate && !args.rebuild_indexes && segment.index_path.exists() {
+            continue;
+        }
+
+        if args.dry_run {
+            info!(
+                "{}, segment: {} would be repaired, torn bytes to truncate: {}",
+                partition.name(),
+                segment.start_offset,
+                report.torn_bytes
+            );
+            continue;
+        }
+
+        if truncate {
+            let file = std::fs::OpenOptions::new()
+                .write(true)
+                .open(&segment.log_path)
+                .with_context(|| {
+                    format!("Failed to open segment log: {}", segment.log_path.display())
+                })?;
+            file.set_len(report.valid_length)?;
+            file.sync_all()?;
+            warn!(
+                "{}, segment: {} truncated {} torn bytes, log size: {} B",
+                partition.name(),
+                segment.start_offset,
+                report.torn_bytes,
+                report.valid_length
+            );
+        }
+
+        IndexRebuilder::new(
+            segment.log_path.to_string_lossy().to_string(),
+            segment.index_path.to_string_lossy().to_string(),
+            segment.start_offset,
+        )
+        .rebuild()
+        .await
+        .map_err(|error| {
+            anyhow!(
+                "Failed to rebuild index: {}, error: {error}",
+                segment.index_path.display()
+            )
+        })?;
+        info!(
+            "{}, segment: {} index has been rebuilt.",
+            partition.name(),
+            segment.start_offset
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use anyhow::{Context, Result};
+use bytes::Bytes;
+use iggy::utils::checksum;
+use server::compat::batch_conversion::batch_converter::{BatchConverter, BatchFormat};
+use server::streaming::batching::message_batch::{RetainedBatchHeader, RETAINED_BATCH_HEADER_LEN};
+use server::streaming::models::messages::RetainedMessage;
+use std::fmt::{Display, Formatter};
+use std::io::ErrorKind;
+use std::path::Path;
+use tokio::io::{AsyncReadExt, BufReader};
+
+const BUF_READER_CAPACITY_BYTES: usize = 512 * 1000;
+/// Offset (8) + state (1) + timestamp (8) + ID (16) + checksum (4) + headers length (4).
+const MESSAGE_HEADER_LEN: usize = 8 + 1 + 8 + 16 + 4 + 4;
+
+/// Result of scanning the log of a single segment, batch by batch.
+/// - `valid_length` - Length of the log up to (and excluding) the first incomplete or malformed batch
+/// - `torn_bytes` - Number of bytes following the last valid batch, most likely left by an interrupted write
+/// - `invalid_checksums` - Offsets of the messages whose payload doesn't match the stored checksum
+/// - `invalid_crcs` - Base offsets of the batches which don't match the stored CRC
+#[derive(Debug, Default)]
+pub struct SegmentReport {
+    pub file_length: u64,
+    pub valid_length: u64,
+    pub torn_bytes: u64,
+    pub batches_count: u64,
+    pub messages_count: u64,
+    pub first_offset: Option<u64>,
+    pub last_offset: Option<u64>,
+    pub invalid_checksums: Vec<u64>,
+    pub invalid_crcs: Vec<u64>,
+}
+
+impl SegmentReport {
+    pub fn is_valid(&self) -> bool {
+        self.torn_bytes == 0 && self.invalid_checksums.is_empty() && self.invalid_crcs.is_empty()
+    }
+}
+
+impl Display for SegmentReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "size: {} B, batches: {}, messages: {}, offsets: {}..{}, torn bytes: {}, invalid checksums: {}, invalid CRCs: {}",
+            self.file_length,
+            self.batches_count,
+            self.messages_count,
+            self.first_offset.map_or("-".to_string(), |offset| offset.to_string()),
+            self.last_offset.map_or("-".to_string(), |offset| offset.to_string()),
+            self.torn_bytes,
+            self.invalid_checksums.len(),
+            self.invalid_crcs.len(),
+        )
+    }
+}
+
+/// Reads all the batches of the segment log, verifying the checksums of the messages
+/// and detecting the incomplete (torn) data at the tail of the log.
+pub async fn scan(log_path: &Path) -> Result<SegmentReport> {
+    let file = tokio::fs::File::open(log_path)
+        .await
+        .with_context(|| format!("Failed to open segment log: {}", log_path.display()))?;
+    let file_length = file.metadata().await?.len();
+    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+    let mut report = SegmentReport {
+        file_length,
+        ..Default::default()
+    };
+
+    let mut position = 0;
+    while position < file_length {
+        if file_length - position < RETAINED_BATCH_HEADER_LEN {
+            break;
+        }
+
+        let mut header_bytes = [0; RETAINED_BATCH_HEADER_LEN as usize];
+        reader.read_exact(&mut header_bytes).await?;
+        let Ok(header) = RetainedBatchHeader::from_bytes(&header_bytes) else {
+            break;
+        };
+        let length = header.length as u64;
+        if file_length - position - RETAINED_BATCH_HEADER_LEN < length {
+            break;
+        }
+
+        let mut bytes = vec![0; length as usize];
+        match reader.read_exact(&mut bytes).await {
+            Ok(_) => {}
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => break,
+            Err(error) => return Err(error.into()),
+        }
+
+        if header.verify_crc(&header_bytes, &bytes).is_err() {
+            report.invalid_crcs.push(header.base_offset);
+        }
+
+        let Some(messages) = read_messages(Bytes::from(bytes)) else {
+            break;
+        };
+
+        for message in &messages {
+            if checksum::calculate(&message.payload) != message.checksum {
+                report.invalid_checksums.push(message.offset);
+            }
+        }
+
+        report.batches_count += 1;
+        report.messages_count += messages.len() as u64;
+        report.first_offset.get_or_insert(header.base_offset);
+        report.last_offset = Some(header.base_offset + header.last_offset_delta as u64);
+        position += RETAINED_BATCH_HEADER_LEN + length;
+    }
+
+    report.valid_length = position;
+    report.torn_bytes = file_length - position;
+    Ok(report)
+}
+
+/// Returns `true` if the segment log has been written before the versioned batch format,
+/// in which case it's converted by the server on startup, instead of being verified or repaired.
+pub async fn is_legacy(log_path: &Path) -> Result<bool> {
+    let log_path = log_path.to_string_lossy().to_string();
+    let format = BatchConverter::new(log_path.clone(), String::new(), String::new(), 0, 0)
+        .detect_format()
+        .await
+        .map_err(|error| {
+            anyhow::anyhow!("Failed to detect batch format: {log_path}, error: {error}")
+        })?;
+    Ok(format == Some(BatchFormat::Legacy))
+}
+
+/// Returns `None` if the batch is malformed, e.g. the message length exceeds the batch.
+fn read_messages(bytes: Bytes) -> Option<Vec<RetainedMessage>> {
+    let mut messages = Vec::new();
+    let mut position = 0;
+    while position < bytes.len() {
+        if bytes.len() - position < 4 {
+            return None;
+        }
+
+        let length = u32::from_le_bytes(bytes[position..position + 4].try_into().ok()?) as usize;
+        position += 4;
+        if length < MESSAGE_HEADER_LEN || bytes.len() - position < length {
+            return None;
+        }
+
+        let message = bytes.slice(position..position + length);
+        let headers_length = u32::from_le_bytes(message[37..41].try_into().ok()?) as usize;
+        if length - MESSAGE_HEADER_LEN < headers_length {
+            return None;
+        }
+
+        messages.push(RetainedMessage::try_from_bytes(message).ok()?);
+        position += length;
+    }
+    Some(messages)
+}
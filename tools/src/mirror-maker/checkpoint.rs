@@ -0,0 +1,73 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+
+/// The progress of the mirror, i.e. the offset of the last message copied from each of the source partitions.
+/// It's replaced atomically (written to the temporary file and renamed), so it's never torn by the crash.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub source_stream: String,
+    pub source_topic: String,
+    pub partitions: BTreeMap<u32, u64>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl Checkpoint {
+    /// Loads the checkpoint from the file or starts from the beginning of the topic if there's no file yet.
+    pub async fn load(path: &Path, source_stream: &str, source_topic: &str) -> Result<Self> {
+        if !tokio::fs::try_exists(path).await? {
+            return Ok(Checkpoint {
+                source_stream: source_stream.to_owned(),
+                source_topic: source_topic.to_owned(),
+                partitions: BTreeMap::new(),
+                path: path.to_owned(),
+            });
+        }
+
+        let content = tokio::fs::read(path)
+            .await
+            .with_context(|| format!("Failed to read checkpoint: {}", path.display()))?;
+        let mut checkpoint: Checkpoint = serde_json::from_slice(&content)
+            .with_context(|| format!("Invalid checkpoint: {}", path.display()))?;
+        if checkpoint.source_stream != source_stream || checkpoint.source_topic != source_topic {
+            anyhow::bail!(
+                "Checkpoint: {} was created for stream: {}, topic: {}",
+                path.display(),
+                checkpoint.source_stream,
+                checkpoint.source_topic
+            );
+        }
+
+        checkpoint.path = path.to_owned();
+        Ok(checkpoint)
+    }
+
+    /// Returns the offset of the first message of the partition which hasn't been copied yet.
+    pub fn get_next_offset(&self, partition_id: u32) -> u64 {
+        self.partitions
+            .get(&partition_id)
+            .map_or(0, |offset| offset + 1)
+    }
+
+    pub async fn save(&mut self, partition_id: u32, offset: u64) -> Result<()> {
+        self.partitions.insert(partition_id, offset);
+        let temp_path = self.path.with_extension("tmp");
+        let mut file = File::create(&temp_path)
+            .await
+            .with_context(|| format!("Failed to create checkpoint: {}", temp_path.display()))?;
+        file.write_all(&serde_json::to_vec_pretty(self)?)
+            .await
+            .with_context(|| format!("Failed to write checkpoint: {}", temp_path.display()))?;
+        file.sync_all()
+            .await
+            .with_context(|| format!("Failed to sync checkpoint: {}", temp_path.display()))?;
+        tokio::fs::rename(&temp_path, &self.path)
+            .await
+            .with_context(|| format!("Failed to replace checkpoint: {}", self.path.display()))?;
+        Ok(())
+    }
+}
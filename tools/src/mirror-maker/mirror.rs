@@ -0,0 +1,167 @@
+use crate::checkpoint::Checkpoint;
+use crate::PartitionMapping;
+use anyhow::{bail, Context, Result};
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::consumer::Consumer;
+use iggy::identifier::{IdKind, Identifier};
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic::TopicDetails;
+use std::time::Duration;
+use tracing::info;
+
+pub struct Mirror {
+    pub source: IggyClient,
+    pub target: IggyClient,
+    pub source_stream: Identifier,
+    pub source_topic: Identifier,
+    pub target_stream: Identifier,
+    pub target_topic: Identifier,
+    pub partition_mapping: PartitionMapping,
+    pub messages_per_batch: u32,
+    pub poll_interval: Duration,
+    pub checkpoint: Checkpoint,
+}
+
+impl Mirror {
+    /// Creates the target stream and topic with the same settings as the source topic, if they don't exist.
+    pub async fn create_target(&self) -> Result<()> {
+        let source_stream = self
+            .source
+            .get_stream(&self.source_stream)
+            .await?
+            .with_context(|| format!("Source stream: {} not found", self.source_stream))?;
+        if self.target.get_stream(&self.target_stream).await?.is_none() {
+            let (name, stream_id) = get_name_and_id(&self.target_stream, &source_stream.name)?;
+            self.target.create_stream(&name, stream_id).await?;
+            info!("Created target stream: {name}.");
+        }
+
+        if self
+            .target
+            .get_topic(&self.target_stream, &self.target_topic)
+            .await?
+            .is_none()
+        {
+            let source_topic = self.get_source_topic().await?;
+            let (name, topic_id) = get_name_and_id(&self.target_topic, &source_topic.name)?;
+            self.target
+                .create_topic(
+                    &self.target_stream,
+                    &name,
+                    source_topic.partitions_count,
+                    source_topic.compression_algorithm,
+                    Some(source_topic.replication_factor),
+                    topic_id,
+                    source_topic.message_expiry,
+                    source_topic.max_topic_size,
+                )
+                .await?;
+            info!(
+                "Created target topic: {name} with {} partitions.",
+                source_topic.partitions_count
+            );
+        }
+        Ok(())
+    }
+
+    /// Copies the messages until the process is stopped, the partitions are read one after another,
+    /// and the checkpoint is saved once each batch is appended to the target topic,
+    /// so the batch that was being copied during the crash is sent again after the restart.
+    pub async fn run(&mut self) -> Result<()> {
+        loop {
+            let partitions_count = self.get_source_topic().await?.partitions_count;
+            if self.partition_mapping == PartitionMapping::Preserve {
+                let target_partitions_count = self
+                    .target
+                    .get_topic(&self.target_stream, &self.target_topic)
+                    .await?
+                    .with_context(|| format!("Target topic: {} not found", self.target_topic))?
+                    .partitions_count;
+                if target_partitions_count < partitions_count {
+                    bail!(
+                        "Target topic has {target_partitions_count} partitions, but the source topic has {partitions_count} partitions"
+                    );
+                }
+            }
+
+            let mut copied_messages = 0;
+            for partition_id in 1..=partitions_count {
+                copied_messages += self.copy_messages(partition_id).await?;
+            }
+
+            if copied_messages == 0 {
+                tokio::time::sleep(self.poll_interval).await;
+            }
+        }
+    }
+
+    async fn copy_messages(&mut self, partition_id: u32) -> Result<u64> {
+        let offset = self.checkpoint.get_next_offset(partition_id);
+        let polled_messages = self
+            .source
+            .poll_messages(
+                &self.source_stream,
+                &self.source_topic,
+                Some(partition_id),
+                &Consumer::default(),
+                &PollingStrategy::offset(offset),
+                self.messages_per_batch,
+                false,
+            )
+            .await
+            .with_context(|| {
+                format!("Failed to poll messages from partition: {partition_id}, offset: {offset}")
+            })?;
+        let Some(last_offset) = polled_messages
+            .messages
+            .last()
+            .map(|message| message.offset)
+        else {
+            return Ok(0);
+        };
+
+        let mut messages = polled_messages
+            .messages
+            .into_iter()
+            .map(|message| Message::new(Some(message.id), message.payload, message.headers))
+            .collect::<Vec<_>>();
+        let partitioning = match self.partition_mapping {
+            PartitionMapping::Preserve => Partitioning::partition_id(partition_id),
+            PartitionMapping::Balanced => Partitioning::balanced(),
+        };
+        self.target
+            .send_messages(
+                &self.target_stream,
+                &self.target_topic,
+                &partitioning,
+                &mut messages,
+            )
+            .await
+            .with_context(|| {
+                format!("Failed to send messages copied from partition: {partition_id}")
+            })?;
+        self.checkpoint.save(partition_id, last_offset).await?;
+        info!(
+            "Copied {} messages from partition: {partition_id}, offsets: {offset}..={last_offset}.",
+            messages.len()
+        );
+        Ok(messages.len() as u64)
+    }
+
+    async fn get_source_topic(&self) -> Result<TopicDetails> {
+        self.source
+            .get_topic(&self.source_stream, &self.source_topic)
+            .await?
+            .with_context(|| format!("Source topic: {} not found", self.source_topic))
+    }
+}
+
+/// The numeric identifier is used as the ID of the created resource, which gets the name of the source one.
+fn get_name_and_id(identifier: &Identifier, source_name: &str) -> Result<(String, Option<u32>)> {
+    match identifier.kind {
+        IdKind::Numeric => Ok((source_name.to_owned(), Some(identifier.get_u32_value()?))),
+        IdKind::String => Ok((identifier.get_string_value()?, None)),
+    }
+}
@@ -0,0 +1,68 @@
+use anyhow::{Context, Result};
+use bytes::Bytes;
+use clap::Parser;
+use iggy::utils::crypto::{Aes256GcmEncryptor, EncryptorKind};
+use server::state::snapshot::StateSnapshot;
+use std::process::ExitCode;
+use tracing::{error, info};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry};
+
+/// Verifies the checksum of the state snapshot created by the state log compaction.
+#[derive(Parser, Debug, Clone)]
+#[command(author, version, about, long_about = None)]
+pub struct StateSnapshotArgs {
+    /// Path to the state snapshot file, e.g. `local_data/state/snapshot`.
+    #[arg(long, default_value = "local_data/state/snapshot")]
+    pub path: String,
+
+    /// Optional base64 encoded key used by the server to encrypt the state,
+    /// if provided, the state is also decrypted and deserialized.
+    #[arg(long, default_value = "")]
+    pub encryption_key: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<ExitCode> {
+    let args = StateSnapshotArgs::parse();
+    Registry::default()
+        .with(tracing_subscriber::fmt::layer())
+        .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+        .init();
+
+    let bytes = tokio::fs::read(&args.path)
+        .await
+        .with_context(|| format!("Failed to read state snapshot: {}", args.path))?;
+    let header = match StateSnapshot::verify(&bytes) {
+        Ok(header) => header,
+        Err(error) => {
+            error!("State snapshot: {} is invalid, error: {error}", args.path);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    info!("State snapshot: {} checksum is valid, {header}", args.path);
+
+    let encryptor = match args.encryption_key.is_empty() {
+        true => None,
+        false => Some(EncryptorKind::Aes256Gcm(
+            Aes256GcmEncryptor::from_base64_key(&args.encryption_key)?,
+        )),
+    };
+    match StateSnapshot::from_bytes(Bytes::from(bytes), encryptor.as_ref()) {
+        Ok(snapshot) => info!(
+            "State snapshot contains {} streams and {} users.",
+            snapshot.state.streams.len(),
+            snapshot.state.users.len()
+        ),
+        Err(error) => {
+            error!(
+                "State snapshot: {} can't be read, error: {error}",
+                args.path
+            );
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}